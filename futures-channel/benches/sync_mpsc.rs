@@ -72,6 +72,48 @@ fn unbounded_uncontended(b: &mut Bencher) {
     })
 }
 
+/// Single producer, single consumer, with the whole batch queued at once
+#[bench]
+fn unbounded_burst(b: &mut Bencher) {
+    let mut cx = noop_context();
+    b.iter(|| {
+        let (tx, mut rx) = mpsc::unbounded();
+
+        // 1000 messages are in the channel before the first one is received
+        for i in 0..1000 {
+            UnboundedSender::unbounded_send(&tx, i).expect("send");
+        }
+        for i in 0..1000 {
+            assert_eq!(Poll::Ready(Some(i)), rx.poll_next_unpin(&mut cx));
+        }
+    })
+}
+
+/// 4 producer threads, single consumer
+#[bench]
+fn unbounded_4_threads(b: &mut Bencher) {
+    b.iter(|| {
+        let (tx, rx) = mpsc::unbounded();
+
+        let threads: Vec<_> = (0..4)
+            .map(|_| {
+                let tx = tx.clone();
+                std::thread::spawn(move || {
+                    for i in 0..1000 {
+                        tx.unbounded_send(i).expect("send");
+                    }
+                })
+            })
+            .collect();
+        drop(tx);
+
+        assert_eq!(futures::executor::block_on(rx.count()), 4000);
+        for t in threads {
+            t.join().unwrap();
+        }
+    })
+}
+
 /// A Stream that continuously sends incrementing number of the queue
 struct TestSender {
     tx: Sender<u32>,
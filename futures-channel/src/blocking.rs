@@ -0,0 +1,66 @@
+//! Parking the current thread until a channel operation completes, for the
+//! `blocking_*` methods of the channels.
+
+use core::mem::ManuallyDrop;
+use futures_core::task::{Context, Poll, RawWaker, RawWakerVTable, Waker};
+use std::sync::atomic::{AtomicBool, Ordering::SeqCst};
+use std::sync::Arc;
+use std::thread::{self, Thread};
+
+struct ThreadNotify {
+    thread: Thread,
+    // Ensures that a wakeup is not forgotten before the next `park()`, in
+    // case the thread is unparked by someone else in the meantime.
+    unparked: AtomicBool,
+}
+
+impl ThreadNotify {
+    fn wake(&self) {
+        if !self.unparked.swap(true, SeqCst) {
+            self.thread.unpark();
+        }
+    }
+}
+
+static VTABLE: RawWakerVTable = RawWakerVTable::new(clone, wake, wake_by_ref, drop_waker);
+
+fn raw_waker(notify: Arc<ThreadNotify>) -> RawWaker {
+    RawWaker::new(Arc::into_raw(notify) as *const (), &VTABLE)
+}
+
+unsafe fn clone(ptr: *const ()) -> RawWaker {
+    let notify = ManuallyDrop::new(Arc::from_raw(ptr as *const ThreadNotify));
+    raw_waker(Arc::clone(&notify))
+}
+
+unsafe fn wake(ptr: *const ()) {
+    Arc::from_raw(ptr as *const ThreadNotify).wake();
+}
+
+unsafe fn wake_by_ref(ptr: *const ()) {
+    ManuallyDrop::new(Arc::from_raw(ptr as *const ThreadNotify)).wake();
+}
+
+unsafe fn drop_waker(ptr: *const ()) {
+    drop(Arc::from_raw(ptr as *const ThreadNotify));
+}
+
+/// Polls `f` on the current thread until it is ready, parking the thread
+/// while it is pending.
+pub(crate) fn block_on<T>(mut f: impl FnMut(&mut Context<'_>) -> Poll<T>) -> T {
+    let notify =
+        Arc::new(ThreadNotify { thread: thread::current(), unparked: AtomicBool::new(false) });
+    // Safety: the vtable functions uphold the `RawWaker` contract for a
+    // pointer obtained from `Arc::into_raw`.
+    let waker = unsafe { Waker::from_raw(raw_waker(notify.clone())) };
+    let mut cx = Context::from_waker(&waker);
+
+    loop {
+        if let Poll::Ready(t) = f(&mut cx) {
+            return t;
+        }
+        while !notify.unparked.swap(false, SeqCst) {
+            thread::park();
+        }
+    }
+}
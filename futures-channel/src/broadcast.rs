@@ -0,0 +1,409 @@
+//! A multi-producer, multi-consumer channel where every receiver sees every
+//! message.
+//!
+//! Channel creation provides a [`Sender`] and a [`Receiver`] handle. Each
+//! message sent into the channel is kept in a ring buffer of fixed capacity
+//! and given to every receiver that exists at the time, so `T` has to be
+//! `Clone`. More receivers can be created with [`Sender::subscribe`], which
+//! starts at the next message, or by cloning a receiver, which starts at the
+//! same position as the original.
+//!
+//! Sending never waits: once the buffer is full, the oldest message is
+//! overwritten. A receiver that falls so far behind that messages it has not
+//! seen yet are overwritten has *lagged*, and its [`LagPolicy`] decides
+//! whether it is told so with a [`Lagged`] error or silently skips ahead to
+//! the oldest message still in the buffer.
+//!
+//! # Disconnection
+//!
+//! When all [`Sender`] handles have been dropped, receivers still get the
+//! messages they have not seen yet, then the end of the stream. When all
+//! [`Receiver`] handles have been dropped, sending fails.
+//!
+//! # Examples
+//!
+//! ```
+//! # futures::executor::block_on(async {
+//! use futures::channel::broadcast;
+//! use futures::stream::StreamExt;
+//!
+//! let (tx, mut rx1) = broadcast::channel(16);
+//! let mut rx2 = tx.subscribe();
+//!
+//! tx.send("hello").unwrap();
+//! drop(tx);
+//!
+//! assert_eq!(rx1.next().await, Some(Ok("hello")));
+//! assert_eq!(rx2.next().await, Some(Ok("hello")));
+//! assert_eq!(rx1.next().await, None);
+//! # });
+//! ```
+
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Context, Poll, Waker};
+use std::collections::VecDeque;
+use std::fmt;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+
+/// How a [`Receiver`] handles messages it missed because it fell behind.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LagPolicy {
+    /// Report the number of missed messages with a [`Lagged`] error, and then
+    /// continue with the oldest message still in the buffer. This is the
+    /// default.
+    Error,
+    /// Continue with the oldest message still in the buffer without
+    /// reporting anything.
+    Skip,
+}
+
+impl Default for LagPolicy {
+    fn default() -> Self {
+        Self::Error
+    }
+}
+
+/// The error produced by a [`Receiver`] that missed messages because it fell
+/// behind, carrying the number of messages it missed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Lagged(pub u64);
+
+impl fmt::Display for Lagged {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "receiver lagged behind and missed {} messages", self.0)
+    }
+}
+
+impl std::error::Error for Lagged {}
+
+/// The error type returned from [`send`](Sender::send) when there are no
+/// receivers left, which hands the message back.
+#[derive(Clone, PartialEq, Eq)]
+pub struct SendError<T>(pub T);
+
+impl<T> fmt::Debug for SendError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("SendError").finish()
+    }
+}
+
+impl<T> fmt::Display for SendError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "send failed because all receivers are gone")
+    }
+}
+
+impl<T> std::error::Error for SendError<T> {}
+
+/// The error type returned from [`try_recv`](Receiver::try_recv).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TryRecvError {
+    /// There is no new message yet.
+    Empty,
+    /// The receiver missed this many messages because it fell behind.
+    Lagged(u64),
+    /// All senders are gone and the receiver has seen every message.
+    Closed,
+}
+
+impl fmt::Display for TryRecvError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "receiver found no new message"),
+            Self::Lagged(n) => write!(f, "receiver lagged behind and missed {} messages", n),
+            Self::Closed => write!(f, "receiver found the channel closed"),
+        }
+    }
+}
+
+impl std::error::Error for TryRecvError {}
+
+struct State<T> {
+    buf: VecDeque<T>,
+    capacity: usize,
+    // Sequence number of the message at the front of `buf`
+    head: u64,
+    senders: usize,
+    receivers: usize,
+    next_id: usize,
+    wakers: Vec<(usize, Waker)>,
+}
+
+impl<T: Clone> State<T> {
+    // Takes the message at `next` for a receiver, moving it past missed
+    // messages first.
+    fn recv(&self, next: &mut u64, policy: LagPolicy) -> Option<Result<T, Lagged>> {
+        if *next < self.head {
+            let missed = self.head - *next;
+            *next = self.head;
+            if policy == LagPolicy::Error {
+                return Some(Err(Lagged(missed)));
+            }
+        }
+        let msg = self.buf.get((*next - self.head) as usize)?.clone();
+        *next += 1;
+        Some(Ok(msg))
+    }
+}
+
+impl<T> State<T> {
+    fn tail(&self) -> u64 {
+        self.head + self.buf.len() as u64
+    }
+
+    fn wake_all(&mut self) {
+        for (_, waker) in self.wakers.drain(..) {
+            waker.wake();
+        }
+    }
+
+    fn new_id(&mut self) -> usize {
+        let id = self.next_id;
+        self.next_id = self.next_id.wrapping_add(1);
+        id
+    }
+}
+
+/// The transmission end of a broadcast channel.
+///
+/// This value is created by the [`channel`] function.
+pub struct Sender<T> {
+    shared: Arc<Mutex<State<T>>>,
+}
+
+/// The receiving end of a broadcast channel.
+///
+/// This value is created by the [`channel`] function, by
+/// [`Sender::subscribe`] or by cloning another receiver.
+pub struct Receiver<T> {
+    shared: Arc<Mutex<State<T>>>,
+    next: u64,
+    id: usize,
+    policy: LagPolicy,
+    terminated: bool,
+}
+
+/// Creates a new broadcast channel, returning the sender and a first
+/// receiver.
+///
+/// The channel buffers the last `capacity` messages, which receivers that
+/// fall behind can still catch up on.
+///
+/// # Panics
+///
+/// Panics if `capacity` is zero.
+pub fn channel<T: Clone>(capacity: usize) -> (Sender<T>, Receiver<T>) {
+    assert!(capacity > 0, "capacity must be greater than zero");
+
+    let state = State {
+        buf: VecDeque::with_capacity(capacity),
+        capacity,
+        head: 0,
+        senders: 1,
+        receivers: 1,
+        next_id: 1,
+        wakers: Vec::new(),
+    };
+    let shared = Arc::new(Mutex::new(state));
+    let rx = Receiver {
+        shared: shared.clone(),
+        next: 0,
+        id: 0,
+        policy: LagPolicy::Error,
+        terminated: false,
+    };
+    (Sender { shared }, rx)
+}
+
+impl<T> Sender<T> {
+    /// Sends a message to every receiver, returning the number of receivers
+    /// that will see it.
+    ///
+    /// This never waits. If the buffer is full, the oldest message is
+    /// overwritten, and receivers that have not seen it yet will lag.
+    ///
+    /// # Errors
+    ///
+    /// Returns the message back if there are no receivers left.
+    pub fn send(&self, msg: T) -> Result<usize, SendError<T>> {
+        let mut state = self.shared.lock().unwrap();
+        if state.receivers == 0 {
+            return Err(SendError(msg));
+        }
+        if state.buf.len() == state.capacity {
+            state.buf.pop_front();
+            state.head += 1;
+        }
+        state.buf.push_back(msg);
+        state.wake_all();
+        Ok(state.receivers)
+    }
+
+    /// Creates a new receiver, which will see the messages sent from now on.
+    pub fn subscribe(&self) -> Receiver<T> {
+        let mut state = self.shared.lock().unwrap();
+        state.receivers += 1;
+        let id = state.new_id();
+        Receiver {
+            shared: self.shared.clone(),
+            next: state.tail(),
+            id,
+            policy: LagPolicy::Error,
+            terminated: false,
+        }
+    }
+
+    /// Returns the number of receivers currently connected to the channel.
+    pub fn receiver_count(&self) -> usize {
+        self.shared.lock().unwrap().receivers
+    }
+}
+
+impl<T> Clone for Sender<T> {
+    fn clone(&self) -> Self {
+        self.shared.lock().unwrap().senders += 1;
+        Self { shared: self.shared.clone() }
+    }
+}
+
+impl<T> Drop for Sender<T> {
+    fn drop(&mut self) {
+        let mut state = self.shared.lock().unwrap();
+        state.senders -= 1;
+        if state.senders == 0 {
+            state.wake_all();
+        }
+    }
+}
+
+impl<T> fmt::Debug for Sender<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Sender").finish()
+    }
+}
+
+impl<T: Clone> Receiver<T> {
+    /// Returns the policy this receiver uses for messages it missed.
+    pub fn lag_policy(&self) -> LagPolicy {
+        self.policy
+    }
+
+    /// Changes the policy this receiver uses for messages it missed.
+    pub fn set_lag_policy(&mut self, policy: LagPolicy) {
+        self.policy = policy;
+    }
+
+    /// Returns the number of messages in the buffer this receiver has not
+    /// seen yet.
+    pub fn len(&self) -> usize {
+        let state = self.shared.lock().unwrap();
+        (state.tail() - self.next.max(state.head)) as usize
+    }
+
+    /// Returns `true` if this receiver has seen every message in the buffer.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Tries to receive the next message without waiting.
+    pub fn try_recv(&mut self) -> Result<T, TryRecvError> {
+        let state = self.shared.lock().unwrap();
+        match state.recv(&mut self.next, self.policy) {
+            Some(Ok(msg)) => Ok(msg),
+            Some(Err(Lagged(n))) => Err(TryRecvError::Lagged(n)),
+            None if state.senders == 0 => Err(TryRecvError::Closed),
+            None => Err(TryRecvError::Empty),
+        }
+    }
+}
+
+impl<T: Clone> Stream for Receiver<T> {
+    type Item = Result<T, Lagged>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = &mut *self;
+        if this.terminated {
+            return Poll::Ready(None);
+        }
+
+        let mut state = this.shared.lock().unwrap();
+        if let Some(item) = state.recv(&mut this.next, this.policy) {
+            return Poll::Ready(Some(item));
+        }
+        if state.senders == 0 {
+            this.terminated = true;
+            return Poll::Ready(None);
+        }
+
+        let id = this.id;
+        match state.wakers.iter_mut().find(|(waker_id, _)| *waker_id == id) {
+            Some((_, waker)) => {
+                if !waker.will_wake(cx.waker()) {
+                    *waker = cx.waker().clone();
+                }
+            }
+            None => state.wakers.push((id, cx.waker().clone())),
+        }
+        Poll::Pending
+    }
+}
+
+impl<T: Clone> FusedStream for Receiver<T> {
+    fn is_terminated(&self) -> bool {
+        self.terminated
+    }
+}
+
+impl<T> Clone for Receiver<T> {
+    fn clone(&self) -> Self {
+        let mut state = self.shared.lock().unwrap();
+        state.receivers += 1;
+        let id = state.new_id();
+        Self {
+            shared: self.shared.clone(),
+            next: self.next,
+            id,
+            policy: self.policy,
+            terminated: self.terminated,
+        }
+    }
+}
+
+impl<T> Drop for Receiver<T> {
+    fn drop(&mut self) {
+        let mut state = self.shared.lock().unwrap();
+        state.receivers -= 1;
+        let id = self.id;
+        state.wakers.retain(|(waker_id, _)| *waker_id != id);
+    }
+}
+
+impl<T> Unpin for Receiver<T> {}
+
+impl<T> fmt::Debug for Receiver<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Receiver").field("next", &self.next).field("policy", &self.policy).finish()
+    }
+}
+
+#[cfg(feature = "sink")]
+impl<T> futures_sink::Sink<T> for Sender<T> {
+    type Error = SendError<T>;
+
+    fn poll_ready(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn start_send(self: Pin<&mut Self>, msg: T) -> Result<(), Self::Error> {
+        self.send(msg).map(drop)
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_close(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+}
@@ -1,12 +1,24 @@
 //! Asynchronous channels.
 //!
 //! Like threads, concurrent tasks sometimes need to communicate with each
-//! other. This module contains two basic abstractions for doing so:
+//! other. This module contains basic abstractions for doing so:
 //!
 //! - [oneshot], a way of sending a single value from one task to another.
 //! - [mpsc], a multi-producer, single-consumer channel for sending values
 //!   between tasks, analogous to the similarly-named structure in the standard
 //!   library.
+//! - [mpmc], a bounded multi-producer, multi-consumer channel where each
+//!   value goes to exactly one receiver.
+//! - [mux], an unbounded multi-producer channel whose values are routed by
+//!   key to a separate receiver per key.
+//! - [priority], a bounded multi-producer, single-consumer channel that
+//!   delivers the highest-priority value first.
+//! - [ring], a bounded multi-producer, single-consumer channel that drops the
+//!   oldest value instead of waiting when it is full.
+//! - [broadcast], a multi-producer, multi-consumer channel where every
+//!   receiver sees every value.
+//! - [watch], a channel holding a single latest value, which many receivers
+//!   can observe.
 //!
 //! All items are only available when the `std` or `alloc` feature of this
 //! library is activated, and it is activated by default.
@@ -29,14 +41,52 @@
 
 #[cfg(not(futures_no_atomic_cas))]
 #[cfg(feature = "alloc")]
+#[cfg_attr(futures_portable_atomic, allow(unused_extern_crates))]
 extern crate alloc;
 
+// On targets without atomic CAS, `Arc` and the atomic types come from the
+// `portable-atomic` crates when the `portable-atomic` feature is enabled.
+#[cfg(not(futures_no_atomic_cas))]
+#[cfg(feature = "alloc")]
+mod sync {
+    #[cfg(not(futures_portable_atomic))]
+    pub(crate) use alloc::sync::Arc;
+    #[cfg(not(futures_portable_atomic))]
+    pub(crate) use core::sync::atomic;
+
+    #[cfg(futures_portable_atomic)]
+    pub(crate) use portable_atomic as atomic;
+    #[cfg(futures_portable_atomic)]
+    pub(crate) use portable_atomic_util::Arc;
+}
+
+#[cfg(not(futures_no_atomic_cas))]
+#[cfg(feature = "std")]
+mod blocking;
+#[cfg(not(futures_no_atomic_cas))]
+#[cfg(feature = "std")]
+pub mod broadcast;
 #[cfg(not(futures_no_atomic_cas))]
 #[cfg(feature = "alloc")]
 mod lock;
 #[cfg(not(futures_no_atomic_cas))]
 #[cfg(feature = "std")]
+pub mod mpmc;
+#[cfg(not(futures_no_atomic_cas))]
+#[cfg(feature = "std")]
 pub mod mpsc;
 #[cfg(not(futures_no_atomic_cas))]
+#[cfg(feature = "std")]
+pub mod mux;
+#[cfg(not(futures_no_atomic_cas))]
 #[cfg(feature = "alloc")]
 pub mod oneshot;
+#[cfg(not(futures_no_atomic_cas))]
+#[cfg(feature = "std")]
+pub mod priority;
+#[cfg(not(futures_no_atomic_cas))]
+#[cfg(feature = "std")]
+pub mod ring;
+#[cfg(not(futures_no_atomic_cas))]
+#[cfg(feature = "std")]
+pub mod watch;
@@ -0,0 +1,510 @@
+//! A bounded multi-producer, multi-consumer channel where each message goes
+//! to exactly one receiver.
+//!
+//! Channel creation provides a [`Sender`] and a [`Receiver`] handle, both of
+//! which can be cloned. Receivers compete for the messages, which makes this
+//! channel a way to distribute work among several tasks: each message is
+//! taken by a single receiver, and receivers waiting for a message are served
+//! in the order they started waiting.
+//!
+//! The channel holds at most `capacity` messages. A sender that finds it
+//! full waits until a receiver takes a message, like a bounded
+//! [`mpsc`](crate::mpsc) channel.
+//!
+//! # Disconnection
+//!
+//! When all [`Sender`] handles have been dropped, receivers still get the
+//! queued messages, then the end of the stream. When all [`Receiver`]
+//! handles have been dropped, sending fails.
+//!
+//! # Examples
+//!
+//! ```
+//! # futures::executor::block_on(async {
+//! use futures::channel::mpmc;
+//! use futures::stream::StreamExt;
+//!
+//! let (mut tx, rx1) = mpmc::channel(16);
+//! let rx2 = rx1.clone();
+//! for job in 0..4 {
+//!     tx.try_send(job).unwrap();
+//! }
+//! drop(tx);
+//!
+//! // each job is done by exactly one of the workers
+//! let (done1, done2) = futures::join!(rx1.collect::<Vec<_>>(), rx2.collect::<Vec<_>>());
+//! let mut done = [done1, done2].concat();
+//! done.sort_unstable();
+//! assert_eq!(done, vec![0, 1, 2, 3]);
+//! # });
+//! ```
+
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Context, Poll, Waker};
+use std::collections::VecDeque;
+use std::fmt;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+
+/// The error type for [`Sender`s](Sender) used as `Sink`s.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SendError {
+    kind: SendErrorKind,
+}
+
+/// The error type returned from [`try_send`](Sender::try_send).
+#[derive(Clone, PartialEq, Eq)]
+pub struct TrySendError<T> {
+    err: SendError,
+    val: T,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum SendErrorKind {
+    Full,
+    Disconnected,
+}
+
+/// The error type returned from [`try_next`](Receiver::try_next).
+#[derive(Debug)]
+pub struct TryRecvError {
+    _priv: (),
+}
+
+impl fmt::Display for SendError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_full() {
+            write!(f, "send failed because channel is full")
+        } else {
+            write!(f, "send failed because all receivers are gone")
+        }
+    }
+}
+
+impl std::error::Error for SendError {}
+
+impl SendError {
+    /// Returns `true` if this error is a result of the channel being full.
+    pub fn is_full(&self) -> bool {
+        self.kind == SendErrorKind::Full
+    }
+
+    /// Returns `true` if this error is a result of all receivers being
+    /// dropped.
+    pub fn is_disconnected(&self) -> bool {
+        self.kind == SendErrorKind::Disconnected
+    }
+}
+
+impl<T> fmt::Debug for TrySendError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TrySendError").field("kind", &self.err.kind).finish()
+    }
+}
+
+impl<T> fmt::Display for TrySendError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.err.fmt(f)
+    }
+}
+
+impl<T: core::any::Any> std::error::Error for TrySendError<T> {}
+
+impl<T> TrySendError<T> {
+    /// Returns `true` if this error is a result of the channel being full.
+    pub fn is_full(&self) -> bool {
+        self.err.is_full()
+    }
+
+    /// Returns `true` if this error is a result of all receivers being
+    /// dropped.
+    pub fn is_disconnected(&self) -> bool {
+        self.err.is_disconnected()
+    }
+
+    /// Returns the message that was attempted to be sent but failed.
+    pub fn into_inner(self) -> T {
+        self.val
+    }
+
+    /// Drops the message and converts into a `SendError`.
+    pub fn into_send_error(self) -> SendError {
+        self.err
+    }
+}
+
+impl fmt::Display for TryRecvError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "receiver channel is empty")
+    }
+}
+
+impl std::error::Error for TryRecvError {}
+
+struct State<T> {
+    buf: VecDeque<T>,
+    capacity: usize,
+    // Slots promised to senders by `poll_ready` that they have not filled
+    // yet.
+    reserved: usize,
+    senders: usize,
+    receivers: usize,
+    next_id: usize,
+    // Receivers waiting for a message, in the order they started waiting.
+    recv_wakers: VecDeque<(usize, Waker)>,
+    send_wakers: Vec<(usize, Waker)>,
+}
+
+impl<T> State<T> {
+    fn has_room(&self) -> bool {
+        self.buf.len() + self.reserved < self.capacity
+    }
+
+    fn is_closed(&self) -> bool {
+        self.receivers == 0
+    }
+
+    // Wakes the receiver that has been waiting the longest.
+    fn wake_receiver(&mut self) {
+        if let Some((_, waker)) = self.recv_wakers.pop_front() {
+            waker.wake();
+        }
+    }
+
+    fn wake_receivers(&mut self) {
+        for (_, waker) in self.recv_wakers.drain(..) {
+            waker.wake();
+        }
+    }
+
+    fn wake_senders(&mut self) {
+        for (_, waker) in self.send_wakers.drain(..) {
+            waker.wake();
+        }
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        let msg = self.buf.pop_front()?;
+        self.wake_senders();
+        // Pass the remaining messages on to the next waiting receiver, as
+        // the one woken for them may have been this one.
+        if !self.buf.is_empty() {
+            self.wake_receiver();
+        }
+        Some(msg)
+    }
+
+    fn new_id(&mut self) -> usize {
+        let id = self.next_id;
+        self.next_id = self.next_id.wrapping_add(1);
+        id
+    }
+}
+
+/// The transmission end of an mpmc channel.
+///
+/// This value is created by the [`channel`] function.
+pub struct Sender<T> {
+    shared: Arc<Mutex<State<T>>>,
+    id: usize,
+    // `true` if `poll_ready` reserved a slot for the next message
+    reserved: bool,
+}
+
+/// The receiving end of an mpmc channel.
+///
+/// This value is created by the [`channel`] function or by cloning another
+/// receiver.
+pub struct Receiver<T> {
+    shared: Arc<Mutex<State<T>>>,
+    id: usize,
+    terminated: bool,
+}
+
+/// Creates a new mpmc channel holding at most `capacity` messages, returning
+/// the sender and a first receiver.
+///
+/// # Panics
+///
+/// Panics if `capacity` is zero.
+pub fn channel<T>(capacity: usize) -> (Sender<T>, Receiver<T>) {
+    assert!(capacity > 0, "capacity must be greater than zero");
+
+    let state = State {
+        buf: VecDeque::with_capacity(capacity),
+        capacity,
+        reserved: 0,
+        senders: 1,
+        receivers: 1,
+        next_id: 2,
+        recv_wakers: VecDeque::new(),
+        send_wakers: Vec::new(),
+    };
+    let shared = Arc::new(Mutex::new(state));
+    let rx = Receiver { shared: shared.clone(), id: 1, terminated: false };
+    (Sender { shared, id: 0, reserved: false }, rx)
+}
+
+impl<T> Sender<T> {
+    /// Polls the channel to determine if there is room to send a message.
+    ///
+    /// Once this returns `Poll::Ready(Ok(()))`, a slot is set aside for the
+    /// next message of this sender, so the following
+    /// [`start_send`](Sender::start_send) cannot fail because the channel is
+    /// full. Otherwise the current task is notified once a receiver takes a
+    /// message. `Poll::Ready(Err(_))` means that all receivers have been
+    /// dropped.
+    pub fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), SendError>> {
+        let mut state = self.shared.lock().unwrap();
+        if state.is_closed() {
+            return Poll::Ready(Err(SendError { kind: SendErrorKind::Disconnected }));
+        }
+        if self.reserved {
+            return Poll::Ready(Ok(()));
+        }
+        if state.has_room() {
+            state.reserved += 1;
+            self.reserved = true;
+            return Poll::Ready(Ok(()));
+        }
+
+        let id = self.id;
+        match state.send_wakers.iter_mut().find(|(waker_id, _)| *waker_id == id) {
+            Some((_, waker)) => {
+                if !waker.will_wake(cx.waker()) {
+                    *waker = cx.waker().clone();
+                }
+            }
+            None => state.send_wakers.push((id, cx.waker().clone())),
+        }
+        Poll::Pending
+    }
+
+    /// Sends a message on the channel.
+    ///
+    /// This function should only be called after
+    /// [`poll_ready`](Sender::poll_ready) has reported that the channel is
+    /// ready to receive a message.
+    pub fn start_send(&mut self, msg: T) -> Result<(), SendError> {
+        self.try_send(msg).map_err(TrySendError::into_send_error)
+    }
+
+    /// Attempts to send a message on the channel, returning the message if
+    /// there was an error.
+    ///
+    /// This uses the slot reserved by [`poll_ready`](Sender::poll_ready) if
+    /// there is one.
+    pub fn try_send(&mut self, msg: T) -> Result<(), TrySendError<T>> {
+        let mut state = self.shared.lock().unwrap();
+        if state.is_closed() {
+            return Err(TrySendError {
+                err: SendError { kind: SendErrorKind::Disconnected },
+                val: msg,
+            });
+        }
+        if self.reserved {
+            self.reserved = false;
+            state.reserved -= 1;
+        } else if !state.has_room() {
+            return Err(TrySendError { err: SendError { kind: SendErrorKind::Full }, val: msg });
+        }
+
+        state.buf.push_back(msg);
+        state.wake_receiver();
+        Ok(())
+    }
+
+    /// Returns whether all receivers have been dropped.
+    pub fn is_closed(&self) -> bool {
+        self.shared.lock().unwrap().is_closed()
+    }
+
+    /// Returns the number of messages currently queued in the channel.
+    pub fn len(&self) -> usize {
+        self.shared.lock().unwrap().buf.len()
+    }
+
+    /// Returns `true` if there are no messages queued in the channel.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the number of receivers currently connected to the channel.
+    pub fn receiver_count(&self) -> usize {
+        self.shared.lock().unwrap().receivers
+    }
+}
+
+impl<T> Clone for Sender<T> {
+    fn clone(&self) -> Self {
+        let mut state = self.shared.lock().unwrap();
+        state.senders += 1;
+        let id = state.new_id();
+        Self { shared: self.shared.clone(), id, reserved: false }
+    }
+}
+
+impl<T> Drop for Sender<T> {
+    fn drop(&mut self) {
+        let mut state = self.shared.lock().unwrap();
+        let id = self.id;
+        state.send_wakers.retain(|(waker_id, _)| *waker_id != id);
+        if self.reserved {
+            // Hand the unused slot to another sender.
+            state.reserved -= 1;
+            state.wake_senders();
+        }
+        state.senders -= 1;
+        if state.senders == 0 {
+            state.wake_receivers();
+        }
+    }
+}
+
+impl<T> fmt::Debug for Sender<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Sender").field("reserved", &self.reserved).finish()
+    }
+}
+
+impl<T> Receiver<T> {
+    /// Tries to receive the next message without notifying a context if
+    /// empty.
+    ///
+    /// This function returns:
+    /// * `Ok(Some(t))` when a message is fetched
+    /// * `Ok(None)` when all senders are gone and no messages are left
+    /// * `Err(e)` when there are no messages available, but the channel is
+    ///   not yet closed
+    pub fn try_next(&mut self) -> Result<Option<T>, TryRecvError> {
+        if self.terminated {
+            return Ok(None);
+        }
+        let mut state = self.shared.lock().unwrap();
+        if let Some(msg) = state.pop() {
+            return Ok(Some(msg));
+        }
+        if state.senders == 0 {
+            self.terminated = true;
+            return Ok(None);
+        }
+        Err(TryRecvError { _priv: () })
+    }
+
+    /// Returns the number of messages currently queued in the channel.
+    pub fn len(&self) -> usize {
+        self.shared.lock().unwrap().buf.len()
+    }
+
+    /// Returns `true` if there are no messages queued in the channel.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the number of receivers currently connected to the channel.
+    pub fn receiver_count(&self) -> usize {
+        self.shared.lock().unwrap().receivers
+    }
+}
+
+impl<T> Stream for Receiver<T> {
+    type Item = T;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<T>> {
+        let this = &mut *self;
+        if this.terminated {
+            return Poll::Ready(None);
+        }
+
+        let mut state = this.shared.lock().unwrap();
+        let id = this.id;
+        if let Some(msg) = state.pop() {
+            state.recv_wakers.retain(|(waker_id, _)| *waker_id != id);
+            return Poll::Ready(Some(msg));
+        }
+        if state.senders == 0 {
+            this.terminated = true;
+            return Poll::Ready(None);
+        }
+
+        // Keep the place in line of a receiver that is already waiting.
+        match state.recv_wakers.iter_mut().find(|(waker_id, _)| *waker_id == id) {
+            Some((_, waker)) => {
+                if !waker.will_wake(cx.waker()) {
+                    *waker = cx.waker().clone();
+                }
+            }
+            None => state.recv_wakers.push_back((id, cx.waker().clone())),
+        }
+        Poll::Pending
+    }
+}
+
+impl<T> FusedStream for Receiver<T> {
+    fn is_terminated(&self) -> bool {
+        self.terminated
+    }
+}
+
+impl<T> Clone for Receiver<T> {
+    fn clone(&self) -> Self {
+        let mut state = self.shared.lock().unwrap();
+        state.receivers += 1;
+        let id = state.new_id();
+        Self { shared: self.shared.clone(), id, terminated: self.terminated }
+    }
+}
+
+impl<T> Drop for Receiver<T> {
+    fn drop(&mut self) {
+        let msgs = {
+            let mut state = self.shared.lock().unwrap();
+            state.receivers -= 1;
+            let id = self.id;
+            state.recv_wakers.retain(|(waker_id, _)| *waker_id != id);
+            if state.receivers == 0 {
+                state.wake_senders();
+                std::mem::replace(&mut state.buf, VecDeque::new())
+            } else {
+                // This receiver may have been woken for a message it will not
+                // take anymore.
+                if !state.buf.is_empty() {
+                    state.wake_receiver();
+                }
+                VecDeque::new()
+            }
+        };
+        // Drop the messages without holding the lock, in case one of them is
+        // a sender of this channel.
+        drop(msgs);
+    }
+}
+
+impl<T> Unpin for Receiver<T> {}
+
+impl<T> fmt::Debug for Receiver<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Receiver").field("terminated", &self.terminated).finish()
+    }
+}
+
+#[cfg(feature = "sink")]
+impl<T> futures_sink::Sink<T> for Sender<T> {
+    type Error = SendError;
+
+    fn poll_ready(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        (*self).poll_ready(cx)
+    }
+
+    fn start_send(mut self: Pin<&mut Self>, msg: T) -> Result<(), Self::Error> {
+        (*self).start_send(msg)
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_close(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+}
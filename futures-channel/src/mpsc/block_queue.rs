@@ -0,0 +1,213 @@
+//! A multi-producer, single consumer queue that stores its messages in
+//! blocks of `BLOCK_CAP` slots.
+//!
+//! Unlike the node-based [`Queue`](super::queue::Queue), which allocates once
+//! per message, this queue allocates once per block, and consecutive messages
+//! are laid out next to each other in memory. It offers the same interface,
+//! including the `Inconsistent` state of `pop`.
+//!
+//! The layout follows the unbounded `SegQueue` of crossbeam: every position
+//! in the queue has an index, and each block covers `LAP` consecutive
+//! indices, the last of which is not a slot. A producer that moves the tail
+//! onto that index is installing the next block, and the others wait for it.
+
+use super::queue::PopResult::{self, *};
+
+use std::alloc::{self, Layout};
+use std::cell::UnsafeCell;
+use std::fmt;
+use std::mem::MaybeUninit;
+use std::ptr;
+use std::sync::atomic::{AtomicBool, AtomicPtr, AtomicUsize, Ordering};
+use std::thread;
+
+// Number of indices covered by a block.
+const LAP: usize = 32;
+// Number of messages a block holds.
+const BLOCK_CAP: usize = LAP - 1;
+
+struct Slot<T> {
+    value: UnsafeCell<MaybeUninit<T>>,
+    // Set once the producer has written `value`.
+    ready: AtomicBool,
+}
+
+struct Block<T> {
+    slots: [Slot<T>; BLOCK_CAP],
+    next: AtomicPtr<Block<T>>,
+}
+
+impl<T> Block<T> {
+    fn new() -> Box<Self> {
+        // All fields of a block are valid when zeroed, so there is no need to
+        // build the (possibly large) array on the stack first.
+        let layout = Layout::new::<Self>();
+        unsafe {
+            let ptr = alloc::alloc_zeroed(layout);
+            if ptr.is_null() {
+                alloc::handle_alloc_error(layout);
+            }
+            Box::from_raw(ptr as *mut Self)
+        }
+    }
+}
+
+/// The multi-producer single-consumer structure. This is not cloneable, but it
+/// may be safely shared so long as it is guaranteed that there is only one
+/// popper at a time (many pushers are allowed).
+pub(super) struct BlockQueue<T> {
+    // Index and block of the next position to push to.
+    tail_index: AtomicUsize,
+    tail_block: AtomicPtr<Block<T>>,
+    // Index and block of the next position to pop from, only accessed by the
+    // consumer.
+    head: UnsafeCell<(usize, *mut Block<T>)>,
+}
+
+unsafe impl<T: Send> Send for BlockQueue<T> {}
+unsafe impl<T: Send> Sync for BlockQueue<T> {}
+
+impl<T> BlockQueue<T> {
+    /// Creates a new queue that is safe to share among multiple producers and
+    /// one consumer.
+    pub(super) fn new() -> Self {
+        let block = Box::into_raw(Block::new());
+        Self {
+            tail_index: AtomicUsize::new(0),
+            tail_block: AtomicPtr::new(block),
+            head: UnsafeCell::new((0, block)),
+        }
+    }
+
+    /// Pushes a new value onto this queue.
+    pub(super) fn push(&self, t: T) {
+        let mut tail = self.tail_index.load(Ordering::Acquire);
+        let mut block = self.tail_block.load(Ordering::Acquire);
+        let mut next_block = None;
+
+        loop {
+            let offset = tail % LAP;
+
+            // Another producer is installing the next block, wait for it.
+            if offset == BLOCK_CAP {
+                thread::yield_now();
+                tail = self.tail_index.load(Ordering::Acquire);
+                block = self.tail_block.load(Ordering::Acquire);
+                continue;
+            }
+
+            // Allocate the next block before taking the last slot, so that the
+            // other producers are kept waiting as briefly as possible.
+            if offset + 1 == BLOCK_CAP && next_block.is_none() {
+                next_block = Some(Block::new());
+            }
+
+            match self.tail_index.compare_exchange_weak(
+                tail,
+                tail.wrapping_add(1),
+                Ordering::SeqCst,
+                Ordering::Acquire,
+            ) {
+                Ok(_) => unsafe {
+                    if offset + 1 == BLOCK_CAP {
+                        let next_block = Box::into_raw(next_block.unwrap());
+                        self.tail_block.store(next_block, Ordering::Release);
+                        self.tail_index.fetch_add(1, Ordering::Release);
+                        (*block).next.store(next_block, Ordering::Release);
+                    }
+
+                    let slot = (*block).slots.get_unchecked(offset);
+                    slot.value.get().write(MaybeUninit::new(t));
+                    slot.ready.store(true, Ordering::Release);
+                    return;
+                },
+                Err(t) => {
+                    tail = t;
+                    block = self.tail_block.load(Ordering::Acquire);
+                }
+            }
+        }
+    }
+
+    /// Pops some data from this queue.
+    ///
+    /// As with the node-based queue, this returns `Inconsistent` when a
+    /// pusher has claimed the next position but not yet written its value.
+    ///
+    /// This function is unsafe because only one thread can call it at a time.
+    pub(super) unsafe fn pop(&self) -> PopResult<T> {
+        let head = &mut *self.head.get();
+
+        if head.0 % LAP == BLOCK_CAP {
+            // Every slot of the current block has been read. Its last pusher
+            // links the next block before writing its value, so the next block
+            // is always there by now.
+            let next = (*head.1).next.load(Ordering::Acquire);
+            if next.is_null() {
+                return Inconsistent;
+            }
+            drop(Box::from_raw(head.1));
+            *head = (head.0.wrapping_add(1), next);
+        }
+
+        let slot = (*head.1).slots.get_unchecked(head.0 % LAP);
+        if slot.ready.load(Ordering::Acquire) {
+            let t = slot.value.get().read().assume_init();
+            head.0 = head.0.wrapping_add(1);
+            return Data(t);
+        }
+
+        if self.tail_index.load(Ordering::Acquire) == head.0 {
+            Empty
+        } else {
+            Inconsistent
+        }
+    }
+
+    /// Pop an element similarly to `pop` function, but spin-wait on inconsistent
+    /// queue state instead of returning `Inconsistent`.
+    ///
+    /// This function is unsafe because only one thread can call it at a time.
+    pub(super) unsafe fn pop_spin(&self) -> Option<T> {
+        loop {
+            match self.pop() {
+                Empty => return None,
+                Data(t) => return Some(t),
+                // See `Queue::pop_spin`.
+                Inconsistent => {
+                    thread::yield_now();
+                }
+            }
+        }
+    }
+}
+
+impl<T> Drop for BlockQueue<T> {
+    fn drop(&mut self) {
+        unsafe {
+            let (mut index, mut block) = *self.head.get();
+            let tail = *self.tail_index.get_mut();
+
+            // With exclusive access, every claimed position has been written.
+            while index != tail {
+                let offset = index % LAP;
+                if offset == BLOCK_CAP {
+                    let next = (*block).next.load(Ordering::Relaxed);
+                    drop(Box::from_raw(block));
+                    block = next;
+                } else {
+                    let slot = (*block).slots.get_unchecked(offset);
+                    ptr::drop_in_place((*slot.value.get()).as_mut_ptr());
+                }
+                index = index.wrapping_add(1);
+            }
+            drop(Box::from_raw(block));
+        }
+    }
+}
+
+impl<T> fmt::Debug for BlockQueue<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BlockQueue").finish()
+    }
+}
@@ -78,26 +78,36 @@
 // happens-before semantics required for the acquire / release semantics used
 // by the queue structure.
 
+use futures_core::future::Future;
+use futures_core::ready;
 use futures_core::stream::{FusedStream, Stream};
 use futures_core::task::__internal::AtomicWaker;
 use futures_core::task::{Context, Poll, Waker};
 use std::fmt;
 use std::pin::Pin;
-use std::sync::atomic::AtomicUsize;
 use std::sync::atomic::Ordering::SeqCst;
-use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicBool, AtomicUsize};
+use std::sync::{Arc, Mutex, Weak};
 use std::thread;
 
+use crate::mpsc::block_queue::BlockQueue;
 use crate::mpsc::queue::Queue;
 
+mod block_queue;
 mod queue;
 #[cfg(feature = "sink")]
 mod sink_impl;
+#[cfg(feature = "sink")]
+pub use self::sink_impl::SendSink;
 
 #[derive(Debug)]
 struct UnboundedSenderInner<T> {
     // Channel state shared between the sender and receiver.
     inner: Arc<UnboundedInner<T>>,
+
+    // Key of this sender's waker in `close_wakers`, once it has registered
+    // one in `poll_closed`.
+    close_key: Option<usize>,
 }
 
 #[derive(Debug)]
@@ -113,6 +123,10 @@ struct BoundedSenderInner<T> {
     // `true` if the sender might be blocked. This is an optimization to avoid
     // having to lock the mutex most of the time.
     maybe_parked: bool,
+
+    // Key of this sender's waker in `close_wakers`, once it has registered
+    // one in `poll_closed`.
+    close_key: Option<usize>,
 }
 
 // We never project Pin<&mut SenderInner> to `Pin<&mut T>`
@@ -122,15 +136,46 @@ impl<T> Unpin for BoundedSenderInner<T> {}
 /// The transmission end of a bounded mpsc channel.
 ///
 /// This value is created by the [`channel`](channel) function.
+///
+/// Each `Sender` is guaranteed one slot in the channel on top of its buffer,
+/// and tracks on its own whether it is waiting for capacity. Its `Sink`
+/// implementation therefore needs exclusive access, and each task that sends
+/// should use its own clone of the sender.
 #[derive(Debug)]
 pub struct Sender<T>(Option<BoundedSenderInner<T>>);
 
 /// The transmission end of an unbounded mpsc channel.
 ///
 /// This value is created by the [`unbounded`](unbounded) function.
+///
+/// `Sink` is also implemented for `&UnboundedSender`, and
+/// [`sink`](UnboundedSender::sink) gives a sink that leaves the channel open
+/// when closed, so several streams can be forwarded into one sender without
+/// cloning it.
 #[derive(Debug)]
 pub struct UnboundedSender<T>(Option<UnboundedSenderInner<T>>);
 
+/// A sender that does not keep a bounded mpsc channel open.
+///
+/// This value is created by the [`downgrade`](Sender::downgrade) method, and
+/// can be turned back into a [`Sender`] with [`upgrade`](WeakSender::upgrade)
+/// as long as another `Sender` is still alive. Once every `Sender` has been
+/// dropped, the receiver sees the end of the stream even if weak senders
+/// remain.
+#[derive(Debug)]
+pub struct WeakSender<T> {
+    inner: Weak<BoundedInner<T>>,
+}
+
+/// A sender that does not keep an unbounded mpsc channel open.
+///
+/// This value is created by the [`downgrade`](UnboundedSender::downgrade)
+/// method; see [`WeakSender`] for details.
+#[derive(Debug)]
+pub struct WeakUnboundedSender<T> {
+    inner: Weak<UnboundedInner<T>>,
+}
+
 trait AssertKinds: Send + Sync + Clone {}
 impl AssertKinds for UnboundedSender<u32> {}
 
@@ -140,6 +185,9 @@ impl AssertKinds for UnboundedSender<u32> {}
 #[derive(Debug)]
 pub struct Receiver<T> {
     inner: Option<Arc<BoundedInner<T>>>,
+    // A message taken out of the queue by `poll_peek`, which is the next one
+    // to be returned.
+    peeked: Option<T>,
 }
 
 /// The receiving end of an unbounded mpsc channel.
@@ -148,6 +196,9 @@ pub struct Receiver<T> {
 #[derive(Debug)]
 pub struct UnboundedReceiver<T> {
     inner: Option<Arc<UnboundedInner<T>>>,
+    // A message taken out of the queue by `poll_peek`, which is the next one
+    // to be returned.
+    peeked: Option<T>,
 }
 
 // `Pin<&mut UnboundedReceiver<T>>` is never projected to `Pin<&mut T>`
@@ -268,13 +319,20 @@ struct UnboundedInner<T> {
     state: AtomicUsize,
 
     // Atomic, FIFO queue used to send messages to the receiver
-    message_queue: Queue<T>,
+    message_queue: BlockQueue<T>,
 
     // Number of senders in existence
     num_senders: AtomicUsize,
 
     // Handle to the receiver's task.
     recv_task: AtomicWaker,
+
+    // Handles to the sender tasks waiting for the channel to close.
+    close_wakers: Mutex<CloseWakers>,
+
+    // Whether the receiver has paused the channel, with the senders waiting
+    // for it to resume.
+    pause: Pause,
 }
 
 #[derive(Debug)]
@@ -287,7 +345,7 @@ struct BoundedInner<T> {
     state: AtomicUsize,
 
     // Atomic, FIFO queue used to send messages to the receiver
-    message_queue: Queue<T>,
+    message_queue: BlockQueue<T>,
 
     // Atomic, FIFO queue used to send parked task handles to the receiver.
     parked_queue: Queue<Arc<Mutex<SenderTask>>>,
@@ -297,6 +355,13 @@ struct BoundedInner<T> {
 
     // Handle to the receiver's task.
     recv_task: AtomicWaker,
+
+    // Handles to the sender tasks waiting for the channel to close.
+    close_wakers: Mutex<CloseWakers>,
+
+    // Whether the receiver has paused the channel, with the senders waiting
+    // for it to resume.
+    pause: Pause,
 }
 
 // Struct representation of `Inner::state`.
@@ -323,6 +388,115 @@ const MAX_CAPACITY: usize = !(OPEN_MASK);
 // a channel. This is because each sender gets a guaranteed slot.
 const MAX_BUFFER: usize = MAX_CAPACITY >> 1;
 
+// Wakers of the senders waiting in `poll_closed`, keyed so that each sender
+// replaces its own entry instead of adding a new one on every poll.
+#[derive(Debug, Default)]
+struct CloseWakers {
+    next_key: usize,
+    wakers: Vec<(usize, Waker)>,
+}
+
+impl CloseWakers {
+    fn register(&mut self, key: &mut Option<usize>, waker: &Waker) {
+        if let Some(key) = *key {
+            if let Some((_, w)) = self.wakers.iter_mut().find(|(k, _)| *k == key) {
+                if !w.will_wake(waker) {
+                    *w = waker.clone();
+                }
+                return;
+            }
+        }
+
+        let next_key = &mut self.next_key;
+        let key = *key.get_or_insert_with(|| {
+            let key = *next_key;
+            *next_key = next_key.wrapping_add(1);
+            key
+        });
+        self.wakers.push((key, waker.clone()));
+    }
+
+    fn remove(&mut self, key: usize) {
+        self.wakers.retain(|(k, _)| *k != key);
+    }
+
+    fn wake_all(&mut self) {
+        for (_, waker) in self.wakers.drain(..) {
+            waker.wake();
+        }
+    }
+}
+
+fn poll_closed(
+    state: &AtomicUsize,
+    close_wakers: &Mutex<CloseWakers>,
+    key: &mut Option<usize>,
+    cx: &mut Context<'_>,
+) -> Poll<()> {
+    if !decode_state(state.load(SeqCst)).is_open {
+        return Poll::Ready(());
+    }
+
+    close_wakers.lock().unwrap().register(key, cx.waker());
+
+    // Check again after registering, in case the channel was closed (and the
+    // wakers woken) in the meantime.
+    if decode_state(state.load(SeqCst)).is_open {
+        Poll::Pending
+    } else {
+        Poll::Ready(())
+    }
+}
+
+// Set by `Receiver::pause`. Unlike `CloseWakers`, the wakers are not keyed,
+// since `UnboundedSender::poll_ready` only has a shared reference; duplicates
+// are skipped with `will_wake` instead, and the list is cleared on resume.
+#[derive(Debug, Default)]
+struct Pause {
+    paused: AtomicBool,
+    wakers: Mutex<Vec<Waker>>,
+}
+
+impl Pause {
+    fn is_paused(&self) -> bool {
+        self.paused.load(SeqCst)
+    }
+
+    fn poll_resumed(&self, cx: &mut Context<'_>) -> Poll<()> {
+        if !self.is_paused() {
+            return Poll::Ready(());
+        }
+
+        {
+            let mut wakers = self.wakers.lock().unwrap();
+            if !wakers.iter().any(|w| w.will_wake(cx.waker())) {
+                wakers.push(cx.waker().clone());
+            }
+        }
+
+        // Check again after registering, in case the channel was resumed (and
+        // the wakers woken) in the meantime.
+        if self.is_paused() {
+            Poll::Pending
+        } else {
+            Poll::Ready(())
+        }
+    }
+
+    fn set_paused(&self, paused: bool) {
+        self.paused.store(paused, SeqCst);
+        if !paused {
+            self.wake_all();
+        }
+    }
+
+    fn wake_all(&self) {
+        for waker in self.wakers.lock().unwrap().drain(..) {
+            waker.wake();
+        }
+    }
+}
+
 // Sent to the consumer to wake up blocked producers
 #[derive(Debug)]
 struct SenderTask {
@@ -352,6 +526,14 @@ impl SenderTask {
 /// guaranteed slot in the channel capacity, and on top of that there are
 /// `buffer` "first come, first serve" slots available to all senders.
 ///
+/// With a `buffer` of zero, the channel works as a rendezvous channel: a
+/// sender's message waits in its guaranteed slot, and the sender is not ready
+/// again until the receiver has taken it. Since flushing waits for the sender
+/// to be ready, `SinkExt::send` only completes once the message has been
+/// handed off to the receiver. The message itself is accepted by
+/// [`start_send`](Sender::start_send) right away, as the `Sink` contract
+/// requires.
+///
 /// The [`Receiver`](Receiver) returned implements the
 /// [`Stream`](futures_core::stream::Stream) trait, while [`Sender`](Sender) implements
 /// `Sink`.
@@ -363,19 +545,22 @@ pub fn channel<T>(buffer: usize) -> (Sender<T>, Receiver<T>) {
     let inner = Arc::new(BoundedInner {
         buffer,
         state: AtomicUsize::new(INIT_STATE),
-        message_queue: Queue::new(),
+        message_queue: BlockQueue::new(),
         parked_queue: Queue::new(),
         num_senders: AtomicUsize::new(1),
         recv_task: AtomicWaker::new(),
+        close_wakers: Mutex::new(CloseWakers::default()),
+        pause: Pause::default(),
     });
 
     let tx = BoundedSenderInner {
         inner: inner.clone(),
         sender_task: Arc::new(Mutex::new(SenderTask::new())),
         maybe_parked: false,
+        close_key: None,
     };
 
-    let rx = Receiver { inner: Some(inner) };
+    let rx = Receiver { inner: Some(inner), peeked: None };
 
     (Sender(Some(tx)), rx)
 }
@@ -390,17 +575,23 @@ pub fn channel<T>(buffer: usize) -> (Sender<T>, Receiver<T>) {
 /// **Note** that the amount of available system memory is an implicit bound to
 /// the channel. Using an `unbounded` channel has the ability of causing the
 /// process to run out of memory. In this case, the process will be aborted.
+///
+/// Messages are stored in fixed-size blocks, and each block is freed as soon
+/// as the receiver has taken all of its messages, so the channel does not
+/// hold on to the memory of a past burst once it has been drained.
 pub fn unbounded<T>() -> (UnboundedSender<T>, UnboundedReceiver<T>) {
     let inner = Arc::new(UnboundedInner {
         state: AtomicUsize::new(INIT_STATE),
-        message_queue: Queue::new(),
+        message_queue: BlockQueue::new(),
         num_senders: AtomicUsize::new(1),
         recv_task: AtomicWaker::new(),
+        close_wakers: Mutex::new(CloseWakers::default()),
+        pause: Pause::default(),
     });
 
-    let tx = UnboundedSenderInner { inner: inner.clone() };
+    let tx = UnboundedSenderInner { inner: inner.clone(), close_key: None };
 
-    let rx = UnboundedReceiver { inner: Some(inner) };
+    let rx = UnboundedReceiver { inner: Some(inner), peeked: None };
 
     (UnboundedSender(Some(tx)), rx)
 }
@@ -628,6 +819,7 @@ impl<T> BoundedSenderInner<T> {
             return Poll::Ready(Err(SendError { kind: SendErrorKind::Disconnected }));
         }
 
+        ready!(self.inner.pause.poll_resumed(cx));
         self.poll_unparked(Some(cx)).map(Ok)
     }
 
@@ -693,8 +885,17 @@ impl<T> BoundedSenderInner<T> {
 impl<T> Sender<T> {
     /// Attempts to send a message on this `Sender`, returning the message
     /// if there was an error.
+    ///
+    /// While the receiver has [paused](Receiver::pause) the channel, this
+    /// fails as if the channel was full.
     pub fn try_send(&mut self, msg: T) -> Result<(), TrySendError<T>> {
         if let Some(inner) = &mut self.0 {
+            if inner.inner.pause.is_paused() && !inner.is_closed() {
+                return Err(TrySendError {
+                    err: SendError { kind: SendErrorKind::Full },
+                    val: msg,
+                });
+            }
             inner.try_send(msg)
         } else {
             Err(TrySendError { err: SendError { kind: SendErrorKind::Disconnected }, val: msg })
@@ -707,7 +908,23 @@ impl<T> Sender<T> {
     /// [`poll_ready`](Sender::poll_ready) has reported that the channel is
     /// ready to receive a message.
     pub fn start_send(&mut self, msg: T) -> Result<(), SendError> {
-        self.try_send(msg).map_err(|e| e.err)
+        // After `poll_ready`, the message is accepted even if the channel has
+        // been paused since.
+        match &mut self.0 {
+            Some(inner) => inner.try_send(msg).map_err(|e| e.err),
+            None => Err(SendError { kind: SendErrorKind::Disconnected }),
+        }
+    }
+
+    /// Sends a message on the channel, parking the current thread until
+    /// there is capacity for it.
+    ///
+    /// This is meant for synchronous code talking to asynchronous tasks, and
+    /// must not be called from an asynchronous context, where it would block
+    /// the executor.
+    pub fn blocking_send(&mut self, msg: T) -> Result<(), SendError> {
+        crate::blocking::block_on(|cx| self.poll_ready(cx))?;
+        self.start_send(msg)
     }
 
     /// Polls the channel to determine if there is guaranteed capacity to send
@@ -732,6 +949,114 @@ impl<T> Sender<T> {
         self.0.as_ref().map(BoundedSenderInner::is_closed).unwrap_or(true)
     }
 
+    /// Polls the channel to determine if it has been closed, either by the
+    /// receiver being dropped or closed, or with
+    /// [`close_channel`](Self::close_channel).
+    ///
+    /// Once this returns `Poll::Ready(())`, no message can be sent anymore.
+    pub fn poll_closed(&mut self, cx: &mut Context<'_>) -> Poll<()> {
+        match &mut self.0 {
+            Some(inner) => {
+                poll_closed(&inner.inner.state, &inner.inner.close_wakers, &mut inner.close_key, cx)
+            }
+            None => Poll::Ready(()),
+        }
+    }
+
+    /// Waits for the channel to be closed, either by the receiver being
+    /// dropped or closed, or with [`close_channel`](Self::close_channel).
+    ///
+    /// This lets a producer notice that nobody is listening anymore without
+    /// having to try a send.
+    pub fn closed(&mut self) -> Closed<'_, T> {
+        Closed { sender: self }
+    }
+
+    /// Returns the number of messages currently queued in the channel.
+    ///
+    /// Messages still being pushed by a concurrent sender may already be
+    /// counted.
+    pub fn len(&self) -> usize {
+        self.0.as_ref().map_or(0, |inner| inner.inner.len())
+    }
+
+    /// Returns `true` if there are no messages queued in the channel.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the number of messages that can currently be queued before
+    /// senders have to wait.
+    ///
+    /// This is [`max_capacity`](Sender::max_capacity) minus [`len`](Sender::len).
+    pub fn capacity(&self) -> usize {
+        self.max_capacity().saturating_sub(self.len())
+    }
+
+    /// Returns the maximum number of messages the channel can hold.
+    ///
+    /// This is the buffer size the channel was created with, plus the one
+    /// guaranteed slot of every sender currently alive, and is `0` once the
+    /// channel has been disconnected from this end.
+    pub fn max_capacity(&self) -> usize {
+        self.0.as_ref().map_or(0, |inner| inner.inner.max_capacity())
+    }
+
+    /// Waits for capacity to send one message, and returns a [`Permit`] to
+    /// send it with.
+    ///
+    /// Since the slot is guaranteed before the message exists, the message
+    /// only has to be built once the send is sure to go through, and
+    /// dropping the returned future (for example in a `select!`) never loses
+    /// a message.
+    ///
+    /// The future fails if the receiver has been dropped.
+    pub fn reserve(&mut self) -> Reserve<'_, T> {
+        Reserve { sender: Some(self) }
+    }
+
+    /// Sends a message, waiting for capacity until `delay` completes.
+    ///
+    /// The future resolves once the message has been sent, or with an error
+    /// that hands the message back: one for which
+    /// [`is_full`](TrySendError::is_full) returns `true` if `delay`
+    /// completed first, or one for which
+    /// [`is_disconnected`](TrySendError::is_disconnected) returns `true` if
+    /// the receiver is gone. This lets a producer shed load after a bounded
+    /// wait without racing a separate timeout against `send`, which could
+    /// lose the message.
+    ///
+    /// `delay` is any future that completes at the deadline, such as a timer
+    /// of the runtime in use. It has to be `Unpin`; pin it with `Box::pin`
+    /// otherwise.
+    ///
+    /// Unlike `SinkExt::send_timeout`, which fails with a `SendTimeoutError`
+    /// and also waits for the message to be flushed, this only waits for
+    /// capacity, and fails with a [`TrySendError`].
+    pub fn send_timeout_with<D>(&mut self, msg: T, delay: D) -> SendTimeout<'_, T, D>
+    where
+        D: Future + Unpin,
+    {
+        SendTimeout { sender: self, msg: Some(msg), delay }
+    }
+
+    /// Attempts to get a [`Permit`] to send one message, without waiting.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the channel is full or if the receiver has been
+    /// dropped.
+    pub fn try_reserve(&mut self) -> Result<Permit<'_, T>, SendError> {
+        let inner = self.0.as_mut().ok_or(SendError { kind: SendErrorKind::Disconnected })?;
+        if inner.is_closed() {
+            return Err(SendError { kind: SendErrorKind::Disconnected });
+        }
+        if inner.inner.pause.is_paused() || inner.poll_unparked(None).is_pending() {
+            return Err(SendError { kind: SendErrorKind::Full });
+        }
+        Ok(Permit { sender: self })
+    }
+
     /// Closes this channel from the sender side, preventing any new messages.
     pub fn close_channel(&mut self) {
         if let Some(inner) = &mut self.0 {
@@ -770,13 +1095,24 @@ impl<T> Sender<T> {
         let ptr = self.0.as_ref().map(|inner| inner.ptr());
         ptr.hash(hasher);
     }
+
+    /// Creates a [`WeakSender`] for this channel, which does not count as a
+    /// sender for keeping the channel open.
+    pub fn downgrade(&self) -> WeakSender<T> {
+        let inner = self.0.as_ref().map(|inner| Arc::downgrade(&inner.inner));
+        WeakSender { inner: inner.unwrap_or_default() }
+    }
 }
 
 impl<T> UnboundedSender<T> {
     /// Check if the channel is ready to receive a message.
-    pub fn poll_ready(&self, _: &mut Context<'_>) -> Poll<Result<(), SendError>> {
+    ///
+    /// This is only pending while the receiver has
+    /// [paused](UnboundedReceiver::pause) the channel.
+    pub fn poll_ready(&self, cx: &mut Context<'_>) -> Poll<Result<(), SendError>> {
         let inner = self.0.as_ref().ok_or(SendError { kind: SendErrorKind::Disconnected })?;
-        inner.poll_ready_nb()
+        ready!(inner.poll_ready_nb())?;
+        inner.inner.pause.poll_resumed(cx).map(Ok)
     }
 
     /// Returns whether this channel is closed without needing a context.
@@ -784,6 +1120,42 @@ impl<T> UnboundedSender<T> {
         self.0.as_ref().map(UnboundedSenderInner::is_closed).unwrap_or(true)
     }
 
+    /// Polls the channel to determine if it has been closed, either by the
+    /// receiver being dropped or closed, or with
+    /// [`close_channel`](Self::close_channel).
+    ///
+    /// Once this returns `Poll::Ready(())`, no message can be sent anymore.
+    pub fn poll_closed(&mut self, cx: &mut Context<'_>) -> Poll<()> {
+        match &mut self.0 {
+            Some(inner) => {
+                poll_closed(&inner.inner.state, &inner.inner.close_wakers, &mut inner.close_key, cx)
+            }
+            None => Poll::Ready(()),
+        }
+    }
+
+    /// Waits for the channel to be closed, either by the receiver being
+    /// dropped or closed, or with [`close_channel`](Self::close_channel).
+    ///
+    /// This lets a producer notice that nobody is listening anymore without
+    /// having to try a send.
+    pub fn closed(&mut self) -> UnboundedClosed<'_, T> {
+        UnboundedClosed { sender: self }
+    }
+
+    /// Returns the number of messages currently queued in the channel.
+    ///
+    /// Messages still being pushed by a concurrent sender may already be
+    /// counted.
+    pub fn len(&self) -> usize {
+        self.0.as_ref().map_or(0, |inner| inner.inner.len())
+    }
+
+    /// Returns `true` if there are no messages queued in the channel.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
     /// Closes this channel from the sender side, preventing any new messages.
     pub fn close_channel(&self) {
         if let Some(inner) = &self.0 {
@@ -816,12 +1188,35 @@ impl<T> UnboundedSender<T> {
         self.do_send_nb(msg).map_err(|e| e.err)
     }
 
+    /// Returns a sink that sends messages along this channel through a shared
+    /// reference.
+    ///
+    /// Unlike the `Sink` implementation of `&UnboundedSender`, closing the
+    /// returned sink does not close the channel. This lets several streams be
+    /// forwarded into the same sender, each finishing on its own schedule.
+    #[cfg(feature = "sink")]
+    pub fn sink(&self) -> SendSink<'_, T> {
+        SendSink { sender: self }
+    }
+
     /// Sends a message along this channel.
     ///
     /// This is an unbounded sender, so this function differs from `Sink::send`
     /// by ensuring the return type reflects that the channel is always ready to
     /// receive messages.
+    ///
+    /// While the receiver has [paused](UnboundedReceiver::pause) the channel,
+    /// this fails with an error for which
+    /// [`is_full`](TrySendError::is_full) returns `true`.
     pub fn unbounded_send(&self, msg: T) -> Result<(), TrySendError<T>> {
+        if let Some(inner) = &self.0 {
+            if inner.inner.pause.is_paused() && !inner.is_closed() {
+                return Err(TrySendError {
+                    err: SendError { kind: SendErrorKind::Full },
+                    val: msg,
+                });
+            }
+        }
         self.do_send_nb(msg)
     }
 
@@ -851,6 +1246,13 @@ impl<T> UnboundedSender<T> {
         let ptr = self.0.as_ref().map(|inner| inner.ptr());
         ptr.hash(hasher);
     }
+
+    /// Creates a [`WeakUnboundedSender`] for this channel, which does not count as a
+    /// sender for keeping the channel open.
+    pub fn downgrade(&self) -> WeakUnboundedSender<T> {
+        let inner = self.0.as_ref().map(|inner| Arc::downgrade(&inner.inner));
+        WeakUnboundedSender { inner: inner.unwrap_or_default() }
+    }
 }
 
 impl<T> Clone for Sender<T> {
@@ -885,7 +1287,7 @@ impl<T> Clone for UnboundedSenderInner<T> {
                 Ok(_) => {
                     // The ABA problem doesn't matter here. We only care that the
                     // number of senders never exceeds the maximum.
-                    return Self { inner: self.inner.clone() };
+                    return Self { inner: self.inner.clone(), close_key: None };
                 }
                 Err(actual) => curr = actual,
             }
@@ -917,6 +1319,7 @@ impl<T> Clone for BoundedSenderInner<T> {
                         inner: self.inner.clone(),
                         sender_task: Arc::new(Mutex::new(SenderTask::new())),
                         maybe_parked: false,
+                        close_key: None,
                     };
                 }
                 Err(actual) => curr = actual,
@@ -927,6 +1330,10 @@ impl<T> Clone for BoundedSenderInner<T> {
 
 impl<T> Drop for UnboundedSenderInner<T> {
     fn drop(&mut self) {
+        if let Some(key) = self.close_key {
+            self.inner.close_wakers.lock().unwrap().remove(key);
+        }
+
         // Ordering between variables don't matter here
         let prev = self.inner.num_senders.fetch_sub(1, SeqCst);
 
@@ -938,6 +1345,10 @@ impl<T> Drop for UnboundedSenderInner<T> {
 
 impl<T> Drop for BoundedSenderInner<T> {
     fn drop(&mut self) {
+        if let Some(key) = self.close_key {
+            self.inner.close_wakers.lock().unwrap().remove(key);
+        }
+
         // Ordering between variables don't matter here
         let prev = self.inner.num_senders.fetch_sub(1, SeqCst);
 
@@ -947,6 +1358,209 @@ impl<T> Drop for BoundedSenderInner<T> {
     }
 }
 
+/// A guaranteed slot to send one message on a bounded channel.
+///
+/// This value is created by the [`reserve`](Sender::reserve) and
+/// [`try_reserve`](Sender::try_reserve) methods. Dropping it without sending
+/// gives the slot back.
+#[must_use = "a permit does nothing unless used to send a message"]
+pub struct Permit<'a, T> {
+    sender: &'a mut Sender<T>,
+}
+
+impl<T> Permit<'_, T> {
+    /// Sends a message using the reserved slot.
+    ///
+    /// This cannot fail because of capacity. If the receiver has been dropped
+    /// in the meantime, the message is dropped, just like messages still
+    /// queued when the receiver goes away.
+    pub fn send(self, msg: T) {
+        let _ = self.sender.start_send(msg);
+    }
+}
+
+impl<T> fmt::Debug for Permit<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Permit").finish()
+    }
+}
+
+/// Future for the [`reserve`](Sender::reserve) method.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct Reserve<'a, T> {
+    sender: Option<&'a mut Sender<T>>,
+}
+
+impl<'a, T> Future for Reserve<'a, T> {
+    type Output = Result<Permit<'a, T>, SendError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let sender = self.sender.as_mut().expect("polled Reserve after completion");
+        if let Err(e) = ready!(sender.poll_ready(cx)) {
+            return Poll::Ready(Err(e));
+        }
+        Poll::Ready(Ok(Permit { sender: self.sender.take().unwrap() }))
+    }
+}
+
+impl<T> fmt::Debug for Reserve<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Reserve").finish()
+    }
+}
+
+/// Future for the [`send_timeout_with`](Sender::send_timeout_with) method.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct SendTimeout<'a, T, D> {
+    sender: &'a mut Sender<T>,
+    msg: Option<T>,
+    delay: D,
+}
+
+impl<T, D: Unpin> Unpin for SendTimeout<'_, T, D> {}
+
+impl<T, D: Future + Unpin> Future for SendTimeout<'_, T, D> {
+    type Output = Result<(), TrySendError<T>>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = &mut *self;
+        let msg = this.msg.take().expect("polled SendTimeout after completion");
+        match this.sender.poll_ready(cx) {
+            // Ready means that the sender is connected
+            Poll::Ready(Ok(())) => Poll::Ready(this.sender.0.as_mut().unwrap().try_send(msg)),
+            Poll::Ready(Err(err)) => Poll::Ready(Err(TrySendError { err, val: msg })),
+            Poll::Pending => match Pin::new(&mut this.delay).poll(cx) {
+                Poll::Ready(_) => Poll::Ready(Err(TrySendError {
+                    err: SendError { kind: SendErrorKind::Full },
+                    val: msg,
+                })),
+                Poll::Pending => {
+                    this.msg = Some(msg);
+                    Poll::Pending
+                }
+            },
+        }
+    }
+}
+
+impl<T, D> fmt::Debug for SendTimeout<'_, T, D> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SendTimeout").finish()
+    }
+}
+
+/// Future for the [`closed`](Sender::closed) method.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct Closed<'a, T> {
+    sender: &'a mut Sender<T>,
+}
+
+impl<T> Future for Closed<'_, T> {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        self.sender.poll_closed(cx)
+    }
+}
+
+impl<T> fmt::Debug for Closed<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Closed").finish()
+    }
+}
+
+/// Future for the [`closed`](UnboundedSender::closed) method.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct UnboundedClosed<'a, T> {
+    sender: &'a mut UnboundedSender<T>,
+}
+
+impl<T> Future for UnboundedClosed<'_, T> {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        self.sender.poll_closed(cx)
+    }
+}
+
+impl<T> fmt::Debug for UnboundedClosed<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UnboundedClosed").finish()
+    }
+}
+
+/*
+ *
+ * ===== impl WeakSender =====
+ *
+ */
+
+// Adds a sender to the channel, unless the last one is already gone.
+fn inc_num_senders(num_senders: &AtomicUsize, max_senders: usize) -> bool {
+    let mut curr = num_senders.load(SeqCst);
+
+    loop {
+        // The channel was closed when the last sender was dropped, so it
+        // cannot be reopened.
+        if curr == 0 {
+            return false;
+        }
+        if curr == max_senders {
+            panic!("cannot clone `Sender` -- too many outstanding senders");
+        }
+
+        match num_senders.compare_exchange(curr, curr + 1, SeqCst, SeqCst) {
+            Ok(_) => return true,
+            Err(actual) => curr = actual,
+        }
+    }
+}
+
+impl<T> WeakSender<T> {
+    /// Attempts to get a [`Sender`] for the channel back.
+    ///
+    /// Returns `None` if every `Sender` has been dropped, or if the receiver
+    /// has been dropped and the channel freed.
+    pub fn upgrade(&self) -> Option<Sender<T>> {
+        let inner = self.inner.upgrade()?;
+        if !inc_num_senders(&inner.num_senders, inner.max_senders()) {
+            return None;
+        }
+        Some(Sender(Some(BoundedSenderInner {
+            inner,
+            sender_task: Arc::new(Mutex::new(SenderTask::new())),
+            maybe_parked: false,
+            close_key: None,
+        })))
+    }
+}
+
+impl<T> Clone for WeakSender<T> {
+    fn clone(&self) -> Self {
+        Self { inner: self.inner.clone() }
+    }
+}
+
+impl<T> WeakUnboundedSender<T> {
+    /// Attempts to get an [`UnboundedSender`] for the channel back.
+    ///
+    /// Returns `None` if every `UnboundedSender` has been dropped, or if the
+    /// receiver has been dropped and the channel freed.
+    pub fn upgrade(&self) -> Option<UnboundedSender<T>> {
+        let inner = self.inner.upgrade()?;
+        if !inc_num_senders(&inner.num_senders, MAX_BUFFER) {
+            return None;
+        }
+        Some(UnboundedSender(Some(UnboundedSenderInner { inner, close_key: None })))
+    }
+}
+
+impl<T> Clone for WeakUnboundedSender<T> {
+    fn clone(&self) -> Self {
+        Self { inner: self.inner.clone() }
+    }
+}
+
 /*
  *
  * ===== impl Receiver =====
@@ -958,6 +1572,9 @@ impl<T> Receiver<T> {
     ///
     /// This prevents any further messages from being sent on the channel while
     /// still enabling the receiver to drain messages that are buffered.
+    /// Messages that senders had already handed to the channel stay queued,
+    /// and [`drain`](Self::drain) returns them synchronously, for example to
+    /// persist them during shutdown.
     pub fn close(&mut self) {
         if let Some(inner) = &mut self.inner {
             inner.set_closed();
@@ -987,7 +1604,165 @@ impl<T> Receiver<T> {
         }
     }
 
+    /// Receives the next message, parking the current thread until one is
+    /// available.
+    ///
+    /// Returns `None` once the channel is closed and empty. This is meant for
+    /// synchronous code talking to asynchronous tasks, and must not be called
+    /// from an asynchronous context, where it would block the executor.
+    pub fn blocking_recv(&mut self) -> Option<T> {
+        crate::blocking::block_on(|cx| Pin::new(&mut *self).poll_next(cx))
+    }
+
+    /// Pauses the channel, making senders wait as if it was full.
+    ///
+    /// `poll_ready` stays pending and `try_send` fails with a full error
+    /// until the channel is [resumed](Self::resume). The messages already
+    /// queued can still be received. This lets a consumer hold off producers
+    /// for a while, for example while its downstream reconnects, without
+    /// closing the channel.
+    pub fn pause(&mut self) {
+        if let Some(inner) = &self.inner {
+            inner.pause.set_paused(true);
+        }
+    }
+
+    /// Resumes a channel paused with [`pause`](Self::pause), waking the
+    /// senders waiting for it.
+    pub fn resume(&mut self) {
+        if let Some(inner) = &self.inner {
+            inner.pause.set_paused(false);
+        }
+    }
+
+    /// Returns whether the channel is currently paused.
+    pub fn is_paused(&self) -> bool {
+        self.inner.as_ref().map_or(false, |inner| inner.pause.is_paused())
+    }
+
+    /// Closes the channel and returns an iterator over the messages that are
+    /// still queued.
+    ///
+    /// This lets shutdown code process the backlog synchronously instead of
+    /// polling the receiver as a stream. Since no further messages can be
+    /// sent once the channel is closed, the iterator ends after the last
+    /// queued message, and the receiver is then terminated.
+    pub fn drain(&mut self) -> Drain<'_, T> {
+        self.close();
+        Drain { receiver: self }
+    }
+
+    /// Polls for a batch of messages, moving up to `limit` of them into
+    /// `buf`, and returns how many were received.
+    ///
+    /// Returns `Poll::Pending` only if no message is available yet. Once at
+    /// least one message has been received, this returns whatever is queued
+    /// (up to `limit`) instead of waiting for more. `Poll::Ready(0)` means
+    /// that the channel is closed and empty, or that `limit` is zero.
+    pub fn poll_recv_many(
+        &mut self,
+        cx: &mut Context<'_>,
+        buf: &mut Vec<T>,
+        limit: usize,
+    ) -> Poll<usize> {
+        let mut received = 0;
+        let mut registered = false;
+        while received < limit {
+            match self.next_message() {
+                Poll::Ready(Some(msg)) => {
+                    buf.push(msg);
+                    received += 1;
+                }
+                Poll::Ready(None) => break,
+                Poll::Pending if received > 0 => break,
+                Poll::Pending if !registered => {
+                    // Same as in `poll_next`, check the queue again after
+                    // registering in case a message raced with the first check.
+                    self.inner.as_ref().unwrap().recv_task.register(cx.waker());
+                    registered = true;
+                }
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+        Poll::Ready(received)
+    }
+
+    /// Receives a batch of messages, moving up to `limit` of them into `buf`.
+    ///
+    /// The returned future resolves to the number of messages received as
+    /// soon as at least one is available; see
+    /// [`poll_recv_many`](Receiver::poll_recv_many) for details. A result of `0`
+    /// means that the channel is closed and empty, or that `limit` is zero.
+    pub fn recv_many<'a>(&'a mut self, buf: &'a mut Vec<T>, limit: usize) -> RecvMany<'a, T> {
+        RecvMany { receiver: self, buf, limit }
+    }
+
+    /// Returns the number of messages currently queued in the channel.
+    ///
+    /// Messages still being pushed by a concurrent sender may already be
+    /// counted.
+    ///
+    /// This includes a message held back by [`poll_peek`](Self::poll_peek).
+    pub fn len(&self) -> usize {
+        self.inner.as_ref().map_or(0, |inner| inner.len()) + self.peeked.is_some() as usize
+    }
+
+    /// Returns `true` if there are no messages queued in the channel.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the number of messages that can currently be queued before
+    /// senders have to wait.
+    ///
+    /// This is [`max_capacity`](Receiver::max_capacity) minus the number of
+    /// messages still in the channel; a peeked message no longer takes up a
+    /// slot.
+    pub fn capacity(&self) -> usize {
+        let len = self.inner.as_ref().map_or(0, |inner| inner.len());
+        self.max_capacity().saturating_sub(len)
+    }
+
+    /// Returns the maximum number of messages the channel can hold.
+    ///
+    /// This is the buffer size the channel was created with, plus the one
+    /// guaranteed slot of every sender currently alive, and is `0` once the
+    /// channel has been disconnected from this end.
+    pub fn max_capacity(&self) -> usize {
+        self.inner.as_ref().map_or(0, |inner| inner.max_capacity())
+    }
+
+    /// Polls for the next message without removing it from the channel, and
+    /// returns a reference to it.
+    ///
+    /// The message is returned again by the next call to `poll_next`,
+    /// [`try_next`](Self::try_next) or [`poll_recv_many`](Self::poll_recv_many).
+    /// `Poll::Ready(None)` means that the channel is closed and empty.
+    pub fn poll_peek(&mut self, cx: &mut Context<'_>) -> Poll<Option<&T>> {
+        ready!(self.poll_fill_peeked(cx));
+        Poll::Ready(self.peeked.as_ref())
+    }
+
+    /// Waits for the next message without removing it from the channel, and
+    /// returns a reference to it.
+    ///
+    /// See [`poll_peek`](Self::poll_peek) for details.
+    pub fn peek(&mut self) -> Peek<'_, T> {
+        Peek { receiver: Some(self) }
+    }
+
+    // Makes sure `peeked` holds the next message, unless the channel is done.
+    fn poll_fill_peeked(&mut self, cx: &mut Context<'_>) -> Poll<()> {
+        if self.peeked.is_none() {
+            self.peeked = ready!(Pin::new(&mut *self).poll_next(cx));
+        }
+        Poll::Ready(())
+    }
+
     fn next_message(&mut self) -> Poll<Option<T>> {
+        if let Some(msg) = self.peeked.take() {
+            return Poll::Ready(Some(msg));
+        }
         let inner = match self.inner.as_mut() {
             None => return Poll::Ready(None),
             Some(inner) => inner,
@@ -1049,7 +1824,7 @@ impl<T> Unpin for Receiver<T> {}
 
 impl<T> FusedStream for Receiver<T> {
     fn is_terminated(&self) -> bool {
-        self.inner.is_none()
+        self.inner.is_none() && self.peeked.is_none()
     }
 }
 
@@ -1112,6 +1887,9 @@ impl<T> UnboundedReceiver<T> {
     ///
     /// This prevents any further messages from being sent on the channel while
     /// still enabling the receiver to drain messages that are buffered.
+    /// Messages that senders had already handed to the channel stay queued,
+    /// and [`drain`](Self::drain) returns them synchronously, for example to
+    /// persist them during shutdown.
     pub fn close(&mut self) {
         if let Some(inner) = &mut self.inner {
             inner.set_closed();
@@ -1135,7 +1913,149 @@ impl<T> UnboundedReceiver<T> {
         }
     }
 
+    /// Receives the next message, parking the current thread until one is
+    /// available.
+    ///
+    /// Returns `None` once the channel is closed and empty. This is meant for
+    /// synchronous code talking to asynchronous tasks, and must not be called
+    /// from an asynchronous context, where it would block the executor.
+    pub fn blocking_recv(&mut self) -> Option<T> {
+        crate::blocking::block_on(|cx| Pin::new(&mut *self).poll_next(cx))
+    }
+
+    /// Pauses the channel, making senders wait as if it was full.
+    ///
+    /// `poll_ready` stays pending and `unbounded_send` fails with a full
+    /// error until the channel is [resumed](Self::resume). The messages
+    /// already queued can still be received. This lets a consumer hold off
+    /// producers for a while, for example while its downstream reconnects,
+    /// without closing the channel.
+    pub fn pause(&mut self) {
+        if let Some(inner) = &self.inner {
+            inner.pause.set_paused(true);
+        }
+    }
+
+    /// Resumes a channel paused with [`pause`](Self::pause), waking the
+    /// senders waiting for it.
+    pub fn resume(&mut self) {
+        if let Some(inner) = &self.inner {
+            inner.pause.set_paused(false);
+        }
+    }
+
+    /// Returns whether the channel is currently paused.
+    pub fn is_paused(&self) -> bool {
+        self.inner.as_ref().map_or(false, |inner| inner.pause.is_paused())
+    }
+
+    /// Closes the channel and returns an iterator over the messages that are
+    /// still queued.
+    ///
+    /// This lets shutdown code process the backlog synchronously instead of
+    /// polling the receiver as a stream. Since no further messages can be
+    /// sent once the channel is closed, the iterator ends after the last
+    /// queued message, and the receiver is then terminated.
+    pub fn drain(&mut self) -> UnboundedDrain<'_, T> {
+        self.close();
+        UnboundedDrain { receiver: self }
+    }
+
+    /// Polls for a batch of messages, moving up to `limit` of them into
+    /// `buf`, and returns how many were received.
+    ///
+    /// Returns `Poll::Pending` only if no message is available yet. Once at
+    /// least one message has been received, this returns whatever is queued
+    /// (up to `limit`) instead of waiting for more. `Poll::Ready(0)` means
+    /// that the channel is closed and empty, or that `limit` is zero.
+    pub fn poll_recv_many(
+        &mut self,
+        cx: &mut Context<'_>,
+        buf: &mut Vec<T>,
+        limit: usize,
+    ) -> Poll<usize> {
+        let mut received = 0;
+        let mut registered = false;
+        while received < limit {
+            match self.next_message() {
+                Poll::Ready(Some(msg)) => {
+                    buf.push(msg);
+                    received += 1;
+                }
+                Poll::Ready(None) => break,
+                Poll::Pending if received > 0 => break,
+                Poll::Pending if !registered => {
+                    // Same as in `poll_next`, check the queue again after
+                    // registering in case a message raced with the first check.
+                    self.inner.as_ref().unwrap().recv_task.register(cx.waker());
+                    registered = true;
+                }
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+        Poll::Ready(received)
+    }
+
+    /// Receives a batch of messages, moving up to `limit` of them into `buf`.
+    ///
+    /// The returned future resolves to the number of messages received as
+    /// soon as at least one is available; see
+    /// [`poll_recv_many`](UnboundedReceiver::poll_recv_many) for details. A result of `0`
+    /// means that the channel is closed and empty, or that `limit` is zero.
+    pub fn recv_many<'a>(
+        &'a mut self,
+        buf: &'a mut Vec<T>,
+        limit: usize,
+    ) -> UnboundedRecvMany<'a, T> {
+        UnboundedRecvMany { receiver: self, buf, limit }
+    }
+
+    /// Returns the number of messages currently queued in the channel.
+    ///
+    /// Messages still being pushed by a concurrent sender may already be
+    /// counted.
+    ///
+    /// This includes a message held back by [`poll_peek`](Self::poll_peek).
+    pub fn len(&self) -> usize {
+        self.inner.as_ref().map_or(0, |inner| inner.len()) + self.peeked.is_some() as usize
+    }
+
+    /// Returns `true` if there are no messages queued in the channel.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Polls for the next message without removing it from the channel, and
+    /// returns a reference to it.
+    ///
+    /// The message is returned again by the next call to `poll_next`,
+    /// [`try_next`](Self::try_next) or [`poll_recv_many`](Self::poll_recv_many).
+    /// `Poll::Ready(None)` means that the channel is closed and empty.
+    pub fn poll_peek(&mut self, cx: &mut Context<'_>) -> Poll<Option<&T>> {
+        ready!(self.poll_fill_peeked(cx));
+        Poll::Ready(self.peeked.as_ref())
+    }
+
+    /// Waits for the next message without removing it from the channel, and
+    /// returns a reference to it.
+    ///
+    /// See [`poll_peek`](Self::poll_peek) for details.
+    pub fn peek(&mut self) -> UnboundedPeek<'_, T> {
+        UnboundedPeek { receiver: Some(self) }
+    }
+
+    // Makes sure `peeked` holds the next message, unless the channel is done.
+    fn poll_fill_peeked(&mut self, cx: &mut Context<'_>) -> Poll<()> {
+        if self.peeked.is_none() {
+            self.peeked = ready!(Pin::new(&mut *self).poll_next(cx));
+        }
+        Poll::Ready(())
+    }
+
     fn next_message(&mut self) -> Poll<Option<T>> {
+        if let Some(msg) = self.peeked.take() {
+            return Poll::Ready(Some(msg));
+        }
         let inner = match self.inner.as_mut() {
             None => return Poll::Ready(None),
             Some(inner) => inner,
@@ -1181,7 +2101,7 @@ impl<T> UnboundedReceiver<T> {
 
 impl<T> FusedStream for UnboundedReceiver<T> {
     fn is_terminated(&self) -> bool {
-        self.inner.is_none()
+        self.inner.is_none() && self.peeked.is_none()
     }
 }
 
@@ -1239,6 +2159,150 @@ impl<T> Drop for UnboundedReceiver<T> {
     }
 }
 
+/// Future for the [`peek`](Receiver::peek) method.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct Peek<'a, T> {
+    receiver: Option<&'a mut Receiver<T>>,
+}
+
+impl<'a, T> Future for Peek<'a, T> {
+    type Output = Option<&'a T>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let receiver = self.receiver.as_mut().expect("polled Peek after completion");
+        ready!(receiver.poll_fill_peeked(cx));
+        let receiver = self.receiver.take().unwrap();
+        Poll::Ready(receiver.peeked.as_ref())
+    }
+}
+
+impl<T> fmt::Debug for Peek<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Peek").finish()
+    }
+}
+
+/// Future for the [`peek`](UnboundedReceiver::peek) method.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct UnboundedPeek<'a, T> {
+    receiver: Option<&'a mut UnboundedReceiver<T>>,
+}
+
+impl<'a, T> Future for UnboundedPeek<'a, T> {
+    type Output = Option<&'a T>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let receiver = self.receiver.as_mut().expect("polled UnboundedPeek after completion");
+        ready!(receiver.poll_fill_peeked(cx));
+        let receiver = self.receiver.take().unwrap();
+        Poll::Ready(receiver.peeked.as_ref())
+    }
+}
+
+impl<T> fmt::Debug for UnboundedPeek<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UnboundedPeek").finish()
+    }
+}
+
+/// Future for the [`recv_many`](Receiver::recv_many) method.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct RecvMany<'a, T> {
+    receiver: &'a mut Receiver<T>,
+    buf: &'a mut Vec<T>,
+    limit: usize,
+}
+
+impl<T> Future for RecvMany<'_, T> {
+    type Output = usize;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<usize> {
+        let this = &mut *self;
+        this.receiver.poll_recv_many(cx, this.buf, this.limit)
+    }
+}
+
+impl<T> fmt::Debug for RecvMany<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RecvMany").field("limit", &self.limit).finish()
+    }
+}
+
+/// Future for the [`recv_many`](UnboundedReceiver::recv_many) method.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct UnboundedRecvMany<'a, T> {
+    receiver: &'a mut UnboundedReceiver<T>,
+    buf: &'a mut Vec<T>,
+    limit: usize,
+}
+
+impl<T> Future for UnboundedRecvMany<'_, T> {
+    type Output = usize;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<usize> {
+        let this = &mut *self;
+        this.receiver.poll_recv_many(cx, this.buf, this.limit)
+    }
+}
+
+impl<T> fmt::Debug for UnboundedRecvMany<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UnboundedRecvMany").field("limit", &self.limit).finish()
+    }
+}
+
+/// Iterator for the [`drain`](Receiver::drain) method.
+pub struct Drain<'a, T> {
+    receiver: &'a mut Receiver<T>,
+}
+
+impl<T> Iterator for Drain<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        // The channel is closed, so `try_next` only fails while a sender is
+        // still in the middle of pushing its message.
+        loop {
+            if let Ok(msg) = self.receiver.try_next() {
+                return msg;
+            }
+            thread::yield_now();
+        }
+    }
+}
+
+impl<T> fmt::Debug for Drain<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Drain").finish()
+    }
+}
+
+/// Iterator for the [`drain`](UnboundedReceiver::drain) method.
+pub struct UnboundedDrain<'a, T> {
+    receiver: &'a mut UnboundedReceiver<T>,
+}
+
+impl<T> Iterator for UnboundedDrain<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        // The channel is closed, so `try_next` only fails while a sender is
+        // still in the middle of pushing its message.
+        loop {
+            if let Ok(msg) = self.receiver.try_next() {
+                return msg;
+            }
+            thread::yield_now();
+        }
+    }
+}
+
+impl<T> fmt::Debug for UnboundedDrain<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UnboundedDrain").finish()
+    }
+}
+
 /*
  *
  * ===== impl Inner =====
@@ -1246,6 +2310,10 @@ impl<T> Drop for UnboundedReceiver<T> {
  */
 
 impl<T> UnboundedInner<T> {
+    fn len(&self) -> usize {
+        decode_state(self.state.load(SeqCst)).num_messages
+    }
+
     // Clear `open` flag in the state, keep `num_messages` intact.
     fn set_closed(&self) {
         let curr = self.state.load(SeqCst);
@@ -1254,10 +2322,22 @@ impl<T> UnboundedInner<T> {
         }
 
         self.state.fetch_and(!OPEN_MASK, SeqCst);
+        self.close_wakers.lock().unwrap().wake_all();
+        // Paused senders find out that the channel is closed when polled.
+        self.pause.wake_all();
     }
 }
 
 impl<T> BoundedInner<T> {
+    fn len(&self) -> usize {
+        decode_state(self.state.load(SeqCst)).num_messages
+    }
+
+    // The buffer size plus the one guaranteed slot per sender.
+    fn max_capacity(&self) -> usize {
+        self.buffer + self.num_senders.load(SeqCst)
+    }
+
     // The return value is such that the total number of messages that can be
     // enqueued into the channel will never exceed MAX_CAPACITY
     fn max_senders(&self) -> usize {
@@ -1272,6 +2352,9 @@ impl<T> BoundedInner<T> {
         }
 
         self.state.fetch_and(!OPEN_MASK, SeqCst);
+        self.close_wakers.lock().unwrap().wake_all();
+        // Paused senders find out that the channel is closed when polled.
+        self.pause.wake_all();
     }
 }
 
@@ -1,6 +1,7 @@
 use super::{SendError, Sender, TrySendError, UnboundedSender};
 use futures_core::task::{Context, Poll};
 use futures_sink::Sink;
+use std::fmt;
 use std::pin::Pin;
 
 impl<T> Sink<T> for Sender<T> {
@@ -59,7 +60,7 @@ impl<T> Sink<T> for &UnboundedSender<T> {
     }
 
     fn start_send(self: Pin<&mut Self>, msg: T) -> Result<(), Self::Error> {
-        self.unbounded_send(msg).map_err(TrySendError::into_send_error)
+        self.do_send_nb(msg).map_err(TrySendError::into_send_error)
     }
 
     fn poll_flush(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
@@ -71,3 +72,36 @@ impl<T> Sink<T> for &UnboundedSender<T> {
         Poll::Ready(Ok(()))
     }
 }
+
+/// Sink for the [`sink`](UnboundedSender::sink) method.
+#[must_use = "sinks do nothing unless polled"]
+pub struct SendSink<'a, T> {
+    pub(super) sender: &'a UnboundedSender<T>,
+}
+
+impl<T> Sink<T> for SendSink<'_, T> {
+    type Error = SendError;
+
+    fn poll_ready(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.sender.poll_ready(cx)
+    }
+
+    fn start_send(self: Pin<&mut Self>, msg: T) -> Result<(), Self::Error> {
+        self.sender.do_send_nb(msg).map_err(TrySendError::into_send_error)
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_close(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        // The channel stays open for the sender and any other sinks borrowing it.
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl<T> fmt::Debug for SendSink<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SendSink").finish()
+    }
+}
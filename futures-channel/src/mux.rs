@@ -0,0 +1,444 @@
+//! An unbounded multi-producer channel whose messages are routed by key to
+//! separate receivers.
+//!
+//! Channel creation provides a [`Sender`] and a [`Router`] handle. Each
+//! message is sent with a key, and the [`Router`] hands out a virtual
+//! [`Receiver`] per key on demand, which then sees only the messages for that
+//! key, in the order they were sent. Messages for keys that have no receiver
+//! are yielded by the [`Router`] itself, which is how new keys show up in the
+//! first place. This is the shape of most multiplexed protocols, where many
+//! logical streams share one connection.
+//!
+//! # Disconnection
+//!
+//! When all [`Sender`] handles have been dropped, the router and every
+//! receiver still get their queued messages, then the end of the stream. A
+//! single key can be ended from the sending side with
+//! [`close_key`](Sender::close_key), or from the receiving side by
+//! [closing](Receiver::close) its receiver. Once a receiver is dropped, its key
+//! is free again and new messages for it go to the router.
+//!
+//! # Examples
+//!
+//! ```
+//! # futures::executor::block_on(async {
+//! use futures::channel::mux;
+//! use futures::stream::StreamExt;
+//!
+//! let (tx, mut router) = mux::channel();
+//! tx.send("a", 1).unwrap();
+//! tx.send("b", 2).unwrap();
+//! tx.send("a", 3).unwrap();
+//!
+//! // The first message of a new key arrives at the router.
+//! assert_eq!(router.next().await, Some(("a", 1)));
+//! let mut a = router.receiver("a").unwrap();
+//! assert_eq!(a.next().await, Some(3));
+//!
+//! tx.close_key(&"a");
+//! assert_eq!(a.next().await, None);
+//! assert_eq!(router.next().await, Some(("b", 2)));
+//! # });
+//! ```
+
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Context, Poll, Waker};
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+use std::hash::Hash;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+
+/// The error type returned from [`send`](Sender::send) when the message can
+/// not be delivered, which hands the key and message back.
+///
+/// This happens when the key has been closed, or when it has no receiver and
+/// the router is gone.
+#[derive(Clone, PartialEq, Eq)]
+pub struct SendError<T>(pub T);
+
+impl<T> fmt::Debug for SendError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("SendError").finish()
+    }
+}
+
+impl<T> fmt::Display for SendError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "send failed because the key is closed or has no receiver")
+    }
+}
+
+impl<T> std::error::Error for SendError<T> {}
+
+/// The error type returned from `try_next` on the [`Router`] and on a
+/// [`Receiver`].
+#[derive(Debug)]
+pub struct TryRecvError {
+    _priv: (),
+}
+
+impl fmt::Display for TryRecvError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "receiver channel is empty")
+    }
+}
+
+impl std::error::Error for TryRecvError {}
+
+struct KeyQueue<T> {
+    buf: VecDeque<T>,
+    // `true` once the key has been closed from either side
+    closed: bool,
+    waker: Option<Waker>,
+}
+
+struct State<K, T> {
+    keys: HashMap<K, KeyQueue<T>>,
+    // Messages for keys that have no receiver
+    unclaimed: VecDeque<(K, T)>,
+    router_open: bool,
+    router_waker: Option<Waker>,
+    senders: usize,
+}
+
+impl<K, T> State<K, T> {
+    fn wake_all(&mut self) {
+        if let Some(waker) = self.router_waker.take() {
+            waker.wake();
+        }
+        for queue in self.keys.values_mut() {
+            if let Some(waker) = queue.waker.take() {
+                waker.wake();
+            }
+        }
+    }
+}
+
+/// The transmission end of a mux channel.
+///
+/// This value is created by the [`channel`] function.
+pub struct Sender<K, T> {
+    shared: Arc<Mutex<State<K, T>>>,
+}
+
+/// The receiving end of a mux channel, which yields the messages of keys that
+/// have no [`Receiver`].
+///
+/// This value is created by the [`channel`] function.
+pub struct Router<K, T> {
+    shared: Arc<Mutex<State<K, T>>>,
+    terminated: bool,
+}
+
+/// The receiving end for a single key of a mux channel.
+///
+/// This value is created by the [`receiver`](Router::receiver) method.
+pub struct Receiver<K: Eq + Hash, T> {
+    shared: Arc<Mutex<State<K, T>>>,
+    key: K,
+    terminated: bool,
+}
+
+/// Creates a new mux channel, returning the sender and router halves.
+pub fn channel<K: Eq + Hash, T>() -> (Sender<K, T>, Router<K, T>) {
+    let state = State {
+        keys: HashMap::new(),
+        unclaimed: VecDeque::new(),
+        router_open: true,
+        router_waker: None,
+        senders: 1,
+    };
+    let shared = Arc::new(Mutex::new(state));
+    let router = Router { shared: shared.clone(), terminated: false };
+    (Sender { shared }, router)
+}
+
+impl<K: Eq + Hash, T> Sender<K, T> {
+    /// Sends a message for `key`.
+    ///
+    /// The message goes to the receiver of `key` if there is one, and to the
+    /// router otherwise. This never waits.
+    ///
+    /// # Errors
+    ///
+    /// Returns the key and message back if the key has been closed, or if it
+    /// has no receiver and the router has been dropped.
+    pub fn send(&self, key: K, msg: T) -> Result<(), SendError<(K, T)>> {
+        let mut state = self.shared.lock().unwrap();
+        if let Some(queue) = state.keys.get_mut(&key) {
+            if queue.closed {
+                return Err(SendError((key, msg)));
+            }
+            queue.buf.push_back(msg);
+            if let Some(waker) = queue.waker.take() {
+                waker.wake();
+            }
+            return Ok(());
+        }
+        if !state.router_open {
+            return Err(SendError((key, msg)));
+        }
+        state.unclaimed.push_back((key, msg));
+        if let Some(waker) = state.router_waker.take() {
+            waker.wake();
+        }
+        Ok(())
+    }
+
+    /// Ends the stream of `key`.
+    ///
+    /// Its receiver still gets the messages already queued, then the end of
+    /// the stream, and further messages for `key` are rejected until that
+    /// receiver is dropped. This does nothing if `key` has no receiver.
+    pub fn close_key(&self, key: &K) {
+        let mut state = self.shared.lock().unwrap();
+        if let Some(queue) = state.keys.get_mut(key) {
+            queue.closed = true;
+            if let Some(waker) = queue.waker.take() {
+                waker.wake();
+            }
+        }
+    }
+
+    /// Returns whether `key` currently has a receiver.
+    pub fn has_receiver(&self, key: &K) -> bool {
+        self.shared.lock().unwrap().keys.contains_key(key)
+    }
+}
+
+impl<K, T> Clone for Sender<K, T> {
+    fn clone(&self) -> Self {
+        self.shared.lock().unwrap().senders += 1;
+        Self { shared: self.shared.clone() }
+    }
+}
+
+impl<K, T> Drop for Sender<K, T> {
+    fn drop(&mut self) {
+        let mut state = self.shared.lock().unwrap();
+        state.senders -= 1;
+        if state.senders == 0 {
+            state.wake_all();
+        }
+    }
+}
+
+impl<K, T> fmt::Debug for Sender<K, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Sender").finish()
+    }
+}
+
+impl<K: Eq + Hash, T> Router<K, T> {
+    /// Creates the receiver for `key`, or returns `None` if `key` already has
+    /// one.
+    ///
+    /// Messages for `key` that are still queued in the router are moved to
+    /// the new receiver, so it sees every message of its key in order.
+    pub fn receiver(&self, key: K) -> Option<Receiver<K, T>>
+    where
+        K: Clone,
+    {
+        let mut state = self.shared.lock().unwrap();
+        if state.keys.contains_key(&key) {
+            return None;
+        }
+
+        let mut buf = VecDeque::new();
+        let mut unclaimed = VecDeque::with_capacity(state.unclaimed.len());
+        for (k, msg) in state.unclaimed.drain(..) {
+            if k == key {
+                buf.push_back(msg);
+            } else {
+                unclaimed.push_back((k, msg));
+            }
+        }
+        state.unclaimed = unclaimed;
+        state.keys.insert(key.clone(), KeyQueue { buf, closed: false, waker: None });
+
+        Some(Receiver { shared: self.shared.clone(), key, terminated: false })
+    }
+
+    /// Tries to receive the next message for a key without a receiver,
+    /// without notifying a context if empty.
+    ///
+    /// This function returns:
+    /// * `Ok(Some(t))` when a message is fetched
+    /// * `Ok(None)` when all senders are gone and no messages are left
+    /// * `Err(e)` when there are no messages available, but the channel is
+    ///   not yet closed
+    pub fn try_next(&mut self) -> Result<Option<(K, T)>, TryRecvError> {
+        if self.terminated {
+            return Ok(None);
+        }
+        let mut state = self.shared.lock().unwrap();
+        if let Some(item) = state.unclaimed.pop_front() {
+            return Ok(Some(item));
+        }
+        if state.senders == 0 {
+            self.terminated = true;
+            return Ok(None);
+        }
+        Err(TryRecvError { _priv: () })
+    }
+}
+
+impl<K: Eq + Hash, T> Stream for Router<K, T> {
+    type Item = (K, T);
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<(K, T)>> {
+        if self.terminated {
+            return Poll::Ready(None);
+        }
+
+        let mut state = self.shared.lock().unwrap();
+        if let Some(item) = state.unclaimed.pop_front() {
+            return Poll::Ready(Some(item));
+        }
+        if state.senders == 0 {
+            drop(state);
+            self.terminated = true;
+            return Poll::Ready(None);
+        }
+        state.router_waker = Some(cx.waker().clone());
+        Poll::Pending
+    }
+}
+
+impl<K: Eq + Hash, T> FusedStream for Router<K, T> {
+    fn is_terminated(&self) -> bool {
+        self.terminated
+    }
+}
+
+impl<K, T> Drop for Router<K, T> {
+    fn drop(&mut self) {
+        let msgs = {
+            let mut state = self.shared.lock().unwrap();
+            state.router_open = false;
+            std::mem::replace(&mut state.unclaimed, VecDeque::new())
+        };
+        // Drop the messages without holding the lock, in case one of them is
+        // a sender of this channel.
+        drop(msgs);
+    }
+}
+
+impl<K, T> Unpin for Router<K, T> {}
+
+impl<K, T> fmt::Debug for Router<K, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Router").field("terminated", &self.terminated).finish()
+    }
+}
+
+impl<K: Eq + Hash, T> Receiver<K, T> {
+    /// Returns the key of this receiver.
+    pub fn key(&self) -> &K {
+        &self.key
+    }
+
+    /// Closes this key without dropping the receiver.
+    ///
+    /// This rejects any further messages for the key while still enabling
+    /// the receiver to drain messages that are queued.
+    pub fn close(&mut self) {
+        let mut state = self.shared.lock().unwrap();
+        if let Some(queue) = state.keys.get_mut(&self.key) {
+            queue.closed = true;
+        }
+    }
+
+    /// Tries to receive the next message for this key without notifying a
+    /// context if empty.
+    ///
+    /// This function returns:
+    /// * `Ok(Some(t))` when a message is fetched
+    /// * `Ok(None)` when the key is closed and no messages are left
+    /// * `Err(e)` when there are no messages available, but the key is not
+    ///   yet closed
+    pub fn try_next(&mut self) -> Result<Option<T>, TryRecvError> {
+        match self.next_message(None) {
+            Poll::Ready(msg) => Ok(msg),
+            Poll::Pending => Err(TryRecvError { _priv: () }),
+        }
+    }
+
+    fn next_message(&mut self, cx: Option<&mut Context<'_>>) -> Poll<Option<T>> {
+        if self.terminated {
+            return Poll::Ready(None);
+        }
+
+        let mut state = self.shared.lock().unwrap();
+        let senders = state.senders;
+        let queue = state.keys.get_mut(&self.key).unwrap();
+        if let Some(msg) = queue.buf.pop_front() {
+            return Poll::Ready(Some(msg));
+        }
+        if queue.closed || senders == 0 {
+            drop(state);
+            self.terminated = true;
+            return Poll::Ready(None);
+        }
+        if let Some(cx) = cx {
+            queue.waker = Some(cx.waker().clone());
+        }
+        Poll::Pending
+    }
+}
+
+impl<K: Eq + Hash, T> Stream for Receiver<K, T> {
+    type Item = T;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<T>> {
+        self.next_message(Some(cx))
+    }
+}
+
+impl<K: Eq + Hash, T> FusedStream for Receiver<K, T> {
+    fn is_terminated(&self) -> bool {
+        self.terminated
+    }
+}
+
+impl<K: Eq + Hash, T> Drop for Receiver<K, T> {
+    fn drop(&mut self) {
+        let queue = self.shared.lock().unwrap().keys.remove(&self.key);
+        // Drop the messages without holding the lock, in case one of them is
+        // a sender of this channel.
+        drop(queue);
+    }
+}
+
+impl<K: Eq + Hash, T> Unpin for Receiver<K, T> {}
+
+impl<K: Eq + Hash + fmt::Debug, T> fmt::Debug for Receiver<K, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Receiver")
+            .field("key", &self.key)
+            .field("terminated", &self.terminated)
+            .finish()
+    }
+}
+
+#[cfg(feature = "sink")]
+impl<K: Eq + Hash, T> futures_sink::Sink<(K, T)> for Sender<K, T> {
+    type Error = SendError<(K, T)>;
+
+    fn poll_ready(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn start_send(self: Pin<&mut Self>, (key, msg): (K, T)) -> Result<(), Self::Error> {
+        self.send(key, msg)
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_close(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+}
@@ -2,11 +2,11 @@
 //!
 //! This is a single-producer, single-consumer channel.
 
-use alloc::sync::Arc;
+use crate::sync::atomic::AtomicBool;
+use crate::sync::atomic::Ordering::SeqCst;
+use crate::sync::Arc;
 use core::fmt;
 use core::pin::Pin;
-use core::sync::atomic::AtomicBool;
-use core::sync::atomic::Ordering::SeqCst;
 use futures_core::future::{FusedFuture, Future};
 use futures_core::task::{Context, Poll, Waker};
 
@@ -63,8 +63,18 @@ struct Inner<T> {
     rx_task: Lock<Option<Waker>>,
 
     /// Like `rx_task` above, except for the task blocked in
-    /// `Sender::poll_canceled`. Additionally, `Lock` cannot be `UnsafeCell`.
+    /// `Sender::poll_canceled` or in `SendAndWait::poll`. Additionally, `Lock`
+    /// cannot be `UnsafeCell`.
     tx_task: Lock<Option<Waker>>,
+
+    /// Set by the `Receiver` once it has taken the value out of `data`, for
+    /// the `SendAndWait` future.
+    delivered: AtomicBool,
+
+    /// Set by `Receiver::drop`, which lets `SendAndWait` tell a receiver that
+    /// went away from one that just has not taken the value yet. Unlike
+    /// `complete`, this is not also set by the `Sender`.
+    rx_dropped: AtomicBool,
 }
 
 /// Creates a new one-shot channel for sending a single value across asynchronous tasks.
@@ -115,6 +125,8 @@ impl<T> Inner<T> {
             data: Lock::new(None),
             rx_task: Lock::new(None),
             tx_task: Lock::new(None),
+            delivered: AtomicBool::new(false),
+            rx_dropped: AtomicBool::new(false),
         }
     }
 
@@ -242,10 +254,8 @@ impl<T> Inner<T> {
         // If we're complete, either `::close_rx` or `::drop_tx` was called.
         // We can assume a successful send if data is present.
         if self.complete.load(SeqCst) {
-            if let Some(mut slot) = self.data.try_lock() {
-                if let Some(data) = slot.take() {
-                    return Ok(Some(data));
-                }
+            if let Some(data) = self.take_data() {
+                return Ok(Some(data));
             }
             Err(Canceled)
         } else {
@@ -286,10 +296,8 @@ impl<T> Inner<T> {
             // If taking the lock fails, the sender will realise that the we're
             // `done` when it checks the `complete` flag on the way out, and
             // will treat the send as a failure.
-            if let Some(mut slot) = self.data.try_lock() {
-                if let Some(data) = slot.take() {
-                    return Poll::Ready(Ok(data));
-                }
+            if let Some(data) = self.take_data() {
+                return Poll::Ready(Ok(data));
             }
             Poll::Ready(Err(Canceled))
         } else {
@@ -297,9 +305,62 @@ impl<T> Inner<T> {
         }
     }
 
+    // Takes the value for the receiver, and lets a `SendAndWait` future know
+    // that it has been delivered.
+    fn take_data(&self) -> Option<T> {
+        let data = self.data.try_lock()?.take()?;
+        self.delivered.store(true, SeqCst);
+
+        // As in `drop_rx`, failing to get the lock means that the sender is
+        // registering its task, and it checks `delivered` again afterwards.
+        if let Some(mut handle) = self.tx_task.try_lock() {
+            if let Some(task) = handle.take() {
+                drop(handle);
+                task.wake()
+            }
+        }
+        Some(data)
+    }
+
+    // Checks whether a value sent by `SendAndWait` has been taken by the
+    // receiver, or handed back because the receiver is gone.
+    fn delivery(&self) -> Option<Result<(), T>> {
+        if self.delivered.load(SeqCst) {
+            return Some(Ok(()));
+        }
+        if self.rx_dropped.load(SeqCst) {
+            // Nobody else touches `data` once the receiver is gone.
+            let data = self.data.try_lock().and_then(|mut slot| slot.take());
+            return Some(data.map_or(Ok(()), Err));
+        }
+        None
+    }
+
+    fn poll_delivered(&self, cx: &mut Context<'_>) -> Poll<Result<(), T>> {
+        if let Some(res) = self.delivery() {
+            return Poll::Ready(res);
+        }
+
+        // Same as in `poll_canceled`, except that failing to get the lock
+        // means that the receiver is trying to wake a previous task, so the
+        // outcome is not known yet and we try again.
+        match self.tx_task.try_lock() {
+            Some(mut p) => *p = Some(cx.waker().clone()),
+            None => {
+                cx.waker().wake_by_ref();
+                return Poll::Pending;
+            }
+        }
+        match self.delivery() {
+            Some(res) => Poll::Ready(res),
+            None => Poll::Pending,
+        }
+    }
+
     fn drop_rx(&self) {
         // Indicate to the `Sender` that we're done, so any future calls to
         // `poll_canceled` are weeded out.
+        self.rx_dropped.store(true, SeqCst);
         self.complete.store(true, SeqCst);
 
         // If we've blocked a task then there's no need for it to stick around,
@@ -342,6 +403,22 @@ impl<T> Sender<T> {
         self.inner.send(t)
     }
 
+    /// Completes this oneshot with a successful result, and waits for the
+    /// [`Receiver`](Receiver) to take the value.
+    ///
+    /// The returned future resolves to `Ok(())` once the receiver has
+    /// received the value. If the receiver is dropped without receiving
+    /// it, or was already dropped or closed when this function was called,
+    /// the future resolves to `Err(t)` instead.
+    pub fn send_and_wait(self, t: T) -> SendAndWait<T> {
+        let inner = self.inner.clone();
+        let unsent = inner.send(t).err();
+        // Dropping the sender is how the receiver learns that the value is
+        // ready.
+        drop(self);
+        SendAndWait { inner: Some(inner), unsent }
+    }
+
     /// Polls this `Sender` half to detect whether its associated
     /// [`Receiver`](Receiver) has been dropped.
     ///
@@ -413,6 +490,43 @@ impl<T> Future for Cancellation<'_, T> {
     }
 }
 
+/// Future for the [`send_and_wait`](Sender::send_and_wait) method.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct SendAndWait<T> {
+    inner: Option<Arc<Inner<T>>>,
+    // The value, if the receiver was gone before it could be sent
+    unsent: Option<T>,
+}
+
+impl<T> Unpin for SendAndWait<T> {}
+
+impl<T> Future for SendAndWait<T> {
+    type Output = Result<(), T>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), T>> {
+        if let Some(t) = self.unsent.take() {
+            self.inner = None;
+            return Poll::Ready(Err(t));
+        }
+        let inner = self.inner.as_ref().expect("polled SendAndWait after completion");
+        let res = futures_core::ready!(inner.poll_delivered(cx));
+        self.inner = None;
+        Poll::Ready(res)
+    }
+}
+
+impl<T> FusedFuture for SendAndWait<T> {
+    fn is_terminated(&self) -> bool {
+        self.inner.is_none()
+    }
+}
+
+impl<T> fmt::Debug for SendAndWait<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SendAndWait").finish()
+    }
+}
+
 /// Error returned from a [`Receiver`](Receiver) when the corresponding
 /// [`Sender`](Sender) is dropped.
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
@@ -450,6 +564,17 @@ impl<T> Receiver<T> {
     pub fn try_recv(&mut self) -> Result<Option<T>, Canceled> {
         self.inner.try_recv()
     }
+
+    /// Receives the value, parking the current thread until it has been sent.
+    ///
+    /// Returns an error if the sender was dropped without sending a value.
+    /// This is meant for synchronous code talking to asynchronous tasks, and
+    /// must not be called from an asynchronous context, where it would block
+    /// the executor.
+    #[cfg(feature = "std")]
+    pub fn blocking_recv(self) -> Result<T, Canceled> {
+        crate::blocking::block_on(|cx| self.inner.recv(cx))
+    }
 }
 
 impl<T> Future for Receiver<T> {
@@ -0,0 +1,492 @@
+//! A bounded multi-producer, single-consumer channel where the receiver
+//! always gets the highest-priority message first.
+//!
+//! Channel creation provides a [`Sender`] and a [`Receiver`] handle. Every
+//! message is sent along with a priority of some [`Ord`] type, and the
+//! receiver always takes the greatest pending priority next. Messages with
+//! equal priorities are received in the order they were sent, so a busy
+//! priority level cannot reorder its own messages, and lower levels are only
+//! served once the higher ones are empty.
+//!
+//! The channel holds at most `capacity` messages. A sender that finds it
+//! full waits until the receiver takes a message, like a bounded
+//! [`mpsc`](crate::mpsc) channel.
+//!
+//! # Disconnection
+//!
+//! When all [`Sender`] handles have been dropped, the receiver still gets
+//! the queued messages, then the end of the stream. When the [`Receiver`] is
+//! dropped or [closed](Receiver::close), sending fails.
+//!
+//! # Examples
+//!
+//! ```
+//! # futures::executor::block_on(async {
+//! use futures::channel::priority;
+//! use futures::stream::StreamExt;
+//!
+//! let (mut tx, rx) = priority::channel(16);
+//! tx.try_send(0, "data 1").unwrap();
+//! tx.try_send(0, "data 2").unwrap();
+//! tx.try_send(1, "control").unwrap();
+//! drop(tx);
+//!
+//! assert_eq!(rx.collect::<Vec<_>>().await, vec!["control", "data 1", "data 2"]);
+//! # });
+//! ```
+
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Context, Poll, Waker};
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use std::fmt;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+
+/// The error type for [`Sender`s](Sender) used as `Sink`s.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SendError {
+    kind: SendErrorKind,
+}
+
+/// The error type returned from [`try_send`](Sender::try_send).
+#[derive(Clone, PartialEq, Eq)]
+pub struct TrySendError<T> {
+    err: SendError,
+    val: T,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum SendErrorKind {
+    Full,
+    Disconnected,
+}
+
+/// The error type returned from [`try_next`](Receiver::try_next).
+#[derive(Debug)]
+pub struct TryRecvError {
+    _priv: (),
+}
+
+impl fmt::Display for SendError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_full() {
+            write!(f, "send failed because channel is full")
+        } else {
+            write!(f, "send failed because receiver is gone")
+        }
+    }
+}
+
+impl std::error::Error for SendError {}
+
+impl SendError {
+    /// Returns `true` if this error is a result of the channel being full.
+    pub fn is_full(&self) -> bool {
+        self.kind == SendErrorKind::Full
+    }
+
+    /// Returns `true` if this error is a result of the receiver being dropped
+    /// or closed.
+    pub fn is_disconnected(&self) -> bool {
+        self.kind == SendErrorKind::Disconnected
+    }
+}
+
+impl<T> fmt::Debug for TrySendError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TrySendError").field("kind", &self.err.kind).finish()
+    }
+}
+
+impl<T> fmt::Display for TrySendError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.err.fmt(f)
+    }
+}
+
+impl<T: core::any::Any> std::error::Error for TrySendError<T> {}
+
+impl<T> TrySendError<T> {
+    /// Returns `true` if this error is a result of the channel being full.
+    pub fn is_full(&self) -> bool {
+        self.err.is_full()
+    }
+
+    /// Returns `true` if this error is a result of the receiver being dropped
+    /// or closed.
+    pub fn is_disconnected(&self) -> bool {
+        self.err.is_disconnected()
+    }
+
+    /// Returns the message that was attempted to be sent but failed.
+    pub fn into_inner(self) -> T {
+        self.val
+    }
+
+    /// Drops the message and converts into a `SendError`.
+    pub fn into_send_error(self) -> SendError {
+        self.err
+    }
+}
+
+impl fmt::Display for TryRecvError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "receiver channel is empty")
+    }
+}
+
+impl std::error::Error for TryRecvError {}
+
+struct Entry<P, T> {
+    priority: P,
+    // Order of the message among the ones sent, which keeps equal priorities
+    // first-in first-out.
+    seq: u64,
+    msg: T,
+}
+
+impl<P: Ord, T> Ord for Entry<P, T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // `BinaryHeap` is a max-heap, so the earlier message has to compare
+        // greater.
+        self.priority.cmp(&other.priority).then_with(|| other.seq.cmp(&self.seq))
+    }
+}
+
+impl<P: Ord, T> PartialOrd for Entry<P, T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<P: Ord, T> PartialEq for Entry<P, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<P: Ord, T> Eq for Entry<P, T> {}
+
+struct State<P, T> {
+    heap: BinaryHeap<Entry<P, T>>,
+    capacity: usize,
+    // Slots promised to senders by `poll_ready` that they have not filled
+    // yet.
+    reserved: usize,
+    next_seq: u64,
+    // `false` once the receiver has been closed or dropped
+    open: bool,
+    senders: usize,
+    next_id: usize,
+    recv_waker: Option<Waker>,
+    send_wakers: Vec<(usize, Waker)>,
+}
+
+impl<P, T> State<P, T> {
+    fn has_room(&self) -> bool {
+        self.heap.len() + self.reserved < self.capacity
+    }
+
+    fn wake_receiver(&mut self) {
+        if let Some(waker) = self.recv_waker.take() {
+            waker.wake();
+        }
+    }
+
+    fn wake_senders(&mut self) {
+        for (_, waker) in self.send_wakers.drain(..) {
+            waker.wake();
+        }
+    }
+
+    fn new_id(&mut self) -> usize {
+        let id = self.next_id;
+        self.next_id = self.next_id.wrapping_add(1);
+        id
+    }
+}
+
+impl<P: Ord, T> State<P, T> {
+    fn pop(&mut self) -> Option<T> {
+        let entry = self.heap.pop()?;
+        self.wake_senders();
+        Some(entry.msg)
+    }
+}
+
+/// The transmission end of a priority channel.
+///
+/// This value is created by the [`channel`] function.
+pub struct Sender<P, T> {
+    shared: Arc<Mutex<State<P, T>>>,
+    id: usize,
+    // `true` if `poll_ready` reserved a slot for the next message
+    reserved: bool,
+}
+
+/// The receiving end of a priority channel.
+///
+/// This value is created by the [`channel`] function.
+pub struct Receiver<P, T> {
+    shared: Arc<Mutex<State<P, T>>>,
+    terminated: bool,
+}
+
+/// Creates a new priority channel holding at most `capacity` messages,
+/// returning the sender and receiver halves.
+///
+/// # Panics
+///
+/// Panics if `capacity` is zero.
+pub fn channel<P: Ord, T>(capacity: usize) -> (Sender<P, T>, Receiver<P, T>) {
+    assert!(capacity > 0, "capacity must be greater than zero");
+
+    let state = State {
+        heap: BinaryHeap::with_capacity(capacity),
+        capacity,
+        reserved: 0,
+        next_seq: 0,
+        open: true,
+        senders: 1,
+        next_id: 1,
+        recv_waker: None,
+        send_wakers: Vec::new(),
+    };
+    let shared = Arc::new(Mutex::new(state));
+    let rx = Receiver { shared: shared.clone(), terminated: false };
+    (Sender { shared, id: 0, reserved: false }, rx)
+}
+
+impl<P: Ord, T> Sender<P, T> {
+    /// Polls the channel to determine if there is room to send a message.
+    ///
+    /// Once this returns `Poll::Ready(Ok(()))`, a slot is set aside for the
+    /// next message of this sender, so the following
+    /// [`start_send`](Sender::start_send) cannot fail because the channel is
+    /// full. Otherwise the current task is notified once the receiver takes a
+    /// message. `Poll::Ready(Err(_))` means that the receiver has been
+    /// dropped or closed.
+    pub fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), SendError>> {
+        let mut state = self.shared.lock().unwrap();
+        if !state.open {
+            return Poll::Ready(Err(SendError { kind: SendErrorKind::Disconnected }));
+        }
+        if self.reserved {
+            return Poll::Ready(Ok(()));
+        }
+        if state.has_room() {
+            state.reserved += 1;
+            self.reserved = true;
+            return Poll::Ready(Ok(()));
+        }
+
+        let id = self.id;
+        match state.send_wakers.iter_mut().find(|(waker_id, _)| *waker_id == id) {
+            Some((_, waker)) => {
+                if !waker.will_wake(cx.waker()) {
+                    *waker = cx.waker().clone();
+                }
+            }
+            None => state.send_wakers.push((id, cx.waker().clone())),
+        }
+        Poll::Pending
+    }
+
+    /// Sends a message with the given priority.
+    ///
+    /// This function should only be called after
+    /// [`poll_ready`](Sender::poll_ready) has reported that the channel is
+    /// ready to receive a message.
+    pub fn start_send(&mut self, priority: P, msg: T) -> Result<(), SendError> {
+        self.try_send(priority, msg).map_err(TrySendError::into_send_error)
+    }
+
+    /// Attempts to send a message with the given priority, returning the
+    /// message if there was an error.
+    ///
+    /// This uses the slot reserved by [`poll_ready`](Sender::poll_ready) if
+    /// there is one.
+    pub fn try_send(&mut self, priority: P, msg: T) -> Result<(), TrySendError<T>> {
+        let mut state = self.shared.lock().unwrap();
+        if !state.open {
+            return Err(TrySendError {
+                err: SendError { kind: SendErrorKind::Disconnected },
+                val: msg,
+            });
+        }
+        if self.reserved {
+            self.reserved = false;
+            state.reserved -= 1;
+        } else if !state.has_room() {
+            return Err(TrySendError { err: SendError { kind: SendErrorKind::Full }, val: msg });
+        }
+
+        let seq = state.next_seq;
+        state.next_seq += 1;
+        state.heap.push(Entry { priority, seq, msg });
+        state.wake_receiver();
+        Ok(())
+    }
+
+    /// Returns whether the receiver has been dropped or closed.
+    pub fn is_closed(&self) -> bool {
+        !self.shared.lock().unwrap().open
+    }
+
+    /// Returns the number of messages currently queued in the channel.
+    pub fn len(&self) -> usize {
+        self.shared.lock().unwrap().heap.len()
+    }
+
+    /// Returns `true` if there are no messages queued in the channel.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<P, T> Clone for Sender<P, T> {
+    fn clone(&self) -> Self {
+        let mut state = self.shared.lock().unwrap();
+        state.senders += 1;
+        let id = state.new_id();
+        Self { shared: self.shared.clone(), id, reserved: false }
+    }
+}
+
+impl<P, T> Drop for Sender<P, T> {
+    fn drop(&mut self) {
+        let mut state = self.shared.lock().unwrap();
+        let id = self.id;
+        state.send_wakers.retain(|(waker_id, _)| *waker_id != id);
+        if self.reserved {
+            // Hand the unused slot to another sender.
+            state.reserved -= 1;
+            state.wake_senders();
+        }
+        state.senders -= 1;
+        if state.senders == 0 {
+            state.wake_receiver();
+        }
+    }
+}
+
+impl<P, T> fmt::Debug for Sender<P, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Sender").field("reserved", &self.reserved).finish()
+    }
+}
+
+impl<P: Ord, T> Receiver<P, T> {
+    /// Closes the receiving half of the channel, without dropping it.
+    ///
+    /// This prevents any further messages from being sent on the channel
+    /// while still enabling the receiver to drain messages that are queued.
+    pub fn close(&mut self) {
+        let mut state = self.shared.lock().unwrap();
+        state.open = false;
+        state.wake_senders();
+    }
+
+    /// Tries to receive the highest-priority message without notifying a
+    /// context if empty.
+    ///
+    /// This function returns:
+    /// * `Ok(Some(t))` when a message is fetched
+    /// * `Ok(None)` when the channel is closed and no messages are left
+    /// * `Err(e)` when there are no messages available, but the channel is
+    ///   not yet closed
+    pub fn try_next(&mut self) -> Result<Option<T>, TryRecvError> {
+        if self.terminated {
+            return Ok(None);
+        }
+        let mut state = self.shared.lock().unwrap();
+        if let Some(msg) = state.pop() {
+            return Ok(Some(msg));
+        }
+        if state.senders == 0 || !state.open {
+            self.terminated = true;
+            return Ok(None);
+        }
+        Err(TryRecvError { _priv: () })
+    }
+
+    /// Returns the number of messages currently queued in the channel.
+    pub fn len(&self) -> usize {
+        self.shared.lock().unwrap().heap.len()
+    }
+
+    /// Returns `true` if there are no messages queued in the channel.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<P: Ord, T> Stream for Receiver<P, T> {
+    type Item = T;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<T>> {
+        match self.try_next() {
+            Ok(msg) => Poll::Ready(msg),
+            Err(_) => {
+                let mut state = self.shared.lock().unwrap();
+                // A message may have arrived since `try_next` released the
+                // lock.
+                if let Some(msg) = state.pop() {
+                    return Poll::Ready(Some(msg));
+                }
+                state.recv_waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+impl<P: Ord, T> FusedStream for Receiver<P, T> {
+    fn is_terminated(&self) -> bool {
+        self.terminated
+    }
+}
+
+impl<P, T> Drop for Receiver<P, T> {
+    fn drop(&mut self) {
+        let msgs: Vec<_> = {
+            let mut state = self.shared.lock().unwrap();
+            state.open = false;
+            state.wake_senders();
+            state.heap.drain().collect()
+        };
+        // Drop the messages without holding the lock, in case one of them is
+        // a sender of this channel.
+        drop(msgs);
+    }
+}
+
+impl<P, T> Unpin for Receiver<P, T> {}
+
+impl<P, T> fmt::Debug for Receiver<P, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Receiver").field("terminated", &self.terminated).finish()
+    }
+}
+
+#[cfg(feature = "sink")]
+impl<P: Ord, T> futures_sink::Sink<(P, T)> for Sender<P, T> {
+    type Error = SendError;
+
+    fn poll_ready(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        (*self).poll_ready(cx)
+    }
+
+    fn start_send(mut self: Pin<&mut Self>, (priority, msg): (P, T)) -> Result<(), Self::Error> {
+        (*self).start_send(priority, msg)
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_close(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+}
@@ -0,0 +1,315 @@
+//! A bounded multi-producer, single-consumer channel that drops the oldest
+//! message when it overflows.
+//!
+//! Channel creation provides a [`Sender`] and a [`Receiver`] handle. Sending
+//! never waits: once the channel holds `capacity` messages, each new message
+//! pushes out the oldest one, and the number of messages lost this way is
+//! counted. This suits telemetry or UI updates, where the latest data matters
+//! most and a slow receiver should not slow down the producers.
+//!
+//! # Disconnection
+//!
+//! When all [`Sender`] handles have been dropped, the receiver still gets
+//! the queued messages, then the end of the stream. When the [`Receiver`] is
+//! dropped or [closed](Receiver::close), sending fails.
+//!
+//! # Examples
+//!
+//! ```
+//! # futures::executor::block_on(async {
+//! use futures::channel::ring;
+//! use futures::stream::StreamExt;
+//!
+//! let (tx, rx) = ring::channel(2);
+//! for i in 0..5 {
+//!     tx.send(i).unwrap();
+//! }
+//! assert_eq!(tx.dropped(), 3);
+//! drop(tx);
+//!
+//! assert_eq!(rx.collect::<Vec<_>>().await, vec![3, 4]);
+//! # });
+//! ```
+
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Context, Poll, Waker};
+use std::collections::VecDeque;
+use std::fmt;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+
+/// The error type returned from [`send`](Sender::send) when the receiver is
+/// gone, which hands the message back.
+#[derive(Clone, PartialEq, Eq)]
+pub struct SendError<T>(pub T);
+
+impl<T> fmt::Debug for SendError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("SendError").finish()
+    }
+}
+
+impl<T> fmt::Display for SendError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "send failed because receiver is gone")
+    }
+}
+
+impl<T> std::error::Error for SendError<T> {}
+
+/// The error type returned from [`try_next`](Receiver::try_next).
+#[derive(Debug)]
+pub struct TryRecvError {
+    _priv: (),
+}
+
+impl fmt::Display for TryRecvError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "receiver channel is empty")
+    }
+}
+
+impl std::error::Error for TryRecvError {}
+
+struct State<T> {
+    buf: VecDeque<T>,
+    capacity: usize,
+    // Number of messages pushed out of a full buffer
+    dropped: u64,
+    // `false` once the receiver has been closed or dropped
+    open: bool,
+    senders: usize,
+    recv_waker: Option<Waker>,
+}
+
+impl<T> State<T> {
+    fn wake_receiver(&mut self) {
+        if let Some(waker) = self.recv_waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+/// The transmission end of a ring channel.
+///
+/// This value is created by the [`channel`] function.
+pub struct Sender<T> {
+    shared: Arc<Mutex<State<T>>>,
+}
+
+/// The receiving end of a ring channel.
+///
+/// This value is created by the [`channel`] function.
+pub struct Receiver<T> {
+    shared: Arc<Mutex<State<T>>>,
+    terminated: bool,
+}
+
+/// Creates a new ring channel holding at most `capacity` messages, returning
+/// the sender and receiver halves.
+///
+/// # Panics
+///
+/// Panics if `capacity` is zero.
+pub fn channel<T>(capacity: usize) -> (Sender<T>, Receiver<T>) {
+    assert!(capacity > 0, "capacity must be greater than zero");
+
+    let state = State {
+        buf: VecDeque::with_capacity(capacity),
+        capacity,
+        dropped: 0,
+        open: true,
+        senders: 1,
+        recv_waker: None,
+    };
+    let shared = Arc::new(Mutex::new(state));
+    let rx = Receiver { shared: shared.clone(), terminated: false };
+    (Sender { shared }, rx)
+}
+
+impl<T> Sender<T> {
+    /// Sends a message, returning the oldest message if it had to be dropped
+    /// to make room.
+    ///
+    /// This never waits.
+    ///
+    /// # Errors
+    ///
+    /// Returns the message back if the receiver has been dropped or closed.
+    pub fn send(&self, msg: T) -> Result<Option<T>, SendError<T>> {
+        let mut state = self.shared.lock().unwrap();
+        if !state.open {
+            return Err(SendError(msg));
+        }
+        let evicted = if state.buf.len() == state.capacity {
+            state.dropped += 1;
+            state.buf.pop_front()
+        } else {
+            None
+        };
+        state.buf.push_back(msg);
+        state.wake_receiver();
+        Ok(evicted)
+    }
+
+    /// Returns the number of messages dropped so far because the channel was
+    /// full.
+    pub fn dropped(&self) -> u64 {
+        self.shared.lock().unwrap().dropped
+    }
+
+    /// Returns whether the receiver has been dropped or closed.
+    pub fn is_closed(&self) -> bool {
+        !self.shared.lock().unwrap().open
+    }
+}
+
+impl<T> Clone for Sender<T> {
+    fn clone(&self) -> Self {
+        self.shared.lock().unwrap().senders += 1;
+        Self { shared: self.shared.clone() }
+    }
+}
+
+impl<T> Drop for Sender<T> {
+    fn drop(&mut self) {
+        let mut state = self.shared.lock().unwrap();
+        state.senders -= 1;
+        if state.senders == 0 {
+            state.wake_receiver();
+        }
+    }
+}
+
+impl<T> fmt::Debug for Sender<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Sender").finish()
+    }
+}
+
+impl<T> Receiver<T> {
+    /// Closes the receiving half of the channel, without dropping it.
+    ///
+    /// This prevents any further messages from being sent on the channel
+    /// while still enabling the receiver to drain messages that are queued.
+    pub fn close(&mut self) {
+        self.shared.lock().unwrap().open = false;
+    }
+
+    /// Tries to receive the oldest queued message without notifying a
+    /// context if empty.
+    ///
+    /// This function returns:
+    /// * `Ok(Some(t))` when a message is fetched
+    /// * `Ok(None)` when the channel is closed and no messages are left
+    /// * `Err(e)` when there are no messages available, but the channel is
+    ///   not yet closed
+    pub fn try_next(&mut self) -> Result<Option<T>, TryRecvError> {
+        if self.terminated {
+            return Ok(None);
+        }
+        let mut state = self.shared.lock().unwrap();
+        if let Some(msg) = state.buf.pop_front() {
+            return Ok(Some(msg));
+        }
+        if state.senders == 0 || !state.open {
+            self.terminated = true;
+            return Ok(None);
+        }
+        Err(TryRecvError { _priv: () })
+    }
+
+    /// Returns the number of messages dropped so far because the channel was
+    /// full.
+    pub fn dropped(&self) -> u64 {
+        self.shared.lock().unwrap().dropped
+    }
+
+    /// Returns the number of messages currently queued in the channel.
+    pub fn len(&self) -> usize {
+        self.shared.lock().unwrap().buf.len()
+    }
+
+    /// Returns `true` if there are no messages queued in the channel.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<T> Stream for Receiver<T> {
+    type Item = T;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<T>> {
+        if self.terminated {
+            return Poll::Ready(None);
+        }
+
+        let mut state = self.shared.lock().unwrap();
+        if let Some(msg) = state.buf.pop_front() {
+            return Poll::Ready(Some(msg));
+        }
+        if state.senders == 0 || !state.open {
+            drop(state);
+            self.terminated = true;
+            return Poll::Ready(None);
+        }
+        state.recv_waker = Some(cx.waker().clone());
+        Poll::Pending
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.terminated {
+            (0, Some(0))
+        } else {
+            (self.len(), None)
+        }
+    }
+}
+
+impl<T> FusedStream for Receiver<T> {
+    fn is_terminated(&self) -> bool {
+        self.terminated
+    }
+}
+
+impl<T> Drop for Receiver<T> {
+    fn drop(&mut self) {
+        let msgs = {
+            let mut state = self.shared.lock().unwrap();
+            state.open = false;
+            std::mem::replace(&mut state.buf, VecDeque::new())
+        };
+        // Drop the messages without holding the lock, in case one of them is
+        // a sender of this channel.
+        drop(msgs);
+    }
+}
+
+impl<T> Unpin for Receiver<T> {}
+
+impl<T> fmt::Debug for Receiver<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Receiver").field("terminated", &self.terminated).finish()
+    }
+}
+
+#[cfg(feature = "sink")]
+impl<T> futures_sink::Sink<T> for Sender<T> {
+    type Error = SendError<T>;
+
+    fn poll_ready(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn start_send(self: Pin<&mut Self>, msg: T) -> Result<(), Self::Error> {
+        self.send(msg).map(drop)
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_close(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+}
@@ -0,0 +1,343 @@
+//! A single-producer, multi-consumer channel holding only the latest value.
+//!
+//! Channel creation provides a [`Sender`] and a [`Receiver`] handle, and the
+//! channel starts out holding the given initial value. Every call to
+//! [`Sender::send`] replaces the value, and receivers can look at the current
+//! value at any time with [`Receiver::borrow`], or wait for it to be replaced
+//! with [`Receiver::changed`]. Intermediate values replaced before a receiver
+//! looked at them are never seen by it, which makes this a good fit for
+//! propagating configuration or signaling shutdown.
+//!
+//! More receivers can be created by cloning a receiver or with
+//! [`Sender::subscribe`].
+//!
+//! # Disconnection
+//!
+//! When the [`Sender`] is dropped, [`Receiver::changed`] fails once the
+//! receiver has seen the last value, which stays readable. When all
+//! [`Receiver`] handles have been dropped, sending fails.
+//!
+//! # Examples
+//!
+//! ```
+//! # futures::executor::block_on(async {
+//! use futures::channel::watch;
+//!
+//! let (tx, mut rx) = watch::channel("initial");
+//! assert_eq!(*rx.borrow(), "initial");
+//!
+//! tx.send("updated").unwrap();
+//! rx.changed().await.unwrap();
+//! assert_eq!(*rx.borrow(), "updated");
+//!
+//! drop(tx);
+//! assert!(rx.changed().await.is_err());
+//! # });
+//! ```
+
+use futures_core::future::{FusedFuture, Future};
+use futures_core::task::{Context, Poll, Waker};
+use std::fmt;
+use std::ops::Deref;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex, RwLock, RwLockReadGuard};
+
+/// The error type returned from [`send`](Sender::send) when there are no
+/// receivers left, which hands the value back.
+#[derive(Clone, PartialEq, Eq)]
+pub struct SendError<T>(pub T);
+
+impl<T> fmt::Debug for SendError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("SendError").finish()
+    }
+}
+
+impl<T> fmt::Display for SendError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "send failed because all receivers are gone")
+    }
+}
+
+impl<T> std::error::Error for SendError<T> {}
+
+/// The error type returned when waiting for a change after the [`Sender`] is
+/// gone.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RecvError(());
+
+impl fmt::Display for RecvError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "sender is gone")
+    }
+}
+
+impl std::error::Error for RecvError {}
+
+#[derive(Debug)]
+struct State {
+    version: u64,
+    closed: bool,
+    receivers: usize,
+    next_id: usize,
+    wakers: Vec<(usize, Waker)>,
+}
+
+impl State {
+    fn wake_all(&mut self) {
+        for (_, waker) in self.wakers.drain(..) {
+            waker.wake();
+        }
+    }
+
+    fn new_id(&mut self) -> usize {
+        let id = self.next_id;
+        self.next_id = self.next_id.wrapping_add(1);
+        id
+    }
+}
+
+#[derive(Debug)]
+struct Shared<T> {
+    value: RwLock<T>,
+    state: Mutex<State>,
+}
+
+/// The sending end of a watch channel.
+///
+/// This value is created by the [`channel`] function.
+pub struct Sender<T> {
+    shared: Arc<Shared<T>>,
+}
+
+/// The receiving end of a watch channel.
+///
+/// This value is created by the [`channel`] function, by
+/// [`Sender::subscribe`] or by cloning another receiver.
+pub struct Receiver<T> {
+    shared: Arc<Shared<T>>,
+    seen: u64,
+    id: usize,
+}
+
+/// A reference to the value held by a watch channel.
+///
+/// The value cannot be replaced while this reference exists, so it should be
+/// dropped quickly, and in particular not held across an `.await`.
+pub struct Ref<'a, T> {
+    guard: RwLockReadGuard<'a, T>,
+}
+
+impl<T> Deref for Ref<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.guard
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Ref<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        (**self).fmt(f)
+    }
+}
+
+/// Creates a new watch channel holding `init`, returning the sender and a
+/// first receiver.
+///
+/// The receiver considers `init` as seen, so it only observes later changes.
+pub fn channel<T>(init: T) -> (Sender<T>, Receiver<T>) {
+    let state = State { version: 0, closed: false, receivers: 1, next_id: 1, wakers: Vec::new() };
+    let shared = Arc::new(Shared { value: RwLock::new(init), state: Mutex::new(state) });
+    let rx = Receiver { shared: shared.clone(), seen: 0, id: 0 };
+    (Sender { shared }, rx)
+}
+
+impl<T> Sender<T> {
+    /// Replaces the value held by the channel and notifies the receivers.
+    ///
+    /// # Errors
+    ///
+    /// Returns the value back if there are no receivers left.
+    pub fn send(&self, value: T) -> Result<(), SendError<T>> {
+        if self.shared.state.lock().unwrap().receivers == 0 {
+            return Err(SendError(value));
+        }
+        self.send_replace(value);
+        Ok(())
+    }
+
+    /// Replaces the value held by the channel, notifies the receivers and
+    /// returns the previous value.
+    ///
+    /// Unlike [`send`](Sender::send), this also works without receivers, so
+    /// the value is there for receivers created later with
+    /// [`subscribe`](Sender::subscribe).
+    pub fn send_replace(&self, value: T) -> T {
+        let previous = std::mem::replace(&mut *self.shared.value.write().unwrap(), value);
+        let mut state = self.shared.state.lock().unwrap();
+        state.version += 1;
+        state.wake_all();
+        previous
+    }
+
+    /// Returns a reference to the current value.
+    pub fn borrow(&self) -> Ref<'_, T> {
+        Ref { guard: self.shared.value.read().unwrap() }
+    }
+
+    /// Creates a new receiver, which considers the current value as seen.
+    pub fn subscribe(&self) -> Receiver<T> {
+        let mut state = self.shared.state.lock().unwrap();
+        state.receivers += 1;
+        let id = state.new_id();
+        Receiver { shared: self.shared.clone(), seen: state.version, id }
+    }
+
+    /// Returns the number of receivers currently connected to the channel.
+    pub fn receiver_count(&self) -> usize {
+        self.shared.state.lock().unwrap().receivers
+    }
+
+    /// Returns `true` if there are no receivers left.
+    pub fn is_closed(&self) -> bool {
+        self.receiver_count() == 0
+    }
+}
+
+impl<T> Drop for Sender<T> {
+    fn drop(&mut self) {
+        let mut state = self.shared.state.lock().unwrap();
+        state.closed = true;
+        state.wake_all();
+    }
+}
+
+impl<T> fmt::Debug for Sender<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Sender").finish()
+    }
+}
+
+impl<T> Receiver<T> {
+    /// Returns a reference to the current value, without marking it as
+    /// seen.
+    pub fn borrow(&self) -> Ref<'_, T> {
+        Ref { guard: self.shared.value.read().unwrap() }
+    }
+
+    /// Returns a reference to the current value, and marks it as seen.
+    pub fn borrow_and_update(&mut self) -> Ref<'_, T> {
+        // Read the version before the value, so that a concurrent change is
+        // at worst seen twice rather than missed.
+        self.seen = self.shared.state.lock().unwrap().version;
+        Ref { guard: self.shared.value.read().unwrap() }
+    }
+
+    /// Returns `true` if the value has changed since this receiver last saw
+    /// it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the sender is gone and the receiver has seen the
+    /// last value.
+    pub fn has_changed(&self) -> Result<bool, RecvError> {
+        let state = self.shared.state.lock().unwrap();
+        if state.version != self.seen {
+            Ok(true)
+        } else if state.closed {
+            Err(RecvError(()))
+        } else {
+            Ok(false)
+        }
+    }
+
+    /// Polls for a change of the value since this receiver last saw it, and
+    /// marks the new value as seen.
+    ///
+    /// This returns `Poll::Ready(Err(_))` if the sender is gone and the
+    /// receiver has seen the last value.
+    pub fn poll_changed(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), RecvError>> {
+        let mut state = self.shared.state.lock().unwrap();
+        if state.version != self.seen {
+            self.seen = state.version;
+            return Poll::Ready(Ok(()));
+        }
+        if state.closed {
+            return Poll::Ready(Err(RecvError(())));
+        }
+
+        let id = self.id;
+        match state.wakers.iter_mut().find(|(waker_id, _)| *waker_id == id) {
+            Some((_, waker)) => {
+                if !waker.will_wake(cx.waker()) {
+                    *waker = cx.waker().clone();
+                }
+            }
+            None => state.wakers.push((id, cx.waker().clone())),
+        }
+        Poll::Pending
+    }
+
+    /// Waits for a change of the value since this receiver last saw it, and
+    /// marks the new value as seen.
+    ///
+    /// The returned future fails if the sender is gone and the receiver has
+    /// seen the last value.
+    pub fn changed(&mut self) -> Changed<'_, T> {
+        Changed { receiver: Some(self) }
+    }
+}
+
+impl<T> Clone for Receiver<T> {
+    fn clone(&self) -> Self {
+        let mut state = self.shared.state.lock().unwrap();
+        state.receivers += 1;
+        let id = state.new_id();
+        Self { shared: self.shared.clone(), seen: self.seen, id }
+    }
+}
+
+impl<T> Drop for Receiver<T> {
+    fn drop(&mut self) {
+        let mut state = self.shared.state.lock().unwrap();
+        state.receivers -= 1;
+        let id = self.id;
+        state.wakers.retain(|(waker_id, _)| *waker_id != id);
+    }
+}
+
+impl<T> fmt::Debug for Receiver<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Receiver").field("seen", &self.seen).finish()
+    }
+}
+
+/// Future for the [`changed`](Receiver::changed) method.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct Changed<'a, T> {
+    receiver: Option<&'a mut Receiver<T>>,
+}
+
+impl<T> Future for Changed<'_, T> {
+    type Output = Result<(), RecvError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let receiver = self.receiver.as_mut().expect("polled Changed after completion");
+        let result = futures_core::ready!(receiver.poll_changed(cx));
+        self.receiver = None;
+        Poll::Ready(result)
+    }
+}
+
+impl<T> FusedFuture for Changed<'_, T> {
+    fn is_terminated(&self) -> bool {
+        self.receiver.is_none()
+    }
+}
+
+impl<T> fmt::Debug for Changed<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Changed").finish()
+    }
+}
@@ -0,0 +1,73 @@
+use futures::channel::broadcast::{self, LagPolicy, Lagged, SendError, TryRecvError};
+use futures::executor::block_on;
+use futures::sink::SinkExt;
+use futures::stream::StreamExt;
+use std::thread;
+
+#[test]
+fn every_receiver_sees_every_message() {
+    let (tx, rx1) = broadcast::channel(4);
+    let rx2 = rx1.clone();
+
+    let t = thread::spawn(move || {
+        for i in 0..3 {
+            assert_eq!(tx.send(i), Ok(2));
+        }
+    });
+
+    let items1 = block_on(rx1.map(Result::unwrap).collect::<Vec<_>>());
+    let items2 = block_on(rx2.map(Result::unwrap).collect::<Vec<_>>());
+    t.join().unwrap();
+    assert_eq!(items1, vec![0, 1, 2]);
+    assert_eq!(items2, vec![0, 1, 2]);
+}
+
+#[test]
+fn subscribe_starts_at_next_message() {
+    let (tx, mut rx1) = broadcast::channel(4);
+    tx.send(1).unwrap();
+    let mut rx2 = tx.subscribe();
+    tx.send(2).unwrap();
+    assert_eq!(tx.receiver_count(), 2);
+
+    assert_eq!(rx1.try_recv(), Ok(1));
+    assert_eq!(rx1.try_recv(), Ok(2));
+    assert_eq!(rx2.try_recv(), Ok(2));
+    assert_eq!(rx2.try_recv(), Err(TryRecvError::Empty));
+
+    drop(tx);
+    assert_eq!(rx2.try_recv(), Err(TryRecvError::Closed));
+}
+
+#[test]
+fn lagging_receiver() {
+    let (tx, rx) = broadcast::channel(2);
+    let mut skipping = rx.clone();
+    skipping.set_lag_policy(LagPolicy::Skip);
+
+    for i in 0..5 {
+        tx.send(i).unwrap();
+    }
+    assert_eq!(rx.len(), 2);
+    drop(tx);
+
+    assert_eq!(block_on(rx.collect::<Vec<_>>()), vec![Err(Lagged(3)), Ok(3), Ok(4)]);
+    assert_eq!(skipping.try_recv(), Ok(3));
+    assert_eq!(skipping.try_recv(), Ok(4));
+    assert_eq!(skipping.try_recv(), Err(TryRecvError::Closed));
+}
+
+#[test]
+fn send_without_receivers() {
+    let (tx, rx) = broadcast::channel(1);
+    drop(rx);
+    assert_eq!(tx.send(1), Err(SendError(1)));
+}
+
+#[test]
+fn send_through_sink() {
+    let (mut tx, rx) = broadcast::channel(4);
+    block_on(tx.send_all(&mut futures::stream::iter(vec![Ok(1), Ok(2)]))).unwrap();
+    drop(tx);
+    assert_eq!(block_on(rx.collect::<Vec<_>>()), vec![Ok(1), Ok(2)]);
+}
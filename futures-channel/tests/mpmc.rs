@@ -0,0 +1,62 @@
+use futures::channel::mpmc;
+use futures::executor::block_on;
+use futures::sink::SinkExt;
+use futures::stream::StreamExt;
+use futures_test::task::noop_context;
+use std::thread;
+
+#[test]
+fn each_message_goes_to_one_receiver() {
+    let (mut tx, rx) = mpmc::channel(4);
+    let workers: Vec<_> = (0..4)
+        .map(|_| {
+            let rx = rx.clone();
+            thread::spawn(move || block_on(rx.collect::<Vec<_>>()))
+        })
+        .collect();
+    drop(rx);
+
+    block_on(async {
+        for i in 0..100 {
+            tx.send(i).await.unwrap();
+        }
+    });
+    drop(tx);
+
+    let mut all: Vec<_> = workers.into_iter().flat_map(|w| w.join().unwrap()).collect();
+    all.sort_unstable();
+    assert_eq!(all, (0..100).collect::<Vec<_>>());
+}
+
+#[test]
+fn waiting_receivers_served_in_order() {
+    let (mut tx, mut rx1) = mpmc::channel(4);
+    let mut rx2 = rx1.clone();
+    let mut cx = noop_context();
+
+    assert!(rx2.poll_next_unpin(&mut cx).is_pending());
+    assert!(rx1.poll_next_unpin(&mut cx).is_pending());
+    tx.try_send(1).unwrap();
+    assert_eq!(rx1.try_next().unwrap(), Some(1));
+    assert!(rx2.try_next().is_err());
+    assert_eq!(rx1.receiver_count(), 2);
+}
+
+#[test]
+fn bounded_and_disconnected() {
+    let (mut tx, mut rx) = mpmc::channel(1);
+    let mut cx = noop_context();
+    tx.try_send(1).unwrap();
+    assert!(tx.try_send(2).unwrap_err().is_full());
+    assert!(tx.poll_ready(&mut cx).is_pending());
+    assert_eq!(rx.try_next().unwrap(), Some(1));
+    assert!(tx.poll_ready(&mut cx).is_ready());
+    tx.start_send(3).unwrap();
+
+    let rx2 = rx.clone();
+    drop(rx);
+    assert!(!tx.is_closed());
+    drop(rx2);
+    assert!(tx.is_closed());
+    assert!(tx.try_send(4).unwrap_err().is_disconnected());
+}
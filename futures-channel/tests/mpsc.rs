@@ -3,7 +3,7 @@ use futures::executor::{block_on, block_on_stream};
 use futures::future::{poll_fn, FutureExt};
 use futures::pin_mut;
 use futures::sink::{Sink, SinkExt};
-use futures::stream::{Stream, StreamExt};
+use futures::stream::{FusedStream, Stream, StreamExt};
 use futures::task::{Context, Poll};
 use futures_test::task::{new_count_waker, noop_context};
 use std::sync::atomic::{AtomicUsize, Ordering};
@@ -649,3 +649,439 @@ fn send_backpressure_multi_senders() {
     let item = block_on(rx.next()).unwrap();
     assert_eq!(item, 2);
 }
+
+#[test]
+fn recv_many_batches() {
+    let (mut tx, mut rx) = mpsc::channel(8);
+    let mut cx = noop_context();
+    let mut buf = Vec::new();
+    assert_eq!(rx.poll_recv_many(&mut cx, &mut buf, 4), Poll::Pending);
+
+    for i in 0..6 {
+        tx.try_send(i).unwrap();
+    }
+    assert_eq!(block_on(rx.recv_many(&mut buf, 4)), 4);
+    assert_eq!(block_on(rx.recv_many(&mut buf, 4)), 2);
+    assert_eq!(buf, vec![0, 1, 2, 3, 4, 5]);
+    assert_eq!(block_on(rx.recv_many(&mut buf, 0)), 0);
+
+    drop(tx);
+    assert_eq!(block_on(rx.recv_many(&mut buf, 4)), 0);
+    assert!(rx.is_terminated());
+}
+
+#[test]
+fn recv_many_unbounded_wakes() {
+    let (tx, mut rx) = mpsc::unbounded();
+    let t = thread::spawn(move || {
+        tx.unbounded_send(1).unwrap();
+        tx.unbounded_send(2).unwrap();
+    });
+
+    let mut buf = Vec::new();
+    while block_on(rx.recv_many(&mut buf, 8)) > 0 {}
+    t.join().unwrap();
+    assert_eq!(buf, vec![1, 2]);
+}
+
+#[test]
+fn reserve_permits() {
+    let (mut tx, mut rx) = mpsc::channel::<i32>(0);
+    let mut cx = noop_context();
+
+    tx.try_reserve().unwrap().send(1);
+    assert!(tx.try_reserve().unwrap_err().is_full());
+    {
+        let mut reserve = tx.reserve();
+        assert!(reserve.poll_unpin(&mut cx).is_pending());
+    }
+
+    assert_eq!(block_on(rx.next()), Some(1));
+    let permit = block_on(tx.reserve()).unwrap();
+    drop(permit);
+    block_on(tx.reserve()).unwrap().send(2);
+    assert_eq!(block_on(rx.next()), Some(2));
+
+    drop(rx);
+    assert!(tx.try_reserve().unwrap_err().is_disconnected());
+    assert!(block_on(tx.reserve()).unwrap_err().is_disconnected());
+}
+
+#[test]
+fn weak_sender_does_not_keep_channel_open() {
+    let (mut tx, mut rx) = mpsc::channel(2);
+    let weak = tx.downgrade();
+
+    let mut upgraded = weak.upgrade().unwrap();
+    block_on(upgraded.send(1)).unwrap();
+    drop(upgraded);
+    block_on(tx.send(2)).unwrap();
+    drop(tx);
+
+    assert_eq!(block_on(rx.by_ref().collect::<Vec<_>>()), vec![1, 2]);
+    assert!(weak.upgrade().is_none());
+}
+
+#[test]
+fn weak_unbounded_sender_upgrade() {
+    let (tx, rx) = mpsc::unbounded();
+    let weak = tx.downgrade();
+    let weak2 = weak.clone();
+
+    weak.upgrade().unwrap().unbounded_send(1).unwrap();
+    drop(tx);
+    assert!(weak2.upgrade().is_none());
+    assert_eq!(block_on(rx.collect::<Vec<_>>()), vec![1]);
+    assert!(weak.upgrade().is_none());
+}
+
+#[test]
+fn queue_introspection() {
+    let (mut tx, mut rx) = mpsc::channel(2);
+    assert!(tx.is_empty());
+    assert_eq!(tx.max_capacity(), 3);
+    assert_eq!(rx.capacity(), 3);
+
+    tx.try_send(1).unwrap();
+    tx.try_send(2).unwrap();
+    assert_eq!(tx.len(), 2);
+    assert_eq!(rx.len(), 2);
+    assert_eq!(tx.capacity(), 1);
+
+    let tx2 = tx.clone();
+    assert_eq!(rx.max_capacity(), 4);
+    drop(tx2);
+
+    assert_eq!(block_on(rx.next()), Some(1));
+    assert_eq!(rx.len(), 1);
+    tx.disconnect();
+    assert_eq!(tx.max_capacity(), 0);
+    assert_eq!(block_on(rx.next()), Some(2));
+    assert!(rx.is_empty());
+
+    let (utx, urx) = mpsc::unbounded();
+    utx.unbounded_send(1).unwrap();
+    assert_eq!(utx.len(), 1);
+    assert_eq!(urx.len(), 1);
+}
+
+#[test]
+fn closed_resolves_when_receiver_drops() {
+    let (mut tx, rx) = mpsc::channel::<i32>(1);
+    let mut tx2 = tx.clone();
+    let mut cx = noop_context();
+    assert_eq!(tx.poll_closed(&mut cx), Poll::Pending);
+    assert_eq!(tx.poll_closed(&mut cx), Poll::Pending);
+
+    let t = thread::spawn(move || drop(rx));
+    block_on(tx.closed());
+    block_on(tx2.closed());
+    t.join().unwrap();
+    assert!(tx.is_closed());
+
+    let (mut utx, mut urx) = mpsc::unbounded::<i32>();
+    assert_eq!(utx.poll_closed(&mut cx), Poll::Pending);
+    urx.close();
+    block_on(utx.closed());
+}
+
+#[test]
+fn peek_does_not_consume() {
+    let (mut tx, mut rx) = mpsc::channel(4);
+    let mut cx = noop_context();
+    assert_eq!(rx.poll_peek(&mut cx), Poll::Pending);
+
+    tx.try_send(1).unwrap();
+    tx.try_send(2).unwrap();
+    assert_eq!(block_on(rx.peek()), Some(&1));
+    assert_eq!(rx.poll_peek(&mut cx), Poll::Ready(Some(&1)));
+    assert_eq!(rx.len(), 2);
+    assert_eq!(rx.try_next().unwrap(), Some(1));
+    assert_eq!(block_on(rx.peek()), Some(&2));
+
+    drop(tx);
+    assert!(!rx.is_terminated());
+    assert_eq!(block_on(rx.next()), Some(2));
+    assert_eq!(block_on(rx.peek()), None);
+    assert!(rx.is_terminated());
+
+    let (utx, mut urx) = mpsc::unbounded();
+    utx.unbounded_send(3).unwrap();
+    assert_eq!(block_on(urx.peek()), Some(&3));
+    let mut buf = Vec::new();
+    assert_eq!(block_on(urx.recv_many(&mut buf, 4)), 1);
+    assert_eq!(buf, vec![3]);
+}
+
+#[test]
+fn rendezvous_send_waits_for_receiver() {
+    let (mut tx, mut rx) = mpsc::channel::<usize>(0);
+    let sent = Arc::new(AtomicUsize::new(0));
+    let sent2 = sent.clone();
+
+    let t = thread::spawn(move || {
+        for i in 1..=3 {
+            block_on(tx.send(i)).unwrap();
+            sent2.store(i, Ordering::SeqCst);
+        }
+    });
+
+    for i in 1..=3 {
+        thread::sleep(std::time::Duration::from_millis(10));
+        // the send of `i` cannot complete before it has been received
+        assert!(sent.load(Ordering::SeqCst) < i);
+        assert_eq!(block_on(rx.next()), Some(i));
+    }
+    t.join().unwrap();
+}
+
+#[test]
+fn blocking_send_and_recv_across_threads() {
+    let (mut tx, mut rx) = mpsc::channel::<i32>(0);
+
+    let t = thread::spawn(move || {
+        for i in 0..10 {
+            tx.blocking_send(i).unwrap();
+        }
+    });
+
+    for i in 0..10 {
+        assert_eq!(rx.blocking_recv(), Some(i));
+    }
+    t.join().unwrap();
+    assert_eq!(rx.blocking_recv(), None);
+}
+
+#[test]
+fn blocking_send_fails_when_receiver_dropped() {
+    let (mut tx, rx) = mpsc::channel::<i32>(0);
+    drop(rx);
+    assert!(tx.blocking_send(1).unwrap_err().is_disconnected());
+}
+
+#[test]
+fn unbounded_blocking_recv() {
+    let (tx, mut rx) = mpsc::unbounded::<i32>();
+
+    let t = thread::spawn(move || {
+        tx.unbounded_send(1).unwrap();
+        tx.unbounded_send(2).unwrap();
+    });
+
+    assert_eq!(rx.blocking_recv(), Some(1));
+    assert_eq!(rx.blocking_recv(), Some(2));
+    assert_eq!(rx.blocking_recv(), None);
+    t.join().unwrap();
+}
+
+#[test]
+fn drain_closes_and_yields_queued_messages() {
+    let (mut tx, mut rx) = mpsc::channel::<i32>(4);
+    for i in 0..3 {
+        tx.try_send(i).unwrap();
+    }
+
+    assert_eq!(rx.drain().collect::<Vec<_>>(), vec![0, 1, 2]);
+    assert!(tx.try_send(3).unwrap_err().is_disconnected());
+    assert!(rx.is_terminated());
+    assert_eq!(rx.drain().next(), None);
+}
+
+#[test]
+fn unbounded_drain_closes_and_yields_queued_messages() {
+    let (tx, mut rx) = mpsc::unbounded::<i32>();
+    for i in 0..3 {
+        tx.unbounded_send(i).unwrap();
+    }
+
+    assert_eq!(rx.drain().collect::<Vec<_>>(), vec![0, 1, 2]);
+    assert!(tx.unbounded_send(3).unwrap_err().is_disconnected());
+    assert!(rx.is_terminated());
+}
+
+#[test]
+fn forward_several_streams_into_unbounded_send_sink() {
+    let (tx, rx) = mpsc::unbounded::<i32>();
+
+    block_on(async {
+        let a = futures::stream::iter(vec![1, 2]).forward(tx.sink());
+        let b = futures::stream::iter(vec![3, 4]).forward(tx.sink());
+        let (a, b) = futures::future::join(a, b).await;
+        a.unwrap();
+        b.unwrap();
+    });
+    assert!(!tx.is_closed());
+    drop(tx);
+
+    let mut msgs = block_on(rx.collect::<Vec<_>>());
+    msgs.sort();
+    assert_eq!(msgs, vec![1, 2, 3, 4]);
+}
+
+#[test]
+fn unbounded_many_messages_from_many_threads() {
+    const THREADS: usize = 4;
+    const MSGS: usize = 10_000;
+
+    let (tx, rx) = mpsc::unbounded::<(usize, usize)>();
+    let threads: Vec<_> = (0..THREADS)
+        .map(|t| {
+            let tx = tx.clone();
+            thread::spawn(move || {
+                for i in 0..MSGS {
+                    tx.unbounded_send((t, i)).unwrap();
+                }
+            })
+        })
+        .collect();
+    drop(tx);
+
+    // Messages from each sender arrive in order
+    let mut next = [0; THREADS];
+    for (t, i) in block_on_stream(rx) {
+        assert_eq!(next[t], i);
+        next[t] += 1;
+    }
+    assert_eq!(next, [MSGS; THREADS]);
+    for t in threads {
+        t.join().unwrap();
+    }
+}
+
+#[test]
+fn dropping_receiver_drops_queued_messages() {
+    let msg = Arc::new(());
+    let (tx, rx) = mpsc::unbounded::<Arc<()>>();
+    for _ in 0..100 {
+        tx.unbounded_send(msg.clone()).unwrap();
+    }
+    drop(rx);
+    drop(tx);
+    assert_eq!(Arc::strong_count(&msg), 1);
+}
+
+#[test]
+fn drain_returns_messages_of_parked_senders() {
+    let (mut tx1, mut rx) = mpsc::channel::<i32>(0);
+    let mut tx2 = tx1.clone();
+
+    // Both senders use their guaranteed slot and are parked afterwards
+    tx1.try_send(1).unwrap();
+    tx2.try_send(2).unwrap();
+    assert!(tx1.try_send(3).unwrap_err().is_full());
+
+    assert_eq!(rx.drain().collect::<Vec<_>>(), vec![1, 2]);
+    assert!(tx1.try_send(3).unwrap_err().is_disconnected());
+    assert!(tx2.try_send(4).unwrap_err().is_disconnected());
+}
+
+#[test]
+fn pause_makes_senders_wait_until_resume() {
+    let (mut tx, mut rx) = mpsc::channel::<i32>(4);
+    let (waker, count) = new_count_waker();
+    let mut cx = Context::from_waker(&waker);
+
+    tx.try_send(1).unwrap();
+    rx.pause();
+    assert!(rx.is_paused());
+    assert!(tx.try_send(2).unwrap_err().is_full());
+    assert!(tx.try_reserve().unwrap_err().is_full());
+    assert!(tx.poll_ready(&mut cx).is_pending());
+    assert!(tx.poll_ready(&mut cx).is_pending());
+
+    // Queued messages can still be received
+    assert_eq!(rx.try_next().unwrap(), Some(1));
+    assert_eq!(count, 0);
+
+    rx.resume();
+    assert_eq!(count, 1);
+    assert!(!rx.is_paused());
+    assert_eq!(tx.poll_ready(&mut cx), Poll::Ready(Ok(())));
+    tx.try_send(2).unwrap();
+    assert_eq!(rx.try_next().unwrap(), Some(2));
+}
+
+#[test]
+fn closing_paused_channel_wakes_senders() {
+    let (mut tx, mut rx) = mpsc::channel::<i32>(4);
+    let (waker, count) = new_count_waker();
+    let mut cx = Context::from_waker(&waker);
+
+    rx.pause();
+    assert!(tx.poll_ready(&mut cx).is_pending());
+    drop(rx);
+    assert_eq!(count, 1);
+    assert!(match tx.poll_ready(&mut cx) {
+        Poll::Ready(Err(e)) => e.is_disconnected(),
+        _ => false,
+    });
+}
+
+#[test]
+fn unbounded_pause_stops_accepting() {
+    let (tx, mut rx) = mpsc::unbounded::<i32>();
+    let (waker, count) = new_count_waker();
+    let mut cx = Context::from_waker(&waker);
+
+    rx.pause();
+    let err = tx.unbounded_send(1).unwrap_err();
+    assert!(err.is_full());
+    assert_eq!(err.into_inner(), 1);
+    assert!(tx.poll_ready(&mut cx).is_pending());
+
+    rx.resume();
+    assert_eq!(count, 1);
+    assert_eq!(tx.poll_ready(&mut cx), Poll::Ready(Ok(())));
+    tx.unbounded_send(2).unwrap();
+    assert_eq!(rx.try_next().unwrap(), Some(2));
+}
+
+#[test]
+fn paused_sink_send_completes_after_resume() {
+    let (mut tx, mut rx) = mpsc::channel::<i32>(4);
+    rx.pause();
+
+    let t = thread::spawn(move || block_on(tx.send(1)).unwrap());
+    thread::sleep(std::time::Duration::from_millis(50));
+    assert!(rx.try_next().is_err());
+
+    rx.resume();
+    assert_eq!(block_on(rx.next()), Some(1));
+    t.join().unwrap();
+}
+
+#[test]
+fn send_timeout_with_sends_when_capacity_frees_up() {
+    let (mut tx, mut rx) = mpsc::channel::<i32>(0);
+    let (_deadline_tx, deadline) = oneshot::channel::<()>();
+    tx.try_send(1).unwrap();
+
+    let mut send = tx.send_timeout_with(2, deadline);
+    assert!(send.poll_unpin(&mut noop_context()).is_pending());
+    assert_eq!(block_on(rx.next()), Some(1));
+    assert_eq!(send.poll_unpin(&mut noop_context()), Poll::Ready(Ok(())));
+    assert_eq!(block_on(rx.next()), Some(2));
+}
+
+#[test]
+fn send_timeout_with_hands_message_back_at_deadline() {
+    let (mut tx, rx) = mpsc::channel::<i32>(0);
+    let (deadline_tx, deadline) = oneshot::channel::<()>();
+    tx.try_send(1).unwrap();
+
+    let mut send = tx.send_timeout_with(2, deadline);
+    assert!(send.poll_unpin(&mut noop_context()).is_pending());
+    deadline_tx.send(()).unwrap();
+    let err = match send.poll_unpin(&mut noop_context()) {
+        Poll::Ready(Err(err)) => err,
+        _ => panic!("expected the send to time out"),
+    };
+    assert!(err.is_full());
+    assert_eq!(err.into_inner(), 2);
+
+    drop(rx);
+    let (_deadline_tx, deadline) = oneshot::channel::<()>();
+    let err = block_on(tx.send_timeout_with(3, deadline)).unwrap_err();
+    assert!(err.is_disconnected());
+    assert_eq!(err.into_inner(), 3);
+}
@@ -0,0 +1,106 @@
+use futures::channel::mux::{self, SendError};
+use futures::executor::block_on;
+use futures::stream::StreamExt;
+use futures_test::task::noop_context;
+use std::thread;
+
+#[test]
+fn routes_messages_by_key() {
+    let (tx, router) = mux::channel();
+    let mut a = router.receiver("a").unwrap();
+    let mut b = router.receiver("b").unwrap();
+    assert!(router.receiver("a").is_none());
+
+    tx.send("a", 1).unwrap();
+    tx.send("b", 2).unwrap();
+    tx.send("c", 3).unwrap();
+    tx.send("a", 4).unwrap();
+    drop(tx);
+
+    assert_eq!(block_on(a.by_ref().collect::<Vec<_>>()), vec![1, 4]);
+    assert_eq!(block_on(b.by_ref().collect::<Vec<_>>()), vec![2]);
+    assert_eq!(block_on(router.collect::<Vec<_>>()), vec![("c", 3)]);
+}
+
+#[test]
+fn new_receiver_takes_queued_messages_of_its_key() {
+    let (tx, mut router) = mux::channel();
+    tx.send(1, "x").unwrap();
+    tx.send(2, "y").unwrap();
+    tx.send(1, "z").unwrap();
+
+    assert_eq!(router.try_next().unwrap(), Some((1, "x")));
+    let mut one = router.receiver(1).unwrap();
+    assert_eq!(one.try_next().unwrap(), Some("z"));
+    assert!(one.try_next().is_err());
+    assert_eq!(router.try_next().unwrap(), Some((2, "y")));
+    assert!(router.try_next().is_err());
+}
+
+#[test]
+fn close_key_ends_only_that_key() {
+    let (tx, router) = mux::channel();
+    let mut a = router.receiver("a").unwrap();
+    let mut b = router.receiver("b").unwrap();
+
+    tx.send("a", 1).unwrap();
+    tx.close_key(&"a");
+    assert_eq!(tx.send("a", 2), Err(SendError(("a", 2))));
+    tx.send("b", 3).unwrap();
+
+    assert_eq!(a.try_next().unwrap(), Some(1));
+    assert_eq!(a.try_next().unwrap(), None);
+    assert_eq!(b.try_next().unwrap(), Some(3));
+
+    // Once its receiver is gone, the key is free again
+    drop(a);
+    assert!(!tx.has_receiver(&"a"));
+    tx.send("a", 4).unwrap();
+    let mut a = router.receiver("a").unwrap();
+    assert_eq!(*a.key(), "a");
+    assert_eq!(a.try_next().unwrap(), Some(4));
+}
+
+#[test]
+fn receiver_close_rejects_further_messages() {
+    let (tx, router) = mux::channel();
+    let mut a = router.receiver(0).unwrap();
+    tx.send(0, 1).unwrap();
+    a.close();
+    assert!(tx.send(0, 2).is_err());
+    assert_eq!(a.try_next().unwrap(), Some(1));
+    assert_eq!(a.try_next().unwrap(), None);
+}
+
+#[test]
+fn send_without_receiver_fails_after_router_dropped() {
+    let (tx, router) = mux::channel();
+    let mut a = router.receiver("a").unwrap();
+    drop(router);
+
+    tx.send("a", 1).unwrap();
+    assert_eq!(tx.send("b", 2), Err(SendError(("b", 2))));
+    assert_eq!(a.try_next().unwrap(), Some(1));
+}
+
+#[test]
+fn wakes_receivers_across_threads() {
+    let (tx, router) = mux::channel();
+    let mut a = router.receiver(0).unwrap();
+    let mut b = router.receiver(1).unwrap();
+    let mut cx = noop_context();
+    assert!(a.poll_next_unpin(&mut cx).is_pending());
+    assert!(b.poll_next_unpin(&mut cx).is_pending());
+
+    let t = thread::spawn(move || {
+        for i in 0..100 {
+            tx.send(i % 2, i).unwrap();
+        }
+    });
+
+    let evens = thread::spawn(move || block_on(a.collect::<Vec<_>>()));
+    let odds = block_on(b.collect::<Vec<_>>());
+    t.join().unwrap();
+    assert_eq!(evens.join().unwrap(), (0..100).step_by(2).collect::<Vec<_>>());
+    assert_eq!(odds, (1..100).step_by(2).collect::<Vec<_>>());
+}
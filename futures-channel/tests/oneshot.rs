@@ -2,7 +2,7 @@ use futures::channel::oneshot::{self, Sender};
 use futures::executor::block_on;
 use futures::future::{poll_fn, FutureExt};
 use futures::task::{Context, Poll};
-use futures_test::task::panic_waker_ref;
+use futures_test::task::{noop_waker_ref, panic_waker_ref};
 use std::sync::mpsc;
 use std::thread;
 
@@ -260,3 +260,41 @@ fn cancel_sends() {
 //         },
 //     }
 // }
+
+#[test]
+fn send_and_wait_resolves_on_receive() {
+    let (tx, rx) = oneshot::channel::<i32>();
+    let mut cx = Context::from_waker(noop_waker_ref());
+    let mut fut = tx.send_and_wait(1);
+    assert!(fut.poll_unpin(&mut cx).is_pending());
+
+    let t = thread::spawn(move || block_on(rx));
+    assert_eq!(block_on(fut), Ok(()));
+    assert_eq!(t.join().unwrap(), Ok(1));
+}
+
+#[test]
+fn send_and_wait_returns_value_if_not_received() {
+    let (tx, rx) = oneshot::channel::<i32>();
+    let fut = tx.send_and_wait(1);
+    let t = thread::spawn(move || drop(rx));
+    assert_eq!(block_on(fut), Err(1));
+    t.join().unwrap();
+
+    let (tx, rx) = oneshot::channel::<i32>();
+    drop(rx);
+    assert_eq!(block_on(tx.send_and_wait(2)), Err(2));
+}
+
+#[test]
+fn blocking_recv() {
+    let (tx, rx) = oneshot::channel::<i32>();
+    let t = thread::spawn(move || tx.send(1).unwrap());
+    assert_eq!(rx.blocking_recv(), Ok(1));
+    t.join().unwrap();
+
+    let (tx, rx) = oneshot::channel::<i32>();
+    let t = thread::spawn(move || drop(tx));
+    assert!(rx.blocking_recv().is_err());
+    t.join().unwrap();
+}
@@ -0,0 +1,80 @@
+use futures::channel::priority;
+use futures::executor::block_on;
+use futures::future::poll_fn;
+use futures::sink::SinkExt;
+use futures::stream::StreamExt;
+use futures_test::task::noop_context;
+use std::thread;
+
+#[test]
+fn highest_priority_first() {
+    let (mut tx, mut rx) = priority::channel(8);
+    tx.try_send(1, "low 1").unwrap();
+    tx.try_send(3, "high").unwrap();
+    tx.try_send(1, "low 2").unwrap();
+    tx.try_send(2, "mid").unwrap();
+    assert_eq!(rx.len(), 4);
+
+    assert_eq!(rx.try_next().unwrap(), Some("high"));
+    assert_eq!(rx.try_next().unwrap(), Some("mid"));
+    assert_eq!(rx.try_next().unwrap(), Some("low 1"));
+    assert_eq!(rx.try_next().unwrap(), Some("low 2"));
+    assert!(rx.try_next().is_err());
+
+    drop(tx);
+    assert_eq!(rx.try_next().unwrap(), None);
+}
+
+#[test]
+fn bounded_capacity() {
+    let (mut tx, mut rx) = priority::channel(2);
+    let mut tx2 = tx.clone();
+    let mut cx = noop_context();
+
+    assert!(tx.poll_ready(&mut cx).is_ready());
+    tx2.try_send(0, 1).unwrap();
+    // the slot reserved by `tx` is not available to `tx2`
+    assert!(tx2.try_send(0, 2).unwrap_err().is_full());
+    assert!(tx2.poll_ready(&mut cx).is_pending());
+    tx.start_send(0, 3).unwrap();
+
+    assert_eq!(block_on(rx.next()), Some(1));
+    assert!(tx2.poll_ready(&mut cx).is_ready());
+    tx2.start_send(5, 4).unwrap();
+    assert_eq!(block_on(rx.next()), Some(4));
+    assert_eq!(block_on(rx.next()), Some(3));
+}
+
+#[test]
+fn sink_across_threads() {
+    let (mut tx, rx) = priority::channel(1);
+    let t = thread::spawn(move || {
+        block_on(async {
+            for i in 0..10 {
+                tx.send((i % 2, i)).await.unwrap();
+            }
+        })
+    });
+
+    let items = block_on(rx.collect::<Vec<_>>());
+    t.join().unwrap();
+    let mut sorted = items.clone();
+    sorted.sort_unstable();
+    assert_eq!(sorted, (0..10).collect::<Vec<_>>());
+}
+
+#[test]
+fn close_rejects_sends() {
+    let (mut tx, mut rx) = priority::channel(4);
+    tx.try_send(0, 1).unwrap();
+    rx.close();
+    assert!(tx.is_closed());
+    assert!(tx.try_send(0, 2).unwrap_err().is_disconnected());
+    assert_eq!(block_on(rx.next()), Some(1));
+    assert_eq!(block_on(rx.next()), None);
+
+    let (mut tx, rx) = priority::channel::<u8, u8>(1);
+    drop(rx);
+    let res = block_on(poll_fn(|cx| tx.poll_ready(cx)));
+    assert!(res.unwrap_err().is_disconnected());
+}
@@ -0,0 +1,52 @@
+use futures::channel::ring::{self, SendError};
+use futures::executor::block_on;
+use futures::sink::SinkExt;
+use futures::stream::StreamExt;
+use futures_test::task::noop_context;
+use std::thread;
+
+#[test]
+fn drops_oldest_when_full() {
+    let (tx, mut rx) = ring::channel(3);
+    for i in 0..3 {
+        assert_eq!(tx.send(i), Ok(None));
+    }
+    assert_eq!(tx.send(3), Ok(Some(0)));
+    assert_eq!(tx.send(4), Ok(Some(1)));
+    assert_eq!(rx.dropped(), 2);
+    assert_eq!(rx.len(), 3);
+
+    assert_eq!(rx.try_next().unwrap(), Some(2));
+    assert_eq!(tx.send(5), Ok(None));
+    assert_eq!(block_on(rx.by_ref().take(3).collect::<Vec<_>>()), vec![3, 4, 5]);
+    assert!(rx.try_next().is_err());
+}
+
+#[test]
+fn wakes_receiver_across_threads() {
+    let (mut tx, mut rx) = ring::channel(16);
+    let mut cx = noop_context();
+    assert!(rx.poll_next_unpin(&mut cx).is_pending());
+
+    let t = thread::spawn(move || {
+        block_on(async {
+            for i in 0..5 {
+                SinkExt::send(&mut tx, i).await.unwrap();
+            }
+        })
+    });
+    assert_eq!(block_on(rx.collect::<Vec<_>>()), vec![0, 1, 2, 3, 4]);
+    t.join().unwrap();
+}
+
+#[test]
+fn send_fails_after_close() {
+    let (tx, mut rx) = ring::channel(2);
+    let tx2 = tx.clone();
+    tx.send(1).unwrap();
+    rx.close();
+    assert!(tx2.is_closed());
+    assert_eq!(tx.send(2), Err(SendError(2)));
+    assert_eq!(block_on(rx.next()), Some(1));
+    assert_eq!(block_on(rx.next()), None);
+}
@@ -0,0 +1,65 @@
+use futures::channel::watch::{self, SendError};
+use futures::executor::block_on;
+use futures::future::FutureExt;
+use futures_test::task::noop_context;
+use std::thread;
+
+#[test]
+fn receivers_see_latest_value() {
+    let (tx, mut rx) = watch::channel(0);
+    let mut rx2 = tx.subscribe();
+    assert_eq!(tx.receiver_count(), 2);
+    assert_eq!(rx.has_changed(), Ok(false));
+
+    tx.send(1).unwrap();
+    tx.send(2).unwrap();
+    assert_eq!(rx.has_changed(), Ok(true));
+    assert_eq!(*rx.borrow(), 2);
+    block_on(rx.changed()).unwrap();
+    assert_eq!(rx.has_changed(), Ok(false));
+
+    assert_eq!(*rx2.borrow_and_update(), 2);
+    assert_eq!(rx2.has_changed(), Ok(false));
+    assert_eq!(tx.send_replace(3), 2);
+    assert_eq!(*tx.borrow(), 3);
+}
+
+#[test]
+fn changed_waits_for_send() {
+    let (tx, mut rx) = watch::channel("init");
+    let mut cx = noop_context();
+    assert!(rx.changed().poll_unpin(&mut cx).is_pending());
+
+    let t = thread::spawn(move || {
+        tx.send("updated").unwrap();
+        tx
+    });
+    block_on(rx.changed()).unwrap();
+    assert_eq!(*rx.borrow(), "updated");
+    drop(t.join().unwrap());
+}
+
+#[test]
+fn changed_fails_after_sender_drop() {
+    let (tx, mut rx) = watch::channel(0);
+    let mut rx2 = rx.clone();
+    tx.send(1).unwrap();
+    drop(tx);
+
+    // the last value is still reported once
+    block_on(rx.changed()).unwrap();
+    assert!(block_on(rx.changed()).is_err());
+    assert_eq!(*rx.borrow(), 1);
+    assert!(block_on(rx2.changed()).is_ok());
+    assert!(rx2.has_changed().is_err());
+}
+
+#[test]
+fn send_fails_without_receivers() {
+    let (tx, rx) = watch::channel(0);
+    drop(rx);
+    assert!(tx.is_closed());
+    assert_eq!(tx.send(1), Err(SendError(1)));
+    tx.send_replace(2);
+    assert_eq!(*tx.subscribe().borrow(), 2);
+}
@@ -1,8 +1,11 @@
 use core::cell::UnsafeCell;
 use core::fmt;
-use core::sync::atomic::AtomicUsize;
-use core::sync::atomic::Ordering::{AcqRel, Acquire, Release};
+use core::sync::atomic::Ordering::{AcqRel, Acquire, Relaxed, Release};
+#[cfg(not(futures_portable_atomic))]
+use core::sync::atomic::{AtomicBool, AtomicUsize};
 use core::task::Waker;
+#[cfg(futures_portable_atomic)]
+use portable_atomic::{AtomicBool, AtomicUsize};
 
 /// A synchronization primitive for task wakeup.
 ///
@@ -89,6 +92,9 @@ use core::task::Waker;
 pub struct AtomicWaker {
     state: AtomicUsize,
     waker: UnsafeCell<Option<Waker>>,
+    // Whether the waker cell holds a waker, updated while holding the lock on
+    // it so that `is_registered` doesn't need to take the lock.
+    registered: AtomicBool,
 }
 
 // `AtomicWaker` is a multi-consumer, single-producer transfer cell. The cell
@@ -202,7 +208,11 @@ impl AtomicWaker {
         trait AssertSync: Sync {}
         impl AssertSync for Waker {}
 
-        Self { state: AtomicUsize::new(WAITING), waker: UnsafeCell::new(None) }
+        Self {
+            state: AtomicUsize::new(WAITING),
+            waker: UnsafeCell::new(None),
+            registered: AtomicBool::new(false),
+        }
     }
 
     /// Registers the waker to be notified on calls to `wake`.
@@ -218,6 +228,11 @@ impl AtomicWaker {
     /// calling `wake`. This will result in the `register` caller's current
     /// task being notified once.
     ///
+    /// If the registered waker would already wake the same task as `waker`,
+    /// as determined by [`Waker::will_wake`], it is kept rather than replaced
+    /// with a clone of `waker`, so that polling a future repeatedly from the
+    /// same task doesn't clone its waker each time.
+    ///
     /// This function is safe to call concurrently, but this is generally a bad
     /// idea. Concurrent calls to `register` will attempt to register different
     /// tasks to be notified. One of the callers will win and have its task set,
@@ -263,8 +278,13 @@ impl AtomicWaker {
         {
             WAITING => {
                 unsafe {
-                    // Locked acquired, update the waker cell
-                    *self.waker.get() = Some(waker.clone());
+                    // Locked acquired, update the waker cell, unless it
+                    // already holds a waker for the same task.
+                    match &*self.waker.get() {
+                        Some(old_waker) if old_waker.will_wake(waker) => {}
+                        _ => *self.waker.get() = Some(waker.clone()),
+                    }
+                    self.registered.store(true, Relaxed);
 
                     // Release the lock. If the state transitioned to include
                     // the `WAKING` bit, this means that at least one wake has
@@ -296,6 +316,7 @@ impl AtomicWaker {
                             // Take the waker to wake once the atomic operation has
                             // completed.
                             let waker = (*self.waker.get()).take().unwrap();
+                            self.registered.store(false, Relaxed);
 
                             // We need to return to WAITING state (clear our lock and
                             // concurrent WAKING flag). This needs to acquire all
@@ -370,6 +391,7 @@ impl AtomicWaker {
             WAITING => {
                 // The waking lock has been acquired.
                 let waker = unsafe { (*self.waker.get()).take() };
+                self.registered.store(false, Relaxed);
 
                 // Release the lock
                 self.state.fetch_and(!WAKING, Release);
@@ -391,6 +413,17 @@ impl AtomicWaker {
             }
         }
     }
+
+    /// Returns whether a waker is registered, that is whether `register` was
+    /// called since the last call to `wake` or `take`.
+    ///
+    /// The result may be stale by the time it is returned if other threads
+    /// call `register`, `wake` or `take` concurrently.
+    pub fn is_registered(&self) -> bool {
+        // The flag is only read, so that this doesn't contend with `register`
+        // for the lock on the waker cell, which would drop the registration.
+        self.registered.load(Relaxed)
+    }
 }
 
 impl Default for AtomicWaker {
@@ -0,0 +1,96 @@
+#![feature(test)]
+#![cfg(feature = "thread-pool")]
+
+extern crate test;
+use crate::test::Bencher;
+
+use futures::executor::{block_on, ThreadPool};
+use futures::future::{self, Future};
+use futures::task::{Context, Poll};
+use std::pin::Pin;
+
+struct Yield {
+    rem: usize,
+}
+
+impl Future for Yield {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if self.rem == 0 {
+            Poll::Ready(())
+        } else {
+            self.rem -= 1;
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }
+    }
+}
+
+#[bench]
+fn spawn_many(b: &mut Bencher) {
+    const NUM: usize = 10_000;
+
+    let pool = ThreadPool::new().unwrap();
+
+    b.iter(|| {
+        let handles: Vec<_> = (0..NUM).map(|i| pool.spawn(async move { i })).collect();
+        block_on(future::join_all(handles));
+    });
+}
+
+#[bench]
+fn spawn_many_from_task(b: &mut Bencher) {
+    const NUM: usize = 10_000;
+
+    let pool = ThreadPool::new().unwrap();
+
+    b.iter(|| {
+        let spawner = pool.clone();
+        let handle = pool.spawn(async move {
+            let handles: Vec<_> = (0..NUM).map(|i| spawner.spawn(async move { i })).collect();
+            future::join_all(handles).await
+        });
+        block_on(handle).unwrap();
+    });
+}
+
+#[bench]
+fn yield_many(b: &mut Bencher) {
+    const NUM: usize = 1_000;
+    const TASKS: usize = 100;
+
+    let pool = ThreadPool::new().unwrap();
+
+    b.iter(|| {
+        let handles: Vec<_> = (0..TASKS).map(|_| pool.spawn(Yield { rem: NUM })).collect();
+        block_on(future::join_all(handles));
+    });
+}
+
+#[bench]
+fn ping_pong(b: &mut Bencher) {
+    use futures::channel::mpsc;
+    use futures::{SinkExt, StreamExt};
+
+    const NUM: usize = 1_000;
+
+    let pool = ThreadPool::new().unwrap();
+
+    b.iter(|| {
+        let (mut ping_tx, mut ping_rx) = mpsc::channel::<usize>(1);
+        let (mut pong_tx, mut pong_rx) = mpsc::channel::<usize>(1);
+        pool.spawn_ok(async move {
+            while let Some(n) = ping_rx.next().await {
+                pong_tx.send(n).await.unwrap();
+            }
+        });
+        let handle = pool.spawn(async move {
+            for i in 0..NUM {
+                ping_tx.send(i).await.unwrap();
+                assert_eq!(pong_rx.next().await, Some(i));
+            }
+        });
+        block_on(handle).unwrap();
+    });
+}
@@ -2,12 +2,14 @@ use std::cell::Cell;
 use std::fmt;
 
 thread_local!(static ENTERED: Cell<bool> = Cell::new(false));
+thread_local!(static NESTING_ALLOWED: Cell<bool> = Cell::new(false));
 
 /// Represents an executor context.
 ///
 /// For more details, see [`enter` documentation](enter()).
 pub struct Enter {
-    _priv: (),
+    // Entered within another executor, with `allow_nested_block_on`.
+    nested: bool,
 }
 
 /// An error returned by `enter` if an execution scope has already been
@@ -51,19 +53,83 @@ impl std::error::Error for EnterError {}
 /// # Error
 ///
 /// Returns an error if the current thread is already marked, in which case the
-/// caller should panic with a tailored error message.
+/// caller should panic with a tailored error message, unless this is called
+/// from within [`allow_nested_block_on`].
 pub fn enter() -> Result<Enter, EnterError> {
     ENTERED.with(|c| {
         if c.get() {
-            Err(EnterError { _priv: () })
+            if NESTING_ALLOWED.with(Cell::get) {
+                Ok(Enter { nested: true })
+            } else {
+                Err(EnterError { _priv: () })
+            }
         } else {
             c.set(true);
 
-            Ok(Enter { _priv: () })
+            Ok(Enter { nested: false })
         }
     })
 }
 
+/// Returns `true` if the current thread is within the dynamic extent of an
+/// executor, such as a task run by [`LocalPool`](crate::LocalPool) or
+/// [`ThreadPool`](crate::ThreadPool), or a future passed to
+/// [`block_on`](crate::block_on).
+///
+/// Blocking the thread there, for example with `block_on`, stops the
+/// executor from running its other tasks, and may deadlock it.
+///
+/// ```
+/// use futures::executor::{block_on, is_entered};
+///
+/// assert!(!is_entered());
+/// block_on(async { assert!(is_entered()) });
+/// ```
+pub fn is_entered() -> bool {
+    ENTERED.with(Cell::get)
+}
+
+/// Runs `f`, allowing it to run executors, such as [`block_on`](crate::block_on),
+/// even though the current thread is already within the dynamic extent of an
+/// executor.
+///
+/// Executors otherwise panic when they are nested, because the outer executor
+/// can't make progress while the inner one blocks its thread: if the future
+/// run by the inner executor waits for a task of the outer one, the thread
+/// deadlocks. Only use this when the nested future is known not to wait for
+/// the outer executor.
+///
+/// Nesting stays allowed for the executors nested in those started by `f`.
+///
+/// ```
+/// use futures::executor::{allow_nested_block_on, block_on};
+///
+/// let output = block_on(async {
+///     allow_nested_block_on(|| block_on(async { 1 })) + 1
+/// });
+/// assert_eq!(output, 2);
+/// ```
+pub fn allow_nested_block_on<R, F: FnOnce() -> R>(f: F) -> R {
+    // Restores the previous value, even if `f` panics.
+    struct Reset(bool);
+
+    impl Drop for Reset {
+        fn drop(&mut self) {
+            NESTING_ALLOWED.with(|c| c.set(self.0));
+        }
+    }
+
+    let _reset = Reset(NESTING_ALLOWED.with(|c| c.replace(true)));
+    f()
+}
+
+impl Enter {
+    // Whether this was entered within another executor.
+    pub(crate) fn is_nested(&self) -> bool {
+        self.nested
+    }
+}
+
 impl fmt::Debug for Enter {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("Enter").finish()
@@ -72,6 +138,9 @@ impl fmt::Debug for Enter {
 
 impl Drop for Enter {
     fn drop(&mut self) {
+        if self.nested {
+            return;
+        }
         ENTERED.with(|c| {
             assert!(c.get());
             c.set(false);
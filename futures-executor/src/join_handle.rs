@@ -0,0 +1,263 @@
+use futures_core::future::Future;
+use futures_core::ready;
+use futures_core::task::{Context, Poll};
+use futures_util::task::AtomicWaker;
+use pin_project_lite::pin_project;
+use std::any::Any;
+use std::error::Error;
+use std::fmt;
+use std::mem;
+use std::panic::{self, AssertUnwindSafe};
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+
+/// A handle to a task spawned with [`ThreadPool::spawn`](crate::ThreadPool::spawn).
+///
+/// Awaiting the handle waits for the task to finish and returns its output,
+/// or a [`JoinError`] if it was aborted with [`abort`](JoinHandle::abort) or
+/// panicked.
+///
+/// Dropping the handle detaches the task, which keeps running, unless
+/// [`set_abort_on_drop`](JoinHandle::set_abort_on_drop) was used to abort it
+/// instead.
+#[cfg_attr(docsrs, doc(cfg(feature = "thread-pool")))]
+pub struct JoinHandle<T> {
+    inner: Arc<Inner<T>>,
+    abort_on_drop: bool,
+}
+
+/// The error returned by a [`JoinHandle`] for a task that did not run to
+/// completion.
+#[cfg_attr(docsrs, doc(cfg(feature = "thread-pool")))]
+pub struct JoinError {
+    repr: Repr,
+}
+
+enum Repr {
+    Cancelled,
+    // The mutex makes the error `Sync`, although the payload is only `Send`.
+    Panic(Mutex<Box<dyn Any + Send + 'static>>),
+}
+
+struct Inner<T> {
+    state: Mutex<State<T>>,
+    // Woken when the task finishes.
+    handle_waker: AtomicWaker,
+    // Woken when the task is aborted.
+    task_waker: AtomicWaker,
+    aborted: AtomicBool,
+    // The handle was dropped, so a panic of the task can't be delivered to it.
+    detached: AtomicBool,
+}
+
+enum State<T> {
+    Running,
+    Done(Result<T, JoinError>),
+    Taken,
+}
+
+impl<T> Inner<T> {
+    fn finish(&self, output: Result<T, JoinError>) {
+        {
+            let mut state = self.state.lock().unwrap();
+            if let State::Running = *state {
+                *state = State::Done(output);
+            }
+        }
+        self.handle_waker.wake();
+    }
+}
+
+impl<T> JoinHandle<T> {
+    /// Aborts the task.
+    ///
+    /// The task's future is dropped the next time the task would have been
+    /// polled, and awaiting the handle returns an error for which
+    /// [`JoinError::is_cancelled`] is `true`, unless the task had already
+    /// finished.
+    pub fn abort(&self) {
+        self.inner.aborted.store(true, Ordering::SeqCst);
+        self.inner.task_waker.wake();
+    }
+
+    /// Returns `true` if the task has finished, either because its future
+    /// completed or panicked, or because it was aborted.
+    pub fn is_finished(&self) -> bool {
+        match *self.inner.state.lock().unwrap() {
+            State::Running => false,
+            State::Done(_) | State::Taken => true,
+        }
+    }
+
+    /// Sets whether dropping this handle aborts the task. By default, it
+    /// detaches the task, which then keeps running.
+    pub fn set_abort_on_drop(&mut self, abort_on_drop: bool) {
+        self.abort_on_drop = abort_on_drop;
+    }
+}
+
+impl<T> Future for JoinHandle<T> {
+    type Output = Result<T, JoinError>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.inner.handle_waker.register(cx.waker());
+        let mut state = self.inner.state.lock().unwrap();
+        match mem::replace(&mut *state, State::Taken) {
+            State::Running => {
+                *state = State::Running;
+                Poll::Pending
+            }
+            State::Done(output) => Poll::Ready(output),
+            State::Taken => panic!("`JoinHandle` polled after completion"),
+        }
+    }
+}
+
+impl<T> Drop for JoinHandle<T> {
+    fn drop(&mut self) {
+        self.inner.detached.store(true, Ordering::SeqCst);
+        if self.abort_on_drop {
+            self.abort();
+        }
+    }
+}
+
+impl<T> fmt::Debug for JoinHandle<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("JoinHandle")
+            .field("finished", &self.is_finished())
+            .field("abort_on_drop", &self.abort_on_drop)
+            .finish()
+    }
+}
+
+impl JoinError {
+    fn cancelled() -> Self {
+        Self { repr: Repr::Cancelled }
+    }
+
+    fn panic(payload: Box<dyn Any + Send + 'static>) -> Self {
+        Self { repr: Repr::Panic(Mutex::new(payload)) }
+    }
+
+    /// Returns `true` if the task was aborted.
+    pub fn is_cancelled(&self) -> bool {
+        match self.repr {
+            Repr::Cancelled => true,
+            Repr::Panic(_) => false,
+        }
+    }
+
+    /// Returns `true` if the task panicked.
+    pub fn is_panic(&self) -> bool {
+        match self.repr {
+            Repr::Cancelled => false,
+            Repr::Panic(_) => true,
+        }
+    }
+
+    /// Consumes the error, returning the payload of the panic of the task.
+    ///
+    /// This can be passed to [`std::panic::resume_unwind`] to propagate the
+    /// panic.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the task did not panic.
+    pub fn into_panic(self) -> Box<dyn Any + Send + 'static> {
+        self.try_into_panic().expect("`JoinError` reason is not a panic")
+    }
+
+    /// Consumes the error, returning the payload of the panic of the task if
+    /// it panicked, or the error itself otherwise.
+    pub fn try_into_panic(self) -> Result<Box<dyn Any + Send + 'static>, Self> {
+        match self.repr {
+            Repr::Panic(payload) => {
+                Ok(payload.into_inner().unwrap_or_else(|poisoned| poisoned.into_inner()))
+            }
+            repr => Err(Self { repr }),
+        }
+    }
+}
+
+impl fmt::Debug for JoinError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.repr {
+            Repr::Cancelled => f.write_str("JoinError::Cancelled"),
+            Repr::Panic(_) => f.write_str("JoinError::Panic(..)"),
+        }
+    }
+}
+
+impl fmt::Display for JoinError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.repr {
+            Repr::Cancelled => f.write_str("task was cancelled"),
+            Repr::Panic(_) => f.write_str("task panicked"),
+        }
+    }
+}
+
+impl Error for JoinError {}
+
+// Finishes the task as cancelled if it is dropped before it completed.
+struct Completion<T> {
+    inner: Arc<Inner<T>>,
+}
+
+impl<T> Drop for Completion<T> {
+    fn drop(&mut self) {
+        self.inner.finish(Err(JoinError::cancelled()));
+    }
+}
+
+pin_project! {
+    /// The future run by the thread pool for a task with a `JoinHandle`.
+    pub(crate) struct JoinTask<Fut: Future> {
+        #[pin]
+        future: Fut,
+        completion: Completion<Fut::Output>,
+    }
+}
+
+impl<Fut: Future> Future for JoinTask<Fut> {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        let this = self.project();
+        let inner = &this.completion.inner;
+
+        inner.task_waker.register(cx.waker());
+        if inner.aborted.load(Ordering::SeqCst) {
+            inner.finish(Err(JoinError::cancelled()));
+            return Poll::Ready(());
+        }
+
+        let future = this.future;
+        let output = match panic::catch_unwind(AssertUnwindSafe(|| future.poll(cx))) {
+            Ok(output) => Ok(ready!(output)),
+            Err(payload) => {
+                if inner.detached.load(Ordering::SeqCst) {
+                    // Let the thread pool hand the panic to its panic handler.
+                    panic::resume_unwind(payload);
+                }
+                Err(JoinError::panic(payload))
+            }
+        };
+        inner.finish(output);
+        Poll::Ready(())
+    }
+}
+
+pub(crate) fn join_handle<Fut: Future>(future: Fut) -> (JoinTask<Fut>, JoinHandle<Fut::Output>) {
+    let inner = Arc::new(Inner {
+        state: Mutex::new(State::Running),
+        handle_waker: AtomicWaker::new(),
+        task_waker: AtomicWaker::new(),
+        aborted: AtomicBool::new(false),
+        detached: AtomicBool::new(false),
+    });
+    let task = JoinTask { future, completion: Completion { inner: inner.clone() } };
+    (task, JoinHandle { inner, abort_on_drop: false })
+}
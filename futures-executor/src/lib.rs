@@ -56,8 +56,16 @@
 #[cfg(feature = "std")]
 mod local_pool;
 #[cfg(feature = "std")]
-pub use crate::local_pool::{block_on, block_on_stream, BlockingStream, LocalPool, LocalSpawner};
+pub use crate::local_pool::{
+    block_on, block_on_stream, block_on_timeout, BlockingStream, LocalPool, LocalSpawner,
+};
 
+#[cfg(feature = "thread-pool")]
+#[cfg(feature = "std")]
+mod join_handle;
+#[cfg(feature = "thread-pool")]
+#[cfg(feature = "std")]
+mod scope;
 #[cfg(feature = "thread-pool")]
 #[cfg_attr(docsrs, doc(cfg(feature = "thread-pool")))]
 #[cfg(feature = "std")]
@@ -68,9 +76,22 @@ mod unpark_mutex;
 #[cfg(feature = "thread-pool")]
 #[cfg_attr(docsrs, doc(cfg(feature = "thread-pool")))]
 #[cfg(feature = "std")]
-pub use crate::thread_pool::{ThreadPool, ThreadPoolBuilder};
+pub use crate::join_handle::{JoinError, JoinHandle};
+#[cfg(feature = "thread-pool")]
+#[cfg_attr(docsrs, doc(cfg(feature = "thread-pool")))]
+#[cfg(feature = "std")]
+pub use crate::scope::Scope;
+#[cfg(feature = "thread-pool")]
+#[cfg_attr(docsrs, doc(cfg(feature = "thread-pool")))]
+#[cfg(feature = "std")]
+pub use crate::thread_pool::{Priority, ThreadPool, ThreadPoolBuilder};
+
+#[cfg(feature = "std")]
+mod metrics;
+#[cfg(feature = "std")]
+pub use crate::metrics::Metrics;
 
 #[cfg(feature = "std")]
 mod enter;
 #[cfg(feature = "std")]
-pub use crate::enter::{enter, Enter, EnterError};
+pub use crate::enter::{allow_nested_block_on, enter, is_entered, Enter, EnterError};
@@ -1,9 +1,13 @@
-use crate::enter;
+use crate::enter::{enter, Enter};
+use crate::metrics::Metrics;
 use futures_core::future::Future;
 use futures_core::stream::Stream;
 use futures_core::task::{Context, Poll};
 use futures_task::{waker_ref, ArcWake};
-use futures_task::{FutureObj, LocalFutureObj, LocalSpawn, Spawn, SpawnError};
+use futures_task::{
+    FutureObj, LocalFutureObj, LocalSpawn, LocalTrySpawn, Spawn, SpawnError, TrySpawn,
+    TrySpawnError,
+};
 use futures_util::pin_mut;
 use futures_util::stream::FuturesUnordered;
 use futures_util::stream::StreamExt;
@@ -15,6 +19,7 @@ use std::sync::{
     Arc,
 };
 use std::thread::{self, Thread};
+use std::time::{Duration, Instant};
 
 /// A single-threaded task pool for polling futures to completion.
 ///
@@ -31,6 +36,10 @@ use std::thread::{self, Thread};
 pub struct LocalPool {
     pool: FuturesUnordered<LocalFutureObj<'static, ()>>,
     incoming: Rc<Incoming>,
+    // Counters for `metrics`, not counting the incoming tasks.
+    tasks_spawned: u64,
+    tasks_completed: u64,
+    poll_time: Duration,
 }
 
 /// A handle to a [`LocalPool`](LocalPool) that implements
@@ -75,28 +84,70 @@ impl ArcWake for ThreadNotify {
     }
 }
 
+// Marks the current thread as running an executor, panicking if it already
+// is, unless this was allowed.
+fn enter_executor() -> Enter {
+    enter().expect(
+        "cannot block on a future from within another executor, as this blocks \
+         the thread of that executor and may deadlock it. `.await` the future \
+         instead, or spawn it and await its output. If it cannot wait for the \
+         other executor, wrap the call in `futures::executor::allow_nested_block_on`",
+    )
+}
+
+// Remembers a wakeup for the executor that a nested one was run within, as
+// the nested executor may have consumed it.
+fn leave_executor(enter: Enter, thread_notify: &ThreadNotify) {
+    if enter.is_nested() {
+        thread_notify.unparked.store(true, Ordering::Release);
+    }
+}
+
 // Set up and run a basic single-threaded spawner loop, invoking `f` on each
 // turn.
-fn run_executor<T, F: FnMut(&mut Context<'_>) -> Poll<T>>(mut f: F) -> T {
-    let _enter = enter().expect(
-        "cannot execute `LocalPool` executor from within \
-         another executor",
-    );
+fn run_executor<T, F: FnMut(&mut Context<'_>) -> Poll<T>>(f: F) -> T {
+    match run_executor_until(None, f) {
+        Some(t) => t,
+        None => unreachable!(),
+    }
+}
+
+// Like `run_executor`, but returns `None` once `deadline` has passed.
+fn run_executor_until<T, F: FnMut(&mut Context<'_>) -> Poll<T>>(
+    deadline: Option<Instant>,
+    mut f: F,
+) -> Option<T> {
+    let enter = enter_executor();
 
     CURRENT_THREAD_NOTIFY.with(|thread_notify| {
         let waker = waker_ref(thread_notify);
         let mut cx = Context::from_waker(&waker);
         loop {
             if let Poll::Ready(t) = f(&mut cx) {
-                return t;
+                leave_executor(enter, thread_notify);
+                return Some(t);
             }
+            let timeout = match deadline {
+                Some(deadline) => {
+                    let now = Instant::now();
+                    if now >= deadline {
+                        leave_executor(enter, thread_notify);
+                        return None;
+                    }
+                    Some(deadline - now)
+                }
+                None => None,
+            };
             // Consume the wakeup that occurred while executing `f`, if any.
             let unparked = thread_notify.unparked.swap(false, Ordering::Acquire);
             if !unparked {
                 // No wakeup occurred. It may occur now, right before parking,
                 // but in that case the token made available by `unpark()`
                 // is guaranteed to still be available and `park()` is a no-op.
-                thread::park();
+                match timeout {
+                    Some(timeout) => thread::park_timeout(timeout),
+                    None => thread::park(),
+                }
                 // When the thread is unparked, `unparked` will have been set
                 // and needs to be unset before the next call to `f` to avoid
                 // a redundant loop iteration.
@@ -107,22 +158,27 @@ fn run_executor<T, F: FnMut(&mut Context<'_>) -> Poll<T>>(mut f: F) -> T {
 }
 
 fn poll_executor<T, F: FnMut(&mut Context<'_>) -> T>(mut f: F) -> T {
-    let _enter = enter().expect(
-        "cannot execute `LocalPool` executor from within \
-         another executor",
-    );
+    let enter = enter_executor();
 
     CURRENT_THREAD_NOTIFY.with(|thread_notify| {
         let waker = waker_ref(thread_notify);
         let mut cx = Context::from_waker(&waker);
-        f(&mut cx)
+        let t = f(&mut cx);
+        leave_executor(enter, thread_notify);
+        t
     })
 }
 
 impl LocalPool {
     /// Create a new, empty pool of tasks.
     pub fn new() -> Self {
-        Self { pool: FuturesUnordered::new(), incoming: Default::default() }
+        Self {
+            pool: FuturesUnordered::new(),
+            incoming: Default::default(),
+            tasks_spawned: 0,
+            tasks_completed: 0,
+            poll_time: Duration::from_secs(0),
+        }
     }
 
     /// Get a clonable handle to the pool as a [`Spawn`].
@@ -130,6 +186,39 @@ impl LocalPool {
         LocalSpawner { incoming: Rc::downgrade(&self.incoming) }
     }
 
+    /// Returns a snapshot of the runtime counters of the pool.
+    ///
+    /// ```
+    /// use futures::executor::LocalPool;
+    /// use futures::future::{pending, ready};
+    /// use futures::task::LocalSpawnExt;
+    ///
+    /// let mut pool = LocalPool::new();
+    /// let spawner = pool.spawner();
+    ///
+    /// spawner.spawn_local(ready(())).unwrap();
+    /// spawner.spawn_local(pending()).unwrap();
+    /// pool.run_until_stalled();
+    ///
+    /// let metrics = pool.metrics();
+    /// assert_eq!(metrics.tasks_spawned(), 2);
+    /// assert_eq!(metrics.tasks_completed(), 1);
+    /// assert_eq!(metrics.queue_depth(), 1);
+    /// ```
+    ///
+    /// Since the pool runs its tasks on the thread calling its run methods,
+    /// [`busy_workers`](Metrics::busy_workers) is always 0.
+    pub fn metrics(&self) -> Metrics {
+        let incoming = self.incoming.borrow().len();
+        Metrics {
+            tasks_spawned: self.tasks_spawned + incoming as u64,
+            tasks_completed: self.tasks_completed,
+            queue_depth: self.pool.len() + incoming,
+            busy_workers: 0,
+            total_poll_time: self.poll_time,
+        }
+    }
+
     /// Run all tasks in the pool to completion.
     ///
     /// ```
@@ -183,6 +272,74 @@ impl LocalPool {
         })
     }
 
+    /// Runs all tasks in the pool until they are complete or `duration` has
+    /// elapsed, whichever comes first.
+    ///
+    /// Returns `true` if all tasks in the pool are complete, and `false` if
+    /// the time ran out first. The remaining tasks can continue with further
+    /// use of one of the pool's run or poll methods, so this can be called
+    /// repeatedly to interleave running the tasks with other work, such as
+    /// rendering frames.
+    ///
+    /// ```
+    /// use futures::executor::LocalPool;
+    /// use futures::future::pending;
+    /// use futures::task::LocalSpawnExt;
+    /// use std::time::Duration;
+    ///
+    /// let mut pool = LocalPool::new();
+    /// let spawner = pool.spawner();
+    ///
+    /// spawner.spawn_local(pending()).unwrap();
+    /// assert!(!pool.run_for(Duration::from_millis(10)));
+    /// ```
+    ///
+    /// The time is only checked in between polls of the tasks, so a task that
+    /// blocks the thread delays the return of this function.
+    pub fn run_for(&mut self, duration: Duration) -> bool {
+        let deadline = Instant::now().checked_add(duration);
+        run_executor_until(deadline, |cx| self.poll_pool(cx)).is_some()
+    }
+
+    /// Runs all the tasks in the pool until the given future completes or
+    /// `deadline` is reached, whichever comes first.
+    ///
+    /// Returns the output of the future, or `None` if the deadline was
+    /// reached first. Like [`run_until`](LocalPool::run_until), this may
+    /// leave incomplete tasks in the pool. To keep the future when the
+    /// deadline is reached, pass it by mutable reference.
+    ///
+    /// ```
+    /// use futures::executor::LocalPool;
+    /// use futures::future::{self, FutureExt};
+    /// use std::time::{Duration, Instant};
+    ///
+    /// let mut pool = LocalPool::new();
+    /// let mut my_app = future::pending::<()>().boxed_local();
+    ///
+    /// let deadline = Instant::now() + Duration::from_millis(10);
+    /// assert_eq!(pool.run_until_deadline(&mut my_app, deadline), None);
+    /// ```
+    ///
+    /// The time is only checked in between polls of the tasks and the future,
+    /// so a task that blocks the thread delays the return of this function.
+    pub fn run_until_deadline<F: Future>(
+        &mut self,
+        future: F,
+        deadline: Instant,
+    ) -> Option<F::Output> {
+        pin_mut!(future);
+
+        run_executor_until(Some(deadline), |cx| {
+            if let Poll::Ready(output) = future.as_mut().poll(cx) {
+                return Poll::Ready(output);
+            }
+
+            let _ = self.poll_pool(cx);
+            Poll::Pending
+        })
+    }
+
     /// Runs all tasks and returns after completing one future or until no more progress
     /// can be made. Returns `true` if one future was completed, `false` otherwise.
     ///
@@ -288,13 +445,20 @@ impl LocalPool {
         // empty the incoming queue of newly-spawned tasks
         {
             let mut incoming = self.incoming.borrow_mut();
+            self.tasks_spawned += incoming.len() as u64;
             for task in incoming.drain(..) {
                 self.pool.push(task)
             }
         }
 
         // try to execute the next ready future
-        self.pool.poll_next_unpin(cx)
+        let start = Instant::now();
+        let ret = self.pool.poll_next_unpin(cx);
+        self.poll_time += start.elapsed();
+        if let Poll::Ready(Some(())) = ret {
+            self.tasks_completed += 1;
+        }
+        ret
     }
 }
 
@@ -315,18 +479,44 @@ pub fn block_on<F: Future>(f: F) -> F::Output {
     run_executor(|cx| f.as_mut().poll(cx))
 }
 
+/// Run a future to completion on the current thread, giving up after
+/// `timeout`.
+///
+/// This function will block the caller until the given future has completed
+/// or `timeout` has elapsed, and returns `None` in the latter case. This is
+/// useful for programs and tests that must not hang forever on a future that
+/// never completes.
+///
+/// To keep the future when it times out, pass it by mutable reference.
+///
+/// ```
+/// use futures::executor::block_on_timeout;
+/// use futures::future;
+/// use std::time::Duration;
+///
+/// assert_eq!(block_on_timeout(future::ready(1), Duration::from_secs(1)), Some(1));
+/// assert_eq!(block_on_timeout(future::pending::<()>(), Duration::from_millis(10)), None);
+/// ```
+pub fn block_on_timeout<F: Future>(f: F, timeout: Duration) -> Option<F::Output> {
+    pin_mut!(f);
+    let deadline = Instant::now().checked_add(timeout);
+    run_executor_until(deadline, |cx| f.as_mut().poll(cx))
+}
+
 /// Turn a stream into a blocking iterator.
 ///
 /// When `next` is called on the resulting `BlockingStream`, the caller
 /// will be blocked until the next element of the `Stream` becomes available.
 pub fn block_on_stream<S: Stream + Unpin>(stream: S) -> BlockingStream<S> {
-    BlockingStream { stream }
+    BlockingStream { stream, done: false }
 }
 
 /// An iterator which blocks on values from a stream until they become available.
 #[derive(Debug)]
 pub struct BlockingStream<S: Stream + Unpin> {
     stream: S,
+    // The stream returned `None`.
+    done: bool,
 }
 
 impl<S: Stream + Unpin> Deref for BlockingStream<S> {
@@ -347,31 +537,116 @@ impl<S: Stream + Unpin> BlockingStream<S> {
     pub fn into_inner(self) -> S {
         self.stream
     }
+
+    /// Like [`next`](Iterator::next), but gives up after `timeout`.
+    ///
+    /// Returns `None` if `timeout` elapsed before the next element of the
+    /// stream became available, in which case the element is not lost and is
+    /// returned by a later call. Otherwise, returns `Some` of what `next`
+    /// returns.
+    ///
+    /// ```
+    /// use futures::channel::mpsc;
+    /// use futures::executor::block_on_stream;
+    /// use std::time::Duration;
+    ///
+    /// let (tx, rx) = mpsc::unbounded();
+    /// let mut iter = block_on_stream(rx);
+    ///
+    /// assert_eq!(iter.next_timeout(Duration::from_millis(10)), None);
+    /// tx.unbounded_send(1).unwrap();
+    /// assert_eq!(iter.next_timeout(Duration::from_secs(1)), Some(Some(1)));
+    /// drop(tx);
+    /// assert_eq!(iter.next_timeout(Duration::from_secs(1)), Some(None));
+    /// ```
+    pub fn next_timeout(&mut self, timeout: Duration) -> Option<Option<S::Item>> {
+        let deadline = Instant::now().checked_add(timeout);
+        let stream = &mut self.stream;
+        let item = run_executor_until(deadline, |cx| stream.poll_next_unpin(cx))?;
+        self.done = item.is_none();
+        Some(item)
+    }
+
+    /// Returns up to `max` elements of the stream that are available right
+    /// away, without blocking.
+    ///
+    /// This stops after `max` elements, at the first element that is not
+    /// available yet, or at the end of the stream, so that it returns even if
+    /// the stream always has an element ready.
+    ///
+    /// ```
+    /// use futures::channel::mpsc;
+    /// use futures::executor::block_on_stream;
+    /// use futures::stream;
+    ///
+    /// let (tx, rx) = mpsc::unbounded();
+    /// let mut iter = block_on_stream(rx);
+    ///
+    /// tx.unbounded_send(1).unwrap();
+    /// tx.unbounded_send(2).unwrap();
+    /// assert_eq!(iter.drain_ready(usize::MAX), [1, 2]);
+    /// assert_eq!(iter.drain_ready(usize::MAX), []);
+    ///
+    /// let mut iter = block_on_stream(stream::repeat(3));
+    /// assert_eq!(iter.drain_ready(2), [3, 3]);
+    /// ```
+    pub fn drain_ready(&mut self, max: usize) -> Vec<S::Item> {
+        let stream = &mut self.stream;
+        let (items, done) = poll_executor(|cx| {
+            let mut items = Vec::new();
+            while items.len() < max {
+                match stream.poll_next_unpin(cx) {
+                    Poll::Ready(Some(item)) => items.push(item),
+                    Poll::Ready(None) => return (items, true),
+                    Poll::Pending => break,
+                }
+            }
+            (items, false)
+        });
+        self.done = done;
+        items
+    }
 }
 
 impl<S: Stream + Unpin> Iterator for BlockingStream<S> {
     type Item = S::Item;
 
     fn next(&mut self) -> Option<Self::Item> {
-        LocalPool::new().run_until(self.stream.next())
+        let item = LocalPool::new().run_until(self.stream.next());
+        self.done = item.is_none();
+        item
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        self.stream.size_hint()
+        if self.done {
+            // The stream may not know that it ended.
+            (0, Some(0))
+        } else {
+            self.stream.size_hint()
+        }
     }
 }
 
 impl Spawn for LocalSpawner {
     fn spawn_obj(&self, future: FutureObj<'static, ()>) -> Result<(), SpawnError> {
-        if let Some(incoming) = self.incoming.upgrade() {
-            incoming.borrow_mut().push(future.into());
+        self.try_spawn_obj(future).map_err(SpawnError::from)
+    }
+
+    fn status(&self) -> Result<(), SpawnError> {
+        if self.incoming.upgrade().is_some() {
             Ok(())
         } else {
             Err(SpawnError::shutdown())
         }
     }
+}
 
-    fn status(&self) -> Result<(), SpawnError> {
+impl LocalSpawn for LocalSpawner {
+    fn spawn_local_obj(&self, future: LocalFutureObj<'static, ()>) -> Result<(), SpawnError> {
+        self.try_spawn_local_obj(future).map_err(SpawnError::from)
+    }
+
+    fn status_local(&self) -> Result<(), SpawnError> {
         if self.incoming.upgrade().is_some() {
             Ok(())
         } else {
@@ -380,21 +655,30 @@ impl Spawn for LocalSpawner {
     }
 }
 
-impl LocalSpawn for LocalSpawner {
-    fn spawn_local_obj(&self, future: LocalFutureObj<'static, ()>) -> Result<(), SpawnError> {
+impl TrySpawn for LocalSpawner {
+    fn try_spawn_obj(
+        &self,
+        future: FutureObj<'static, ()>,
+    ) -> Result<(), TrySpawnError<FutureObj<'static, ()>>> {
         if let Some(incoming) = self.incoming.upgrade() {
-            incoming.borrow_mut().push(future);
+            incoming.borrow_mut().push(future.into());
             Ok(())
         } else {
-            Err(SpawnError::shutdown())
+            Err(TrySpawnError::new(SpawnError::shutdown(), future))
         }
     }
+}
 
-    fn status_local(&self) -> Result<(), SpawnError> {
-        if self.incoming.upgrade().is_some() {
+impl LocalTrySpawn for LocalSpawner {
+    fn try_spawn_local_obj(
+        &self,
+        future: LocalFutureObj<'static, ()>,
+    ) -> Result<(), TrySpawnError<LocalFutureObj<'static, ()>>> {
+        if let Some(incoming) = self.incoming.upgrade() {
+            incoming.borrow_mut().push(future);
             Ok(())
         } else {
-            Err(SpawnError::shutdown())
+            Err(TrySpawnError::new(SpawnError::shutdown(), future))
         }
     }
 }
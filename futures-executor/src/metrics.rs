@@ -0,0 +1,48 @@
+use std::time::Duration;
+
+/// A snapshot of the runtime counters of an executor, returned by
+/// [`LocalPool::metrics`](crate::LocalPool::metrics) and `ThreadPool::metrics`.
+///
+/// The counters are read one after the other while the executor keeps
+/// running, so they are not guaranteed to be consistent with each other.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Metrics {
+    pub(crate) tasks_spawned: u64,
+    pub(crate) tasks_completed: u64,
+    pub(crate) queue_depth: usize,
+    pub(crate) busy_workers: usize,
+    pub(crate) total_poll_time: Duration,
+}
+
+impl Metrics {
+    /// Returns the number of tasks spawned on the executor.
+    pub fn tasks_spawned(&self) -> u64 {
+        self.tasks_spawned
+    }
+
+    /// Returns the number of tasks that have completed, including those
+    /// that panicked or were aborted.
+    pub fn tasks_completed(&self) -> u64 {
+        self.tasks_completed
+    }
+
+    /// Returns the number of tasks that are waiting to be polled.
+    ///
+    /// For a `LocalPool`, which only polls its tasks while one of its run
+    /// methods is called, this is the number of tasks that have not
+    /// completed.
+    pub fn queue_depth(&self) -> usize {
+        self.queue_depth
+    }
+
+    /// Returns the number of worker threads that are running a task.
+    pub fn busy_workers(&self) -> usize {
+        self.busy_workers
+    }
+
+    /// Returns the total time spent polling tasks, summed over all worker
+    /// threads.
+    pub fn total_poll_time(&self) -> Duration {
+        self.total_poll_time
+    }
+}
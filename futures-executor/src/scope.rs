@@ -0,0 +1,152 @@
+use crate::enter;
+use crate::ThreadPool;
+use futures_core::future::Future;
+use futures_core::task::{Context, Poll};
+use futures_task::FutureObj;
+use pin_project_lite::pin_project;
+use std::any::Any;
+use std::fmt;
+use std::marker::PhantomData;
+use std::mem;
+use std::panic::{self, AssertUnwindSafe};
+use std::pin::Pin;
+use std::sync::{Arc, Condvar, Mutex};
+
+/// A scope for spawning tasks that may borrow data from outside of it,
+/// created with [`ThreadPool::scope`].
+///
+/// The tasks may borrow the scope too, so that they can spawn tasks in it.
+#[cfg_attr(docsrs, doc(cfg(feature = "thread-pool")))]
+pub struct Scope<'scope, 'env: 'scope> {
+    pool: ThreadPool,
+    state: Arc<ScopeState>,
+    // Invariant over both lifetimes, like `std::thread::Scope`.
+    scope: PhantomData<&'scope mut &'scope ()>,
+    env: PhantomData<&'env mut &'env ()>,
+}
+
+struct ScopeState {
+    // The number of tasks whose future has not been dropped yet.
+    pending: Mutex<usize>,
+    // Notified when `pending` falls to 0.
+    condvar: Condvar,
+    // The payload of the first panic of a task.
+    panic: Mutex<Option<Box<dyn Any + Send + 'static>>>,
+}
+
+impl<'scope> Scope<'scope, '_> {
+    /// Spawns a task in the scope, that polls the given future to completion.
+    ///
+    /// The future may borrow data from outside of the scope, or the scope
+    /// itself, since [`ThreadPool::scope`] waits for it to be dropped before
+    /// returning.
+    pub fn spawn<Fut>(&'scope self, future: Fut)
+    where
+        Fut: Future<Output = ()> + Send + 'scope,
+    {
+        *self.state.pending.lock().unwrap() += 1;
+        let task = ScopedTask { future, _done: Done { state: self.state.clone() } };
+        let task: Pin<Box<dyn Future<Output = ()> + Send + 'scope>> = Box::pin(task);
+        // Safety: `ThreadPool::scope` doesn't return before the task is
+        // dropped, which decrements `pending`, so the data it borrows outlives
+        // it.
+        let task: Pin<Box<dyn Future<Output = ()> + Send + 'static>> =
+            unsafe { mem::transmute(task) };
+        self.pool.spawn_obj_ok(FutureObj::new(task));
+    }
+}
+
+impl fmt::Debug for Scope<'_, '_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Scope")
+            .field("pool", &self.pool)
+            .field("pending", &*self.state.pending.lock().unwrap())
+            .finish()
+    }
+}
+
+// Marks a task as done when it is dropped.
+struct Done {
+    state: Arc<ScopeState>,
+}
+
+impl Drop for Done {
+    fn drop(&mut self) {
+        let mut pending = self.state.pending.lock().unwrap();
+        *pending -= 1;
+        if *pending == 0 {
+            self.state.condvar.notify_all();
+        }
+    }
+}
+
+pin_project! {
+    struct ScopedTask<Fut> {
+        #[pin]
+        future: Fut,
+        // Declared after `future`, so that it is dropped after it.
+        _done: Done,
+    }
+}
+
+impl<Fut: Future<Output = ()>> Future for ScopedTask<Fut> {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        let this = self.project();
+        let future = this.future;
+        match panic::catch_unwind(AssertUnwindSafe(|| future.poll(cx))) {
+            Ok(poll) => poll,
+            Err(payload) => {
+                let mut panic = this._done.state.panic.lock().unwrap();
+                if panic.is_none() {
+                    *panic = Some(payload);
+                }
+                Poll::Ready(())
+            }
+        }
+    }
+}
+
+pub(crate) fn scope<'env, F, R>(pool: &ThreadPool, f: F) -> R
+where
+    F: for<'scope> FnOnce(&'scope Scope<'scope, 'env>) -> R,
+{
+    // Like `block_on`, refuse to block the thread of an executor. This must
+    // fail before any task is spawned, as the tasks must be waited for.
+    drop(enter().expect(
+        "cannot call `ThreadPool::scope` from within an executor, as this blocks \
+         the thread of that executor and may deadlock it",
+    ));
+
+    let scope = Scope {
+        pool: pool.clone(),
+        state: Arc::new(ScopeState {
+            pending: Mutex::new(0),
+            condvar: Condvar::new(),
+            panic: Mutex::new(None),
+        }),
+        scope: PhantomData,
+        env: PhantomData,
+    };
+    let res = panic::catch_unwind(AssertUnwindSafe(|| f(&scope)));
+
+    // Wait for the tasks even if `f` panicked, since they may borrow data
+    // that is dropped when unwinding.
+    let state = &scope.state;
+    let mut pending = state.pending.lock().unwrap();
+    while *pending > 0 {
+        pending = state.condvar.wait(pending).unwrap();
+    }
+    drop(pending);
+
+    match res {
+        Err(payload) => panic::resume_unwind(payload),
+        Ok(r) => {
+            if let Some(payload) = state.panic.lock().unwrap().take() {
+                panic::resume_unwind(payload);
+            }
+            r
+        }
+    }
+}
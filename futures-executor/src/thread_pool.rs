@@ -1,27 +1,51 @@
 use crate::enter;
+use crate::join_handle::{join_handle, JoinHandle};
+use crate::metrics::Metrics;
+use crate::scope::{self, Scope};
 use crate::unpark_mutex::UnparkMutex;
 use futures_core::future::Future;
 use futures_core::task::{Context, Poll};
 use futures_task::{waker_ref, ArcWake};
-use futures_task::{FutureObj, Spawn, SpawnError};
+use futures_task::{FutureObj, Spawn, SpawnError, TrySpawn, TrySpawnError};
 use futures_util::future::FutureExt;
+use std::any::Any;
+use std::cell::RefCell;
 use std::cmp;
+use std::collections::VecDeque;
 use std::fmt;
 use std::io;
-use std::sync::atomic::{AtomicUsize, Ordering};
-use std::sync::mpsc;
-use std::sync::{Arc, Mutex};
+use std::mem;
+use std::panic::{self, AssertUnwindSafe};
+use std::ptr;
+use std::sync::atomic::{self, AtomicUsize, Ordering};
+use std::sync::{Arc, Condvar, Mutex};
 use std::thread;
+use std::time::{Duration, Instant};
 
 /// A general-purpose thread pool for scheduling tasks that poll futures to
 /// completion.
 ///
-/// The thread pool multiplexes any number of tasks onto a fixed number of
-/// worker threads.
+/// The thread pool multiplexes any number of tasks onto a number of worker
+/// threads, which can be changed with
+/// [`set_pool_size`](ThreadPool::set_pool_size) or scaled automatically, as
+/// configured by the [`ThreadPoolBuilder`].
+///
+/// Each worker thread has a queue of its own for the tasks spawned or woken
+/// up while it runs a task, and takes tasks from the queues of the other
+/// worker threads when it runs out of them.
 ///
 /// This type is a clonable handle to the threadpool itself.
 /// Cloning it will only create a new reference, not a new threadpool.
 ///
+/// Tasks are run in order of their [`Priority`], so that latency-critical
+/// tasks spawned with [`spawn_with_priority`](ThreadPool::spawn_with_priority)
+/// don't wait behind background work.
+///
+/// A task that panics is dropped without affecting the other tasks or the
+/// worker thread it ran on. See
+/// [`ThreadPoolBuilder::panic_handler`](ThreadPoolBuilder::panic_handler) for
+/// what happens to the panic.
+///
 /// This type is only available when the `thread-pool` feature of this
 /// library is activated.
 #[cfg_attr(docsrs, doc(cfg(feature = "thread-pool")))]
@@ -36,25 +60,81 @@ pub struct ThreadPool {
 #[cfg_attr(docsrs, doc(cfg(feature = "thread-pool")))]
 pub struct ThreadPoolBuilder {
     pool_size: usize,
+    min_pool_size: Option<usize>,
+    max_pool_size: Option<usize>,
+    idle_timeout: Option<Duration>,
     stack_size: usize,
     name_prefix: Option<String>,
     after_start: Option<Arc<dyn Fn(usize) + Send + Sync>>,
     before_stop: Option<Arc<dyn Fn(usize) + Send + Sync>>,
+    panic_handler: Option<Arc<PanicHandler>>,
+}
+
+/// The priority of a task spawned on a [`ThreadPool`].
+///
+/// A worker thread always runs a task of the highest priority waiting in its
+/// queues, so a steady stream of tasks of high priority starves the tasks of
+/// lower priority. Tasks of the same priority run in the order they are
+/// scheduled in.
+///
+/// This type is only available when the `thread-pool` feature of this
+/// library is activated.
+#[cfg_attr(docsrs, doc(cfg(feature = "thread-pool")))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Priority {
+    /// For background work that may wait behind all other tasks.
+    Low,
+    /// The priority of tasks spawned without one.
+    Normal,
+    /// For latency-critical tasks, such as health checks.
+    High,
+}
+
+impl Default for Priority {
+    fn default() -> Self {
+        Self::Normal
+    }
 }
 
+type PanicHandler = dyn Fn(Box<dyn Any + Send>) + Send + Sync;
+
 trait AssertSendSync: Send + Sync {}
 impl AssertSendSync for ThreadPool {}
 
 struct PoolState {
-    tx: Mutex<mpsc::Sender<Message>>,
-    rx: Mutex<mpsc::Receiver<Message>>,
+    // Tasks scheduled from outside of the worker threads.
+    injector: Mutex<RunQueue>,
+    // Notified, with `injector` locked, when there are tasks to run or
+    // worker threads to stop.
+    condvar: Condvar,
+    workers: Mutex<Vec<Arc<Worker>>>,
     cnt: AtomicUsize,
-    size: usize,
+    // The number of worker threads, not counting those asked to stop.
+    size: AtomicUsize,
+    // The number of worker threads asked to stop that have not stopped yet.
+    stopping: AtomicUsize,
+    // The number of worker threads waiting for a task.
+    idle: AtomicUsize,
+    next_idx: AtomicUsize,
+    spawned: AtomicUsize,
+    completed: AtomicUsize,
+    // The number of worker threads running a task.
+    busy: AtomicUsize,
+    // The time spent running tasks by the worker threads that stopped.
+    stopped_poll_time: Mutex<Duration>,
+    min_size: usize,
+    max_size: usize,
+    idle_timeout: Option<Duration>,
+    stack_size: usize,
+    name_prefix: Option<String>,
+    after_start: Option<Arc<dyn Fn(usize) + Send + Sync>>,
+    before_stop: Option<Arc<dyn Fn(usize) + Send + Sync>>,
+    panic_handler: Option<Arc<PanicHandler>>,
 }
 
 impl fmt::Debug for ThreadPool {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_struct("ThreadPool").field("size", &self.state.size).finish()
+        f.debug_struct("ThreadPool").field("size", &self.pool_size()).finish()
     }
 }
 
@@ -62,14 +142,75 @@ impl fmt::Debug for ThreadPoolBuilder {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("ThreadPoolBuilder")
             .field("pool_size", &self.pool_size)
+            .field("min_pool_size", &self.min_pool_size)
+            .field("max_pool_size", &self.max_pool_size)
+            .field("idle_timeout", &self.idle_timeout)
             .field("name_prefix", &self.name_prefix)
             .finish()
     }
 }
 
-enum Message {
-    Run(Task),
-    Close,
+// A queue with a level per priority, from which tasks of higher priority are
+// taken first.
+struct RunQueue {
+    levels: [VecDeque<Task>; 3],
+}
+
+impl RunQueue {
+    fn new() -> Self {
+        Self { levels: [VecDeque::new(), VecDeque::new(), VecDeque::new()] }
+    }
+
+    fn push(&mut self, task: Task) {
+        self.levels[task.priority as usize].push_back(task);
+    }
+
+    fn pop(&mut self) -> Option<Task> {
+        self.levels.iter_mut().rev().find_map(VecDeque::pop_front)
+    }
+
+    fn len(&self) -> usize {
+        self.levels.iter().map(VecDeque::len).sum()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.levels.iter().all(VecDeque::is_empty)
+    }
+
+    // The level of the tasks that `pop` would take from.
+    fn top_level(&self) -> Option<usize> {
+        self.levels.iter().rposition(|level| !level.is_empty())
+    }
+
+    // Takes the second half of the tasks of each level.
+    fn split_off_half(&mut self) -> Self {
+        let mut other = Self::new();
+        for (level, other) in self.levels.iter_mut().zip(other.levels.iter_mut()) {
+            *other = level.split_off(level.len() / 2);
+        }
+        other
+    }
+
+    fn append(&mut self, other: &mut Self) {
+        for (level, other) in self.levels.iter_mut().zip(other.levels.iter_mut()) {
+            level.append(other);
+        }
+    }
+}
+
+struct Worker {
+    idx: usize,
+    // The local queue of the worker thread.
+    queue: Mutex<RunQueue>,
+    // The time spent running tasks.
+    poll_time: Mutex<Duration>,
+}
+
+thread_local! {
+    // The pool of the worker thread running on this thread, if any, and the
+    // worker thread.
+    static CURRENT_WORKER: RefCell<Option<(*const PoolState, Arc<Worker>)>> =
+        RefCell::new(None);
 }
 
 impl ThreadPool {
@@ -96,12 +237,18 @@ impl ThreadPool {
     /// > **Note**: This method is similar to `Spawn::spawn_obj`, except that
     /// >           it is guaranteed to always succeed.
     pub fn spawn_obj_ok(&self, future: FutureObj<'static, ()>) {
+        self.spawn_obj_with_priority(future, Priority::Normal)
+    }
+
+    fn spawn_obj_with_priority(&self, future: FutureObj<'static, ()>, priority: Priority) {
+        self.state.spawned.fetch_add(1, Ordering::Relaxed);
         let task = Task {
             future,
             wake_handle: Arc::new(WakeHandle { exec: self.clone(), mutex: UnparkMutex::new() }),
             exec: self.clone(),
+            priority,
         };
-        self.state.send(Message::Run(task));
+        self.run(task);
     }
 
     /// Spawns a task that polls the given future with output `()` to
@@ -124,6 +271,191 @@ impl ThreadPool {
     {
         self.spawn_obj_ok(FutureObj::new(Box::new(future)))
     }
+
+    /// Spawns a task that polls the given future to completion, and returns
+    /// a [`JoinHandle`] to await its output or abort it.
+    ///
+    /// Dropping the handle detaches the task, which keeps running.
+    ///
+    /// ```
+    /// use futures::executor::{block_on, ThreadPool};
+    ///
+    /// let pool = ThreadPool::new().unwrap();
+    ///
+    /// let handle = pool.spawn(async { 1 + 2 });
+    /// assert_eq!(block_on(handle).unwrap(), 3);
+    /// ```
+    pub fn spawn<Fut>(&self, future: Fut) -> JoinHandle<Fut::Output>
+    where
+        Fut: Future + Send + 'static,
+        Fut::Output: Send + 'static,
+    {
+        self.spawn_with_priority(future, Priority::Normal)
+    }
+
+    /// Spawns a task like [`spawn`](ThreadPool::spawn), that is run ahead of
+    /// the tasks of lower priority, and after those of higher priority.
+    ///
+    /// The task keeps its priority each time it is woken up.
+    ///
+    /// ```
+    /// use futures::executor::{block_on, Priority, ThreadPool};
+    ///
+    /// let pool = ThreadPool::new().unwrap();
+    ///
+    /// let handle = pool.spawn_with_priority(async { "healthy" }, Priority::High);
+    /// assert_eq!(block_on(handle).unwrap(), "healthy");
+    /// ```
+    pub fn spawn_with_priority<Fut>(
+        &self,
+        future: Fut,
+        priority: Priority,
+    ) -> JoinHandle<Fut::Output>
+    where
+        Fut: Future + Send + 'static,
+        Fut::Output: Send + 'static,
+    {
+        let (task, handle) = join_handle(future);
+        self.spawn_obj_with_priority(FutureObj::new(Box::new(task)), priority);
+        handle
+    }
+
+    /// Creates a scope for spawning tasks that may borrow data from outside
+    /// of it, and runs `f` with it.
+    ///
+    /// This blocks the current thread until all the tasks spawned in the
+    /// scope have been dropped, which they are once they complete. The panic
+    /// of `f` is then resumed if it panicked, or else the first panic of the
+    /// tasks, if any.
+    ///
+    /// A task that is never woken up again, and whose waker is not dropped,
+    /// blocks the thread forever.
+    ///
+    /// ```
+    /// use futures::executor::ThreadPool;
+    /// use std::sync::atomic::{AtomicUsize, Ordering};
+    ///
+    /// let pool = ThreadPool::new().unwrap();
+    /// let data = vec![1, 2, 3, 4];
+    /// let sum = AtomicUsize::new(0);
+    ///
+    /// pool.scope(|s| {
+    ///     for chunk in data.chunks(2) {
+    ///         let sum = &sum;
+    ///         s.spawn(async move {
+    ///             sum.fetch_add(chunk.iter().sum(), Ordering::SeqCst);
+    ///         });
+    ///     }
+    /// });
+    /// assert_eq!(sum.into_inner(), 10);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Like [`block_on`](crate::block_on), panics if called from within an
+    /// executor, such as a task of this pool, as blocking its thread may
+    /// deadlock it.
+    pub fn scope<'env, F, R>(&self, f: F) -> R
+    where
+        F: for<'scope> FnOnce(&'scope Scope<'scope, 'env>) -> R,
+    {
+        scope::scope(self, f)
+    }
+
+    /// Returns a snapshot of the runtime counters of the pool.
+    ///
+    /// ```
+    /// use futures::executor::{block_on, ThreadPool};
+    ///
+    /// let pool = ThreadPool::new().unwrap();
+    /// block_on(pool.spawn(async {})).unwrap();
+    ///
+    /// let metrics = pool.metrics();
+    /// assert_eq!(metrics.tasks_spawned(), 1);
+    /// assert!(metrics.tasks_completed() <= 1);
+    /// ```
+    pub fn metrics(&self) -> Metrics {
+        let state = &self.state;
+        let (queue_depth, poll_time) = {
+            let workers = state.workers.lock().unwrap();
+            let mut queue_depth = 0;
+            let mut poll_time = *state.stopped_poll_time.lock().unwrap();
+            for worker in workers.iter() {
+                queue_depth += worker.queue.lock().unwrap().len();
+                poll_time += *worker.poll_time.lock().unwrap();
+            }
+            (queue_depth, poll_time)
+        };
+        Metrics {
+            tasks_spawned: state.spawned.load(Ordering::Relaxed) as u64,
+            tasks_completed: state.completed.load(Ordering::Relaxed) as u64,
+            queue_depth: queue_depth + state.injector.lock().unwrap().len(),
+            busy_workers: state.busy.load(Ordering::SeqCst),
+            total_poll_time: poll_time,
+        }
+    }
+
+    /// Returns the number of worker threads in the pool.
+    ///
+    /// This does not count worker threads that were asked to stop, but
+    /// are still finishing the task they are running.
+    pub fn pool_size(&self) -> usize {
+        self.state.size.load(Ordering::SeqCst)
+    }
+
+    /// Changes the number of worker threads in the pool to `size`.
+    ///
+    /// New worker threads are started right away. Worker threads are
+    /// stopped once they are done with the task they are running, and the
+    /// tasks waiting to run are kept, to be run by the remaining threads.
+    ///
+    /// The minimum and maximum pool sizes of the [`ThreadPoolBuilder`] only
+    /// bound automatic resizing, so `size` may be outside of them.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a worker thread could not be started. The pool
+    /// keeps the worker threads that were started.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size == 0`.
+    pub fn set_pool_size(&self, size: usize) -> Result<(), io::Error> {
+        assert!(size > 0);
+        let old_size = self.state.size.swap(size, Ordering::SeqCst);
+        if size < old_size {
+            self.state.stop_workers(old_size - size);
+        }
+        for started in old_size..size {
+            if let Err(e) = PoolState::start_worker(&self.state) {
+                self.state.size.fetch_sub(size - started, Ordering::SeqCst);
+                return Err(e);
+            }
+        }
+        Ok(())
+    }
+
+    // Schedules `task`, starting a new worker thread for it if all of them
+    // are busy and the pool may grow.
+    fn run(&self, task: Task) {
+        let state = &self.state;
+        state.push(task);
+        if state.idle.load(Ordering::SeqCst) > 0 {
+            return;
+        }
+        let mut size = state.size.load(Ordering::SeqCst);
+        while size < state.max_size {
+            match state.size.compare_exchange(size, size + 1, Ordering::SeqCst, Ordering::SeqCst) {
+                Ok(_) => {
+                    if PoolState::start_worker(state).is_err() {
+                        state.size.fetch_sub(1, Ordering::SeqCst);
+                    }
+                    return;
+                }
+                Err(actual) => size = actual,
+            }
+        }
+    }
 }
 
 impl Spawn for ThreadPool {
@@ -133,32 +465,223 @@ impl Spawn for ThreadPool {
     }
 }
 
+impl TrySpawn for ThreadPool {
+    fn try_spawn_obj(
+        &self,
+        future: FutureObj<'static, ()>,
+    ) -> Result<(), TrySpawnError<FutureObj<'static, ()>>> {
+        self.spawn_obj_ok(future);
+        Ok(())
+    }
+}
+
 impl PoolState {
-    fn send(&self, msg: Message) {
-        self.tx.lock().unwrap().send(msg).unwrap();
+    // Pushes `task` to the local queue of the current worker thread, or to
+    // the injector if this is not a worker thread of this pool.
+    fn push(&self, task: Task) {
+        let mut task = Some(task);
+        CURRENT_WORKER.with(|worker| {
+            if let Some((pool, worker)) = &*worker.borrow() {
+                if ptr::eq(*pool, self) {
+                    worker.queue.lock().unwrap().push(task.take().unwrap());
+                }
+            }
+        });
+
+        match task {
+            Some(task) => {
+                let mut injector = self.injector.lock().unwrap();
+                injector.push(task);
+                if self.idle.load(Ordering::SeqCst) > 0 {
+                    self.condvar.notify_one();
+                }
+            }
+            None => {
+                // Pairs with the fence in `work`, so that either an idle
+                // worker thread finds the task, or it is notified here.
+                atomic::fence(Ordering::SeqCst);
+                if self.idle.load(Ordering::SeqCst) > 0 {
+                    let _injector = self.injector.lock().unwrap();
+                    self.condvar.notify_one();
+                }
+            }
+        }
     }
 
-    fn work(
-        &self,
-        idx: usize,
-        after_start: Option<Arc<dyn Fn(usize) + Send + Sync>>,
-        before_stop: Option<Arc<dyn Fn(usize) + Send + Sync>>,
-    ) {
+    fn stop_workers(&self, count: usize) {
+        self.stopping.fetch_add(count, Ordering::SeqCst);
+        let _injector = self.injector.lock().unwrap();
+        self.condvar.notify_all();
+    }
+
+    // Takes one of the stop requests, if any.
+    fn take_stop(&self) -> bool {
+        let mut stopping = self.stopping.load(Ordering::SeqCst);
+        while stopping > 0 {
+            match self.stopping.compare_exchange(
+                stopping,
+                stopping - 1,
+                Ordering::SeqCst,
+                Ordering::SeqCst,
+            ) {
+                Ok(_) => return true,
+                Err(actual) => stopping = actual,
+            }
+        }
+        false
+    }
+
+    // Takes a task from the local queue, or else from the injector, or else
+    // steals half of the tasks of another worker thread. The injector comes
+    // first if it has tasks of higher priority than the local queue.
+    fn next_task(&self, worker: &Worker) -> Option<Task> {
+        // The queues are not locked at the same time, so that this can't
+        // deadlock with a worker thread handing its tasks over at exit.
+        let local = worker.queue.lock().unwrap().top_level();
+        let injected = self.injector.lock().unwrap().top_level();
+        if injected > local {
+            if let Some(task) = self.injector.lock().unwrap().pop() {
+                return Some(task);
+            }
+        }
+        if let Some(task) = worker.queue.lock().unwrap().pop() {
+            return Some(task);
+        }
+        if let Some(task) = self.injector.lock().unwrap().pop() {
+            return Some(task);
+        }
+        self.steal(worker)
+    }
+
+    fn steal(&self, worker: &Worker) -> Option<Task> {
+        let mut stolen = {
+            let workers = self.workers.lock().unwrap();
+            // Start after this worker thread so that they don't all steal
+            // from the same one.
+            let start = workers.iter().position(|w| w.idx == worker.idx).unwrap_or(0);
+            let victims = workers.iter().cycle().skip(start + 1).take(workers.len());
+            let mut stolen = RunQueue::new();
+            for victim in victims {
+                if victim.idx == worker.idx {
+                    continue;
+                }
+                let mut queue = victim.queue.lock().unwrap();
+                if !queue.is_empty() {
+                    stolen = queue.split_off_half();
+                    break;
+                }
+            }
+            stolen
+        };
+        let task = stolen.pop();
+        if !stolen.is_empty() {
+            worker.queue.lock().unwrap().append(&mut stolen);
+        }
+        task
+    }
+
+    // Starts a worker thread, which must already be counted in `size`.
+    fn start_worker(state: &Arc<Self>) -> Result<(), io::Error> {
+        let idx = state.next_idx.fetch_add(1, Ordering::Relaxed);
+        let mut thread_builder = thread::Builder::new();
+        if let Some(ref name_prefix) = state.name_prefix {
+            thread_builder = thread_builder.name(format!("{}{}", name_prefix, idx));
+        }
+        if state.stack_size > 0 {
+            thread_builder = thread_builder.stack_size(state.stack_size);
+        }
+        let state = state.clone();
+        thread_builder.spawn(move || state.work(idx))?;
+        Ok(())
+    }
+
+    fn work(&self, idx: usize) {
         let _scope = enter().unwrap();
-        if let Some(after_start) = after_start {
+        let worker = Arc::new(Worker {
+            idx,
+            queue: Mutex::new(RunQueue::new()),
+            poll_time: Mutex::new(Duration::from_secs(0)),
+        });
+        self.workers.lock().unwrap().push(worker.clone());
+        CURRENT_WORKER.with(|current| {
+            *current.borrow_mut() = Some((self as *const Self, worker.clone()));
+        });
+        if let Some(after_start) = &self.after_start {
             after_start(idx);
         }
-        loop {
-            let msg = self.rx.lock().unwrap().recv().unwrap();
-            match msg {
-                Message::Run(task) => task.run(),
-                Message::Close => break,
+
+        while !self.take_stop() {
+            if let Some(task) = self.next_task(&worker) {
+                self.busy.fetch_add(1, Ordering::SeqCst);
+                let start = Instant::now();
+                task.run();
+                *worker.poll_time.lock().unwrap() += start.elapsed();
+                self.busy.fetch_sub(1, Ordering::SeqCst);
+                continue;
+            }
+
+            self.idle.fetch_add(1, Ordering::SeqCst);
+            atomic::fence(Ordering::SeqCst);
+            let mut injector = self.injector.lock().unwrap();
+            // Look for tasks again with `injector` locked, so that a task
+            // scheduled since then comes with a notification.
+            let mut timed_out = false;
+            if injector.is_empty()
+                && self.stopping.load(Ordering::SeqCst) == 0
+                && !self.has_stealable_tasks()
+            {
+                match self.idle_timeout {
+                    Some(timeout) => {
+                        let (guard, res) = self.condvar.wait_timeout(injector, timeout).unwrap();
+                        injector = guard;
+                        timed_out = res.timed_out();
+                    }
+                    None => injector = self.condvar.wait(injector).unwrap(),
+                }
+            }
+            drop(injector);
+            self.idle.fetch_sub(1, Ordering::SeqCst);
+            if timed_out && self.try_shrink() {
+                break;
             }
         }
-        if let Some(before_stop) = before_stop {
+
+        // Hand the tasks left in the local queue over to the other worker
+        // threads.
+        CURRENT_WORKER.with(|current| *current.borrow_mut() = None);
+        {
+            // Hold the lock so that `metrics` sees the poll time once.
+            let mut workers = self.workers.lock().unwrap();
+            workers.retain(|w| w.idx != idx);
+            *self.stopped_poll_time.lock().unwrap() += *worker.poll_time.lock().unwrap();
+        }
+        let mut tasks = mem::replace(&mut *worker.queue.lock().unwrap(), RunQueue::new());
+        if !tasks.is_empty() {
+            self.injector.lock().unwrap().append(&mut tasks);
+            self.condvar.notify_all();
+        }
+
+        if let Some(before_stop) = &self.before_stop {
             before_stop(idx);
         }
     }
+
+    fn has_stealable_tasks(&self) -> bool {
+        self.workers.lock().unwrap().iter().any(|worker| !worker.queue.lock().unwrap().is_empty())
+    }
+
+    // Removes an idle worker thread from `size`, unless the pool is at its
+    // minimum size.
+    fn try_shrink(&self) -> bool {
+        let mut size = self.size.load(Ordering::SeqCst);
+        while size > self.min_size {
+            match self.size.compare_exchange(size, size - 1, Ordering::SeqCst, Ordering::SeqCst) {
+                Ok(_) => return true,
+                Err(actual) => size = actual,
+            }
+        }
+        false
+    }
 }
 
 impl Clone for ThreadPool {
@@ -171,9 +694,7 @@ impl Clone for ThreadPool {
 impl Drop for ThreadPool {
     fn drop(&mut self) {
         if self.state.cnt.fetch_sub(1, Ordering::Relaxed) == 1 {
-            for _ in 0..self.state.size {
-                self.state.send(Message::Close);
-            }
+            self.state.stop_workers(self.pool_size());
         }
     }
 }
@@ -185,10 +706,14 @@ impl ThreadPoolBuilder {
     pub fn new() -> Self {
         Self {
             pool_size: cmp::max(1, num_cpus::get()),
+            min_pool_size: None,
+            max_pool_size: None,
+            idle_timeout: None,
             stack_size: 0,
             name_prefix: None,
             after_start: None,
             before_stop: None,
+            panic_handler: None,
         }
     }
 
@@ -206,6 +731,48 @@ impl ThreadPoolBuilder {
         self
     }
 
+    /// Set the minimum size of a future ThreadPool.
+    ///
+    /// When an [`idle_timeout`](ThreadPoolBuilder::idle_timeout) is set,
+    /// worker threads that stay idle for that long are stopped, as long as
+    /// the pool has more than this number of worker threads. By default,
+    /// this is equal to the pool size, so the pool doesn't shrink.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size == 0`.
+    pub fn min_pool_size(&mut self, size: usize) -> &mut Self {
+        assert!(size > 0);
+        self.min_pool_size = Some(size);
+        self
+    }
+
+    /// Set the maximum size of a future ThreadPool.
+    ///
+    /// When a task is scheduled while all worker threads are busy, a new
+    /// worker thread is started for it, as long as the pool has less than
+    /// this number of worker threads. By default, this is equal to the pool
+    /// size, so the pool doesn't grow.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size == 0`.
+    pub fn max_pool_size(&mut self, size: usize) -> &mut Self {
+        assert!(size > 0);
+        self.max_pool_size = Some(size);
+        self
+    }
+
+    /// Set how long worker threads of a future ThreadPool wait for a task
+    /// before stopping, if the pool is larger than its
+    /// [minimum size](ThreadPoolBuilder::min_pool_size).
+    ///
+    /// By default, idle worker threads are never stopped.
+    pub fn idle_timeout(&mut self, timeout: Duration) -> &mut Self {
+        self.idle_timeout = Some(timeout);
+        self
+    }
+
     /// Set stack size of threads in the pool, in bytes.
     ///
     /// By default, worker threads use Rust's standard stack size.
@@ -229,8 +796,8 @@ impl ThreadPoolBuilder {
     /// but before running any tasks on it.
     ///
     /// This hook is intended for bookkeeping and monitoring.
-    /// The closure `f` will be dropped after the `builder` is dropped
-    /// and all worker threads in the pool have executed it.
+    /// The closure `f` will be dropped after the `builder` and the pool are
+    /// dropped and all worker threads in the pool have stopped.
     ///
     /// The closure provided will receive an index corresponding to the worker
     /// thread it's running on.
@@ -245,8 +812,8 @@ impl ThreadPoolBuilder {
     /// Execute closure `f` just prior to shutting down each worker thread.
     ///
     /// This hook is intended for bookkeeping and monitoring.
-    /// The closure `f` will be dropped after the `builder` is dropped
-    /// and all threads in the pool have executed it.
+    /// The closure `f` will be dropped after the `builder` and the pool are
+    /// dropped and all threads in the pool have executed it.
     ///
     /// The closure provided will receive an index corresponding to the worker
     /// thread it's running on.
@@ -258,30 +825,62 @@ impl ThreadPoolBuilder {
         self
     }
 
+    /// Execute closure `f` with the payload of each panic of a task that
+    /// has no [`JoinHandle`] to deliver it to.
+    ///
+    /// Panics in tasks are caught, so that they don't take down the worker
+    /// thread polling them, and the panicking task is dropped. The payload
+    /// of the panic is returned by the task's `JoinHandle` if it was spawned
+    /// with [`ThreadPool::spawn`] and the handle was not dropped. Otherwise,
+    /// it is passed to this closure, or dropped by default.
+    pub fn panic_handler<F>(&mut self, f: F) -> &mut Self
+    where
+        F: Fn(Box<dyn Any + Send>) + Send + Sync + 'static,
+    {
+        self.panic_handler = Some(Arc::new(f));
+        self
+    }
+
     /// Create a [`ThreadPool`](ThreadPool) with the given configuration.
+    ///
+    /// The pool size is clamped to the minimum and maximum pool sizes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the minimum pool size is larger than the maximum pool size.
     pub fn create(&mut self) -> Result<ThreadPool, io::Error> {
-        let (tx, rx) = mpsc::channel();
+        let min_size = self.min_pool_size.unwrap_or(self.pool_size);
+        let max_size = self.max_pool_size.unwrap_or(self.pool_size);
+        assert!(min_size <= max_size, "min_pool_size must not be larger than max_pool_size");
+        let size = cmp::min(cmp::max(self.pool_size, min_size), max_size);
+
         let pool = ThreadPool {
             state: Arc::new(PoolState {
-                tx: Mutex::new(tx),
-                rx: Mutex::new(rx),
+                injector: Mutex::new(RunQueue::new()),
+                condvar: Condvar::new(),
+                workers: Mutex::new(Vec::new()),
                 cnt: AtomicUsize::new(1),
-                size: self.pool_size,
+                size: AtomicUsize::new(size),
+                stopping: AtomicUsize::new(0),
+                idle: AtomicUsize::new(0),
+                next_idx: AtomicUsize::new(0),
+                spawned: AtomicUsize::new(0),
+                completed: AtomicUsize::new(0),
+                busy: AtomicUsize::new(0),
+                stopped_poll_time: Mutex::new(Duration::from_secs(0)),
+                min_size,
+                max_size,
+                idle_timeout: self.idle_timeout,
+                stack_size: self.stack_size,
+                name_prefix: self.name_prefix.clone(),
+                after_start: self.after_start.clone(),
+                before_stop: self.before_stop.clone(),
+                panic_handler: self.panic_handler.clone(),
             }),
         };
 
-        for counter in 0..self.pool_size {
-            let state = pool.state.clone();
-            let after_start = self.after_start.clone();
-            let before_stop = self.before_stop.clone();
-            let mut thread_builder = thread::Builder::new();
-            if let Some(ref name_prefix) = self.name_prefix {
-                thread_builder = thread_builder.name(format!("{}{}", name_prefix, counter));
-            }
-            if self.stack_size > 0 {
-                thread_builder = thread_builder.stack_size(self.stack_size);
-            }
-            thread_builder.spawn(move || state.work(counter, after_start, before_stop))?;
+        for _ in 0..size {
+            PoolState::start_worker(&pool.state)?;
         }
         Ok(pool)
     }
@@ -298,6 +897,7 @@ struct Task {
     future: FutureObj<'static, ()>,
     exec: ThreadPool,
     wake_handle: Arc<WakeHandle>,
+    priority: Priority,
 }
 
 struct WakeHandle {
@@ -309,7 +909,7 @@ impl Task {
     /// Actually run the task (invoking `poll` on the future) on the current
     /// thread.
     fn run(self) {
-        let Self { mut future, wake_handle, mut exec } = self;
+        let Self { mut future, wake_handle, mut exec, priority } = self;
         let waker = waker_ref(&wake_handle);
         let mut cx = Context::from_waker(&waker);
 
@@ -319,12 +919,24 @@ impl Task {
             wake_handle.mutex.start_poll();
 
             loop {
-                let res = future.poll_unpin(&mut cx);
+                let res = panic::catch_unwind(AssertUnwindSafe(|| future.poll_unpin(&mut cx)));
                 match res {
-                    Poll::Pending => {}
-                    Poll::Ready(()) => return wake_handle.mutex.complete(),
+                    Ok(Poll::Pending) => {}
+                    Ok(Poll::Ready(())) => {
+                        exec.state.completed.fetch_add(1, Ordering::Relaxed);
+                        return wake_handle.mutex.complete();
+                    }
+                    Err(payload) => {
+                        exec.state.completed.fetch_add(1, Ordering::Relaxed);
+                        wake_handle.mutex.complete();
+                        drop(future);
+                        if let Some(panic_handler) = &exec.state.panic_handler {
+                            panic_handler(payload);
+                        }
+                        return;
+                    }
                 }
-                let task = Self { future, wake_handle: wake_handle.clone(), exec };
+                let task = Self { future, wake_handle: wake_handle.clone(), exec, priority };
                 match wake_handle.mutex.wait(task) {
                     Ok(()) => return, // we've waited
                     Err(task) => {
@@ -346,9 +958,8 @@ impl fmt::Debug for Task {
 
 impl ArcWake for WakeHandle {
     fn wake_by_ref(arc_self: &Arc<Self>) {
-        match arc_self.mutex.notify() {
-            Ok(task) => arc_self.exec.state.send(Message::Run(task)),
-            Err(()) => {}
+        if let Ok(task) = arc_self.mutex.notify() {
+            arc_self.exec.run(task);
         }
     }
 }
@@ -361,14 +972,15 @@ mod tests {
     #[test]
     fn test_drop_after_start() {
         let (tx, rx) = mpsc::sync_channel(2);
-        let _cpu_pool = ThreadPoolBuilder::new()
+        let cpu_pool = ThreadPoolBuilder::new()
             .pool_size(2)
             .after_start(move |_| tx.send(1).unwrap())
             .create()
             .unwrap();
 
-        // After ThreadPoolBuilder is deconstructed, the tx should be dropped
-        // so that we can use rx as an iterator.
+        // After ThreadPoolBuilder and ThreadPool are deconstructed, the tx
+        // should be dropped so that we can use rx as an iterator.
+        drop(cpu_pool);
         let count = rx.into_iter().count();
         assert_eq!(count, 2);
     }
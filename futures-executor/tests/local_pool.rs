@@ -1,14 +1,14 @@
 use futures::channel::oneshot;
-use futures::executor::LocalPool;
+use futures::executor::{allow_nested_block_on, block_on, is_entered, LocalPool};
 use futures::future::{self, lazy, poll_fn, Future};
-use futures::task::{Context, LocalSpawn, Poll, Spawn, Waker};
+use futures::task::{Context, LocalSpawn, LocalTrySpawn, Poll, Spawn, SpawnError, TrySpawn, Waker};
 use std::cell::{Cell, RefCell};
 use std::pin::Pin;
 use std::rc::Rc;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use std::thread;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 struct Pending(Rc<()>);
 
@@ -357,6 +357,60 @@ fn nesting_run_run_until_stalled() {
     pool.run();
 }
 
+#[test]
+#[should_panic(expected = "allow_nested_block_on")]
+fn nesting_block_on() {
+    block_on(async { block_on(async {}) });
+}
+
+#[test]
+fn allow_nested_block_on_runs_nested_executor() {
+    let output = block_on(async {
+        assert!(is_entered());
+        allow_nested_block_on(|| block_on(async { 1 }))
+    });
+    assert_eq!(output, 1);
+    assert!(!is_entered());
+}
+
+#[test]
+fn allow_nested_block_on_keeps_outer_wakeups() {
+    let (tx, mut rx) = oneshot::channel();
+    let mut tx = Some(tx);
+    block_on(poll_fn(move |cx| {
+        if let Poll::Ready(res) = Pin::new(&mut rx).poll(cx) {
+            res.unwrap();
+            return Poll::Ready(());
+        }
+        if let Some(tx) = tx.take() {
+            // The nested executor parks the thread after the outer future
+            // was woken, which consumes the wakeup.
+            let done = Arc::new(AtomicBool::new(false));
+            let mut tx = Some(tx);
+            allow_nested_block_on(|| {
+                block_on(poll_fn(|cx| {
+                    if let Some(tx) = tx.take() {
+                        tx.send(()).unwrap();
+                        let done = done.clone();
+                        let waker = cx.waker().clone();
+                        thread::spawn(move || {
+                            thread::sleep(Duration::from_millis(10));
+                            done.store(true, Ordering::SeqCst);
+                            waker.wake();
+                        });
+                    }
+                    if done.load(Ordering::SeqCst) {
+                        Poll::Ready(())
+                    } else {
+                        Poll::Pending
+                    }
+                }))
+            });
+        }
+        Poll::Pending
+    }));
+}
+
 #[test]
 fn tasks_are_scheduled_fairly() {
     let state = Rc::new(RefCell::new([0, 0]));
@@ -432,3 +486,199 @@ fn park_unpark_independence() {
 
     futures::executor::block_on(future)
 }
+
+#[test]
+fn run_for_returns_when_done() {
+    let mut pool = LocalPool::new();
+    let spawn = pool.spawner();
+    let cnt = Rc::new(Cell::new(0));
+
+    let cnt2 = cnt.clone();
+    spawn.spawn_local_obj(Box::pin(lazy(move |_| cnt2.set(cnt2.get() + 1))).into()).unwrap();
+
+    assert!(pool.run_for(Duration::from_secs(60)));
+    assert_eq!(cnt.get(), 1);
+}
+
+#[test]
+fn run_for_returns_at_deadline() {
+    let mut pool = LocalPool::new();
+    let spawn = pool.spawner();
+
+    spawn.spawn_local_obj(Box::pin(pending()).into()).unwrap();
+    let start = Instant::now();
+    assert!(!pool.run_for(Duration::from_millis(20)));
+    assert!(start.elapsed() >= Duration::from_millis(20));
+
+    // the task is still in the pool
+    assert!(!pool.try_run_one());
+}
+
+#[test]
+fn run_until_deadline() {
+    let mut pool = LocalPool::new();
+
+    let deadline = Instant::now() + Duration::from_secs(60);
+    assert_eq!(pool.run_until_deadline(lazy(|_| 1), deadline), Some(1));
+
+    let (tx, mut rx) = oneshot::channel();
+    let deadline = Instant::now() + Duration::from_millis(20);
+    assert_eq!(pool.run_until_deadline(&mut rx, deadline), None);
+    assert!(Instant::now() >= deadline);
+
+    // the future can be resumed afterwards, and wakeups end the park early
+    let handle = thread::spawn(move || {
+        thread::sleep(Duration::from_millis(10));
+        tx.send(2).unwrap();
+    });
+    let deadline = Instant::now() + Duration::from_secs(60);
+    assert_eq!(pool.run_until_deadline(&mut rx, deadline), Some(Ok(2)));
+    handle.join().unwrap();
+}
+
+#[test]
+fn block_on_timeout() {
+    use futures::executor::block_on_timeout;
+
+    assert_eq!(block_on_timeout(lazy(|_| 1), Duration::from_secs(60)), Some(1));
+
+    let start = Instant::now();
+    assert_eq!(block_on_timeout(pending(), Duration::from_millis(20)), None);
+    assert!(start.elapsed() >= Duration::from_millis(20));
+
+    let (tx, mut rx) = oneshot::channel();
+    assert_eq!(block_on_timeout(&mut rx, Duration::from_millis(0)), None);
+    let handle = thread::spawn(move || {
+        thread::sleep(Duration::from_millis(10));
+        tx.send(1).unwrap();
+    });
+    assert_eq!(block_on_timeout(rx, Duration::from_secs(60)), Some(Ok(1)));
+    handle.join().unwrap();
+}
+
+#[test]
+fn blocking_stream_next_timeout() {
+    use futures::channel::mpsc;
+    use futures::executor::block_on_stream;
+
+    let (tx, rx) = mpsc::unbounded();
+    let mut iter = block_on_stream(rx);
+
+    let start = Instant::now();
+    assert_eq!(iter.next_timeout(Duration::from_millis(20)), None);
+    assert!(start.elapsed() >= Duration::from_millis(20));
+
+    let handle = thread::spawn(move || {
+        thread::sleep(Duration::from_millis(10));
+        tx.unbounded_send(1).unwrap();
+    });
+    assert_eq!(iter.next_timeout(Duration::from_secs(60)), Some(Some(1)));
+    handle.join().unwrap();
+    assert_eq!(iter.next_timeout(Duration::from_secs(60)), Some(None));
+}
+
+#[test]
+fn blocking_stream_drain_ready() {
+    use futures::channel::mpsc;
+    use futures::executor::block_on_stream;
+
+    let (tx, rx) = mpsc::unbounded();
+    let mut iter = block_on_stream(rx);
+    assert_eq!(iter.drain_ready(usize::MAX), []);
+
+    for i in 0..3 {
+        tx.unbounded_send(i).unwrap();
+    }
+    assert_eq!(iter.drain_ready(usize::MAX), [0, 1, 2]);
+    assert_eq!(iter.drain_ready(usize::MAX), []);
+
+    tx.unbounded_send(3).unwrap();
+    drop(tx);
+    assert_eq!(iter.drain_ready(usize::MAX), [3]);
+    assert_eq!(iter.size_hint(), (0, Some(0)));
+}
+
+#[test]
+fn blocking_stream_drain_ready_stops_at_max() {
+    use futures::executor::block_on_stream;
+    use futures::stream;
+
+    let mut iter = block_on_stream(stream::repeat(1));
+    assert_eq!(iter.drain_ready(3), [1, 1, 1]);
+    assert_eq!(iter.drain_ready(0), []);
+
+    let mut iter = block_on_stream(stream::iter(0..2));
+    assert_eq!(iter.drain_ready(3), [0, 1]);
+    assert_eq!(iter.size_hint(), (0, Some(0)));
+}
+
+#[test]
+fn blocking_stream_size_hint() {
+    use futures::executor::block_on_stream;
+    use futures::stream;
+
+    let mut iter = block_on_stream(stream::iter(vec![1, 2]));
+    assert_eq!(iter.size_hint(), (2, Some(2)));
+    assert_eq!(iter.next(), Some(1));
+    assert_eq!(iter.size_hint(), (1, Some(1)));
+
+    // `poll_fn` can't tell that it ended
+    let mut items = vec![1];
+    let mut iter = block_on_stream(stream::poll_fn(move |_| Poll::Ready(items.pop())));
+    assert_eq!(iter.size_hint(), (0, None));
+    assert_eq!(iter.next(), Some(1));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.size_hint(), (0, Some(0)));
+}
+
+#[test]
+fn metrics() {
+    let mut pool = LocalPool::new();
+    let spawn = pool.spawner();
+    assert_eq!(pool.metrics(), Default::default());
+
+    spawn.spawn_local_obj(Box::pin(lazy(|_| ())).into()).unwrap();
+    spawn.spawn_local_obj(Box::pin(pending()).into()).unwrap();
+    let metrics = pool.metrics();
+    assert_eq!(metrics.tasks_spawned(), 2);
+    assert_eq!(metrics.queue_depth(), 2);
+
+    pool.run_until_stalled();
+    let metrics = pool.metrics();
+    assert_eq!(metrics.tasks_spawned(), 2);
+    assert_eq!(metrics.tasks_completed(), 1);
+    assert_eq!(metrics.queue_depth(), 1);
+    assert_eq!(metrics.busy_workers(), 0);
+}
+
+#[test]
+fn try_spawn_returns_future_after_shutdown() {
+    let mut pool = LocalPool::new();
+    let spawner = pool.spawner();
+
+    let ran = Rc::new(Cell::new(false));
+    let future = {
+        let ran = ran.clone();
+        async move { ran.set(true) }
+    };
+    spawner.try_spawn_local_obj(Box::pin(future).into()).unwrap();
+    pool.run();
+    assert!(ran.replace(false));
+    drop(pool);
+
+    let future = {
+        let ran = ran.clone();
+        async move { ran.set(true) }
+    };
+    let err = spawner.try_spawn_local_obj(Box::pin(future).into()).unwrap_err();
+    assert!(err.error().is_shutdown());
+    assert!(!err.error().is_at_capacity());
+    assert!(!ran.get());
+
+    // The future can still be run elsewhere.
+    block_on(err.into_future());
+    assert!(ran.get());
+
+    let err = spawner.try_spawn_obj(Box::pin(async {}).into()).unwrap_err();
+    assert!(SpawnError::from(err).is_shutdown());
+}
@@ -0,0 +1,386 @@
+#![cfg(feature = "thread-pool")]
+
+use futures::channel::oneshot;
+use futures::executor::{block_on, Priority, ThreadPool};
+use futures::future::{self, FutureExt};
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{mpsc, Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+struct SetOnDrop(Arc<AtomicBool>);
+
+impl Drop for SetOnDrop {
+    fn drop(&mut self) {
+        self.0.store(true, Ordering::SeqCst);
+    }
+}
+
+#[cfg_attr(miri, ignore)] // https://github.com/rust-lang/miri/issues/1038
+#[test]
+fn spawn_returns_output() {
+    let pool = ThreadPool::new().unwrap();
+
+    let handles: Vec<_> = (0..10).map(|i| pool.spawn(async move { i * 2 })).collect();
+    let outputs = block_on(future::join_all(handles));
+    assert_eq!(
+        outputs.into_iter().map(Result::unwrap).collect::<Vec<_>>(),
+        [0, 2, 4, 6, 8, 10, 12, 14, 16, 18]
+    );
+}
+
+#[cfg_attr(miri, ignore)] // https://github.com/rust-lang/miri/issues/1038
+#[test]
+fn abort_drops_future() {
+    let pool = ThreadPool::new().unwrap();
+    let dropped = Arc::new(AtomicBool::new(false));
+    let (_tx, rx) = oneshot::channel::<()>();
+
+    let guard = SetOnDrop(dropped.clone());
+    let handle = pool.spawn(async move {
+        let _guard = guard;
+        rx.await.unwrap();
+    });
+    handle.abort();
+
+    let err = block_on(handle).unwrap_err();
+    assert!(err.is_cancelled());
+    assert_eq!(err.to_string(), "task was cancelled");
+    drop(pool);
+    while !dropped.load(Ordering::SeqCst) {
+        thread::yield_now();
+    }
+}
+
+#[cfg_attr(miri, ignore)] // https://github.com/rust-lang/miri/issues/1038
+#[test]
+fn abort_after_completion() {
+    let pool = ThreadPool::new().unwrap();
+
+    let (tx, rx) = oneshot::channel();
+    let handle = pool.spawn(async move { tx.send(()).unwrap() });
+    block_on(rx).unwrap();
+    while !handle.is_finished() {
+        thread::yield_now();
+    }
+    handle.abort();
+    assert!(block_on(handle).is_ok());
+}
+
+#[cfg_attr(miri, ignore)] // https://github.com/rust-lang/miri/issues/1038
+#[test]
+fn drop_detaches_by_default() {
+    let pool = ThreadPool::new().unwrap();
+
+    let (tx1, rx1) = oneshot::channel::<()>();
+    let (tx2, rx2) = oneshot::channel();
+    drop(pool.spawn(async move {
+        rx1.await.unwrap();
+        tx2.send(()).unwrap();
+    }));
+    tx1.send(()).unwrap();
+    block_on(rx2).unwrap();
+}
+
+#[cfg_attr(miri, ignore)] // https://github.com/rust-lang/miri/issues/1038
+#[test]
+fn drop_aborts_if_configured() {
+    let pool = ThreadPool::new().unwrap();
+
+    let (mut tx, rx) = oneshot::channel::<()>();
+    let mut handle = pool.spawn(rx.map(|_| ()));
+    handle.set_abort_on_drop(true);
+    drop(handle);
+    block_on(tx.cancellation());
+}
+
+#[cfg_attr(miri, ignore)] // https://github.com/rust-lang/miri/issues/1038
+#[test]
+fn panic_is_delivered_to_handle() {
+    let pool = ThreadPool::builder().pool_size(1).create().unwrap();
+
+    let handle = pool.spawn(async { panic!("boom") });
+    let err = block_on(handle).unwrap_err();
+    assert!(err.is_panic());
+    assert!(!err.is_cancelled());
+    assert_eq!(*err.into_panic().downcast::<&str>().unwrap(), "boom");
+
+    // the worker thread survived the panic
+    assert_eq!(block_on(pool.spawn(async { 1 })).unwrap(), 1);
+}
+
+#[cfg_attr(miri, ignore)] // https://github.com/rust-lang/miri/issues/1038
+#[test]
+fn panic_handler() {
+    let (tx, rx) = mpsc::channel();
+    let tx = std::sync::Mutex::new(tx);
+    let pool = ThreadPool::builder()
+        .pool_size(1)
+        .panic_handler(move |payload| {
+            let msg = *payload.downcast::<&str>().unwrap();
+            tx.lock().unwrap().send(msg).unwrap();
+        })
+        .create()
+        .unwrap();
+
+    pool.spawn_ok(async { panic!("spawn_ok") });
+    assert_eq!(rx.recv().unwrap(), "spawn_ok");
+
+    let (start_tx, start_rx) = oneshot::channel::<()>();
+    drop(pool.spawn(async {
+        start_rx.await.unwrap();
+        panic!("detached")
+    }));
+    start_tx.send(()).unwrap();
+    assert_eq!(rx.recv().unwrap(), "detached");
+
+    let handle = pool.spawn(async { panic!("joined") });
+    assert!(block_on(handle).unwrap_err().is_panic());
+    assert_eq!(block_on(pool.spawn(async { 1 })).unwrap(), 1);
+    assert!(rx.try_recv().is_err());
+}
+
+#[cfg_attr(miri, ignore)] // https://github.com/rust-lang/miri/issues/1038
+#[test]
+fn set_pool_size() {
+    let started = Arc::new(AtomicUsize::new(0));
+    let stopped = Arc::new(AtomicUsize::new(0));
+    let pool = {
+        let started = started.clone();
+        let stopped = stopped.clone();
+        ThreadPool::builder()
+            .pool_size(2)
+            .after_start(move |_| {
+                started.fetch_add(1, Ordering::SeqCst);
+            })
+            .before_stop(move |_| {
+                stopped.fetch_add(1, Ordering::SeqCst);
+            })
+            .create()
+            .unwrap()
+    };
+
+    pool.set_pool_size(5).unwrap();
+    assert_eq!(pool.pool_size(), 5);
+    while started.load(Ordering::SeqCst) < 5 {
+        thread::yield_now();
+    }
+
+    pool.set_pool_size(1).unwrap();
+    assert_eq!(pool.pool_size(), 1);
+    while stopped.load(Ordering::SeqCst) < 4 {
+        thread::yield_now();
+    }
+    assert_eq!(block_on(pool.spawn(async { 1 })).unwrap(), 1);
+    assert_eq!(stopped.load(Ordering::SeqCst), 4);
+}
+
+#[cfg_attr(miri, ignore)] // https://github.com/rust-lang/miri/issues/1038
+#[test]
+fn grows_when_busy() {
+    let pool = ThreadPool::builder().pool_size(1).max_pool_size(2).create().unwrap();
+    assert_eq!(pool.pool_size(), 1);
+
+    // block the only worker thread
+    let (started_tx, started_rx) = mpsc::channel();
+    let (tx, rx) = mpsc::channel::<()>();
+    let blocked = pool.spawn(async move {
+        started_tx.send(()).unwrap();
+        rx.recv().unwrap()
+    });
+    started_rx.recv().unwrap();
+
+    assert_eq!(block_on(pool.spawn(async { 1 })).unwrap(), 1);
+    assert_eq!(pool.pool_size(), 2);
+    tx.send(()).unwrap();
+    block_on(blocked).unwrap();
+}
+
+#[cfg_attr(miri, ignore)] // https://github.com/rust-lang/miri/issues/1038
+#[test]
+fn shrinks_when_idle() {
+    let pool = ThreadPool::builder()
+        .pool_size(3)
+        .min_pool_size(1)
+        .idle_timeout(Duration::from_millis(10))
+        .create()
+        .unwrap();
+    assert_eq!(pool.pool_size(), 3);
+
+    while pool.pool_size() > 1 {
+        thread::sleep(Duration::from_millis(10));
+    }
+    thread::sleep(Duration::from_millis(50));
+    assert_eq!(pool.pool_size(), 1);
+    assert_eq!(block_on(pool.spawn(async { 1 })).unwrap(), 1);
+}
+
+#[cfg_attr(miri, ignore)] // https://github.com/rust-lang/miri/issues/1038
+#[test]
+fn tasks_of_busy_worker_are_stolen() {
+    let pool = ThreadPool::builder().pool_size(2).create().unwrap();
+
+    let spawner = pool.clone();
+    let handle = pool.spawn(async move {
+        // these go to the local queue of this worker thread, which then
+        // blocks until the other worker thread has run them
+        let (tx, rx) = mpsc::channel();
+        for i in 0..10 {
+            let tx = tx.clone();
+            spawner.spawn_ok(async move { tx.send(i).unwrap() });
+        }
+        let mut received: Vec<_> = (0..10).map(|_| rx.recv().unwrap()).collect();
+        received.sort_unstable();
+        received
+    });
+    assert_eq!(block_on(handle).unwrap(), (0..10).collect::<Vec<_>>());
+}
+
+#[cfg_attr(miri, ignore)] // https://github.com/rust-lang/miri/issues/1038
+#[test]
+fn metrics() {
+    let pool = ThreadPool::builder().pool_size(1).create().unwrap();
+    assert_eq!(pool.metrics(), Default::default());
+
+    // block the only worker thread, so that the next tasks are queued
+    let (started_tx, started_rx) = mpsc::channel();
+    let (tx, rx) = mpsc::channel::<()>();
+    pool.spawn_ok(async move {
+        started_tx.send(()).unwrap();
+        rx.recv().unwrap();
+        thread::sleep(Duration::from_millis(10));
+    });
+    started_rx.recv().unwrap();
+    for _ in 0..3 {
+        pool.spawn_ok(async {});
+    }
+
+    let metrics = pool.metrics();
+    assert_eq!(metrics.tasks_spawned(), 4);
+    assert_eq!(metrics.tasks_completed(), 0);
+    assert_eq!(metrics.queue_depth(), 3);
+    assert_eq!(metrics.busy_workers(), 1);
+
+    tx.send(()).unwrap();
+    while pool.metrics().tasks_completed() < 4 {
+        thread::yield_now();
+    }
+    let metrics = pool.metrics();
+    assert_eq!(metrics.queue_depth(), 0);
+    assert!(metrics.total_poll_time() >= Duration::from_millis(10));
+}
+
+#[cfg_attr(miri, ignore)] // https://github.com/rust-lang/miri/issues/1038
+#[test]
+fn runs_tasks_by_priority() {
+    let pool = ThreadPool::builder().pool_size(1).create().unwrap();
+
+    // block the only worker thread, so that the next tasks are queued
+    let (started_tx, started_rx) = mpsc::channel();
+    let (tx, rx) = mpsc::channel::<()>();
+    pool.spawn_ok(async move {
+        started_tx.send(()).unwrap();
+        rx.recv().unwrap();
+    });
+    started_rx.recv().unwrap();
+
+    let order = Arc::new(Mutex::new(Vec::new()));
+    let handles: Vec<_> = [Priority::Low, Priority::Normal, Priority::High, Priority::Low]
+        .iter()
+        .map(|&priority| {
+            let order = order.clone();
+            pool.spawn_with_priority(async move { order.lock().unwrap().push(priority) }, priority)
+        })
+        .collect();
+
+    tx.send(()).unwrap();
+    for handle in handles {
+        block_on(handle).unwrap();
+    }
+    assert_eq!(
+        *order.lock().unwrap(),
+        [Priority::High, Priority::Normal, Priority::Low, Priority::Low]
+    );
+}
+
+#[cfg_attr(miri, ignore)] // https://github.com/rust-lang/miri/issues/1038
+#[test]
+fn scope_borrows_data() {
+    let pool = ThreadPool::builder().pool_size(2).create().unwrap();
+    let mut data = vec![1, 2, 3, 4, 5];
+
+    let spawned = pool.scope(|s| {
+        let mut spawned = 0;
+        for x in data.iter_mut() {
+            s.spawn(async move { *x *= 10 });
+            spawned += 1;
+        }
+        spawned
+    });
+    assert_eq!(spawned, 5);
+    assert_eq!(data, [10, 20, 30, 40, 50]);
+}
+
+#[cfg_attr(miri, ignore)] // https://github.com/rust-lang/miri/issues/1038
+#[test]
+fn scope_waits_for_tasks_spawned_by_tasks() {
+    let pool = ThreadPool::builder().pool_size(2).create().unwrap();
+    let counter = AtomicUsize::new(0);
+
+    pool.scope(|s| {
+        let counter = &counter;
+        s.spawn(async move {
+            for _ in 0..10 {
+                s.spawn(async move {
+                    thread::sleep(Duration::from_millis(1));
+                    counter.fetch_add(1, Ordering::SeqCst);
+                });
+            }
+        });
+    });
+    assert_eq!(counter.load(Ordering::SeqCst), 10);
+}
+
+#[cfg_attr(miri, ignore)] // https://github.com/rust-lang/miri/issues/1038
+#[test]
+fn scope_propagates_panics_after_waiting() {
+    let pool = ThreadPool::builder().pool_size(2).create().unwrap();
+    let done = AtomicBool::new(false);
+
+    let res = panic::catch_unwind(AssertUnwindSafe(|| {
+        pool.scope(|s| {
+            let done = &done;
+            s.spawn(async { panic!("boom") });
+            s.spawn(async move {
+                thread::sleep(Duration::from_millis(10));
+                done.store(true, Ordering::SeqCst);
+            });
+        })
+    }));
+    assert_eq!(*res.unwrap_err().downcast::<&str>().unwrap(), "boom");
+    assert!(done.load(Ordering::SeqCst));
+
+    // a panic of the closure is resumed too, after waiting for the tasks
+    let done = AtomicBool::new(false);
+    let res = panic::catch_unwind(AssertUnwindSafe(|| {
+        pool.scope(|s| {
+            let done = &done;
+            s.spawn(async move {
+                thread::sleep(Duration::from_millis(10));
+                done.store(true, Ordering::SeqCst);
+            });
+            panic!("closure");
+        })
+    }));
+    assert_eq!(*res.unwrap_err().downcast::<&str>().unwrap(), "closure");
+    assert!(done.load(Ordering::SeqCst));
+}
+
+#[cfg_attr(miri, ignore)] // https://github.com/rust-lang/miri/issues/1038
+#[test]
+#[should_panic(expected = "from within an executor")]
+fn scope_within_executor() {
+    let pool = ThreadPool::new().unwrap();
+    block_on(async { pool.scope(|_| ()) });
+}
@@ -0,0 +1,140 @@
+use std::io::Result;
+use std::ops::DerefMut;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+/// Receive datagrams asynchronously.
+///
+/// This trait is the message-oriented counterpart of
+/// [`AsyncRead`](crate::AsyncRead), for transports like UDP sockets where
+/// data arrives as separate datagrams, each with the address of its sender.
+/// The address type `A` is a parameter so that the same abstraction works
+/// for any kind of transport, for example with `std::net::SocketAddr` for
+/// UDP.
+pub trait AsyncDatagramRead<A> {
+    /// Attempt to receive a single datagram into `buf`.
+    ///
+    /// On success, returns `Poll::Ready(Ok((num_bytes_read, addr)))`, where
+    /// `addr` is the address the datagram was sent from.
+    ///
+    /// If no datagram is available for reading, the method returns
+    /// `Poll::Pending` and arranges for the current task (via
+    /// `cx.waker().wake_by_ref()`) to receive a notification when one is
+    /// available.
+    ///
+    /// A datagram longer than `buf` is truncated, and what did not fit is
+    /// discarded, unless the implementation documents otherwise.
+    ///
+    /// # Implementation
+    ///
+    /// This function may not return errors of kind `WouldBlock` or
+    /// `Interrupted`.  Implementations must convert `WouldBlock` into
+    /// `Poll::Pending` and either internally retry or convert
+    /// `Interrupted` into another error kind.
+    fn poll_recv_from(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<Result<(usize, A)>>;
+}
+
+/// Send datagrams asynchronously.
+///
+/// This trait is the message-oriented counterpart of
+/// [`AsyncWrite`](crate::AsyncWrite), for transports like UDP sockets where
+/// data is sent as separate datagrams, each to a given address of type `A`.
+pub trait AsyncDatagramWrite<A> {
+    /// Attempt to send `buf` as a single datagram to `target`.
+    ///
+    /// On success, returns `Poll::Ready(Ok(num_bytes_written))`. Unlike a
+    /// write to a byte stream, the datagram is either sent at once or not at
+    /// all, so this is the length of `buf` unless the implementation
+    /// documents otherwise.
+    ///
+    /// If the datagram cannot be sent yet, the method returns
+    /// `Poll::Pending` and arranges for the current task (via
+    /// `cx.waker().wake_by_ref()`) to receive a notification when it can be.
+    ///
+    /// # Implementation
+    ///
+    /// This function may not return errors of kind `WouldBlock` or
+    /// `Interrupted`.  Implementations must convert `WouldBlock` into
+    /// `Poll::Pending` and either internally retry or convert
+    /// `Interrupted` into another error kind.
+    fn poll_send_to(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+        target: &A,
+    ) -> Poll<Result<usize>>;
+}
+
+macro_rules! deref_async_datagram_read {
+    () => {
+        fn poll_recv_from(
+            mut self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+            buf: &mut [u8],
+        ) -> Poll<Result<(usize, A)>> {
+            Pin::new(&mut **self).poll_recv_from(cx, buf)
+        }
+    };
+}
+
+impl<A, T: ?Sized + AsyncDatagramRead<A> + Unpin> AsyncDatagramRead<A> for Box<T> {
+    deref_async_datagram_read!();
+}
+
+impl<A, T: ?Sized + AsyncDatagramRead<A> + Unpin> AsyncDatagramRead<A> for &mut T {
+    deref_async_datagram_read!();
+}
+
+impl<A, P> AsyncDatagramRead<A> for Pin<P>
+where
+    P: DerefMut + Unpin,
+    P::Target: AsyncDatagramRead<A>,
+{
+    fn poll_recv_from(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<Result<(usize, A)>> {
+        self.get_mut().as_mut().poll_recv_from(cx, buf)
+    }
+}
+
+macro_rules! deref_async_datagram_write {
+    () => {
+        fn poll_send_to(
+            mut self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+            buf: &[u8],
+            target: &A,
+        ) -> Poll<Result<usize>> {
+            Pin::new(&mut **self).poll_send_to(cx, buf, target)
+        }
+    };
+}
+
+impl<A, T: ?Sized + AsyncDatagramWrite<A> + Unpin> AsyncDatagramWrite<A> for Box<T> {
+    deref_async_datagram_write!();
+}
+
+impl<A, T: ?Sized + AsyncDatagramWrite<A> + Unpin> AsyncDatagramWrite<A> for &mut T {
+    deref_async_datagram_write!();
+}
+
+impl<A, P> AsyncDatagramWrite<A> for Pin<P>
+where
+    P: DerefMut + Unpin,
+    P::Target: AsyncDatagramWrite<A>,
+{
+    fn poll_send_to(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+        target: &A,
+    ) -> Poll<Result<usize>> {
+        self.get_mut().as_mut().poll_send_to(cx, buf, target)
+    }
+}
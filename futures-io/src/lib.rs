@@ -5,6 +5,9 @@
 //! `std::io::{Read, Write, Seek, BufRead}`. The primary difference is
 //! that these traits integrate with the asynchronous task system.
 //!
+//! The `AsyncDatagramRead` and `AsyncDatagramWrite` traits do the same for
+//! message-oriented transports like UDP sockets.
+//!
 //! All items of this library are only available when the `std` feature of this
 //! library is activated, and it is activated by default.
 
@@ -21,6 +24,11 @@
 ))]
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
+#[cfg(feature = "std")]
+mod datagram;
+#[cfg(feature = "std")]
+mod read_buf;
+
 #[cfg(feature = "std")]
 mod if_std {
     use std::io;
@@ -34,6 +42,9 @@ mod if_std {
     #[doc(no_inline)]
     pub use io::{Error, ErrorKind, IoSlice, IoSliceMut, Result, SeekFrom};
 
+    pub use crate::datagram::{AsyncDatagramRead, AsyncDatagramWrite};
+    pub use crate::read_buf::ReadBuf;
+
     /// Read bytes asynchronously.
     ///
     /// This trait is analogous to the `std::io::Read` trait, but integrates
@@ -98,6 +109,45 @@ mod if_std {
 
             self.poll_read(cx, &mut [])
         }
+
+        /// Attempt to read from the `AsyncRead` into `buf`, which may be
+        /// uninitialized.
+        ///
+        /// On success, returns `Poll::Ready(Ok(()))` and marks the bytes that
+        /// were read as filled in `buf`. If no bytes were filled although
+        /// `buf` had room, the reader has reached its end.
+        ///
+        /// If no data is available for reading, the method returns
+        /// `Poll::Pending` and arranges for the current task (via
+        /// `cx.waker().wake_by_ref()`) to receive a notification when the object becomes
+        /// readable or is closed.
+        ///
+        /// By default, this method initializes the unfilled part of `buf` and
+        /// delegates to `poll_read`. Since `buf` keeps track of the bytes that
+        /// are initialized, they are only zeroed once when the buffer is
+        /// reused. Objects which can read into uninitialized memory should
+        /// override this method to avoid the zeroing altogether.
+        ///
+        /// # Implementation
+        ///
+        /// This function may not return errors of kind `WouldBlock` or
+        /// `Interrupted`.  Implementations must convert `WouldBlock` into
+        /// `Poll::Pending` and either internally retry or convert
+        /// `Interrupted` into another error kind.
+        fn poll_read_buf(
+            self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+            buf: &mut ReadBuf<'_>,
+        ) -> Poll<Result<()>> {
+            match self.poll_read(cx, buf.initialize_unfilled()) {
+                Poll::Ready(Ok(n)) => {
+                    buf.advance(n);
+                    Poll::Ready(Ok(()))
+                }
+                Poll::Ready(Err(e)) => Poll::Ready(Err(e)),
+                Poll::Pending => Poll::Pending,
+            }
+        }
     }
 
     /// Write bytes asynchronously.
@@ -239,6 +289,31 @@ mod if_std {
             cx: &mut Context<'_>,
             pos: SeekFrom,
         ) -> Poll<Result<u64>>;
+
+        /// Attempt to seek relative to the current position, by `offset`
+        /// bytes.
+        ///
+        /// This is equivalent to seeking with `SeekFrom::Current(offset)`,
+        /// but does not return the new position. Implementations that buffer
+        /// data, like a buffered reader, can use this to seek within their
+        /// buffer without discarding it.
+        ///
+        /// By default, this calls [`poll_seek`](AsyncSeek::poll_seek) with
+        /// `SeekFrom::Current(offset)`.
+        ///
+        /// # Implementation
+        ///
+        /// This function may not return errors of kind `WouldBlock` or
+        /// `Interrupted`.  Implementations must convert `WouldBlock` into
+        /// `Poll::Pending` and either internally retry or convert
+        /// `Interrupted` into another error kind.
+        fn poll_seek_relative(
+            self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+            offset: i64,
+        ) -> Poll<Result<()>> {
+            self.poll_seek(cx, SeekFrom::Current(offset)).map(|res| res.map(|_| ()))
+        }
     }
 
     /// Read bytes asynchronously.
@@ -314,6 +389,14 @@ mod if_std {
             ) -> Poll<Result<usize>> {
                 Pin::new(&mut **self).poll_read_vectored(cx, bufs)
             }
+
+            fn poll_read_buf(
+                mut self: Pin<&mut Self>,
+                cx: &mut Context<'_>,
+                buf: &mut ReadBuf<'_>,
+            ) -> Poll<Result<()>> {
+                Pin::new(&mut **self).poll_read_buf(cx, buf)
+            }
         };
     }
 
@@ -345,6 +428,14 @@ mod if_std {
         ) -> Poll<Result<usize>> {
             self.get_mut().as_mut().poll_read_vectored(cx, bufs)
         }
+
+        fn poll_read_buf(
+            self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+            buf: &mut ReadBuf<'_>,
+        ) -> Poll<Result<()>> {
+            self.get_mut().as_mut().poll_read_buf(cx, buf)
+        }
     }
 
     macro_rules! delegate_async_read_to_stdio {
@@ -369,6 +460,18 @@ mod if_std {
 
     impl AsyncRead for &[u8] {
         delegate_async_read_to_stdio!();
+
+        fn poll_read_buf(
+            mut self: Pin<&mut Self>,
+            _: &mut Context<'_>,
+            buf: &mut ReadBuf<'_>,
+        ) -> Poll<Result<()>> {
+            let n = self.len().min(buf.remaining());
+            let (a, b) = self.split_at(n);
+            buf.put_slice(a);
+            *self = b;
+            Poll::Ready(Ok(()))
+        }
     }
 
     macro_rules! deref_async_write {
@@ -478,6 +581,14 @@ mod if_std {
             ) -> Poll<Result<u64>> {
                 Pin::new(&mut **self).poll_seek(cx, pos)
             }
+
+            fn poll_seek_relative(
+                mut self: Pin<&mut Self>,
+                cx: &mut Context<'_>,
+                offset: i64,
+            ) -> Poll<Result<()>> {
+                Pin::new(&mut **self).poll_seek_relative(cx, offset)
+            }
         };
     }
 
@@ -501,6 +612,14 @@ mod if_std {
         ) -> Poll<Result<u64>> {
             self.get_mut().as_mut().poll_seek(cx, pos)
         }
+
+        fn poll_seek_relative(
+            self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+            offset: i64,
+        ) -> Poll<Result<()>> {
+            self.get_mut().as_mut().poll_seek_relative(cx, offset)
+        }
     }
 
     macro_rules! deref_async_buf_read {
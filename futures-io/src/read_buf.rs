@@ -0,0 +1,190 @@
+use std::fmt;
+use std::mem::MaybeUninit;
+use std::ptr;
+
+/// A buffer to read into that may be partially uninitialized.
+///
+/// The buffer is split into three consecutive regions: bytes that have been
+/// filled with data, bytes that are initialized but not filled yet, and bytes
+/// that are not initialized. Readers working with
+/// [`poll_read_buf`](crate::AsyncRead::poll_read_buf) write into the unfilled
+/// part and then mark what they wrote as filled. Because the buffer remembers
+/// how much of it is initialized, the memory only has to be zeroed once, if
+/// at all, even when the buffer is reused across many reads.
+pub struct ReadBuf<'a> {
+    buf: &'a mut [MaybeUninit<u8>],
+    filled: usize,
+    initialized: usize,
+}
+
+impl<'a> ReadBuf<'a> {
+    /// Creates a new `ReadBuf` from a fully initialized buffer.
+    pub fn new(buf: &'a mut [u8]) -> Self {
+        let initialized = buf.len();
+        // Safety: `MaybeUninit<u8>` has the same layout as `u8`, and the
+        // initialized length makes sure no uninitialized byte is written back.
+        let buf = unsafe { &mut *(buf as *mut [u8] as *mut [MaybeUninit<u8>]) };
+        Self { buf, filled: 0, initialized }
+    }
+
+    /// Creates a new `ReadBuf` from a buffer that may be uninitialized.
+    pub fn uninit(buf: &'a mut [MaybeUninit<u8>]) -> Self {
+        Self { buf, filled: 0, initialized: 0 }
+    }
+
+    /// Returns the total capacity of the buffer.
+    pub fn capacity(&self) -> usize {
+        self.buf.len()
+    }
+
+    /// Returns the number of bytes that can still be filled.
+    pub fn remaining(&self) -> usize {
+        self.capacity() - self.filled
+    }
+
+    /// Returns the filled part of the buffer.
+    pub fn filled(&self) -> &[u8] {
+        // Safety: the filled part is always initialized.
+        unsafe { &*(&self.buf[..self.filled] as *const [MaybeUninit<u8>] as *const [u8]) }
+    }
+
+    /// Returns the filled part of the buffer, mutably.
+    pub fn filled_mut(&mut self) -> &mut [u8] {
+        // Safety: the filled part is always initialized.
+        unsafe { &mut *(&mut self.buf[..self.filled] as *mut [MaybeUninit<u8>] as *mut [u8]) }
+    }
+
+    /// Returns the initialized part of the buffer, which includes the filled
+    /// part.
+    pub fn initialized(&self) -> &[u8] {
+        // Safety: `initialized` bytes have been written.
+        unsafe { &*(&self.buf[..self.initialized] as *const [MaybeUninit<u8>] as *const [u8]) }
+    }
+
+    /// Returns the unfilled part of the buffer, which may be uninitialized.
+    ///
+    /// # Safety
+    ///
+    /// The caller must not write uninitialized bytes into the returned
+    /// slice, since other parts of the buffer may already be initialized.
+    pub unsafe fn unfilled_mut(&mut self) -> &mut [MaybeUninit<u8>] {
+        &mut self.buf[self.filled..]
+    }
+
+    /// Returns the unfilled part of the buffer, zeroing the bytes of it that
+    /// are not initialized yet.
+    ///
+    /// Bytes are only zeroed the first time; after that the buffer knows they
+    /// are initialized.
+    pub fn initialize_unfilled(&mut self) -> &mut [u8] {
+        self.initialize_unfilled_to(self.remaining())
+    }
+
+    /// Returns the first `n` bytes of the unfilled part of the buffer,
+    /// zeroing the bytes of them that are not initialized yet.
+    ///
+    /// # Panics
+    ///
+    /// Panics if fewer than `n` bytes remain.
+    pub fn initialize_unfilled_to(&mut self, n: usize) -> &mut [u8] {
+        assert!(self.remaining() >= n, "n overflows remaining");
+
+        let end = self.filled + n;
+        if self.initialized < end {
+            for b in &mut self.buf[self.initialized..end] {
+                *b = MaybeUninit::new(0);
+            }
+            self.initialized = end;
+        }
+
+        // Safety: the bytes up to `end` are initialized.
+        unsafe { &mut *(&mut self.buf[self.filled..end] as *mut [MaybeUninit<u8>] as *mut [u8]) }
+    }
+
+    /// Marks the next `n` unfilled bytes as initialized.
+    ///
+    /// This does nothing for bytes that are already known to be initialized.
+    ///
+    /// # Safety
+    ///
+    /// The caller must have initialized the first `n` bytes of
+    /// [`unfilled_mut`](Self::unfilled_mut).
+    pub unsafe fn assume_init(&mut self, n: usize) {
+        let end = self.filled + n;
+        if end > self.initialized {
+            self.initialized = end;
+        }
+    }
+
+    /// Marks the next `n` unfilled bytes as filled.
+    ///
+    /// # Panics
+    ///
+    /// Panics if those bytes are not all initialized.
+    pub fn advance(&mut self, n: usize) {
+        let filled = self.filled.checked_add(n).expect("filled overflow");
+        self.set_filled(filled);
+    }
+
+    /// Sets the number of filled bytes.
+    ///
+    /// This can also shrink the filled part, for example to reuse the buffer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the filled part would include bytes that are not
+    /// initialized.
+    pub fn set_filled(&mut self, n: usize) {
+        assert!(n <= self.initialized, "filled must not become larger than initialized");
+        self.filled = n;
+    }
+
+    /// Clears the buffer, keeping track of which bytes are initialized.
+    pub fn clear(&mut self) {
+        self.filled = 0;
+    }
+
+    /// Appends `src` to the filled part of the buffer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if fewer than `src.len()` bytes remain.
+    pub fn put_slice(&mut self, src: &[u8]) {
+        assert!(self.remaining() >= src.len(), "src.len() must fit in remaining()");
+
+        let end = self.filled + src.len();
+        // Safety: the destination has room for `src`, checked above.
+        unsafe {
+            let dst = self.buf[self.filled..end].as_mut_ptr() as *mut u8;
+            ptr::copy_nonoverlapping(src.as_ptr(), dst, src.len());
+        }
+        if end > self.initialized {
+            self.initialized = end;
+        }
+        self.filled = end;
+    }
+
+    /// Returns a new `ReadBuf` over at most `n` bytes of the unfilled part of
+    /// this buffer.
+    ///
+    /// This is how readers that limit the size of a read, like `Take` in
+    /// `futures-util`, pass the buffer on. Once the returned buffer has been
+    /// read into, the bytes it filled can be added to this one with
+    /// [`assume_init`](Self::assume_init) and [`advance`](Self::advance),
+    /// after checking that the reader didn't replace it with another buffer.
+    pub fn take(&mut self, n: usize) -> ReadBuf<'_> {
+        let n = n.min(self.remaining());
+        let initialized = (self.initialized - self.filled).min(n);
+        ReadBuf { buf: &mut self.buf[self.filled..self.filled + n], filled: 0, initialized }
+    }
+}
+
+impl fmt::Debug for ReadBuf<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ReadBuf")
+            .field("filled", &self.filled)
+            .field("initialized", &self.initialized)
+            .field("capacity", &self.capacity())
+            .finish()
+    }
+}
@@ -33,6 +33,12 @@ pub fn try_join_internal(input: TokenStream) -> TokenStream {
     crate::join::try_join(input)
 }
 
+/// The `join_try_all!` macro.
+#[proc_macro]
+pub fn join_try_all_internal(input: TokenStream) -> TokenStream {
+    crate::join::join_try_all(input)
+}
+
 /// The `select!` macro.
 #[proc_macro]
 pub fn select_internal(input: TokenStream) -> TokenStream {
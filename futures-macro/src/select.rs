@@ -4,7 +4,7 @@ use proc_macro::TokenStream;
 use proc_macro2::Span;
 use quote::{format_ident, quote};
 use syn::parse::{Parse, ParseStream};
-use syn::{parse_quote, Expr, Ident, Pat, Token};
+use syn::{parse_quote, Attribute, Expr, Ident, LitInt, Pat, Token};
 
 mod kw {
     syn::custom_keyword!(complete);
@@ -16,13 +16,17 @@ struct Select {
     default: Option<Expr>,
     normal_fut_exprs: Vec<Expr>,
     normal_fut_handlers: Vec<(Pat, Expr)>,
+    // `#[priority(..)]` of each normal case, `0` if unspecified
+    normal_fut_priorities: Vec<u32>,
+    // condition after `, if` of each normal case
+    normal_fut_guards: Vec<Option<Expr>>,
 }
 
 #[allow(clippy::large_enum_variant)]
 enum CaseKind {
     Complete,
     Default,
-    Normal(Pat, Expr),
+    Normal(Pat, Expr, Option<Expr>),
 }
 
 impl Parse for Select {
@@ -32,9 +36,36 @@ impl Parse for Select {
             default: None,
             normal_fut_exprs: vec![],
             normal_fut_handlers: vec![],
+            normal_fut_priorities: vec![],
+            normal_fut_guards: vec![],
         };
 
         while !input.is_empty() {
+            // `#[priority(<int>)]`
+            let attrs = input.call(Attribute::parse_outer)?;
+            let mut priority = None;
+            for attr in &attrs {
+                if !attr.path.is_ident("priority") {
+                    return Err(syn::Error::new_spanned(
+                        attr,
+                        "unknown attribute, only `#[priority(..)]` is allowed",
+                    ));
+                }
+                if priority.is_some() {
+                    return Err(syn::Error::new_spanned(
+                        attr,
+                        "multiple `#[priority(..)]` attributes found, only one allowed",
+                    ));
+                }
+                priority = Some(attr.parse_args::<LitInt>()?.base10_parse::<u32>()?);
+            }
+            if !attrs.is_empty() && (input.peek(kw::complete) || input.peek(Token![default])) {
+                return Err(syn::Error::new_spanned(
+                    &attrs[0],
+                    "`#[priority(..)]` is only allowed on future branches",
+                ));
+            }
+
             let case_kind = if input.peek(kw::complete) {
                 // `complete`
                 if select.complete.is_some() {
@@ -54,7 +85,15 @@ impl Parse for Select {
                 let pat = input.parse()?;
                 input.parse::<Token![=]>()?;
                 let expr = input.parse()?;
-                CaseKind::Normal(pat, expr)
+                // `, if <expr>`
+                let guard = if input.peek(Token![,]) && input.peek2(Token![if]) {
+                    input.parse::<Token![,]>()?;
+                    input.parse::<Token![if]>()?;
+                    Some(input.call(Expr::parse_without_eager_brace)?)
+                } else {
+                    None
+                };
+                CaseKind::Normal(pat, expr, guard)
             };
 
             // `=> <expr>`
@@ -76,9 +115,11 @@ impl Parse for Select {
             match case_kind {
                 CaseKind::Complete => select.complete = Some(expr),
                 CaseKind::Default => select.default = Some(expr),
-                CaseKind::Normal(pat, fut_expr) => {
+                CaseKind::Normal(pat, fut_expr, guard) => {
                     select.normal_fut_exprs.push(fut_expr);
                     select.normal_fut_handlers.push((pat, expr));
+                    select.normal_fut_priorities.push(priority.unwrap_or(0));
+                    select.normal_fut_guards.push(guard);
                 }
             }
         }
@@ -145,13 +186,14 @@ fn select_inner(input: TokenStream, random: bool) -> TokenStream {
 
     // bind non-`Ident` future exprs w/ `let`
     let mut future_let_bindings = Vec::with_capacity(parsed.normal_fut_exprs.len());
-    let bound_future_names: Vec<_> = parsed
+    let pin_futures: Vec<_> = parsed
         .normal_fut_exprs
         .into_iter()
+        .zip(parsed.normal_fut_guards)
         .zip(variant_names.iter())
-        .map(|(expr, variant_name)| {
-            match expr {
-                syn::Expr::Path(path) => {
+        .map(|((expr, guard), variant_name)| {
+            match (expr, guard) {
+                (syn::Expr::Path(path), guard) => {
                     // Don't bind futures that are already a path.
                     // This prevents creating redundant stack space
                     // for them.
@@ -162,9 +204,50 @@ fn select_inner(input: TokenStream, random: bool) -> TokenStream {
                         __futures_crate::async_await::assert_fused_future(&#path);
                         __futures_crate::async_await::assert_unpin(&#path);
                     });
-                    path
+                    let pin_future = quote! {
+                        let mut #variant_name = unsafe {
+                            __futures_crate::Pin::new_unchecked(&mut #path)
+                        };
+                    };
+                    match guard {
+                        Some(guard) => {
+                            // Evaluate the condition once, before polling.
+                            let enabled = format_ident!("__enabled{}", variant_name);
+                            future_let_bindings.push(quote! {
+                                let #enabled: bool = #guard;
+                            });
+                            quote! {
+                                if !#enabled {
+                                    return __futures_crate::None;
+                                }
+                                #pin_future
+                            }
+                        }
+                        None => pin_future,
+                    }
                 }
-                _ => {
+                (expr, Some(guard)) => {
+                    // Only evaluate the expression if the branch is enabled,
+                    // so that it can rely on the condition, such as unwrapping
+                    // an `Option` checked to be `Some`. The Future is pinned on
+                    // the stack within an `Option`, as described below.
+                    future_let_bindings.push(quote! {
+                        let mut #variant_name = if #guard {
+                            __futures_crate::Some(#expr)
+                        } else {
+                            __futures_crate::None
+                        };
+                    });
+                    quote! {
+                        let mut #variant_name = match &mut #variant_name {
+                            __futures_crate::Some(#variant_name) => unsafe {
+                                __futures_crate::Pin::new_unchecked(#variant_name)
+                            },
+                            __futures_crate::None => return __futures_crate::None,
+                        };
+                    }
+                }
+                (expr, None) => {
                     // Bind and pin the resulting Future on the stack. This is
                     // necessary to support direct select! calls on !Unpin
                     // Futures. The Future is not explicitly pinned here with
@@ -179,7 +262,11 @@ fn select_inner(input: TokenStream, random: bool) -> TokenStream {
                     future_let_bindings.push(quote! {
                         let mut #variant_name = #expr;
                     });
-                    parse_quote! { #variant_name }
+                    quote! {
+                        let mut #variant_name = unsafe {
+                            __futures_crate::Pin::new_unchecked(&mut #variant_name)
+                        };
+                    }
                 }
             }
         })
@@ -187,8 +274,8 @@ fn select_inner(input: TokenStream, random: bool) -> TokenStream {
 
     // For each future, make an `&mut dyn FnMut(&mut Context<'_>) -> Option<Poll<__PrivResult<...>>`
     // to use for polling that individual future. These will then be put in an array.
-    let poll_functions = bound_future_names.iter().zip(variant_names.iter()).map(
-        |(bound_future_name, variant_name)| {
+    let poll_functions =
+        pin_futures.iter().zip(variant_names.iter()).map(|(pin_future, variant_name)| {
             // Below we lazily create the Pin on the Future below.
             // This is done in order to avoid allocating memory in the generator
             // for the Pin variable.
@@ -197,16 +284,16 @@ fn select_inner(input: TokenStream, random: bool) -> TokenStream {
             //    it implements Unpin.
             // 2. The Future is created in scope of the select! function and will
             //    not be moved for the duration of it. It is thereby stack-pinned
+            //
+            // `pin_future` binds the pinned Future to the variant name.
             quote! {
                 let mut #variant_name = |__cx: &mut __futures_crate::task::Context<'_>| {
-                    let mut #bound_future_name = unsafe {
-                        __futures_crate::Pin::new_unchecked(&mut #bound_future_name)
-                    };
-                    if __futures_crate::future::FusedFuture::is_terminated(&#bound_future_name) {
+                    #pin_future
+                    if __futures_crate::future::FusedFuture::is_terminated(&#variant_name) {
                         __futures_crate::None
                     } else {
                         __futures_crate::Some(__futures_crate::future::FutureExt::poll_unpin(
-                            &mut #bound_future_name,
+                            &mut #variant_name,
                             __cx,
                         ).map(#enum_ident::#variant_name))
                     }
@@ -215,8 +302,7 @@ fn select_inner(input: TokenStream, random: bool) -> TokenStream {
                     &mut __futures_crate::task::Context<'_>
                 ) -> __futures_crate::Option<__futures_crate::task::Poll<_>> = &mut #variant_name;
             }
-        },
-    );
+        });
 
     let none_polled = if parsed.complete.is_some() {
         quote! {
@@ -288,6 +374,41 @@ fn select_inner(input: TokenStream, random: bool) -> TokenStream {
         quote!()
     };
 
+    // Poll the futures by decreasing priority, shuffling (or keeping in order)
+    // only the futures of the same priority.
+    let fut_priorities = parsed.normal_fut_priorities;
+    let mut priorities = fut_priorities.clone();
+    priorities.sort_unstable_by(|a, b| b.cmp(a));
+    priorities.dedup();
+    if priorities.is_empty() {
+        // Keep polling an empty array of futures, for `complete` to run.
+        priorities.push(0);
+    }
+    let poll_groups = priorities.into_iter().map(|priority| {
+        let group = variant_names
+            .iter()
+            .zip(&fut_priorities)
+            .filter(|(_, p)| **p == priority)
+            .map(|(variant_name, _)| variant_name);
+        quote! {
+            let mut __select_arr = [#( #group ),*];
+            #shuffle
+            for poller in &mut __select_arr {
+                let poller: &mut &mut dyn FnMut(
+                    &mut __futures_crate::task::Context<'_>
+                ) -> __futures_crate::Option<__futures_crate::task::Poll<_>> = poller;
+                match poller(__cx) {
+                    __futures_crate::Some(x @ __futures_crate::task::Poll::Ready(_)) =>
+                        return x,
+                    __futures_crate::Some(__futures_crate::task::Poll::Pending) => {
+                        __any_polled = true;
+                    }
+                    __futures_crate::None => {}
+                }
+            }
+        }
+    });
+
     TokenStream::from(quote! { {
         #enum_item
 
@@ -299,21 +420,7 @@ fn select_inner(input: TokenStream, random: bool) -> TokenStream {
 
                 #( #poll_functions )*
 
-                let mut __select_arr = [#( #variant_names ),*];
-                #shuffle
-                for poller in &mut __select_arr {
-                    let poller: &mut &mut dyn FnMut(
-                        &mut __futures_crate::task::Context<'_>
-                    ) -> __futures_crate::Option<__futures_crate::task::Poll<_>> = poller;
-                    match poller(__cx) {
-                        __futures_crate::Some(x @ __futures_crate::task::Poll::Ready(_)) =>
-                            return x,
-                        __futures_crate::Some(__futures_crate::task::Poll::Pending) => {
-                            __any_polled = true;
-                        }
-                        __futures_crate::None => {}
-                    }
-                }
+                #( #poll_groups )*
 
                 if !__any_polled {
                     #none_polled
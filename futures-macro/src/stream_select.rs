@@ -1,25 +1,107 @@
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote, ToTokens};
-use syn::{parse::Parser, punctuated::Punctuated, Expr, Index, Token};
+use syn::{parse::Parser, punctuated::Punctuated, Expr, ExprRange, Index, RangeLimits, Token};
 
 /// The `stream_select!` macro.
 pub(crate) fn stream_select(input: TokenStream) -> Result<TokenStream, syn::Error> {
-    let args = Punctuated::<Expr, Token![,]>::parse_terminated.parse2(input)?;
+    let mut args = Punctuated::<Expr, Token![,]>::parse_terminated.parse2(input)?;
     if args.len() < 2 {
         return Ok(quote! {
            compile_error!("stream select macro needs at least two arguments.")
         });
     }
+    // A trailing `..<expr>` is the `SelectAll` of the dynamic streams.
+    let dynamic = match args.last() {
+        Some(Expr::Range(ExprRange { from: None, limits: RangeLimits::HalfOpen(_), .. })) => {
+            match args.pop().unwrap().into_value() {
+                Expr::Range(range) => range.to,
+                _ => unreachable!(),
+            }
+        }
+        _ => None,
+    };
+    for arg in &args {
+        if let Expr::Range(ExprRange { from: None, .. }) = arg {
+            return Err(syn::Error::new_spanned(
+                arg,
+                "the `..` dynamic streams must be the last argument",
+            ));
+        }
+    }
     let generic_idents = (0..args.len()).map(|i| format_ident!("_{}", i)).collect::<Vec<_>>();
     let field_idents = (0..args.len()).map(|i| format_ident!("__{}", i)).collect::<Vec<_>>();
     let field_idents_2 = (0..args.len()).map(|i| format_ident!("___{}", i)).collect::<Vec<_>>();
     let field_indices = (0..args.len()).map(Index::from).collect::<Vec<_>>();
     let args = args.iter().map(|e| e.to_token_stream());
 
+    let dynamic_index = Index::from(field_idents.len());
+    let (dynamic_generic, dynamic_field, dynamic_bound, dynamic_pat, dynamic_arg) = match &dynamic {
+        Some(dynamic) => (
+            Some(quote!(, DYNAMIC)),
+            Some(quote!(, __futures_crate::stream::SelectAll<DYNAMIC>)),
+            Some(
+                quote!(DYNAMIC: __futures_crate::stream::Stream<Item=ITEM> + ::std::marker::Unpin,),
+            ),
+            Some(quote!(, ref mut dynamic)),
+            Some(quote!(, #dynamic)),
+        ),
+        None => (None, None, None, None, None),
+    };
+    let streams_len = field_idents.len();
+    // Poll the dynamic streams first as often as one of them would be polled
+    // first if they were polled along with the others.
+    let (poll_dynamic_first, poll_dynamic_last) = if dynamic.is_some() {
+        let poll_dynamic = quote! {
+            match __futures_crate::stream::Stream::poll_next(::std::pin::Pin::new(&mut *dynamic), cx) {
+                r @ __futures_crate::task::Poll::Ready(Some(_)) => {
+                    return r;
+                },
+                __futures_crate::task::Poll::Pending => {
+                    any_pending = true;
+                },
+                __futures_crate::task::Poll::Ready(None) => {},
+            }
+        };
+        (
+            Some(quote! {
+                let dynamic_first = __futures_crate::async_await::gen_index(#streams_len + dynamic.len()) < dynamic.len();
+                if dynamic_first {
+                    #poll_dynamic
+                }
+            }),
+            Some(quote! {
+                if !dynamic_first {
+                    #poll_dynamic
+                }
+            }),
+        )
+    } else {
+        (None, None)
+    };
+    let dynamic_size_hint = dynamic.as_ref().map(|_| {
+        quote! {
+            let new_hint = self.#dynamic_index.size_hint();
+            s.0 += new_hint.0;
+            s.1 = s.1.and_then(|a| new_hint.1.map(|b| a + b));
+        }
+    });
+    let dynamic_impl = dynamic.as_ref().map(|_| {
+        quote! {
+            #[allow(dead_code)]
+            impl<#(#generic_idents),* #dynamic_generic> StreamSelect<#(#generic_idents),* #dynamic_generic> {
+                fn dynamic_mut(&mut self) -> &mut __futures_crate::stream::SelectAll<DYNAMIC> {
+                    &mut self.#dynamic_index
+                }
+            }
+        }
+    });
+
     Ok(quote! {
         {
             #[derive(Debug)]
-            struct StreamSelect<#(#generic_idents),*> (#(Option<#generic_idents>),*);
+            struct StreamSelect<#(#generic_idents),* #dynamic_generic> (#(Option<#generic_idents>),* #dynamic_field);
+
+            #dynamic_impl
 
             enum StreamEnum<#(#generic_idents),*> {
                 #(
@@ -43,17 +125,18 @@ pub(crate) fn stream_select(input: TokenStream) -> Result<TokenStream, syn::Erro
                 }
             }
 
-            impl<ITEM, #(#generic_idents),*> __futures_crate::stream::Stream for StreamSelect<#(#generic_idents),*>
-            where #(#generic_idents: __futures_crate::stream::Stream<Item=ITEM> + ::std::marker::Unpin,)*
+            impl<ITEM, #(#generic_idents),* #dynamic_generic> __futures_crate::stream::Stream for StreamSelect<#(#generic_idents),* #dynamic_generic>
+            where #(#generic_idents: __futures_crate::stream::Stream<Item=ITEM> + ::std::marker::Unpin,)* #dynamic_bound
             {
                 type Item = ITEM;
 
                 fn poll_next(mut self: ::std::pin::Pin<&mut Self>, cx: &mut __futures_crate::task::Context<'_>) -> __futures_crate::task::Poll<Option<Self::Item>> {
-                    let Self(#(ref mut #field_idents),*) = self.get_mut();
+                    let Self(#(ref mut #field_idents),* #dynamic_pat) = self.get_mut();
                     #(
                         let mut #field_idents_2 = false;
                     )*
                     let mut any_pending = false;
+                    #poll_dynamic_first
                     {
                         let mut stream_array = [#(#field_idents.as_mut().map(|f| StreamEnum::#generic_idents(f)).unwrap_or(StreamEnum::None)),*];
                         __futures_crate::async_await::shuffle(&mut stream_array);
@@ -81,6 +164,7 @@ pub(crate) fn stream_select(input: TokenStream) -> Result<TokenStream, syn::Erro
                             }
                         }
                     }
+                    #poll_dynamic_last
                     #(
                         if #field_idents_2 {
                             *#field_idents = None;
@@ -102,11 +186,12 @@ pub(crate) fn stream_select(input: TokenStream) -> Result<TokenStream, syn::Erro
                             s.1 = s.1.and_then(|a| new_hint.1.map(|b| a + b));
                         }
                     )*
+                    #dynamic_size_hint
                     s
                 }
             }
 
-            StreamSelect(#(Some(#args)),*)
+            StreamSelect(#(Some(#args)),* #dynamic_arg)
 
         }
     })
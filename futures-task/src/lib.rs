@@ -17,7 +17,7 @@
 extern crate alloc;
 
 mod spawn;
-pub use crate::spawn::{LocalSpawn, Spawn, SpawnError};
+pub use crate::spawn::{LocalSpawn, LocalTrySpawn, Spawn, SpawnError, TrySpawn, TrySpawnError};
 
 #[cfg(not(futures_no_atomic_cas))]
 #[cfg(feature = "alloc")]
@@ -33,6 +33,13 @@ mod waker;
 #[cfg(feature = "alloc")]
 pub use crate::waker::waker;
 
+#[cfg(not(futures_no_atomic_cas))]
+#[cfg(feature = "alloc")]
+mod waker_fn;
+#[cfg(not(futures_no_atomic_cas))]
+#[cfg(feature = "alloc")]
+pub use crate::waker_fn::waker_fn;
+
 #[cfg(not(futures_no_atomic_cas))]
 #[cfg(feature = "alloc")]
 mod waker_ref;
@@ -40,6 +47,13 @@ mod waker_ref;
 #[cfg(feature = "alloc")]
 pub use crate::waker_ref::{waker_ref, WakerRef};
 
+#[cfg(not(futures_no_atomic_cas))]
+#[cfg(feature = "alloc")]
+mod rc_wake;
+#[cfg(not(futures_no_atomic_cas))]
+#[cfg(feature = "alloc")]
+pub use crate::rc_wake::{rc_waker, rc_waker_ref, RcWake};
+
 mod future_obj;
 pub use crate::future_obj::{FutureObj, LocalFutureObj, UnsafeFutureObj};
 
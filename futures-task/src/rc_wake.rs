@@ -0,0 +1,145 @@
+use super::WakerRef;
+use alloc::rc::Rc;
+use core::mem::{self, ManuallyDrop};
+use core::task::{RawWaker, RawWakerVTable, Waker};
+
+/// A way of waking up a specific task of a single-threaded executor.
+///
+/// This is the counterpart of [`ArcWake`](super::ArcWake) for types that are
+/// expected to be wrapped in an `Rc`, for executors whose tasks never leave
+/// the thread they were spawned on, as on WASM, embedded or GUI event loops.
+/// It avoids the atomic reference counting of `Arc`, and doesn't require the
+/// type to be `Send` or `Sync`.
+///
+/// There are two ways to convert `RcWake` into [`Waker`]:
+///
+/// * [`rc_waker`](super::rc_waker()) converts `Rc<impl RcWake>` into [`Waker`].
+/// * [`rc_waker_ref`](super::rc_waker_ref()) converts `&Rc<impl RcWake>` into
+///   [`WakerRef`] that provides access to a [`&Waker`][`Waker`].
+///
+/// Both are `unsafe`, since a [`Waker`] is `Send` and `Sync` while an `Rc` is
+/// not.
+///
+/// [`Waker`]: std::task::Waker
+pub trait RcWake {
+    /// Indicates that the associated task is ready to make progress and should
+    /// be `poll`ed.
+    ///
+    /// This function is only ever called from the thread which created the
+    /// `RcWake` based [`Waker`].
+    ///
+    /// [`Waker`]: std::task::Waker
+    fn wake(self: Rc<Self>) {
+        Self::wake_by_ref(&self)
+    }
+
+    /// Indicates that the associated task is ready to make progress and should
+    /// be `poll`ed.
+    ///
+    /// This function is similar to [`wake`](RcWake::wake), but must not consume the provided data
+    /// pointer.
+    fn wake_by_ref(rc_self: &Rc<Self>);
+}
+
+fn waker_vtable<W: RcWake>() -> &'static RawWakerVTable {
+    &RawWakerVTable::new(
+        clone_rc_raw::<W>,
+        wake_rc_raw::<W>,
+        wake_by_ref_rc_raw::<W>,
+        drop_rc_raw::<W>,
+    )
+}
+
+/// Creates a [`Waker`] from an `Rc<impl RcWake>`.
+///
+/// The returned [`Waker`] will call
+/// [`RcWake.wake()`](RcWake::wake) if awoken.
+///
+/// # Safety
+///
+/// The returned [`Waker`] and all its clones must only be cloned, woken and
+/// dropped on the current thread. The futures polled with it must then not
+/// send it to other threads, which is the case when there are none.
+///
+/// # Examples
+///
+/// ```
+/// use futures::task::{rc_waker, Context, RcWake};
+/// use std::cell::Cell;
+/// use std::rc::Rc;
+///
+/// struct Flag(Cell<bool>);
+///
+/// impl RcWake for Flag {
+///     fn wake_by_ref(rc_self: &Rc<Self>) {
+///         rc_self.0.set(true);
+///     }
+/// }
+///
+/// let flag = Rc::new(Flag(Cell::new(false)));
+/// // Safety: the waker is only used on this thread.
+/// let waker = unsafe { rc_waker(flag.clone()) };
+/// let cx = Context::from_waker(&waker);
+/// cx.waker().wake_by_ref();
+/// assert!(flag.0.get());
+/// ```
+pub unsafe fn rc_waker<W>(wake: Rc<W>) -> Waker
+where
+    W: RcWake + 'static,
+{
+    let ptr = Rc::into_raw(wake) as *const ();
+
+    Waker::from_raw(RawWaker::new(ptr, waker_vtable::<W>()))
+}
+
+/// Creates a reference to a [`Waker`] from a reference to `Rc<impl RcWake>`.
+///
+/// The resulting [`Waker`] will call
+/// [`RcWake.wake()`](RcWake::wake) if awoken.
+///
+/// # Safety
+///
+/// As with [`rc_waker`](super::rc_waker()), the [`Waker`] and all its clones
+/// must only be cloned, woken and dropped on the current thread.
+#[inline]
+pub unsafe fn rc_waker_ref<W>(wake: &Rc<W>) -> WakerRef<'_>
+where
+    W: RcWake + 'static,
+{
+    // simply copy the pointer instead of using Rc::into_raw,
+    // as we don't actually keep a refcount by using ManuallyDrop.
+    let ptr = &**wake as *const W as *const ();
+
+    let waker = ManuallyDrop::new(Waker::from_raw(RawWaker::new(ptr, waker_vtable::<W>())));
+    WakerRef::new_unowned(waker)
+}
+
+#[allow(clippy::redundant_clone)] // The clone here isn't actually redundant.
+unsafe fn increase_refcount<T: RcWake>(data: *const ()) {
+    // Retain Rc, but don't touch refcount by wrapping in ManuallyDrop
+    let rc = mem::ManuallyDrop::new(Rc::<T>::from_raw(data as *const T));
+    // Now increase refcount, but don't drop new refcount either
+    let _rc_clone: mem::ManuallyDrop<_> = rc.clone();
+}
+
+// used by `rc_waker_ref`
+unsafe fn clone_rc_raw<T: RcWake>(data: *const ()) -> RawWaker {
+    increase_refcount::<T>(data);
+    RawWaker::new(data, waker_vtable::<T>())
+}
+
+unsafe fn wake_rc_raw<T: RcWake>(data: *const ()) {
+    let rc: Rc<T> = Rc::from_raw(data as *const T);
+    RcWake::wake(rc);
+}
+
+// used by `rc_waker_ref`
+unsafe fn wake_by_ref_rc_raw<T: RcWake>(data: *const ()) {
+    // Retain Rc, but don't touch refcount by wrapping in ManuallyDrop
+    let rc = mem::ManuallyDrop::new(Rc::<T>::from_raw(data as *const T));
+    RcWake::wake_by_ref(&rc);
+}
+
+unsafe fn drop_rc_raw<T: RcWake>(data: *const ()) {
+    drop(Rc::<T>::from_raw(data as *const T))
+}
@@ -51,20 +51,65 @@ pub trait LocalSpawn {
     }
 }
 
+/// An extension of [`Spawn`] for executors that give the future back when
+/// they fail to spawn it.
+pub trait TrySpawn: Spawn {
+    /// Spawns a future that will be run to completion.
+    ///
+    /// # Errors
+    ///
+    /// As with [`Spawn::spawn_obj`], the executor may be unable to spawn
+    /// tasks. The returned error then holds the future, so that the caller
+    /// can spawn it somewhere else, or run it itself.
+    fn try_spawn_obj(
+        &self,
+        future: FutureObj<'static, ()>,
+    ) -> Result<(), TrySpawnError<FutureObj<'static, ()>>>;
+}
+
+/// An extension of [`LocalSpawn`] for executors that give the future back
+/// when they fail to spawn it.
+pub trait LocalTrySpawn: LocalSpawn {
+    /// Spawns a future that will be run to completion.
+    ///
+    /// # Errors
+    ///
+    /// As with [`LocalSpawn::spawn_local_obj`], the executor may be unable to
+    /// spawn tasks. The returned error then holds the future, so that the
+    /// caller can spawn it somewhere else, or run it itself.
+    fn try_spawn_local_obj(
+        &self,
+        future: LocalFutureObj<'static, ()>,
+    ) -> Result<(), TrySpawnError<LocalFutureObj<'static, ()>>>;
+}
+
 /// An error that occurred during spawning.
 pub struct SpawnError {
-    _priv: (),
+    kind: Kind,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    Shutdown,
+    AtCapacity,
 }
 
 impl fmt::Debug for SpawnError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_tuple("SpawnError").field(&"shutdown").finish()
+        let kind = match self.kind {
+            Kind::Shutdown => "shutdown",
+            Kind::AtCapacity => "at capacity",
+        };
+        f.debug_tuple("SpawnError").field(&kind).finish()
     }
 }
 
 impl fmt::Display for SpawnError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "Executor is shutdown")
+        match self.kind {
+            Kind::Shutdown => write!(f, "Executor is shutdown"),
+            Kind::AtCapacity => write!(f, "Executor is at capacity"),
+        }
     }
 }
 
@@ -74,15 +119,78 @@ impl std::error::Error for SpawnError {}
 impl SpawnError {
     /// Spawning failed because the executor has been shut down.
     pub fn shutdown() -> Self {
-        Self { _priv: () }
+        Self { kind: Kind::Shutdown }
+    }
+
+    /// Spawning failed because the executor has as many tasks as it can
+    /// hold. Spawning may succeed later, once some of them have completed.
+    pub fn at_capacity() -> Self {
+        Self { kind: Kind::AtCapacity }
     }
 
     /// Check whether spawning failed to the executor being shut down.
     pub fn is_shutdown(&self) -> bool {
-        true
+        self.kind == Kind::Shutdown
+    }
+
+    /// Check whether spawning failed to the executor being at capacity.
+    pub fn is_at_capacity(&self) -> bool {
+        self.kind == Kind::AtCapacity
     }
 }
 
+/// An error returned by [`TrySpawn::try_spawn_obj`] and
+/// [`LocalTrySpawn::try_spawn_local_obj`], holding the future that could not
+/// be spawned.
+pub struct TrySpawnError<Fut> {
+    error: SpawnError,
+    future: Fut,
+}
+
+impl<Fut> TrySpawnError<Fut> {
+    /// Creates an error for `future` failing to be spawned because of
+    /// `error`.
+    pub fn new(error: SpawnError, future: Fut) -> Self {
+        Self { error, future }
+    }
+
+    /// Returns why spawning failed.
+    pub fn error(&self) -> &SpawnError {
+        &self.error
+    }
+
+    /// Returns the future that could not be spawned.
+    pub fn into_future(self) -> Fut {
+        self.future
+    }
+
+    /// Returns why spawning failed, and the future that could not be spawned.
+    pub fn into_parts(self) -> (SpawnError, Fut) {
+        (self.error, self.future)
+    }
+}
+
+impl<Fut> From<TrySpawnError<Fut>> for SpawnError {
+    fn from(err: TrySpawnError<Fut>) -> Self {
+        err.error
+    }
+}
+
+impl<Fut> fmt::Debug for TrySpawnError<Fut> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TrySpawnError").field("error", &self.error).finish()
+    }
+}
+
+impl<Fut> fmt::Display for TrySpawnError<Fut> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.error, f)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<Fut> std::error::Error for TrySpawnError<Fut> {}
+
 impl<Sp: ?Sized + Spawn> Spawn for &Sp {
     fn spawn_obj(&self, future: FutureObj<'static, ()>) -> Result<(), SpawnError> {
         Sp::spawn_obj(self, future)
@@ -123,6 +231,42 @@ impl<Sp: ?Sized + LocalSpawn> LocalSpawn for &mut Sp {
     }
 }
 
+impl<Sp: ?Sized + TrySpawn> TrySpawn for &Sp {
+    fn try_spawn_obj(
+        &self,
+        future: FutureObj<'static, ()>,
+    ) -> Result<(), TrySpawnError<FutureObj<'static, ()>>> {
+        Sp::try_spawn_obj(self, future)
+    }
+}
+
+impl<Sp: ?Sized + LocalTrySpawn> LocalTrySpawn for &Sp {
+    fn try_spawn_local_obj(
+        &self,
+        future: LocalFutureObj<'static, ()>,
+    ) -> Result<(), TrySpawnError<LocalFutureObj<'static, ()>>> {
+        Sp::try_spawn_local_obj(self, future)
+    }
+}
+
+impl<Sp: ?Sized + TrySpawn> TrySpawn for &mut Sp {
+    fn try_spawn_obj(
+        &self,
+        future: FutureObj<'static, ()>,
+    ) -> Result<(), TrySpawnError<FutureObj<'static, ()>>> {
+        Sp::try_spawn_obj(self, future)
+    }
+}
+
+impl<Sp: ?Sized + LocalTrySpawn> LocalTrySpawn for &mut Sp {
+    fn try_spawn_local_obj(
+        &self,
+        future: LocalFutureObj<'static, ()>,
+    ) -> Result<(), TrySpawnError<LocalFutureObj<'static, ()>>> {
+        Sp::try_spawn_local_obj(self, future)
+    }
+}
+
 #[cfg(feature = "alloc")]
 mod if_alloc {
     use super::*;
@@ -189,4 +333,60 @@ mod if_alloc {
             (**self).status_local()
         }
     }
+
+    impl<Sp: ?Sized + TrySpawn> TrySpawn for Box<Sp> {
+        fn try_spawn_obj(
+            &self,
+            future: FutureObj<'static, ()>,
+        ) -> Result<(), TrySpawnError<FutureObj<'static, ()>>> {
+            (**self).try_spawn_obj(future)
+        }
+    }
+
+    impl<Sp: ?Sized + LocalTrySpawn> LocalTrySpawn for Box<Sp> {
+        fn try_spawn_local_obj(
+            &self,
+            future: LocalFutureObj<'static, ()>,
+        ) -> Result<(), TrySpawnError<LocalFutureObj<'static, ()>>> {
+            (**self).try_spawn_local_obj(future)
+        }
+    }
+
+    impl<Sp: ?Sized + TrySpawn> TrySpawn for Rc<Sp> {
+        fn try_spawn_obj(
+            &self,
+            future: FutureObj<'static, ()>,
+        ) -> Result<(), TrySpawnError<FutureObj<'static, ()>>> {
+            (**self).try_spawn_obj(future)
+        }
+    }
+
+    impl<Sp: ?Sized + LocalTrySpawn> LocalTrySpawn for Rc<Sp> {
+        fn try_spawn_local_obj(
+            &self,
+            future: LocalFutureObj<'static, ()>,
+        ) -> Result<(), TrySpawnError<LocalFutureObj<'static, ()>>> {
+            (**self).try_spawn_local_obj(future)
+        }
+    }
+
+    #[cfg(not(futures_no_atomic_cas))]
+    impl<Sp: ?Sized + TrySpawn> TrySpawn for alloc::sync::Arc<Sp> {
+        fn try_spawn_obj(
+            &self,
+            future: FutureObj<'static, ()>,
+        ) -> Result<(), TrySpawnError<FutureObj<'static, ()>>> {
+            (**self).try_spawn_obj(future)
+        }
+    }
+
+    #[cfg(not(futures_no_atomic_cas))]
+    impl<Sp: ?Sized + LocalTrySpawn> LocalTrySpawn for alloc::sync::Arc<Sp> {
+        fn try_spawn_local_obj(
+            &self,
+            future: LocalFutureObj<'static, ()>,
+        ) -> Result<(), TrySpawnError<LocalFutureObj<'static, ()>>> {
+            (**self).try_spawn_local_obj(future)
+        }
+    }
 }
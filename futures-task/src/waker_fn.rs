@@ -0,0 +1,52 @@
+use super::arc_wake::ArcWake;
+use super::waker::waker;
+use alloc::sync::Arc;
+use core::task::Waker;
+
+struct WakerFn<F> {
+    wake: F,
+}
+
+impl<F: Fn() + Send + Sync + 'static> ArcWake for WakerFn<F> {
+    fn wake_by_ref(arc_self: &Arc<Self>) {
+        (arc_self.wake)()
+    }
+}
+
+/// Creates a [`Waker`] that calls `wake` each time it is awoken.
+///
+/// The closure is shared by the clones of the waker, and dropped with the
+/// last of them.
+///
+/// This is a shorthand for implementing [`ArcWake`] for a type of one's
+/// own, for example in tests.
+///
+/// # Examples
+///
+/// ```
+/// use futures::channel::oneshot;
+/// use futures::future::FutureExt;
+/// use futures::task::{waker_fn, Context};
+/// use std::sync::atomic::{AtomicUsize, Ordering};
+/// use std::sync::Arc;
+///
+/// let wakes = Arc::new(AtomicUsize::new(0));
+/// let waker = {
+///     let wakes = wakes.clone();
+///     waker_fn(move || {
+///         wakes.fetch_add(1, Ordering::SeqCst);
+///     })
+/// };
+/// let mut cx = Context::from_waker(&waker);
+///
+/// let (tx, mut rx) = oneshot::channel();
+/// assert!(rx.poll_unpin(&mut cx).is_pending());
+/// tx.send(1).unwrap();
+/// assert_eq!(wakes.load(Ordering::SeqCst), 1);
+/// ```
+pub fn waker_fn<F>(wake: F) -> Waker
+where
+    F: Fn() + Send + Sync + 'static,
+{
+    waker(Arc::new(WakerFn { wake }))
+}
@@ -3,6 +3,9 @@ use futures_core::stream::Stream;
 #[doc(hidden)]
 pub fn assert_is_unpin_stream<S: Stream + Unpin>(_: &mut S) {}
 
+#[doc(hidden)]
+pub fn assert_is_unpin_sink<Si: Unpin>(_: &mut Si) {}
+
 /// Assert that the next poll to the provided stream will return
 /// [`Poll::Pending`](futures_core::task::Poll::Pending).
 ///
@@ -122,3 +125,181 @@ macro_rules! assert_stream_done {
         }
     }};
 }
+
+/// Assert that the next polls to the provided stream will return
+/// [`Poll::Ready`](futures_core::task::Poll::Ready) with each of the provided
+/// items, in order.
+///
+/// # Examples
+///
+/// ```
+/// use futures::stream;
+/// use futures_test::{assert_stream_done, assert_stream_items};
+///
+/// let mut stream = stream::iter(vec![1, 2, 3]);
+///
+/// assert_stream_items!(stream, vec![1, 2, 3]);
+/// assert_stream_done!(stream);
+/// ```
+#[macro_export]
+macro_rules! assert_stream_items {
+    ($stream:expr, $items:expr) => {{
+        let mut stream = &mut $stream;
+        $crate::__private::assert::assert_is_unpin_stream(stream);
+        let mut cx = $crate::task::noop_context();
+        for (i, item) in ::std::iter::IntoIterator::into_iter($items).enumerate() {
+            let stream = $crate::__private::Pin::new(&mut *stream);
+            match $crate::__private::stream::Stream::poll_next(stream, &mut cx) {
+                $crate::__private::task::Poll::Ready($crate::__private::Some(x)) => {
+                    assert_eq!(x, item, "assertion failed: unexpected item at index {}", i);
+                }
+                $crate::__private::task::Poll::Ready($crate::__private::None) => {
+                    panic!(
+                        "assertion failed: expected stream to provide item at index {} but stream is at its end",
+                        i
+                    );
+                }
+                $crate::__private::task::Poll::Pending => {
+                    panic!(
+                        "assertion failed: expected stream to provide item at index {} but stream wasn't ready",
+                        i
+                    );
+                }
+            }
+        }
+    }};
+}
+
+/// Assert that the next call to [`poll_ready`](futures_sink::Sink::poll_ready)
+/// on the provided sink will return
+/// [`Poll::Ready`](futures_core::task::Poll::Ready) with `Ok`.
+///
+/// # Examples
+///
+/// ```
+/// use futures::channel::mpsc;
+/// use futures_test::{assert_sink_pending, assert_sink_ready, assert_sink_send};
+/// use futures_test::assert_stream_next;
+///
+/// let (mut tx, mut rx) = mpsc::channel::<i32>(0);
+///
+/// assert_sink_ready!(tx);
+/// assert_sink_send!(tx, 1);
+/// assert_sink_pending!(tx);
+/// assert_stream_next!(rx, 1);
+/// assert_sink_ready!(tx);
+/// ```
+#[macro_export]
+macro_rules! assert_sink_ready {
+    ($sink:expr) => {{
+        let mut sink = &mut $sink;
+        $crate::__private::assert::assert_is_unpin_sink(sink);
+        let sink = $crate::__private::Pin::new(sink);
+        let mut cx = $crate::task::noop_context();
+        match $crate::__private::Sink::poll_ready(sink, &mut cx) {
+            $crate::__private::task::Poll::Ready($crate::__private::Ok(())) => {}
+            $crate::__private::task::Poll::Ready($crate::__private::Err(e)) => {
+                panic!("assertion failed: expected sink to be ready but it failed: {:?}", e);
+            }
+            $crate::__private::task::Poll::Pending => {
+                panic!("assertion failed: expected sink to be ready but it was pending");
+            }
+        }
+    }};
+}
+
+/// Assert that the next call to [`poll_ready`](futures_sink::Sink::poll_ready)
+/// on the provided sink will return
+/// [`Poll::Pending`](futures_core::task::Poll::Pending).
+///
+/// # Examples
+///
+/// ```
+/// use futures::channel::mpsc;
+/// use futures_test::{assert_sink_pending, assert_sink_ready, assert_sink_send};
+/// use futures_test::assert_stream_next;
+///
+/// let (mut tx, mut rx) = mpsc::channel::<i32>(0);
+///
+/// assert_sink_ready!(tx);
+/// assert_sink_send!(tx, 1);
+/// assert_sink_pending!(tx);
+/// assert_stream_next!(rx, 1);
+/// assert_sink_ready!(tx);
+/// ```
+#[macro_export]
+macro_rules! assert_sink_pending {
+    ($sink:expr) => {{
+        let mut sink = &mut $sink;
+        $crate::__private::assert::assert_is_unpin_sink(sink);
+        let sink = $crate::__private::Pin::new(sink);
+        let mut cx = $crate::task::noop_context();
+        if $crate::__private::Sink::poll_ready(sink, &mut cx).is_ready() {
+            panic!("assertion failed: sink is not pending");
+        }
+    }};
+}
+
+/// Assert that the provided sink is ready, and accepts the provided item with
+/// [`start_send`](futures_sink::Sink::start_send).
+///
+/// # Examples
+///
+/// ```
+/// use futures::channel::mpsc;
+/// use futures_test::{assert_sink_pending, assert_sink_ready, assert_sink_send};
+/// use futures_test::assert_stream_next;
+///
+/// let (mut tx, mut rx) = mpsc::channel::<i32>(0);
+///
+/// assert_sink_ready!(tx);
+/// assert_sink_send!(tx, 1);
+/// assert_sink_pending!(tx);
+/// assert_stream_next!(rx, 1);
+/// assert_sink_ready!(tx);
+/// ```
+#[macro_export]
+macro_rules! assert_sink_send {
+    ($sink:expr, $item:expr) => {{
+        let mut sink = &mut $sink;
+        $crate::assert_sink_ready!(sink);
+        let sink = $crate::__private::Pin::new(sink);
+        if let $crate::__private::Err(e) = $crate::__private::Sink::start_send(sink, $item) {
+            panic!("assertion failed: expected sink to accept item but it failed: {:?}", e);
+        }
+    }};
+}
+
+/// Assert that the next call to [`poll_flush`](futures_sink::Sink::poll_flush)
+/// on the provided sink will return
+/// [`Poll::Ready`](futures_core::task::Poll::Ready) with `Ok`.
+///
+/// # Examples
+///
+/// ```
+/// use futures::sink;
+/// use futures_test::{assert_sink_flushed, assert_sink_send};
+///
+/// let mut sink = sink::drain::<i32>();
+///
+/// assert_sink_send!(sink, 1);
+/// assert_sink_flushed!(sink);
+/// ```
+#[macro_export]
+macro_rules! assert_sink_flushed {
+    ($sink:expr) => {{
+        let mut sink = &mut $sink;
+        $crate::__private::assert::assert_is_unpin_sink(sink);
+        let sink = $crate::__private::Pin::new(sink);
+        let mut cx = $crate::task::noop_context();
+        match $crate::__private::Sink::poll_flush(sink, &mut cx) {
+            $crate::__private::task::Poll::Ready($crate::__private::Ok(())) => {}
+            $crate::__private::task::Poll::Ready($crate::__private::Err(e)) => {
+                panic!("assertion failed: expected sink to be flushed but it failed: {:?}", e);
+            }
+            $crate::__private::task::Poll::Pending => {
+                panic!("assertion failed: expected sink to be flushed but it was pending");
+            }
+        }
+    }};
+}
@@ -149,6 +149,14 @@ impl<S: AsyncSeek> AsyncSeek for AssertUnmoved<S> {
     ) -> Poll<io::Result<u64>> {
         self.poll_with(|s| s.poll_seek(cx, pos))
     }
+
+    fn poll_seek_relative(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        offset: i64,
+    ) -> Poll<io::Result<()>> {
+        self.poll_with(|s| s.poll_seek_relative(cx, offset))
+    }
 }
 
 impl<R: AsyncBufRead> AsyncBufRead for AssertUnmoved<R> {
@@ -8,6 +8,7 @@ use std::thread;
 
 pub use crate::assert_unmoved::AssertUnmoved;
 pub use crate::interleave_pending::InterleavePending;
+pub use crate::poll_count::{PollCount, PollCounter};
 
 /// Additional combinators for testing futures.
 pub trait FutureTestExt: Future {
@@ -103,6 +104,32 @@ pub trait FutureTestExt: Future {
     {
         InterleavePending::new(self)
     }
+
+    /// Counts the number of times the future is polled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures::future::{self, Future};
+    /// use futures_test::future::FutureTestExt;
+    /// use futures_test::task::noop_context;
+    /// use futures::pin_mut;
+    ///
+    /// let future = future::ready(1).interleave_pending().count_polls();
+    /// pin_mut!(future);
+    ///
+    /// let mut cx = noop_context();
+    ///
+    /// assert!(future.as_mut().poll(&mut cx).is_pending());
+    /// assert!(future.as_mut().poll(&mut cx).is_ready());
+    /// assert_eq!(future.polls(), 2);
+    /// ```
+    fn count_polls(self) -> PollCount<Self>
+    where
+        Self: Sized,
+    {
+        PollCount::new(self)
+    }
 }
 
 impl<Fut> FutureTestExt for Fut where Fut: Future {}
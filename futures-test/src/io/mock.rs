@@ -0,0 +1,230 @@
+use futures_io::{self as io, AsyncRead, AsyncWrite};
+use std::{
+    cmp,
+    collections::VecDeque,
+    mem,
+    pin::Pin,
+    task::{Context, Poll, Waker},
+    thread,
+};
+
+/// I/O object which plays back a script of reads and writes, built with a
+/// [`MockBuilder`].
+///
+/// Each read returns the next bytes scripted with [`read`](MockBuilder::read),
+/// and each write is asserted to match the next bytes scripted with
+/// [`write`](MockBuilder::write). The bytes of an action can be read or written
+/// over several calls, e.g. when the buffer is smaller than the action.
+///
+/// A read polled while the next action is a write returns
+/// [`Poll::Pending`] until the write is done, and the other way around, so a
+/// task can read and write concurrently. Once the script is exhausted, reads
+/// return end-of-file, and writes panic.
+///
+/// The mock panics when it is dropped before the whole script was played back.
+///
+/// # Examples
+///
+/// ```
+/// use futures::executor::block_on;
+/// use futures::io::{AsyncReadExt, AsyncWriteExt};
+/// use futures_test::io::Mock;
+///
+/// let mut mock = Mock::builder().write(b"PING\n").read(b"PONG\n").build();
+///
+/// block_on(async {
+///     mock.write_all(b"PING\n").await?;
+///     let mut buf = [0; 5];
+///     mock.read_exact(&mut buf).await?;
+///     assert_eq!(&buf, b"PONG\n");
+///     Ok::<(), std::io::Error>(())
+/// })?;
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[derive(Debug)]
+pub struct Mock {
+    actions: VecDeque<Action>,
+    read_waker: Option<Waker>,
+    write_waker: Option<Waker>,
+}
+
+#[derive(Debug)]
+enum Action {
+    Read(Vec<u8>),
+    Write(Vec<u8>),
+    Pending,
+    ReadError(io::Error),
+    WriteError(io::Error),
+}
+
+/// Builder of the script of a [`Mock`].
+///
+/// See [`Mock::builder`].
+#[derive(Debug, Default)]
+pub struct MockBuilder {
+    actions: VecDeque<Action>,
+}
+
+impl MockBuilder {
+    /// Creates a builder with an empty script.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Scripts a read of the given bytes.
+    pub fn read(&mut self, buf: &[u8]) -> &mut Self {
+        if !buf.is_empty() {
+            self.actions.push_back(Action::Read(buf.to_vec()));
+        }
+        self
+    }
+
+    /// Scripts a write, which is asserted to be of the given bytes.
+    pub fn write(&mut self, buf: &[u8]) -> &mut Self {
+        if !buf.is_empty() {
+            self.actions.push_back(Action::Write(buf.to_vec()));
+        }
+        self
+    }
+
+    /// Scripts a [`Poll::Pending`] returned by the next read or write.
+    ///
+    /// The task is woken right away, like with
+    /// [`interleave_pending`](super::AsyncReadTestExt::interleave_pending).
+    pub fn pending(&mut self) -> &mut Self {
+        self.actions.push_back(Action::Pending);
+        self
+    }
+
+    /// Scripts a read which fails with the given error.
+    pub fn read_error(&mut self, error: io::Error) -> &mut Self {
+        self.actions.push_back(Action::ReadError(error));
+        self
+    }
+
+    /// Scripts a write which fails with the given error.
+    pub fn write_error(&mut self, error: io::Error) -> &mut Self {
+        self.actions.push_back(Action::WriteError(error));
+        self
+    }
+
+    /// Builds a mock which plays back the script, leaving the builder empty.
+    pub fn build(&mut self) -> Mock {
+        Mock {
+            actions: mem::replace(&mut self.actions, VecDeque::new()),
+            read_waker: None,
+            write_waker: None,
+        }
+    }
+}
+
+impl Mock {
+    /// Creates a builder of the script of a mock.
+    pub fn builder() -> MockBuilder {
+        MockBuilder::new()
+    }
+
+    // Pops the action an operation is done with, and wakes the tasks waiting
+    // for it to be done.
+    fn advance(&mut self) -> Action {
+        let action = self.actions.pop_front().unwrap();
+        for waker in self.read_waker.take().into_iter().chain(self.write_waker.take()) {
+            waker.wake();
+        }
+        action
+    }
+}
+
+impl AsyncRead for Mock {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        let this = self.get_mut();
+        match this.actions.front_mut() {
+            None => Poll::Ready(Ok(0)),
+            Some(Action::Read(data)) => {
+                let n = cmp::min(buf.len(), data.len());
+                buf[..n].copy_from_slice(&data[..n]);
+                data.drain(..n);
+                if data.is_empty() {
+                    this.advance();
+                }
+                Poll::Ready(Ok(n))
+            }
+            Some(Action::ReadError(_)) => match this.advance() {
+                Action::ReadError(error) => Poll::Ready(Err(error)),
+                _ => unreachable!(),
+            },
+            Some(Action::Pending) => {
+                this.advance();
+                cx.waker().wake_by_ref();
+                Poll::Pending
+            }
+            Some(Action::Write(_)) | Some(Action::WriteError(_)) => {
+                this.read_waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+impl AsyncWrite for Mock {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        let this = self.get_mut();
+        match this.actions.front_mut() {
+            None => panic!("unexpected write of {:?}, the script is exhausted", buf),
+            Some(Action::Write(expected)) => {
+                let n = cmp::min(buf.len(), expected.len());
+                assert_eq!(
+                    &buf[..n],
+                    &expected[..n],
+                    "the written bytes don't match the scripted write"
+                );
+                expected.drain(..n);
+                if expected.is_empty() {
+                    this.advance();
+                }
+                Poll::Ready(Ok(n))
+            }
+            Some(Action::WriteError(_)) => match this.advance() {
+                Action::WriteError(error) => Poll::Ready(Err(error)),
+                _ => unreachable!(),
+            },
+            Some(Action::Pending) => {
+                this.advance();
+                cx.waker().wake_by_ref();
+                Poll::Pending
+            }
+            Some(Action::Read(_)) | Some(Action::ReadError(_)) => {
+                this.write_waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_close(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl Drop for Mock {
+    fn drop(&mut self) {
+        if !thread::panicking() {
+            assert!(
+                self.actions.is_empty(),
+                "the mock was dropped before the end of the script, next action: {:?}",
+                self.actions.front().unwrap()
+            );
+        }
+    }
+}
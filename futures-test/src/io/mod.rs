@@ -2,6 +2,9 @@
 
 mod limited;
 
+mod mock;
+pub use mock::{Mock, MockBuilder};
+
 pub mod read;
 pub use read::AsyncReadTestExt;
 
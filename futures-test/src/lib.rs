@@ -26,6 +26,7 @@ compile_error!(
 pub mod __private {
     pub use futures_core::{future, stream, task};
     pub use futures_executor::block_on;
+    pub use futures_sink::Sink;
     pub use std::{
         option::Option::{None, Some},
         pin::Pin,
@@ -56,8 +57,12 @@ pub mod sink;
 #[cfg(feature = "std")]
 pub mod io;
 
+#[cfg(feature = "std")]
+pub mod time;
+
 mod assert_unmoved;
 mod interleave_pending;
+mod poll_count;
 mod track_closed;
 
 /// Enables an `async` test function. The generated future will be run to completion with
@@ -0,0 +1,143 @@
+use futures_core::future::{FusedFuture, Future};
+use futures_core::stream::{FusedStream, Stream};
+use futures_sink::Sink;
+use pin_project::pin_project;
+use std::{
+    pin::Pin,
+    sync::atomic::{AtomicUsize, Ordering},
+    sync::Arc,
+    task::{Context, Poll},
+};
+
+/// Wrapper that counts the number of times it was polled.
+///
+/// See the `count_polls` methods on:
+/// * [`FutureTestExt`](crate::future::FutureTestExt::count_polls)
+/// * [`StreamTestExt`](crate::stream::StreamTestExt::count_polls)
+/// * [`SinkTestExt`](crate::sink::SinkTestExt::count_polls_sink)
+#[pin_project]
+#[derive(Debug)]
+pub struct PollCount<T> {
+    #[pin]
+    inner: T,
+    count: Arc<AtomicUsize>,
+}
+
+/// Number of times a [`PollCount`] was polled, which stays accessible once
+/// the wrapper was moved into the code under test.
+///
+/// See [`PollCount::counter`].
+#[derive(Clone, Debug)]
+pub struct PollCounter {
+    count: Arc<AtomicUsize>,
+}
+
+impl PollCounter {
+    /// Get the current count.
+    pub fn get(&self) -> usize {
+        self.count.load(Ordering::SeqCst)
+    }
+}
+
+impl PartialEq<usize> for PollCounter {
+    fn eq(&self, other: &usize) -> bool {
+        self.get() == *other
+    }
+}
+
+impl<T> PollCount<T> {
+    pub(crate) fn new(inner: T) -> Self {
+        Self { inner, count: Arc::new(AtomicUsize::new(0)) }
+    }
+
+    /// Returns the number of times this wrapper was polled.
+    pub fn polls(&self) -> usize {
+        self.count.load(Ordering::SeqCst)
+    }
+
+    /// Returns a handle to the number of times this wrapper was polled.
+    pub fn counter(&self) -> PollCounter {
+        PollCounter { count: self.count.clone() }
+    }
+
+    /// Acquires a reference to the underlying object that this adaptor is
+    /// wrapping.
+    pub fn get_ref(&self) -> &T {
+        &self.inner
+    }
+
+    /// Acquires a mutable reference to the underlying object that this
+    /// adaptor is wrapping.
+    pub fn get_mut(&mut self) -> &mut T {
+        &mut self.inner
+    }
+
+    /// Acquires a pinned mutable reference to the underlying object that
+    /// this adaptor is wrapping.
+    pub fn get_pin_mut(self: Pin<&mut Self>) -> Pin<&mut T> {
+        self.project().inner
+    }
+
+    /// Consumes this adaptor returning the underlying object.
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+
+    fn counted(self: Pin<&mut Self>) -> Pin<&mut T> {
+        let this = self.project();
+        this.count.fetch_add(1, Ordering::SeqCst);
+        this.inner
+    }
+}
+
+impl<Fut: Future> Future for PollCount<Fut> {
+    type Output = Fut::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.counted().poll(cx)
+    }
+}
+
+impl<Fut: FusedFuture> FusedFuture for PollCount<Fut> {
+    fn is_terminated(&self) -> bool {
+        self.inner.is_terminated()
+    }
+}
+
+impl<St: Stream> Stream for PollCount<St> {
+    type Item = St::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.counted().poll_next(cx)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<St: FusedStream> FusedStream for PollCount<St> {
+    fn is_terminated(&self) -> bool {
+        self.inner.is_terminated()
+    }
+}
+
+impl<Si: Sink<Item>, Item> Sink<Item> for PollCount<Si> {
+    type Error = Si::Error;
+
+    fn poll_ready(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.counted().poll_ready(cx)
+    }
+
+    fn start_send(self: Pin<&mut Self>, item: Item) -> Result<(), Self::Error> {
+        self.project().inner.start_send(item)
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.counted().poll_flush(cx)
+    }
+
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.counted().poll_close(cx)
+    }
+}
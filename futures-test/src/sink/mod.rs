@@ -4,6 +4,7 @@ use futures_sink::Sink;
 
 pub use crate::assert_unmoved::AssertUnmoved;
 pub use crate::interleave_pending::InterleavePending;
+pub use crate::poll_count::{PollCount, PollCounter};
 pub use crate::track_closed::TrackClosed;
 
 /// Additional combinators for testing sinks.
@@ -77,6 +78,33 @@ pub trait SinkTestExt<Item>: Sink<Item> {
     {
         TrackClosed::new(self)
     }
+
+    /// Counts the number of times the sink is polled by
+    /// [`poll_ready`](Sink::poll_ready), [`poll_flush`](Sink::poll_flush)
+    /// or [`poll_close`](Sink::poll_close).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::sink::{self, SinkExt};
+    /// use futures_test::sink::SinkTestExt;
+    ///
+    /// let mut sink = sink::drain::<i32>().count_polls_sink();
+    ///
+    /// sink.feed(1).await?;
+    /// assert_eq!(sink.polls(), 1);
+    /// sink.flush().await?;
+    /// assert_eq!(sink.polls(), 2);
+    /// # Ok::<(), std::convert::Infallible>(()) })?;
+    /// # Ok::<(), std::convert::Infallible>(())
+    /// ```
+    fn count_polls_sink(self) -> PollCount<Self>
+    where
+        Self: Sized,
+    {
+        PollCount::new(self)
+    }
 }
 
 impl<Item, W> SinkTestExt<Item> for W where W: Sink<Item> {}
@@ -4,6 +4,7 @@ use futures_core::stream::Stream;
 
 pub use crate::assert_unmoved::AssertUnmoved;
 pub use crate::interleave_pending::InterleavePending;
+pub use crate::poll_count::{PollCount, PollCounter};
 
 /// Additional combinators for testing streams.
 pub trait StreamTestExt: Stream {
@@ -52,6 +53,34 @@ pub trait StreamTestExt: Stream {
     {
         InterleavePending::new(self)
     }
+
+    /// Counts the number of times the stream is polled.
+    ///
+    /// This lets a test check that a combinator doesn't poll the streams it
+    /// wraps more than necessary, e.g. after they ended.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures::stream::{self, StreamExt};
+    /// use futures_test::stream::StreamTestExt;
+    /// use futures_test::{assert_stream_done, assert_stream_next};
+    ///
+    /// let stream = stream::iter(vec![1]).count_polls();
+    /// let counter = stream.counter();
+    /// let mut stream = stream.fuse();
+    ///
+    /// assert_stream_next!(stream, 1);
+    /// assert_stream_done!(stream);
+    /// assert_stream_done!(stream);
+    /// assert_eq!(counter, 2);
+    /// ```
+    fn count_polls(self) -> PollCount<Self>
+    where
+        Self: Sized,
+    {
+        PollCount::new(self)
+    }
 }
 
 impl<St> StreamTestExt for St where St: Stream {}
@@ -35,6 +35,10 @@
 //!   called.
 //! - [`RecordSpawner`](crate::task::RecordSpawner) records the spawned futures.
 //!
+//! Test executors:
+//! - [`RecordingExecutor`](crate::task::RecordingExecutor) runs its tasks in a
+//!   deterministic order, and records when they are polled and woken.
+//!
 //! For convenience there additionally exist various functions that directly
 //! return waker/spawner references: [`noop_waker_ref`](crate::task::noop_waker_ref),
 //! [`panic_waker_ref`](crate::task::panic_waker_ref), [`noop_spawner_mut`](crate::task::noop_spawner_mut) and [`panic_spawner_mut`](crate::task::panic_spawner_mut).
@@ -56,5 +60,8 @@ pub use self::panic_waker::{panic_waker, panic_waker_ref};
 mod record_spawner;
 pub use self::record_spawner::RecordSpawner;
 
+mod recording_executor;
+pub use self::recording_executor::{Event, RecordingExecutor, RecordingSpawner, TaskId};
+
 mod wake_counter;
 pub use self::wake_counter::{new_count_waker, AwokenCount};
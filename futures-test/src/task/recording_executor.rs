@@ -0,0 +1,276 @@
+use futures_core::future::Future;
+use futures_core::task::{Context, Poll};
+use futures_task::{FutureObj, LocalFutureObj, LocalSpawn, Spawn, SpawnError};
+use futures_util::task::{self, ArcWake};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fmt;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::sync::{Arc, Mutex};
+
+/// Identifier of a task spawned on a [`RecordingExecutor`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct TaskId(usize);
+
+/// An event recorded by a [`RecordingExecutor`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Event {
+    /// The task was spawned.
+    Spawned(TaskId),
+    /// The waker of the task was woken.
+    Woken(TaskId),
+    /// The task was polled, and returned the given result.
+    Polled(TaskId, Poll<()>),
+}
+
+/// A single-threaded executor which records the order in which its tasks are
+/// polled and woken.
+///
+/// The tasks are polled in the order in which they were woken, unless the
+/// executor is created [`with_seed`](RecordingExecutor::with_seed), which
+/// makes it pick the next task to poll among the woken ones pseudo-randomly.
+/// Running a test with several seeds shakes out assumptions about the order in
+/// which tasks run, while each seed still gives a reproducible order.
+///
+/// The executor can also check that tasks aren't woken spuriously, see
+/// [`assert_no_spurious_wakeups`](RecordingExecutor::assert_no_spurious_wakeups).
+///
+/// # Examples
+///
+/// ```
+/// use futures::channel::oneshot;
+/// use futures::task::Poll;
+/// use futures_test::task::{Event, RecordingExecutor};
+///
+/// let executor = RecordingExecutor::new();
+/// let (tx, rx) = oneshot::channel();
+/// let receiver = executor.spawn(async move { assert_eq!(rx.await, Ok(1)) });
+/// let sender = executor.spawn(async move { tx.send(1).unwrap() });
+/// executor.run_until_stalled();
+///
+/// assert_eq!(
+///     executor.events(),
+///     vec![
+///         Event::Spawned(receiver),
+///         Event::Spawned(sender),
+///         Event::Polled(receiver, Poll::Pending),
+///         Event::Woken(receiver),
+///         Event::Polled(sender, Poll::Ready(())),
+///         Event::Polled(receiver, Poll::Ready(())),
+///     ]
+/// );
+/// executor.assert_no_spurious_wakeups();
+/// ```
+pub struct RecordingExecutor {
+    tasks: Rc<RefCell<Tasks>>,
+    shared: Arc<Mutex<Shared>>,
+    rng: RefCell<Option<u64>>,
+}
+
+#[derive(Default)]
+struct Tasks {
+    next_id: usize,
+    pending: HashMap<TaskId, Task>,
+}
+
+struct Task {
+    future: LocalFutureObj<'static, ()>,
+    was_polled: bool,
+}
+
+// The state which the wakers, which may be sent to other threads, access.
+#[derive(Default)]
+struct Shared {
+    // The woken tasks which weren't polled since.
+    ready: Vec<TaskId>,
+    events: Vec<Event>,
+    // Whether the poll in progress woke or spawned a task.
+    made_progress: bool,
+    spurious_wakeups: Vec<TaskId>,
+}
+
+impl Shared {
+    fn schedule(&mut self, id: TaskId) {
+        if !self.ready.contains(&id) {
+            self.ready.push(id);
+        }
+    }
+}
+
+struct TaskWaker {
+    id: TaskId,
+    shared: Arc<Mutex<Shared>>,
+}
+
+impl ArcWake for TaskWaker {
+    fn wake_by_ref(arc_self: &Arc<Self>) {
+        let mut shared = arc_self.shared.lock().unwrap();
+        shared.events.push(Event::Woken(arc_self.id));
+        shared.made_progress = true;
+        shared.schedule(arc_self.id);
+    }
+}
+
+impl RecordingExecutor {
+    /// Creates an executor which polls the tasks in the order in which they
+    /// were woken.
+    pub fn new() -> Self {
+        Self {
+            tasks: Rc::new(RefCell::new(Tasks::default())),
+            shared: Arc::new(Mutex::new(Shared::default())),
+            rng: RefCell::new(None),
+        }
+    }
+
+    /// Creates an executor which polls the woken tasks in a pseudo-random order
+    /// determined by `seed`.
+    pub fn with_seed(seed: u64) -> Self {
+        let executor = Self::new();
+        // xorshift gets stuck at zero.
+        *executor.rng.borrow_mut() = Some(seed | 1);
+        executor
+    }
+
+    /// Spawns a task, which is polled on the next run of the executor.
+    pub fn spawn<Fut>(&self, future: Fut) -> TaskId
+    where
+        Fut: Future<Output = ()> + 'static,
+    {
+        self.spawner().spawn_task(LocalFutureObj::new(Box::new(future)))
+    }
+
+    /// Returns a handle spawning tasks on this executor, which the tasks can
+    /// use to spawn other tasks.
+    pub fn spawner(&self) -> RecordingSpawner {
+        RecordingSpawner { tasks: self.tasks.clone(), shared: self.shared.clone() }
+    }
+
+    /// Runs the woken tasks until none of them is woken anymore.
+    pub fn run_until_stalled(&self) {
+        while let Some(id) = self.next_task() {
+            let mut task = match self.tasks.borrow_mut().pending.remove(&id) {
+                Some(task) => task,
+                // The task completed before it was woken.
+                None => continue,
+            };
+            self.shared.lock().unwrap().made_progress = false;
+
+            let waker = task::waker(Arc::new(TaskWaker { id, shared: self.shared.clone() }));
+            let poll = Pin::new(&mut task.future).poll(&mut Context::from_waker(&waker));
+
+            let mut shared = self.shared.lock().unwrap();
+            shared.events.push(Event::Polled(id, poll));
+            if poll.is_pending() {
+                if task.was_polled && !shared.made_progress {
+                    shared.spurious_wakeups.push(id);
+                }
+                drop(shared);
+                task.was_polled = true;
+                self.tasks.borrow_mut().pending.insert(id, task);
+            }
+        }
+    }
+
+    /// Returns the number of tasks which didn't complete yet.
+    pub fn pending_tasks(&self) -> usize {
+        self.tasks.borrow().pending.len()
+    }
+
+    /// Returns the events recorded since the executor was created.
+    pub fn events(&self) -> Vec<Event> {
+        self.shared.lock().unwrap().events.clone()
+    }
+
+    /// Asserts that no task was woken without making progress.
+    ///
+    /// A wakeup is considered spurious if the task returned
+    /// [`Poll::Pending`] when it was polled after it, without waking or
+    /// spawning any task. The check is meant for tests of the wakeups of a
+    /// primitive, where each task awaits a single operation of the primitive,
+    /// and should only be woken once the operation can complete.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a task was woken spuriously, listing those tasks.
+    pub fn assert_no_spurious_wakeups(&self) {
+        let shared = self.shared.lock().unwrap();
+        assert!(
+            shared.spurious_wakeups.is_empty(),
+            "tasks were woken without making progress: {:?}",
+            shared.spurious_wakeups
+        );
+    }
+
+    fn next_task(&self) -> Option<TaskId> {
+        let mut shared = self.shared.lock().unwrap();
+        if shared.ready.is_empty() {
+            return None;
+        }
+        let index = match &mut *self.rng.borrow_mut() {
+            Some(state) => {
+                // https://en.wikipedia.org/wiki/Xorshift#xorshift*
+                *state ^= *state >> 12;
+                *state ^= *state << 25;
+                *state ^= *state >> 27;
+                (state.wrapping_mul(0x2545_f491_4f6c_dd1d) % shared.ready.len() as u64) as usize
+            }
+            None => 0,
+        };
+        Some(shared.ready.remove(index))
+    }
+}
+
+impl Default for RecordingExecutor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for RecordingExecutor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RecordingExecutor").field("pending_tasks", &self.pending_tasks()).finish()
+    }
+}
+
+/// A handle spawning tasks on a [`RecordingExecutor`], returned by
+/// [`RecordingExecutor::spawner`].
+#[derive(Clone)]
+pub struct RecordingSpawner {
+    tasks: Rc<RefCell<Tasks>>,
+    shared: Arc<Mutex<Shared>>,
+}
+
+impl RecordingSpawner {
+    fn spawn_task(&self, future: LocalFutureObj<'static, ()>) -> TaskId {
+        let mut tasks = self.tasks.borrow_mut();
+        let id = TaskId(tasks.next_id);
+        tasks.next_id += 1;
+        tasks.pending.insert(id, Task { future, was_polled: false });
+        let mut shared = self.shared.lock().unwrap();
+        shared.events.push(Event::Spawned(id));
+        shared.made_progress = true;
+        shared.schedule(id);
+        id
+    }
+}
+
+impl fmt::Debug for RecordingSpawner {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RecordingSpawner").finish()
+    }
+}
+
+impl Spawn for RecordingSpawner {
+    fn spawn_obj(&self, future: FutureObj<'static, ()>) -> Result<(), SpawnError> {
+        self.spawn_task(future.into());
+        Ok(())
+    }
+}
+
+impl LocalSpawn for RecordingSpawner {
+    fn spawn_local_obj(&self, future: LocalFutureObj<'static, ()>) -> Result<(), SpawnError> {
+        self.spawn_task(future);
+        Ok(())
+    }
+}
@@ -0,0 +1,150 @@
+//! A mock clock for testing time-based code.
+
+use futures_core::future::{FusedFuture, Future};
+use futures_core::task::{Context, Poll, Waker};
+use futures_util::time::Timer;
+use std::collections::HashMap;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// A [`Timer`] whose time only passes when it is [`advance`](MockClock::advance)d.
+///
+/// This lets timeouts, debouncing, throttling and other time-based code be
+/// tested instantly and deterministically: the delays created by the clock
+/// complete exactly when the clock is advanced past their deadline.
+///
+/// Clones of a clock share the same time.
+///
+/// # Examples
+///
+/// ```
+/// use futures::future::FutureExt;
+/// use futures::time::Timer;
+/// use futures_test::time::MockClock;
+/// use std::time::Duration;
+///
+/// let clock = MockClock::new();
+/// let mut delay = clock.delay(Duration::from_secs(10));
+///
+/// clock.advance(Duration::from_secs(9));
+/// assert!((&mut delay).now_or_never().is_none());
+/// clock.advance(Duration::from_secs(1));
+/// assert!(delay.now_or_never().is_some());
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct MockClock {
+    state: Arc<Mutex<State>>,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    elapsed: Duration,
+    next_id: u64,
+    // The pending delays which were polled, by id.
+    waiting: HashMap<u64, (Duration, Waker)>,
+}
+
+impl MockClock {
+    /// Creates a new clock, at which no time has elapsed.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the time elapsed since the clock was created.
+    pub fn elapsed(&self) -> Duration {
+        self.state.lock().unwrap().elapsed
+    }
+
+    /// Advances the clock by `duration`, waking the tasks waiting on the delays
+    /// whose deadline has elapsed.
+    pub fn advance(&self, duration: Duration) {
+        let mut state = self.state.lock().unwrap();
+        state.elapsed += duration;
+        let elapsed = state.elapsed;
+        let mut wakers = Vec::new();
+        state.waiting.retain(|_, (deadline, waker)| {
+            if *deadline <= elapsed {
+                wakers.push(waker.clone());
+                false
+            } else {
+                true
+            }
+        });
+        drop(state);
+        for waker in wakers {
+            waker.wake();
+        }
+    }
+
+    /// Returns the deadline of the earliest pending delay which was polled,
+    /// as a time elapsed since the clock was created.
+    ///
+    /// This is useful to advance the clock to the next point where something
+    /// happens.
+    pub fn next_deadline(&self) -> Option<Duration> {
+        self.state.lock().unwrap().waiting.values().map(|(deadline, _)| *deadline).min()
+    }
+}
+
+impl Timer for MockClock {
+    type Delay = MockDelay;
+
+    fn delay(&self, duration: Duration) -> MockDelay {
+        let mut state = self.state.lock().unwrap();
+        let id = state.next_id;
+        state.next_id += 1;
+        MockDelay { clock: Some(self.clone()), deadline: state.elapsed + duration, id }
+    }
+}
+
+/// Future which completes once its [`MockClock`] is advanced past its
+/// deadline, created by [`MockClock::delay`](Timer::delay).
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct MockDelay {
+    // `None` indicates that the delay completed.
+    clock: Option<MockClock>,
+    deadline: Duration,
+    id: u64,
+}
+
+impl MockDelay {
+    /// Returns the time elapsed since the creation of the clock at which this
+    /// delay completes.
+    pub fn deadline(&self) -> Duration {
+        self.deadline
+    }
+}
+
+impl Future for MockDelay {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        let clock = self.clock.as_ref().expect("MockDelay polled after completion");
+        let mut state = clock.state.lock().unwrap();
+        if state.elapsed >= self.deadline {
+            state.waiting.remove(&self.id);
+            drop(state);
+            self.clock = None;
+            Poll::Ready(())
+        } else {
+            state.waiting.insert(self.id, (self.deadline, cx.waker().clone()));
+            Poll::Pending
+        }
+    }
+}
+
+impl FusedFuture for MockDelay {
+    fn is_terminated(&self) -> bool {
+        self.clock.is_none()
+    }
+}
+
+impl Drop for MockDelay {
+    fn drop(&mut self) {
+        if let Some(clock) = &self.clock {
+            clock.state.lock().unwrap().waiting.remove(&self.id);
+        }
+    }
+}
@@ -35,8 +35,18 @@ fn main() {
     // run. This is needed for compatibility with non-cargo build systems that
     // don't run the build script.
     if NO_ATOMIC_CAS_TARGETS.contains(&&*target) {
-        println!("cargo:rustc-cfg=futures_no_atomic_cas");
+        // With the `portable-atomic` feature, the atomic types and `Arc` of
+        // the `portable-atomic` crates are used instead on these targets.
+        if env::var_os("CARGO_FEATURE_PORTABLE_ATOMIC").is_some() {
+            println!("cargo:rustc-cfg=futures_portable_atomic");
+        } else {
+            println!("cargo:rustc-cfg=futures_no_atomic_cas");
+        }
     }
+    println!("cargo:rustc-check-cfg=cfg(futures_no_atomic_cas, futures_portable_atomic)");
+
+    // `loom` is set when checking the concurrency of the locks with loom.
+    println!("cargo:rustc-check-cfg=cfg(loom)");
 
     println!("cargo:rerun-if-changed=no_atomic_cas.rs");
 }
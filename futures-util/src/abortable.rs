@@ -1,11 +1,11 @@
+use crate::sync::atomic::{AtomicBool, Ordering};
+use crate::sync::Arc;
 use crate::task::AtomicWaker;
-use alloc::sync::Arc;
 use core::fmt;
 use core::pin::Pin;
-use core::sync::atomic::{AtomicBool, Ordering};
 use futures_core::future::Future;
+use futures_core::stream::{FusedStream, Stream};
 use futures_core::task::{Context, Poll};
-use futures_core::Stream;
 use pin_project_lite::pin_project;
 
 pin_project! {
@@ -70,6 +70,131 @@ impl<T> Abortable<T> {
     }
 }
 
+pin_project! {
+    /// A stream which can be remotely stopped using an `AbortHandle`, but
+    /// which yields the items that are already available before ending.
+    ///
+    /// Unlike [`Abortable`], aborting does not end the stream right away: the
+    /// underlying stream keeps being polled for as long as it returns items
+    /// without being pending, which lets adapters with internal buffers, such
+    /// as decoders with lookahead, hand out what they already hold. An
+    /// optional limit bounds the number of items yielded after the abort.
+    #[derive(Debug)]
+    #[must_use = "streams do nothing unless polled"]
+    pub struct DrainAbortable<St> {
+        #[pin]
+        stream: St,
+        inner: Arc<AbortInner>,
+        drain_limit: Option<usize>,
+        terminated: bool,
+    }
+}
+
+impl<St> DrainAbortable<St> {
+    /// Creates a new `DrainAbortable` stream using an existing
+    /// `AbortRegistration`.
+    ///
+    /// Once `abort` is called on the handle tied to `reg`, the stream yields
+    /// at most `drain_limit` more items, all of which must be immediately
+    /// available, and then ends. A `drain_limit` of `None` yields every item
+    /// that is available.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, AbortHandle, DrainAbortable, StreamExt};
+    ///
+    /// let (abort_handle, abort_registration) = AbortHandle::new_pair();
+    /// let stream = stream::iter(vec![1, 2, 3]).chain(stream::pending());
+    /// let stream = DrainAbortable::new(stream, abort_registration, 2);
+    /// abort_handle.abort();
+    ///
+    /// assert_eq!(stream.collect::<Vec<_>>().await, vec![1, 2]);
+    /// # });
+    /// ```
+    pub fn new(stream: St, reg: AbortRegistration, drain_limit: impl Into<Option<usize>>) -> Self {
+        Self { stream, inner: reg.inner, drain_limit: drain_limit.into(), terminated: false }
+    }
+
+    /// Checks whether the stream has been aborted. Note that all this
+    /// method indicates is whether [`AbortHandle::abort`] was *called*; the
+    /// stream may still yield items that were already available.
+    pub fn is_aborted(&self) -> bool {
+        self.inner.aborted.load(Ordering::Relaxed)
+    }
+}
+
+impl<St> DrainAbortable<St>
+where
+    St: Stream,
+{
+    fn poll_drain(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<St::Item>> {
+        let this = self.project();
+
+        if *this.drain_limit != Some(0) {
+            if let Poll::Ready(Some(item)) = this.stream.poll_next(cx) {
+                if let Some(limit) = this.drain_limit {
+                    *limit -= 1;
+                }
+                return Poll::Ready(Some(item));
+            }
+        }
+        *this.terminated = true;
+        Poll::Ready(None)
+    }
+}
+
+impl<St> Stream for DrainAbortable<St>
+where
+    St: Stream,
+{
+    type Item = St::Item;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        if self.terminated {
+            return Poll::Ready(None);
+        }
+        if self.is_aborted() {
+            return self.poll_drain(cx);
+        }
+
+        if let Poll::Ready(item) = self.as_mut().project().stream.poll_next(cx) {
+            return Poll::Ready(item);
+        }
+
+        // Register to receive a wakeup if the stream is aborted in the future
+        self.inner.waker.register(cx.waker());
+
+        // Check to see if the stream was aborted between the first check and
+        // registration. The stream was just pending, so there is nothing left
+        // to drain.
+        if self.is_aborted() {
+            *self.project().terminated = true;
+            return Poll::Ready(None);
+        }
+
+        Poll::Pending
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.terminated {
+            (0, Some(0))
+        } else {
+            (0, self.stream.size_hint().1)
+        }
+    }
+}
+
+impl<St> FusedStream for DrainAbortable<St>
+where
+    St: FusedStream,
+{
+    fn is_terminated(&self) -> bool {
+        self.terminated || self.stream.is_terminated()
+    }
+}
+
 /// A registration handle for an `Abortable` task.
 /// Values of this type can be acquired from `AbortHandle::new` and are used
 /// in calls to `Abortable::new`.
@@ -108,3 +108,49 @@ document_join_macro! {
         }}
     }
 }
+
+#[cfg(feature = "alloc")]
+#[allow(unreachable_pub)]
+#[doc(hidden)]
+pub use futures_macro::join_try_all_internal;
+
+/// Polls multiple futures simultaneously until all of them complete, resolving
+/// to a [`Result`] containing either a tuple of the successful outputs or a
+/// `Vec` of all the errors.
+///
+/// Unlike [`try_join!`], which completes as soon as one of the futures returns
+/// an error, `join_try_all!` keeps polling the other futures, so that every
+/// error is reported, e.g. when validating several inputs concurrently. The
+/// errors are in the order in which the futures were passed to the macro, and
+/// all the futures must have the same error type.
+///
+/// This macro is only usable inside of async functions, closures, and blocks.
+/// It is also gated behind the `async-await` feature of this library, which is
+/// activated by default.
+///
+/// # Examples
+///
+/// ```
+/// # futures::executor::block_on(async {
+/// use futures::join_try_all;
+///
+/// let a = async { Ok::<i32, &str>(1) };
+/// let b = async { Ok::<u64, &str>(2) };
+/// assert_eq!(join_try_all!(a, b), Ok((1, 2)));
+///
+/// let a = async { Err::<i32, &str>("invalid name") };
+/// let b = async { Ok::<u64, &str>(2) };
+/// let c = async { Err::<(), &str>("invalid email") };
+/// assert_eq!(join_try_all!(a, b, c), Err(vec!["invalid name", "invalid email"]));
+/// # });
+/// ```
+#[cfg(feature = "alloc")]
+#[macro_export]
+macro_rules! join_try_all {
+    ($($tokens:tt)*) => {{
+        use $crate::__private as __futures_crate;
+        $crate::join_try_all_internal! {
+            $( $tokens )*
+        }
+    }}
+}
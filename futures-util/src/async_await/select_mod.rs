@@ -143,6 +143,58 @@ macro_rules! document_select_macro {
         /// # });
         /// ```
         ///
+        /// Branches can be annotated with a `#[priority(n)]` attribute, `n` being
+        /// `0` by default. If futures of different priorities are ready, the branch
+        /// with the highest priority is selected, and only the branches of the same
+        /// priority are selected pseudo-randomly. This lets urgent messages, such as
+        /// control messages, be handled before the others:
+        ///
+        /// ```
+        /// # futures::executor::block_on(async {
+        /// use futures::channel::mpsc;
+        /// use futures::select;
+        /// use futures::stream::StreamExt;
+        /// let (data_tx, mut data_rx) = mpsc::unbounded();
+        /// let (control_tx, mut control_rx) = mpsc::unbounded();
+        /// data_tx.unbounded_send("data").unwrap();
+        /// control_tx.unbounded_send("stop").unwrap();
+        ///
+        /// let msg = select! {
+        ///     msg = data_rx.next() => msg,
+        ///     #[priority(1)]
+        ///     msg = control_rx.next() => msg,
+        /// };
+        /// assert_eq!(msg, Some("stop"));
+        /// # });
+        /// ```
+        ///
+        /// A branch can be disabled by a condition following its future, as in
+        /// `pat = fut, if condition => ...`. The condition is evaluated once, when
+        /// `select!` starts, and a disabled branch behaves as if it didn't exist: its
+        /// future expression is neither evaluated nor polled. This lets `select!`
+        /// select on an optional future:
+        ///
+        /// ```
+        /// # futures::executor::block_on(async {
+        /// use futures::future;
+        /// use futures::select;
+        /// let mut opt_fut = Some(future::ready(4));
+        ///
+        /// let res = select! {
+        ///     x = opt_fut.as_mut().unwrap(), if opt_fut.is_some() => x,
+        ///     complete => 0,
+        /// };
+        /// assert_eq!(res, 4);
+        ///
+        /// opt_fut = None;
+        /// let res = select! {
+        ///     x = opt_fut.as_mut().unwrap(), if opt_fut.is_some() => x,
+        ///     complete => 0,
+        /// };
+        /// assert_eq!(res, 0);
+        /// # });
+        /// ```
+        ///
         /// Note that the futures that have been matched over can still be mutated
         /// from inside the `select!` block's branches. This can be used to implement
         /// more complex behavior such as timer resets or writing into the head of
@@ -293,6 +345,58 @@ macro_rules! document_select_macro {
         /// # });
         /// ```
         ///
+        /// Branches can be annotated with a `#[priority(n)]` attribute, `n` being
+        /// `0` by default. If futures of different priorities are ready, the branch
+        /// with the highest priority is selected, and only the branches of the same
+        /// priority are selected in order of declaration. This lets urgent
+        /// messages, such as control messages, be handled before the others:
+        ///
+        /// ```
+        /// # futures::executor::block_on(async {
+        /// use futures::channel::mpsc;
+        /// use futures::select_biased;
+        /// use futures::stream::StreamExt;
+        /// let (data_tx, mut data_rx) = mpsc::unbounded();
+        /// let (control_tx, mut control_rx) = mpsc::unbounded();
+        /// data_tx.unbounded_send("data").unwrap();
+        /// control_tx.unbounded_send("stop").unwrap();
+        ///
+        /// let msg = select_biased! {
+        ///     msg = data_rx.next() => msg,
+        ///     #[priority(1)]
+        ///     msg = control_rx.next() => msg,
+        /// };
+        /// assert_eq!(msg, Some("stop"));
+        /// # });
+        /// ```
+        ///
+        /// A branch can be disabled by a condition following its future, as in
+        /// `pat = fut, if condition => ...`. The condition is evaluated once, when
+        /// `select_biased!` starts, and a disabled branch behaves as if it didn't exist: its
+        /// future expression is neither evaluated nor polled. This lets `select_biased!`
+        /// select on an optional future:
+        ///
+        /// ```
+        /// # futures::executor::block_on(async {
+        /// use futures::future;
+        /// use futures::select_biased;
+        /// let mut opt_fut = Some(future::ready(4));
+        ///
+        /// let res = select_biased! {
+        ///     x = opt_fut.as_mut().unwrap(), if opt_fut.is_some() => x,
+        ///     complete => 0,
+        /// };
+        /// assert_eq!(res, 4);
+        ///
+        /// opt_fut = None;
+        /// let res = select_biased! {
+        ///     x = opt_fut.as_mut().unwrap(), if opt_fut.is_some() => x,
+        ///     complete => 0,
+        /// };
+        /// assert_eq!(res, 0);
+        /// # });
+        /// ```
+        ///
         /// Note that the futures that have been matched over can still be mutated
         /// from inside the `select_biased!` block's branches. This can be used to implement
         /// more complex behavior such as timer resets or writing into the head of
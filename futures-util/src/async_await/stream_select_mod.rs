@@ -28,6 +28,31 @@ pub use futures_macro::stream_select_internal;
 /// }
 /// # });
 /// ```
+///
+/// The streams can also be combined with a runtime collection of streams of
+/// the same type, given as a trailing `..` [`SelectAll`](crate::stream::SelectAll).
+/// The `SelectAll` is polled before the other streams with a probability
+/// proportional to the number of streams it holds, but it isn't randomized
+/// itself: it yields the items of its streams in the order the streams were
+/// woken in, and a stream goes to the back of its queue once it yielded an
+/// item, so the ready streams of the `SelectAll` are served round-robin. The
+/// `SelectAll` can be accessed through the `dynamic_mut` method of the
+/// returned stream.
+///
+/// ```
+/// # futures::executor::block_on(async {
+/// use futures::{stream, StreamExt, stream_select};
+/// let control = stream::iter(vec!["control"]);
+/// let connections = stream::select_all(vec![stream::iter(vec!["first"])]);
+///
+/// let mut messages = stream_select!(control, ..connections);
+/// messages.dynamic_mut().push(stream::iter(vec!["second"]));
+///
+/// let mut messages = messages.collect::<Vec<_>>().await;
+/// messages.sort();
+/// assert_eq!(messages, vec!["control", "first", "second"]);
+/// # });
+/// ```
 #[cfg(feature = "std")]
 #[macro_export]
 macro_rules! stream_select {
@@ -273,6 +273,17 @@ mod if_std {
                 Either::Right(x) => x.poll_seek(cx, pos),
             }
         }
+
+        fn poll_seek_relative(
+            self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+            offset: i64,
+        ) -> Poll<Result<()>> {
+            match self.project() {
+                Either::Left(x) => x.poll_seek_relative(cx, offset),
+                Either::Right(x) => x.poll_seek_relative(cx, offset),
+            }
+        }
     }
 
     impl<A, B> AsyncBufRead for Either<A, B>
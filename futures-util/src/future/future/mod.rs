@@ -10,7 +10,11 @@ use core::pin::Pin;
 
 use crate::fns::{inspect_fn, into_fn, ok_fn, InspectFn, IntoFn, OkFn};
 use crate::future::{assert_future, Either};
+#[cfg(feature = "instrument")]
+use crate::instrument::Instrument;
 use crate::stream::assert_stream;
+#[cfg(feature = "std")]
+use crate::task::LocalKey;
 #[cfg(feature = "alloc")]
 use futures_core::future::{BoxFuture, LocalBoxFuture};
 use futures_core::{
@@ -100,6 +104,12 @@ mod catch_unwind;
 #[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
 pub use self::catch_unwind::CatchUnwind;
 
+#[cfg(feature = "std")]
+mod with_value;
+#[cfg(feature = "std")]
+#[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
+pub use self::with_value::WithValue;
+
 #[cfg(feature = "channel")]
 #[cfg_attr(docsrs, doc(cfg(feature = "channel")))]
 #[cfg(feature = "std")]
@@ -437,6 +447,70 @@ pub trait FutureExt: Future {
         ))
     }
 
+    /// Sets the task-local value of `key` to `value` while this future is
+    /// polled, so that it can be read with [`LocalKey::with`] from anywhere
+    /// beneath this future.
+    ///
+    /// The value is dropped with the returned future. Wrapping a future in
+    /// `with_value` again for the same key shadows the outer value while the
+    /// inner future is polled.
+    ///
+    /// This method is only available when the `std` feature of this
+    /// library is activated, and it is activated by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::future::FutureExt;
+    /// use futures::task_local;
+    ///
+    /// task_local! {
+    ///     static NAME: &'static str;
+    /// }
+    ///
+    /// let future = async {
+    ///     let inner = async { NAME.with(|name| *name) }.with_value(&NAME, "inner");
+    ///     (NAME.with(|name| *name), inner.await)
+    /// };
+    /// assert_eq!(future.with_value(&NAME, "outer").await, ("outer", "inner"));
+    /// # });
+    /// ```
+    #[cfg(feature = "std")]
+    fn with_value<T: 'static>(self, key: &'static LocalKey<T>, value: T) -> WithValue<Self, T>
+    where
+        Self: Sized,
+    {
+        assert_future::<Self::Output, _>(WithValue::new(self, key, value))
+    }
+
+    /// Names this future for the [`PollHook`](crate::instrument::PollHook)s
+    /// registered with [`add_hook`](crate::instrument::add_hook), which are
+    /// called around each of its polls.
+    ///
+    /// This method is only available when the `instrument` feature of this
+    /// library is activated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures_util::future::{self, FutureExt};
+    ///
+    /// let future = future::ready(1).instrument("ready");
+    /// assert_eq!(future.name(), "ready");
+    /// assert_eq!(future.await, 1);
+    /// # });
+    /// ```
+    #[cfg(feature = "instrument")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "instrument")))]
+    fn instrument(self, name: &'static str) -> Instrument<Self>
+    where
+        Self: Sized,
+    {
+        assert_future::<Self::Output, _>(Instrument::new(self, name))
+    }
+
     /// Create a cloneable handle to this future where all handles will resolve
     /// to the same result.
     ///
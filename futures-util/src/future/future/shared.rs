@@ -260,7 +260,7 @@ where
         }
 
         let waker = waker_ref(&inner.notifier);
-        let mut cx = Context::from_waker(&waker);
+        let mut cx = crate::task::child_context(cx, &waker);
 
         struct Reset<'a>(&'a AtomicUsize);
 
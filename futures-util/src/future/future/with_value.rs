@@ -0,0 +1,39 @@
+use crate::task::LocalKey;
+use core::pin::Pin;
+use futures_core::future::{FusedFuture, Future};
+use futures_core::task::{Context, Poll};
+use pin_project_lite::pin_project;
+
+pin_project! {
+    /// Future for the [`with_value`](super::FutureExt::with_value) method.
+    #[derive(Debug)]
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct WithValue<Fut, T: 'static> {
+        #[pin]
+        future: Fut,
+        key: &'static LocalKey<T>,
+        value: Option<T>,
+    }
+}
+
+impl<Fut, T: 'static> WithValue<Fut, T> {
+    pub(super) fn new(future: Fut, key: &'static LocalKey<T>, value: T) -> Self {
+        Self { future, key, value: Some(value) }
+    }
+}
+
+impl<Fut: Future, T: 'static> Future for WithValue<Fut, T> {
+    type Output = Fut::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        let future = this.future;
+        this.key.scope(this.value, || future.poll(cx))
+    }
+}
+
+impl<Fut: FusedFuture, T: 'static> FusedFuture for WithValue<Fut, T> {
+    fn is_terminated(&self) -> bool {
+        self.future.is_terminated()
+    }
+}
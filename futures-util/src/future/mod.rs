@@ -28,7 +28,7 @@ pub use self::future::{
 pub use self::future::FlattenStream;
 
 #[cfg(feature = "std")]
-pub use self::future::CatchUnwind;
+pub use self::future::{CatchUnwind, WithValue};
 
 #[cfg(feature = "channel")]
 #[cfg_attr(docsrs, doc(cfg(feature = "channel")))]
@@ -109,6 +109,11 @@ pub use self::select_ok::{select_ok, SelectOk};
 mod either;
 pub use self::either::Either;
 
+#[cfg(feature = "context-ext")]
+mod with_ext;
+#[cfg(feature = "context-ext")]
+pub use self::with_ext::{with_ext, WithExt};
+
 #[cfg(not(futures_no_atomic_cas))]
 #[cfg(feature = "alloc")]
 mod abortable;
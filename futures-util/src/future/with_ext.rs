@@ -0,0 +1,83 @@
+use super::assert_future;
+use core::any::Any;
+use core::pin::Pin;
+use core::task::ContextBuilder;
+use futures_core::future::{FusedFuture, Future};
+use futures_core::task::{Context, Poll};
+use pin_project_lite::pin_project;
+
+pin_project! {
+    /// Future for the [`with_ext`](with_ext()) function.
+    #[derive(Debug)]
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct WithExt<Fut, T> {
+        #[pin]
+        future: Fut,
+        data: T,
+    }
+}
+
+/// Polls `future` with `data` as the extension data of its
+/// [`Context`], so that the futures beneath it can reach it through
+/// [`Context::ext`], rather than through a task-local value.
+///
+/// The combinators of this library that poll their futures with a context of
+/// their own, such as `FuturesUnordered`, keep the extension data of the
+/// context they are polled with. A context only has one extension, so
+/// `data` replaces the extension of the context `future` is polled with.
+///
+/// This function is only available when the `context-ext` feature of this
+/// library is activated, which requires a nightly compiler.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(context_ext)]
+/// # futures::executor::block_on(async {
+/// use futures_util::future;
+///
+/// struct RequestId(u32);
+///
+/// let child = future::poll_fn(|cx| {
+///     let id = cx.ext().downcast_ref::<RequestId>().unwrap();
+///     std::task::Poll::Ready(id.0)
+/// });
+/// assert_eq!(future::with_ext(child, RequestId(7)).await, 7);
+/// # });
+/// ```
+pub fn with_ext<Fut, T>(future: Fut, data: T) -> WithExt<Fut, T>
+where
+    Fut: Future,
+    T: Any,
+{
+    assert_future::<Fut::Output, _>(WithExt { future, data })
+}
+
+impl<Fut, T> WithExt<Fut, T> {
+    /// Returns a mutable reference to the extension data.
+    pub fn data_mut(&mut self) -> &mut T {
+        &mut self.data
+    }
+
+    /// Consumes this combinator, returning the underlying future and the
+    /// extension data.
+    pub fn into_inner(self) -> (Fut, T) {
+        (self.future, self.data)
+    }
+}
+
+impl<Fut: Future, T: Any> Future for WithExt<Fut, T> {
+    type Output = Fut::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        let mut cx = ContextBuilder::from(cx).ext(this.data).build();
+        this.future.poll(&mut cx)
+    }
+}
+
+impl<Fut: FusedFuture, T: Any> FusedFuture for WithExt<Fut, T> {
+    fn is_terminated(&self) -> bool {
+        self.future.is_terminated()
+    }
+}
@@ -0,0 +1,210 @@
+//! Hooks for profiling the polls of futures and streams.
+//!
+//! Wrapping a future with [`FutureExt::instrument`](crate::FutureExt::instrument),
+//! or a stream with [`StreamExt::instrument`](crate::StreamExt::instrument),
+//! gives it a name. Each time it is polled, the [`PollHook`]s registered with
+//! [`add_hook`] are told when the poll starts and how long it took, so that a
+//! profiler can attribute time to the futures it is spent in.
+//!
+//! When no hook is registered, an instrumented future costs a single atomic
+//! load for each poll.
+//!
+//! This module is only available when the `instrument` feature of this
+//! library is activated.
+
+use core::pin::Pin;
+use futures_core::future::{FusedFuture, Future};
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Context, Poll};
+use pin_project_lite::pin_project;
+use std::ptr;
+use std::sync::atomic::{AtomicPtr, AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
+
+/// A callback invoked around the polls of instrumented futures and streams.
+///
+/// Hooks are called on the thread polling the future, in the order they were
+/// registered, so they should be quick.
+pub trait PollHook: Send + Sync {
+    /// Called before the future or stream named `name` is polled.
+    fn poll_start(&self, name: &'static str) {
+        let _ = name;
+    }
+
+    /// Called after the future or stream named `name` was polled, with the
+    /// time the poll took and whether it returned `Poll::Ready`.
+    fn poll_end(&self, name: &'static str, elapsed: Duration, ready: bool);
+}
+
+/// The identifier of a hook registered with [`add_hook`], to remove it with
+/// [`remove_hook`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct HookId(u64);
+
+struct Registry {
+    hooks: RwLock<Vec<(HookId, Arc<dyn PollHook>)>>,
+    next_id: AtomicU64,
+}
+
+// Allocated on first use and never freed.
+static REGISTRY: AtomicPtr<Registry> = AtomicPtr::new(ptr::null_mut());
+// The number of hooks registered, to skip the registry when there are none.
+static HOOKS: AtomicUsize = AtomicUsize::new(0);
+
+fn registry() -> &'static Registry {
+    let mut registry = REGISTRY.load(Ordering::Acquire);
+    if registry.is_null() {
+        let new = Box::into_raw(Box::new(Registry {
+            hooks: RwLock::new(Vec::new()),
+            next_id: AtomicU64::new(0),
+        }));
+        match REGISTRY.compare_exchange(ptr::null_mut(), new, Ordering::AcqRel, Ordering::Acquire) {
+            Ok(_) => registry = new,
+            Err(existing) => {
+                // Another thread won the race.
+                drop(unsafe { Box::from_raw(new) });
+                registry = existing;
+            }
+        }
+    }
+    unsafe { &*registry }
+}
+
+/// Registers `hook` to be called around the polls of all instrumented
+/// futures and streams, on all threads.
+///
+/// # Examples
+///
+/// ```
+/// use futures::executor::block_on;
+/// use futures_util::future::{self, FutureExt};
+/// use futures_util::instrument::{self, PollHook};
+/// use std::sync::atomic::{AtomicUsize, Ordering};
+/// use std::sync::Arc;
+/// use std::time::Duration;
+///
+/// struct CountPolls(Arc<AtomicUsize>);
+///
+/// impl PollHook for CountPolls {
+///     fn poll_end(&self, name: &'static str, _elapsed: Duration, _ready: bool) {
+///         if name == "answer" {
+///             self.0.fetch_add(1, Ordering::SeqCst);
+///         }
+///     }
+/// }
+///
+/// let polls = Arc::new(AtomicUsize::new(0));
+/// let id = instrument::add_hook(CountPolls(polls.clone()));
+/// assert_eq!(block_on(future::ready(42).instrument("answer")), 42);
+/// instrument::remove_hook(id);
+/// assert_eq!(polls.load(Ordering::SeqCst), 1);
+/// ```
+pub fn add_hook<H: PollHook + 'static>(hook: H) -> HookId {
+    let registry = registry();
+    let id = HookId(registry.next_id.fetch_add(1, Ordering::Relaxed));
+    let mut hooks = registry.hooks.write().unwrap();
+    hooks.push((id, Arc::new(hook)));
+    HOOKS.fetch_add(1, Ordering::SeqCst);
+    id
+}
+
+/// Removes the hook registered as `id`.
+///
+/// Returns `false` if it was already removed.
+pub fn remove_hook(id: HookId) -> bool {
+    let mut hooks = registry().hooks.write().unwrap();
+    match hooks.iter().position(|(hook_id, _)| *hook_id == id) {
+        Some(index) => {
+            hooks.remove(index);
+            HOOKS.fetch_sub(1, Ordering::SeqCst);
+            true
+        }
+        None => false,
+    }
+}
+
+// Polls with `poll`, calling the hooks around it if there are any.
+fn instrumented<T>(name: &'static str, poll: impl FnOnce() -> Poll<T>) -> Poll<T> {
+    if HOOKS.load(Ordering::SeqCst) == 0 {
+        return poll();
+    }
+    // Take the hooks out of the lock while polling, so that a hook added in
+    // between is not told about the end of a poll it didn't see start.
+    let hooks: Vec<_> =
+        registry().hooks.read().unwrap().iter().map(|(_, hook)| hook.clone()).collect();
+    for hook in &hooks {
+        hook.poll_start(name);
+    }
+    let start = Instant::now();
+    let res = poll();
+    let elapsed = start.elapsed();
+    for hook in &hooks {
+        hook.poll_end(name, elapsed, res.is_ready());
+    }
+    res
+}
+
+pin_project! {
+    /// Future and stream for the [`instrument`](crate::FutureExt::instrument)
+    /// methods of `FutureExt` and `StreamExt`.
+    #[derive(Debug)]
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct Instrument<T> {
+        #[pin]
+        inner: T,
+        name: &'static str,
+    }
+}
+
+impl<T> Instrument<T> {
+    pub(crate) fn new(inner: T, name: &'static str) -> Self {
+        Self { inner, name }
+    }
+
+    /// Returns the name given to the future or stream.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Consumes this combinator, returning the underlying future or stream.
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+}
+
+impl<Fut: Future> Future for Instrument<Fut> {
+    type Output = Fut::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        let inner = this.inner;
+        instrumented(this.name, || inner.poll(cx))
+    }
+}
+
+impl<Fut: FusedFuture> FusedFuture for Instrument<Fut> {
+    fn is_terminated(&self) -> bool {
+        self.inner.is_terminated()
+    }
+}
+
+impl<St: Stream> Stream for Instrument<St> {
+    type Item = St::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        let inner = this.inner;
+        instrumented(this.name, || inner.poll_next(cx))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<St: FusedStream> FusedStream for Instrument<St> {
+    fn is_terminated(&self) -> bool {
+        self.inner.is_terminated()
+    }
+}
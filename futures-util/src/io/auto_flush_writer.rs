@@ -0,0 +1,149 @@
+use super::BufWriter;
+use futures_core::future::Future;
+use futures_core::ready;
+use futures_core::task::{Context, Poll};
+use futures_io::AsyncWrite;
+use pin_project_lite::pin_project;
+use std::fmt;
+use std::io;
+use std::pin::Pin;
+
+pin_project! {
+    /// Wraps a writer, buffers its output, and flushes it when enough data
+    /// has been buffered or data has been buffered for long enough.
+    ///
+    /// Like [`BufWriter`], small writes are coalesced into a buffer of
+    /// `threshold` bytes. A write that does not fit into the rest of the
+    /// buffer first writes the buffered data to the underlying writer and
+    /// flushes it, and writes of at least `threshold` bytes bypass the buffer.
+    ///
+    /// In addition, the first write into an empty buffer starts a deadline
+    /// obtained by calling `delay`, typically a closure creating a timer
+    /// future of the runtime in use. Once that future completes, the next
+    /// write first flushes everything, so data never lingers longer than the
+    /// delay while the writer is in use. The deadline is only checked when
+    /// the writer is polled; a writer that goes idle still needs to be
+    /// flushed or closed to write out the rest of its data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::future;
+    /// use futures::io::{AsyncWriteExt, AutoFlushWriter};
+    ///
+    /// let mut writer = AutoFlushWriter::new(Vec::new(), 4, future::pending::<()>);
+    ///
+    /// writer.write_all(b"ab").await?;
+    /// assert_eq!(writer.get_ref(), b"");
+    ///
+    /// // This does not fit, so the buffered bytes are written out first.
+    /// writer.write_all(b"cde").await?;
+    /// assert_eq!(writer.get_ref(), b"ab");
+    ///
+    /// writer.flush().await?;
+    /// assert_eq!(writer.get_ref(), b"abcde");
+    /// # Ok::<(), Box<dyn std::error::Error>>(()) }).unwrap();
+    /// ```
+    #[must_use = "writers do nothing unless polled"]
+    pub struct AutoFlushWriter<W, F, Fut> {
+        #[pin]
+        inner: BufWriter<W>,
+        threshold: usize,
+        delay: F,
+        #[pin]
+        deadline: Option<Fut>,
+        flushing: bool,
+    }
+}
+
+impl<W, F, Fut> AutoFlushWriter<W, F, Fut>
+where
+    W: AsyncWrite,
+    F: FnMut() -> Fut,
+    Fut: Future,
+{
+    /// Creates a new `AutoFlushWriter` buffering up to `threshold` bytes,
+    /// which are written out at the latest once the future returned by
+    /// `delay` completes.
+    pub fn new(inner: W, threshold: usize, delay: F) -> Self {
+        Self {
+            inner: BufWriter::with_capacity(threshold, inner),
+            threshold,
+            delay,
+            deadline: None,
+            flushing: false,
+        }
+    }
+
+    /// Returns a reference to the internally buffered data.
+    pub fn buffer(&self) -> &[u8] {
+        self.inner.buffer()
+    }
+
+    delegate_access_inner!(inner, W, (.));
+
+    fn poll_flush_all(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        let mut this = self.project();
+        ready!(this.inner.poll_flush(cx))?;
+        this.deadline.set(None);
+        *this.flushing = false;
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl<W: fmt::Debug, F, Fut> fmt::Debug for AutoFlushWriter<W, F, Fut> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AutoFlushWriter")
+            .field("inner", &self.inner)
+            .field("threshold", &self.threshold)
+            .field("lingering", &self.deadline.is_some())
+            .finish()
+    }
+}
+
+impl<W, F, Fut> AsyncWrite for AutoFlushWriter<W, F, Fut>
+where
+    W: AsyncWrite,
+    F: FnMut() -> Fut,
+    Fut: Future,
+{
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        if self.buffer().len() + buf.len() > self.threshold && !self.buffer().is_empty() {
+            *self.as_mut().project().flushing = true;
+        }
+        let mut this = self.as_mut().project();
+        if let Some(deadline) = this.deadline.as_mut().as_pin_mut() {
+            if deadline.poll(cx).is_ready() {
+                this.deadline.set(None);
+                *this.flushing = true;
+            }
+        }
+        if self.flushing {
+            ready!(self.as_mut().poll_flush_all(cx))?;
+        }
+
+        let mut this = self.project();
+        let n = ready!(this.inner.as_mut().poll_write(cx, buf))?;
+        if this.deadline.is_none() && !this.inner.buffer().is_empty() {
+            this.deadline.set(Some((this.delay)()));
+        }
+        Poll::Ready(Ok(n))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        self.poll_flush_all(cx)
+    }
+
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        let mut this = self.project();
+        ready!(this.inner.poll_close(cx))?;
+        this.deadline.set(None);
+        *this.flushing = false;
+        Poll::Ready(Ok(()))
+    }
+}
@@ -1,8 +1,7 @@
-use super::DEFAULT_BUF_SIZE;
-use futures_core::future::Future;
+use super::{SeekRelative, DEFAULT_BUF_SIZE};
 use futures_core::ready;
 use futures_core::task::{Context, Poll};
-use futures_io::{AsyncBufRead, AsyncRead, AsyncSeek, AsyncWrite, IoSliceMut, SeekFrom};
+use futures_io::{AsyncBufRead, AsyncRead, AsyncSeek, AsyncWrite, IoSliceMut, ReadBuf, SeekFrom};
 use pin_project_lite::pin_project;
 use std::io::{self, Read};
 use std::pin::Pin;
@@ -60,6 +59,54 @@ impl<R: AsyncRead> BufReader<R> {
         &self.buffer[self.pos..self.cap]
     }
 
+    /// Returns the number of bytes the internal buffer can hold at once.
+    pub fn capacity(&self) -> usize {
+        self.buffer.len()
+    }
+
+    /// Changes the capacity of the internal buffer, keeping the data that
+    /// is buffered.
+    ///
+    /// The buffer is never shrunk below the length of the buffered data, so
+    /// the actual capacity may end up larger than `capacity`; it returns to
+    /// the requested size on the next call once less data is buffered.
+    pub fn set_capacity(&mut self, capacity: usize) {
+        let len = self.cap - self.pos;
+        let mut buffer = vec![0; cmp::max(capacity, len)];
+        buffer[..len].copy_from_slice(&self.buffer[self.pos..self.cap]);
+        self.buffer = buffer.into_boxed_slice();
+        self.pos = 0;
+        self.cap = len;
+    }
+
+    /// Consumes this `BufReader`, returning the underlying reader and the
+    /// data that has been buffered but not read yet.
+    ///
+    /// Unlike [`into_inner`](Self::into_inner), no data is lost, so reading
+    /// can be handed off to something else, such as another decoder, that
+    /// starts with the returned bytes and then continues with the reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::io::{AsyncBufReadExt, BufReader, Cursor};
+    ///
+    /// let mut reader = BufReader::new(Cursor::new(b"header\nbody"));
+    /// let mut header = String::new();
+    /// reader.read_line(&mut header).await?;
+    ///
+    /// let (inner, buffered) = reader.into_parts();
+    /// assert_eq!(header, "header\n");
+    /// assert_eq!(buffered, b"body");
+    /// assert_eq!(inner.position(), 11);
+    /// # Ok::<(), Box<dyn std::error::Error>>(()) }).unwrap();
+    /// ```
+    pub fn into_parts(self) -> (R, Vec<u8>) {
+        let buffered = self.buffer[self.pos..self.cap].to_vec();
+        (self.inner, buffered)
+    }
+
     /// Invalidates all data in the internal buffer.
     #[inline]
     fn discard_buffer(self: Pin<&mut Self>) {
@@ -74,8 +121,8 @@ impl<R: AsyncRead + AsyncSeek> BufReader<R> {
     /// the buffer will not be flushed, allowing for more efficient seeks.
     /// This method does not return the location of the underlying reader, so the caller
     /// must track this information themselves if it is required.
-    pub fn seek_relative(self: Pin<&mut Self>, offset: i64) -> SeeKRelative<'_, R> {
-        SeeKRelative { inner: self, offset, first: true }
+    pub fn seek_relative(self: Pin<&mut Self>, offset: i64) -> SeekRelative<'_, Self> {
+        SeekRelative::new(self, offset)
     }
 
     /// Attempts to seek relative to the current position. If the new position lies within the buffer,
@@ -139,6 +186,25 @@ impl<R: AsyncRead> AsyncRead for BufReader<R> {
         self.consume(nread);
         Poll::Ready(Ok(nread))
     }
+
+    fn poll_read_buf(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        // Same as in `poll_read`, large reads go straight to the inner reader,
+        // which may then avoid initializing `buf`.
+        if self.pos == self.cap && buf.remaining() >= self.buffer.len() {
+            let res = ready!(self.as_mut().project().inner.poll_read_buf(cx, buf));
+            self.discard_buffer();
+            return Poll::Ready(res);
+        }
+        let rem = ready!(self.as_mut().poll_fill_buf(cx))?;
+        let nread = cmp::min(rem.len(), buf.remaining());
+        buf.put_slice(&rem[..nread]);
+        self.consume(nread);
+        Poll::Ready(Ok(()))
+    }
 }
 
 impl<R: AsyncRead> AsyncBufRead for BufReader<R> {
@@ -188,8 +254,9 @@ impl<R: AsyncRead + AsyncSeek> AsyncSeek for BufReader<R> {
     /// at the same position.
     ///
     /// To seek without discarding the internal buffer, use
-    /// [`BufReader::seek_relative`](BufReader::seek_relative) or
-    /// [`BufReader::poll_seek_relative`](BufReader::poll_seek_relative).
+    /// [`BufReader::seek_relative`](BufReader::seek_relative),
+    /// [`BufReader::poll_seek_relative`](BufReader::poll_seek_relative) or
+    /// [`AsyncSeekExt::seek_relative`](crate::io::AsyncSeekExt::seek_relative).
     ///
     /// See [`AsyncSeek`](futures_io::AsyncSeek) for more details.
     ///
@@ -227,34 +294,18 @@ impl<R: AsyncRead + AsyncSeek> AsyncSeek for BufReader<R> {
         self.discard_buffer();
         Poll::Ready(Ok(result))
     }
-}
-
-/// Future for the [`BufReader::seek_relative`](self::BufReader::seek_relative) method.
-#[derive(Debug)]
-#[must_use = "futures do nothing unless polled"]
-pub struct SeeKRelative<'a, R> {
-    inner: Pin<&'a mut BufReader<R>>,
-    offset: i64,
-    first: bool,
-}
-
-impl<R> Future for SeeKRelative<'_, R>
-where
-    R: AsyncRead + AsyncSeek,
-{
-    type Output = io::Result<()>;
 
-    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
-        let offset = self.offset;
-        if self.first {
-            self.first = false;
-            self.inner.as_mut().poll_seek_relative(cx, offset)
-        } else {
-            self.inner
-                .as_mut()
-                .as_mut()
-                .poll_seek(cx, SeekFrom::Current(offset))
-                .map(|res| res.map(|_| ()))
-        }
+    /// Seeks relative to the current position without discarding the
+    /// internal buffer if the new position lies within it, like
+    /// [`BufReader::poll_seek_relative`](BufReader::poll_seek_relative).
+    fn poll_seek_relative(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        offset: i64,
+    ) -> Poll<io::Result<()>> {
+        BufReader::poll_seek_relative(self, cx, offset)
     }
 }
+
+/// Future for the [`BufReader::seek_relative`](self::BufReader::seek_relative) method.
+pub type SeeKRelative<'a, R> = SeekRelative<'a, BufReader<R>>;
@@ -1,6 +1,6 @@
 use futures_core::ready;
 use futures_core::task::{Context, Poll};
-use futures_io::{AsyncBufRead, AsyncRead, IoSliceMut};
+use futures_io::{AsyncBufRead, AsyncRead, IoSliceMut, ReadBuf};
 use pin_project_lite::pin_project;
 use std::fmt;
 use std::io;
@@ -109,6 +109,25 @@ where
         }
         this.second.poll_read_vectored(cx, bufs)
     }
+
+    fn poll_read_buf(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        let this = self.project();
+
+        if !*this.done_first {
+            let filled = buf.filled().len();
+            ready!(this.first.poll_read_buf(cx, buf))?;
+            if buf.filled().len() == filled && buf.remaining() > 0 {
+                *this.done_first = true;
+            } else {
+                return Poll::Ready(Ok(()));
+            }
+        }
+        this.second.poll_read_buf(cx, buf)
+    }
 }
 
 impl<T, U> AsyncBufRead for Chain<T, U>
@@ -0,0 +1,138 @@
+use futures_core::ready;
+use futures_core::task::{Context, Poll};
+use futures_io::{AsyncBufRead, AsyncRead};
+use pin_project_lite::pin_project;
+use std::fmt;
+use std::io;
+use std::pin::Pin;
+
+/// Creates a reader that reads from each reader of `readers` in turn.
+///
+/// The returned reader reads all bytes from the first reader until it reaches
+/// EOF, then moves on to the next one, and reaches EOF itself after the last.
+/// Readers are taken from the iterator only once the previous one is done,
+/// and dropped as soon as they reach EOF, so the iterator can open them
+/// lazily. If the readers implement [`AsyncBufRead`], so does the returned
+/// reader.
+///
+/// This is the same as chaining the readers with
+/// [`chain`](super::AsyncReadExt::chain), for any number of readers of the
+/// same type.
+///
+/// # Examples
+///
+/// ```
+/// # futures::executor::block_on(async {
+/// use futures::io::{self, AsyncReadExt, Cursor};
+///
+/// let parts = vec![Cursor::new(vec![1, 2]), Cursor::new(vec![]), Cursor::new(vec![3])];
+/// let mut reader = io::chain_all(parts);
+///
+/// let mut buffer = Vec::new();
+/// reader.read_to_end(&mut buffer).await?;
+/// assert_eq!(buffer, [1, 2, 3]);
+/// # Ok::<(), Box<dyn std::error::Error>>(()) }).unwrap();
+/// ```
+pub fn chain_all<I>(readers: I) -> ChainAll<I::IntoIter>
+where
+    I: IntoIterator,
+    I::Item: AsyncRead,
+{
+    ChainAll { readers: readers.into_iter(), current: None }
+}
+
+pin_project! {
+    /// Reader for the [`chain_all()`] function.
+    #[must_use = "readers do nothing unless polled"]
+    pub struct ChainAll<I: Iterator> {
+        readers: I,
+        #[pin]
+        current: Option<I::Item>,
+    }
+}
+
+impl<I: Iterator> ChainAll<I> {
+    /// Returns a reference to the reader currently being read from, if any.
+    pub fn current(&self) -> Option<&I::Item> {
+        self.current.as_ref()
+    }
+
+    /// Returns a reference to the iterator of the remaining readers.
+    pub fn readers(&self) -> &I {
+        &self.readers
+    }
+
+    // Makes sure `current` is set, returning `false` once all readers are done.
+    fn next_reader(self: Pin<&mut Self>) -> bool {
+        let mut this = self.project();
+        if this.current.is_none() {
+            match this.readers.next() {
+                Some(reader) => this.current.set(Some(reader)),
+                None => return false,
+            }
+        }
+        true
+    }
+}
+
+impl<I> fmt::Debug for ChainAll<I>
+where
+    I: Iterator + fmt::Debug,
+    I::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ChainAll")
+            .field("readers", &self.readers)
+            .field("current", &self.current)
+            .finish()
+    }
+}
+
+impl<I> AsyncRead for ChainAll<I>
+where
+    I: Iterator,
+    I::Item: AsyncRead,
+{
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        while self.as_mut().next_reader() {
+            let mut current = self.as_mut().project().current;
+            let n = ready!(current.as_mut().as_pin_mut().unwrap().poll_read(cx, buf))?;
+            if n > 0 || buf.is_empty() {
+                return Poll::Ready(Ok(n));
+            }
+            current.set(None);
+        }
+        Poll::Ready(Ok(0))
+    }
+}
+
+impl<I> AsyncBufRead for ChainAll<I>
+where
+    I: Iterator,
+    I::Item: AsyncBufRead,
+{
+    fn poll_fill_buf(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<&[u8]>> {
+        while self.as_mut().next_reader() {
+            let mut current = self.as_mut().project().current;
+            let reader = current.as_mut().as_pin_mut().unwrap();
+            if !ready!(reader.poll_fill_buf(cx))?.is_empty() {
+                // With polonius the buffer could be returned directly; fill it
+                // again instead, which is ready since the reader just was.
+                let reader = self.project().current.as_pin_mut().unwrap();
+                return reader.poll_fill_buf(cx);
+            }
+            current.set(None);
+        }
+        Poll::Ready(Ok(&[]))
+    }
+
+    fn consume(self: Pin<&mut Self>, amt: usize) {
+        if let Some(reader) = self.project().current.as_pin_mut() {
+            reader.consume(amt)
+        }
+    }
+}
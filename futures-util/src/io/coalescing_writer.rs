@@ -0,0 +1,149 @@
+use futures_core::ready;
+use futures_core::task::{Context, Poll};
+use futures_io::{AsyncWrite, IoSlice};
+use pin_project_lite::pin_project;
+use std::collections::VecDeque;
+use std::io;
+use std::pin::Pin;
+
+pin_project! {
+    /// Wraps a writer and coalesces small writes into vectored writes.
+    ///
+    /// Each write is held back as a separate slice until a batch of
+    /// `max_writes` writes or `max_bytes` bytes has been collected, and the
+    /// batch is then submitted to the underlying writer with
+    /// [`poll_write_vectored`](AsyncWrite::poll_write_vectored). This makes
+    /// one call to the underlying writer for many small writes, which helps
+    /// with chatty protocols over writers that support vectored writes, such
+    /// as sockets. Writes of at least `max_bytes` bytes are passed through
+    /// directly once the pending batch has been written.
+    ///
+    /// Like [`BufWriter`](super::BufWriter), the held back writes are
+    /// discarded when the `CoalescingWriter` is dropped, so it needs to be
+    /// flushed or closed to write them out.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::io::{AsyncWriteExt, CoalescingWriter};
+    ///
+    /// let mut writer = CoalescingWriter::new(Vec::new(), 3, 1024);
+    ///
+    /// writer.write_all(b"GET ").await?;
+    /// writer.write_all(b"/index.html").await?;
+    /// assert_eq!(writer.get_ref(), b"");
+    ///
+    /// writer.write_all(b" HTTP/1.1").await?;
+    /// writer.flush().await?;
+    /// assert_eq!(writer.get_ref(), b"GET /index.html HTTP/1.1");
+    /// # Ok::<(), Box<dyn std::error::Error>>(()) }).unwrap();
+    /// ```
+    #[derive(Debug)]
+    #[must_use = "writers do nothing unless polled"]
+    pub struct CoalescingWriter<W> {
+        #[pin]
+        inner: W,
+        chunks: VecDeque<Vec<u8>>,
+        // Bytes of the first chunk that have already been written.
+        written: usize,
+        // Bytes of all chunks that have not been written yet.
+        buffered: usize,
+        max_writes: usize,
+        max_bytes: usize,
+    }
+}
+
+impl<W: AsyncWrite> CoalescingWriter<W> {
+    /// Creates a new `CoalescingWriter` submitting batches of at most
+    /// `max_writes` writes and `max_bytes` bytes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_writes` or `max_bytes` is 0.
+    pub fn new(inner: W, max_writes: usize, max_bytes: usize) -> Self {
+        assert!(max_writes > 0, "max_writes must be non-zero");
+        assert!(max_bytes > 0, "max_bytes must be non-zero");
+        Self {
+            inner,
+            chunks: VecDeque::with_capacity(max_writes),
+            written: 0,
+            buffered: 0,
+            max_writes,
+            max_bytes,
+        }
+    }
+
+    /// Returns the number of bytes held back that have not been written
+    /// to the underlying writer yet.
+    pub fn buffered(&self) -> usize {
+        self.buffered
+    }
+
+    delegate_access_inner!(inner, W, ());
+
+    fn poll_write_batch(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        let mut this = self.project();
+
+        while !this.chunks.is_empty() {
+            let n = {
+                let mut slices = Vec::with_capacity(this.chunks.len());
+                let mut chunks = this.chunks.iter();
+                if let Some(first) = chunks.next() {
+                    slices.push(IoSlice::new(&first[*this.written..]));
+                }
+                slices.extend(chunks.map(|chunk| IoSlice::new(chunk)));
+                ready!(this.inner.as_mut().poll_write_vectored(cx, &slices))?
+            };
+            if n == 0 {
+                return Poll::Ready(Err(io::Error::new(
+                    io::ErrorKind::WriteZero,
+                    "failed to write the buffered data",
+                )));
+            }
+
+            *this.buffered -= n;
+            *this.written += n;
+            while let Some(first) = this.chunks.front() {
+                if *this.written < first.len() {
+                    break;
+                }
+                *this.written -= first.len();
+                this.chunks.pop_front();
+            }
+        }
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl<W: AsyncWrite> AsyncWrite for CoalescingWriter<W> {
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        if self.chunks.len() == self.max_writes || self.buffered + buf.len() > self.max_bytes {
+            ready!(self.as_mut().poll_write_batch(cx))?;
+        }
+        if buf.len() >= self.max_bytes {
+            return self.project().inner.poll_write(cx, buf);
+        }
+
+        let this = self.project();
+        if !buf.is_empty() {
+            this.chunks.push_back(buf.to_vec());
+            *this.buffered += buf.len();
+        }
+        Poll::Ready(Ok(buf.len()))
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        ready!(self.as_mut().poll_write_batch(cx))?;
+        self.project().inner.poll_flush(cx)
+    }
+
+    fn poll_close(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        ready!(self.as_mut().poll_write_batch(cx))?;
+        self.project().inner.poll_close(cx)
+    }
+}
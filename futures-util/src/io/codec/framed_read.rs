@@ -0,0 +1,132 @@
+use super::Decoder;
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Context, Poll};
+use futures_io::AsyncRead;
+use pin_project_lite::pin_project;
+use std::fmt;
+use std::pin::Pin;
+
+// Number of bytes read from the reader at a time.
+const READ_SIZE: usize = 8 * 1024;
+
+pin_project! {
+    /// Stream of the frames decoded from an [`AsyncRead`].
+    ///
+    /// Bytes are read into an internal buffer, and the [`Decoder`] is asked
+    /// for a frame each time more of them have arrived. The stream ends
+    /// once the reader reaches EOF and the remaining bytes have been decoded
+    /// with [`decode_eof`](Decoder::decode_eof), or after the first error.
+    #[must_use = "streams do nothing unless polled"]
+    pub struct FramedRead<R, D> {
+        #[pin]
+        inner: R,
+        decoder: D,
+        buffer: Vec<u8>,
+        eof: bool,
+        done: bool,
+    }
+}
+
+impl<R, D> FramedRead<R, D>
+where
+    R: AsyncRead,
+    D: Decoder,
+{
+    /// Creates a new `FramedRead` decoding the bytes of `inner` with
+    /// `decoder`.
+    pub fn new(inner: R, decoder: D) -> Self {
+        Self { inner, decoder, buffer: Vec::new(), eof: false, done: false }
+    }
+
+    /// Returns a reference to the decoder.
+    pub fn decoder(&self) -> &D {
+        &self.decoder
+    }
+
+    /// Returns a mutable reference to the decoder.
+    pub fn decoder_mut(&mut self) -> &mut D {
+        &mut self.decoder
+    }
+
+    /// Returns the bytes that have been read but not decoded yet.
+    pub fn read_buffer(&self) -> &[u8] {
+        &self.buffer
+    }
+
+    delegate_access_inner!(inner, R, ());
+}
+
+impl<R: fmt::Debug, D: fmt::Debug> fmt::Debug for FramedRead<R, D> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FramedRead")
+            .field("inner", &self.inner)
+            .field("decoder", &self.decoder)
+            .field("buffered", &self.buffer.len())
+            .field("eof", &self.eof)
+            .finish()
+    }
+}
+
+impl<R, D> Stream for FramedRead<R, D>
+where
+    R: AsyncRead,
+    D: Decoder,
+{
+    type Item = Result<D::Item, D::Error>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+        if *this.done {
+            return Poll::Ready(None);
+        }
+
+        loop {
+            let frame = if *this.eof {
+                this.decoder.decode_eof(this.buffer)
+            } else {
+                this.decoder.decode(this.buffer)
+            };
+            match frame {
+                Ok(Some(frame)) => return Poll::Ready(Some(Ok(frame))),
+                Ok(None) if *this.eof => {
+                    *this.done = true;
+                    return Poll::Ready(None);
+                }
+                Ok(None) => {}
+                Err(e) => {
+                    *this.done = true;
+                    return Poll::Ready(Some(Err(e)));
+                }
+            }
+
+            let len = this.buffer.len();
+            this.buffer.resize(len + READ_SIZE, 0);
+            let n = match this.inner.as_mut().poll_read(cx, &mut this.buffer[len..]) {
+                Poll::Ready(Ok(n)) => n,
+                Poll::Ready(Err(e)) => {
+                    this.buffer.truncate(len);
+                    *this.done = true;
+                    return Poll::Ready(Some(Err(e.into())));
+                }
+                Poll::Pending => {
+                    this.buffer.truncate(len);
+                    return Poll::Pending;
+                }
+            };
+            this.buffer.truncate(len + n);
+            if n == 0 {
+                *this.eof = true;
+            }
+        }
+    }
+}
+
+impl<R, D> FusedStream for FramedRead<R, D>
+where
+    R: AsyncRead,
+    D: Decoder,
+{
+    fn is_terminated(&self) -> bool {
+        self.done
+    }
+}
@@ -0,0 +1,117 @@
+use super::Encoder;
+use futures_core::ready;
+use futures_core::task::{Context, Poll};
+use futures_io::AsyncWrite;
+use futures_sink::Sink;
+use pin_project_lite::pin_project;
+use std::fmt;
+use std::io;
+use std::pin::Pin;
+
+// Once this many bytes are buffered, they are written out before another
+// frame is accepted.
+const BACKPRESSURE_BOUNDARY: usize = 8 * 1024;
+
+pin_project! {
+    /// Sink of frames encoded into an [`AsyncWrite`].
+    ///
+    /// Frames are encoded into an internal buffer with the [`Encoder`], which
+    /// is written out once it grows large enough, and whenever the sink is
+    /// flushed or closed.
+    #[must_use = "sinks do nothing unless polled"]
+    #[cfg_attr(docsrs, doc(cfg(feature = "sink")))]
+    pub struct FramedWrite<W, E> {
+        #[pin]
+        inner: W,
+        encoder: E,
+        buffer: Vec<u8>,
+    }
+}
+
+impl<W, E> FramedWrite<W, E>
+where
+    W: AsyncWrite,
+{
+    /// Creates a new `FramedWrite` encoding frames into `inner` with
+    /// `encoder`.
+    pub fn new(inner: W, encoder: E) -> Self {
+        Self { inner, encoder, buffer: Vec::new() }
+    }
+
+    /// Returns a reference to the encoder.
+    pub fn encoder(&self) -> &E {
+        &self.encoder
+    }
+
+    /// Returns a mutable reference to the encoder.
+    pub fn encoder_mut(&mut self) -> &mut E {
+        &mut self.encoder
+    }
+
+    /// Returns the bytes that have been encoded but not written yet.
+    pub fn write_buffer(&self) -> &[u8] {
+        &self.buffer
+    }
+
+    delegate_access_inner!(inner, W, ());
+
+    fn poll_write_buffer(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        let mut this = self.project();
+        let mut written = 0;
+        let res = loop {
+            if written == this.buffer.len() {
+                break Poll::Ready(Ok(()));
+            }
+            match this.inner.as_mut().poll_write(cx, &this.buffer[written..]) {
+                Poll::Ready(Ok(0)) => break Poll::Ready(Err(io::ErrorKind::WriteZero.into())),
+                Poll::Ready(Ok(n)) => written += n,
+                Poll::Ready(Err(e)) => break Poll::Ready(Err(e)),
+                Poll::Pending => break Poll::Pending,
+            }
+        };
+        this.buffer.drain(..written);
+        res
+    }
+}
+
+impl<W: fmt::Debug, E: fmt::Debug> fmt::Debug for FramedWrite<W, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FramedWrite")
+            .field("inner", &self.inner)
+            .field("encoder", &self.encoder)
+            .field("buffered", &self.buffer.len())
+            .finish()
+    }
+}
+
+impl<W, E, Item> Sink<Item> for FramedWrite<W, E>
+where
+    W: AsyncWrite,
+    E: Encoder<Item>,
+{
+    type Error = E::Error;
+
+    fn poll_ready(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        if self.buffer.len() >= BACKPRESSURE_BOUNDARY {
+            ready!(self.poll_write_buffer(cx))?;
+        }
+        Poll::Ready(Ok(()))
+    }
+
+    fn start_send(self: Pin<&mut Self>, item: Item) -> Result<(), Self::Error> {
+        let this = self.project();
+        this.encoder.encode(item, this.buffer)
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        ready!(self.as_mut().poll_write_buffer(cx))?;
+        ready!(self.project().inner.poll_flush(cx))?;
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_close(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        ready!(self.as_mut().poll_write_buffer(cx))?;
+        ready!(self.project().inner.poll_close(cx))?;
+        Poll::Ready(Ok(()))
+    }
+}
@@ -0,0 +1,169 @@
+use super::{Decoder, Encoder};
+use std::io;
+
+const DEFAULT_MAX_FRAME_LENGTH: usize = 8 * 1024 * 1024;
+
+/// A codec for frames prefixed by their length.
+///
+/// Every frame is preceded by a length field holding the number of bytes in
+/// the frame, not counting the field itself. By default the field is four
+/// bytes long and big-endian, and frames of up to 8 MiB are accepted; all
+/// three can be changed with the builder-style methods.
+///
+/// Decoding produces frames as `Vec<u8>`, and anything that implements
+/// `AsRef<[u8]>` can be encoded.
+///
+/// Decoding fails with an [`InvalidData`](io::ErrorKind::InvalidData) error
+/// when a length field exceeds the maximum frame length, so a peer cannot
+/// make the reader buffer an unbounded amount of data, and encoding fails
+/// with an [`InvalidInput`](io::ErrorKind::InvalidInput) error for a frame
+/// that is too long to be sent.
+///
+/// # Examples
+///
+/// ```
+/// use futures::io::codec::{Decoder, Encoder, LengthDelimitedCodec};
+///
+/// let mut codec = LengthDelimitedCodec::new().length_field_length(2).little_endian();
+///
+/// let mut buffer = Vec::new();
+/// codec.encode(b"abc", &mut buffer)?;
+/// assert_eq!(buffer, b"\x03\x00abc");
+///
+/// assert_eq!(codec.decode(&mut buffer)?, Some(b"abc".to_vec()));
+/// assert!(buffer.is_empty());
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct LengthDelimitedCodec {
+    length_field_length: usize,
+    big_endian: bool,
+    max_frame_length: usize,
+}
+
+impl LengthDelimitedCodec {
+    /// Creates a new codec with the default settings.
+    pub fn new() -> Self {
+        Self {
+            length_field_length: 4,
+            big_endian: true,
+            max_frame_length: DEFAULT_MAX_FRAME_LENGTH,
+        }
+    }
+
+    /// Sets the number of bytes of the length field.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `length` is not between 1 and 8.
+    pub fn length_field_length(mut self, length: usize) -> Self {
+        assert!((1..=8).contains(&length), "length field must be between 1 and 8 bytes long");
+        self.length_field_length = length;
+        self
+    }
+
+    /// Stores the length field in big-endian byte order, which is the
+    /// default.
+    pub fn big_endian(mut self) -> Self {
+        self.big_endian = true;
+        self
+    }
+
+    /// Stores the length field in little-endian byte order.
+    pub fn little_endian(mut self) -> Self {
+        self.big_endian = false;
+        self
+    }
+
+    /// Sets the maximum length of a frame, not counting its length field.
+    pub fn max_frame_length(mut self, length: usize) -> Self {
+        self.max_frame_length = length;
+        self
+    }
+
+    /// Returns the maximum length of a frame.
+    pub fn get_max_frame_length(&self) -> usize {
+        self.max_frame_length
+    }
+
+    fn read_length(&self, field: &[u8]) -> u64 {
+        let mut bytes = [0; 8];
+        if self.big_endian {
+            bytes[8 - field.len()..].copy_from_slice(field);
+            u64::from_be_bytes(bytes)
+        } else {
+            bytes[..field.len()].copy_from_slice(field);
+            u64::from_le_bytes(bytes)
+        }
+    }
+
+    fn write_length(&self, length: u64, dst: &mut Vec<u8>) {
+        let n = self.length_field_length;
+        if self.big_endian {
+            dst.extend_from_slice(&length.to_be_bytes()[8 - n..]);
+        } else {
+            dst.extend_from_slice(&length.to_le_bytes()[..n]);
+        }
+    }
+}
+
+impl Default for LengthDelimitedCodec {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Decoder for LengthDelimitedCodec {
+    type Item = Vec<u8>;
+    type Error = io::Error;
+
+    fn decode(&mut self, src: &mut Vec<u8>) -> io::Result<Option<Vec<u8>>> {
+        let header = self.length_field_length;
+        if src.len() < header {
+            return Ok(None);
+        }
+
+        let length = self.read_length(&src[..header]);
+        if length > self.max_frame_length as u64 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "frame length exceeds the maximum frame length",
+            ));
+        }
+
+        let end = header + length as usize;
+        if src.len() < end {
+            // Make room for the rest of the frame up front.
+            src.reserve(end - src.len());
+            return Ok(None);
+        }
+
+        let frame = src[header..end].to_vec();
+        src.drain(..end);
+        Ok(Some(frame))
+    }
+}
+
+impl<B: AsRef<[u8]>> Encoder<B> for LengthDelimitedCodec {
+    type Error = io::Error;
+
+    fn encode(&mut self, item: B, dst: &mut Vec<u8>) -> io::Result<()> {
+        let frame = item.as_ref();
+        let max_length = if self.length_field_length == 8 {
+            u64::max_value()
+        } else {
+            (1 << (self.length_field_length * 8)) - 1
+        };
+        if frame.len() > self.max_frame_length || frame.len() as u64 > max_length {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "frame is too long to be encoded",
+            ));
+        }
+
+        dst.reserve(self.length_field_length + frame.len());
+        self.write_length(frame.len() as u64, dst);
+        dst.extend_from_slice(frame);
+        Ok(())
+    }
+}
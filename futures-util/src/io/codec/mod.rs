@@ -0,0 +1,94 @@
+//! Framing of byte streams into messages.
+//!
+//! A [`Decoder`] splits the bytes read from an [`AsyncRead`](futures_io::AsyncRead)
+//! into frames, and an [`Encoder`] turns frames back into bytes for an
+//! [`AsyncWrite`](futures_io::AsyncWrite). [`FramedRead`] uses a decoder to
+//! turn a reader into a [`Stream`](futures_core::stream::Stream) of frames,
+//! and [`FramedWrite`] uses an encoder to turn a writer into a
+//! [`Sink`](futures_sink::Sink) of frames.
+//!
+//! [`LengthDelimitedCodec`] is a codec for the common case of frames that
+//! are prefixed by their length.
+//!
+//! # Examples
+//!
+//! ```
+//! # futures::executor::block_on(async {
+//! use futures::io::codec::{FramedRead, FramedWrite, LengthDelimitedCodec};
+//! use futures::io::Cursor;
+//! use futures::sink::SinkExt;
+//! use futures::stream::TryStreamExt;
+//!
+//! let mut writer = FramedWrite::new(Vec::new(), LengthDelimitedCodec::new());
+//! writer.send(b"hello".to_vec()).await?;
+//! writer.send(b"world".to_vec()).await?;
+//!
+//! let bytes = writer.into_inner();
+//! assert_eq!(&bytes[..9], b"\x00\x00\x00\x05hello");
+//!
+//! let reader = FramedRead::new(Cursor::new(bytes), LengthDelimitedCodec::new());
+//! let frames: Vec<Vec<u8>> = reader.try_collect().await?;
+//! assert_eq!(frames, [b"hello".to_vec(), b"world".to_vec()]);
+//! # Ok::<(), Box<dyn std::error::Error>>(()) }).unwrap();
+//! ```
+
+use std::io;
+
+mod framed_read;
+pub use self::framed_read::FramedRead;
+
+#[cfg(feature = "sink")]
+mod framed_write;
+#[cfg(feature = "sink")]
+#[cfg_attr(docsrs, doc(cfg(feature = "sink")))]
+pub use self::framed_write::FramedWrite;
+
+mod length_delimited;
+pub use self::length_delimited::LengthDelimitedCodec;
+
+/// Decodes frames from a buffer of bytes.
+pub trait Decoder {
+    /// The type of decoded frames.
+    type Item;
+
+    /// The type of decoding errors.
+    ///
+    /// I/O errors of the underlying reader are converted into it.
+    type Error: From<io::Error>;
+
+    /// Attempts to decode a frame from the start of `src`.
+    ///
+    /// If `src` holds a whole frame, its bytes should be removed from `src`
+    /// and the frame returned. If more bytes are needed, `Ok(None)` should be
+    /// returned; `decode` is then called again once more bytes have been read
+    /// and appended to `src`.
+    fn decode(&mut self, src: &mut Vec<u8>) -> Result<Option<Self::Item>, Self::Error>;
+
+    /// Attempts to decode a frame once the underlying reader has reached EOF.
+    ///
+    /// This is called repeatedly until it returns `Ok(None)`, after which
+    /// the stream ends. By default it calls [`decode`](Self::decode), and
+    /// fails with an [`UnexpectedEof`](io::ErrorKind::UnexpectedEof) error
+    /// if that leaves bytes that are not a whole frame.
+    fn decode_eof(&mut self, src: &mut Vec<u8>) -> Result<Option<Self::Item>, Self::Error> {
+        match self.decode(src)? {
+            Some(frame) => Ok(Some(frame)),
+            None if src.is_empty() => Ok(None),
+            None => {
+                Err(io::Error::new(io::ErrorKind::UnexpectedEof, "bytes remaining on stream")
+                    .into())
+            }
+        }
+    }
+}
+
+/// Encodes frames into a buffer of bytes.
+pub trait Encoder<Item> {
+    /// The type of encoding errors.
+    ///
+    /// I/O errors of the underlying writer are converted into it.
+    type Error: From<io::Error>;
+
+    /// Encodes `item` by appending its bytes to `dst`.
+    fn encode(&mut self, item: Item, dst: &mut Vec<u8>) -> Result<(), Self::Error>;
+}
@@ -0,0 +1,208 @@
+use super::DEFAULT_BUF_SIZE;
+use futures_core::future::Future;
+use futures_core::ready;
+use futures_core::task::{Context, Poll};
+use futures_io::{AsyncRead, AsyncWrite};
+use pin_project_lite::pin_project;
+use std::fmt;
+use std::io;
+use std::mem;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+
+/// A pool of buffers that can be shared between many copies started with
+/// [`copy_with()`], so that they reuse buffers instead of allocating new ones.
+///
+/// Cloning a `BufferPool` returns a handle to the same pool.
+#[derive(Clone)]
+pub struct BufferPool {
+    buffers: Arc<Mutex<Vec<Vec<u8>>>>,
+    max_idle: usize,
+}
+
+impl BufferPool {
+    /// Creates a new, empty pool keeping at most `max_idle` buffers that are
+    /// not in use. Buffers given back to a full pool are dropped.
+    pub fn new(max_idle: usize) -> Self {
+        Self { buffers: Arc::new(Mutex::new(Vec::new())), max_idle }
+    }
+
+    /// Returns the number of buffers in the pool that are not in use.
+    pub fn idle(&self) -> usize {
+        self.buffers.lock().unwrap().len()
+    }
+
+    fn get(&self, size: usize) -> Vec<u8> {
+        let mut buf = self.buffers.lock().unwrap().pop().unwrap_or_default();
+        buf.resize(size, 0);
+        buf
+    }
+
+    fn put(&self, buf: Vec<u8>) {
+        // Don't panic in a destructor if another thread poisoned the lock.
+        if let Ok(mut buffers) = self.buffers.lock() {
+            if buffers.len() < self.max_idle {
+                buffers.push(buf);
+            }
+        }
+    }
+}
+
+impl fmt::Debug for BufferPool {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BufferPool")
+            .field("idle", &self.buffers.lock().map(|buffers| buffers.len()).unwrap_or(0))
+            .field("max_idle", &self.max_idle)
+            .finish()
+    }
+}
+
+/// Configuration for [`copy_with()`].
+#[derive(Clone, Debug)]
+pub struct CopyConfig {
+    /// The size of the buffer, and so the largest number of bytes read and
+    /// written at once. The default is currently 8 KB, but may change in the
+    /// future.
+    pub buf_size: usize,
+    /// The pool to take the buffer from and give it back to once the copy is
+    /// done, or `None` to allocate a new buffer. The default is `None`.
+    pub pool: Option<BufferPool>,
+}
+
+impl Default for CopyConfig {
+    fn default() -> Self {
+        Self { buf_size: DEFAULT_BUF_SIZE, pool: None }
+    }
+}
+
+/// Creates a future which copies all the bytes from one object to another,
+/// using a buffer configured by `config`.
+///
+/// This works like [`copy()`](super::copy()), but lets the caller choose the
+/// size of the buffer, and take it from a [`BufferPool`] shared between many
+/// copies to avoid allocating a buffer for each of them, as a proxy would.
+/// The buffer is given back to the pool when the returned future completes or
+/// is dropped.
+///
+/// On success the number of bytes is returned.
+///
+/// # Panics
+///
+/// Panics if `config.buf_size` is 0.
+///
+/// # Examples
+///
+/// ```
+/// # futures::executor::block_on(async {
+/// use futures::io::{self, BufferPool, CopyConfig, Cursor};
+///
+/// let pool = BufferPool::new(16);
+/// let config = CopyConfig { buf_size: 2, pool: Some(pool.clone()) };
+///
+/// let reader = Cursor::new([1, 2, 3, 4, 5]);
+/// let mut writer = Vec::new();
+/// let bytes = io::copy_with(reader, &mut writer, config).await?;
+///
+/// assert_eq!(bytes, 5);
+/// assert_eq!(writer, [1, 2, 3, 4, 5]);
+/// assert_eq!(pool.idle(), 1);
+/// # Ok::<(), Box<dyn std::error::Error>>(()) }).unwrap();
+/// ```
+pub fn copy_with<R, W>(reader: R, writer: &mut W, config: CopyConfig) -> CopyWith<'_, R, W>
+where
+    R: AsyncRead,
+    W: AsyncWrite + Unpin + ?Sized,
+{
+    assert!(config.buf_size > 0, "buf_size must be non-zero");
+    let buf = match &config.pool {
+        Some(pool) => pool.get(config.buf_size),
+        None => vec![0; config.buf_size],
+    };
+    CopyWith {
+        reader,
+        writer,
+        buf: PooledBuf { buf, pool: config.pool },
+        pos: 0,
+        cap: 0,
+        amt: 0,
+        read_done: false,
+    }
+}
+
+// A buffer that is given back to its pool when dropped.
+struct PooledBuf {
+    buf: Vec<u8>,
+    pool: Option<BufferPool>,
+}
+
+impl Drop for PooledBuf {
+    fn drop(&mut self) {
+        if let Some(pool) = &self.pool {
+            pool.put(mem::replace(&mut self.buf, Vec::new()));
+        }
+    }
+}
+
+pin_project! {
+    /// Future for the [`copy_with()`] function.
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct CopyWith<'a, R, W: ?Sized> {
+        #[pin]
+        reader: R,
+        writer: &'a mut W,
+        buf: PooledBuf,
+        pos: usize,
+        cap: usize,
+        amt: u64,
+        read_done: bool,
+    }
+}
+
+impl<R, W> Future for CopyWith<'_, R, W>
+where
+    R: AsyncRead,
+    W: AsyncWrite + Unpin + ?Sized,
+{
+    type Output = io::Result<u64>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut this = self.project();
+        loop {
+            if *this.pos == *this.cap && !*this.read_done {
+                let n = ready!(this.reader.as_mut().poll_read(cx, &mut this.buf.buf))?;
+                if n == 0 {
+                    *this.read_done = true;
+                } else {
+                    *this.pos = 0;
+                    *this.cap = n;
+                }
+            }
+
+            while *this.pos < *this.cap {
+                let buf = &this.buf.buf[*this.pos..*this.cap];
+                let i = ready!(Pin::new(&mut this.writer).poll_write(cx, buf))?;
+                if i == 0 {
+                    return Poll::Ready(Err(io::ErrorKind::WriteZero.into()));
+                }
+                *this.pos += i;
+                *this.amt += i as u64;
+            }
+
+            if *this.read_done {
+                ready!(Pin::new(&mut this.writer).poll_flush(cx))?;
+                return Poll::Ready(Ok(*this.amt));
+            }
+        }
+    }
+}
+
+impl<R: fmt::Debug, W: fmt::Debug + ?Sized> fmt::Debug for CopyWith<'_, R, W> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CopyWith")
+            .field("reader", &self.reader)
+            .field("writer", &self.writer)
+            .field("buf_size", &self.buf.buf.len())
+            .field("amt", &self.amt)
+            .finish()
+    }
+}
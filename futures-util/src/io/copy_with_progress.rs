@@ -0,0 +1,178 @@
+use super::BufReader;
+use futures_core::future::Future;
+use futures_core::ready;
+use futures_core::task::{Context, Poll};
+use futures_io::{AsyncBufRead, AsyncRead, AsyncWrite};
+use pin_project_lite::pin_project;
+use std::fmt;
+use std::io;
+use std::pin::Pin;
+
+/// Creates a future which copies all the bytes from one object to another,
+/// reporting its progress along the way.
+///
+/// This works like [`copy()`](super::copy()), and additionally calls
+/// `progress` with the total number of bytes copied so far after every write
+/// to `writer`. Returning an error from `progress` stops the copy, and the
+/// future then resolves to that error.
+///
+/// On success the number of bytes is returned.
+///
+/// # Examples
+///
+/// ```
+/// # futures::executor::block_on(async {
+/// use futures::io::{self, AsyncWriteExt, Cursor};
+///
+/// let reader = Cursor::new([1, 2, 3, 4]);
+/// let mut writer = Cursor::new(vec![0u8; 5]);
+/// let mut reported = 0;
+///
+/// let bytes = io::copy_with_progress(reader, &mut writer, |copied| {
+///     reported = copied;
+///     Ok(())
+/// })
+/// .await?;
+/// writer.close().await?;
+///
+/// assert_eq!(bytes, 4);
+/// assert_eq!(reported, 4);
+/// assert_eq!(writer.into_inner(), [1, 2, 3, 4, 0]);
+/// # Ok::<(), Box<dyn std::error::Error>>(()) }).unwrap();
+/// ```
+pub fn copy_with_progress<R, W, F>(
+    reader: R,
+    writer: &mut W,
+    progress: F,
+) -> CopyWithProgress<'_, R, W, F>
+where
+    R: AsyncRead,
+    W: AsyncWrite + Unpin + ?Sized,
+    F: FnMut(u64) -> io::Result<()>,
+{
+    CopyWithProgress { inner: copy_buf_with_progress(BufReader::new(reader), writer, progress) }
+}
+
+/// Creates a future which copies all the bytes from one object to another,
+/// reporting its progress along the way.
+///
+/// This works like [`copy_buf()`](super::copy_buf()), and additionally calls
+/// `progress` with the total number of bytes copied so far after every write
+/// to `writer`. Returning an error from `progress` stops the copy, and the
+/// future then resolves to that error.
+///
+/// On success the number of bytes is returned.
+///
+/// # Examples
+///
+/// ```
+/// # futures::executor::block_on(async {
+/// use futures::io::{self, Cursor};
+/// use std::io::{Error, ErrorKind};
+///
+/// let reader = Cursor::new(vec![0u8; 1024]);
+/// let mut writer = Cursor::new(Vec::new());
+///
+/// // Give up once more than 100 bytes have been copied.
+/// let result = io::copy_buf_with_progress(reader, &mut writer, |copied| {
+///     if copied > 100 {
+///         Err(Error::new(ErrorKind::Other, "too large"))
+///     } else {
+///         Ok(())
+///     }
+/// })
+/// .await;
+///
+/// assert_eq!(result.unwrap_err().kind(), ErrorKind::Other);
+/// # });
+/// ```
+pub fn copy_buf_with_progress<R, W, F>(
+    reader: R,
+    writer: &mut W,
+    progress: F,
+) -> CopyBufWithProgress<'_, R, W, F>
+where
+    R: AsyncBufRead,
+    W: AsyncWrite + Unpin + ?Sized,
+    F: FnMut(u64) -> io::Result<()>,
+{
+    CopyBufWithProgress { reader, writer, amt: 0, progress }
+}
+
+pin_project! {
+    /// Future for the [`copy_with_progress()`] function.
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct CopyWithProgress<'a, R, W: ?Sized, F> {
+        #[pin]
+        inner: CopyBufWithProgress<'a, BufReader<R>, W, F>,
+    }
+}
+
+impl<R, W, F> Future for CopyWithProgress<'_, R, W, F>
+where
+    R: AsyncRead,
+    W: AsyncWrite + Unpin + ?Sized,
+    F: FnMut(u64) -> io::Result<()>,
+{
+    type Output = io::Result<u64>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.project().inner.poll(cx)
+    }
+}
+
+impl<R: fmt::Debug, W: fmt::Debug + ?Sized, F> fmt::Debug for CopyWithProgress<'_, R, W, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CopyWithProgress").field("inner", &self.inner).finish()
+    }
+}
+
+pin_project! {
+    /// Future for the [`copy_buf_with_progress()`] function.
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct CopyBufWithProgress<'a, R, W: ?Sized, F> {
+        #[pin]
+        reader: R,
+        writer: &'a mut W,
+        amt: u64,
+        progress: F,
+    }
+}
+
+impl<R, W, F> Future for CopyBufWithProgress<'_, R, W, F>
+where
+    R: AsyncBufRead,
+    W: AsyncWrite + Unpin + ?Sized,
+    F: FnMut(u64) -> io::Result<()>,
+{
+    type Output = io::Result<u64>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut this = self.project();
+        loop {
+            let buffer = ready!(this.reader.as_mut().poll_fill_buf(cx))?;
+            if buffer.is_empty() {
+                ready!(Pin::new(&mut this.writer).poll_flush(cx))?;
+                return Poll::Ready(Ok(*this.amt));
+            }
+
+            let i = ready!(Pin::new(&mut this.writer).poll_write(cx, buffer))?;
+            if i == 0 {
+                return Poll::Ready(Err(io::ErrorKind::WriteZero.into()));
+            }
+            *this.amt += i as u64;
+            this.reader.as_mut().consume(i);
+            (this.progress)(*this.amt)?;
+        }
+    }
+}
+
+impl<R: fmt::Debug, W: fmt::Debug + ?Sized, F> fmt::Debug for CopyBufWithProgress<'_, R, W, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CopyBufWithProgress")
+            .field("reader", &self.reader)
+            .field("writer", &self.writer)
+            .field("amt", &self.amt)
+            .finish()
+    }
+}
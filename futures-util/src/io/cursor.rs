@@ -1,7 +1,9 @@
 use futures_core::task::{Context, Poll};
 #[cfg(feature = "read_initializer")]
 use futures_io::Initializer;
-use futures_io::{AsyncBufRead, AsyncRead, AsyncSeek, AsyncWrite, IoSlice, IoSliceMut, SeekFrom};
+use futures_io::{
+    AsyncBufRead, AsyncRead, AsyncSeek, AsyncWrite, IoSlice, IoSliceMut, ReadBuf, SeekFrom,
+};
 use std::io;
 use std::pin::Pin;
 
@@ -180,6 +182,18 @@ impl<T: AsRef<[u8]> + Unpin> AsyncRead for Cursor<T> {
     ) -> Poll<io::Result<usize>> {
         Poll::Ready(io::Read::read_vectored(&mut self.inner, bufs))
     }
+
+    fn poll_read_buf(
+        mut self: Pin<&mut Self>,
+        _: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        let rem = io::BufRead::fill_buf(&mut self.inner)?;
+        let n = rem.len().min(buf.remaining());
+        buf.put_slice(&rem[..n]);
+        io::BufRead::consume(&mut self.inner, n);
+        Poll::Ready(Ok(()))
+    }
 }
 
 impl<T> AsyncBufRead for Cursor<T>
@@ -0,0 +1,60 @@
+use futures_core::ready;
+use futures_core::task::{Context, Poll};
+use futures_io::AsyncDatagramWrite;
+use futures_sink::Sink;
+use pin_project_lite::pin_project;
+use std::io;
+use std::pin::Pin;
+
+pin_project! {
+    /// Sink for the [`into_datagram_sink`](super::AsyncDatagramWriteExt::into_datagram_sink)
+    /// method.
+    #[must_use = "sinks do nothing unless polled"]
+    #[derive(Debug)]
+    #[cfg_attr(docsrs, doc(cfg(feature = "sink")))]
+    pub struct DatagramSink<W, B, A> {
+        #[pin]
+        writer: W,
+        // A datagram that has not been sent yet.
+        buffer: Option<(B, A)>,
+    }
+}
+
+impl<W: AsyncDatagramWrite<A>, B: AsRef<[u8]>, A> DatagramSink<W, B, A> {
+    pub(super) fn new(writer: W) -> Self {
+        Self { writer, buffer: None }
+    }
+
+    delegate_access_inner!(writer, W, ());
+
+    fn poll_send_buffer(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        let this = self.project();
+        if let Some((bytes, target)) = this.buffer {
+            ready!(this.writer.poll_send_to(cx, bytes.as_ref(), target))?;
+        }
+        *this.buffer = None;
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl<W: AsyncDatagramWrite<A>, B: AsRef<[u8]>, A> Sink<(B, A)> for DatagramSink<W, B, A> {
+    type Error = io::Error;
+
+    fn poll_ready(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.poll_send_buffer(cx)
+    }
+
+    fn start_send(self: Pin<&mut Self>, item: (B, A)) -> Result<(), Self::Error> {
+        debug_assert!(self.buffer.is_none());
+        *self.project().buffer = Some(item);
+        Ok(())
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.poll_send_buffer(cx)
+    }
+
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.poll_send_buffer(cx)
+    }
+}
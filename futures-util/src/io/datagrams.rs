@@ -0,0 +1,47 @@
+use futures_core::ready;
+use futures_core::stream::Stream;
+use futures_core::task::{Context, Poll};
+use futures_io::AsyncDatagramRead;
+use pin_project_lite::pin_project;
+use std::fmt;
+use std::io;
+use std::marker::PhantomData;
+use std::pin::Pin;
+
+pin_project! {
+    /// Stream for the [`datagrams`](super::AsyncDatagramReadExt::datagrams) method.
+    #[must_use = "streams do nothing unless polled"]
+    pub struct Datagrams<R, A> {
+        #[pin]
+        reader: R,
+        buf: Vec<u8>,
+        _addr: PhantomData<fn() -> A>,
+    }
+}
+
+impl<R: AsyncDatagramRead<A>, A> Datagrams<R, A> {
+    pub(super) fn new(reader: R, max_len: usize) -> Self {
+        Self { reader, buf: vec![0; max_len], _addr: PhantomData }
+    }
+
+    delegate_access_inner!(reader, R, ());
+}
+
+impl<R: fmt::Debug, A> fmt::Debug for Datagrams<R, A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Datagrams")
+            .field("reader", &self.reader)
+            .field("max_len", &self.buf.len())
+            .finish()
+    }
+}
+
+impl<R: AsyncDatagramRead<A>, A> Stream for Datagrams<R, A> {
+    type Item = io::Result<(Vec<u8>, A)>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        let (n, addr) = ready!(this.reader.poll_recv_from(cx, this.buf))?;
+        Poll::Ready(Some(Ok((this.buf[..n].to_vec(), addr))))
+    }
+}
@@ -0,0 +1,182 @@
+use futures_core::task::{Context, Poll, Waker};
+use futures_io::{AsyncRead, AsyncWrite};
+use std::collections::VecDeque;
+use std::io;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+
+/// One end of an in-memory pipe created by the [`duplex()`] function.
+///
+/// Bytes written to one end can be read from the other. Dropping or
+/// [closing](super::AsyncWriteExt::close) an end makes the other end reach
+/// EOF once it has read everything written before, and writing to an end
+/// whose peer has been dropped fails with a
+/// [`BrokenPipe`](io::ErrorKind::BrokenPipe) error.
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct DuplexStream {
+    read: Arc<Mutex<Pipe>>,
+    write: Arc<Mutex<Pipe>>,
+}
+
+/// Creates a pair of connected in-memory streams, each implementing
+/// [`AsyncRead`] and [`AsyncWrite`].
+///
+/// Each direction buffers up to `capacity` bytes; writing to an end whose
+/// buffer is full waits until the other end has read from it. This is
+/// useful for testing, and for connecting protocol implementations that
+/// run in the same process.
+///
+/// # Panics
+///
+/// Panics if `capacity` is 0.
+///
+/// # Examples
+///
+/// ```
+/// # futures::executor::block_on(async {
+/// use futures::io::{self, AsyncReadExt, AsyncWriteExt};
+///
+/// let (mut client, mut server) = io::duplex(64);
+///
+/// client.write_all(b"ping").await?;
+/// let mut buf = [0; 4];
+/// server.read_exact(&mut buf).await?;
+/// assert_eq!(&buf, b"ping");
+///
+/// server.write_all(b"pong").await?;
+/// drop(server);
+/// let mut reply = Vec::new();
+/// client.read_to_end(&mut reply).await?;
+/// assert_eq!(reply, b"pong");
+/// # Ok::<(), Box<dyn std::error::Error>>(()) }).unwrap();
+/// ```
+pub fn duplex(capacity: usize) -> (DuplexStream, DuplexStream) {
+    assert!(capacity > 0, "capacity must be non-zero");
+    let one = Arc::new(Mutex::new(Pipe::new(capacity)));
+    let two = Arc::new(Mutex::new(Pipe::new(capacity)));
+    (DuplexStream { read: one.clone(), write: two.clone() }, DuplexStream { read: two, write: one })
+}
+
+// The bytes in flight in one direction.
+#[derive(Debug)]
+struct Pipe {
+    buf: VecDeque<u8>,
+    capacity: usize,
+    // The writing end has been closed or dropped.
+    write_closed: bool,
+    // The reading end has been dropped.
+    read_closed: bool,
+    read_waker: Option<Waker>,
+    write_waker: Option<Waker>,
+}
+
+impl Pipe {
+    fn new(capacity: usize) -> Self {
+        Self {
+            buf: VecDeque::new(),
+            capacity,
+            write_closed: false,
+            read_closed: false,
+            read_waker: None,
+            write_waker: None,
+        }
+    }
+
+    fn close_write(&mut self) {
+        self.write_closed = true;
+        wake(&mut self.read_waker);
+    }
+
+    fn close_read(&mut self) {
+        self.read_closed = true;
+        wake(&mut self.write_waker);
+    }
+}
+
+fn register(slot: &mut Option<Waker>, cx: &Context<'_>) {
+    match slot {
+        Some(waker) if waker.will_wake(cx.waker()) => {}
+        _ => *slot = Some(cx.waker().clone()),
+    }
+}
+
+fn wake(slot: &mut Option<Waker>) {
+    if let Some(waker) = slot.take() {
+        waker.wake();
+    }
+}
+
+impl AsyncRead for DuplexStream {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        let mut pipe = self.read.lock().unwrap();
+        if buf.is_empty() {
+            return Poll::Ready(Ok(0));
+        }
+        if pipe.buf.is_empty() {
+            if pipe.write_closed {
+                return Poll::Ready(Ok(0));
+            }
+            register(&mut pipe.read_waker, cx);
+            return Poll::Pending;
+        }
+
+        let n = buf.len().min(pipe.buf.len());
+        for (dst, src) in buf.iter_mut().zip(pipe.buf.drain(..n)) {
+            *dst = src;
+        }
+        wake(&mut pipe.write_waker);
+        Poll::Ready(Ok(n))
+    }
+}
+
+impl AsyncWrite for DuplexStream {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        let mut pipe = self.write.lock().unwrap();
+        if pipe.read_closed || pipe.write_closed {
+            return Poll::Ready(Err(io::ErrorKind::BrokenPipe.into()));
+        }
+        if buf.is_empty() {
+            return Poll::Ready(Ok(0));
+        }
+        let room = pipe.capacity - pipe.buf.len();
+        if room == 0 {
+            register(&mut pipe.write_waker, cx);
+            return Poll::Pending;
+        }
+
+        let n = buf.len().min(room);
+        pipe.buf.extend(&buf[..n]);
+        wake(&mut pipe.read_waker);
+        Poll::Ready(Ok(n))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_close(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<io::Result<()>> {
+        self.write.lock().unwrap().close_write();
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl Drop for DuplexStream {
+    fn drop(&mut self) {
+        // Don't panic while unwinding if the other end poisoned the locks.
+        if let Ok(mut pipe) = self.write.lock() {
+            pipe.close_write();
+        }
+        if let Ok(mut pipe) = self.read.lock() {
+            pipe.close_read();
+        }
+    }
+}
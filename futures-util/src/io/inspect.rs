@@ -0,0 +1,213 @@
+use futures_core::ready;
+use futures_core::task::{Context, Poll};
+use futures_io::{AsyncRead, AsyncWrite, IoSlice, IoSliceMut, ReadBuf};
+use pin_project_lite::pin_project;
+use std::fmt;
+use std::io;
+use std::ops::Deref;
+use std::pin::Pin;
+
+/// Creates a reader that calls a closure with every chunk of bytes read from
+/// `reader`.
+///
+/// The closure sees exactly the bytes handed out to the caller, in order, and
+/// is not called for reads that return no bytes. This is useful for hashing,
+/// counting or logging data as it goes through, without writing an
+/// [`AsyncRead`] implementation for it.
+///
+/// # Examples
+///
+/// ```
+/// # futures::executor::block_on(async {
+/// use futures::io::{self, AsyncReadExt, Cursor};
+///
+/// let mut count = 0;
+/// let mut reader = io::inspect_read(Cursor::new([1, 2, 3, 4]), |chunk| count += chunk.len());
+///
+/// let mut buffer = Vec::new();
+/// reader.read_to_end(&mut buffer).await?;
+/// drop(reader);
+///
+/// assert_eq!(buffer, [1, 2, 3, 4]);
+/// assert_eq!(count, 4);
+/// # Ok::<(), Box<dyn std::error::Error>>(()) }).unwrap();
+/// ```
+pub fn inspect_read<R, F>(reader: R, f: F) -> InspectRead<R, F>
+where
+    R: AsyncRead,
+    F: FnMut(&[u8]),
+{
+    InspectRead { inner: reader, f }
+}
+
+/// Creates a writer that calls a closure with every chunk of bytes written to
+/// `writer`.
+///
+/// The closure sees exactly the bytes `writer` accepted, in order, and is not
+/// called for writes that take no bytes. This is useful for hashing, counting
+/// or logging data as it goes through, without writing an [`AsyncWrite`]
+/// implementation for it.
+///
+/// # Examples
+///
+/// ```
+/// # futures::executor::block_on(async {
+/// use futures::io::{self, AsyncWriteExt};
+///
+/// let mut log = Vec::new();
+/// let mut writer = io::inspect_write(Vec::new(), |chunk| log.push(chunk.len()));
+///
+/// writer.write_all(b"hello").await?;
+/// writer.write_all(b"world!").await?;
+/// drop(writer);
+///
+/// assert_eq!(log, [5, 6]);
+/// # Ok::<(), Box<dyn std::error::Error>>(()) }).unwrap();
+/// ```
+pub fn inspect_write<W, F>(writer: W, f: F) -> InspectWrite<W, F>
+where
+    W: AsyncWrite,
+    F: FnMut(&[u8]),
+{
+    InspectWrite { inner: writer, f }
+}
+
+// Calls `f` with the first `n` bytes spread over `bufs`.
+fn inspect_slices<F, B>(f: &mut F, bufs: &[B], mut n: usize)
+where
+    F: FnMut(&[u8]),
+    B: Deref<Target = [u8]>,
+{
+    for buf in bufs {
+        if n == 0 {
+            break;
+        }
+        let len = buf.len().min(n);
+        if len > 0 {
+            f(&buf[..len]);
+        }
+        n -= len;
+    }
+}
+
+pin_project! {
+    /// Reader for the [`inspect_read()`] function.
+    #[must_use = "readers do nothing unless you `.await` or poll them"]
+    pub struct InspectRead<R, F> {
+        #[pin]
+        inner: R,
+        f: F,
+    }
+}
+
+impl<R, F> InspectRead<R, F> {
+    delegate_access_inner!(inner, R, ());
+}
+
+impl<R: fmt::Debug, F> fmt::Debug for InspectRead<R, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("InspectRead").field("inner", &self.inner).finish()
+    }
+}
+
+impl<R, F> AsyncRead for InspectRead<R, F>
+where
+    R: AsyncRead,
+    F: FnMut(&[u8]),
+{
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        let this = self.project();
+        let n = ready!(this.inner.poll_read(cx, buf))?;
+        if n > 0 {
+            (this.f)(&buf[..n]);
+        }
+        Poll::Ready(Ok(n))
+    }
+
+    fn poll_read_vectored(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        bufs: &mut [IoSliceMut<'_>],
+    ) -> Poll<io::Result<usize>> {
+        let this = self.project();
+        let n = ready!(this.inner.poll_read_vectored(cx, bufs))?;
+        inspect_slices(this.f, bufs, n);
+        Poll::Ready(Ok(n))
+    }
+
+    fn poll_read_buf(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        let this = self.project();
+        let before = buf.filled().len();
+        ready!(this.inner.poll_read_buf(cx, buf))?;
+        if buf.filled().len() > before {
+            (this.f)(&buf.filled()[before..]);
+        }
+        Poll::Ready(Ok(()))
+    }
+}
+
+pin_project! {
+    /// Writer for the [`inspect_write()`] function.
+    #[must_use = "writers do nothing unless polled"]
+    pub struct InspectWrite<W, F> {
+        #[pin]
+        inner: W,
+        f: F,
+    }
+}
+
+impl<W, F> InspectWrite<W, F> {
+    delegate_access_inner!(inner, W, ());
+}
+
+impl<W: fmt::Debug, F> fmt::Debug for InspectWrite<W, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("InspectWrite").field("inner", &self.inner).finish()
+    }
+}
+
+impl<W, F> AsyncWrite for InspectWrite<W, F>
+where
+    W: AsyncWrite,
+    F: FnMut(&[u8]),
+{
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        let this = self.project();
+        let n = ready!(this.inner.poll_write(cx, buf))?;
+        if n > 0 {
+            (this.f)(&buf[..n]);
+        }
+        Poll::Ready(Ok(n))
+    }
+
+    fn poll_write_vectored(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        bufs: &[IoSlice<'_>],
+    ) -> Poll<io::Result<usize>> {
+        let this = self.project();
+        let n = ready!(this.inner.poll_write_vectored(cx, bufs))?;
+        inspect_slices(this.f, bufs, n);
+        Poll::Ready(Ok(n))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        self.project().inner.poll_flush(cx)
+    }
+
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        self.project().inner.poll_close(cx)
+    }
+}
@@ -0,0 +1,148 @@
+use futures_core::ready;
+use futures_core::task::{Context, Poll};
+use futures_io::{AsyncWrite, IoSlice};
+use pin_project_lite::pin_project;
+use std::pin::Pin;
+use std::{cmp, io};
+
+pin_project! {
+    /// Wraps a writer and limits the number of bytes that can be written to
+    /// it, the writing counterpart of [`Take`](super::Take).
+    ///
+    /// Writes are cut short so that at most `limit` bytes reach the
+    /// underlying writer. Once the limit has been reached, writing anything
+    /// more fails with a [`WriteZero`](io::ErrorKind::WriteZero) error, so
+    /// [`write_all`](super::AsyncWriteExt::write_all) fails for data that
+    /// does not fit. Flushing and closing are not limited.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::io::{AsyncWriteExt, ErrorKind, LimitedWriter};
+    ///
+    /// let mut writer = LimitedWriter::new(Vec::new(), 4);
+    ///
+    /// writer.write_all(b"ab").await?;
+    /// assert_eq!(writer.write(b"cdef").await?, 2);
+    ///
+    /// let err = writer.write_all(b"g").await.unwrap_err();
+    /// assert_eq!(err.kind(), ErrorKind::WriteZero);
+    /// assert_eq!(writer.into_inner(), b"abcd");
+    /// # Ok::<(), Box<dyn std::error::Error>>(()) }).unwrap();
+    /// ```
+    #[derive(Debug)]
+    #[must_use = "writers do nothing unless polled"]
+    pub struct LimitedWriter<W> {
+        #[pin]
+        inner: W,
+        limit: u64,
+    }
+}
+
+impl<W: AsyncWrite> LimitedWriter<W> {
+    /// Creates a new `LimitedWriter` that writes at most `limit` bytes to
+    /// `inner`.
+    pub fn new(inner: W, limit: u64) -> Self {
+        Self { inner, limit }
+    }
+
+    /// Returns the remaining number of bytes that can be written before
+    /// this instance starts returning errors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::io::{AsyncWriteExt, LimitedWriter};
+    ///
+    /// let mut writer = LimitedWriter::new(Vec::new(), 4);
+    /// writer.write_all(b"ab").await?;
+    ///
+    /// assert_eq!(writer.limit(), 2);
+    /// # Ok::<(), Box<dyn std::error::Error>>(()) }).unwrap();
+    /// ```
+    pub fn limit(&self) -> u64 {
+        self.limit
+    }
+
+    /// Sets the number of bytes that can be written before this instance
+    /// starts returning errors. This is the same as constructing a new
+    /// `LimitedWriter` instance, so the amount of bytes written and the
+    /// previous limit value don't matter when calling this method.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::io::{AsyncWriteExt, LimitedWriter};
+    ///
+    /// let mut writer = LimitedWriter::new(Vec::new(), 2);
+    /// writer.write_all(b"ab").await?;
+    /// assert_eq!(writer.limit(), 0);
+    ///
+    /// writer.set_limit(10);
+    /// writer.write_all(b"cdef").await?;
+    ///
+    /// assert_eq!(writer.into_inner(), b"abcdef");
+    /// # Ok::<(), Box<dyn std::error::Error>>(()) }).unwrap();
+    /// ```
+    pub fn set_limit(&mut self, limit: u64) {
+        self.limit = limit
+    }
+
+    delegate_access_inner!(inner, W, ());
+}
+
+fn limit_reached() -> io::Error {
+    io::Error::new(io::ErrorKind::WriteZero, "write limit reached")
+}
+
+impl<W: AsyncWrite> AsyncWrite for LimitedWriter<W> {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        let this = self.project();
+
+        if buf.is_empty() {
+            return this.inner.poll_write(cx, buf);
+        }
+        if *this.limit == 0 {
+            return Poll::Ready(Err(limit_reached()));
+        }
+
+        let max = cmp::min(buf.len() as u64, *this.limit) as usize;
+        let n = ready!(this.inner.poll_write(cx, &buf[..max]))?;
+        *this.limit -= n as u64;
+        Poll::Ready(Ok(n))
+    }
+
+    fn poll_write_vectored(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        bufs: &[IoSlice<'_>],
+    ) -> Poll<io::Result<usize>> {
+        let len = bufs.iter().map(|b| b.len() as u64).sum::<u64>();
+        if len > self.limit {
+            // The slices would have to be cut short; write what fits of the
+            // first non-empty one instead.
+            let buf = bufs.iter().find(|b| !b.is_empty()).map_or(&[][..], |b| &**b);
+            return self.poll_write(cx, buf);
+        }
+
+        let this = self.project();
+        let n = ready!(this.inner.poll_write_vectored(cx, bufs))?;
+        *this.limit -= n as u64;
+        Poll::Ready(Ok(n))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        self.project().inner.poll_flush(cx)
+    }
+
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        self.project().inner.poll_close(cx)
+    }
+}
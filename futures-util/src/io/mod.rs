@@ -28,7 +28,9 @@ use std::{pin::Pin, ptr};
 #[doc(no_inline)]
 pub use std::io::{Error, ErrorKind, IoSlice, IoSliceMut, Result, SeekFrom};
 
-pub use futures_io::{AsyncBufRead, AsyncRead, AsyncSeek, AsyncWrite};
+pub use futures_io::{
+    AsyncBufRead, AsyncDatagramRead, AsyncDatagramWrite, AsyncRead, AsyncSeek, AsyncWrite, ReadBuf,
+};
 
 // used by `BufReader` and `BufWriter`
 // https://github.com/rust-lang/rust/blob/master/src/libstd/sys_common/io.rs#L1
@@ -45,8 +47,14 @@ unsafe fn initialize<R: AsyncRead>(_reader: &R, buf: &mut [u8]) {
 mod allow_std;
 pub use self::allow_std::AllowStdIo;
 
+mod auto_flush_writer;
+pub use self::auto_flush_writer::AutoFlushWriter;
+
 mod buf_reader;
-pub use self::buf_reader::{BufReader, SeeKRelative};
+pub use self::buf_reader::BufReader;
+
+#[deprecated(note = "This is now an alias for [SeekRelative](SeekRelative)")]
+pub use self::buf_reader::SeeKRelative;
 
 mod buf_writer;
 pub use self::buf_writer::BufWriter;
@@ -57,18 +65,47 @@ pub use self::line_writer::LineWriter;
 mod chain;
 pub use self::chain::Chain;
 
+mod chain_all;
+pub use self::chain_all::{chain_all, ChainAll};
+
 mod close;
 pub use self::close::Close;
 
+mod coalescing_writer;
+pub use self::coalescing_writer::CoalescingWriter;
+
+pub mod codec;
+
 mod copy;
 pub use self::copy::{copy, Copy};
 
 mod copy_buf;
 pub use self::copy_buf::{copy_buf, CopyBuf};
 
+mod copy_with;
+pub use self::copy_with::{copy_with, BufferPool, CopyConfig, CopyWith};
+
+mod copy_with_progress;
+pub use self::copy_with_progress::{
+    copy_buf_with_progress, copy_with_progress, CopyBufWithProgress, CopyWithProgress,
+};
+
 mod cursor;
 pub use self::cursor::Cursor;
 
+#[cfg(feature = "sink")]
+#[cfg_attr(docsrs, doc(cfg(feature = "sink")))]
+mod datagram_sink;
+#[cfg(feature = "sink")]
+#[cfg_attr(docsrs, doc(cfg(feature = "sink")))]
+pub use self::datagram_sink::DatagramSink;
+
+mod datagrams;
+pub use self::datagrams::Datagrams;
+
+mod duplex;
+pub use self::duplex::{duplex, DuplexStream};
+
 mod empty;
 pub use self::empty::{empty, Empty};
 
@@ -78,6 +115,9 @@ pub use self::fill_buf::FillBuf;
 mod flush;
 pub use self::flush::Flush;
 
+mod inspect;
+pub use self::inspect::{inspect_read, inspect_write, InspectRead, InspectWrite};
+
 #[cfg(feature = "sink")]
 #[cfg_attr(docsrs, doc(cfg(feature = "sink")))]
 mod into_sink;
@@ -85,21 +125,35 @@ mod into_sink;
 #[cfg_attr(docsrs, doc(cfg(feature = "sink")))]
 pub use self::into_sink::IntoSink;
 
+mod limited_writer;
+pub use self::limited_writer::LimitedWriter;
+
 mod lines;
 pub use self::lines::Lines;
 
+pub mod ratelimit;
+
 mod read;
 pub use self::read::Read;
 
 mod read_vectored;
 pub use self::read_vectored::ReadVectored;
 
+mod read_buf;
+pub use self::read_buf::ReadIntoBuf;
+
 mod read_exact;
 pub use self::read_exact::ReadExact;
 
+mod read_exact_or_eof;
+pub use self::read_exact_or_eof::ReadExactOrEof;
+
 mod read_line;
 pub use self::read_line::ReadLine;
 
+mod read_line_limited;
+pub use self::read_line_limited::ReadLineLimited;
+
 mod read_to_end;
 pub use self::read_to_end::ReadToEnd;
 
@@ -109,11 +163,26 @@ pub use self::read_to_string::ReadToString;
 mod read_until;
 pub use self::read_until::ReadUntil;
 
+mod read_until_limited;
+pub use self::read_until_limited::{Overflow, ReadUntilLimited};
+
+mod recv_from;
+pub use self::recv_from::RecvFrom;
+
+mod reader_stream;
+pub use self::reader_stream::ReaderStream;
+
 mod repeat;
 pub use self::repeat::{repeat, Repeat};
 
 mod seek;
-pub use self::seek::Seek;
+pub use self::seek::{Seek, SeekRelative};
+
+mod segmented_cursor;
+pub use self::segmented_cursor::{SegmentedCursor, Segments};
+
+mod send_to;
+pub use self::send_to::SendTo;
 
 mod sink;
 pub use self::sink::{sink, Sink};
@@ -121,9 +190,25 @@ pub use self::sink::{sink, Sink};
 mod split;
 pub use self::split::{ReadHalf, ReuniteError, WriteHalf};
 
+mod split_by;
+pub use self::split_by::SplitBy;
+
+mod sync_io;
+pub use self::sync_io::{SyncReader, SyncWriter};
+
 mod take;
 pub use self::take::Take;
 
+mod tee;
+pub use self::tee::Tee;
+
+#[cfg(feature = "tokio-compat")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tokio-compat")))]
+mod tokio_compat;
+#[cfg(feature = "tokio-compat")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tokio-compat")))]
+pub use self::tokio_compat::{TokioCompat, TokioCompatExt};
+
 mod window;
 pub use self::window::Window;
 
@@ -220,6 +305,38 @@ pub trait AsyncReadExt: AsyncRead {
         assert_future::<Result<usize>, _>(ReadVectored::new(self, bufs))
     }
 
+    /// Creates a future which will read from the `AsyncRead` into `buf`,
+    /// which may be uninitialized.
+    ///
+    /// The returned future will resolve once the read operation is completed,
+    /// with the bytes read marked as filled in `buf`. See
+    /// [`AsyncRead::poll_read_buf`] for how this avoids zeroing memory.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::io::{AsyncReadExt, Cursor, ReadBuf};
+    /// use std::mem::MaybeUninit;
+    ///
+    /// let mut reader = Cursor::new([1, 2, 3, 4]);
+    /// let mut storage = [MaybeUninit::<u8>::uninit(); 8];
+    /// let mut buf = ReadBuf::uninit(&mut storage);
+    ///
+    /// reader.read_buf(&mut buf).await?;
+    ///
+    /// assert_eq!(buf.filled(), [1, 2, 3, 4]);
+    /// // Nothing beyond the bytes read had to be initialized.
+    /// assert_eq!(buf.initialized().len(), 4);
+    /// # Ok::<(), Box<dyn std::error::Error>>(()) }).unwrap();
+    /// ```
+    fn read_buf<'a, 'b>(&'a mut self, buf: &'a mut ReadBuf<'b>) -> ReadIntoBuf<'a, 'b, Self>
+    where
+        Self: Unpin,
+    {
+        assert_future::<Result<()>, _>(ReadIntoBuf::new(self, buf))
+    }
+
     /// Creates a future which will read exactly enough bytes to fill `buf`,
     /// returning an error if end of file (EOF) is hit sooner.
     ///
@@ -264,6 +381,42 @@ pub trait AsyncReadExt: AsyncRead {
         assert_future::<Result<()>, _>(ReadExact::new(self, buf))
     }
 
+    /// Creates a future which will read bytes from this `AsyncRead` until
+    /// `buf` is full or EOF is reached.
+    ///
+    /// Unlike [`read_exact`](AsyncReadExt::read_exact), reaching EOF before
+    /// `buf` is filled is not an error: the future resolves to the number of
+    /// bytes read, which is less than `buf.len()` only at EOF. This is useful
+    /// for reading fixed-size records where the last one may be partial.
+    ///
+    /// If an error occurs, the bytes read so far have been written to `buf`,
+    /// but their number is not reported.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::io::{AsyncReadExt, Cursor};
+    ///
+    /// let mut reader = Cursor::new([1, 2, 3, 4, 5]);
+    /// let mut record = [0u8; 3];
+    ///
+    /// assert_eq!(reader.read_exact_or_eof(&mut record).await?, 3);
+    /// assert_eq!(record, [1, 2, 3]);
+    ///
+    /// assert_eq!(reader.read_exact_or_eof(&mut record).await?, 2);
+    /// assert_eq!(record[..2], [4, 5]);
+    ///
+    /// assert_eq!(reader.read_exact_or_eof(&mut record).await?, 0);
+    /// # Ok::<(), Box<dyn std::error::Error>>(()) }).unwrap();
+    /// ```
+    fn read_exact_or_eof<'a>(&'a mut self, buf: &'a mut [u8]) -> ReadExactOrEof<'a, Self>
+    where
+        Self: Unpin,
+    {
+        assert_future::<Result<usize>, _>(ReadExactOrEof::new(self, buf))
+    }
+
     /// Creates a future which will read all the bytes from this `AsyncRead`.
     ///
     /// On success the total number of bytes read is returned.
@@ -393,6 +546,46 @@ pub trait AsyncReadExt: AsyncRead {
     {
         Compat::new(self)
     }
+
+    /// Converts this reader into a blocking [`std::io::Read`].
+    ///
+    /// Each read blocks the current thread until this reader is ready, using
+    /// a lightweight executor that parks the thread, so that code written for
+    /// `std::io::Read` can read from it. This is the opposite of
+    /// [`AllowStdIo`].
+    ///
+    /// If this reader also implements [`AsyncSeek`], the returned reader
+    /// implements [`std::io::Seek`].
+    ///
+    /// # Blocking
+    ///
+    /// The returned reader must only be used from synchronous code, such as a
+    /// thread dedicated to it, and never from async context: a read called
+    /// from a future or from a task run by an executor blocks the thread of
+    /// the executor, which stops it from running its other tasks, and
+    /// deadlocks it if the reader waits for one of them. Unlike a nested
+    /// `futures::executor::block_on`, this isn't detected, and doesn't
+    /// panic.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures::io::{AsyncReadExt, Cursor};
+    /// use std::io::Read;
+    ///
+    /// let mut reader = Cursor::new(b"hello world").into_sync_read();
+    ///
+    /// let mut contents = String::new();
+    /// reader.read_to_string(&mut contents)?;
+    /// assert_eq!(contents, "hello world");
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    fn into_sync_read(self) -> SyncReader<Self>
+    where
+        Self: Sized + Unpin,
+    {
+        SyncReader::new(self)
+    }
 }
 
 impl<R: AsyncRead + ?Sized> AsyncReadExt for R {}
@@ -587,6 +780,79 @@ pub trait AsyncWriteExt: AsyncWrite {
     {
         crate::sink::assert_sink::<Item, Error, _>(IntoSink::new(self))
     }
+
+    /// Creates an adaptor which writes everything written to it to both this
+    /// writer and `other`.
+    ///
+    /// Each write goes to this writer first, and the bytes it accepts are then
+    /// written to `other` as well. Flushing or closing the returned writer
+    /// flushes or closes both. The bytes `other` has not taken yet are
+    /// buffered, at most one write's worth at a time, so the data can be sent
+    /// and archived at once without holding on to all of it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::io::{AsyncWriteExt, Cursor};
+    ///
+    /// let mut writer = Cursor::new(vec![]).tee(Cursor::new(vec![]));
+    ///
+    /// writer.write_all(&[1, 2, 3, 4]).await?;
+    /// writer.close().await?;
+    ///
+    /// let (sent, archived) = writer.into_inner();
+    /// assert_eq!(sent.into_inner(), [1, 2, 3, 4]);
+    /// assert_eq!(archived.into_inner(), [1, 2, 3, 4]);
+    /// # Ok::<(), Box<dyn std::error::Error>>(()) }).unwrap();
+    /// ```
+    fn tee<W>(self, other: W) -> Tee<Self, W>
+    where
+        Self: Sized,
+        W: AsyncWrite,
+    {
+        assert_write(Tee::new(self, other))
+    }
+
+    /// Converts this writer into a blocking [`std::io::Write`].
+    ///
+    /// Each write blocks the current thread until this writer is ready,
+    /// using a lightweight executor that parks the thread, so that code
+    /// written for `std::io::Write` can write to it. This is the opposite of
+    /// [`AllowStdIo`].
+    ///
+    /// If this writer also implements [`AsyncSeek`], the returned writer
+    /// implements [`std::io::Seek`].
+    ///
+    /// # Blocking
+    ///
+    /// The returned writer must only be used from synchronous code, such as a
+    /// thread dedicated to it, and never from async context: a write called
+    /// from a future or from a task run by an executor blocks the thread of
+    /// the executor, which stops it from running its other tasks, and
+    /// deadlocks it if the writer waits for one of them. Unlike a nested
+    /// `futures::executor::block_on`, this isn't detected, and doesn't
+    /// panic.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures::io::{AsyncWriteExt, Cursor};
+    /// use std::io::Write;
+    ///
+    /// let mut writer = Cursor::new(Vec::new()).into_sync_write();
+    ///
+    /// write!(writer, "{} + {} = {}", 1, 2, 1 + 2)?;
+    /// writer.flush()?;
+    /// assert_eq!(writer.into_inner().into_inner(), b"1 + 2 = 3");
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    fn into_sync_write(self) -> SyncWriter<Self>
+    where
+        Self: Sized + Unpin,
+    {
+        SyncWriter::new(self)
+    }
 }
 
 impl<W: AsyncWrite + ?Sized> AsyncWriteExt for W {}
@@ -615,6 +881,50 @@ pub trait AsyncSeekExt: AsyncSeek {
     {
         self.seek(SeekFrom::Current(0))
     }
+
+    /// Creates a future which will seek relative to the current position, by
+    /// `offset` bytes.
+    ///
+    /// This is like seeking with `SeekFrom::Current(offset)`, but does not
+    /// yield the new position. Buffered readers like [`BufReader`] keep their
+    /// buffer if the new position lies within it, which makes skipping over
+    /// small parts of the data cheap.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::io::{AsyncReadExt, AsyncSeekExt, BufReader, Cursor};
+    ///
+    /// let mut reader = BufReader::new(Cursor::new(b"header:body"));
+    /// let mut tag = [0; 6];
+    /// reader.read_exact(&mut tag).await?;
+    ///
+    /// // Skip the separator.
+    /// reader.seek_relative(1).await?;
+    /// assert_eq!(reader.buffer(), b"body");
+    ///
+    /// let mut body = String::new();
+    /// reader.read_to_string(&mut body).await?;
+    /// assert_eq!(body, "body");
+    /// # Ok::<(), Box<dyn std::error::Error>>(()) }).unwrap();
+    /// ```
+    fn seek_relative(&mut self, offset: i64) -> SeekRelative<'_, Self>
+    where
+        Self: Unpin,
+    {
+        assert_future::<Result<()>, _>(SeekRelative::new(Pin::new(self), offset))
+    }
+
+    /// Creates a future which will seek to the start of the stream.
+    ///
+    /// This is equivalent to `self.seek(SeekFrom::Start(0))`.
+    fn rewind(&mut self) -> Seek<'_, Self>
+    where
+        Self: Unpin,
+    {
+        self.seek(SeekFrom::Start(0))
+    }
 }
 
 impl<S: AsyncSeek + ?Sized> AsyncSeekExt for S {}
@@ -723,6 +1033,53 @@ pub trait AsyncBufReadExt: AsyncBufRead {
         assert_future::<Result<usize>, _>(ReadUntil::new(self, byte, buf))
     }
 
+    /// Creates a future which will read all the bytes associated with this I/O
+    /// object into `buf` until the delimiter `byte` or EOF is reached, but
+    /// appends at most `limit` bytes to `buf`.
+    ///
+    /// This works like [`read_until`](AsyncBufReadExt::read_until), except
+    /// that a line of more than `limit` bytes, counting the delimiter, is
+    /// handled according to `overflow`: with [`Overflow::Error`] the future
+    /// fails with an [`InvalidData`](std::io::ErrorKind::InvalidData) error,
+    /// and with [`Overflow::Truncate`] only the first `limit` bytes are kept
+    /// and the rest of the line is skipped. Either way, a peer sending a
+    /// line without end cannot make `buf` grow without bound.
+    ///
+    /// The returned future will resolve to the number of bytes consumed from
+    /// the reader, which is larger than the number of bytes appended to `buf`
+    /// if the line was truncated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::io::{AsyncBufReadExt, Cursor, ErrorKind, Overflow};
+    ///
+    /// let mut cursor = Cursor::new(b"lorem-ipsum-dolor");
+    /// let mut buf = vec![];
+    ///
+    /// let num_bytes = cursor.read_until_limited(b'-', &mut buf, 3, Overflow::Truncate).await?;
+    /// assert_eq!(num_bytes, 6);
+    /// assert_eq!(buf, b"lor");
+    /// buf.clear();
+    ///
+    /// let result = cursor.read_until_limited(b'-', &mut buf, 3, Overflow::Error).await;
+    /// assert_eq!(result.unwrap_err().kind(), ErrorKind::InvalidData);
+    /// # Ok::<(), Box<dyn std::error::Error>>(()) }).unwrap();
+    /// ```
+    fn read_until_limited<'a>(
+        &'a mut self,
+        byte: u8,
+        buf: &'a mut Vec<u8>,
+        limit: usize,
+        overflow: Overflow,
+    ) -> ReadUntilLimited<'a, Self>
+    where
+        Self: Unpin,
+    {
+        assert_future::<Result<usize>, _>(ReadUntilLimited::new(self, byte, buf, limit, overflow))
+    }
+
     /// Creates a future which will read all the bytes associated with this I/O
     /// object into `buf` until a newline (the 0xA byte) or EOF is reached,
     /// This method is the async equivalent to [`BufRead::read_line`](std::io::BufRead::read_line).
@@ -781,6 +1138,52 @@ pub trait AsyncBufReadExt: AsyncBufRead {
         assert_future::<Result<usize>, _>(ReadLine::new(self, buf))
     }
 
+    /// Creates a future which will read all the bytes associated with this I/O
+    /// object into `buf` until a newline (the 0xA byte) or EOF is reached, but
+    /// appends at most `limit` bytes to `buf`.
+    ///
+    /// This works like [`read_line`](AsyncBufReadExt::read_line), and handles
+    /// lines longer than `limit` bytes, counting the newline, according to
+    /// `overflow` as [`read_until_limited`](AsyncBufReadExt::read_until_limited)
+    /// does. Line-based protocol servers can use it to bound the memory used
+    /// by a single line.
+    ///
+    /// When a line is truncated in the middle of a character, the incomplete
+    /// character is dropped as well, so `buf` stays valid UTF-8.
+    ///
+    /// The returned future will resolve to the number of bytes consumed from
+    /// the reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::io::{AsyncBufReadExt, Cursor, Overflow};
+    ///
+    /// let mut cursor = Cursor::new(b"a very long line\nshort\n");
+    /// let mut buf = String::new();
+    ///
+    /// let num_bytes = cursor.read_line_limited(&mut buf, 6, Overflow::Truncate).await?;
+    /// assert_eq!(num_bytes, 17);
+    /// assert_eq!(buf, "a very");
+    /// buf.clear();
+    ///
+    /// cursor.read_line_limited(&mut buf, 6, Overflow::Truncate).await?;
+    /// assert_eq!(buf, "short\n");
+    /// # Ok::<(), Box<dyn std::error::Error>>(()) }).unwrap();
+    /// ```
+    fn read_line_limited<'a>(
+        &'a mut self,
+        buf: &'a mut String,
+        limit: usize,
+        overflow: Overflow,
+    ) -> ReadLineLimited<'a, Self>
+    where
+        Self: Unpin,
+    {
+        assert_future::<Result<usize>, _>(ReadLineLimited::new(self, buf, limit, overflow))
+    }
+
     /// Returns a stream over the lines of this reader.
     /// This method is the async equivalent to [`BufRead::lines`](std::io::BufRead::lines).
     ///
@@ -819,10 +1222,137 @@ pub trait AsyncBufReadExt: AsyncBufRead {
     {
         assert_stream::<Result<String>, _>(Lines::new(self))
     }
+
+    /// Returns a stream over the segments of this reader separated by
+    /// `delimiter`, which may be several bytes long.
+    ///
+    /// The stream yields the bytes between delimiters, without the delimiter
+    /// itself. The bytes after the last delimiter are yielded as a final
+    /// segment unless they are empty.
+    ///
+    /// A segment longer than `max_len` bytes fails with an
+    /// [`InvalidData`](std::io::ErrorKind::InvalidData) error as soon as
+    /// this is known, without buffering the rest of it, and ends the stream.
+    /// The stream also ends after any other error.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `delimiter` is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::io::{AsyncBufReadExt, Cursor};
+    /// use futures::stream::TryStreamExt;
+    ///
+    /// let cursor = Cursor::new(b"GET / HTTP/1.1\r\n\r\nbody\r\n\r\n");
+    ///
+    /// let segments: Vec<Vec<u8>> = cursor.split_by("\r\n\r\n", 1024).try_collect().await?;
+    /// assert_eq!(segments, [b"GET / HTTP/1.1".to_vec(), b"body".to_vec()]);
+    /// # Ok::<(), Box<dyn std::error::Error>>(()) }).unwrap();
+    /// ```
+    fn split_by<D>(self, delimiter: D, max_len: usize) -> SplitBy<Self>
+    where
+        Self: Sized,
+        D: Into<Vec<u8>>,
+    {
+        assert_stream::<Result<Vec<u8>>, _>(SplitBy::new(self, delimiter.into(), max_len))
+    }
 }
 
 impl<R: AsyncBufRead + ?Sized> AsyncBufReadExt for R {}
 
+/// An extension trait which adds utility methods to `AsyncDatagramRead` types.
+pub trait AsyncDatagramReadExt<A>: AsyncDatagramRead<A> {
+    /// Creates a future which will receive a single datagram into `buf`.
+    ///
+    /// The returned future will resolve to the number of bytes received and
+    /// the address of the sender once a datagram has been received.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::io::{AsyncDatagramRead, AsyncDatagramReadExt};
+    /// use futures::task::{Context, Poll};
+    /// use std::io;
+    /// use std::pin::Pin;
+    ///
+    /// // A socket that always receives "hi" from address 1.
+    /// struct Socket;
+    ///
+    /// impl AsyncDatagramRead<u32> for Socket {
+    ///     fn poll_recv_from(
+    ///         self: Pin<&mut Self>,
+    ///         _: &mut Context<'_>,
+    ///         buf: &mut [u8],
+    ///     ) -> Poll<io::Result<(usize, u32)>> {
+    ///         buf[..2].copy_from_slice(b"hi");
+    ///         Poll::Ready(Ok((2, 1)))
+    ///     }
+    /// }
+    ///
+    /// let mut buf = [0; 16];
+    /// let (n, from) = Socket.recv_from(&mut buf).await?;
+    /// assert_eq!((&buf[..n], from), (&b"hi"[..], 1));
+    /// # Ok::<(), Box<dyn std::error::Error>>(()) }).unwrap();
+    /// ```
+    fn recv_from<'a>(&'a mut self, buf: &'a mut [u8]) -> RecvFrom<'a, Self, A>
+    where
+        Self: Unpin,
+    {
+        assert_future::<Result<(usize, A)>, _>(RecvFrom::new(self, buf))
+    }
+
+    /// Converts this datagram reader into a stream of the received datagrams
+    /// and the addresses they were sent from.
+    ///
+    /// Datagrams longer than `max_len` bytes are truncated, as
+    /// [`poll_recv_from`](AsyncDatagramRead::poll_recv_from) does. An error
+    /// does not end the stream, since errors of datagram transports are often
+    /// about a single datagram.
+    fn datagrams(self, max_len: usize) -> Datagrams<Self, A>
+    where
+        Self: Sized,
+    {
+        assert_stream::<Result<(Vec<u8>, A)>, _>(Datagrams::new(self, max_len))
+    }
+}
+
+impl<A, R: AsyncDatagramRead<A> + ?Sized> AsyncDatagramReadExt<A> for R {}
+
+/// An extension trait which adds utility methods to `AsyncDatagramWrite` types.
+pub trait AsyncDatagramWriteExt<A>: AsyncDatagramWrite<A> {
+    /// Creates a future which will send `buf` as a single datagram to
+    /// `target`.
+    ///
+    /// The returned future will resolve to the number of bytes sent once the
+    /// datagram has been sent.
+    fn send_to<'a>(&'a mut self, buf: &'a [u8], target: &'a A) -> SendTo<'a, Self, A>
+    where
+        Self: Unpin,
+    {
+        assert_future::<Result<usize>, _>(SendTo::new(self, buf, target))
+    }
+
+    /// Allow using a datagram writer as a sink of datagrams and the
+    /// addresses to send them to.
+    ///
+    /// Each item is sent as one datagram. Flushing or closing the sink sends
+    /// the datagram it holds, if any.
+    #[cfg(feature = "sink")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "sink")))]
+    fn into_datagram_sink<B: AsRef<[u8]>>(self) -> DatagramSink<Self, B, A>
+    where
+        Self: Sized,
+    {
+        crate::sink::assert_sink::<(B, A), Error, _>(DatagramSink::new(self))
+    }
+}
+
+impl<A, W: AsyncDatagramWrite<A> + ?Sized> AsyncDatagramWriteExt<A> for W {}
+
 // Just a helper function to ensure the reader we're returning all have the
 // right implementations.
 pub(crate) fn assert_read<R>(reader: R) -> R
@@ -0,0 +1,250 @@
+//! Readers and writers limited to a number of bytes per period of time.
+//!
+//! [`RateLimitedReader`] and [`RateLimitedWriter`] wrap an I/O object and
+//! cap its throughput with a token bucket: the bucket holds up to `burst`
+//! bytes worth of tokens, every byte read or written uses one of them, and
+//! `rate` tokens are put back each time a period ends. After the object has
+//! been idle, up to `burst` bytes can go through at once; over a longer
+//! transfer the throughput settles at `rate` bytes per period.
+//!
+//! Periods are measured by a delay future created by the `delay` closure
+//! passed to the adapter, typically a timer of the runtime in use, such as
+//! `async_std::task::sleep` or `tokio::time::sleep`, and every call should
+//! take the same time. A one-second timer gives a budget of `rate` bytes per
+//! second, while a shorter timer with a proportionally lower `rate` spreads
+//! the same budget more evenly.
+//!
+//! # Examples
+//!
+//! ```
+//! # futures::executor::block_on(async {
+//! use futures::future;
+//! use futures::io::ratelimit::RateLimitedReader;
+//! use futures::io::{AsyncReadExt, Cursor};
+//!
+//! // a timer that never fires, so the budget is never topped up
+//! let mut reader = RateLimitedReader::new(Cursor::new([1u8; 16]), 4, 6, future::pending::<()>);
+//! let mut buffer = [0; 16];
+//!
+//! // the first read can use the whole burst
+//! assert_eq!(reader.read(&mut buffer).await?, 6);
+//! assert_eq!(reader.tokens(), 0);
+//! assert!(futures::poll!(reader.read(&mut buffer)).is_pending());
+//! # Ok::<(), Box<dyn std::error::Error>>(()) }).unwrap();
+//! ```
+
+use futures_core::future::Future;
+use futures_core::ready;
+use futures_core::task::{Context, Poll};
+use futures_io::{AsyncRead, AsyncWrite};
+use pin_project_lite::pin_project;
+use std::fmt;
+use std::io;
+use std::pin::Pin;
+
+pin_project! {
+    struct Bucket<F, Fut> {
+        rate: usize,
+        burst: usize,
+        tokens: usize,
+        delay: F,
+        // Running while the bucket is not full.
+        #[pin]
+        refill: Option<Fut>,
+    }
+}
+
+impl<F, Fut> Bucket<F, Fut>
+where
+    F: FnMut() -> Fut,
+    Fut: Future,
+{
+    fn new(rate: usize, burst: usize, delay: F) -> Self {
+        assert!(rate > 0, "rate must be greater than zero");
+        assert!(burst > 0, "burst must be greater than zero");
+        Self { rate, burst, tokens: burst, delay, refill: None }
+    }
+
+    /// Waits until at least one token is available, and returns how many are.
+    fn poll_tokens(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<usize> {
+        let mut this = self.project();
+        while let Some(refill) = this.refill.as_mut().as_pin_mut() {
+            if refill.poll(cx).is_pending() {
+                break;
+            }
+            *this.tokens = (*this.burst).min(this.tokens.saturating_add(*this.rate));
+            if *this.tokens < *this.burst {
+                this.refill.set(Some((this.delay)()));
+            } else {
+                this.refill.set(None);
+            }
+        }
+
+        if *this.tokens == 0 {
+            return Poll::Pending;
+        }
+        Poll::Ready(*this.tokens)
+    }
+
+    fn consume(self: Pin<&mut Self>, n: usize) {
+        let mut this = self.project();
+        *this.tokens -= n;
+        if n > 0 && this.refill.is_none() {
+            this.refill.set(Some((this.delay)()));
+        }
+    }
+}
+
+pin_project! {
+    /// Reader limited to a number of bytes per period of time.
+    ///
+    /// See the [module documentation](self) for how the limit works.
+    #[must_use = "readers do nothing unless you `.await` or poll them"]
+    pub struct RateLimitedReader<R, F, Fut> {
+        #[pin]
+        inner: R,
+        #[pin]
+        bucket: Bucket<F, Fut>,
+    }
+}
+
+impl<R, F, Fut> RateLimitedReader<R, F, Fut>
+where
+    R: AsyncRead,
+    F: FnMut() -> Fut,
+    Fut: Future,
+{
+    /// Creates a new reader that reads at most `rate` bytes from `inner` per
+    /// period measured by `delay`, and at most `burst` bytes at once.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rate` or `burst` is zero.
+    pub fn new(inner: R, rate: usize, burst: usize, delay: F) -> Self {
+        Self { inner, bucket: Bucket::new(rate, burst, delay) }
+    }
+
+    /// Returns the number of bytes that can currently be read without
+    /// waiting for the rate limit.
+    pub fn tokens(&self) -> usize {
+        self.bucket.tokens
+    }
+
+    delegate_access_inner!(inner, R, ());
+}
+
+impl<R, F, Fut> AsyncRead for RateLimitedReader<R, F, Fut>
+where
+    R: AsyncRead,
+    F: FnMut() -> Fut,
+    Fut: Future,
+{
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        let mut this = self.project();
+        if buf.is_empty() {
+            return this.inner.poll_read(cx, buf);
+        }
+
+        let tokens = ready!(this.bucket.as_mut().poll_tokens(cx));
+        let max = buf.len().min(tokens);
+        let n = ready!(this.inner.poll_read(cx, &mut buf[..max]))?;
+        this.bucket.consume(n);
+        Poll::Ready(Ok(n))
+    }
+}
+
+impl<R: fmt::Debug, F, Fut> fmt::Debug for RateLimitedReader<R, F, Fut> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RateLimitedReader")
+            .field("inner", &self.inner)
+            .field("rate", &self.bucket.rate)
+            .field("burst", &self.bucket.burst)
+            .field("tokens", &self.bucket.tokens)
+            .finish()
+    }
+}
+
+pin_project! {
+    /// Writer limited to a number of bytes per period of time.
+    ///
+    /// See the [module documentation](self) for how the limit works.
+    #[must_use = "writers do nothing unless polled"]
+    pub struct RateLimitedWriter<W, F, Fut> {
+        #[pin]
+        inner: W,
+        #[pin]
+        bucket: Bucket<F, Fut>,
+    }
+}
+
+impl<W, F, Fut> RateLimitedWriter<W, F, Fut>
+where
+    W: AsyncWrite,
+    F: FnMut() -> Fut,
+    Fut: Future,
+{
+    /// Creates a new writer that writes at most `rate` bytes to `inner` per
+    /// period measured by `delay`, and at most `burst` bytes at once.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rate` or `burst` is zero.
+    pub fn new(inner: W, rate: usize, burst: usize, delay: F) -> Self {
+        Self { inner, bucket: Bucket::new(rate, burst, delay) }
+    }
+
+    /// Returns the number of bytes that can currently be written without
+    /// waiting for the rate limit.
+    pub fn tokens(&self) -> usize {
+        self.bucket.tokens
+    }
+
+    delegate_access_inner!(inner, W, ());
+}
+
+impl<W, F, Fut> AsyncWrite for RateLimitedWriter<W, F, Fut>
+where
+    W: AsyncWrite,
+    F: FnMut() -> Fut,
+    Fut: Future,
+{
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        let mut this = self.project();
+        if buf.is_empty() {
+            return this.inner.poll_write(cx, buf);
+        }
+
+        let tokens = ready!(this.bucket.as_mut().poll_tokens(cx));
+        let max = buf.len().min(tokens);
+        let n = ready!(this.inner.poll_write(cx, &buf[..max]))?;
+        this.bucket.consume(n);
+        Poll::Ready(Ok(n))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        self.project().inner.poll_flush(cx)
+    }
+
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        self.project().inner.poll_close(cx)
+    }
+}
+
+impl<W: fmt::Debug, F, Fut> fmt::Debug for RateLimitedWriter<W, F, Fut> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RateLimitedWriter")
+            .field("inner", &self.inner)
+            .field("rate", &self.bucket.rate)
+            .field("burst", &self.bucket.burst)
+            .field("tokens", &self.bucket.tokens)
+            .finish()
+    }
+}
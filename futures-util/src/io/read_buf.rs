@@ -0,0 +1,31 @@
+use crate::io::AsyncRead;
+use futures_core::future::Future;
+use futures_core::task::{Context, Poll};
+use futures_io::ReadBuf;
+use std::io;
+use std::pin::Pin;
+
+/// Future for the [`read_buf`](super::AsyncReadExt::read_buf) method.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct ReadIntoBuf<'a, 'b, R: ?Sized> {
+    reader: &'a mut R,
+    buf: &'a mut ReadBuf<'b>,
+}
+
+impl<R: ?Sized + Unpin> Unpin for ReadIntoBuf<'_, '_, R> {}
+
+impl<'a, 'b, R: AsyncRead + ?Sized + Unpin> ReadIntoBuf<'a, 'b, R> {
+    pub(super) fn new(reader: &'a mut R, buf: &'a mut ReadBuf<'b>) -> Self {
+        Self { reader, buf }
+    }
+}
+
+impl<R: AsyncRead + ?Sized + Unpin> Future for ReadIntoBuf<'_, '_, R> {
+    type Output = io::Result<()>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = &mut *self;
+        Pin::new(&mut this.reader).poll_read_buf(cx, this.buf)
+    }
+}
@@ -0,0 +1,82 @@
+use super::read_until_limited::{read_until_limited_internal, LimitState, Overflow};
+use futures_core::future::Future;
+use futures_core::ready;
+use futures_core::task::{Context, Poll};
+use futures_io::AsyncBufRead;
+use std::io;
+use std::mem;
+use std::pin::Pin;
+use std::str;
+
+/// Future for the [`read_line_limited`](super::AsyncBufReadExt::read_line_limited)
+/// method.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct ReadLineLimited<'a, R: ?Sized> {
+    reader: &'a mut R,
+    buf: &'a mut String,
+    bytes: Vec<u8>,
+    limit: usize,
+    overflow: Overflow,
+    state: LimitState,
+}
+
+impl<R: ?Sized + Unpin> Unpin for ReadLineLimited<'_, R> {}
+
+impl<'a, R: AsyncBufRead + ?Sized + Unpin> ReadLineLimited<'a, R> {
+    pub(super) fn new(
+        reader: &'a mut R,
+        buf: &'a mut String,
+        limit: usize,
+        overflow: Overflow,
+    ) -> Self {
+        Self {
+            reader,
+            bytes: mem::replace(buf, String::new()).into_bytes(),
+            buf,
+            limit,
+            overflow,
+            state: LimitState::default(),
+        }
+    }
+}
+
+impl<R: AsyncBufRead + ?Sized + Unpin> Future for ReadLineLimited<'_, R> {
+    type Output = io::Result<usize>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let Self { reader, buf, bytes, limit, overflow, state } = &mut *self;
+        let ret = ready!(read_until_limited_internal(
+            Pin::new(reader),
+            cx,
+            b'\n',
+            bytes,
+            *limit,
+            *overflow,
+            state
+        ));
+        let state = mem::replace(state, LimitState::default());
+
+        if let Err(e) = str::from_utf8(bytes) {
+            // Truncating may have cut a character in half; drop what is left
+            // of it rather than failing.
+            let truncated = state.read > state.appended;
+            if truncated && e.error_len().is_none() {
+                bytes.truncate(e.valid_up_to());
+            } else {
+                return Poll::Ready(ret.and_then(|()| {
+                    Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        "stream did not contain valid UTF-8",
+                    ))
+                }));
+            }
+        }
+
+        debug_assert!(buf.is_empty());
+        // Safety: `bytes` is a valid UTF-8 because `str::from_utf8` returned `Ok`,
+        // or it has been cut down to the part that is.
+        mem::swap(unsafe { buf.as_mut_vec() }, bytes);
+        Poll::Ready(ret.map(|()| state.read))
+    }
+}
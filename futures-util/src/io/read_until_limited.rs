@@ -0,0 +1,119 @@
+use futures_core::future::Future;
+use futures_core::ready;
+use futures_core::task::{Context, Poll};
+use futures_io::AsyncBufRead;
+use std::io;
+use std::mem;
+use std::pin::Pin;
+
+/// What [`read_until_limited`](super::AsyncBufReadExt::read_until_limited)
+/// and [`read_line_limited`](super::AsyncBufReadExt::read_line_limited) do
+/// once a line is longer than the limit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Overflow {
+    /// Fail with an [`InvalidData`](io::ErrorKind::InvalidData) error.
+    ///
+    /// The first `limit` bytes of the line have been appended to the buffer
+    /// and consumed; the rest of the line is left in the reader.
+    Error,
+    /// Keep the first `limit` bytes of the line, and skip the rest of it up
+    /// to and including the delimiter.
+    Truncate,
+}
+
+// Progress of a limited read that has not completed yet.
+#[derive(Debug, Default)]
+pub(super) struct LimitState {
+    // Bytes consumed from the reader.
+    pub(super) read: usize,
+    // Bytes appended to the buffer.
+    pub(super) appended: usize,
+}
+
+/// Future for the [`read_until_limited`](super::AsyncBufReadExt::read_until_limited)
+/// method.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct ReadUntilLimited<'a, R: ?Sized> {
+    reader: &'a mut R,
+    byte: u8,
+    buf: &'a mut Vec<u8>,
+    limit: usize,
+    overflow: Overflow,
+    state: LimitState,
+}
+
+impl<R: ?Sized + Unpin> Unpin for ReadUntilLimited<'_, R> {}
+
+impl<'a, R: AsyncBufRead + ?Sized + Unpin> ReadUntilLimited<'a, R> {
+    pub(super) fn new(
+        reader: &'a mut R,
+        byte: u8,
+        buf: &'a mut Vec<u8>,
+        limit: usize,
+        overflow: Overflow,
+    ) -> Self {
+        Self { reader, byte, buf, limit, overflow, state: LimitState::default() }
+    }
+}
+
+// Like `read_until_internal`, but appends at most `limit` bytes. On
+// completion the state is left for the caller to inspect and reset.
+pub(super) fn read_until_limited_internal<R: AsyncBufRead + ?Sized>(
+    mut reader: Pin<&mut R>,
+    cx: &mut Context<'_>,
+    byte: u8,
+    buf: &mut Vec<u8>,
+    limit: usize,
+    overflow: Overflow,
+    state: &mut LimitState,
+) -> Poll<io::Result<()>> {
+    loop {
+        let (done, used) = {
+            let available = ready!(reader.as_mut().poll_fill_buf(cx))?;
+            let (done, used) = match memchr::memchr(byte, available) {
+                Some(i) => (true, i + 1),
+                None => (false, available.len()),
+            };
+
+            let room = limit - state.appended;
+            let take = used.min(room);
+            buf.extend_from_slice(&available[..take]);
+            state.appended += take;
+
+            if used > room && overflow == Overflow::Error {
+                reader.as_mut().consume(take);
+                state.read += take;
+                return Poll::Ready(Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "line exceeds the length limit",
+                )));
+            }
+            (done, used)
+        };
+        reader.as_mut().consume(used);
+        state.read += used;
+        if done || used == 0 {
+            return Poll::Ready(Ok(()));
+        }
+    }
+}
+
+impl<R: AsyncBufRead + ?Sized + Unpin> Future for ReadUntilLimited<'_, R> {
+    type Output = io::Result<usize>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let Self { reader, byte, buf, limit, overflow, state } = &mut *self;
+        let ret = ready!(read_until_limited_internal(
+            Pin::new(reader),
+            cx,
+            *byte,
+            buf,
+            *limit,
+            *overflow,
+            state
+        ));
+        let read = mem::replace(state, LimitState::default()).read;
+        Poll::Ready(ret.map(|()| read))
+    }
+}
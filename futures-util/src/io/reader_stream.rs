@@ -0,0 +1,92 @@
+use futures_core::ready;
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Context, Poll};
+use futures_io::AsyncRead;
+use pin_project_lite::pin_project;
+use std::io;
+use std::pin::Pin;
+
+pin_project! {
+    /// A stream of byte chunks read from an [`AsyncRead`].
+    ///
+    /// Each item of the stream is an owned `Vec<u8>` holding at most
+    /// `chunk_size` bytes, as returned by a single call to
+    /// [`poll_read`](AsyncRead::poll_read). The stream ends when the reader
+    /// reaches EOF.
+    ///
+    /// This is the reverse of
+    /// [`TryStreamExt::into_async_read`](crate::stream::TryStreamExt::into_async_read).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::io::{Cursor, ReaderStream};
+    /// use futures::stream::TryStreamExt;
+    ///
+    /// let reader = Cursor::new(vec![1, 2, 3, 4, 5]);
+    /// let chunks: Vec<Vec<u8>> = ReaderStream::new(reader, 2).try_collect().await?;
+    ///
+    /// assert_eq!(chunks, vec![vec![1, 2], vec![3, 4], vec![5]]);
+    /// # Ok::<(), Box<dyn std::error::Error>>(()) }).unwrap();
+    /// ```
+    #[derive(Debug)]
+    #[must_use = "streams do nothing unless polled"]
+    pub struct ReaderStream<R> {
+        #[pin]
+        inner: R,
+        buf: Vec<u8>,
+        chunk_size: usize,
+        done: bool,
+    }
+}
+
+impl<R: AsyncRead> ReaderStream<R> {
+    /// Creates a new `ReaderStream` that reads chunks of at most `chunk_size`
+    /// bytes from `inner`.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `chunk_size` is zero.
+    pub fn new(inner: R, chunk_size: usize) -> Self {
+        assert!(chunk_size > 0, "chunk_size must be greater than zero");
+        Self { inner, buf: Vec::new(), chunk_size, done: false }
+    }
+
+    delegate_access_inner!(inner, R, ());
+}
+
+impl<R: AsyncRead> Stream for ReaderStream<R> {
+    type Item = io::Result<Vec<u8>>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+
+        if *this.done {
+            return Poll::Ready(None);
+        }
+
+        if this.buf.is_empty() {
+            // The buffer is kept across `Poll::Pending` so it only has to be
+            // initialized once per chunk.
+            this.buf.resize(*this.chunk_size, 0);
+        }
+
+        let n = ready!(this.inner.poll_read(cx, this.buf))?;
+        if n == 0 {
+            *this.done = true;
+            this.buf.clear();
+            return Poll::Ready(None);
+        }
+
+        let mut chunk = std::mem::replace(this.buf, Vec::new());
+        chunk.truncate(n);
+        Poll::Ready(Some(Ok(chunk)))
+    }
+}
+
+impl<R: AsyncRead> FusedStream for ReaderStream<R> {
+    fn is_terminated(&self) -> bool {
+        self.done
+    }
+}
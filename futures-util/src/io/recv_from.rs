@@ -0,0 +1,32 @@
+use futures_core::future::Future;
+use futures_core::task::{Context, Poll};
+use futures_io::AsyncDatagramRead;
+use std::io;
+use std::marker::PhantomData;
+use std::pin::Pin;
+
+/// Future for the [`recv_from`](super::AsyncDatagramReadExt::recv_from) method.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct RecvFrom<'a, R: ?Sized, A> {
+    reader: &'a mut R,
+    buf: &'a mut [u8],
+    _addr: PhantomData<fn() -> A>,
+}
+
+impl<R: ?Sized + Unpin, A> Unpin for RecvFrom<'_, R, A> {}
+
+impl<'a, R: AsyncDatagramRead<A> + ?Sized + Unpin, A> RecvFrom<'a, R, A> {
+    pub(super) fn new(reader: &'a mut R, buf: &'a mut [u8]) -> Self {
+        Self { reader, buf, _addr: PhantomData }
+    }
+}
+
+impl<R: AsyncDatagramRead<A> + ?Sized + Unpin, A> Future for RecvFrom<'_, R, A> {
+    type Output = io::Result<(usize, A)>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = &mut *self;
+        Pin::new(&mut this.reader).poll_recv_from(cx, this.buf)
+    }
+}
@@ -28,3 +28,27 @@ impl<S: AsyncSeek + ?Sized + Unpin> Future for Seek<'_, S> {
         Pin::new(&mut this.seek).poll_seek(cx, this.pos)
     }
 }
+
+/// Future for the [`seek_relative`](crate::io::AsyncSeekExt::seek_relative) and
+/// [`BufReader::seek_relative`](crate::io::BufReader::seek_relative) methods.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct SeekRelative<'a, S: ?Sized> {
+    seek: Pin<&'a mut S>,
+    offset: i64,
+}
+
+impl<'a, S: AsyncSeek + ?Sized> SeekRelative<'a, S> {
+    pub(super) fn new(seek: Pin<&'a mut S>, offset: i64) -> Self {
+        Self { seek, offset }
+    }
+}
+
+impl<S: AsyncSeek + ?Sized> Future for SeekRelative<'_, S> {
+    type Output = io::Result<()>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let offset = self.offset;
+        self.seek.as_mut().poll_seek_relative(cx, offset)
+    }
+}
@@ -0,0 +1,289 @@
+use futures_core::task::{Context, Poll};
+use futures_io::{AsyncBufRead, AsyncRead, AsyncSeek, AsyncWrite, SeekFrom};
+use std::collections::VecDeque;
+use std::io;
+use std::pin::Pin;
+
+/// A buffer made up of a sequence of contiguous byte segments, which a
+/// [`SegmentedCursor`] can read from.
+///
+/// This is implemented for [`VecDeque<u8>`](VecDeque), whose bytes are
+/// stored in up to two segments, and for slices and vectors of byte buffers
+/// like `Vec<Vec<u8>>`. Rope-like buffer types can implement it to be read
+/// without copying them into a contiguous buffer first.
+pub trait Segments {
+    /// Returns the number of segments.
+    fn segment_count(&self) -> usize;
+
+    /// Returns the segment at `index`, which is less than the number of
+    /// segments.
+    fn segment(&self, index: usize) -> &[u8];
+}
+
+impl Segments for VecDeque<u8> {
+    fn segment_count(&self) -> usize {
+        2
+    }
+
+    fn segment(&self, index: usize) -> &[u8] {
+        let (front, back) = self.as_slices();
+        if index == 0 {
+            front
+        } else {
+            back
+        }
+    }
+}
+
+impl<S: AsRef<[u8]>> Segments for [S] {
+    fn segment_count(&self) -> usize {
+        self.len()
+    }
+
+    fn segment(&self, index: usize) -> &[u8] {
+        self[index].as_ref()
+    }
+}
+
+impl<S: AsRef<[u8]>> Segments for Vec<S> {
+    fn segment_count(&self) -> usize {
+        self.len()
+    }
+
+    fn segment(&self, index: usize) -> &[u8] {
+        self[index].as_ref()
+    }
+}
+
+impl<T: Segments + ?Sized> Segments for &T {
+    fn segment_count(&self) -> usize {
+        (**self).segment_count()
+    }
+
+    fn segment(&self, index: usize) -> &[u8] {
+        (**self).segment(index)
+    }
+}
+
+impl<T: Segments + ?Sized> Segments for &mut T {
+    fn segment_count(&self) -> usize {
+        (**self).segment_count()
+    }
+
+    fn segment(&self, index: usize) -> &[u8] {
+        (**self).segment(index)
+    }
+}
+
+/// A cursor over a non-contiguous in-memory buffer, providing it with
+/// [`AsyncRead`], [`AsyncBufRead`] and [`AsyncSeek`] implementations.
+///
+/// This is the counterpart of [`Cursor`](super::Cursor) for buffers
+/// implementing [`Segments`], such as a `VecDeque<u8>` that data is
+/// accumulated into, which can then be parsed in place. Reads copy from
+/// as many segments as needed, and
+/// [`poll_fill_buf`](AsyncBufRead::poll_fill_buf) returns the rest of the
+/// current segment.
+///
+/// `SegmentedCursor<VecDeque<u8>>` also implements [`AsyncWrite`], which,
+/// like writing to a `Cursor<Vec<u8>>`, overwrites the bytes at the current
+/// position and extends the buffer past its end.
+///
+/// # Examples
+///
+/// ```
+/// # futures::executor::block_on(async {
+/// use futures::io::{AsyncReadExt, SegmentedCursor};
+///
+/// let segments = vec![&b"hello"[..], b" ", b"world"];
+/// let mut cursor = SegmentedCursor::new(segments);
+///
+/// let mut buf = [0; 7];
+/// cursor.read_exact(&mut buf).await?;
+/// assert_eq!(&buf, b"hello w");
+/// assert_eq!(cursor.position(), 7);
+/// # Ok::<(), Box<dyn std::error::Error>>(()) }).unwrap();
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct SegmentedCursor<T> {
+    inner: T,
+    pos: u64,
+    // The segment containing `pos`, and the offset of `pos` in it.
+    index: usize,
+    offset: usize,
+    // `index` and `offset` need to be recomputed from `pos`.
+    stale: bool,
+}
+
+impl<T> SegmentedCursor<T> {
+    /// Creates a new cursor wrapping the provided segmented buffer, starting
+    /// at position `0`.
+    pub fn new(inner: T) -> Self {
+        Self { inner, pos: 0, index: 0, offset: 0, stale: true }
+    }
+
+    /// Consumes this cursor, returning the underlying value.
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+
+    /// Gets a reference to the underlying value in this cursor.
+    pub fn get_ref(&self) -> &T {
+        &self.inner
+    }
+
+    /// Gets a mutable reference to the underlying value in this cursor.
+    ///
+    /// The position of the cursor is kept, even if the segments change.
+    pub fn get_mut(&mut self) -> &mut T {
+        self.stale = true;
+        &mut self.inner
+    }
+
+    /// Returns the current position of this cursor.
+    pub fn position(&self) -> u64 {
+        self.pos
+    }
+
+    /// Sets the position of this cursor.
+    pub fn set_position(&mut self, pos: u64) {
+        self.pos = pos;
+        self.stale = true;
+    }
+}
+
+impl<T: Segments> SegmentedCursor<T> {
+    fn len(&self) -> u64 {
+        (0..self.inner.segment_count()).map(|i| self.inner.segment(i).len() as u64).sum()
+    }
+
+    // Returns the rest of the current segment, moving on to the next
+    // non-empty one if needed. This is empty at the end of the buffer.
+    fn remaining_segment(&mut self) -> &[u8] {
+        if self.stale {
+            self.stale = false;
+            self.index = 0;
+            let mut start = 0;
+            while self.index < self.inner.segment_count() {
+                let len = self.inner.segment(self.index).len() as u64;
+                if self.pos < start + len {
+                    break;
+                }
+                start += len;
+                self.index += 1;
+            }
+            // This is 0 when the position is past the end.
+            self.offset = self.pos.saturating_sub(start).min(usize::max_value() as u64) as usize;
+        }
+
+        while self.index < self.inner.segment_count() {
+            if self.offset < self.inner.segment(self.index).len() {
+                return &self.inner.segment(self.index)[self.offset..];
+            }
+            self.index += 1;
+            self.offset = 0;
+        }
+        &[]
+    }
+
+    fn advance(&mut self, amt: usize) {
+        self.offset += amt;
+        self.pos += amt as u64;
+    }
+}
+
+impl<T: Segments + Unpin> AsyncSeek for SegmentedCursor<T> {
+    fn poll_seek(
+        mut self: Pin<&mut Self>,
+        _: &mut Context<'_>,
+        pos: SeekFrom,
+    ) -> Poll<io::Result<u64>> {
+        let (base, offset) = match pos {
+            SeekFrom::Start(n) => {
+                self.set_position(n);
+                return Poll::Ready(Ok(n));
+            }
+            SeekFrom::End(n) => (self.len(), n),
+            SeekFrom::Current(n) => (self.pos, n),
+        };
+        let new_pos = if offset >= 0 {
+            base.checked_add(offset as u64)
+        } else {
+            base.checked_sub(offset.wrapping_neg() as u64)
+        };
+        match new_pos {
+            Some(n) => {
+                self.set_position(n);
+                Poll::Ready(Ok(n))
+            }
+            None => Poll::Ready(Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "invalid seek to a negative or overflowing position",
+            ))),
+        }
+    }
+}
+
+impl<T: Segments + Unpin> AsyncRead for SegmentedCursor<T> {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        _: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        let mut n = 0;
+        while n < buf.len() {
+            let rem = self.remaining_segment();
+            if rem.is_empty() {
+                break;
+            }
+            let amt = rem.len().min(buf.len() - n);
+            buf[n..n + amt].copy_from_slice(&rem[..amt]);
+            self.advance(amt);
+            n += amt;
+        }
+        Poll::Ready(Ok(n))
+    }
+}
+
+impl<T: Segments + Unpin> AsyncBufRead for SegmentedCursor<T> {
+    fn poll_fill_buf(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<io::Result<&[u8]>> {
+        Poll::Ready(Ok(self.get_mut().remaining_segment()))
+    }
+
+    fn consume(mut self: Pin<&mut Self>, amt: usize) {
+        let amt = amt.min(self.remaining_segment().len());
+        self.advance(amt);
+    }
+}
+
+impl AsyncWrite for SegmentedCursor<VecDeque<u8>> {
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        _: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        let this = &mut *self;
+        let pos = this.pos as usize;
+        if pos > this.inner.len() {
+            this.inner.resize(pos, 0);
+        }
+
+        let overwrite = buf.len().min(this.inner.len() - pos);
+        for (i, &byte) in buf[..overwrite].iter().enumerate() {
+            this.inner[pos + i] = byte;
+        }
+        this.inner.extend(&buf[overwrite..]);
+
+        this.pos += buf.len() as u64;
+        this.stale = true;
+        Poll::Ready(Ok(buf.len()))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_close(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+}
@@ -0,0 +1,31 @@
+use futures_core::future::Future;
+use futures_core::task::{Context, Poll};
+use futures_io::AsyncDatagramWrite;
+use std::io;
+use std::pin::Pin;
+
+/// Future for the [`send_to`](super::AsyncDatagramWriteExt::send_to) method.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct SendTo<'a, W: ?Sized, A> {
+    writer: &'a mut W,
+    buf: &'a [u8],
+    target: &'a A,
+}
+
+impl<W: ?Sized + Unpin, A> Unpin for SendTo<'_, W, A> {}
+
+impl<'a, W: AsyncDatagramWrite<A> + ?Sized + Unpin, A> SendTo<'a, W, A> {
+    pub(super) fn new(writer: &'a mut W, buf: &'a [u8], target: &'a A) -> Self {
+        Self { writer, buf, target }
+    }
+}
+
+impl<W: AsyncDatagramWrite<A> + ?Sized + Unpin, A> Future for SendTo<'_, W, A> {
+    type Output = io::Result<usize>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = &mut *self;
+        Pin::new(&mut this.writer).poll_send_to(cx, this.buf, this.target)
+    }
+}
@@ -13,6 +13,10 @@ pub struct ReadHalf<T> {
 }
 
 /// The writable half of an object returned from `AsyncRead::split`.
+///
+/// Closing the `WriteHalf` closes the underlying object with
+/// [`poll_close`](AsyncWrite::poll_close), which shuts down its write side
+/// while the `ReadHalf` can go on reading.
 #[derive(Debug)]
 pub struct WriteHalf<T> {
     handle: BiLock<T>,
@@ -31,6 +35,22 @@ pub(super) fn split<T: AsyncRead + AsyncWrite>(t: T) -> (ReadHalf<T>, WriteHalf<
     (ReadHalf { handle: a }, WriteHalf { handle: b })
 }
 
+impl<T> ReadHalf<T> {
+    /// Returns `true` if this `ReadHalf` and `other` were created by the same
+    /// call to `AsyncReadExt::split`, and can therefore be `reunite`d.
+    pub fn is_pair_of(&self, other: &WriteHalf<T>) -> bool {
+        self.handle.is_pair_of(&other.handle)
+    }
+}
+
+impl<T> WriteHalf<T> {
+    /// Returns `true` if this `WriteHalf` and `other` were created by the same
+    /// call to `AsyncReadExt::split`, and can therefore be `reunite`d.
+    pub fn is_pair_of(&self, other: &ReadHalf<T>) -> bool {
+        self.handle.is_pair_of(&other.handle)
+    }
+}
+
 impl<T: Unpin> ReadHalf<T> {
     /// Attempts to put the two "halves" of a split `AsyncRead + AsyncWrite` back
     /// together. Succeeds only if the `ReadHalf<T>` and `WriteHalf<T>` are
@@ -97,17 +117,28 @@ impl<W: AsyncWrite> AsyncWrite for WriteHalf<W> {
 
 /// Error indicating a `ReadHalf<T>` and `WriteHalf<T>` were not two halves
 /// of a `AsyncRead + AsyncWrite`, and thus could not be `reunite`d.
+///
+/// Its `Debug` and `Display` output identify the split each half came from,
+/// so mixed up halves can be traced back.
 pub struct ReuniteError<T>(pub ReadHalf<T>, pub WriteHalf<T>);
 
 impl<T> fmt::Debug for ReuniteError<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_tuple("ReuniteError").field(&"...").finish()
+        f.debug_struct("ReuniteError")
+            .field("read_half", &self.0.handle.pair_id())
+            .field("write_half", &self.1.handle.pair_id())
+            .finish()
     }
 }
 
 impl<T> fmt::Display for ReuniteError<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "tried to reunite a ReadHalf and WriteHalf that don't form a pair")
+        write!(
+            f,
+            "tried to reunite a ReadHalf (of split {:p}) and WriteHalf (of split {:p}) that don't form a pair",
+            self.0.handle.pair_id(),
+            self.1.handle.pair_id(),
+        )
     }
 }
 
@@ -0,0 +1,116 @@
+use futures_core::ready;
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Context, Poll};
+use futures_io::AsyncBufRead;
+use pin_project_lite::pin_project;
+use std::io;
+use std::mem;
+use std::pin::Pin;
+
+pin_project! {
+    /// Stream for the [`split_by`](super::AsyncBufReadExt::split_by) method.
+    #[derive(Debug)]
+    #[must_use = "streams do nothing unless polled"]
+    pub struct SplitBy<R> {
+        #[pin]
+        reader: R,
+        delimiter: Vec<u8>,
+        max_len: usize,
+        buf: Vec<u8>,
+        done: bool,
+    }
+}
+
+impl<R: AsyncBufRead> SplitBy<R> {
+    pub(super) fn new(reader: R, delimiter: Vec<u8>, max_len: usize) -> Self {
+        assert!(!delimiter.is_empty(), "delimiter must not be empty");
+        Self { reader, delimiter, max_len, buf: Vec::new(), done: false }
+    }
+
+    delegate_access_inner!(reader, R, ());
+}
+
+// Returns the position of the first occurrence of `needle` in `haystack`.
+fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    let mut offset = 0;
+    while let Some(i) = memchr::memchr(needle[0], &haystack[offset..]) {
+        let start = offset + i;
+        if haystack[start..].starts_with(needle) {
+            return Some(start);
+        }
+        offset = start + 1;
+    }
+    None
+}
+
+fn too_long_error() -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, "segment exceeds the maximum length")
+}
+
+impl<R: AsyncBufRead> Stream for SplitBy<R> {
+    type Item = io::Result<Vec<u8>>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+        if *this.done {
+            return Poll::Ready(None);
+        }
+
+        loop {
+            let available = match ready!(this.reader.as_mut().poll_fill_buf(cx)) {
+                Ok(available) => available,
+                Err(e) => {
+                    *this.done = true;
+                    return Poll::Ready(Some(Err(e)));
+                }
+            };
+            if available.is_empty() {
+                *this.done = true;
+                if this.buf.is_empty() {
+                    return Poll::Ready(None);
+                }
+                if this.buf.len() > *this.max_len {
+                    this.buf.clear();
+                    return Poll::Ready(Some(Err(too_long_error())));
+                }
+                return Poll::Ready(Some(Ok(mem::replace(this.buf, Vec::new()))));
+            }
+
+            // The delimiter may start in the bytes that were buffered before.
+            let old_len = this.buf.len();
+            let search_from = old_len.saturating_sub(this.delimiter.len() - 1);
+            this.buf.extend_from_slice(available);
+
+            let found = find(&this.buf[search_from..], this.delimiter).map(|i| search_from + i);
+            let too_long = match found {
+                Some(end) => end > *this.max_len,
+                // Even if the delimiter starts with the last bytes, the
+                // segment before it is too long.
+                None => this.buf.len() >= this.max_len.saturating_add(this.delimiter.len()),
+            };
+            if too_long {
+                *this.done = true;
+                this.buf.clear();
+                return Poll::Ready(Some(Err(too_long_error())));
+            }
+
+            match found {
+                Some(end) => {
+                    this.reader.as_mut().consume(end + this.delimiter.len() - old_len);
+                    this.buf.truncate(end);
+                    return Poll::Ready(Some(Ok(mem::replace(this.buf, Vec::new()))));
+                }
+                None => {
+                    let used = this.buf.len() - old_len;
+                    this.reader.as_mut().consume(used);
+                }
+            }
+        }
+    }
+}
+
+impl<R: AsyncBufRead> FusedStream for SplitBy<R> {
+    fn is_terminated(&self) -> bool {
+        self.done
+    }
+}
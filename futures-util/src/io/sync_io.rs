@@ -0,0 +1,170 @@
+use crate::task::{waker_ref, ArcWake};
+use futures_core::task::{Context, Poll};
+use futures_io::{AsyncRead, AsyncSeek, AsyncWrite, IoSlice, IoSliceMut, SeekFrom};
+use std::io;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread::{self, Thread};
+
+// Wakes up the thread blocked in `block_on_poll`.
+struct ThreadNotify {
+    thread: Thread,
+    unparked: AtomicBool,
+}
+
+impl ArcWake for ThreadNotify {
+    fn wake_by_ref(arc_self: &Arc<Self>) {
+        // Only unpark if the thread has not been woken up yet, to avoid
+        // unparking it for unrelated code.
+        if !arc_self.unparked.swap(true, Ordering::Release) {
+            arc_self.thread.unpark();
+        }
+    }
+}
+
+thread_local! {
+    static CURRENT_THREAD_NOTIFY: Arc<ThreadNotify> = Arc::new(ThreadNotify {
+        thread: thread::current(),
+        unparked: AtomicBool::new(false),
+    });
+}
+
+// Polls `f` until it is ready, parking the current thread in between.
+fn block_on_poll<T, F>(mut f: F) -> T
+where
+    F: FnMut(&mut Context<'_>) -> Poll<T>,
+{
+    CURRENT_THREAD_NOTIFY.with(|thread_notify| {
+        let waker = waker_ref(thread_notify);
+        let mut cx = Context::from_waker(&waker);
+        loop {
+            if let Poll::Ready(t) = f(&mut cx) {
+                return t;
+            }
+            while !thread_notify.unparked.swap(false, Ordering::Acquire) {
+                thread::park();
+            }
+        }
+    })
+}
+
+/// Reader for the [`into_sync_read`](super::AsyncReadExt::into_sync_read)
+/// method.
+///
+/// This implements [`std::io::Read`] by blocking the current thread until
+/// the underlying `AsyncRead` is ready, and [`std::io::Seek`] in the same way
+/// if it implements [`AsyncSeek`]. It must not be used from a task run by an
+/// executor, since blocking the thread of the executor stops it from running
+/// other tasks, and may deadlock it.
+#[derive(Debug)]
+pub struct SyncReader<R> {
+    inner: R,
+}
+
+impl<R: AsyncRead + Unpin> SyncReader<R> {
+    pub(super) fn new(inner: R) -> Self {
+        Self { inner }
+    }
+
+    /// Gets a reference to the underlying reader.
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Gets a mutable reference to the underlying reader.
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    /// Consumes this adapter, returning the underlying reader.
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+impl<R: AsyncRead + Unpin> io::Read for SyncReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let inner = &mut self.inner;
+        block_on_poll(|cx| Pin::new(&mut *inner).poll_read(cx, buf))
+    }
+
+    fn read_vectored(&mut self, bufs: &mut [IoSliceMut<'_>]) -> io::Result<usize> {
+        let inner = &mut self.inner;
+        block_on_poll(|cx| Pin::new(&mut *inner).poll_read_vectored(cx, bufs))
+    }
+}
+
+impl<R: AsyncRead + AsyncSeek + Unpin> io::Seek for SyncReader<R> {
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        let inner = &mut self.inner;
+        block_on_poll(|cx| Pin::new(&mut *inner).poll_seek(cx, pos))
+    }
+}
+
+/// Writer for the [`into_sync_write`](super::AsyncWriteExt::into_sync_write)
+/// method.
+///
+/// This implements [`std::io::Write`] by blocking the current thread until
+/// the underlying `AsyncWrite` is ready, and [`std::io::Seek`] in the same
+/// way if it implements [`AsyncSeek`]. It must not be used from a task run
+/// by an executor, since blocking the thread of the executor stops it from
+/// running other tasks, and may deadlock it.
+///
+/// Dropping the writer does not close the underlying writer; use
+/// [`close`](SyncWriter::close) for that.
+#[derive(Debug)]
+pub struct SyncWriter<W> {
+    inner: W,
+}
+
+impl<W: AsyncWrite + Unpin> SyncWriter<W> {
+    pub(super) fn new(inner: W) -> Self {
+        Self { inner }
+    }
+
+    /// Gets a reference to the underlying writer.
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Gets a mutable reference to the underlying writer.
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    /// Consumes this adapter, returning the underlying writer.
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+
+    /// Closes the underlying writer, blocking until it is closed.
+    pub fn close(&mut self) -> io::Result<()> {
+        let inner = &mut self.inner;
+        block_on_poll(|cx| Pin::new(&mut *inner).poll_close(cx))
+    }
+}
+
+impl<W: AsyncWrite + Unpin> io::Write for SyncWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let inner = &mut self.inner;
+        block_on_poll(|cx| Pin::new(&mut *inner).poll_write(cx, buf))
+    }
+
+    fn write_vectored(&mut self, bufs: &[IoSlice<'_>]) -> io::Result<usize> {
+        let inner = &mut self.inner;
+        block_on_poll(|cx| Pin::new(&mut *inner).poll_write_vectored(cx, bufs))
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        let inner = &mut self.inner;
+        block_on_poll(|cx| Pin::new(&mut *inner).poll_flush(cx))
+    }
+}
+
+impl<W: AsyncWrite + AsyncSeek + Unpin> io::Seek for SyncWriter<W> {
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        let inner = &mut self.inner;
+        block_on_poll(|cx| Pin::new(&mut *inner).poll_seek(cx, pos))
+    }
+}
@@ -1,6 +1,6 @@
 use futures_core::ready;
 use futures_core::task::{Context, Poll};
-use futures_io::{AsyncBufRead, AsyncRead};
+use futures_io::{AsyncBufRead, AsyncRead, ReadBuf};
 use pin_project_lite::pin_project;
 use std::pin::Pin;
 use std::{cmp, io};
@@ -98,6 +98,36 @@ impl<R: AsyncRead> AsyncRead for Take<R> {
         *this.limit -= n as u64;
         Poll::Ready(Ok(n))
     }
+
+    fn poll_read_buf(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        let this = self.project();
+
+        if *this.limit == 0 {
+            return Poll::Ready(Ok(()));
+        }
+
+        let max = cmp::min(buf.remaining() as u64, *this.limit) as usize;
+        let mut limited = buf.take(max);
+        let ptr = limited.filled().as_ptr();
+        ready!(this.inner.poll_read_buf(cx, &mut limited))?;
+
+        // Ensure the inner reader didn't replace `limited` with another buffer.
+        assert_eq!(ptr, limited.filled().as_ptr(), "ReadBuf swapped");
+        let n = limited.filled().len();
+        let initialized = limited.initialized().len();
+
+        // Safety: as checked above, `limited` is still the buffer over the
+        // start of the unfilled part of `buf`, so the bytes it reports as
+        // initialized are initialized in `buf` too.
+        unsafe { buf.assume_init(initialized) };
+        buf.advance(n);
+        *this.limit -= n as u64;
+        Poll::Ready(Ok(()))
+    }
 }
 
 impl<R: AsyncBufRead> AsyncBufRead for Take<R> {
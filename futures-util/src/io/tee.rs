@@ -0,0 +1,134 @@
+use futures_core::ready;
+use futures_core::task::{Context, Poll};
+use futures_io::AsyncWrite;
+use pin_project_lite::pin_project;
+use std::fmt;
+use std::io;
+use std::pin::Pin;
+
+pin_project! {
+    /// Writer for the [`tee`](super::AsyncWriteExt::tee) method.
+    ///
+    /// Every byte accepted by the first writer is also written to the second
+    /// one. The bytes the second writer has not taken yet are kept in a small
+    /// buffer, so at most one chunk is held back at a time, and the writer can
+    /// only go as fast as the slower of the two.
+    #[must_use = "writers do nothing unless polled"]
+    pub struct Tee<W1, W2> {
+        #[pin]
+        writer1: W1,
+        #[pin]
+        writer2: W2,
+        // Bytes written to `writer1` but not yet to `writer2`.
+        lag: Vec<u8>,
+    }
+}
+
+impl<W1, W2> Tee<W1, W2>
+where
+    W1: AsyncWrite,
+    W2: AsyncWrite,
+{
+    pub(super) fn new(writer1: W1, writer2: W2) -> Self {
+        Self { writer1, writer2, lag: Vec::new() }
+    }
+
+    /// Get a shared reference to the inner writers.
+    pub fn get_ref(&self) -> (&W1, &W2) {
+        (&self.writer1, &self.writer2)
+    }
+
+    /// Get a mutable reference to the inner writers.
+    pub fn get_mut(&mut self) -> (&mut W1, &mut W2) {
+        (&mut self.writer1, &mut self.writer2)
+    }
+
+    /// Get a pinned mutable reference to the inner writers.
+    pub fn get_pin_mut(self: Pin<&mut Self>) -> (Pin<&mut W1>, Pin<&mut W2>) {
+        let this = self.project();
+        (this.writer1, this.writer2)
+    }
+
+    /// Consumes this combinator, returning the underlying writers.
+    ///
+    /// Note that bytes the second writer has not received yet are lost, so
+    /// the `Tee` should be flushed first.
+    pub fn into_inner(self) -> (W1, W2) {
+        (self.writer1, self.writer2)
+    }
+
+    fn poll_catch_up(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        let mut this = self.project();
+        while !this.lag.is_empty() {
+            let n = ready!(this.writer2.as_mut().poll_write(cx, this.lag))?;
+            if n == 0 {
+                return Poll::Ready(Err(io::ErrorKind::WriteZero.into()));
+            }
+            this.lag.drain(..n);
+        }
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl<W1: fmt::Debug, W2: fmt::Debug> fmt::Debug for Tee<W1, W2> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Tee")
+            .field("writer1", &self.writer1)
+            .field("writer2", &self.writer2)
+            .field("lag", &self.lag.len())
+            .finish()
+    }
+}
+
+impl<W1, W2> AsyncWrite for Tee<W1, W2>
+where
+    W1: AsyncWrite,
+    W2: AsyncWrite,
+{
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        ready!(self.as_mut().poll_catch_up(cx))?;
+
+        let mut this = self.project();
+        let n = ready!(this.writer1.poll_write(cx, buf))?;
+
+        // The first writer has taken `n` bytes, so they have to be reported as
+        // written. Whatever the second writer does not take right away, even
+        // because of an error, is retried on the next call.
+        let written = match this.writer2.as_mut().poll_write(cx, &buf[..n]) {
+            Poll::Ready(Ok(m)) => m,
+            Poll::Ready(Err(_)) | Poll::Pending => 0,
+        };
+        this.lag.extend_from_slice(&buf[written..n]);
+        Poll::Ready(Ok(n))
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        ready!(self.as_mut().poll_catch_up(cx))?;
+
+        let this = self.project();
+        let writer1_ready = this.writer1.poll_flush(cx)?.is_ready();
+        let writer2_ready = this.writer2.poll_flush(cx)?.is_ready();
+        if writer1_ready && writer2_ready {
+            Poll::Ready(Ok(()))
+        } else {
+            Poll::Pending
+        }
+    }
+
+    fn poll_close(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        ready!(self.as_mut().poll_catch_up(cx))?;
+
+        let this = self.project();
+        let writer1_ready = this.writer1.poll_close(cx)?.is_ready();
+        let writer2_ready = this.writer2.poll_close(cx)?.is_ready();
+        if writer1_ready && writer2_ready {
+            Poll::Ready(Ok(()))
+        } else {
+            Poll::Pending
+        }
+    }
+}
@@ -0,0 +1,186 @@
+use futures_core::ready;
+use futures_core::task::{Context, Poll};
+use futures_io::{AsyncBufRead, AsyncRead, AsyncWrite, IoSlice};
+use pin_project_lite::pin_project;
+use std::io;
+use std::pin::Pin;
+use tokio_1::io::{
+    AsyncBufRead as TokioAsyncBufRead, AsyncRead as TokioAsyncRead, AsyncWrite as TokioAsyncWrite,
+    ReadBuf,
+};
+
+pin_project! {
+    /// Adapter between the I/O traits of this library and those of tokio, in
+    /// both directions.
+    ///
+    /// Wrapping a [`futures_io`] reader or writer makes it implement
+    /// `tokio::io::AsyncRead`, `AsyncBufRead` or `AsyncWrite`, and wrapping a
+    /// tokio one makes it implement [`AsyncRead`], [`AsyncBufRead`] or
+    /// [`AsyncWrite`]. This lets libraries written against futures-io run on
+    /// tokio transports, and the other way around. Closing the adapter shuts
+    /// tokio writers down, and shutting it down closes futures-io writers.
+    ///
+    /// Created by the [`tokio_compat`](super::TokioCompatExt::tokio_compat)
+    /// method, or [`TokioCompat::new`].
+    ///
+    /// This type is only available when the `tokio-compat` feature of this
+    /// library is activated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::io::{AsyncReadExt, Cursor, TokioCompatExt};
+    ///
+    /// // A futures-io reader, made a tokio reader, and then back.
+    /// let reader = Cursor::new(b"Hello World!").tokio_compat();
+    /// let mut reader = reader.tokio_compat();
+    ///
+    /// let mut output = Vec::new();
+    /// reader.read_to_end(&mut output).await?;
+    /// assert_eq!(output, b"Hello World!");
+    /// # Ok::<(), Box<dyn std::error::Error>>(()) }).unwrap();
+    /// ```
+    #[derive(Debug)]
+    pub struct TokioCompat<T> {
+        #[pin]
+        inner: T,
+    }
+}
+
+impl<T> TokioCompat<T> {
+    /// Wraps a reader or writer of either library.
+    pub fn new(inner: T) -> Self {
+        Self { inner }
+    }
+
+    /// Gets a reference to the underlying I/O object.
+    pub fn get_ref(&self) -> &T {
+        &self.inner
+    }
+
+    /// Gets a mutable reference to the underlying I/O object.
+    pub fn get_mut(&mut self) -> &mut T {
+        &mut self.inner
+    }
+
+    /// Gets a pinned mutable reference to the underlying I/O object.
+    pub fn get_pin_mut(self: Pin<&mut Self>) -> Pin<&mut T> {
+        self.project().inner
+    }
+
+    /// Consumes this adapter, returning the underlying I/O object.
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+}
+
+/// Extension trait wrapping readers and writers in a [`TokioCompat`].
+///
+/// This trait is only available when the `tokio-compat` feature of this
+/// library is activated.
+pub trait TokioCompatExt: Sized {
+    /// Wraps a reader or writer of this library to implement the traits of
+    /// tokio, or a tokio one to implement the traits of this library.
+    fn tokio_compat(self) -> TokioCompat<Self> {
+        TokioCompat::new(self)
+    }
+}
+
+impl<T> TokioCompatExt for T {}
+
+impl<R: AsyncRead> TokioAsyncRead for TokioCompat<R> {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        let n = ready!(self.project().inner.poll_read(cx, buf.initialize_unfilled()))?;
+        buf.advance(n);
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl<R: TokioAsyncRead> AsyncRead for TokioCompat<R> {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        let mut buf = ReadBuf::new(buf);
+        ready!(self.project().inner.poll_read(cx, &mut buf))?;
+        Poll::Ready(Ok(buf.filled().len()))
+    }
+}
+
+impl<R: AsyncBufRead> TokioAsyncBufRead for TokioCompat<R> {
+    fn poll_fill_buf(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<&[u8]>> {
+        self.project().inner.poll_fill_buf(cx)
+    }
+
+    fn consume(self: Pin<&mut Self>, amt: usize) {
+        self.project().inner.consume(amt)
+    }
+}
+
+impl<R: TokioAsyncBufRead> AsyncBufRead for TokioCompat<R> {
+    fn poll_fill_buf(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<&[u8]>> {
+        self.project().inner.poll_fill_buf(cx)
+    }
+
+    fn consume(self: Pin<&mut Self>, amt: usize) {
+        self.project().inner.consume(amt)
+    }
+}
+
+impl<W: AsyncWrite> TokioAsyncWrite for TokioCompat<W> {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        self.project().inner.poll_write(cx, buf)
+    }
+
+    fn poll_write_vectored(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        bufs: &[IoSlice<'_>],
+    ) -> Poll<io::Result<usize>> {
+        self.project().inner.poll_write_vectored(cx, bufs)
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        self.project().inner.poll_flush(cx)
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        self.project().inner.poll_close(cx)
+    }
+}
+
+impl<W: TokioAsyncWrite> AsyncWrite for TokioCompat<W> {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        self.project().inner.poll_write(cx, buf)
+    }
+
+    fn poll_write_vectored(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        bufs: &[IoSlice<'_>],
+    ) -> Poll<io::Result<usize>> {
+        self.project().inner.poll_write_vectored(cx, bufs)
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        self.project().inner.poll_flush(cx)
+    }
+
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        self.project().inner.poll_shutdown(cx)
+    }
+}
@@ -2,6 +2,8 @@
 //! and the `AsyncRead` and `AsyncWrite` traits.
 
 #![cfg_attr(feature = "write-all-vectored", feature(io_slice_advance))]
+#![cfg_attr(feature = "async-iterator", feature(async_iterator))]
+#![cfg_attr(feature = "context-ext", feature(context_ext, local_waker))]
 #![cfg_attr(not(feature = "std"), no_std)]
 #![warn(
     missing_debug_implementations,
@@ -48,6 +50,9 @@ pub mod __private {
         result::Result::{Err, Ok},
     };
 
+    #[cfg(feature = "alloc")]
+    pub use alloc::vec::Vec;
+
     pub mod async_await {
         pub use crate::async_await::*;
     }
@@ -328,9 +333,31 @@ pub use crate::io::{
 #[cfg(feature = "alloc")]
 pub mod lock;
 
+pub mod time;
+
+#[cfg(feature = "instrument")]
+#[cfg_attr(docsrs, doc(cfg(feature = "instrument")))]
+pub mod instrument;
+
 #[cfg(not(futures_no_atomic_cas))]
 #[cfg(feature = "alloc")]
 mod abortable;
 
+// On targets without atomic CAS, `Arc` and the atomic types come from the
+// `portable-atomic` crates when the `portable-atomic` feature is enabled.
+#[cfg(not(futures_no_atomic_cas))]
+#[cfg(feature = "alloc")]
+mod sync {
+    #[cfg(not(futures_portable_atomic))]
+    pub(crate) use alloc::sync::{Arc, Weak};
+    #[cfg(not(futures_portable_atomic))]
+    pub(crate) use core::sync::atomic;
+
+    #[cfg(futures_portable_atomic)]
+    pub(crate) use portable_atomic as atomic;
+    #[cfg(futures_portable_atomic)]
+    pub(crate) use portable_atomic_util::{Arc, Weak};
+}
+
 mod fns;
 mod unfold_state;
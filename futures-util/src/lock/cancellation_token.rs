@@ -0,0 +1,275 @@
+use super::waiter_list::{WaiterList, WaiterNode};
+use futures_core::future::{FusedFuture, Future};
+use futures_core::task::{Context, Poll, Waker};
+use pin_project_lite::pin_project;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex as StdMutex, Weak};
+use std::{fmt, mem};
+
+/// A token signalling cancellation to the tasks holding it, and to the tokens
+/// derived from it.
+///
+/// The tasks of a shutdown tree each hold a clone of a token, or a
+/// [child token](CancellationToken::child_token) derived from it, and stop
+/// once it is [`cancel`](CancellationToken::cancel)led. Cancelling a token
+/// cancels its clones and its children, recursively, but not its parent.
+///
+/// Dropping a token does not cancel it.
+///
+/// # Examples
+///
+/// ```
+/// # futures::executor::block_on(async {
+/// use futures::lock::CancellationToken;
+///
+/// let token = CancellationToken::new();
+/// let child = token.child_token();
+///
+/// token.cancel();
+/// child.cancelled().await;
+/// assert!(child.is_cancelled());
+/// # });
+/// ```
+#[derive(Clone)]
+pub struct CancellationToken {
+    node: Arc<Node>,
+}
+
+struct Node {
+    cancelled: AtomicBool,
+    state: StdMutex<State>,
+}
+
+struct State {
+    children: Vec<Weak<Node>>,
+    // The wakers of the waiting futures. Only the `Some` ones are linked
+    // into the list.
+    waiters: WaiterList<Option<Waker>>,
+}
+
+impl Node {
+    fn new(cancelled: bool) -> Self {
+        Self {
+            cancelled: AtomicBool::new(cancelled),
+            state: StdMutex::new(State { children: Vec::new(), waiters: WaiterList::new() }),
+        }
+    }
+
+    fn cancel(&self) {
+        if self.cancelled.swap(true, Ordering::AcqRel) {
+            return;
+        }
+        let children = {
+            let mut state = self.state.lock().unwrap();
+            while let Some(waiter) = state.waiters.pop_front() {
+                if let Some(waker) = waiter.take() {
+                    waker.wake();
+                }
+            }
+            mem::replace(&mut state.children, Vec::new())
+        };
+        for child in children {
+            if let Some(child) = child.upgrade() {
+                child.cancel();
+            }
+        }
+    }
+}
+
+impl CancellationToken {
+    /// Creates a new token, which is not cancelled.
+    pub fn new() -> Self {
+        Self { node: Arc::new(Node::new(false)) }
+    }
+
+    /// Creates a child token, which is cancelled when this token is, but can
+    /// also be cancelled on its own without affecting this token.
+    ///
+    /// If this token is already cancelled, so is the child.
+    pub fn child_token(&self) -> Self {
+        let mut state = self.node.state.lock().unwrap();
+        if self.is_cancelled() {
+            return Self { node: Arc::new(Node::new(true)) };
+        }
+        let child = Arc::new(Node::new(false));
+        // Forget about the children which were dropped before growing.
+        if state.children.len() == state.children.capacity() {
+            state.children.retain(|child| child.upgrade().is_some());
+        }
+        state.children.push(Arc::downgrade(&child));
+        Self { node: child }
+    }
+
+    /// Cancels this token, its clones and its children, waking the tasks
+    /// waiting for it.
+    pub fn cancel(&self) {
+        self.node.cancel();
+    }
+
+    /// Returns whether this token was cancelled.
+    pub fn is_cancelled(&self) -> bool {
+        self.node.cancelled.load(Ordering::Acquire)
+    }
+
+    /// Returns a future which resolves once this token is cancelled.
+    pub fn cancelled(&self) -> WaitForCancellationFuture<'_> {
+        WaitForCancellationFuture {
+            token: Some(self),
+            waiter: WaiterNode::new(None),
+            is_waiting: false,
+        }
+    }
+
+    /// Runs the given future until it completes, or until this token is
+    /// cancelled, in which case the future is dropped without being polled
+    /// again.
+    ///
+    /// The returned future resolves to `None` if the token was cancelled
+    /// first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::future;
+    /// use futures::lock::CancellationToken;
+    ///
+    /// let token = CancellationToken::new();
+    /// assert_eq!(token.run_until_cancelled(async { 1 }).await, Some(1));
+    ///
+    /// token.cancel();
+    /// assert_eq!(token.run_until_cancelled(future::pending::<()>()).await, None);
+    /// # });
+    /// ```
+    pub fn run_until_cancelled<Fut: Future>(&self, future: Fut) -> RunUntilCancelled<'_, Fut> {
+        RunUntilCancelled { cancelled: self.cancelled(), future: Some(future) }
+    }
+}
+
+impl Default for CancellationToken {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for CancellationToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CancellationToken").field("is_cancelled", &self.is_cancelled()).finish()
+    }
+}
+
+/// A future which resolves when a token was cancelled, returned by
+/// [`CancellationToken::cancelled`].
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct WaitForCancellationFuture<'a> {
+    // `None` indicates that the cancellation was observed.
+    token: Option<&'a CancellationToken>,
+    // Linked into the waiters of the token while waiting, which is why this
+    // future is `!Unpin`.
+    waiter: WaiterNode<Option<Waker>>,
+    // Whether the waiter may be linked.
+    is_waiting: bool,
+}
+
+impl fmt::Debug for WaitForCancellationFuture<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WaitForCancellationFuture").field("token", &self.token).finish()
+    }
+}
+
+impl FusedFuture for WaitForCancellationFuture<'_> {
+    fn is_terminated(&self) -> bool {
+        self.token.is_none()
+    }
+}
+
+impl Future for WaitForCancellationFuture<'_> {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        // Safety: the waiter is never moved out of the future.
+        let this = unsafe { self.get_unchecked_mut() };
+        let token = this.token.expect("polled WaitForCancellationFuture after completion");
+        if !token.is_cancelled() {
+            let mut state = token.node.state.lock().unwrap();
+            // Check again now that `cancel` can't miss our waker.
+            if !token.is_cancelled() {
+                let waiter = unsafe { state.waiters.get(&this.waiter) };
+                match waiter {
+                    Some(waker) => {
+                        if !waker.will_wake(cx.waker()) {
+                            *waker = cx.waker().clone();
+                        }
+                    }
+                    None => {
+                        *waiter = Some(cx.waker().clone());
+                        // Safety: the waiter is pinned, and removed from the
+                        // list before the future is dropped.
+                        unsafe { state.waiters.push_back(Pin::new_unchecked(&this.waiter)) };
+                        this.is_waiting = true;
+                    }
+                }
+                return Poll::Pending;
+            }
+        }
+        this.remove_waiter();
+        this.token = None;
+        Poll::Ready(())
+    }
+}
+
+impl WaitForCancellationFuture<'_> {
+    fn remove_waiter(&mut self) {
+        if let Some(token) = self.token {
+            if self.is_waiting {
+                unsafe { token.node.state.lock().unwrap().waiters.remove(&self.waiter) };
+                self.is_waiting = false;
+            }
+        }
+    }
+}
+
+impl Drop for WaitForCancellationFuture<'_> {
+    fn drop(&mut self) {
+        self.remove_waiter();
+    }
+}
+
+pin_project! {
+    /// Future for the [`CancellationToken::run_until_cancelled`] method.
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    #[derive(Debug)]
+    pub struct RunUntilCancelled<'a, Fut> {
+        #[pin]
+        cancelled: WaitForCancellationFuture<'a>,
+        #[pin]
+        future: Option<Fut>,
+    }
+}
+
+impl<Fut: Future> FusedFuture for RunUntilCancelled<'_, Fut> {
+    fn is_terminated(&self) -> bool {
+        self.future.is_none()
+    }
+}
+
+impl<Fut: Future> Future for RunUntilCancelled<'_, Fut> {
+    type Output = Option<Fut::Output>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut this = self.project();
+        let future =
+            this.future.as_mut().as_pin_mut().expect("RunUntilCancelled polled after completion");
+
+        // Check for cancellation first, so the future isn't polled anymore
+        // once the token is cancelled.
+        if this.cancelled.poll(cx).is_ready() {
+            this.future.set(None);
+            return Poll::Ready(None);
+        }
+        let output = futures_core::ready!(future.poll(cx));
+        this.future.set(None);
+        Poll::Ready(Some(output))
+    }
+}
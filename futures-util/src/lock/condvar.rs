@@ -0,0 +1,212 @@
+use super::waiter_list::{WaiterList, WaiterNode};
+use super::{Mutex, MutexGuard, MutexLockFuture};
+use futures_core::future::{FusedFuture, Future};
+use futures_core::task::{Context, Poll, Waker};
+use std::fmt;
+use std::mem;
+use std::pin::Pin;
+use std::sync::Mutex as StdMutex;
+
+/// A futures-aware condition variable, used together with a [`Mutex`].
+///
+/// A task waiting on a condition variable releases the lock of the mutex
+/// until it is notified, and acquires it again before resuming, so that the
+/// state protected by the mutex can be checked and waited on as with
+/// `std::sync::Condvar`.
+///
+/// As with `std::sync::Condvar`, a task may resume without being notified,
+/// so the condition waited for should be checked again in a loop.
+///
+/// # Examples
+///
+/// ```
+/// # futures::executor::block_on(async {
+/// use futures::future;
+/// use futures::lock::{Condvar, Mutex};
+///
+/// let ready = Mutex::new(false);
+/// let condvar = Condvar::new();
+///
+/// let wait = async {
+///     let mut guard = ready.lock().await;
+///     while !*guard {
+///         guard = condvar.wait(guard).await;
+///     }
+/// };
+/// let notify = async {
+///     *ready.lock().await = true;
+///     condvar.notify_one();
+/// };
+/// future::join(wait, notify).await;
+/// # });
+/// ```
+pub struct Condvar {
+    waiters: StdMutex<WaiterList<Waiter>>,
+}
+
+// The state of a `CondvarWaitFuture`. Only the `Waiting` ones are linked into
+// the list of waiters, in the order they started waiting.
+enum Waiter {
+    Idle,
+    Waiting(Waker),
+    // Unlinked by `notify_one`.
+    One,
+    // Unlinked by `notify_all`.
+    All,
+}
+
+impl Waiter {
+    fn notify(&mut self, notification: Self) {
+        if let Self::Waiting(waker) = mem::replace(self, notification) {
+            waker.wake();
+        }
+    }
+}
+
+fn notify_one(waiters: &mut WaiterList<Waiter>) {
+    if let Some(waiter) = waiters.pop_front() {
+        waiter.notify(Waiter::One);
+    }
+}
+
+impl Condvar {
+    /// Creates a new condition variable.
+    pub fn new() -> Self {
+        Self { waiters: StdMutex::new(WaiterList::new()) }
+    }
+
+    /// Releases the lock held by `guard` and waits for a notification, then
+    /// acquires the lock again.
+    ///
+    /// The lock is released when the returned future is first polled, once
+    /// this task is registered as waiting, so that a notification sent after
+    /// the lock was released cannot be missed.
+    pub fn wait<'a, T: ?Sized>(&'a self, guard: MutexGuard<'a, T>) -> CondvarWaitFuture<'a, T> {
+        CondvarWaitFuture {
+            condvar: self,
+            mutex: guard.mutex(),
+            guard: Some(guard),
+            waiter: WaiterNode::new(Waiter::Idle),
+            is_waiting: false,
+            lock: None,
+        }
+    }
+
+    /// Notifies the first task waiting on this condition variable.
+    ///
+    /// The notification is lost if no task is waiting.
+    pub fn notify_one(&self) {
+        notify_one(&mut self.waiters.lock().unwrap());
+    }
+
+    /// Notifies all the tasks waiting on this condition variable.
+    pub fn notify_all(&self) {
+        let mut waiters = self.waiters.lock().unwrap();
+        while let Some(waiter) = waiters.pop_front() {
+            waiter.notify(Waiter::All);
+        }
+    }
+}
+
+impl Default for Condvar {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for Condvar {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let waiters = self.waiters.lock().unwrap();
+        f.debug_struct("Condvar").field("has_waiters", &!waiters.is_empty()).finish()
+    }
+}
+
+/// A future which resolves once the task was notified and acquired the lock
+/// of the mutex again, returned by [`Condvar::wait`].
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct CondvarWaitFuture<'a, T: ?Sized> {
+    condvar: &'a Condvar,
+    mutex: &'a Mutex<T>,
+    // `Some` until the future is first polled.
+    guard: Option<MutexGuard<'a, T>>,
+    // Linked into the waiters of the condition variable while waiting, which
+    // is why this future is `!Unpin`.
+    waiter: WaiterNode<Waiter>,
+    // Whether the waiter may be in another state than `Waiter::Idle`.
+    is_waiting: bool,
+    // `Some` while acquiring the lock again.
+    lock: Option<MutexLockFuture<'a, T>>,
+}
+
+impl<T: ?Sized> fmt::Debug for CondvarWaitFuture<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CondvarWaitFuture")
+            .field("was_notified", &(self.guard.is_none() && !self.is_waiting))
+            .field("mutex", &self.mutex)
+            .finish()
+    }
+}
+
+impl<T: ?Sized> FusedFuture for CondvarWaitFuture<'_, T> {
+    fn is_terminated(&self) -> bool {
+        self.guard.is_none() && !self.is_waiting && self.lock.is_none()
+    }
+}
+
+impl<'a, T: ?Sized> Future for CondvarWaitFuture<'a, T> {
+    type Output = MutexGuard<'a, T>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        // Safety: the waiter and the lock future are never moved out of this
+        // future.
+        let this = unsafe { self.get_unchecked_mut() };
+        if let Some(guard) = this.guard.take() {
+            let mut waiters = this.condvar.waiters.lock().unwrap();
+            *unsafe { waiters.get(&this.waiter) } = Waiter::Waiting(cx.waker().clone());
+            // Safety: the waiter is pinned, and removed from the list before
+            // the future is dropped.
+            unsafe { waiters.push_back(Pin::new_unchecked(&this.waiter)) };
+            this.is_waiting = true;
+            drop(waiters);
+            drop(guard);
+            return Poll::Pending;
+        }
+
+        if this.is_waiting {
+            let mut waiters = this.condvar.waiters.lock().unwrap();
+            let waiter = unsafe { waiters.get(&this.waiter) };
+            if let Waiter::Waiting(waker) = waiter {
+                if !waker.will_wake(cx.waker()) {
+                    *waker = cx.waker().clone();
+                }
+                return Poll::Pending;
+            }
+            *waiter = Waiter::Idle;
+            drop(waiters);
+            this.is_waiting = false;
+            this.lock = Some(this.mutex.lock());
+        }
+
+        let lock = this.lock.as_mut().expect("polled CondvarWaitFuture after completion");
+        let guard = futures_core::ready!(unsafe { Pin::new_unchecked(lock) }.poll(cx));
+        this.lock = None;
+        Poll::Ready(guard)
+    }
+}
+
+impl<T: ?Sized> Drop for CondvarWaitFuture<'_, T> {
+    fn drop(&mut self) {
+        if self.is_waiting {
+            let mut waiters = self.condvar.waiters.lock().unwrap();
+            match mem::replace(unsafe { waiters.get(&self.waiter) }, Waiter::Idle) {
+                Waiter::Waiting(_) => {
+                    unsafe { waiters.remove(&self.waiter) };
+                }
+                // Pass on the notification this future was dropped before
+                // receiving.
+                Waiter::One => notify_one(&mut waiters),
+                Waiter::Idle | Waiter::All => {}
+            }
+        }
+    }
+}
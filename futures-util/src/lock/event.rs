@@ -0,0 +1,176 @@
+use super::waiter_list::{WaiterList, WaiterNode};
+use futures_core::future::{FusedFuture, Future};
+use futures_core::task::{Context, Poll, Waker};
+use std::fmt;
+use std::mem;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex as StdMutex;
+
+/// A futures-aware manual-reset event.
+///
+/// Once [`set`](Event::set), an event wakes all the tasks waiting on it, and
+/// lets the tasks waiting afterwards through immediately, until it is
+/// [`reset`](Event::reset). This suits gating tasks until some initialization
+/// is done, which a oneshot channel can only signal to a single task.
+///
+/// # Examples
+///
+/// ```
+/// # futures::executor::block_on(async {
+/// use futures::future;
+/// use futures::lock::Event;
+///
+/// let initialized = Event::new();
+///
+/// let wait = async {
+///     initialized.wait().await;
+///     // ...
+/// };
+/// let initialize = async {
+///     // ...
+///     initialized.set();
+/// };
+/// future::join(wait, initialize).await;
+///
+/// // The event lets waiting tasks through until it is reset.
+/// initialized.wait().await;
+/// # });
+/// ```
+pub struct Event {
+    is_set: AtomicBool,
+    waiters: StdMutex<WaiterList<Waiter>>,
+}
+
+// The state of an `EventWaitFuture`. Only the `Waiting` ones are linked into
+// the list of waiters.
+enum Waiter {
+    Idle,
+    Waiting(Waker),
+    // Unlinked by `Event::set`, so that the future completes even if the
+    // event is reset before it is polled.
+    Set,
+}
+
+impl Event {
+    /// Creates a new event, which isn't set.
+    pub fn new() -> Self {
+        Self { is_set: AtomicBool::new(false), waiters: StdMutex::new(WaiterList::new()) }
+    }
+
+    /// Returns whether the event is set.
+    pub fn is_set(&self) -> bool {
+        self.is_set.load(Ordering::Acquire)
+    }
+
+    /// Sets the event, waking all the tasks waiting on it.
+    ///
+    /// The tasks waiting on the event afterwards complete immediately, until
+    /// the event is reset.
+    pub fn set(&self) {
+        self.is_set.store(true, Ordering::Release);
+        let mut waiters = self.waiters.lock().unwrap();
+        while let Some(waiter) = waiters.pop_front() {
+            if let Waiter::Waiting(waker) = mem::replace(waiter, Waiter::Set) {
+                waker.wake();
+            }
+        }
+    }
+
+    /// Resets the event, so that the tasks waiting on it afterwards wait for
+    /// it to be set again.
+    ///
+    /// The tasks woken by an earlier call to [`set`](Event::set) still
+    /// complete.
+    pub fn reset(&self) {
+        self.is_set.store(false, Ordering::Release);
+    }
+
+    /// Returns a future which resolves once the event is set.
+    pub fn wait(&self) -> EventWaitFuture<'_> {
+        EventWaitFuture { event: Some(self), waiter: WaiterNode::new(Waiter::Idle) }
+    }
+}
+
+impl Default for Event {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for Event {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Event").field("is_set", &self.is_set()).finish()
+    }
+}
+
+/// A future which resolves once the event is set, returned by
+/// [`Event::wait`].
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct EventWaitFuture<'a> {
+    // `None` indicates that the event was set.
+    event: Option<&'a Event>,
+    // Linked into the waiters of the event while waiting, which is why this
+    // future is `!Unpin`.
+    waiter: WaiterNode<Waiter>,
+}
+
+impl fmt::Debug for EventWaitFuture<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EventWaitFuture").field("event", &self.event).finish()
+    }
+}
+
+impl FusedFuture for EventWaitFuture<'_> {
+    fn is_terminated(&self) -> bool {
+        self.event.is_none()
+    }
+}
+
+impl Future for EventWaitFuture<'_> {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        // Safety: the waiter is never moved out of the future.
+        let this = unsafe { self.get_unchecked_mut() };
+        let event = this.event.expect("polled EventWaitFuture after completion");
+
+        let mut waiters = event.waiters.lock().unwrap();
+        let waiter = unsafe { waiters.get(&this.waiter) };
+        match waiter {
+            Waiter::Idle => {
+                if event.is_set() {
+                    drop(waiters);
+                    this.event = None;
+                    return Poll::Ready(());
+                }
+                *waiter = Waiter::Waiting(cx.waker().clone());
+                // Safety: the waiter is pinned, and removed from the list
+                // before the future is dropped.
+                unsafe { waiters.push_back(Pin::new_unchecked(&this.waiter)) };
+                Poll::Pending
+            }
+            Waiter::Waiting(waker) => {
+                if !waker.will_wake(cx.waker()) {
+                    *waker = cx.waker().clone();
+                }
+                Poll::Pending
+            }
+            Waiter::Set => {
+                *waiter = Waiter::Idle;
+                drop(waiters);
+                this.event = None;
+                Poll::Ready(())
+            }
+        }
+    }
+}
+
+impl Drop for EventWaitFuture<'_> {
+    fn drop(&mut self) {
+        if let Some(event) = self.event {
+            let mut waiters = event.waiters.lock().unwrap();
+            unsafe { waiters.remove(&self.waiter) };
+        }
+    }
+}
@@ -1,25 +1,56 @@
+use super::waiter_list::{WaiterList, WaiterNode};
+use crate::task::ArcWake;
 use futures_core::future::{FusedFuture, Future};
 use futures_core::task::{Context, Poll, Waker};
-use slab::Slab;
+use pin_project_lite::pin_project;
 use std::cell::UnsafeCell;
 use std::marker::PhantomData;
 use std::ops::{Deref, DerefMut};
 use std::pin::Pin;
-use std::sync::atomic::{AtomicUsize, Ordering};
-use std::sync::Mutex as StdMutex;
-use std::{fmt, mem};
+use std::sync::{Arc, LockResult, PoisonError};
+use std::{fmt, mem, thread};
+
+#[cfg(all(test, loom))]
+use loom::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Mutex as StdMutex,
+};
+#[cfg(not(all(test, loom)))]
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Mutex as StdMutex,
+};
 
 /// A futures-aware mutex.
 ///
+/// Waiting for the lock doesn't allocate: the state of a waiting task is kept
+/// in its [`MutexLockFuture`], which is why that future must be pinned.
+///
 /// # Fairness
 ///
-/// This mutex provides no fairness guarantees. Tasks may not acquire the mutex
-/// in the order that they requested the lock, and it's possible for a single task
-/// which repeatedly takes the lock to starve other tasks, which may be left waiting
-/// indefinitely.
+/// A mutex created by [`new`](Mutex::new) provides no fairness guarantees.
+/// Tasks may not acquire the mutex in the order that they requested the lock,
+/// and it's possible for a single task which repeatedly takes the lock to
+/// starve other tasks, which may be left waiting indefinitely.
+///
+/// A mutex created by [`new_fair`](Mutex::new_fair) instead hands the lock
+/// over to the waiting tasks in the order that they requested it, so that
+/// tasks requesting it later cannot take it before them. This prevents
+/// starvation at the cost of throughput under contention, as the lock stays
+/// unavailable until the woken task is polled.
+///
+/// # Poisoning
+///
+/// A mutex is poisoned when a task panics while holding its lock, as the data
+/// it protects may have been left in an inconsistent state. The
+/// [`lock`](Mutex::lock) and [`try_lock`](Mutex::try_lock) methods ignore
+/// poisoning, while [`lock_checked`](Mutex::lock_checked) lets the caller
+/// detect it and recover the data, until the poison is cleared with
+/// [`clear_poison`](Mutex::clear_poison).
 pub struct Mutex<T: ?Sized> {
     state: AtomicUsize,
-    waiters: StdMutex<Slab<Waiter>>,
+    waiters: StdMutex<WaiterList<Waiter>>,
+    fair: bool,
     value: UnsafeCell<T>,
 }
 
@@ -29,6 +60,7 @@ impl<T: ?Sized> fmt::Debug for Mutex<T> {
         f.debug_struct("Mutex")
             .field("is_locked", &((state & IS_LOCKED) != 0))
             .field("has_waiters", &((state & HAS_WAITERS) != 0))
+            .field("is_poisoned", &((state & IS_POISONED) != 0))
             .finish()
     }
 }
@@ -45,36 +77,74 @@ impl<T: Default> Default for Mutex<T> {
     }
 }
 
+// The state of a `MutexLockFuture`. Only the `Waiting` ones are linked into
+// the list of waiters, in the order they started waiting.
 enum Waiter {
+    Idle,
     Waiting(Waker),
+    // Unlinked by an unlocking task to try acquiring the lock again.
     Woken,
+    // The lock was handed over to this waiter by a fair mutex.
+    Acquired,
 }
 
 impl Waiter {
-    fn register(&mut self, waker: &Waker) {
-        match self {
-            Self::Waiting(w) if waker.will_wake(w) => {}
-            _ => *self = Self::Waiting(waker.clone()),
+    fn wake(&mut self) {
+        if let Self::Waiting(waker) = mem::replace(self, Self::Woken) {
+            waker.wake();
         }
     }
 
-    fn wake(&mut self) {
-        match mem::replace(self, Self::Woken) {
-            Self::Waiting(waker) => waker.wake(),
-            Self::Woken => {}
+    fn hand_over(&mut self) {
+        if let Self::Waiting(waker) = mem::replace(self, Self::Acquired) {
+            waker.wake();
         }
     }
 }
 
 const IS_LOCKED: usize = 1 << 0;
 const HAS_WAITERS: usize = 1 << 1;
+const IS_POISONED: usize = 1 << 2;
 
 impl<T> Mutex<T> {
     /// Creates a new futures-aware mutex.
     pub fn new(t: T) -> Self {
+        Self::with_fairness(t, false)
+    }
+
+    /// Creates a new futures-aware mutex, handing the lock over to the waiting
+    /// tasks in the order that they requested it.
+    ///
+    /// See the [fairness](Mutex#fairness) section for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures::lock::Mutex;
+    /// use futures::task::{noop_waker_ref, Context};
+    /// use futures::FutureExt;
+    ///
+    /// let mutex = Mutex::new_fair(0);
+    /// let guard = mutex.try_lock().unwrap();
+    ///
+    /// let mut cx = Context::from_waker(noop_waker_ref());
+    /// let mut waiter = Box::pin(mutex.lock());
+    /// assert!(waiter.poll_unpin(&mut cx).is_pending());
+    ///
+    /// // The lock is handed over to the waiting task.
+    /// drop(guard);
+    /// assert!(mutex.try_lock().is_none());
+    /// assert!(waiter.poll_unpin(&mut cx).is_ready());
+    /// ```
+    pub fn new_fair(t: T) -> Self {
+        Self::with_fairness(t, true)
+    }
+
+    fn with_fairness(t: T, fair: bool) -> Self {
         Self {
             state: AtomicUsize::new(0),
-            waiters: StdMutex::new(Slab::new()),
+            waiters: StdMutex::new(WaiterList::new()),
+            fair,
             value: UnsafeCell::new(t),
         }
     }
@@ -99,20 +169,117 @@ impl<T: ?Sized> Mutex<T> {
     ///
     /// If the lock is currently held, this will return `None`.
     pub fn try_lock(&self) -> Option<MutexGuard<'_, T>> {
-        let old_state = self.state.fetch_or(IS_LOCKED, Ordering::Acquire);
-        if (old_state & IS_LOCKED) == 0 {
-            Some(MutexGuard { mutex: self })
+        if self.try_lock_raw() {
+            Some(MutexGuard::new(self))
         } else {
             None
         }
     }
 
+    fn try_lock_raw(&self) -> bool {
+        let old_state = self.state.fetch_or(IS_LOCKED, Ordering::Acquire);
+        (old_state & IS_LOCKED) == 0
+    }
+
     /// Acquire the lock asynchronously.
     ///
     /// This method returns a future that will resolve once the lock has been
     /// successfully acquired.
     pub fn lock(&self) -> MutexLockFuture<'_, T> {
-        MutexLockFuture { mutex: Some(self), wait_key: WAIT_KEY_NONE }
+        MutexLockFuture {
+            mutex: Some(self),
+            waiter: WaiterNode::new(Waiter::Idle),
+            is_waiting: false,
+        }
+    }
+
+    /// Acquire the lock asynchronously, checking whether the mutex is
+    /// poisoned.
+    ///
+    /// This method returns a future that will resolve once the lock has been
+    /// successfully acquired, to an error holding the guard if a task
+    /// panicked while holding the lock before. See the
+    /// [poisoning](Mutex#poisoning) section for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::lock::Mutex;
+    /// use std::panic::{self, AssertUnwindSafe};
+    ///
+    /// let mutex = Mutex::new(0);
+    /// let _ = panic::catch_unwind(AssertUnwindSafe(|| {
+    ///     let _guard = mutex.try_lock().unwrap();
+    ///     panic!();
+    /// }));
+    ///
+    /// let guard = mutex.lock_checked().await.unwrap_err().into_inner();
+    /// assert_eq!(*guard, 0);
+    /// drop(guard);
+    ///
+    /// mutex.clear_poison();
+    /// assert!(mutex.lock_checked().await.is_ok());
+    /// # });
+    /// ```
+    pub fn lock_checked(&self) -> MutexLockCheckedFuture<'_, T> {
+        MutexLockCheckedFuture { inner: self.lock() }
+    }
+
+    /// Returns whether the mutex is poisoned.
+    pub fn is_poisoned(&self) -> bool {
+        (self.state.load(Ordering::Acquire) & IS_POISONED) != 0
+    }
+
+    /// Clears the poisoned state of the mutex, once the data it protects was
+    /// found or made consistent again.
+    pub fn clear_poison(&self) {
+        self.state.fetch_and(!IS_POISONED, Ordering::Release);
+    }
+
+    /// Acquire the lock, blocking the current thread until it is able to do
+    /// so.
+    ///
+    /// This lets synchronous code, such as `Drop` implementations or FFI
+    /// callbacks, briefly access the protected data without running an
+    /// executor.
+    ///
+    /// This method must not be called from within an asynchronous context:
+    /// it blocks the executor's thread, and deadlocks if the lock is held by
+    /// a task running on that thread.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures::lock::Mutex;
+    ///
+    /// let mutex = Mutex::new(0);
+    /// *mutex.blocking_lock() += 1;
+    /// assert_eq!(*mutex.blocking_lock(), 1);
+    /// ```
+    pub fn blocking_lock(&self) -> MutexGuard<'_, T> {
+        if let Some(guard) = self.try_lock() {
+            return guard;
+        }
+
+        struct ThreadWaker(thread::Thread);
+
+        impl ArcWake for ThreadWaker {
+            fn wake_by_ref(arc_self: &Arc<Self>) {
+                arc_self.0.unpark();
+            }
+        }
+
+        let waker = crate::task::waker(Arc::new(ThreadWaker(thread::current())));
+        let mut cx = Context::from_waker(&waker);
+        let future = self.lock();
+        pin_utils::pin_mut!(future);
+        loop {
+            if let Poll::Ready(guard) = future.as_mut().poll(&mut cx) {
+                return guard;
+            }
+            thread::park();
+        }
     }
 
     /// Returns a mutable reference to the underlying data.
@@ -137,49 +304,94 @@ impl<T: ?Sized> Mutex<T> {
         unsafe { &mut *self.value.get() }
     }
 
-    fn remove_waker(&self, wait_key: usize, wake_another: bool) {
-        if wait_key != WAIT_KEY_NONE {
-            let mut waiters = self.waiters.lock().unwrap();
-            match waiters.remove(wait_key) {
-                Waiter::Waiting(_) => {}
-                Waiter::Woken => {
-                    // We were awoken, but then dropped before we could
-                    // wake up to acquire the lock. Wake up another
-                    // waiter.
-                    if wake_another {
-                        if let Some((_i, waiter)) = waiters.iter_mut().next() {
-                            waiter.wake();
-                        }
-                    }
+    // Removes the waiter of a `MutexLockFuture` which took the lock, which
+    // may still be linked if it acquired the lock while waiting.
+    fn remove_acquired_waiter(&self, node: &WaiterNode<Waiter>) {
+        let mut waiters = self.waiters.lock().unwrap();
+        *unsafe { waiters.get(node) } = Waiter::Idle;
+        unsafe { waiters.remove(node) };
+        if waiters.is_empty() {
+            self.state.fetch_and(!HAS_WAITERS, Ordering::Relaxed); // released by mutex unlock
+        }
+    }
+
+    // Removes the waiter of a `MutexLockFuture` dropped before it took the
+    // lock, waking up another waiter if it had been awoken to acquire the lock.
+    fn remove_dropped_waiter(&self, node: &WaiterNode<Waiter>) {
+        let mut waiters = self.waiters.lock().unwrap();
+        match mem::replace(unsafe { waiters.get(node) }, Waiter::Idle) {
+            Waiter::Idle => {}
+            Waiter::Waiting(_) => {
+                unsafe { waiters.remove(node) };
+            }
+            Waiter::Woken => {
+                // We were awoken, but then dropped before we could
+                // wake up to acquire the lock. Wake up another
+                // waiter.
+                if let Some(waiter) = waiters.pop_front() {
+                    waiter.wake();
                 }
             }
-            if waiters.is_empty() {
-                self.state.fetch_and(!HAS_WAITERS, Ordering::Relaxed); // released by mutex unlock
+            Waiter::Acquired => {
+                // The lock was handed over to us, but we were dropped
+                // before we could take it. Hand it over to the next
+                // waiter.
+                self.hand_over(&mut waiters);
+            }
+        }
+        if waiters.is_empty() {
+            self.state.fetch_and(!HAS_WAITERS, Ordering::Relaxed); // released by mutex unlock
+        }
+    }
+
+    // Hands the lock over to the first waiter of a fair mutex, or unlocks it
+    // if none is waiting.
+    fn hand_over(&self, waiters: &mut WaiterList<Waiter>) {
+        match waiters.pop_front() {
+            Some(waiter) => waiter.hand_over(),
+            None => {
+                self.state.fetch_and(!IS_LOCKED, Ordering::AcqRel);
             }
         }
     }
 
     // Unlocks the mutex. Called by MutexGuard and MappedMutexGuard when they are
-    // dropped.
-    fn unlock(&self) {
+    // dropped, with whether the thread was already panicking when the lock was
+    // acquired.
+    fn unlock(&self, was_panicking: bool) {
+        if !was_panicking && thread::panicking() {
+            self.state.fetch_or(IS_POISONED, Ordering::Relaxed); // released by mutex unlock
+        }
+        if self.fair {
+            let mut waiters = self.waiters.lock().unwrap();
+            self.hand_over(&mut waiters);
+            if waiters.is_empty() {
+                self.state.fetch_and(!HAS_WAITERS, Ordering::Relaxed); // released by mutex unlock
+            }
+            return;
+        }
         let old_state = self.state.fetch_and(!IS_LOCKED, Ordering::AcqRel);
         if (old_state & HAS_WAITERS) != 0 {
             let mut waiters = self.waiters.lock().unwrap();
-            if let Some((_i, waiter)) = waiters.iter_mut().next() {
+            if let Some(waiter) = waiters.pop_front() {
                 waiter.wake();
             }
+            if waiters.is_empty() {
+                self.state.fetch_and(!HAS_WAITERS, Ordering::Relaxed); // released by mutex unlock
+            }
         }
     }
 }
 
-// Sentinel for when no slot in the `Slab` has been dedicated to this object.
-const WAIT_KEY_NONE: usize = usize::max_value();
-
 /// A future which resolves when the target mutex has been successfully acquired.
 pub struct MutexLockFuture<'a, T: ?Sized> {
     // `None` indicates that the mutex was successfully acquired.
     mutex: Option<&'a Mutex<T>>,
-    wait_key: usize,
+    // Linked into the waiters of the mutex while waiting, which is why this
+    // future is `!Unpin`.
+    waiter: WaiterNode<Waiter>,
+    // Whether the waiter may be in another state than `Waiter::Idle`.
+    is_waiting: bool,
 }
 
 impl<T: ?Sized> fmt::Debug for MutexLockFuture<'_, T> {
@@ -187,10 +399,7 @@ impl<T: ?Sized> fmt::Debug for MutexLockFuture<'_, T> {
         f.debug_struct("MutexLockFuture")
             .field("was_acquired", &self.mutex.is_none())
             .field("mutex", &self.mutex)
-            .field(
-                "wait_key",
-                &(if self.wait_key == WAIT_KEY_NONE { None } else { Some(self.wait_key) }),
-            )
+            .field("is_waiting", &self.is_waiting)
             .finish()
     }
 }
@@ -201,36 +410,73 @@ impl<T: ?Sized> FusedFuture for MutexLockFuture<'_, T> {
     }
 }
 
+impl<'a, T: ?Sized> MutexLockFuture<'a, T> {
+    fn acquired(&mut self, mutex: &'a Mutex<T>) -> MutexGuard<'a, T> {
+        if self.is_waiting {
+            mutex.remove_acquired_waiter(&self.waiter);
+            self.is_waiting = false;
+        }
+        self.mutex = None;
+        MutexGuard::new(mutex)
+    }
+}
+
 impl<'a, T: ?Sized> Future for MutexLockFuture<'a, T> {
     type Output = MutexGuard<'a, T>;
 
-    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
-        let mutex = self.mutex.expect("polled MutexLockFuture after completion");
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        // Safety: the waiter is never moved out of the future.
+        let this = unsafe { self.get_unchecked_mut() };
+        let mutex = this.mutex.expect("polled MutexLockFuture after completion");
 
-        if let Some(lock) = mutex.try_lock() {
-            mutex.remove_waker(self.wait_key, false);
-            self.mutex = None;
-            return Poll::Ready(lock);
+        if mutex.fair && this.is_waiting {
+            let mut waiters = mutex.waiters.lock().unwrap();
+            match unsafe { waiters.get(&this.waiter) } {
+                Waiter::Acquired => {
+                    drop(waiters);
+                    return Poll::Ready(this.acquired(mutex));
+                }
+                Waiter::Waiting(waker) => {
+                    if !waker.will_wake(cx.waker()) {
+                        *waker = cx.waker().clone();
+                    }
+                    return Poll::Pending;
+                }
+                Waiter::Idle | Waiter::Woken => {}
+            }
+        }
+
+        if mutex.try_lock_raw() {
+            return Poll::Ready(this.acquired(mutex));
         }
 
         {
             let mut waiters = mutex.waiters.lock().unwrap();
-            if self.wait_key == WAIT_KEY_NONE {
-                self.wait_key = waiters.insert(Waiter::Waiting(cx.waker().clone()));
-                if waiters.len() == 1 {
-                    mutex.state.fetch_or(HAS_WAITERS, Ordering::Relaxed); // released by mutex unlock
+            let was_empty = waiters.is_empty();
+            let waiter = unsafe { waiters.get(&this.waiter) };
+            match waiter {
+                Waiter::Waiting(waker) => {
+                    if !waker.will_wake(cx.waker()) {
+                        *waker = cx.waker().clone();
+                    }
+                }
+                _ => {
+                    *waiter = Waiter::Waiting(cx.waker().clone());
+                    // Safety: the waiter is pinned, and removed from the list
+                    // before the future is dropped.
+                    unsafe { waiters.push_back(Pin::new_unchecked(&this.waiter)) };
+                    if was_empty {
+                        mutex.state.fetch_or(HAS_WAITERS, Ordering::Relaxed); // released by mutex unlock
+                    }
                 }
-            } else {
-                waiters[self.wait_key].register(cx.waker());
             }
+            this.is_waiting = true;
         }
 
         // Ensure that we haven't raced `MutexGuard::drop`'s unlock path by
         // attempting to acquire the lock again.
-        if let Some(lock) = mutex.try_lock() {
-            mutex.remove_waker(self.wait_key, false);
-            self.mutex = None;
-            return Poll::Ready(lock);
+        if mutex.try_lock_raw() {
+            return Poll::Ready(this.acquired(mutex));
         }
 
         Poll::Pending
@@ -240,11 +486,47 @@ impl<'a, T: ?Sized> Future for MutexLockFuture<'a, T> {
 impl<T: ?Sized> Drop for MutexLockFuture<'_, T> {
     fn drop(&mut self) {
         if let Some(mutex) = self.mutex {
-            // This future was dropped before it acquired the mutex.
-            //
-            // Remove ourselves from the map, waking up another waiter if we
-            // had been awoken to acquire the lock.
-            mutex.remove_waker(self.wait_key, true);
+            if self.is_waiting {
+                // This future was dropped before it acquired the mutex.
+                //
+                // Remove ourselves from the list, waking up another waiter if
+                // we had been awoken to acquire the lock.
+                mutex.remove_dropped_waiter(&self.waiter);
+            }
+        }
+    }
+}
+
+pin_project! {
+    /// A future which resolves when the target mutex has been successfully
+    /// acquired, returned by [`Mutex::lock_checked`].
+    pub struct MutexLockCheckedFuture<'a, T: ?Sized> {
+        #[pin]
+        inner: MutexLockFuture<'a, T>,
+    }
+}
+
+impl<T: ?Sized> fmt::Debug for MutexLockCheckedFuture<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MutexLockCheckedFuture").field("inner", &self.inner).finish()
+    }
+}
+
+impl<T: ?Sized> FusedFuture for MutexLockCheckedFuture<'_, T> {
+    fn is_terminated(&self) -> bool {
+        self.inner.is_terminated()
+    }
+}
+
+impl<'a, T: ?Sized> Future for MutexLockCheckedFuture<'a, T> {
+    type Output = LockResult<MutexGuard<'a, T>>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let guard = futures_core::ready!(self.project().inner.poll(cx));
+        if guard.mutex.is_poisoned() {
+            Poll::Ready(Err(PoisonError::new(guard)))
+        } else {
+            Poll::Ready(Ok(guard))
         }
     }
 }
@@ -254,9 +536,21 @@ impl<T: ?Sized> Drop for MutexLockFuture<'_, T> {
 /// unlocked.
 pub struct MutexGuard<'a, T: ?Sized> {
     mutex: &'a Mutex<T>,
+    // Whether the thread was panicking when the lock was acquired, so that
+    // releasing it while unwinding from that panic doesn't poison the mutex.
+    was_panicking: bool,
 }
 
 impl<'a, T: ?Sized> MutexGuard<'a, T> {
+    fn new(mutex: &'a Mutex<T>) -> Self {
+        Self { mutex, was_panicking: thread::panicking() }
+    }
+
+    // Returns the mutex this guard is locking, for `Condvar::wait`.
+    pub(super) fn mutex(&self) -> &'a Mutex<T> {
+        self.mutex
+    }
+
     /// Returns a locked view over a portion of the locked data.
     ///
     /// # Example
@@ -277,12 +571,12 @@ impl<'a, T: ?Sized> MutexGuard<'a, T> {
     where
         F: FnOnce(&mut T) -> &mut U,
     {
-        let mutex = this.mutex;
+        let (mutex, was_panicking) = (this.mutex, this.was_panicking);
         let value = f(unsafe { &mut *this.mutex.value.get() });
         // Don't run the `drop` method for MutexGuard. The ownership of the underlying
         // locked state is being moved to the returned MappedMutexGuard.
         mem::forget(this);
-        MappedMutexGuard { mutex, value, _marker: PhantomData }
+        MappedMutexGuard { mutex, value, was_panicking, _marker: PhantomData }
     }
 }
 
@@ -294,7 +588,7 @@ impl<T: ?Sized + fmt::Debug> fmt::Debug for MutexGuard<'_, T> {
 
 impl<T: ?Sized> Drop for MutexGuard<'_, T> {
     fn drop(&mut self) {
-        self.mutex.unlock()
+        self.mutex.unlock(self.was_panicking)
     }
 }
 
@@ -316,6 +610,7 @@ impl<T: ?Sized> DerefMut for MutexGuard<'_, T> {
 pub struct MappedMutexGuard<'a, T: ?Sized, U: ?Sized> {
     mutex: &'a Mutex<T>,
     value: *mut U,
+    was_panicking: bool,
     _marker: PhantomData<&'a mut U>,
 }
 
@@ -341,12 +636,12 @@ impl<'a, T: ?Sized, U: ?Sized> MappedMutexGuard<'a, T, U> {
     where
         F: FnOnce(&mut U) -> &mut V,
     {
-        let mutex = this.mutex;
+        let (mutex, was_panicking) = (this.mutex, this.was_panicking);
         let value = f(unsafe { &mut *this.value });
         // Don't run the `drop` method for MappedMutexGuard. The ownership of the underlying
         // locked state is being moved to the returned MappedMutexGuard.
         mem::forget(this);
-        MappedMutexGuard { mutex, value, _marker: PhantomData }
+        MappedMutexGuard { mutex, value, was_panicking, _marker: PhantomData }
     }
 }
 
@@ -361,7 +656,7 @@ impl<T: ?Sized, U: ?Sized + fmt::Debug> fmt::Debug for MappedMutexGuard<'_, T, U
 
 impl<T: ?Sized, U: ?Sized> Drop for MappedMutexGuard<'_, T, U> {
     fn drop(&mut self) {
-        self.mutex.unlock()
+        self.mutex.unlock(self.was_panicking)
     }
 }
 
@@ -404,3 +699,80 @@ fn test_mutex_guard_debug_not_recurse() {
     let guard = MutexGuard::map(guard, |n| n);
     let _ = format!("{:?}", guard);
 }
+
+#[cfg(all(test, loom))]
+mod loom_tests {
+    use super::Mutex;
+    use futures_core::future::Future;
+    use futures_core::task::{Context, Poll};
+    use loom::future::block_on;
+    use loom::sync::Arc;
+    use loom::thread;
+    use std::pin::Pin;
+
+    fn increment(mutex: &Mutex<usize>) {
+        let mut guard = block_on(mutex.lock());
+        assert!(mutex.try_lock().is_none());
+        *guard += 1;
+    }
+
+    fn contended(mutex: Mutex<usize>) {
+        let mutex = Arc::new(mutex);
+        let threads: Vec<_> = (0..2)
+            .map(|_| {
+                let mutex = mutex.clone();
+                thread::spawn(move || increment(&mutex))
+            })
+            .collect();
+        increment(&mutex);
+        for thread in threads {
+            thread.join().unwrap();
+        }
+        assert_eq!(*mutex.try_lock().unwrap(), 3);
+    }
+
+    #[test]
+    fn mutex_contended() {
+        loom::model(|| contended(Mutex::new(0)));
+    }
+
+    #[test]
+    fn fair_mutex_contended() {
+        loom::model(|| contended(Mutex::new_fair(0)));
+    }
+
+    fn dropped_waiter(mutex: Mutex<()>) {
+        let mutex = Arc::new(mutex);
+        let guard = mutex.try_lock().unwrap();
+
+        let dropped = {
+            let mutex = mutex.clone();
+            thread::spawn(move || {
+                let mut future = Box::pin(mutex.lock());
+                let waker = futures_task::noop_waker();
+                if let Poll::Ready(guard) = future.as_mut().poll(&mut Context::from_waker(&waker)) {
+                    drop(guard);
+                }
+            })
+        };
+        let waiting = {
+            let mutex = mutex.clone();
+            thread::spawn(move || drop(block_on(mutex.lock())))
+        };
+
+        drop(guard);
+        dropped.join().unwrap();
+        waiting.join().unwrap();
+        assert!(mutex.try_lock().is_some());
+    }
+
+    #[test]
+    fn mutex_dropped_waiter() {
+        loom::model(|| dropped_waiter(Mutex::new(())));
+    }
+
+    #[test]
+    fn fair_mutex_dropped_waiter() {
+        loom::model(|| dropped_waiter(Mutex::new_fair(())));
+    }
+}
@@ -0,0 +1,236 @@
+use super::waiter_list::{WaiterList, WaiterNode};
+use futures_core::future::{FusedFuture, Future};
+use futures_core::task::{Context, Poll, Waker};
+use std::fmt;
+use std::mem;
+use std::pin::Pin;
+use std::sync::Mutex as StdMutex;
+
+/// A futures-aware notification primitive, waking one or all of the tasks
+/// waiting for a notification.
+///
+/// A task waits for a notification by awaiting [`notified`](Notify::notified).
+/// [`notify_one`](Notify::notify_one) wakes a single waiting task, in the
+/// order they started waiting, or stores a permit for the next task to wait
+/// if none is, so that a notification sent before the task arrives isn't
+/// lost. [`notify_waiters`](Notify::notify_waiters) wakes all the tasks
+/// waiting, without storing a permit.
+///
+/// # Examples
+///
+/// ```
+/// # futures::executor::block_on(async {
+/// use futures::lock::Notify;
+///
+/// let notify = Notify::new();
+///
+/// // The notification is stored until a task waits for it.
+/// notify.notify_one();
+/// notify.notified().await;
+/// # });
+/// ```
+pub struct Notify {
+    state: StdMutex<State>,
+}
+
+struct State {
+    // Set by `notify_one` when no task was waiting.
+    permit: bool,
+    // Incremented by each call to `notify_waiters`.
+    generation: usize,
+    waiters: WaiterList<Waiter>,
+}
+
+// The state of a `Notified` future. Only the `Waiting` ones are linked into
+// the list of waiters, in the order they started waiting.
+enum Waiter {
+    Idle,
+    Waiting(Waker),
+    // Unlinked by `notify_one`.
+    One,
+    // Unlinked by `notify_waiters`.
+    All,
+}
+
+impl Waiter {
+    fn notify(&mut self, notification: Self) {
+        if let Self::Waiting(waker) = mem::replace(self, notification) {
+            waker.wake();
+        }
+    }
+}
+
+impl State {
+    fn notify_one(&mut self) {
+        match self.waiters.pop_front() {
+            Some(waiter) => waiter.notify(Waiter::One),
+            None => self.permit = true,
+        }
+    }
+}
+
+impl Notify {
+    /// Creates a new `Notify`, without a stored permit.
+    pub fn new() -> Self {
+        Self {
+            state: StdMutex::new(State {
+                permit: false,
+                generation: 0,
+                waiters: WaiterList::new(),
+            }),
+        }
+    }
+
+    /// Wait for a notification.
+    ///
+    /// This method returns a future that will resolve once the task was
+    /// notified by [`notify_one`](Notify::notify_one) or, if the future was
+    /// created before it was called, by
+    /// [`notify_waiters`](Notify::notify_waiters).
+    pub fn notified(&self) -> Notified<'_> {
+        let generation = self.state.lock().unwrap().generation;
+        Notified { notify: Some(self), generation, waiter: WaiterNode::new(Waiter::Idle) }
+    }
+
+    /// Notifies the first task waiting, or stores a permit for the next
+    /// task to wait if none is.
+    ///
+    /// At most one permit is stored: notifying repeatedly while no task
+    /// waits only lets a single task through.
+    pub fn notify_one(&self) {
+        self.state.lock().unwrap().notify_one();
+    }
+
+    /// Notifies all the tasks waiting, i.e. the futures returned by
+    /// [`notified`](Notify::notified) before this call.
+    ///
+    /// Unlike [`notify_one`](Notify::notify_one), this does not store a
+    /// permit when no task is waiting.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::lock::Notify;
+    ///
+    /// let notify = Notify::new();
+    /// let first = notify.notified();
+    /// let second = notify.notified();
+    ///
+    /// notify.notify_waiters();
+    /// first.await;
+    /// second.await;
+    /// # });
+    /// ```
+    pub fn notify_waiters(&self) {
+        let mut state = self.state.lock().unwrap();
+        state.generation = state.generation.wrapping_add(1);
+        while let Some(waiter) = state.waiters.pop_front() {
+            waiter.notify(Waiter::All);
+        }
+    }
+}
+
+impl Default for Notify {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for Notify {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let state = self.state.lock().unwrap();
+        f.debug_struct("Notify")
+            .field("permit", &state.permit)
+            .field("has_waiters", &!state.waiters.is_empty())
+            .finish()
+    }
+}
+
+/// A future which resolves when the task was notified, returned by
+/// [`Notify::notified`].
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct Notified<'a> {
+    // `None` indicates that the notification was received.
+    notify: Option<&'a Notify>,
+    // The number of calls to `notify_waiters` when this future was created.
+    generation: usize,
+    // Linked into the waiters of the `Notify` while waiting, which is why this
+    // future is `!Unpin`.
+    waiter: WaiterNode<Waiter>,
+}
+
+impl fmt::Debug for Notified<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Notified")
+            .field("was_notified", &self.notify.is_none())
+            .field("notify", &self.notify)
+            .finish()
+    }
+}
+
+impl FusedFuture for Notified<'_> {
+    fn is_terminated(&self) -> bool {
+        self.notify.is_none()
+    }
+}
+
+impl Future for Notified<'_> {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        // Safety: the waiter is never moved out of the future.
+        let this = unsafe { self.get_unchecked_mut() };
+        let notify = this.notify.expect("polled Notified after completion");
+
+        let mut state = notify.state.lock().unwrap();
+        let state = &mut *state;
+        let waiter = unsafe { state.waiters.get(&this.waiter) };
+        match waiter {
+            Waiter::Idle => {
+                if state.generation != this.generation {
+                    this.notify = None;
+                    return Poll::Ready(());
+                }
+                if state.permit {
+                    state.permit = false;
+                    this.notify = None;
+                    return Poll::Ready(());
+                }
+                *waiter = Waiter::Waiting(cx.waker().clone());
+                // Safety: the waiter is pinned, and removed from the list
+                // before the future is dropped.
+                unsafe { state.waiters.push_back(Pin::new_unchecked(&this.waiter)) };
+                Poll::Pending
+            }
+            Waiter::Waiting(waker) => {
+                if !waker.will_wake(cx.waker()) {
+                    *waker = cx.waker().clone();
+                }
+                Poll::Pending
+            }
+            Waiter::One | Waiter::All => {
+                *waiter = Waiter::Idle;
+                this.notify = None;
+                Poll::Ready(())
+            }
+        }
+    }
+}
+
+impl Drop for Notified<'_> {
+    fn drop(&mut self) {
+        if let Some(notify) = self.notify {
+            let mut state = notify.state.lock().unwrap();
+            match mem::replace(unsafe { state.waiters.get(&self.waiter) }, Waiter::Idle) {
+                Waiter::Waiting(_) => {
+                    unsafe { state.waiters.remove(&self.waiter) };
+                }
+                // Pass on the notification this future was dropped before
+                // receiving.
+                Waiter::One => state.notify_one(),
+                Waiter::Idle | Waiter::All => {}
+            }
+        }
+    }
+}
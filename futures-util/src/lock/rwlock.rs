@@ -0,0 +1,701 @@
+use super::waiter_list::{WaiterList, WaiterNode};
+use futures_core::future::{FusedFuture, Future};
+use futures_core::task::{Context, Poll, Waker};
+use std::cell::UnsafeCell;
+use std::ops::{Deref, DerefMut};
+use std::pin::Pin;
+use std::sync::{Arc, Mutex as StdMutex};
+use std::{fmt, mem};
+
+/// A futures-aware read-write lock.
+///
+/// This lock allows any number of readers or at most one writer to access
+/// the data at any point in time.
+///
+/// Along with the readers, at most one task can hold an
+/// [upgradable read](RwLock::upgradable_read) lock, which it can then upgrade
+/// to the write lock without releasing it in between, so that no other writer
+/// can modify the data before it does.
+///
+/// # Fairness
+///
+/// This lock prefers writers: once a writer is waiting for the lock, new
+/// readers wait until it has acquired and released it, so that a steady
+/// stream of readers cannot starve writers. As a consequence, a task that
+/// already holds a read lock must not wait for another one, as this
+/// deadlocks if a writer starts waiting in between.
+///
+/// Beyond that, no guarantee is made about the order in which waiting tasks
+/// acquire the lock.
+pub struct RwLock<T: ?Sized> {
+    state: StdMutex<State>,
+    value: UnsafeCell<T>,
+}
+
+struct State {
+    // The number of read guards alive.
+    readers: usize,
+    // Whether a write guard is alive.
+    writer: bool,
+    // Whether an upgradable read guard is alive.
+    upgradable: bool,
+    // The number of writers and upgrades in `waiters`, which keep new readers
+    // waiting.
+    waiting_writers: usize,
+    // The futures waiting for the lock, which stay linked once woken until
+    // they acquire it or are dropped.
+    waiters: WaiterList<Waiter>,
+}
+
+struct Waiter {
+    access: Access,
+    // `None` once the waiter was woken.
+    waker: Option<Waker>,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Access {
+    Read,
+    UpgradableRead,
+    Write,
+    // Upgrading an upgradable read lock to the write lock.
+    Upgrade,
+}
+
+impl Access {
+    fn is_write(self) -> bool {
+        self == Self::Write || self == Self::Upgrade
+    }
+}
+
+impl Waiter {
+    fn register(&mut self, waker: &Waker) {
+        match &self.waker {
+            Some(w) if waker.will_wake(w) => {}
+            _ => self.waker = Some(waker.clone()),
+        }
+    }
+
+    fn wake(&mut self) {
+        if let Some(waker) = self.waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+impl State {
+    // Wakes the waiters that can acquire the lock, after it was released or a
+    // waiter stopped waiting.
+    fn wake(&mut self) {
+        if self.writer {
+            return;
+        }
+        if self.waiting_writers > 0 {
+            if self.readers == 0 {
+                // Wake a single writer, unless one was already woken and has
+                // yet to acquire the lock. While an upgradable read lock is
+                // held, only its upgrade can acquire the write lock.
+                let access = if self.upgradable { Access::Upgrade } else { Access::Write };
+                if !self
+                    .waiters
+                    .iter_mut()
+                    .any(|waiter| waiter.access == access && waiter.waker.is_none())
+                {
+                    if let Some(writer) =
+                        self.waiters.iter_mut().find(|waiter| waiter.access == access)
+                    {
+                        writer.wake();
+                    }
+                }
+            }
+        } else {
+            for waiter in self.waiters.iter_mut() {
+                if waiter.access == Access::Read || !self.upgradable {
+                    waiter.wake();
+                }
+            }
+        }
+    }
+}
+
+impl<T> RwLock<T> {
+    /// Creates a new futures-aware read-write lock.
+    pub fn new(t: T) -> Self {
+        Self {
+            state: StdMutex::new(State {
+                readers: 0,
+                writer: false,
+                upgradable: false,
+                waiting_writers: 0,
+                waiters: WaiterList::new(),
+            }),
+            value: UnsafeCell::new(t),
+        }
+    }
+
+    /// Consumes this lock, returning the underlying data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures::lock::RwLock;
+    ///
+    /// let lock = RwLock::new(0);
+    /// assert_eq!(lock.into_inner(), 0);
+    /// ```
+    pub fn into_inner(self) -> T {
+        self.value.into_inner()
+    }
+}
+
+impl<T: ?Sized> RwLock<T> {
+    /// Attempt to acquire a read lock immediately.
+    ///
+    /// If the lock is held by a writer, or a writer is waiting for it, this
+    /// will return `None`.
+    pub fn try_read(&self) -> Option<RwLockReadGuard<'_, T>> {
+        if self.try_acquire(Access::Read) {
+            Some(RwLockReadGuard { lock: self })
+        } else {
+            None
+        }
+    }
+
+    /// Attempt to acquire the write lock immediately.
+    ///
+    /// If the lock is currently held, this will return `None`.
+    pub fn try_write(&self) -> Option<RwLockWriteGuard<'_, T>> {
+        if self.try_acquire(Access::Write) {
+            Some(RwLockWriteGuard { lock: self })
+        } else {
+            None
+        }
+    }
+
+    /// Attempt to acquire an upgradable read lock immediately.
+    ///
+    /// If the lock is held by a writer or by another upgradable reader, or a
+    /// writer is waiting for it, this will return `None`.
+    pub fn try_upgradable_read(&self) -> Option<RwLockUpgradableReadGuard<'_, T>> {
+        if self.try_acquire(Access::UpgradableRead) {
+            Some(RwLockUpgradableReadGuard { lock: self })
+        } else {
+            None
+        }
+    }
+
+    /// Acquire a read lock asynchronously.
+    ///
+    /// This method returns a future that will resolve once a read lock has
+    /// been successfully acquired.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::lock::RwLock;
+    ///
+    /// let lock = RwLock::new(1);
+    /// let first = lock.read().await;
+    /// let second = lock.read().await;
+    /// assert_eq!(*first + *second, 2);
+    /// assert!(lock.try_write().is_none());
+    /// # });
+    /// ```
+    pub fn read(&self) -> RwLockReadFuture<'_, T> {
+        RwLockReadFuture::new(self)
+    }
+
+    /// Acquire the write lock asynchronously.
+    ///
+    /// This method returns a future that will resolve once the write lock has
+    /// been successfully acquired.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::lock::RwLock;
+    ///
+    /// let lock = RwLock::new(1);
+    /// *lock.write().await += 1;
+    /// assert_eq!(*lock.read().await, 2);
+    /// # });
+    /// ```
+    pub fn write(&self) -> RwLockWriteFuture<'_, T> {
+        RwLockWriteFuture::new(self)
+    }
+
+    /// Acquire an upgradable read lock asynchronously.
+    ///
+    /// This method returns a future that will resolve once an upgradable read
+    /// lock has been successfully acquired. Such a lock is shared with the
+    /// readers, but not with writers or other upgradable readers, so that it
+    /// can be [upgraded](RwLockUpgradableReadGuard::upgrade) to the write lock
+    /// without another writer acquiring it in between.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::lock::{RwLock, RwLockUpgradableReadGuard};
+    ///
+    /// let lock = RwLock::new(None);
+    /// let guard = lock.upgradable_read().await;
+    /// assert!(lock.try_read().is_some());
+    ///
+    /// if guard.is_none() {
+    ///     let mut guard = RwLockUpgradableReadGuard::upgrade(guard).await;
+    ///     *guard = Some(1);
+    /// }
+    /// assert_eq!(*lock.read().await, Some(1));
+    /// # });
+    /// ```
+    pub fn upgradable_read(&self) -> RwLockUpgradableReadFuture<'_, T> {
+        RwLockUpgradableReadFuture::new(self)
+    }
+
+    /// Acquire a read lock asynchronously, returning a guard that keeps the
+    /// lock alive instead of borrowing it.
+    ///
+    /// Such a guard can be held across tasks, or returned from functions
+    /// which only have an `Arc` to the lock.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::lock::RwLock;
+    /// use std::sync::Arc;
+    ///
+    /// let lock = Arc::new(RwLock::new(1));
+    /// let guard = lock.clone().read_owned().await;
+    /// drop(lock);
+    /// assert_eq!(*guard, 1);
+    /// # });
+    /// ```
+    pub fn read_owned(self: Arc<Self>) -> OwnedRwLockReadFuture<T> {
+        OwnedRwLockReadFuture::new(self)
+    }
+
+    /// Acquire the write lock asynchronously, returning a guard that keeps the
+    /// lock alive instead of borrowing it.
+    pub fn write_owned(self: Arc<Self>) -> OwnedRwLockWriteFuture<T> {
+        OwnedRwLockWriteFuture::new(self)
+    }
+
+    /// Returns a mutable reference to the underlying data.
+    ///
+    /// Since this call borrows the `RwLock` mutably, no actual locking needs
+    /// to take place -- the mutable borrow statically guarantees no locks
+    /// exist.
+    pub fn get_mut(&mut self) -> &mut T {
+        // We know statically that there are no other references to `self`, so
+        // there's no need to lock it.
+        unsafe { &mut *self.value.get() }
+    }
+
+    fn try_acquire(&self, access: Access) -> bool {
+        Self::acquire(&mut self.state.lock().unwrap(), access)
+    }
+
+    fn acquire(state: &mut State, access: Access) -> bool {
+        match access {
+            Access::Read => {
+                if state.writer || state.waiting_writers > 0 {
+                    return false;
+                }
+                state.readers += 1;
+            }
+            Access::UpgradableRead => {
+                if state.writer || state.upgradable || state.waiting_writers > 0 {
+                    return false;
+                }
+                state.upgradable = true;
+            }
+            Access::Write => {
+                if state.writer || state.upgradable || state.readers > 0 {
+                    return false;
+                }
+                state.writer = true;
+            }
+            Access::Upgrade => {
+                if state.readers > 0 {
+                    return false;
+                }
+                state.upgradable = false;
+                state.writer = true;
+            }
+        }
+        true
+    }
+
+    // Acquires the lock, or registers the task to be woken once it may be
+    // able to.
+    //
+    // Safety: the waiter must be removed with `cancel` before it is dropped,
+    // if this returns `Pending`.
+    unsafe fn poll_acquire(
+        &self,
+        access: Access,
+        waiter: Pin<&WaiterNode<Waiter>>,
+        is_waiting: &mut bool,
+        cx: &mut Context<'_>,
+    ) -> Poll<()> {
+        let mut state = self.state.lock().unwrap();
+        let state = &mut *state;
+        if *is_waiting && access.is_write() {
+            // Don't let our own entry count as a writer keeping us waiting.
+            state.waiting_writers -= 1;
+        }
+        if Self::acquire(state, access) {
+            if *is_waiting {
+                state.waiters.remove(&waiter);
+                *is_waiting = false;
+            }
+            return Poll::Ready(());
+        }
+        if *is_waiting {
+            state.waiters.get(&waiter).register(cx.waker());
+        } else {
+            state.waiters.get(&waiter).waker = Some(cx.waker().clone());
+            state.waiters.push_back(waiter);
+            *is_waiting = true;
+        }
+        if access.is_write() {
+            state.waiting_writers += 1;
+        }
+        Poll::Pending
+    }
+
+    // Stops waiting for the lock, letting other waiters acquire it if they
+    // were kept waiting by us, or if we were woken to acquire it.
+    fn cancel(&self, access: Access, waiter: &WaiterNode<Waiter>, is_waiting: bool) {
+        if !is_waiting && access != Access::Upgrade {
+            return;
+        }
+        let mut state = self.state.lock().unwrap();
+        if is_waiting {
+            unsafe { state.waiters.remove(waiter) };
+            if access.is_write() {
+                state.waiting_writers -= 1;
+            }
+        }
+        if access == Access::Upgrade {
+            // The upgradable read lock is released along with its upgrade.
+            state.upgradable = false;
+        }
+        state.wake();
+    }
+
+    // Releases a read lock. Called by the read guards when they are dropped.
+    fn unlock_read(&self) {
+        let mut state = self.state.lock().unwrap();
+        state.readers -= 1;
+        state.wake();
+    }
+
+    // Releases the upgradable read lock. Called by the upgradable read guard
+    // when it is dropped.
+    fn unlock_upgradable(&self) {
+        let mut state = self.state.lock().unwrap();
+        state.upgradable = false;
+        state.wake();
+    }
+
+    // Releases the write lock. Called by the write guards when they are
+    // dropped.
+    fn unlock_write(&self) {
+        let mut state = self.state.lock().unwrap();
+        state.writer = false;
+        state.wake();
+    }
+}
+
+impl<T: ?Sized> fmt::Debug for RwLock<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let state = self.state.lock().unwrap();
+        f.debug_struct("RwLock")
+            .field("readers", &state.readers)
+            .field("is_write_locked", &state.writer)
+            .field("has_waiters", &!state.waiters.is_empty())
+            .finish()
+    }
+}
+
+impl<T> From<T> for RwLock<T> {
+    fn from(t: T) -> Self {
+        Self::new(t)
+    }
+}
+
+impl<T: Default> Default for RwLock<T> {
+    fn default() -> Self {
+        Self::new(Default::default())
+    }
+}
+
+macro_rules! lock_future {
+    (
+        $(#[$attr:meta])*
+        $name:ident<$($lt:lifetime,)? $t:ident>($lock:ty) -> $guard:ty, $access:expr, $make_guard:expr
+    ) => {
+        $(#[$attr])*
+        pub struct $name<$($lt,)? $t: ?Sized> {
+            // `None` indicates that the lock was successfully acquired.
+            lock: Option<$lock>,
+            // Linked into the waiters of the lock while waiting, which is why
+            // this future is `!Unpin`.
+            waiter: WaiterNode<Waiter>,
+            // Whether the waiter is linked.
+            is_waiting: bool,
+        }
+
+        #[allow(single_use_lifetimes)] // generated for borrowed and owned locks alike
+        impl<$($lt,)? $t: ?Sized> $name<$($lt,)? $t> {
+            fn new(lock: $lock) -> Self {
+                Self {
+                    lock: Some(lock),
+                    waiter: WaiterNode::new(Waiter { access: $access, waker: None }),
+                    is_waiting: false,
+                }
+            }
+        }
+
+        #[allow(single_use_lifetimes)] // generated for borrowed and owned locks alike
+        impl<$($lt,)? $t: ?Sized> fmt::Debug for $name<$($lt,)? $t> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_struct(stringify!($name))
+                    .field("was_acquired", &self.lock.is_none())
+                    .field("lock", &self.lock)
+                    .field("is_waiting", &self.is_waiting)
+                    .finish()
+            }
+        }
+
+        #[allow(single_use_lifetimes)] // generated for borrowed and owned locks alike
+        impl<$($lt,)? $t: ?Sized> FusedFuture for $name<$($lt,)? $t> {
+            fn is_terminated(&self) -> bool {
+                self.lock.is_none()
+            }
+        }
+
+        #[allow(single_use_lifetimes)] // generated for borrowed and owned locks alike
+        impl<$($lt,)? $t: ?Sized> Future for $name<$($lt,)? $t> {
+            type Output = $guard;
+
+            fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+                // Safety: the waiter is never moved out of the future, and
+                // cancelled when it is dropped.
+                let this = unsafe { self.get_unchecked_mut() };
+                let lock = this.lock.as_ref().expect(concat!(
+                    "polled ",
+                    stringify!($name),
+                    " after completion"
+                ));
+                let waiter = unsafe { Pin::new_unchecked(&this.waiter) };
+                futures_core::ready!(unsafe {
+                    lock.poll_acquire($access, waiter, &mut this.is_waiting, cx)
+                });
+                let lock = this.lock.take().unwrap();
+                Poll::Ready($make_guard(lock))
+            }
+        }
+
+        #[allow(single_use_lifetimes)] // generated for borrowed and owned locks alike
+        impl<$($lt,)? $t: ?Sized> Drop for $name<$($lt,)? $t> {
+            fn drop(&mut self) {
+                if let Some(lock) = &self.lock {
+                    // This future was dropped before it acquired the lock.
+                    lock.cancel($access, &self.waiter, self.is_waiting);
+                }
+            }
+        }
+    };
+}
+
+lock_future! {
+    /// A future which resolves when a read lock has been successfully acquired.
+    RwLockReadFuture<'a, T>(&'a RwLock<T>) -> RwLockReadGuard<'a, T>, Access::Read,
+    |lock| RwLockReadGuard { lock }
+}
+
+lock_future! {
+    /// A future which resolves when the write lock has been successfully
+    /// acquired.
+    RwLockWriteFuture<'a, T>(&'a RwLock<T>) -> RwLockWriteGuard<'a, T>, Access::Write,
+    |lock| RwLockWriteGuard { lock }
+}
+
+lock_future! {
+    /// A future which resolves when an upgradable read lock has been
+    /// successfully acquired.
+    RwLockUpgradableReadFuture<'a, T>(&'a RwLock<T>) -> RwLockUpgradableReadGuard<'a, T>,
+    Access::UpgradableRead,
+    |lock| RwLockUpgradableReadGuard { lock }
+}
+
+lock_future! {
+    /// A future which resolves when an upgradable read lock has been
+    /// upgraded to the write lock, returned by
+    /// [`RwLockUpgradableReadGuard::upgrade`].
+    ///
+    /// Dropping this future before it resolves releases the upgradable read
+    /// lock.
+    RwLockUpgradeFuture<'a, T>(&'a RwLock<T>) -> RwLockWriteGuard<'a, T>, Access::Upgrade,
+    |lock| RwLockWriteGuard { lock }
+}
+
+lock_future! {
+    /// A future which resolves when a read lock has been successfully acquired,
+    /// returned by [`RwLock::read_owned`].
+    OwnedRwLockReadFuture<T>(Arc<RwLock<T>>) -> OwnedRwLockReadGuard<T>, Access::Read,
+    |lock| OwnedRwLockReadGuard { lock }
+}
+
+lock_future! {
+    /// A future which resolves when the write lock has been successfully
+    /// acquired, returned by [`RwLock::write_owned`].
+    OwnedRwLockWriteFuture<T>(Arc<RwLock<T>>) -> OwnedRwLockWriteGuard<T>, Access::Write,
+    |lock| OwnedRwLockWriteGuard { lock }
+}
+
+/// An RAII guard returned by the `read` and `try_read` methods.
+/// When this structure is dropped (falls out of scope), the read lock will be
+/// released.
+pub struct RwLockReadGuard<'a, T: ?Sized> {
+    lock: &'a RwLock<T>,
+}
+
+/// An RAII guard returned by the `write` and `try_write` methods.
+/// When this structure is dropped (falls out of scope), the write lock will be
+/// released.
+pub struct RwLockWriteGuard<'a, T: ?Sized> {
+    lock: &'a RwLock<T>,
+}
+
+/// An RAII guard returned by the `upgradable_read` and `try_upgradable_read`
+/// methods. When this structure is dropped (falls out of scope), the
+/// upgradable read lock will be released.
+pub struct RwLockUpgradableReadGuard<'a, T: ?Sized> {
+    lock: &'a RwLock<T>,
+}
+
+impl<'a, T: ?Sized> RwLockUpgradableReadGuard<'a, T> {
+    /// Upgrades this guard to the write lock, waiting for the readers to
+    /// release their locks.
+    ///
+    /// New readers wait for the upgrade, and no writer can acquire the lock
+    /// before it.
+    pub fn upgrade(this: Self) -> RwLockUpgradeFuture<'a, T> {
+        let lock = this.lock;
+        // Don't run the `drop` method for the guard. The upgradable read lock
+        // is being moved to the returned future.
+        mem::forget(this);
+        RwLockUpgradeFuture::new(lock)
+    }
+
+    /// Attempt to upgrade this guard to the write lock immediately.
+    ///
+    /// If readers hold the lock, this will return the guard back.
+    pub fn try_upgrade(this: Self) -> Result<RwLockWriteGuard<'a, T>, Self> {
+        let lock = this.lock;
+        if lock.try_acquire(Access::Upgrade) {
+            mem::forget(this);
+            Ok(RwLockWriteGuard { lock })
+        } else {
+            Err(this)
+        }
+    }
+}
+
+/// An RAII guard returned by the `read_owned` method.
+/// When this structure is dropped (falls out of scope), the read lock will be
+/// released.
+pub struct OwnedRwLockReadGuard<T: ?Sized> {
+    lock: Arc<RwLock<T>>,
+}
+
+/// An RAII guard returned by the `write_owned` method.
+/// When this structure is dropped (falls out of scope), the write lock will be
+/// released.
+pub struct OwnedRwLockWriteGuard<T: ?Sized> {
+    lock: Arc<RwLock<T>>,
+}
+
+macro_rules! guard {
+    ($name:ident<$($lt:lifetime,)? $t:ident>, $unlock:ident) => {
+        #[allow(single_use_lifetimes)] // generated for borrowed and owned locks alike
+        impl<$($lt,)? $t: ?Sized + fmt::Debug> fmt::Debug for $name<$($lt,)? $t> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_struct(stringify!($name))
+                    .field("value", &&**self)
+                    .field("lock", &self.lock)
+                    .finish()
+            }
+        }
+
+        #[allow(single_use_lifetimes)] // generated for borrowed and owned locks alike
+        impl<$($lt,)? $t: ?Sized> Drop for $name<$($lt,)? $t> {
+            fn drop(&mut self) {
+                self.lock.$unlock()
+            }
+        }
+
+        #[allow(single_use_lifetimes)] // generated for borrowed and owned locks alike
+        impl<$($lt,)? $t: ?Sized> Deref for $name<$($lt,)? $t> {
+            type Target = $t;
+            fn deref(&self) -> &$t {
+                unsafe { &*self.lock.value.get() }
+            }
+        }
+
+    };
+}
+
+guard!(RwLockReadGuard<'a, T>, unlock_read);
+guard!(RwLockWriteGuard<'a, T>, unlock_write);
+guard!(RwLockUpgradableReadGuard<'a, T>, unlock_upgradable);
+guard!(OwnedRwLockReadGuard<T>, unlock_read);
+guard!(OwnedRwLockWriteGuard<T>, unlock_write);
+
+impl<T: ?Sized> DerefMut for RwLockWriteGuard<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        unsafe { &mut *self.lock.value.get() }
+    }
+}
+
+impl<T: ?Sized> DerefMut for OwnedRwLockWriteGuard<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        unsafe { &mut *self.lock.value.get() }
+    }
+}
+
+// Read-write locks can be moved freely between threads and acquired on any
+// thread so long as the inner value can be safely sent between threads, and
+// shared between them for the readers.
+unsafe impl<T: ?Sized + Send> Send for RwLock<T> {}
+unsafe impl<T: ?Sized + Send + Sync> Sync for RwLock<T> {}
+
+// It's safe to switch which thread the acquire is being attempted on so long as
+// `T` can be accessed on that thread.
+unsafe impl<T: ?Sized + Send + Sync> Send for RwLockReadFuture<'_, T> {}
+unsafe impl<T: ?Sized + Send + Sync> Send for RwLockWriteFuture<'_, T> {}
+// doesn't have any interesting `&self` methods (only Debug)
+unsafe impl<T: ?Sized> Sync for RwLockReadFuture<'_, T> {}
+unsafe impl<T: ?Sized> Sync for RwLockWriteFuture<'_, T> {}
+unsafe impl<T: ?Sized + Send + Sync> Send for RwLockUpgradableReadFuture<'_, T> {}
+unsafe impl<T: ?Sized> Sync for RwLockUpgradableReadFuture<'_, T> {}
+unsafe impl<T: ?Sized + Send + Sync> Send for RwLockUpgradeFuture<'_, T> {}
+unsafe impl<T: ?Sized> Sync for RwLockUpgradeFuture<'_, T> {}
+
+// A read guard only gives shared access to the value, from whichever thread
+// it is used on.
+unsafe impl<T: ?Sized + Sync> Send for RwLockReadGuard<'_, T> {}
+unsafe impl<T: ?Sized + Sync> Sync for RwLockReadGuard<'_, T> {}
+unsafe impl<T: ?Sized + Send + Sync> Send for RwLockWriteGuard<'_, T> {}
+unsafe impl<T: ?Sized + Send + Sync> Sync for RwLockWriteGuard<'_, T> {}
+// An upgradable read guard only gives shared access to the value, but can be
+// upgraded to give exclusive access on whichever thread it was sent to.
+unsafe impl<T: ?Sized + Send + Sync> Send for RwLockUpgradableReadGuard<'_, T> {}
+unsafe impl<T: ?Sized + Sync> Sync for RwLockUpgradableReadGuard<'_, T> {}
@@ -0,0 +1,413 @@
+use super::waiter_list::{WaiterList, WaiterNode};
+use futures_core::future::{FusedFuture, Future};
+use futures_core::task::{Context, Poll, Waker};
+use std::fmt;
+use std::mem;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex as StdMutex};
+
+/// A futures-aware counting semaphore.
+///
+/// A semaphore holds a number of permits, which tasks acquire before
+/// proceeding and release once done, limiting how many of them proceed
+/// concurrently, e.g. to bound the number of open connections.
+///
+/// Tasks acquire permits in the order they started waiting for them, and new
+/// attempts to acquire permits wait behind them, so that tasks waiting for
+/// many permits are not starved by tasks acquiring them one at a time.
+///
+/// # Examples
+///
+/// ```
+/// # futures::executor::block_on(async {
+/// use futures::lock::Semaphore;
+///
+/// let semaphore = Semaphore::new(2);
+/// let first = semaphore.acquire(1).await;
+/// let second = semaphore.acquire(1).await;
+/// assert!(semaphore.try_acquire(1).is_none());
+///
+/// drop(first);
+/// assert!(semaphore.try_acquire(1).is_some());
+/// # drop(second);
+/// # });
+/// ```
+pub struct Semaphore {
+    state: StdMutex<State>,
+}
+
+struct State {
+    permits: usize,
+    waiters: WaiterList<Waiter>,
+}
+
+// The state of a future acquiring permits. Only the `Waiting` ones are linked
+// into the list of waiters, in the order they started waiting.
+enum Waiter {
+    Idle,
+    Waiting { needed: usize, waker: Waker },
+    // Unlinked once the permits were handed to this waiter.
+    Acquired,
+}
+
+impl State {
+    // Hands the available permits to the waiters in order, until one of them
+    // needs more than what is left.
+    fn hand_off(&mut self) {
+        while let Some(Waiter::Waiting { needed, .. }) = self.waiters.front() {
+            if *needed > self.permits {
+                break;
+            }
+            self.permits -= *needed;
+            let waiter = self.waiters.pop_front().unwrap();
+            if let Waiter::Waiting { waker, .. } = mem::replace(waiter, Waiter::Acquired) {
+                waker.wake();
+            }
+        }
+    }
+}
+
+impl Semaphore {
+    /// Creates a new semaphore with the given number of permits.
+    pub fn new(permits: usize) -> Self {
+        Self { state: StdMutex::new(State { permits, waiters: WaiterList::new() }) }
+    }
+
+    /// Returns the number of permits currently available.
+    pub fn available_permits(&self) -> usize {
+        self.state.lock().unwrap().permits
+    }
+
+    /// Adds `n` permits to the semaphore, waking the tasks which can now
+    /// acquire theirs.
+    pub fn add_permits(&self, n: usize) {
+        let mut state = self.state.lock().unwrap();
+        state.permits += n;
+        state.hand_off();
+    }
+
+    /// Attempt to acquire `n` permits immediately.
+    ///
+    /// If fewer than `n` permits are available, or other tasks are waiting
+    /// for permits, this will return `None`.
+    pub fn try_acquire(&self, n: usize) -> Option<SemaphorePermit<'_>> {
+        if self.try_take(n) {
+            Some(SemaphorePermit { semaphore: self, permits: n })
+        } else {
+            None
+        }
+    }
+
+    /// Attempt to acquire `n` permits immediately, returning a permit which
+    /// keeps the semaphore alive instead of borrowing it.
+    ///
+    /// If fewer than `n` permits are available, or other tasks are waiting
+    /// for permits, this will return `None`.
+    pub fn try_acquire_owned(self: Arc<Self>, n: usize) -> Option<OwnedSemaphorePermit> {
+        if self.try_take(n) {
+            Some(OwnedSemaphorePermit { semaphore: self, permits: n })
+        } else {
+            None
+        }
+    }
+
+    /// Acquire `n` permits asynchronously.
+    ///
+    /// This method returns a future that will resolve once the permits have
+    /// been successfully acquired. If `n` is larger than the number of
+    /// permits the semaphore ever holds, it never resolves.
+    pub fn acquire(&self, n: usize) -> SemaphoreAcquireFuture<'_> {
+        SemaphoreAcquireFuture {
+            semaphore: Some(self),
+            permits: n,
+            waiter: WaiterNode::new(Waiter::Idle),
+            is_waiting: false,
+        }
+    }
+
+    /// Acquire `n` permits asynchronously, returning a permit which keeps the
+    /// semaphore alive instead of borrowing it.
+    ///
+    /// Such a permit can be moved into a spawned task, which releases it when
+    /// it completes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::lock::Semaphore;
+    /// use std::sync::Arc;
+    ///
+    /// let semaphore = Arc::new(Semaphore::new(1));
+    /// let permit = semaphore.clone().acquire_owned(1).await;
+    /// assert_eq!(semaphore.available_permits(), 0);
+    /// drop(permit);
+    /// assert_eq!(semaphore.available_permits(), 1);
+    /// # });
+    /// ```
+    pub fn acquire_owned(self: Arc<Self>, n: usize) -> OwnedSemaphoreAcquireFuture {
+        OwnedSemaphoreAcquireFuture {
+            semaphore: Some(self),
+            permits: n,
+            waiter: WaiterNode::new(Waiter::Idle),
+            is_waiting: false,
+        }
+    }
+
+    fn try_take(&self, n: usize) -> bool {
+        let mut state = self.state.lock().unwrap();
+        if state.permits < n || !state.waiters.is_empty() {
+            return false;
+        }
+        state.permits -= n;
+        true
+    }
+
+    // Acquires `n` permits, or registers the task to be woken once they were
+    // handed to it.
+    //
+    // Safety: the waiter must be removed with `cancel` before it is dropped,
+    // if this returns `Pending`.
+    unsafe fn poll_acquire(
+        &self,
+        n: usize,
+        waiter: Pin<&WaiterNode<Waiter>>,
+        is_waiting: &mut bool,
+        cx: &mut Context<'_>,
+    ) -> Poll<()> {
+        let mut state = self.state.lock().unwrap();
+        let state = &mut *state;
+        if !*is_waiting {
+            if state.permits >= n && state.waiters.is_empty() {
+                state.permits -= n;
+                return Poll::Ready(());
+            }
+            *state.waiters.get(&waiter) = Waiter::Waiting { needed: n, waker: cx.waker().clone() };
+            state.waiters.push_back(waiter);
+            *is_waiting = true;
+            return Poll::Pending;
+        }
+
+        let waiter = state.waiters.get(&waiter);
+        match waiter {
+            Waiter::Waiting { waker, .. } => {
+                if !waker.will_wake(cx.waker()) {
+                    *waker = cx.waker().clone();
+                }
+                Poll::Pending
+            }
+            _ => {
+                *waiter = Waiter::Idle;
+                *is_waiting = false;
+                Poll::Ready(())
+            }
+        }
+    }
+
+    // Stops waiting for permits, returning them if they were already handed
+    // to us, or letting the next waiters acquire theirs if we were first.
+    fn cancel(&self, n: usize, waiter: &WaiterNode<Waiter>) {
+        let mut state = self.state.lock().unwrap();
+        match mem::replace(unsafe { state.waiters.get(waiter) }, Waiter::Idle) {
+            Waiter::Idle => {}
+            Waiter::Waiting { .. } => {
+                unsafe { state.waiters.remove(waiter) };
+            }
+            Waiter::Acquired => state.permits += n,
+        }
+        state.hand_off();
+    }
+}
+
+impl fmt::Debug for Semaphore {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let state = self.state.lock().unwrap();
+        f.debug_struct("Semaphore")
+            .field("permits", &state.permits)
+            .field("has_waiters", &!state.waiters.is_empty())
+            .finish()
+    }
+}
+
+/// A future which resolves when the permits have been successfully acquired,
+/// returned by [`Semaphore::acquire`].
+pub struct SemaphoreAcquireFuture<'a> {
+    // `None` indicates that the permits were successfully acquired.
+    semaphore: Option<&'a Semaphore>,
+    permits: usize,
+    // Linked into the waiters of the semaphore while waiting, which is why
+    // this future is `!Unpin`.
+    waiter: WaiterNode<Waiter>,
+    // Whether the waiter may be in another state than `Waiter::Idle`.
+    is_waiting: bool,
+}
+
+/// A future which resolves when the permits have been successfully acquired,
+/// returned by [`Semaphore::acquire_owned`].
+pub struct OwnedSemaphoreAcquireFuture {
+    // `None` indicates that the permits were successfully acquired.
+    semaphore: Option<Arc<Semaphore>>,
+    permits: usize,
+    // Linked into the waiters of the semaphore while waiting, which is why
+    // this future is `!Unpin`.
+    waiter: WaiterNode<Waiter>,
+    // Whether the waiter may be in another state than `Waiter::Idle`.
+    is_waiting: bool,
+}
+
+impl fmt::Debug for SemaphoreAcquireFuture<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SemaphoreAcquireFuture")
+            .field("was_acquired", &self.semaphore.is_none())
+            .field("semaphore", &self.semaphore)
+            .field("permits", &self.permits)
+            .finish()
+    }
+}
+
+impl fmt::Debug for OwnedSemaphoreAcquireFuture {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OwnedSemaphoreAcquireFuture")
+            .field("was_acquired", &self.semaphore.is_none())
+            .field("semaphore", &self.semaphore)
+            .field("permits", &self.permits)
+            .finish()
+    }
+}
+
+impl FusedFuture for SemaphoreAcquireFuture<'_> {
+    fn is_terminated(&self) -> bool {
+        self.semaphore.is_none()
+    }
+}
+
+impl FusedFuture for OwnedSemaphoreAcquireFuture {
+    fn is_terminated(&self) -> bool {
+        self.semaphore.is_none()
+    }
+}
+
+impl<'a> Future for SemaphoreAcquireFuture<'a> {
+    type Output = SemaphorePermit<'a>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        // Safety: the waiter is never moved out of the future, and cancelled
+        // when it is dropped.
+        let this = unsafe { self.get_unchecked_mut() };
+        let semaphore = this.semaphore.expect("polled SemaphoreAcquireFuture after completion");
+        let waiter = unsafe { Pin::new_unchecked(&this.waiter) };
+        futures_core::ready!(unsafe {
+            semaphore.poll_acquire(this.permits, waiter, &mut this.is_waiting, cx)
+        });
+        this.semaphore = None;
+        Poll::Ready(SemaphorePermit { semaphore, permits: this.permits })
+    }
+}
+
+impl Future for OwnedSemaphoreAcquireFuture {
+    type Output = OwnedSemaphorePermit;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        // Safety: the waiter is never moved out of the future, and cancelled
+        // when it is dropped.
+        let this = unsafe { self.get_unchecked_mut() };
+        let semaphore =
+            this.semaphore.as_ref().expect("polled OwnedSemaphoreAcquireFuture after completion");
+        let waiter = unsafe { Pin::new_unchecked(&this.waiter) };
+        futures_core::ready!(unsafe {
+            semaphore.poll_acquire(this.permits, waiter, &mut this.is_waiting, cx)
+        });
+        let semaphore = this.semaphore.take().unwrap();
+        Poll::Ready(OwnedSemaphorePermit { semaphore, permits: this.permits })
+    }
+}
+
+impl Drop for SemaphoreAcquireFuture<'_> {
+    fn drop(&mut self) {
+        if let (Some(semaphore), true) = (self.semaphore, self.is_waiting) {
+            // This future was dropped before it acquired the permits.
+            semaphore.cancel(self.permits, &self.waiter);
+        }
+    }
+}
+
+impl Drop for OwnedSemaphoreAcquireFuture {
+    fn drop(&mut self) {
+        if let (Some(semaphore), true) = (&self.semaphore, self.is_waiting) {
+            // This future was dropped before it acquired the permits.
+            semaphore.cancel(self.permits, &self.waiter);
+        }
+    }
+}
+
+/// An RAII guard holding permits acquired from a [`Semaphore`].
+/// When this structure is dropped (falls out of scope), the permits will be
+/// released.
+pub struct SemaphorePermit<'a> {
+    semaphore: &'a Semaphore,
+    permits: usize,
+}
+
+/// An RAII guard holding permits acquired from a [`Semaphore`] through an
+/// `Arc`. When this structure is dropped (falls out of scope), the permits
+/// will be released.
+pub struct OwnedSemaphorePermit {
+    semaphore: Arc<Semaphore>,
+    permits: usize,
+}
+
+impl SemaphorePermit<'_> {
+    /// Returns the number of permits held.
+    pub fn permits(&self) -> usize {
+        self.permits
+    }
+
+    /// Forgets the permits without releasing them, permanently removing them
+    /// from the semaphore.
+    pub fn forget(mut self) {
+        self.permits = 0;
+    }
+}
+
+impl OwnedSemaphorePermit {
+    /// Returns the number of permits held.
+    pub fn permits(&self) -> usize {
+        self.permits
+    }
+
+    /// Forgets the permits without releasing them, permanently removing them
+    /// from the semaphore.
+    pub fn forget(mut self) {
+        self.permits = 0;
+    }
+}
+
+impl fmt::Debug for SemaphorePermit<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SemaphorePermit").field("permits", &self.permits).finish()
+    }
+}
+
+impl fmt::Debug for OwnedSemaphorePermit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OwnedSemaphorePermit").field("permits", &self.permits).finish()
+    }
+}
+
+impl Drop for SemaphorePermit<'_> {
+    fn drop(&mut self) {
+        let permits = mem::replace(&mut self.permits, 0);
+        if permits > 0 {
+            self.semaphore.add_permits(permits);
+        }
+    }
+}
+
+impl Drop for OwnedSemaphorePermit {
+    fn drop(&mut self) {
+        let permits = mem::replace(&mut self.permits, 0);
+        if permits > 0 {
+            self.semaphore.add_permits(permits);
+        }
+    }
+}
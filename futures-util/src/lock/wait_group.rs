@@ -0,0 +1,189 @@
+use super::waiter_list::{WaiterList, WaiterNode};
+use futures_core::future::{FusedFuture, Future};
+use futures_core::task::{Context, Poll, Waker};
+use std::fmt;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex as StdMutex};
+
+/// A futures-aware wait group, waiting for a set of operations to complete.
+///
+/// Each clone of a `WaitGroup` is held by an operation in flight, and dropped
+/// once it completes. [`wait`](WaitGroup::wait) resolves once all the other
+/// clones were dropped.
+///
+/// # Examples
+///
+/// ```
+/// # futures::executor::block_on(async {
+/// use futures::executor::ThreadPool;
+/// use futures::lock::WaitGroup;
+///
+/// let pool = ThreadPool::new().unwrap();
+/// let wait_group = WaitGroup::new();
+///
+/// for _ in 0..4 {
+///     let wait_group = wait_group.clone();
+///     pool.spawn_ok(async move {
+///         // ...
+///         drop(wait_group);
+///     });
+/// }
+///
+/// // Wait for the spawned tasks to complete.
+/// wait_group.wait().await;
+/// # });
+/// ```
+pub struct WaitGroup {
+    inner: Arc<Inner>,
+}
+
+struct Inner {
+    // The number of `WaitGroup`s alive.
+    count: AtomicUsize,
+    // The wakers of the waiting futures. Only the `Some` ones are linked
+    // into the list.
+    waiters: StdMutex<WaiterList<Option<Waker>>>,
+}
+
+impl Inner {
+    fn release(&self) {
+        if self.count.fetch_sub(1, Ordering::AcqRel) == 1 {
+            let mut waiters = self.waiters.lock().unwrap();
+            while let Some(waiter) = waiters.pop_front() {
+                if let Some(waker) = waiter.take() {
+                    waker.wake();
+                }
+            }
+        }
+    }
+}
+
+impl WaitGroup {
+    /// Creates a new wait group, of which this is the only handle.
+    pub fn new() -> Self {
+        Self {
+            inner: Arc::new(Inner {
+                count: AtomicUsize::new(1),
+                waiters: StdMutex::new(WaiterList::new()),
+            }),
+        }
+    }
+
+    /// Returns the number of handles to this wait group, including this one.
+    pub fn count(&self) -> usize {
+        self.inner.count.load(Ordering::Acquire)
+    }
+
+    /// Consumes this handle, returning a future which resolves once all the
+    /// other handles were dropped.
+    pub fn wait(self) -> WaitGroupFuture {
+        let inner = self.inner.clone();
+        drop(self);
+        WaitGroupFuture { inner: Some(inner), waiter: WaiterNode::new(None), is_waiting: false }
+    }
+}
+
+impl Clone for WaitGroup {
+    fn clone(&self) -> Self {
+        self.inner.count.fetch_add(1, Ordering::Relaxed);
+        Self { inner: self.inner.clone() }
+    }
+}
+
+impl Drop for WaitGroup {
+    fn drop(&mut self) {
+        self.inner.release();
+    }
+}
+
+impl Default for WaitGroup {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for WaitGroup {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WaitGroup").field("count", &self.count()).finish()
+    }
+}
+
+/// A future which resolves once all the handles to a wait group were dropped,
+/// returned by [`WaitGroup::wait`].
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct WaitGroupFuture {
+    // `None` indicates that the handles were all dropped.
+    inner: Option<Arc<Inner>>,
+    // Linked into the waiters of the wait group while waiting, which is why
+    // this future is `!Unpin`.
+    waiter: WaiterNode<Option<Waker>>,
+    // Whether the waiter may be linked.
+    is_waiting: bool,
+}
+
+impl WaitGroupFuture {
+    fn remove_waiter(&mut self) {
+        if let Some(inner) = &self.inner {
+            if self.is_waiting {
+                unsafe { inner.waiters.lock().unwrap().remove(&self.waiter) };
+                self.is_waiting = false;
+            }
+        }
+    }
+}
+
+impl fmt::Debug for WaitGroupFuture {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WaitGroupFuture")
+            .field("count", &self.inner.as_ref().map(|inner| inner.count.load(Ordering::Acquire)))
+            .finish()
+    }
+}
+
+impl FusedFuture for WaitGroupFuture {
+    fn is_terminated(&self) -> bool {
+        self.inner.is_none()
+    }
+}
+
+impl Future for WaitGroupFuture {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        // Safety: the waiter is never moved out of the future.
+        let this = unsafe { self.get_unchecked_mut() };
+        let inner = this.inner.as_ref().expect("polled WaitGroupFuture after completion");
+        if inner.count.load(Ordering::Acquire) != 0 {
+            let mut waiters = inner.waiters.lock().unwrap();
+            // Check again now that the last handle can't miss our waker.
+            if inner.count.load(Ordering::Acquire) != 0 {
+                let waiter = unsafe { waiters.get(&this.waiter) };
+                match waiter {
+                    Some(waker) => {
+                        if !waker.will_wake(cx.waker()) {
+                            *waker = cx.waker().clone();
+                        }
+                    }
+                    None => {
+                        *waiter = Some(cx.waker().clone());
+                        // Safety: the waiter is pinned, and removed from the
+                        // list before the future is dropped.
+                        unsafe { waiters.push_back(Pin::new_unchecked(&this.waiter)) };
+                        this.is_waiting = true;
+                    }
+                }
+                return Poll::Pending;
+            }
+        }
+        this.remove_waiter();
+        this.inner = None;
+        Poll::Ready(())
+    }
+}
+
+impl Drop for WaitGroupFuture {
+    fn drop(&mut self) {
+        self.remove_waiter();
+    }
+}
@@ -0,0 +1,165 @@
+use std::cell::UnsafeCell;
+use std::marker::{PhantomData, PhantomPinned};
+use std::pin::Pin;
+use std::ptr::NonNull;
+
+/// An intrusive doubly linked list of the tasks waiting on a lock primitive.
+///
+/// The nodes of the list live in the pinned futures waiting on the primitive,
+/// so that waiting doesn't require any allocation. The list and its nodes are
+/// only ever accessed while holding the lock protecting the list, which is
+/// what makes borrowing it mutably enough to access the nodes linked into it.
+pub(super) struct WaiterList<T> {
+    head: Option<NonNull<WaiterNode<T>>>,
+    tail: Option<NonNull<WaiterNode<T>>>,
+}
+
+/// A node of a [`WaiterList`], holding the state of a waiting task.
+pub(super) struct WaiterNode<T> {
+    links: UnsafeCell<Links<T>>,
+    value: UnsafeCell<T>,
+    _pinned: PhantomPinned,
+}
+
+/// An iterator over the values of the nodes of a [`WaiterList`], in order.
+pub(super) struct IterMut<'a, T> {
+    next: Option<NonNull<WaiterNode<T>>>,
+    _marker: PhantomData<&'a mut WaiterList<T>>,
+}
+
+struct Links<T> {
+    prev: Option<NonNull<WaiterNode<T>>>,
+    next: Option<NonNull<WaiterNode<T>>>,
+    linked: bool,
+}
+
+impl<T> WaiterList<T> {
+    pub(super) fn new() -> Self {
+        Self { head: None, tail: None }
+    }
+
+    pub(super) fn is_empty(&self) -> bool {
+        self.head.is_none()
+    }
+
+    /// Links `node` at the back of the list.
+    ///
+    /// # Safety
+    ///
+    /// The node must not be linked into any list, and must be removed from
+    /// this one before it is dropped.
+    pub(super) unsafe fn push_back(&mut self, node: Pin<&WaiterNode<T>>) {
+        let ptr = NonNull::from(&*node);
+        let links = &mut *node.links.get();
+        debug_assert!(!links.linked);
+        links.prev = self.tail;
+        links.next = None;
+        links.linked = true;
+        match self.tail {
+            Some(tail) => (*tail.as_ref().links.get()).next = Some(ptr),
+            None => self.head = Some(ptr),
+        }
+        self.tail = Some(ptr);
+    }
+
+    /// Returns the value of the first node of the list.
+    pub(super) fn front(&mut self) -> Option<&mut T> {
+        let head = self.head?;
+        // Safety: the nodes linked into the list are alive, and only accessed
+        // through it.
+        unsafe { Some(&mut *head.as_ref().value.get()) }
+    }
+
+    /// Returns an iterator over the values of the nodes of the list.
+    pub(super) fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut { next: self.head, _marker: PhantomData }
+    }
+
+    /// Unlinks the first node of the list, returning its value.
+    pub(super) fn pop_front(&mut self) -> Option<&mut T> {
+        let head = self.head?;
+        // Safety: the nodes linked into the list are alive, and only accessed
+        // through it.
+        unsafe {
+            let node = &*head.as_ptr();
+            self.unlink(node);
+            Some(&mut *node.value.get())
+        }
+    }
+
+    /// Unlinks `node` if it is linked, returning whether it was.
+    ///
+    /// # Safety
+    ///
+    /// The node must not be linked into another list.
+    pub(super) unsafe fn remove(&mut self, node: &WaiterNode<T>) -> bool {
+        if !(*node.links.get()).linked {
+            return false;
+        }
+        self.unlink(node);
+        true
+    }
+
+    /// Returns the value of `node`, whether it is linked or not.
+    ///
+    /// # Safety
+    ///
+    /// The node must only ever be linked into this list, so that borrowing
+    /// the list guarantees that the value isn't accessed concurrently.
+    pub(super) unsafe fn get<'a>(&'a mut self, node: &'a WaiterNode<T>) -> &'a mut T {
+        &mut *node.value.get()
+    }
+
+    unsafe fn unlink(&mut self, node: &WaiterNode<T>) {
+        let links = &mut *node.links.get();
+        match links.prev {
+            Some(prev) => (*prev.as_ref().links.get()).next = links.next,
+            None => self.head = links.next,
+        }
+        match links.next {
+            Some(next) => (*next.as_ref().links.get()).prev = links.prev,
+            None => self.tail = links.prev,
+        }
+        links.prev = None;
+        links.next = None;
+        links.linked = false;
+    }
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<&'a mut T> {
+        let node = self.next?;
+        // Safety: the nodes linked into the list are alive, and only accessed
+        // through it, which is borrowed by this iterator.
+        unsafe {
+            let node = &*node.as_ptr();
+            self.next = (*node.links.get()).next;
+            Some(&mut *node.value.get())
+        }
+    }
+}
+
+impl<T> WaiterNode<T> {
+    pub(super) fn new(value: T) -> Self {
+        Self {
+            links: UnsafeCell::new(Links { prev: None, next: None, linked: false }),
+            value: UnsafeCell::new(value),
+            _pinned: PhantomPinned,
+        }
+    }
+}
+
+impl<T> Drop for WaiterNode<T> {
+    fn drop(&mut self) {
+        debug_assert!(!unsafe { &*self.links.get() }.linked, "dropped a linked waiter");
+    }
+}
+
+// The list and its nodes are only accessed while holding the lock protecting
+// the list, so they can be sent and shared between threads as long as the
+// values of the nodes can be sent.
+unsafe impl<T: Send> Send for WaiterList<T> {}
+unsafe impl<T: Send> Send for WaiterNode<T> {}
+unsafe impl<T: Send> Sync for WaiterNode<T> {}
@@ -1,4 +1,5 @@
 use alloc::collections::VecDeque;
+use core::fmt;
 use core::pin::Pin;
 use futures_core::ready;
 use futures_core::stream::{FusedStream, Stream};
@@ -6,23 +7,123 @@ use futures_core::task::{Context, Poll};
 use futures_sink::Sink;
 use pin_project_lite::pin_project;
 
+/// A threshold crossed by the occupancy of a [`Buffer`], as reported to the
+/// callback registered with [`Buffer::on_threshold`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BufferLevel {
+    /// The number of buffered items rose to the high threshold.
+    High,
+    /// The number of buffered items fell back to the low threshold.
+    Low,
+}
+
+#[derive(Debug)]
+struct Thresholds {
+    low: usize,
+    high: usize,
+    above: bool,
+}
+
 pin_project! {
     /// Sink for the [`buffer`](super::SinkExt::buffer) method.
-    #[derive(Debug)]
     #[must_use = "sinks do nothing unless polled"]
-    pub struct Buffer<Si, Item> {
+    pub struct Buffer<Si, Item, F = fn(BufferLevel)> {
         #[pin]
         sink: Si,
         buf: VecDeque<Item>,
 
         // Track capacity separately from the `VecDeque`, which may be rounded up
         capacity: usize,
+        high_water_mark: usize,
+        thresholds: Option<Thresholds>,
+        callback: Option<F>,
+    }
+}
+
+impl<Si, Item, F> fmt::Debug for Buffer<Si, Item, F>
+where
+    Si: fmt::Debug,
+    Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Buffer")
+            .field("sink", &self.sink)
+            .field("buf", &self.buf)
+            .field("capacity", &self.capacity)
+            .field("high_water_mark", &self.high_water_mark)
+            .field("thresholds", &self.thresholds)
+            .finish()
     }
 }
 
 impl<Si: Sink<Item>, Item> Buffer<Si, Item> {
     pub(super) fn new(sink: Si, capacity: usize) -> Self {
-        Self { sink, buf: VecDeque::with_capacity(capacity), capacity }
+        Self {
+            sink,
+            buf: VecDeque::with_capacity(capacity),
+            capacity,
+            high_water_mark: 0,
+            thresholds: None,
+            callback: None,
+        }
+    }
+}
+
+impl<Si: Sink<Item>, Item, F: FnMut(BufferLevel)> Buffer<Si, Item, F> {
+    /// Calls `f` whenever the number of buffered items crosses one of two
+    /// thresholds.
+    ///
+    /// `f` is called with [`BufferLevel::High`] once the buffer holds `high`
+    /// items, and then with [`BufferLevel::Low`] once it drains back down to
+    /// `low` items, after which it can report `High` again. This lets an
+    /// application react to sustained backpressure, for example by shedding
+    /// load, without being notified for every item.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `low` is not less than `high`, or if `high` is greater than
+    /// the capacity of the buffer.
+    pub fn on_threshold<G>(self, low: usize, high: usize, f: G) -> Buffer<Si, Item, G>
+    where
+        G: FnMut(BufferLevel),
+    {
+        assert!(low < high, "low threshold must be less than the high threshold");
+        assert!(high <= self.capacity, "high threshold must not exceed the capacity");
+        Buffer {
+            sink: self.sink,
+            buf: self.buf,
+            capacity: self.capacity,
+            high_water_mark: self.high_water_mark,
+            thresholds: Some(Thresholds { low, high, above: false }),
+            callback: Some(f),
+        }
+    }
+
+    /// Returns the number of items currently held in the buffer.
+    pub fn len(&self) -> usize {
+        self.buf.len()
+    }
+
+    /// Returns `true` if the buffer holds no items.
+    pub fn is_empty(&self) -> bool {
+        self.buf.is_empty()
+    }
+
+    /// Returns the number of items the buffer can hold.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the largest number of items the buffer has held at once since
+    /// it was created or since the last call to
+    /// [`reset_high_water_mark`](Buffer::reset_high_water_mark).
+    pub fn high_water_mark(&self) -> usize {
+        self.high_water_mark
+    }
+
+    /// Resets the high-water mark to the current number of buffered items.
+    pub fn reset_high_water_mark(&mut self) {
+        self.high_water_mark = self.buf.len();
     }
 
     delegate_access_inner!(sink, Si, ());
@@ -31,6 +132,13 @@ impl<Si: Sink<Item>, Item> Buffer<Si, Item> {
         let mut this = self.project();
         ready!(this.sink.as_mut().poll_ready(cx))?;
         while let Some(item) = this.buf.pop_front() {
+            if let (Some(thresholds), Some(callback)) = (&mut *this.thresholds, &mut *this.callback)
+            {
+                if thresholds.above && this.buf.len() <= thresholds.low {
+                    thresholds.above = false;
+                    callback(BufferLevel::Low);
+                }
+            }
             this.sink.as_mut().start_send(item)?;
             if !this.buf.is_empty() {
                 ready!(this.sink.as_mut().poll_ready(cx))?;
@@ -41,7 +149,7 @@ impl<Si: Sink<Item>, Item> Buffer<Si, Item> {
 }
 
 // Forwarding impl of Stream from the underlying sink
-impl<S, Item> Stream for Buffer<S, Item>
+impl<S, Item, F> Stream for Buffer<S, Item, F>
 where
     S: Sink<Item> + Stream,
 {
@@ -56,7 +164,7 @@ where
     }
 }
 
-impl<S, Item> FusedStream for Buffer<S, Item>
+impl<S, Item, F> FusedStream for Buffer<S, Item, F>
 where
     S: Sink<Item> + FusedStream,
 {
@@ -65,7 +173,7 @@ where
     }
 }
 
-impl<Si: Sink<Item>, Item> Sink<Item> for Buffer<Si, Item> {
+impl<Si: Sink<Item>, Item, F: FnMut(BufferLevel)> Sink<Item> for Buffer<Si, Item, F> {
     type Error = Si::Error;
 
     fn poll_ready(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
@@ -84,11 +192,22 @@ impl<Si: Sink<Item>, Item> Sink<Item> for Buffer<Si, Item> {
 
     fn start_send(self: Pin<&mut Self>, item: Item) -> Result<(), Self::Error> {
         if self.capacity == 0 {
-            self.project().sink.start_send(item)
-        } else {
-            self.project().buf.push_back(item);
-            Ok(())
+            return self.project().sink.start_send(item);
+        }
+
+        let this = self.project();
+        this.buf.push_back(item);
+        let len = this.buf.len();
+        if len > *this.high_water_mark {
+            *this.high_water_mark = len;
+        }
+        if let (Some(thresholds), Some(callback)) = (this.thresholds, this.callback) {
+            if !thresholds.above && len >= thresholds.high {
+                thresholds.above = true;
+                callback(BufferLevel::High);
+            }
         }
+        Ok(())
     }
 
     fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
@@ -0,0 +1,173 @@
+use alloc::collections::VecDeque;
+use core::fmt;
+use core::pin::Pin;
+use futures_core::future::Future;
+use futures_core::ready;
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Context, Poll};
+use futures_sink::Sink;
+use pin_project_lite::pin_project;
+
+pin_project! {
+    /// Sink for the [`buffer_linger`](super::SinkExt::buffer_linger) method.
+    #[must_use = "sinks do nothing unless polled"]
+    pub struct BufferLinger<Si, Item, F, Fut> {
+        #[pin]
+        sink: Si,
+        buf: VecDeque<Item>,
+
+        // Track capacity separately from the `VecDeque`, which may be rounded up
+        capacity: usize,
+        delay: F,
+        #[pin]
+        deadline: Option<Fut>,
+        flushing: bool,
+    }
+}
+
+impl<Si, Item, F, Fut> fmt::Debug for BufferLinger<Si, Item, F, Fut>
+where
+    Si: fmt::Debug,
+    Item: fmt::Debug,
+    Fut: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BufferLinger")
+            .field("sink", &self.sink)
+            .field("buf", &self.buf)
+            .field("capacity", &self.capacity)
+            .field("deadline", &self.deadline)
+            .field("flushing", &self.flushing)
+            .finish()
+    }
+}
+
+impl<Si, Item, F, Fut> BufferLinger<Si, Item, F, Fut>
+where
+    Si: Sink<Item>,
+    F: FnMut() -> Fut,
+    Fut: Future,
+{
+    pub(super) fn new(sink: Si, capacity: usize, delay: F) -> Self {
+        Self {
+            sink,
+            buf: VecDeque::with_capacity(capacity),
+            capacity,
+            delay,
+            deadline: None,
+            flushing: false,
+        }
+    }
+
+    delegate_access_inner!(sink, Si, ());
+
+    fn try_empty_buffer(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Si::Error>> {
+        let mut this = self.project();
+        ready!(this.sink.as_mut().poll_ready(cx))?;
+        while let Some(item) = this.buf.pop_front() {
+            this.sink.as_mut().start_send(item)?;
+            if !this.buf.is_empty() {
+                ready!(this.sink.as_mut().poll_ready(cx))?;
+            }
+        }
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_flush_all(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Result<(), Si::Error>> {
+        ready!(self.as_mut().try_empty_buffer(cx))?;
+        debug_assert!(self.buf.is_empty());
+        let mut this = self.project();
+        ready!(this.sink.poll_flush(cx))?;
+        this.deadline.set(None);
+        *this.flushing = false;
+        Poll::Ready(Ok(()))
+    }
+}
+
+// Forwarding impl of Stream from the underlying sink
+impl<S, Item, F, Fut> Stream for BufferLinger<S, Item, F, Fut>
+where
+    S: Sink<Item> + Stream,
+{
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<S::Item>> {
+        self.project().sink.poll_next(cx)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.sink.size_hint()
+    }
+}
+
+impl<S, Item, F, Fut> FusedStream for BufferLinger<S, Item, F, Fut>
+where
+    S: Sink<Item> + FusedStream,
+{
+    fn is_terminated(&self) -> bool {
+        self.sink.is_terminated()
+    }
+}
+
+impl<Si, Item, F, Fut> Sink<Item> for BufferLinger<Si, Item, F, Fut>
+where
+    Si: Sink<Item>,
+    F: FnMut() -> Fut,
+    Fut: Future,
+{
+    type Error = Si::Error;
+
+    fn poll_ready(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        // Once the oldest unflushed item has lingered for long enough, flush
+        // everything before accepting more.
+        if let Some(deadline) = self.as_mut().project().deadline.as_pin_mut() {
+            if deadline.poll(cx).is_ready() {
+                *self.as_mut().project().flushing = true;
+            }
+        }
+        if self.flushing {
+            ready!(self.as_mut().poll_flush_all(cx))?;
+        }
+
+        if self.capacity == 0 {
+            return self.project().sink.poll_ready(cx);
+        }
+
+        let _ = self.as_mut().try_empty_buffer(cx)?;
+
+        if self.buf.len() >= self.capacity {
+            Poll::Pending
+        } else {
+            Poll::Ready(Ok(()))
+        }
+    }
+
+    fn start_send(self: Pin<&mut Self>, item: Item) -> Result<(), Self::Error> {
+        let mut this = self.project();
+        if this.deadline.is_none() {
+            this.deadline.set(Some((this.delay)()));
+        }
+        if *this.capacity == 0 {
+            this.sink.start_send(item)
+        } else {
+            this.buf.push_back(item);
+            Ok(())
+        }
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.poll_flush_all(cx)
+    }
+
+    fn poll_close(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        ready!(self.as_mut().try_empty_buffer(cx))?;
+        debug_assert!(self.buf.is_empty());
+        let mut this = self.project();
+        this.deadline.set(None);
+        *this.flushing = false;
+        this.sink.poll_close(cx)
+    }
+}
@@ -0,0 +1,195 @@
+//! A keyed set of sinks
+
+use alloc::vec::Vec;
+use core::fmt::{self, Debug};
+use core::pin::Pin;
+use futures_core::ready;
+use futures_core::task::{Context, Poll};
+use futures_sink::Sink;
+
+/// Sink that routes each `(key, item)` pair to the sink registered under the
+/// key.
+///
+/// This mirrors [`StreamGroup`](crate::stream::StreamGroup) on the write side.
+/// Sinks can be registered up front with [`insert`](Demux::insert), and the
+/// factory passed to [`new`](Demux::new) creates one the first time an item
+/// is sent for a key that has no sink.
+///
+/// Backpressure is tracked per key: an item handed to `start_send` waits
+/// until the sink for its key is ready, and only that delays the next call
+/// to `poll_ready`. Items for other keys are not held up by a sink that
+/// nothing is being sent to. Flushing and closing apply to every sink in the
+/// set.
+///
+/// Looking up a key is a linear scan over the sinks in the set.
+///
+/// # Examples
+///
+/// ```
+/// # futures::executor::block_on(async {
+/// use futures::sink::{Demux, SinkExt};
+///
+/// let mut demux = Demux::new(|_key: &&str| Vec::new());
+/// demux.send(("a", 1)).await.unwrap();
+/// demux.send(("b", 2)).await.unwrap();
+/// demux.send(("a", 3)).await.unwrap();
+///
+/// assert_eq!(demux.get(&"a"), Some(&vec![1, 3]));
+/// assert_eq!(demux.get(&"b"), Some(&vec![2]));
+/// # });
+/// ```
+#[must_use = "sinks do nothing unless polled"]
+pub struct Demux<K, Si, Item, F> {
+    sinks: Vec<(K, Si)>,
+    factory: F,
+    pending: Option<(K, Item)>,
+}
+
+// Pinning is never projected to children
+impl<K, Si, Item, F> Unpin for Demux<K, Si, Item, F> {}
+
+impl<K: Debug, Si: Debug, Item, F> Debug for Demux<K, Si, Item, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Demux")
+            .field("sinks", &self.sinks)
+            .field("pending", &self.pending.as_ref().map(|(key, _)| key))
+            .finish()
+    }
+}
+
+impl<K, Si, Item, F> Demux<K, Si, Item, F>
+where
+    K: Clone + Eq,
+    F: FnMut(&K) -> Si,
+{
+    /// Constructs a new, empty `Demux` which calls `factory` to create the
+    /// sink for a key it has not seen yet.
+    pub fn new(factory: F) -> Self {
+        Self { sinks: Vec::new(), factory, pending: None }
+    }
+
+    /// Returns the number of sinks contained in the set.
+    pub fn len(&self) -> usize {
+        self.sinks.len()
+    }
+
+    /// Returns `true` if the set contains no sinks.
+    pub fn is_empty(&self) -> bool {
+        self.sinks.is_empty()
+    }
+
+    /// Returns `true` if a sink is registered under `key`.
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.sinks.iter().any(|(k, _)| k == key)
+    }
+
+    /// Get a shared reference to the sink registered under `key`.
+    pub fn get(&self, key: &K) -> Option<&Si> {
+        self.sinks.iter().find(|(k, _)| k == key).map(|(_, sink)| sink)
+    }
+
+    /// Get a mutable reference to the sink registered under `key`.
+    pub fn get_mut(&mut self, key: &K) -> Option<&mut Si> {
+        self.sinks.iter_mut().find(|(k, _)| k == key).map(|(_, sink)| sink)
+    }
+
+    /// Registers a sink under `key`, returning the sink previously registered
+    /// under it, if there was one.
+    pub fn insert(&mut self, key: K, sink: Si) -> Option<Si> {
+        let previous = self.remove(&key);
+        self.sinks.push((key, sink));
+        previous
+    }
+
+    /// Removes the sink registered under `key` from the set and returns it,
+    /// if there was one.
+    ///
+    /// Items that were sent to it but not flushed yet are left in the sink.
+    /// If an item for `key` is still waiting to be accepted, the next call to
+    /// `poll_ready` creates a new sink for it.
+    pub fn remove(&mut self, key: &K) -> Option<Si> {
+        let index = self.sinks.iter().position(|(k, _)| k == key)?;
+        Some(self.sinks.swap_remove(index).1)
+    }
+
+    /// Consumes this sink, returning the sinks in the set along with their
+    /// keys.
+    ///
+    /// Note that an item that has not been accepted by its sink yet is
+    /// dropped.
+    pub fn into_inner(self) -> Vec<(K, Si)> {
+        self.sinks
+    }
+
+    fn poll_pending<E>(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), E>>
+    where
+        Si: Sink<Item, Error = E> + Unpin,
+    {
+        let key = match &self.pending {
+            Some((key, _)) => key,
+            None => return Poll::Ready(Ok(())),
+        };
+        let index = match self.sinks.iter().position(|(k, _)| k == key) {
+            Some(index) => index,
+            None => {
+                let sink = (self.factory)(key);
+                self.sinks.push((key.clone(), sink));
+                self.sinks.len() - 1
+            }
+        };
+        let sink = &mut self.sinks[index].1;
+        ready!(Pin::new(&mut *sink).poll_ready(cx))?;
+        let (_, item) = self.pending.take().unwrap();
+        Poll::Ready(Pin::new(sink).start_send(item))
+    }
+}
+
+impl<K, Si, Item, F> Sink<(K, Item)> for Demux<K, Si, Item, F>
+where
+    K: Clone + Eq,
+    Si: Sink<Item> + Unpin,
+    F: FnMut(&K) -> Si,
+{
+    type Error = Si::Error;
+
+    fn poll_ready(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.get_mut().poll_pending(cx)
+    }
+
+    fn start_send(self: Pin<&mut Self>, item: (K, Item)) -> Result<(), Self::Error> {
+        let this = self.get_mut();
+        assert!(this.pending.is_none(), "start_send called without poll_ready being called first");
+        this.pending = Some(item);
+        Ok(())
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        let this = self.get_mut();
+        ready!(this.poll_pending(cx))?;
+
+        let mut all_flushed = true;
+        for (_, sink) in &mut this.sinks {
+            all_flushed &= Pin::new(sink).poll_flush(cx)?.is_ready();
+        }
+        if all_flushed {
+            Poll::Ready(Ok(()))
+        } else {
+            Poll::Pending
+        }
+    }
+
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        let this = self.get_mut();
+        ready!(this.poll_pending(cx))?;
+
+        let mut all_closed = true;
+        for (_, sink) in &mut this.sinks {
+            all_closed &= Pin::new(sink).poll_close(cx)?.is_ready();
+        }
+        if all_closed {
+            Poll::Ready(Ok(()))
+        } else {
+            Poll::Pending
+        }
+    }
+}
@@ -9,6 +9,7 @@ use futures_sink::Sink;
 #[derive(Debug)]
 #[must_use = "sinks do nothing unless polled"]
 pub struct Drain<T> {
+    count: usize,
     marker: PhantomData<T>,
 }
 
@@ -24,10 +25,18 @@ pub struct Drain<T> {
 ///
 /// let mut drain = sink::drain();
 /// drain.send(5).await?;
+/// assert_eq!(drain.count(), 1);
 /// # Ok::<(), std::convert::Infallible>(()) }).unwrap();
 /// ```
 pub fn drain<T>() -> Drain<T> {
-    assert_sink::<T, Infallible, _>(Drain { marker: PhantomData })
+    assert_sink::<T, Infallible, _>(Drain { count: 0, marker: PhantomData })
+}
+
+impl<T> Drain<T> {
+    /// Returns the number of items discarded by this sink so far.
+    pub fn count(&self) -> usize {
+        self.count
+    }
 }
 
 impl<T> Unpin for Drain<T> {}
@@ -39,7 +48,8 @@ impl<T> Sink<T> for Drain<T> {
         Poll::Ready(Ok(()))
     }
 
-    fn start_send(self: Pin<&mut Self>, _item: T) -> Result<(), Self::Error> {
+    fn start_send(mut self: Pin<&mut Self>, _item: T) -> Result<(), Self::Error> {
+        self.count += 1;
         Ok(())
     }
 
@@ -0,0 +1,258 @@
+use alloc::vec::Vec;
+use core::convert::Infallible;
+use core::pin::Pin;
+use futures_core::task::{Context, Poll};
+use futures_sink::Sink;
+
+/// How a [`FanoutSet`] treats members that are not ready to receive an item.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FanoutPolicy {
+    /// Wait until every member is ready, so that the set only processes items
+    /// as fast as its _slowest_ member. This is the default.
+    Wait,
+    /// Send each item only to the members that are ready for it; the others
+    /// miss it but stay in the set.
+    Skip,
+    /// Remove members that are not ready for an item from the set.
+    Remove,
+}
+
+impl Default for FanoutPolicy {
+    fn default() -> Self {
+        Self::Wait
+    }
+}
+
+#[derive(Debug)]
+struct Member<Si> {
+    key: usize,
+    sink: Si,
+    ready: bool,
+}
+
+/// Sink that clones incoming items and forwards them to a set of sinks which
+/// can change while it is running.
+///
+/// Members are added with [`insert`](FanoutSet::insert), which returns a key
+/// that can later be passed to [`remove`](FanoutSet::remove). A member only
+/// receives the items accepted after it has been polled by this sink's
+/// `poll_ready`, and what happens to members that are not ready at that
+/// point is decided by the set's [`FanoutPolicy`].
+///
+/// A member that fails is removed from the set and dropped, and its error is
+/// discarded, so the set itself never fails. Members which should report
+/// their errors can be wrapped, for example with
+/// [`sink_map_err`](super::SinkExt::sink_map_err).
+///
+/// # Examples
+///
+/// ```
+/// # futures::executor::block_on(async {
+/// use futures::channel::mpsc;
+/// use futures::sink::{FanoutSet, SinkExt};
+/// use futures::stream::StreamExt;
+///
+/// let (tx1, rx1) = mpsc::unbounded();
+/// let (tx2, rx2) = mpsc::unbounded();
+///
+/// let mut set = FanoutSet::new();
+/// let key1 = set.insert(tx1);
+/// set.send(1).await.unwrap();
+/// set.insert(tx2);
+/// set.send(2).await.unwrap();
+/// set.remove(key1);
+/// set.send(3).await.unwrap();
+/// drop(set);
+///
+/// assert_eq!(rx1.collect::<Vec<_>>().await, vec![1, 2]);
+/// assert_eq!(rx2.collect::<Vec<_>>().await, vec![2, 3]);
+/// # });
+/// ```
+#[derive(Debug)]
+#[must_use = "sinks do nothing unless polled"]
+pub struct FanoutSet<Si> {
+    members: Vec<Member<Si>>,
+    next_key: usize,
+    policy: FanoutPolicy,
+}
+
+impl<Si> Default for FanoutSet<Si> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Si> FanoutSet<Si> {
+    /// Creates an empty set using [`FanoutPolicy::Wait`].
+    pub fn new() -> Self {
+        Self::with_policy(FanoutPolicy::Wait)
+    }
+
+    /// Creates an empty set using the given policy for members that are not
+    /// ready.
+    pub fn with_policy(policy: FanoutPolicy) -> Self {
+        Self { members: Vec::new(), next_key: 0, policy }
+    }
+
+    /// Returns the policy used for members that are not ready.
+    pub fn policy(&self) -> FanoutPolicy {
+        self.policy
+    }
+
+    /// Changes the policy used for members that are not ready.
+    pub fn set_policy(&mut self, policy: FanoutPolicy) {
+        self.policy = policy;
+    }
+
+    /// Adds a sink to the set, returning the key that identifies it.
+    pub fn insert(&mut self, sink: Si) -> usize {
+        let key = self.next_key;
+        self.next_key += 1;
+        self.members.push(Member { key, sink, ready: false });
+        key
+    }
+
+    /// Removes the sink identified by `key` from the set and returns it, or
+    /// `None` if it has already been removed.
+    ///
+    /// Items that were sent to it but not flushed yet are left in the sink.
+    pub fn remove(&mut self, key: usize) -> Option<Si> {
+        let index = self.members.iter().position(|member| member.key == key)?;
+        Some(self.members.remove(index).sink)
+    }
+
+    /// Returns `true` if the sink identified by `key` is still in the set.
+    pub fn contains(&self, key: usize) -> bool {
+        self.members.iter().any(|member| member.key == key)
+    }
+
+    /// Get a shared reference to the sink identified by `key`.
+    pub fn get(&self, key: usize) -> Option<&Si> {
+        self.members.iter().find(|member| member.key == key).map(|member| &member.sink)
+    }
+
+    /// Get a mutable reference to the sink identified by `key`.
+    pub fn get_mut(&mut self, key: usize) -> Option<&mut Si> {
+        self.members.iter_mut().find(|member| member.key == key).map(|member| &mut member.sink)
+    }
+
+    /// Returns the number of sinks in the set.
+    pub fn len(&self) -> usize {
+        self.members.len()
+    }
+
+    /// Returns `true` if the set contains no sinks.
+    pub fn is_empty(&self) -> bool {
+        self.members.is_empty()
+    }
+
+    /// Consumes this sink, returning the members still in the set along with
+    /// their keys.
+    pub fn into_inner(self) -> Vec<(usize, Si)> {
+        self.members.into_iter().map(|member| (member.key, member.sink)).collect()
+    }
+}
+
+impl<Si: Unpin> Unpin for FanoutSet<Si> {}
+
+// `Vec::retain` only hands out shared references before Rust 1.61
+fn retain_mut<T>(vec: &mut Vec<T>, mut f: impl FnMut(&mut T) -> bool) {
+    let mut i = 0;
+    while i < vec.len() {
+        if f(&mut vec[i]) {
+            i += 1;
+        } else {
+            vec.remove(i);
+        }
+    }
+}
+
+impl<Si, Item> Sink<Item> for FanoutSet<Si>
+where
+    Si: Sink<Item> + Unpin,
+    Item: Clone,
+{
+    type Error = Infallible;
+
+    fn poll_ready(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        let this = self.get_mut();
+        let policy = this.policy;
+        let mut all_ready = true;
+
+        retain_mut(&mut this.members, |member| {
+            if member.ready {
+                return true;
+            }
+            match Pin::new(&mut member.sink).poll_ready(cx) {
+                Poll::Ready(Ok(())) => {
+                    member.ready = true;
+                    true
+                }
+                Poll::Ready(Err(_)) => false,
+                Poll::Pending => {
+                    all_ready = false;
+                    policy != FanoutPolicy::Remove
+                }
+            }
+        });
+
+        if all_ready || policy != FanoutPolicy::Wait {
+            Poll::Ready(Ok(()))
+        } else {
+            Poll::Pending
+        }
+    }
+
+    fn start_send(self: Pin<&mut Self>, item: Item) -> Result<(), Self::Error> {
+        let this = self.get_mut();
+
+        retain_mut(&mut this.members, |member| {
+            if !member.ready {
+                return true;
+            }
+            member.ready = false;
+            Pin::new(&mut member.sink).start_send(item.clone()).is_ok()
+        });
+        Ok(())
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        let this = self.get_mut();
+        let mut all_flushed = true;
+
+        retain_mut(&mut this.members, |member| match Pin::new(&mut member.sink).poll_flush(cx) {
+            Poll::Ready(Ok(())) => true,
+            Poll::Ready(Err(_)) => false,
+            Poll::Pending => {
+                all_flushed = false;
+                true
+            }
+        });
+
+        if all_flushed {
+            Poll::Ready(Ok(()))
+        } else {
+            Poll::Pending
+        }
+    }
+
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        let this = self.get_mut();
+        let mut all_closed = true;
+
+        retain_mut(&mut this.members, |member| match Pin::new(&mut member.sink).poll_close(cx) {
+            Poll::Ready(Ok(())) => true,
+            Poll::Ready(Err(_)) => false,
+            Poll::Pending => {
+                all_closed = false;
+                true
+            }
+        });
+
+        if all_closed {
+            Poll::Ready(Ok(()))
+        } else {
+            Poll::Pending
+        }
+    }
+}
@@ -0,0 +1,77 @@
+use crate::stream::{Fuse, StreamExt};
+use core::fmt;
+use core::pin::Pin;
+use futures_core::future::Future;
+use futures_core::ready;
+use futures_core::stream::Stream;
+use futures_core::task::{Context, Poll};
+use futures_sink::Sink;
+use pin_project_lite::pin_project;
+
+pin_project! {
+    /// Future for the [`feed_all`](super::SinkExt::feed_all) method.
+    #[allow(explicit_outlives_requirements)] // https://github.com/rust-lang/rust/issues/60993
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct FeedAll<'a, Si, St>
+    where
+        Si: ?Sized,
+        St: Stream,
+    {
+        sink: &'a mut Si,
+        #[pin]
+        stream: Fuse<St>,
+        buffered: Option<St::Item>,
+    }
+}
+
+impl<Si, St> fmt::Debug for FeedAll<'_, Si, St>
+where
+    Si: fmt::Debug + ?Sized,
+    St: fmt::Debug + Stream,
+    St::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FeedAll")
+            .field("sink", &self.sink)
+            .field("stream", &self.stream)
+            .field("buffered", &self.buffered)
+            .finish()
+    }
+}
+
+impl<'a, Si, St> FeedAll<'a, Si, St>
+where
+    Si: Sink<St::Item> + Unpin + ?Sized,
+    St: Stream,
+{
+    pub(super) fn new(sink: &'a mut Si, stream: St) -> Self {
+        Self { sink, stream: stream.fuse(), buffered: None }
+    }
+}
+
+impl<Si, St> Future for FeedAll<'_, Si, St>
+where
+    Si: Sink<St::Item> + Unpin + ?Sized,
+    St: Stream,
+{
+    type Output = Result<(), Si::Error>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut this = self.project();
+        let mut sink = Pin::new(&mut **this.sink);
+
+        loop {
+            // If we've got an item buffered already, we need to write it to
+            // the sink before we can do anything else
+            if this.buffered.is_some() {
+                ready!(sink.as_mut().poll_ready(cx))?;
+                sink.as_mut().start_send(this.buffered.take().unwrap())?;
+            }
+
+            match ready!(this.stream.as_mut().poll_next(cx)) {
+                Some(item) => *this.buffered = Some(item),
+                None => return Poll::Ready(Ok(())),
+            }
+        }
+    }
+}
@@ -0,0 +1,118 @@
+use super::assert_sink;
+use core::fmt;
+use core::pin::Pin;
+use futures_core::task::{Context, Poll};
+use futures_sink::Sink;
+
+/// Sink for the [`from_fn`] function.
+#[must_use = "sinks do nothing unless polled"]
+pub struct FromFn<T, R, S, Fl> {
+    state: T,
+    poll_ready: R,
+    start_send: S,
+    poll_flush: Fl,
+}
+
+impl<T, R, S, Fl> Unpin for FromFn<T, R, S, Fl> {}
+
+impl<T: fmt::Debug, R, S, Fl> fmt::Debug for FromFn<T, R, S, Fl> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FromFn").field("state", &self.state).finish()
+    }
+}
+
+/// Creates a new sink from a state and functions implementing `poll_ready`,
+/// `start_send` and `poll_flush`.
+///
+/// Each function is given mutable access to the state, so an ad-hoc sink can
+/// be written without a struct and a manual `Sink` implementation. Closing
+/// the sink flushes it. This is the sink counterpart of
+/// [`stream::poll_fn`](crate::stream::poll_fn); to write the send operation
+/// as an async function instead, see [`unfold`](super::unfold).
+///
+/// # Examples
+///
+/// ```
+/// # futures::executor::block_on(async {
+/// use futures::sink::{self, SinkExt};
+/// use futures::task::Poll;
+///
+/// // a sink batching items, which are committed once flushed
+/// let mut sink = sink::from_fn(
+///     (Vec::new(), Vec::new()),
+///     |_, _| Poll::Ready(Ok(())),
+///     |(batch, _), item: i32| {
+///         batch.push(item);
+///         Ok::<_, std::convert::Infallible>(())
+///     },
+///     |(batch, committed), _| {
+///         committed.append(batch);
+///         Poll::Ready(Ok(()))
+///     },
+/// );
+///
+/// sink.feed(1).await?;
+/// sink.feed(2).await?;
+/// assert!(sink.get_ref().1.is_empty());
+/// sink.flush().await?;
+/// assert_eq!(sink.get_ref().1, vec![1, 2]);
+/// # Ok::<(), std::convert::Infallible>(()) }).unwrap();
+/// ```
+pub fn from_fn<T, R, S, Fl, Item, E>(
+    state: T,
+    poll_ready: R,
+    start_send: S,
+    poll_flush: Fl,
+) -> FromFn<T, R, S, Fl>
+where
+    R: FnMut(&mut T, &mut Context<'_>) -> Poll<Result<(), E>>,
+    S: FnMut(&mut T, Item) -> Result<(), E>,
+    Fl: FnMut(&mut T, &mut Context<'_>) -> Poll<Result<(), E>>,
+{
+    assert_sink::<Item, E, _>(FromFn { state, poll_ready, start_send, poll_flush })
+}
+
+impl<T, R, S, Fl> FromFn<T, R, S, Fl> {
+    /// Get a shared reference to the state.
+    pub fn get_ref(&self) -> &T {
+        &self.state
+    }
+
+    /// Get a mutable reference to the state.
+    pub fn get_mut(&mut self) -> &mut T {
+        &mut self.state
+    }
+
+    /// Consumes this sink, returning the state.
+    pub fn into_inner(self) -> T {
+        self.state
+    }
+}
+
+impl<T, R, S, Fl, Item, E> Sink<Item> for FromFn<T, R, S, Fl>
+where
+    R: FnMut(&mut T, &mut Context<'_>) -> Poll<Result<(), E>>,
+    S: FnMut(&mut T, Item) -> Result<(), E>,
+    Fl: FnMut(&mut T, &mut Context<'_>) -> Poll<Result<(), E>>,
+{
+    type Error = E;
+
+    fn poll_ready(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        let this = self.get_mut();
+        (this.poll_ready)(&mut this.state, cx)
+    }
+
+    fn start_send(self: Pin<&mut Self>, item: Item) -> Result<(), Self::Error> {
+        let this = self.get_mut();
+        (this.start_send)(&mut this.state, item)
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        let this = self.get_mut();
+        (this.poll_flush)(&mut this.state, cx)
+    }
+
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.poll_flush(cx)
+    }
+}
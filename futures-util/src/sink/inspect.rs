@@ -0,0 +1,102 @@
+use super::{assert_sink, drain, Drain};
+use core::convert::Infallible;
+use core::fmt;
+use core::pin::Pin;
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Context, Poll};
+use futures_sink::Sink;
+use pin_project_lite::pin_project;
+
+pin_project! {
+    /// Sink for the [`sink_inspect`](super::SinkExt::sink_inspect) method and
+    /// the [`inspect`] function.
+    #[must_use = "sinks do nothing unless polled"]
+    pub struct SinkInspect<Si, F> {
+        #[pin]
+        sink: Si,
+        f: F,
+    }
+}
+
+impl<Si: fmt::Debug, F> fmt::Debug for SinkInspect<Si, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SinkInspect").field("sink", &self.sink).finish()
+    }
+}
+
+impl<Si, F> SinkInspect<Si, F> {
+    pub(super) fn new(sink: Si, f: F) -> Self {
+        Self { sink, f }
+    }
+
+    delegate_access_inner!(sink, Si, ());
+}
+
+/// Create a sink that calls a closure with a reference to each item, and
+/// then discards it.
+///
+/// This is [`drain`] combined with
+/// [`sink_inspect`](super::SinkExt::sink_inspect), which is useful in tests
+/// and for debugging the end of a pipeline. The number of discarded items
+/// can be read through [`get_ref`](SinkInspect::get_ref).
+///
+/// # Examples
+///
+/// ```
+/// # futures::executor::block_on(async {
+/// use futures::sink::{self, SinkExt};
+///
+/// let mut seen = Vec::new();
+/// let mut sink = sink::inspect(|item: &i32| seen.push(*item));
+/// sink.send(1).await?;
+/// sink.send(2).await?;
+/// assert_eq!(sink.get_ref().count(), 2);
+/// drop(sink);
+/// assert_eq!(seen, vec![1, 2]);
+/// # Ok::<(), std::convert::Infallible>(()) }).unwrap();
+/// ```
+pub fn inspect<T, F>(f: F) -> SinkInspect<Drain<T>, F>
+where
+    F: FnMut(&T),
+{
+    assert_sink::<T, Infallible, _>(SinkInspect::new(drain(), f))
+}
+
+impl<Si, F, Item> Sink<Item> for SinkInspect<Si, F>
+where
+    Si: Sink<Item>,
+    F: FnMut(&Item),
+{
+    type Error = Si::Error;
+
+    fn poll_ready(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.project().sink.poll_ready(cx)
+    }
+
+    fn start_send(self: Pin<&mut Self>, item: Item) -> Result<(), Self::Error> {
+        let this = self.project();
+        (this.f)(&item);
+        this.sink.start_send(item)
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.project().sink.poll_flush(cx)
+    }
+
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.project().sink.poll_close(cx)
+    }
+}
+
+// Forwarding impl of Stream from the underlying sink
+impl<S: Stream, F> Stream for SinkInspect<S, F> {
+    type Item = S::Item;
+
+    delegate_stream!(sink);
+}
+
+impl<S: FusedStream, F> FusedStream for SinkInspect<S, F> {
+    fn is_terminated(&self) -> bool {
+        self.sink.is_terminated()
+    }
+}
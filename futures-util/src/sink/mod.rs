@@ -8,8 +8,8 @@
 
 use crate::future::{assert_future, Either};
 use core::pin::Pin;
-use futures_core::future::Future;
-use futures_core::stream::Stream;
+use futures_core::future::{Future, TryFuture};
+use futures_core::stream::{Stream, TryStream};
 use futures_core::task::{Context, Poll};
 
 #[cfg(feature = "compat")]
@@ -26,12 +26,26 @@ pub use self::drain::{drain, Drain};
 mod fanout;
 pub use self::fanout::Fanout;
 
+#[cfg(feature = "alloc")]
+mod fanout_set;
+#[cfg(feature = "alloc")]
+pub use self::fanout_set::{FanoutPolicy, FanoutSet};
+
 mod feed;
 pub use self::feed::Feed;
 
+mod feed_all;
+pub use self::feed_all::FeedAll;
+
 mod flush;
 pub use self::flush::Flush;
 
+mod from_fn;
+pub use self::from_fn::{from_fn, FromFn};
+
+mod inspect;
+pub use self::inspect::{inspect, SinkInspect};
+
 mod err_into;
 pub use self::err_into::SinkErrInto;
 
@@ -44,19 +58,56 @@ pub use self::send::Send;
 mod send_all;
 pub use self::send_all::SendAll;
 
+mod send_timeout;
+pub use self::send_timeout::{FeedTimeout, SendTimeout, SendTimeoutError};
+
+#[cfg(not(futures_no_atomic_cas))]
+#[cfg(feature = "alloc")]
+mod send_all_concurrent;
+#[cfg(not(futures_no_atomic_cas))]
+#[cfg(feature = "alloc")]
+pub use self::send_all_concurrent::SendAllConcurrent;
+
 mod unfold;
 pub use self::unfold::{unfold, Unfold};
 
+mod unfold_full;
+pub use self::unfold_full::{unfold_full, UnfoldFull};
+
 mod with;
 pub use self::with::With;
 
 mod with_flat_map;
 pub use self::with_flat_map::WithFlatMap;
 
+#[cfg(feature = "channel")]
+mod write_blocking;
+#[cfg(feature = "channel")]
+#[cfg_attr(docsrs, doc(cfg(feature = "channel")))]
+pub use self::write_blocking::{write_blocking, WriteBlocking};
+
+mod try_with_flat_map;
+pub use self::try_with_flat_map::TryWithFlatMap;
+
 #[cfg(feature = "alloc")]
 mod buffer;
 #[cfg(feature = "alloc")]
-pub use self::buffer::Buffer;
+pub use self::buffer::{Buffer, BufferLevel};
+
+#[cfg(feature = "alloc")]
+mod buffer_linger;
+#[cfg(feature = "alloc")]
+pub use self::buffer_linger::BufferLinger;
+
+#[cfg(feature = "alloc")]
+mod demux;
+#[cfg(feature = "alloc")]
+pub use self::demux::Demux;
+
+#[cfg(feature = "alloc")]
+mod throttle;
+#[cfg(feature = "alloc")]
+pub use self::throttle::Throttle;
 
 impl<T: ?Sized, Item> SinkExt<Item> for T where T: Sink<Item> {}
 
@@ -125,6 +176,58 @@ pub trait SinkExt<Item>: Sink<Item> {
         assert_sink::<U, Self::Error, _>(WithFlatMap::new(self, f))
     }
 
+    /// Composes a fallible function *in front of* the sink.
+    ///
+    /// This works like [`with_flat_map`](SinkExt::with_flat_map), but the
+    /// stream produced by `f` can fail with its own error type, which the
+    /// errors of the underlying sink are converted into. The first error,
+    /// from either the stream or the sink, is returned by the adapter.
+    ///
+    /// This is useful when expanding an item involves fallible encoding.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::channel::mpsc;
+    /// use futures::sink::SinkExt;
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// #[derive(Debug)]
+    /// enum EncodeError {
+    ///     NotAscii,
+    ///     Send(mpsc::SendError),
+    /// }
+    ///
+    /// impl From<mpsc::SendError> for EncodeError {
+    ///     fn from(e: mpsc::SendError) -> Self {
+    ///         EncodeError::Send(e)
+    ///     }
+    /// }
+    ///
+    /// let (tx, rx) = mpsc::channel(5);
+    ///
+    /// let mut tx = tx.try_with_flat_map(|s: &str| {
+    ///     stream::iter(s.chars().map(|c| if c.is_ascii() { Ok(c as u8) } else { Err(EncodeError::NotAscii) }))
+    /// });
+    ///
+    /// tx.send("hi").await.unwrap();
+    /// assert!(tx.send("é").await.is_err());
+    /// drop(tx);
+    /// let received: Vec<u8> = rx.collect().await;
+    /// assert_eq!(received, b"hi");
+    /// # });
+    /// ```
+    fn try_with_flat_map<U, St, F>(self, f: F) -> TryWithFlatMap<Self, Item, U, St, F>
+    where
+        F: FnMut(U) -> St,
+        St: TryStream<Ok = Item>,
+        St::Error: From<Self::Error>,
+        Self: Sized,
+    {
+        assert_sink::<U, St::Error, _>(TryWithFlatMap::new(self, f))
+    }
+
     /*
     fn with_map<U, F>(self, f: F) -> WithMap<Self, U, F>
         where F: FnMut(U) -> Self::SinkItem,
@@ -148,6 +251,20 @@ pub trait SinkExt<Item>: Sink<Item> {
         assert_sink::<Item, E, _>(SinkMapErr::new(self, f))
     }
 
+    /// Calls a closure with a reference to each item before passing it on to
+    /// the sink.
+    ///
+    /// This is useful for debugging a pipeline or counting what goes through
+    /// it. To inspect items without forwarding them anywhere, see
+    /// [`inspect`](self::inspect()).
+    fn sink_inspect<F>(self, f: F) -> SinkInspect<Self, F>
+    where
+        F: FnMut(&Item),
+        Self: Sized,
+    {
+        assert_sink::<Item, Self::Error, _>(SinkInspect::new(self, f))
+    }
+
     /// Map this sink's error to a different error type using the `Into` trait.
     ///
     /// If wanting to map errors of a `Sink + Stream`, use `.sink_err_into().err_into()`.
@@ -179,6 +296,96 @@ pub trait SinkExt<Item>: Sink<Item> {
         assert_sink::<Item, Self::Error, _>(Buffer::new(self, capacity))
     }
 
+    /// Adds a fixed-size buffer to the current sink, and flushes it once
+    /// items have been waiting for too long.
+    ///
+    /// This behaves like [`buffer`](SinkExt::buffer), except that the first
+    /// item sent after a flush starts a deadline obtained by calling `delay`.
+    /// Once that future completes, the next call to `poll_ready` first writes
+    /// the buffered items to the underlying sink and flushes it. This bounds
+    /// the latency of batching writers under low traffic, without flushing
+    /// after every item. `delay` is typically a closure creating a timer
+    /// future of the runtime in use.
+    ///
+    /// The deadline is checked by `poll_ready`, which also registers the task
+    /// to be woken when it expires. Producers that stay idle without polling
+    /// the sink, rather than for example driving it through
+    /// [`send_all`](SinkExt::send_all), still need to flush it themselves.
+    ///
+    /// This method is only available when the `std` or `alloc` feature of this
+    /// library is activated, and it is activated by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::future;
+    /// use futures::sink::SinkExt;
+    ///
+    /// // A deadline that has already expired flushes before the next item.
+    /// let mut sink = Vec::new().buffer_linger(8, || future::ready(()));
+    /// sink.feed(1).await.unwrap();
+    /// sink.feed(2).await.unwrap();
+    /// assert_eq!(sink.get_ref(), &vec![1]);
+    /// # });
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn buffer_linger<F, Fut>(self, capacity: usize, delay: F) -> BufferLinger<Self, Item, F, Fut>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future,
+        Self: Sized,
+    {
+        assert_sink::<Item, Self::Error, _>(BufferLinger::new(self, capacity, delay))
+    }
+
+    /// Limits the rate at which items are sent to the current sink.
+    ///
+    /// The adapter holds `burst` tokens, and each item sent uses one of them.
+    /// A used token is given back once the future returned by `delay`, which
+    /// is called as the item is sent, completes. `poll_ready` waits while no
+    /// tokens are left, so at most `burst` items are sent within any period
+    /// of time measured by `delay`, and that many can be sent at once after
+    /// the sink has been idle. For example, throttling to `burst` items per
+    /// second with a one-second timer keeps a downstream API within a
+    /// documented limit of that many requests per second.
+    ///
+    /// `delay` is typically a closure creating a timer future of the runtime
+    /// in use, and every call should take the same time.
+    ///
+    /// This method is only available when the `std` or `alloc` feature of this
+    /// library is activated, and it is activated by default.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `burst` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::future;
+    /// use futures::sink::SinkExt;
+    ///
+    /// // a timer that never fires, so the tokens are never given back
+    /// let mut sink = Vec::new().throttle(2, future::pending::<()>);
+    ///
+    /// sink.send(1).await.unwrap();
+    /// sink.send(2).await.unwrap();
+    /// assert_eq!(sink.tokens(), 0);
+    /// assert!(futures::poll!(sink.send(3)).is_pending());
+    /// # });
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn throttle<F, Fut>(self, burst: usize, delay: F) -> Throttle<Self, F, Fut>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future,
+        Self: Sized,
+    {
+        assert_sink::<Item, Self::Error, _>(Throttle::new(self, burst, delay))
+    }
+
     /// Close the sink.
     fn close(&mut self) -> Close<'_, Self, Item>
     where
@@ -237,6 +444,59 @@ pub trait SinkExt<Item>: Sink<Item> {
         assert_future::<Result<(), Self::Error>, _>(Feed::new(self, item))
     }
 
+    /// Like [`feed`](SinkExt::feed), but gives up once `deadline` completes
+    /// while the sink is still not ready to accept the item.
+    ///
+    /// In that case the returned future fails with
+    /// [`SendTimeoutError::Timeout`], which hands the item back so that the
+    /// caller can drop it, retry it later or send it elsewhere, rather than
+    /// blocking forever on a stuck sink. `deadline` is typically a timer
+    /// future of the runtime in use.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::channel::mpsc;
+    /// use futures::future;
+    /// use futures::sink::SinkExt;
+    ///
+    /// let (mut tx, _rx) = mpsc::channel(0);
+    /// tx.feed_timeout(1, future::pending::<()>()).await.unwrap();
+    ///
+    /// // the channel is full and its receiver never reads from it
+    /// let err = tx.feed_timeout(2, future::ready(())).await.unwrap_err();
+    /// assert!(err.is_timeout());
+    /// assert_eq!(err.into_item(), Some(2));
+    /// # });
+    /// ```
+    fn feed_timeout<Fut>(&mut self, item: Item, deadline: Fut) -> FeedTimeout<'_, Self, Item, Fut>
+    where
+        Fut: Future,
+        Self: Unpin,
+    {
+        assert_future::<Result<(), SendTimeoutError<Item, Self::Error>>, _>(FeedTimeout::new(
+            self, item, deadline,
+        ))
+    }
+
+    /// Like [`send`](SinkExt::send), but gives up once `deadline` completes
+    /// before the item has been accepted and flushed.
+    ///
+    /// If the sink did not accept the item in time, the returned future fails
+    /// with [`SendTimeoutError::Timeout`], which hands the item back. If it
+    /// accepted it but did not finish flushing, it fails with
+    /// [`SendTimeoutError::FlushTimeout`], and the item stays in the sink.
+    fn send_timeout<Fut>(&mut self, item: Item, deadline: Fut) -> SendTimeout<'_, Self, Item, Fut>
+    where
+        Fut: Future,
+        Self: Unpin,
+    {
+        assert_future::<Result<(), SendTimeoutError<Item, Self::Error>>, _>(SendTimeout::new(
+            self, item, deadline,
+        ))
+    }
+
     /// A future that completes after the given stream has been fully processed
     /// into the sink, including flushing.
     ///
@@ -257,6 +517,96 @@ pub trait SinkExt<Item>: Sink<Item> {
         assert_future::<Result<(), Self::Error>, _>(SendAll::new(self, stream))
     }
 
+    /// A future that completes after every item of the given stream has been
+    /// received by the sink.
+    ///
+    /// Unlike [`send_all`](SinkExt::send_all), this never flushes the sink,
+    /// not even while waiting for the stream, and it takes items rather than
+    /// results. It is the caller's responsibility to flush the sink
+    /// afterwards, or to keep feeding it first. This is like calling
+    /// [`feed`](SinkExt::feed) for each item, but with a single future. Items
+    /// of an iterator can be fed through [`stream::iter`](crate::stream::iter).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::sink::SinkExt;
+    /// use futures::stream;
+    ///
+    /// let mut sink = Vec::new();
+    /// sink.feed_all(stream::iter(vec![1, 2])).await.unwrap();
+    /// sink.feed_all(stream::iter(vec![3])).await.unwrap();
+    /// sink.flush().await.unwrap();
+    /// assert_eq!(sink, vec![1, 2, 3]);
+    /// # });
+    /// ```
+    fn feed_all<St>(&mut self, stream: St) -> FeedAll<'_, Self, St>
+    where
+        St: Stream<Item = Item>,
+        Self: Unpin,
+    {
+        assert_future::<Result<(), Self::Error>, _>(FeedAll::new(self, stream))
+    }
+
+    /// A future that completes after the items produced by the futures of the
+    /// given stream have been processed into the sink, including flushing.
+    ///
+    /// Unlike [`send_all`](SinkExt::send_all), which waits for each item
+    /// before asking for the next one, this keeps up to `limit` futures from
+    /// `stream` running at the same time and sends their outputs in the
+    /// order in which they complete, as soon as the sink is ready to accept
+    /// them. A `limit` of `None` or zero places no bound on the number of
+    /// futures in flight. The sink is flushed whenever no item is ready to be
+    /// sent, and once every future has completed. Note that the sink is
+    /// **not** closed.
+    ///
+    /// If one of the futures fails, or the sink returns an error, that error
+    /// is returned by this future; the futures still in flight are dropped
+    /// along with it.
+    ///
+    /// This method is only available when the `std` or `alloc` feature of this
+    /// library is activated, and it is activated by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::channel::mpsc;
+    /// use futures::future;
+    /// use futures::sink::SinkExt;
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let (mut tx, rx) = mpsc::channel(4);
+    /// let requests = stream::iter(1..=4).map(|i| future::ok::<_, mpsc::SendError>(i * 10));
+    ///
+    /// tx.send_all_concurrent(requests, 2).await?;
+    /// drop(tx);
+    ///
+    /// let mut received = rx.collect::<Vec<_>>().await;
+    /// received.sort();
+    /// assert_eq!(received, vec![10, 20, 30, 40]);
+    /// # Ok::<(), mpsc::SendError>(()) }).unwrap();
+    /// ```
+    #[cfg(not(futures_no_atomic_cas))]
+    #[cfg(feature = "alloc")]
+    fn send_all_concurrent<St, Fut>(
+        &mut self,
+        stream: St,
+        limit: impl Into<Option<usize>>,
+    ) -> SendAllConcurrent<'_, Self, St>
+    where
+        St: Stream<Item = Fut>,
+        Fut: TryFuture<Ok = Item, Error = Self::Error>,
+        Self: Unpin,
+    {
+        assert_future::<Result<(), Self::Error>, _>(SendAllConcurrent::new(
+            self,
+            stream,
+            limit.into(),
+        ))
+    }
+
     /// Wrap this sink in an `Either` sink, making it the left-hand variant
     /// of that `Either`.
     ///
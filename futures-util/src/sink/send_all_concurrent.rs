@@ -0,0 +1,113 @@
+use crate::future::{IntoFuture, TryFutureExt};
+use crate::stream::{Fuse, FuturesUnordered, StreamExt};
+use core::fmt;
+use core::num::NonZeroUsize;
+use core::pin::Pin;
+use futures_core::future::{Future, TryFuture};
+use futures_core::ready;
+use futures_core::stream::Stream;
+use futures_core::task::{Context, Poll};
+use futures_sink::Sink;
+use pin_project_lite::pin_project;
+
+pin_project! {
+    /// Future for the [`send_all_concurrent`](super::SinkExt::send_all_concurrent) method.
+    #[allow(explicit_outlives_requirements)] // https://github.com/rust-lang/rust/issues/60993
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct SendAllConcurrent<'a, Si, St>
+    where
+        Si: ?Sized,
+        St: Stream,
+        St::Item: TryFuture,
+    {
+        sink: &'a mut Si,
+        #[pin]
+        stream: Fuse<St>,
+        in_progress: FuturesUnordered<IntoFuture<St::Item>>,
+        buffered: Option<<St::Item as TryFuture>::Ok>,
+        limit: Option<NonZeroUsize>,
+    }
+}
+
+impl<Si, St> fmt::Debug for SendAllConcurrent<'_, Si, St>
+where
+    Si: fmt::Debug + ?Sized,
+    St: fmt::Debug + Stream,
+    St::Item: TryFuture,
+    <St::Item as TryFuture>::Ok: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SendAllConcurrent")
+            .field("sink", &self.sink)
+            .field("stream", &self.stream)
+            .field("in_progress", &self.in_progress)
+            .field("buffered", &self.buffered)
+            .field("limit", &self.limit)
+            .finish()
+    }
+}
+
+impl<'a, Si, St, Fut, Item, Error> SendAllConcurrent<'a, Si, St>
+where
+    Si: Sink<Item, Error = Error> + Unpin + ?Sized,
+    St: Stream<Item = Fut>,
+    Fut: TryFuture<Ok = Item, Error = Error>,
+{
+    pub(super) fn new(sink: &'a mut Si, stream: St, limit: Option<usize>) -> Self {
+        Self {
+            sink,
+            stream: stream.fuse(),
+            in_progress: FuturesUnordered::new(),
+            buffered: None,
+            limit: limit.and_then(NonZeroUsize::new),
+        }
+    }
+}
+
+impl<Si, St, Fut, Item, Error> Future for SendAllConcurrent<'_, Si, St>
+where
+    Si: Sink<Item, Error = Error> + Unpin + ?Sized,
+    St: Stream<Item = Fut>,
+    Fut: TryFuture<Ok = Item, Error = Error>,
+{
+    type Output = Result<(), Error>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut this = self.project();
+
+        loop {
+            // Start as many items as the limit allows, so their futures make
+            // progress while the sink is busy with earlier ones.
+            while this.limit.map(|limit| this.in_progress.len() < limit.get()).unwrap_or(true) {
+                match this.stream.as_mut().poll_next(cx) {
+                    Poll::Ready(Some(fut)) => this.in_progress.push(fut.into_future()),
+                    Poll::Ready(None) | Poll::Pending => break,
+                }
+            }
+
+            // An item that completed earlier has to be handed to the sink
+            // before the next completed one can be taken.
+            if this.buffered.is_some() {
+                match Pin::new(&mut **this.sink).poll_ready(cx)? {
+                    Poll::Ready(()) => {
+                        let item = this.buffered.take().unwrap();
+                        Pin::new(&mut **this.sink).start_send(item)?;
+                    }
+                    Poll::Pending => return Poll::Pending,
+                }
+            }
+
+            match this.in_progress.poll_next_unpin(cx) {
+                Poll::Ready(Some(item)) => *this.buffered = Some(item?),
+                Poll::Ready(None) if this.stream.is_done() => {
+                    ready!(Pin::new(&mut **this.sink).poll_flush(cx))?;
+                    return Poll::Ready(Ok(()));
+                }
+                Poll::Ready(None) | Poll::Pending => {
+                    ready!(Pin::new(&mut **this.sink).poll_flush(cx))?;
+                    return Poll::Pending;
+                }
+            }
+        }
+    }
+}
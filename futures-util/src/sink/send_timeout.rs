@@ -0,0 +1,153 @@
+use core::fmt;
+use core::pin::Pin;
+use futures_core::future::Future;
+use futures_core::ready;
+use futures_core::task::{Context, Poll};
+use futures_sink::Sink;
+use pin_project_lite::pin_project;
+
+/// Error returned by [`feed_timeout`](super::SinkExt::feed_timeout) and
+/// [`send_timeout`](super::SinkExt::send_timeout).
+#[derive(PartialEq, Eq)]
+pub enum SendTimeoutError<Item, E> {
+    /// The sink did not accept the item before the deadline, which hands the
+    /// item back.
+    Timeout(Item),
+    /// The sink accepted the item, but did not finish flushing it before the
+    /// deadline. Only returned by `send_timeout`.
+    FlushTimeout,
+    /// The sink failed.
+    Sink(E),
+}
+
+impl<Item, E> SendTimeoutError<Item, E> {
+    /// Returns `true` if the deadline expired, whether or not the item was
+    /// accepted.
+    pub fn is_timeout(&self) -> bool {
+        match self {
+            Self::Timeout(_) | Self::FlushTimeout => true,
+            Self::Sink(_) => false,
+        }
+    }
+
+    /// Returns the item if the sink did not accept it.
+    pub fn into_item(self) -> Option<Item> {
+        match self {
+            Self::Timeout(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+
+impl<Item, E: fmt::Debug> fmt::Debug for SendTimeoutError<Item, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Timeout(_) => f.write_str("Timeout(..)"),
+            Self::FlushTimeout => f.write_str("FlushTimeout"),
+            Self::Sink(e) => f.debug_tuple("Sink").field(e).finish(),
+        }
+    }
+}
+
+impl<Item, E: fmt::Display> fmt::Display for SendTimeoutError<Item, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Timeout(_) => f.write_str("sink did not accept the item in time"),
+            Self::FlushTimeout => f.write_str("sink did not flush the item in time"),
+            Self::Sink(e) => e.fmt(f),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<Item, E: fmt::Debug + fmt::Display> std::error::Error for SendTimeoutError<Item, E> {}
+
+pin_project! {
+    /// Future for the [`feed_timeout`](super::SinkExt::feed_timeout) method.
+    #[derive(Debug)]
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct FeedTimeout<'a, Si: ?Sized, Item, Fut> {
+        sink: &'a mut Si,
+        item: Option<Item>,
+        #[pin]
+        deadline: Fut,
+    }
+}
+
+impl<'a, Si: Sink<Item> + Unpin + ?Sized, Item, Fut: Future> FeedTimeout<'a, Si, Item, Fut> {
+    pub(super) fn new(sink: &'a mut Si, item: Item, deadline: Fut) -> Self {
+        Self { sink, item: Some(item), deadline }
+    }
+}
+
+impl<Si, Item, Fut> Future for FeedTimeout<'_, Si, Item, Fut>
+where
+    Si: Sink<Item> + Unpin + ?Sized,
+    Fut: Future,
+{
+    type Output = Result<(), SendTimeoutError<Item, Si::Error>>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        let mut sink = Pin::new(&mut **this.sink);
+        assert!(this.item.is_some(), "polled FeedTimeout after completion");
+
+        match sink.as_mut().poll_ready(cx) {
+            Poll::Ready(Ok(())) => {}
+            Poll::Ready(Err(e)) => {
+                this.item.take();
+                return Poll::Ready(Err(SendTimeoutError::Sink(e)));
+            }
+            Poll::Pending => {
+                ready!(this.deadline.poll(cx));
+                let item = this.item.take().unwrap();
+                return Poll::Ready(Err(SendTimeoutError::Timeout(item)));
+            }
+        }
+        let item = this.item.take().unwrap();
+        Poll::Ready(sink.start_send(item).map_err(SendTimeoutError::Sink))
+    }
+}
+
+pin_project! {
+    /// Future for the [`send_timeout`](super::SinkExt::send_timeout) method.
+    #[derive(Debug)]
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct SendTimeout<'a, Si: ?Sized, Item, Fut> {
+        #[pin]
+        feed: FeedTimeout<'a, Si, Item, Fut>,
+    }
+}
+
+impl<'a, Si: Sink<Item> + Unpin + ?Sized, Item, Fut: Future> SendTimeout<'a, Si, Item, Fut> {
+    pub(super) fn new(sink: &'a mut Si, item: Item, deadline: Fut) -> Self {
+        Self { feed: FeedTimeout::new(sink, item, deadline) }
+    }
+}
+
+impl<Si, Item, Fut> Future for SendTimeout<'_, Si, Item, Fut>
+where
+    Si: Sink<Item> + Unpin + ?Sized,
+    Fut: Future,
+{
+    type Output = Result<(), SendTimeoutError<Item, Si::Error>>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut feed = self.project().feed;
+
+        if feed.item.is_some() {
+            ready!(feed.as_mut().poll(cx))?;
+        }
+
+        // we're done sending the item, but want to block on flushing the
+        // sink until the deadline
+        let feed = feed.project();
+        match Pin::new(&mut **feed.sink).poll_flush(cx) {
+            Poll::Ready(result) => Poll::Ready(result.map_err(SendTimeoutError::Sink)),
+            Poll::Pending => {
+                ready!(feed.deadline.poll(cx));
+                Poll::Ready(Err(SendTimeoutError::FlushTimeout))
+            }
+        }
+    }
+}
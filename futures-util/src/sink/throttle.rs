@@ -0,0 +1,123 @@
+use alloc::boxed::Box;
+use alloc::collections::VecDeque;
+use core::fmt;
+use core::pin::Pin;
+use futures_core::future::Future;
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Context, Poll};
+use futures_sink::Sink;
+use pin_project_lite::pin_project;
+
+pin_project! {
+    /// Sink for the [`throttle`](super::SinkExt::throttle) method.
+    #[must_use = "sinks do nothing unless polled"]
+    pub struct Throttle<Si, F, Fut> {
+        #[pin]
+        sink: Si,
+        delay: F,
+        tokens: usize,
+        refills: VecDeque<Pin<Box<Fut>>>,
+    }
+}
+
+impl<Si, F, Fut> fmt::Debug for Throttle<Si, F, Fut>
+where
+    Si: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Throttle")
+            .field("sink", &self.sink)
+            .field("tokens", &self.tokens)
+            .field("refills", &self.refills.len())
+            .finish()
+    }
+}
+
+impl<Si, F, Fut> Throttle<Si, F, Fut>
+where
+    F: FnMut() -> Fut,
+    Fut: Future,
+{
+    pub(super) fn new(sink: Si, burst: usize, delay: F) -> Self {
+        assert!(burst > 0, "burst must be greater than zero");
+        Self { sink, delay, tokens: burst, refills: VecDeque::with_capacity(burst) }
+    }
+
+    /// Returns the number of items that can currently be sent without
+    /// waiting for the rate limit.
+    pub fn tokens(&self) -> usize {
+        self.tokens
+    }
+
+    delegate_access_inner!(sink, Si, ());
+}
+
+// Forwarding impl of Stream from the underlying sink
+impl<S, F, Fut> Stream for Throttle<S, F, Fut>
+where
+    S: Stream,
+{
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<S::Item>> {
+        self.project().sink.poll_next(cx)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.sink.size_hint()
+    }
+}
+
+impl<S, F, Fut> FusedStream for Throttle<S, F, Fut>
+where
+    S: FusedStream,
+{
+    fn is_terminated(&self) -> bool {
+        self.sink.is_terminated()
+    }
+}
+
+impl<Si, F, Fut, Item> Sink<Item> for Throttle<Si, F, Fut>
+where
+    Si: Sink<Item>,
+    F: FnMut() -> Fut,
+    Fut: Future,
+{
+    type Error = Si::Error;
+
+    fn poll_ready(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        let this = self.project();
+
+        // The refills were started in order and take the same time, so they
+        // complete in order as well.
+        while let Some(refill) = this.refills.front_mut() {
+            if refill.as_mut().poll(cx).is_pending() {
+                break;
+            }
+            this.refills.pop_front();
+            *this.tokens += 1;
+        }
+
+        if *this.tokens == 0 {
+            return Poll::Pending;
+        }
+        this.sink.poll_ready(cx)
+    }
+
+    fn start_send(self: Pin<&mut Self>, item: Item) -> Result<(), Self::Error> {
+        let this = self.project();
+
+        assert!(*this.tokens > 0, "start_send called without poll_ready being called first");
+        *this.tokens -= 1;
+        this.refills.push_back(Box::pin((this.delay)()));
+        this.sink.start_send(item)
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.project().sink.poll_flush(cx)
+    }
+
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.project().sink.poll_close(cx)
+    }
+}
@@ -0,0 +1,212 @@
+use super::assert_sink;
+use core::{future::Future, pin::Pin};
+use futures_core::ready;
+use futures_core::task::{Context, Poll};
+use futures_sink::Sink;
+use pin_project_lite::pin_project;
+
+pin_project! {
+    #[project = StateProj]
+    #[project_replace = StateProjReplace]
+    #[derive(Debug)]
+    enum State<T, SendFut, FlushFut, CloseFut> {
+        Value {
+            value: T,
+        },
+        Sending {
+            #[pin]
+            future: SendFut,
+        },
+        Flushing {
+            #[pin]
+            future: FlushFut,
+        },
+        Closing {
+            #[pin]
+            future: CloseFut,
+        },
+        Closed,
+        Empty,
+    }
+}
+
+impl<T, SendFut, FlushFut, CloseFut, E> State<T, SendFut, FlushFut, CloseFut>
+where
+    SendFut: Future<Output = Result<T, E>>,
+    FlushFut: Future<Output = Result<T, E>>,
+    CloseFut: Future<Output = Result<(), E>>,
+{
+    // Drives the operation in progress, if any, to completion.
+    fn poll_idle(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), E>> {
+        let result = match self.as_mut().project() {
+            StateProj::Sending { future } => ready!(future.poll(cx)),
+            StateProj::Flushing { future } => ready!(future.poll(cx)),
+            StateProj::Closing { future } => {
+                let result = ready!(future.poll(cx));
+                self.set(if result.is_ok() { State::Closed } else { State::Empty });
+                return Poll::Ready(result);
+            }
+            StateProj::Value { .. } | StateProj::Closed => return Poll::Ready(Ok(())),
+            StateProj::Empty => panic!("UnfoldFull polled after an error"),
+        };
+        Poll::Ready(match result {
+            Ok(value) => {
+                self.set(State::Value { value });
+                Ok(())
+            }
+            Err(err) => {
+                self.set(State::Empty);
+                Err(err)
+            }
+        })
+    }
+
+    fn take_value(self: Pin<&mut Self>) -> Option<T> {
+        match &*self {
+            State::Value { .. } => match self.project_replace(State::Empty) {
+                StateProjReplace::Value { value } => Some(value),
+                _ => unreachable!(),
+            },
+            _ => None,
+        }
+    }
+}
+
+pin_project! {
+    /// Sink for the [`unfold_full`] function.
+    #[derive(Debug)]
+    #[must_use = "sinks do nothing unless polled"]
+    pub struct UnfoldFull<T, S, SendFut, Fl, FlushFut, C, CloseFut> {
+        send: S,
+        flush: Fl,
+        close: Option<C>,
+        #[pin]
+        state: State<T, SendFut, FlushFut, CloseFut>,
+    }
+}
+
+/// Create a sink from a state and three functions implementing the send, flush
+/// and close operations.
+///
+/// This works like [`unfold`](super::unfold), which only lets the send
+/// operation be customized, but `flush` is also called with the state each
+/// time the sink is flushed, and `close` consumes the state when the sink is
+/// closed. Pending sends are completed before either of them runs, and `close`
+/// does not flush first, so it should do that itself if needed. A function
+/// that has nothing to do can return [`future::ok`](crate::future::ok).
+///
+/// After one of the futures returns an error the state is lost, and using the
+/// sink again panics.
+///
+/// # Examples
+///
+/// ```
+/// # futures::executor::block_on(async {
+/// use futures::future;
+/// use futures::sink::{self, SinkExt};
+///
+/// // a sink writing lines into a buffer, committed to `out` on flush
+/// let mut out = Vec::new();
+/// {
+///     let unfold = sink::unfold_full(
+///         (Vec::new(), &mut out),
+///         |(mut buf, out), line: &str| {
+///             buf.push(line.to_string());
+///             future::ok::<_, std::convert::Infallible>((buf, out))
+///         },
+///         |(mut buf, out)| {
+///             out.append(&mut buf);
+///             future::ok((buf, out))
+///         },
+///         |(_buf, _out)| future::ok(()),
+///     );
+///     futures::pin_mut!(unfold);
+///     unfold.feed("hello").await?;
+///     unfold.feed("world").await?;
+///     unfold.flush().await?;
+///     unfold.close().await?;
+/// }
+/// assert_eq!(out, vec!["hello", "world"]);
+/// # Ok::<(), std::convert::Infallible>(()) }).unwrap();
+/// ```
+pub fn unfold_full<T, S, SendFut, Fl, FlushFut, C, CloseFut, Item, E>(
+    init: T,
+    send: S,
+    flush: Fl,
+    close: C,
+) -> UnfoldFull<T, S, SendFut, Fl, FlushFut, C, CloseFut>
+where
+    S: FnMut(T, Item) -> SendFut,
+    SendFut: Future<Output = Result<T, E>>,
+    Fl: FnMut(T) -> FlushFut,
+    FlushFut: Future<Output = Result<T, E>>,
+    C: FnOnce(T) -> CloseFut,
+    CloseFut: Future<Output = Result<(), E>>,
+{
+    assert_sink::<Item, E, _>(UnfoldFull {
+        send,
+        flush,
+        close: Some(close),
+        state: State::Value { value: init },
+    })
+}
+
+impl<T, S, SendFut, Fl, FlushFut, C, CloseFut, Item, E> Sink<Item>
+    for UnfoldFull<T, S, SendFut, Fl, FlushFut, C, CloseFut>
+where
+    S: FnMut(T, Item) -> SendFut,
+    SendFut: Future<Output = Result<T, E>>,
+    Fl: FnMut(T) -> FlushFut,
+    FlushFut: Future<Output = Result<T, E>>,
+    C: FnOnce(T) -> CloseFut,
+    CloseFut: Future<Output = Result<(), E>>,
+{
+    type Error = E;
+
+    fn poll_ready(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        let mut this = self.project();
+        ready!(this.state.as_mut().poll_idle(cx))?;
+        if let State::Closed = *this.state {
+            panic!("poll_ready called after poll_close");
+        }
+        Poll::Ready(Ok(()))
+    }
+
+    fn start_send(self: Pin<&mut Self>, item: Item) -> Result<(), Self::Error> {
+        let mut this = self.project();
+        let future = match this.state.as_mut().take_value() {
+            Some(value) => (this.send)(value, item),
+            None => panic!("start_send called without poll_ready being called first"),
+        };
+        this.state.set(State::Sending { future });
+        Ok(())
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        let mut this = self.project();
+        if let State::Flushing { .. } = *this.state {
+            return this.state.poll_idle(cx);
+        }
+        ready!(this.state.as_mut().poll_idle(cx))?;
+        if let Some(value) = this.state.as_mut().take_value() {
+            let future = (this.flush)(value);
+            this.state.set(State::Flushing { future });
+            this.state.poll_idle(cx)
+        } else {
+            // already closed
+            Poll::Ready(Ok(()))
+        }
+    }
+
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        let mut this = self.project();
+        ready!(this.state.as_mut().poll_idle(cx))?;
+        if let Some(value) = this.state.as_mut().take_value() {
+            let close = this.close.take().expect("close function already called");
+            this.state.set(State::Closing { future: close(value) });
+            this.state.poll_idle(cx)
+        } else {
+            Poll::Ready(Ok(()))
+        }
+    }
+}
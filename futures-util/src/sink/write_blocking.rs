@@ -0,0 +1,155 @@
+use super::assert_sink;
+use crate::task::SpawnExt;
+use core::pin::Pin;
+use futures_channel::{mpsc, oneshot};
+use futures_core::future::Future;
+use futures_core::ready;
+use futures_core::stream::Stream;
+use futures_core::task::{Context, Poll};
+use futures_sink::Sink;
+use futures_task::{Spawn, SpawnError};
+use std::io::{self, Write};
+
+#[derive(Debug)]
+enum Command {
+    Write(Vec<u8>),
+    Flush(oneshot::Sender<io::Result<()>>),
+}
+
+/// Sink for the [`write_blocking`] function.
+#[derive(Debug)]
+#[must_use = "sinks do nothing unless polled"]
+pub struct WriteBlocking {
+    tx: mpsc::Sender<Command>,
+    flush: Option<oneshot::Receiver<io::Result<()>>>,
+}
+
+/// Drives the blocking writer on the spawned task, writing the buffers it
+/// receives until the sink is dropped or closed.
+struct Writer<W> {
+    writer: W,
+    rx: mpsc::Receiver<Command>,
+    error: Option<io::Error>,
+}
+
+impl<W> Unpin for Writer<W> {}
+
+impl<W: Write> Future for Writer<W> {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        let this = &mut *self;
+        loop {
+            match ready!(Pin::new(&mut this.rx).poll_next(cx)) {
+                Some(Command::Write(buf)) => {
+                    // Once a write failed, later buffers are dropped until the
+                    // error has been reported by a flush.
+                    if this.error.is_none() {
+                        this.error = this.writer.write_all(&buf).err();
+                    }
+                }
+                Some(Command::Flush(reply)) => {
+                    let result = match this.error.take() {
+                        Some(e) => Err(e),
+                        None => this.writer.flush(),
+                    };
+                    let _ = reply.send(result);
+                }
+                None => return Poll::Ready(()),
+            }
+        }
+    }
+}
+
+fn stopped() -> io::Error {
+    io::Error::new(io::ErrorKind::BrokenPipe, "writer task stopped")
+}
+
+/// Converts a blocking `Write` into a `Sink` of byte buffers, by writing them
+/// on a task spawned onto `spawner`.
+///
+/// Each buffer sent to the sink is written with `write_all` on the spawned
+/// task, and at most `queue` buffers wait to be written; once that many are
+/// queued, the sink is not ready until the task catches up. Flushing the sink
+/// waits for every queued buffer to be written and then flushes the writer.
+/// This is meant for files or sockets managed by synchronous code, and the
+/// spawner should be one that tolerates blocking tasks, like a `ThreadPool`
+/// dedicated to that purpose.
+///
+/// A write error is reported by the next flush or close, and buffers sent
+/// in the meantime are dropped. Closing the sink flushes it and then stops
+/// the spawned task, which drops the writer. Dropping the sink without
+/// closing it stops the task after the queued buffers are written, without
+/// flushing.
+///
+/// # Errors
+///
+/// Returns an error if the task could not be spawned.
+///
+/// # Panics
+///
+/// Panics if `queue` is zero.
+///
+/// # Examples
+///
+/// ```
+/// # futures::executor::block_on(async {
+/// use futures::executor::ThreadPool;
+/// use futures::sink::{self, SinkExt};
+///
+/// let pool = ThreadPool::new().unwrap();
+/// let mut sink = sink::write_blocking(std::io::sink(), &pool, 4).unwrap();
+/// sink.send(b"hello".to_vec()).await?;
+/// sink.close().await?;
+/// # Ok::<(), std::io::Error>(()) }).unwrap();
+/// ```
+pub fn write_blocking<W, Sp>(
+    writer: W,
+    spawner: &Sp,
+    queue: usize,
+) -> Result<WriteBlocking, SpawnError>
+where
+    W: Write + Send + 'static,
+    Sp: Spawn + ?Sized,
+{
+    assert!(queue > 0, "queue must be greater than zero");
+
+    // every sender gets a guaranteed slot on top of the buffer
+    let (tx, rx) = mpsc::channel(queue - 1);
+    spawner.spawn(Writer { writer, rx, error: None })?;
+    Ok(assert_sink::<Vec<u8>, io::Error, _>(WriteBlocking { tx, flush: None }))
+}
+
+impl Sink<Vec<u8>> for WriteBlocking {
+    type Error = io::Error;
+
+    fn poll_ready(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.tx.poll_ready(cx).map_err(|_| stopped())
+    }
+
+    fn start_send(mut self: Pin<&mut Self>, buf: Vec<u8>) -> Result<(), Self::Error> {
+        self.tx.start_send(Command::Write(buf)).map_err(|_| stopped())
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        let this = &mut *self;
+        if this.flush.is_none() {
+            ready!(this.tx.poll_ready(cx)).map_err(|_| stopped())?;
+            let (reply, flush) = oneshot::channel();
+            this.tx.start_send(Command::Flush(reply)).map_err(|_| stopped())?;
+            this.flush = Some(flush);
+        }
+        let result = ready!(Pin::new(this.flush.as_mut().unwrap()).poll(cx));
+        this.flush = None;
+        Poll::Ready(result.unwrap_or_else(|_| Err(stopped())))
+    }
+
+    fn poll_close(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        if self.tx.is_closed() {
+            return Poll::Ready(Ok(()));
+        }
+        ready!(self.as_mut().poll_flush(cx))?;
+        self.tx.close_channel();
+        Poll::Ready(Ok(()))
+    }
+}
@@ -1,5 +1,5 @@
 use super::assert_stream;
-use crate::stream::{AbortHandle, Abortable};
+use crate::stream::{AbortHandle, Abortable, DrainAbortable};
 use crate::Stream;
 
 /// Creates a new `Abortable` stream and an `AbortHandle` which can be used to stop it.
@@ -17,3 +17,23 @@ where
     let abortable = assert_stream::<St::Item, _>(Abortable::new(stream, reg));
     (abortable, handle)
 }
+
+/// Creates a new `DrainAbortable` stream and an `AbortHandle` which can be used
+/// to stop it.
+///
+/// Once aborted, the stream yields at most `drain_limit` more items that are
+/// immediately available before ending, see [`DrainAbortable::new`].
+///
+/// This function is only available when the `std` or `alloc` feature of this
+/// library is activated, and it is activated by default.
+pub fn drain_abortable<St>(
+    stream: St,
+    drain_limit: impl Into<Option<usize>>,
+) -> (DrainAbortable<St>, AbortHandle)
+where
+    St: Stream,
+{
+    let (handle, reg) = AbortHandle::new_pair();
+    let abortable = assert_stream::<St::Item, _>(DrainAbortable::new(stream, reg, drain_limit));
+    (abortable, handle)
+}
@@ -0,0 +1,158 @@
+use crate::stream::{FuturesUnordered, StreamExt};
+use alloc::collections::VecDeque;
+use core::fmt::{self, Debug};
+use core::pin::Pin;
+use futures_core::future::Future;
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Context, Poll};
+
+/// A set of futures which may complete in any order, with at most a fixed
+/// number of them running at once.
+///
+/// This is like [`FuturesUnordered`], but only up to `limit` futures are
+/// polled at a time. Futures pushed while the set is full are held in a
+/// queue without being polled, and are started in the order they were pushed
+/// as running futures complete. This gives concurrency limiting without
+/// having to route the futures through a stream and
+/// [`buffer_unordered`](super::StreamExt::buffer_unordered).
+///
+/// This type is only available when the `std` or `alloc` feature of this
+/// library is activated, and it is activated by default.
+///
+/// # Examples
+///
+/// ```
+/// # futures::executor::block_on(async {
+/// use futures::future;
+/// use futures::stream::{BoundedFuturesUnordered, StreamExt};
+///
+/// let mut set = BoundedFuturesUnordered::new(2);
+/// for i in 0..5 {
+///     set.push(future::ready(i));
+/// }
+/// assert_eq!(set.len(), 5);
+///
+/// let mut outputs = set.collect::<Vec<_>>().await;
+/// outputs.sort();
+/// assert_eq!(outputs, vec![0, 1, 2, 3, 4]);
+/// # });
+/// ```
+#[must_use = "streams do nothing unless polled"]
+pub struct BoundedFuturesUnordered<Fut> {
+    in_progress: FuturesUnordered<Fut>,
+    queued: VecDeque<Fut>,
+    limit: usize,
+}
+
+// Queued futures are never pinned, and `FuturesUnordered` is always `Unpin`.
+impl<Fut> Unpin for BoundedFuturesUnordered<Fut> {}
+
+impl<Fut> BoundedFuturesUnordered<Fut> {
+    /// Constructs a new, empty `BoundedFuturesUnordered` that runs at most
+    /// `limit` futures at a time.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `limit` is zero.
+    pub fn new(limit: usize) -> Self {
+        assert!(limit > 0, "limit must be greater than zero");
+        Self { in_progress: FuturesUnordered::new(), queued: VecDeque::new(), limit }
+    }
+
+    /// Returns the maximum number of futures that are run at once.
+    pub fn limit(&self) -> usize {
+        self.limit
+    }
+
+    /// Returns the number of futures contained in the set, both running and
+    /// queued.
+    pub fn len(&self) -> usize {
+        self.in_progress.len() + self.queued.len()
+    }
+
+    /// Returns `true` if the set contains no futures.
+    pub fn is_empty(&self) -> bool {
+        self.in_progress.is_empty() && self.queued.is_empty()
+    }
+
+    /// Returns the number of futures that have been started.
+    pub fn in_progress_len(&self) -> usize {
+        self.in_progress.len()
+    }
+
+    /// Returns the number of futures waiting for a free slot.
+    pub fn queued_len(&self) -> usize {
+        self.queued.len()
+    }
+
+    /// Push a future into the set.
+    ///
+    /// The future is started right away if fewer than `limit` futures are
+    /// running, and queued otherwise. This method will not call `poll` on the
+    /// submitted future. The caller must ensure that
+    /// `BoundedFuturesUnordered::poll_next` is called in order to receive
+    /// wake-up notifications for the given future.
+    pub fn push(&mut self, future: Fut) {
+        if self.in_progress.len() < self.limit {
+            self.in_progress.push(future);
+        } else {
+            self.queued.push_back(future);
+        }
+    }
+
+    /// Clears the set, removing all futures, both running and queued.
+    pub fn clear(&mut self) {
+        self.in_progress.clear();
+        self.queued.clear();
+    }
+}
+
+impl<Fut> Debug for BoundedFuturesUnordered<Fut> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BoundedFuturesUnordered")
+            .field("in_progress", &self.in_progress.len())
+            .field("queued", &self.queued.len())
+            .field("limit", &self.limit)
+            .finish()
+    }
+}
+
+impl<Fut: Future> Stream for BoundedFuturesUnordered<Fut> {
+    type Item = Fut::Output;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+
+        // Start queued futures in any free slots.
+        while this.in_progress.len() < this.limit {
+            match this.queued.pop_front() {
+                Some(future) => this.in_progress.push(future),
+                None => break,
+            }
+        }
+
+        this.in_progress.poll_next_unpin(cx)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<Fut: Future> FusedStream for BoundedFuturesUnordered<Fut> {
+    fn is_terminated(&self) -> bool {
+        self.queued.is_empty() && self.in_progress.is_terminated()
+    }
+}
+
+impl<Fut> Extend<Fut> for BoundedFuturesUnordered<Fut> {
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = Fut>,
+    {
+        for future in iter {
+            self.push(future);
+        }
+    }
+}
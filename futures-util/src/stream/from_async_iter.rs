@@ -0,0 +1,57 @@
+use super::assert_stream;
+use core::async_iter::AsyncIterator;
+use core::pin::Pin;
+use futures_core::stream::Stream;
+use futures_core::task::{Context, Poll};
+use pin_project_lite::pin_project;
+
+pin_project! {
+    /// Stream for the [`from_async_iter`] function.
+    #[derive(Debug)]
+    #[must_use = "streams do nothing unless polled"]
+    pub struct FromAsyncIter<I> {
+        #[pin]
+        iter: I,
+    }
+}
+
+impl<I> FromAsyncIter<I> {
+    delegate_access_inner!(iter, I, ());
+}
+
+/// Converts an [`AsyncIterator`] from the standard library into a `Stream`.
+///
+/// This function is only available when the `async-iterator` feature of this
+/// library is activated, which requires a nightly compiler.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(async_iterator, async_iter_from_iter)]
+/// # futures::executor::block_on(async {
+/// use futures::stream::{self, StreamExt};
+///
+/// let iter = core::async_iter::from_iter(vec![1, 2, 3]);
+/// let stream = stream::from_async_iter(iter).map(|x| x * 2);
+///
+/// assert_eq!(stream.collect::<Vec<_>>().await, vec![2, 4, 6]);
+/// # });
+/// ```
+pub fn from_async_iter<I>(iter: I) -> FromAsyncIter<I>
+where
+    I: AsyncIterator,
+{
+    assert_stream::<I::Item, _>(FromAsyncIter { iter })
+}
+
+impl<I: AsyncIterator> Stream for FromAsyncIter<I> {
+    type Item = I::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.project().iter.poll_next(cx)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
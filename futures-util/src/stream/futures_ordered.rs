@@ -140,6 +140,74 @@ impl<Fut: Future> FuturesOrdered<Fut> {
         self.next_incoming_index += 1;
         self.in_progress_queue.push(wrapped);
     }
+
+    /// Returns the number of futures that are still running.
+    pub fn in_progress_len(&self) -> usize {
+        self.in_progress_queue.len()
+    }
+
+    /// Returns the number of outputs that have completed but are buffered
+    /// until the futures pushed before them complete.
+    pub fn buffered_len(&self) -> usize {
+        self.queued_outputs.len()
+    }
+
+    /// Returns the insertion index that the next pushed future will get.
+    ///
+    /// Futures are numbered consecutively from zero in the order in which
+    /// they are pushed. This index can be passed to
+    /// [`cancel`](FuturesOrdered::cancel).
+    pub fn next_index(&self) -> usize {
+        self.next_incoming_index
+    }
+
+    /// Cancels the still-running future with the given insertion index,
+    /// dropping it and yielding `placeholder` in its place in the output
+    /// order. The task polling the queue is woken, so that it yields the
+    /// placeholder once its turn comes.
+    ///
+    /// Returns `false`, and drops `placeholder`, if no running future has that
+    /// index, for example because it has already completed.
+    ///
+    /// This is a linear scan over the running futures.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::future::{self, FutureExt};
+    /// use futures::stream::{FuturesOrdered, StreamExt};
+    ///
+    /// let mut queue = FuturesOrdered::new();
+    /// queue.push(future::ready(Ok(1)).left_future());
+    /// let index = queue.next_index();
+    /// queue.push(future::pending().right_future());
+    /// queue.push(future::ready(Ok(3)).left_future());
+    ///
+    /// assert!(queue.cancel(index, Err("cancelled")));
+    /// let outputs = queue.collect::<Vec<_>>().await;
+    /// assert_eq!(outputs, vec![Ok(1), Err("cancelled"), Ok(3)]);
+    /// # });
+    /// ```
+    pub fn cancel(&mut self, index: usize, placeholder: Fut::Output) -> bool {
+        let len = self.in_progress_queue.len();
+        self.in_progress_queue.retain(|wrapper| wrapper.index != index);
+        if self.in_progress_queue.len() == len {
+            return false;
+        }
+        self.queued_outputs.push(OrderWrapper { data: placeholder, index });
+        // The task may be waiting for the cancelled future, which won't wake
+        // it anymore.
+        self.in_progress_queue.wake_task();
+        true
+    }
+
+    /// Clears the queue, dropping all running futures and buffered outputs.
+    pub fn clear(&mut self) {
+        self.in_progress_queue.clear();
+        self.queued_outputs.clear();
+        self.next_outgoing_index = self.next_incoming_index;
+    }
 }
 
 impl<Fut: Future> Default for FuturesOrdered<Fut> {
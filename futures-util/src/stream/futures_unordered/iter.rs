@@ -1,8 +1,8 @@
 use super::task::Task;
 use super::FuturesUnordered;
+use crate::sync::atomic::Ordering::Relaxed;
 use core::marker::PhantomData;
 use core::pin::Pin;
-use core::sync::atomic::Ordering::Relaxed;
 
 /// Mutable iterator over all futures in the unordered set.
 #[derive(Debug)]
@@ -36,6 +36,14 @@ pub struct IntoIter<Fut: Unpin> {
     pub(super) inner: FuturesUnordered<Fut>,
 }
 
+/// Draining iterator over all futures in the unordered set.
+///
+/// This is created by the [`drain`](FuturesUnordered::drain) method.
+#[derive(Debug)]
+pub struct Drain<'a, Fut: Unpin> {
+    pub(super) inner: &'a mut FuturesUnordered<Fut>,
+}
+
 impl<Fut: Unpin> Iterator for IntoIter<Fut> {
     type Item = Fut;
 
@@ -70,6 +78,39 @@ impl<Fut: Unpin> Iterator for IntoIter<Fut> {
 
 impl<Fut: Unpin> ExactSizeIterator for IntoIter<Fut> {}
 
+impl<Fut: Unpin> Iterator for Drain<'_, Fut> {
+    type Item = Fut;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let head = *self.inner.head_all.get_mut();
+
+        if head.is_null() {
+            return None;
+        }
+
+        unsafe {
+            let task = self.inner.unlink(head);
+            // Moving out of the future is safe because it is `Unpin`
+            let future = (*task.future.get()).take().unwrap();
+            self.inner.release_task(task);
+            Some(future)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.inner.len();
+        (len, Some(len))
+    }
+}
+
+impl<Fut: Unpin> ExactSizeIterator for Drain<'_, Fut> {}
+
+impl<Fut: Unpin> Drop for Drain<'_, Fut> {
+    fn drop(&mut self) {
+        self.inner.clear();
+    }
+}
+
 impl<'a, Fut> Iterator for IterPinMut<'a, Fut> {
     type Item = Pin<&'a mut Fut>;
 
@@ -3,8 +3,10 @@
 //! This module is only available when the `std` or `alloc` feature of this
 //! library is activated, and it is activated by default.
 
+use crate::sync::atomic::Ordering::{AcqRel, Acquire, Relaxed, Release, SeqCst};
+use crate::sync::atomic::{AtomicBool, AtomicPtr};
+use crate::sync::{Arc, Weak};
 use crate::task::AtomicWaker;
-use alloc::sync::{Arc, Weak};
 use core::cell::UnsafeCell;
 use core::cmp;
 use core::fmt::{self, Debug};
@@ -13,8 +15,6 @@ use core::marker::PhantomData;
 use core::mem;
 use core::pin::Pin;
 use core::ptr;
-use core::sync::atomic::Ordering::{AcqRel, Acquire, Relaxed, Release, SeqCst};
-use core::sync::atomic::{AtomicBool, AtomicPtr};
 use futures_core::future::Future;
 use futures_core::stream::{FusedStream, Stream};
 use futures_core::task::{Context, Poll};
@@ -23,7 +23,7 @@ use futures_task::{FutureObj, LocalFutureObj, LocalSpawn, Spawn, SpawnError};
 mod abort;
 
 mod iter;
-pub use self::iter::{IntoIter, Iter, IterMut, IterPinMut, IterPinRef};
+pub use self::iter::{Drain, IntoIter, Iter, IterMut, IterPinMut, IterPinRef};
 
 mod task;
 use self::task::Task;
@@ -89,6 +89,10 @@ unsafe impl<Fut: Send> Send for FuturesUnordered<Fut> {}
 unsafe impl<Fut: Sync> Sync for FuturesUnordered<Fut> {}
 impl<Fut> Unpin for FuturesUnordered<Fut> {}
 
+// A handle to a future pushed with `push_task`, which `remove_task` removes
+// without scanning the set.
+pub(crate) struct TaskHandle<Fut>(Arc<Task<Fut>>);
+
 impl Spawn for FuturesUnordered<FutureObj<'_, ()>> {
     fn spawn_obj(&self, future_obj: FutureObj<'static, ()>) -> Result<(), SpawnError> {
         self.push(future_obj);
@@ -187,6 +191,11 @@ impl<Fut> FuturesUnordered<Fut> {
     /// ensure that [`FuturesUnordered::poll_next`](Stream::poll_next) is called
     /// in order to receive wake-up notifications for the given future.
     pub fn push(&self, future: Fut) {
+        self.push_task(future);
+    }
+
+    // Like `push`, but returning a handle to the future for `remove_task`.
+    pub(crate) fn push_task(&self, future: Fut) -> TaskHandle<Fut> {
         let task = Arc::new(Task {
             future: UnsafeCell::new(Some(future)),
             next_all: AtomicPtr::new(self.pending_next_all()),
@@ -201,9 +210,11 @@ impl<Fut> FuturesUnordered<Fut> {
         // as terminated.
         self.is_terminated.store(false, Relaxed);
 
-        // Right now our task has a strong reference count of 1. We transfer
-        // ownership of this reference count to our internal linked list
-        // and we'll reclaim ownership through the `unlink` method below.
+        // Besides the reference of the handle, our task has a strong reference
+        // count of 1. We transfer ownership of this reference count to our
+        // internal linked list and we'll reclaim ownership through the
+        // `unlink` method below.
+        let handle = TaskHandle(task.clone());
         let ptr = self.link(task);
 
         // We'll need to get the future "into the system" to start tracking it,
@@ -211,6 +222,22 @@ impl<Fut> FuturesUnordered<Fut> {
         // futures are ready. To do that we unconditionally enqueue it for
         // polling here.
         self.ready_to_run_queue.enqueue(ptr);
+
+        handle
+    }
+
+    // Removes the future of a handle returned by `push_task` on this set,
+    // dropping it. Returns `false` if the future already completed or was
+    // removed.
+    pub(crate) fn remove_task(&mut self, handle: &TaskHandle<Fut>) -> bool {
+        // Released tasks have no future, and are never linked again.
+        // Safety: `&mut self` guarantees that the future isn't being polled.
+        if unsafe { (*handle.0.future.get()).is_none() } {
+            return false;
+        }
+        let task = unsafe { self.unlink(Arc::as_ptr(&handle.0)) };
+        self.release_task(task);
+        true
     }
 
     /// Returns an iterator that allows inspecting each future in the set.
@@ -247,6 +274,83 @@ impl<Fut> FuturesUnordered<Fut> {
         IterPinMut { task, len, _marker: PhantomData }
     }
 
+    /// Retains only the futures for which the predicate returns `true`.
+    ///
+    /// The predicate is called once for every future in the set, in no
+    /// particular order, and every future for which it returns `false` is
+    /// dropped without being polled again. This makes it possible to evict
+    /// stale work, such as timed out requests, without rebuilding the set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures::future;
+    /// use futures::stream::FuturesUnordered;
+    ///
+    /// let mut set: FuturesUnordered<_> = (0..5).map(future::ready).collect();
+    ///
+    /// let mut visited = 0;
+    /// set.retain(|_fut| {
+    ///     visited += 1;
+    ///     visited <= 3
+    /// });
+    /// assert_eq!(set.len(), 3);
+    /// ```
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(Pin<&mut Fut>) -> bool,
+    {
+        // `head_all` can be accessed directly and we don't need to spin on
+        // `Task::next_all` since we have exclusive access to the set.
+        let mut task = *self.head_all.get_mut() as *const Task<Fut>;
+
+        while !task.is_null() {
+            unsafe {
+                // Read the next pointer first, since unlinking resets it.
+                let next = (*task).next_all.load(Relaxed);
+                let future = (*(*task).future.get()).as_mut().unwrap();
+
+                // Safety: We won't move the future ever again
+                if !f(Pin::new_unchecked(future)) {
+                    let task = self.unlink(task);
+                    self.release_task(task);
+                }
+                task = next;
+            }
+        }
+    }
+
+    // Wakes the task which last polled the set, for the collections built on
+    // it whose output changes without any of the futures being woken.
+    pub(crate) fn wake_task(&self) {
+        self.ready_to_run_queue.waker.wake();
+    }
+
+    /// Removes every future from the set without polling them, returning
+    /// them in an iterator.
+    ///
+    /// Any futures that have not been yielded when the iterator is dropped
+    /// are dropped as well.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures::future;
+    /// use futures::stream::FuturesUnordered;
+    ///
+    /// let mut set: FuturesUnordered<_> = (0..3).map(future::ready).collect();
+    /// let mut drained: Vec<_> = set.drain().map(|fut| fut.into_inner()).collect();
+    /// drained.sort();
+    /// assert_eq!(drained, vec![0, 1, 2]);
+    /// assert!(set.is_empty());
+    /// ```
+    pub fn drain(&mut self) -> Drain<'_, Fut>
+    where
+        Fut: Unpin,
+    {
+        Drain { inner: self }
+    }
+
     /// Returns the current head node and number of futures in the list of all
     /// futures within a context where access is shared with other threads
     /// (mostly for use with the `len` and `iter_pin_ref` methods).
@@ -422,6 +526,11 @@ impl<Fut: Future> Stream for FuturesUnordered<Fut> {
         self.ready_to_run_queue.waker.register(cx.waker());
 
         loop {
+            // Yield if the budget of the task is exhausted.
+            if !self.is_empty() && crate::task::coop::poll_proceed(cx).is_pending() {
+                return Poll::Pending;
+            }
+
             // Safety: &mut self guarantees the mutual exclusion `dequeue`
             // expects
             let task = match unsafe { self.ready_to_run_queue.dequeue() } {
@@ -528,7 +637,7 @@ impl<Fut: Future> Stream for FuturesUnordered<Fut> {
             // deallocating the task if need be.
             let res = {
                 let waker = Task::waker_ref(bomb.task.as_ref().unwrap());
-                let mut cx = Context::from_waker(&waker);
+                let mut cx = crate::task::child_context(cx, &waker);
 
                 // Safety: We won't move the future ever again
                 let future = unsafe { Pin::new_unchecked(future) };
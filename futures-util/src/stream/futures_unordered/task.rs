@@ -1,7 +1,7 @@
-use alloc::sync::{Arc, Weak};
+use crate::sync::atomic::Ordering::{self, SeqCst};
+use crate::sync::atomic::{AtomicBool, AtomicPtr};
+use crate::sync::{Arc, Weak};
 use core::cell::UnsafeCell;
-use core::sync::atomic::Ordering::{self, SeqCst};
-use core::sync::atomic::{AtomicBool, AtomicPtr};
 
 use super::abort::abort;
 use super::ReadyToRunQueue;
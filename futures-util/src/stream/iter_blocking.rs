@@ -0,0 +1,123 @@
+use super::assert_stream;
+use crate::task::SpawnExt;
+use core::pin::Pin;
+use futures_channel::mpsc;
+use futures_core::future::Future;
+use futures_core::ready;
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Context, Poll};
+use futures_task::{Spawn, SpawnError};
+
+/// Stream for the [`iter_blocking`] function.
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct IterBlocking<T> {
+    rx: mpsc::Receiver<T>,
+}
+
+/// Drives the blocking iterator on the spawned task, handing its items to the
+/// channel until the iterator is exhausted or the stream is dropped.
+struct Prefetch<I: Iterator> {
+    iter: I,
+    tx: mpsc::Sender<I::Item>,
+}
+
+impl<I: Iterator> Unpin for Prefetch<I> {}
+
+impl<I: Iterator> Future for Prefetch<I> {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        let this = &mut *self;
+        loop {
+            if ready!(this.tx.poll_ready(cx)).is_err() {
+                return Poll::Ready(());
+            }
+            match this.iter.next() {
+                Some(item) => {
+                    if this.tx.start_send(item).is_err() {
+                        return Poll::Ready(());
+                    }
+                }
+                None => return Poll::Ready(()),
+            }
+        }
+    }
+}
+
+/// Converts a blocking `Iterator` into a `Stream` by pulling its items on a
+/// task spawned onto `spawner`.
+///
+/// Calls to `iter.next()` are made on the spawned task, which stays ahead of
+/// the consumer by at most `prefetch` items; once that many items are
+/// waiting, it stops calling `next()` until the stream is polled again. This
+/// is meant for iterators that block, such as a client for a paginated API,
+/// and the spawner should be one that tolerates blocking tasks, like a
+/// `ThreadPool` dedicated to that purpose.
+///
+/// The stream ends once the iterator returns `None`. Dropping the stream
+/// stops the spawned task after its current call to `next()` returns. If the
+/// iterator panics, the panic is left to the spawner and the stream ends.
+///
+/// # Errors
+///
+/// Returns an error if the task could not be spawned.
+///
+/// # Panics
+///
+/// Panics if `prefetch` is zero.
+///
+/// # Examples
+///
+/// ```
+/// # futures::executor::block_on(async {
+/// use futures::executor::ThreadPool;
+/// use futures::stream::{self, StreamExt};
+///
+/// let pool = ThreadPool::new().unwrap();
+/// let pages = (0..3).map(|page| {
+///     // stand-in for a blocking request
+///     std::thread::sleep(std::time::Duration::from_millis(1));
+///     page
+/// });
+///
+/// let stream = stream::iter_blocking(pages, &pool, 2).unwrap();
+/// assert_eq!(stream.collect::<Vec<_>>().await, vec![0, 1, 2]);
+/// # });
+/// ```
+pub fn iter_blocking<I, Sp>(
+    iter: I,
+    spawner: &Sp,
+    prefetch: usize,
+) -> Result<IterBlocking<I::Item>, SpawnError>
+where
+    I: IntoIterator,
+    I::IntoIter: Send + 'static,
+    I::Item: Send + 'static,
+    Sp: Spawn + ?Sized,
+{
+    assert!(prefetch > 0, "prefetch must be greater than zero");
+
+    // every sender gets a guaranteed slot on top of the buffer
+    let (tx, rx) = mpsc::channel(prefetch - 1);
+    spawner.spawn(Prefetch { iter: iter.into_iter(), tx })?;
+    Ok(assert_stream::<I::Item, _>(IterBlocking { rx }))
+}
+
+impl<T> Stream for IterBlocking<T> {
+    type Item = T;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<T>> {
+        Pin::new(&mut self.rx).poll_next(cx)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.rx.size_hint()
+    }
+}
+
+impl<T> FusedStream for IterBlocking<T> {
+    fn is_terminated(&self) -> bool {
+        self.rx.is_terminated()
+    }
+}
@@ -0,0 +1,207 @@
+use crate::stream::futures_unordered::TaskHandle;
+use crate::stream::{FuturesUnordered, StreamExt};
+use alloc::vec::Vec;
+use core::fmt::{self, Debug};
+use core::pin::Pin;
+use futures_core::future::Future;
+use futures_core::ready;
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Context, Poll};
+use pin_project_lite::pin_project;
+
+/// A token identifying a future pushed into a [`KeyedFuturesUnordered`].
+///
+/// Tokens are handed out by [`KeyedFuturesUnordered::push`] and are unique
+/// within the set that created them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FutureToken {
+    id: usize,
+    // The slot of the future in `KeyedFuturesUnordered::slots`, which is
+    // reused once the future is gone.
+    slot: usize,
+}
+
+impl FutureToken {
+    /// Returns the numeric value of this token.
+    pub fn get(self) -> usize {
+        self.id
+    }
+}
+
+pin_project! {
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    #[derive(Debug)]
+    struct TokenWrapper<Fut> {
+        #[pin]
+        future: Fut,
+        token: FutureToken,
+    }
+}
+
+impl<Fut: Future> Future for TokenWrapper<Fut> {
+    type Output = (FutureToken, Fut::Output);
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let token = self.token;
+        self.project().future.poll(cx).map(|output| (token, output))
+    }
+}
+
+/// A set of futures which may complete in any order, each identified by a
+/// token.
+///
+/// This is a [`FuturesUnordered`] whose [`push`](KeyedFuturesUnordered::push)
+/// returns a [`FutureToken`] for the pushed future. Outputs are yielded
+/// together with the token of the future that produced them, and a pending
+/// future can be cancelled by its token with
+/// [`cancel`](KeyedFuturesUnordered::cancel). This makes per-request
+/// cancellation possible without keeping a separate map of abort handles.
+///
+/// This type is only available when the `std` or `alloc` feature of this
+/// library is activated, and it is activated by default.
+///
+/// # Examples
+///
+/// ```
+/// # futures::executor::block_on(async {
+/// use futures::future::{self, FutureExt};
+/// use futures::stream::{KeyedFuturesUnordered, StreamExt};
+///
+/// let mut set = KeyedFuturesUnordered::new();
+/// let stalled = set.push(future::pending::<&str>().left_future());
+/// let ready = set.push(future::ready("done").right_future());
+///
+/// assert!(set.cancel(stalled));
+/// assert!(!set.cancel(stalled));
+/// assert_eq!(set.next().await, Some((ready, "done")));
+/// assert_eq!(set.next().await, None);
+/// # });
+/// ```
+#[must_use = "streams do nothing unless polled"]
+pub struct KeyedFuturesUnordered<Fut> {
+    inner: FuturesUnordered<TokenWrapper<Fut>>,
+    // The futures in the set, indexed by the slots of their tokens.
+    slots: Vec<Option<Slot<Fut>>>,
+    free_slots: Vec<usize>,
+    next_id: usize,
+}
+
+struct Slot<Fut> {
+    id: usize,
+    task: TaskHandle<TokenWrapper<Fut>>,
+}
+
+impl<Fut> KeyedFuturesUnordered<Fut> {
+    /// Constructs a new, empty `KeyedFuturesUnordered`.
+    ///
+    /// The returned `KeyedFuturesUnordered` does not contain any futures.
+    /// In this state, `KeyedFuturesUnordered::poll_next` will return
+    /// `Poll::Ready(None)`.
+    pub fn new() -> Self {
+        Self {
+            inner: FuturesUnordered::new(),
+            slots: Vec::new(),
+            free_slots: Vec::new(),
+            next_id: 0,
+        }
+    }
+
+    /// Returns the number of futures contained in the set.
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    /// Returns `true` if the set contains no futures.
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    /// Push a future into the set, returning the token that identifies it.
+    ///
+    /// This method will not call `poll` on the submitted future. The caller
+    /// must ensure that `KeyedFuturesUnordered::poll_next` is called in order
+    /// to receive wake-up notifications for the given future.
+    pub fn push(&mut self, future: Fut) -> FutureToken {
+        let slot = match self.free_slots.pop() {
+            Some(slot) => slot,
+            None => {
+                self.slots.push(None);
+                self.slots.len() - 1
+            }
+        };
+        let token = FutureToken { id: self.next_id, slot };
+        self.next_id += 1;
+        let task = self.inner.push_task(TokenWrapper { future, token });
+        self.slots[slot] = Some(Slot { id: token.id, task });
+        token
+    }
+
+    /// Returns `true` if the future identified by `token` is still in the set.
+    pub fn contains(&self, token: FutureToken) -> bool {
+        match self.slots.get(token.slot) {
+            Some(Some(slot)) => slot.id == token.id,
+            _ => false,
+        }
+    }
+
+    /// Cancels the future identified by `token`, dropping it without polling
+    /// it again.
+    ///
+    /// Returns `false` if the future has already completed or been cancelled.
+    pub fn cancel(&mut self, token: FutureToken) -> bool {
+        if !self.contains(token) {
+            return false;
+        }
+        let slot = self.remove_slot(token).unwrap();
+        self.inner.remove_task(&slot.task)
+    }
+
+    /// Clears the set, removing all futures.
+    pub fn clear(&mut self) {
+        self.inner.clear();
+        self.slots.clear();
+        self.free_slots.clear();
+    }
+
+    fn remove_slot(&mut self, token: FutureToken) -> Option<Slot<Fut>> {
+        let slot = self.slots.get_mut(token.slot)?.take();
+        if slot.is_some() {
+            self.free_slots.push(token.slot);
+        }
+        slot
+    }
+}
+
+impl<Fut> Default for KeyedFuturesUnordered<Fut> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Fut> Debug for KeyedFuturesUnordered<Fut> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "KeyedFuturesUnordered {{ ... }}")
+    }
+}
+
+impl<Fut: Future> Stream for KeyedFuturesUnordered<Fut> {
+    type Item = (FutureToken, Fut::Output);
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let item = ready!(self.inner.poll_next_unpin(cx));
+        if let Some((token, _)) = &item {
+            self.remove_slot(*token);
+        }
+        Poll::Ready(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<Fut: Future> FusedStream for KeyedFuturesUnordered<Fut> {
+    fn is_terminated(&self) -> bool {
+        self.inner.is_terminated()
+    }
+}
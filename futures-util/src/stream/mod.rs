@@ -18,28 +18,42 @@ pub use futures_core::stream::{FusedStream, Stream, TryStream};
 #[allow(clippy::module_inception)]
 mod stream;
 pub use self::stream::{
-    Chain, Collect, Concat, Cycle, Enumerate, Filter, FilterMap, FlatMap, Flatten, Fold, ForEach,
-    Fuse, Inspect, Map, Next, NextIf, NextIfEq, Peek, PeekMut, Peekable, Scan, SelectNextSome,
-    Skip, SkipWhile, StreamExt, StreamFuture, Take, TakeUntil, TakeWhile, Then, TryFold,
-    TryForEach, Unzip, Zip,
+    Chain, Collect, CollectInto, Concat, Cycle, DelayItems, Enumerate, Filter, FilterMap, FlatMap,
+    Flatten, Fold, ForEach, Fuse, Inspect, Intersperse, IntersperseWith, Map, MaxBy, MaxByKey,
+    MinBy, MinByKey, Next, NextIf, NextIfEq, Peek, PeekMut, Peekable, Position, Reduce, Sample,
+    Scan, SelectNextSome, Skip, SkipWhile, StartWith, StreamExt, StreamFuture, Take, TakeUntil,
+    TakeWhile, Then, TryFold, TryForEach, Unzip, WithLatestFrom, Zip, ZipWith,
 };
 
 #[cfg(feature = "std")]
-pub use self::stream::CatchUnwind;
+pub use self::stream::{CatchUnwind, InstrumentPolls, PollStats};
+
+#[cfg(feature = "async-iterator")]
+pub use self::stream::IntoAsyncIter;
+
+#[cfg(not(futures_no_atomic_cas))]
+#[cfg(feature = "std")]
+pub use self::stream::{Metered, Metrics};
 
 #[cfg(feature = "alloc")]
-pub use self::stream::Chunks;
+pub use self::stream::{BufferWhile, Chunks, ChunksExact};
 
 #[cfg(feature = "alloc")]
-pub use self::stream::ReadyChunks;
+pub use self::stream::{ReadyChunks, ReadyChunksTimeout};
+
+#[cfg(feature = "alloc")]
+pub use self::stream::SplitAt;
 
 #[cfg(feature = "sink")]
 #[cfg_attr(docsrs, doc(cfg(feature = "sink")))]
-pub use self::stream::Forward;
+pub use self::stream::{Forward, ForwardFlushEvery, ForwardFlushInterval};
 
 #[cfg(not(futures_no_atomic_cas))]
 #[cfg(feature = "alloc")]
-pub use self::stream::{BufferUnordered, Buffered, ForEachConcurrent, TryForEachConcurrent};
+pub use self::stream::{
+    BufferUnordered, Buffered, ErrorPolicy, ForEachConcurrent, ForEachConcurrentWithResults,
+    MapConcurrent, TryForEachConcurrent, TryForEachConcurrentWithPolicy, TryMapConcurrent,
+};
 
 #[cfg(not(futures_no_atomic_cas))]
 #[cfg(feature = "sink")]
@@ -50,8 +64,8 @@ pub use self::stream::{ReuniteError, SplitSink, SplitStream};
 mod try_stream;
 pub use self::try_stream::{
     try_unfold, AndThen, ErrInto, InspectErr, InspectOk, IntoStream, MapErr, MapOk, OrElse,
-    TryCollect, TryConcat, TryFilter, TryFilterMap, TryFlatten, TryNext, TrySkipWhile,
-    TryStreamExt, TryTakeWhile, TryUnfold,
+    OrElseStream, TryCollect, TryCollectInto, TryConcat, TryFilter, TryFilterMap, TryFlatten,
+    TryNext, TrySkipWhile, TryStartWith, TryStreamExt, TryTakeWhile, TryUnfold,
 };
 
 #[cfg(feature = "io")]
@@ -75,12 +89,26 @@ pub use self::try_stream::{TryChunks, TryChunksError};
 mod iter;
 pub use self::iter::{iter, Iter};
 
+#[cfg(feature = "async-iterator")]
+mod from_async_iter;
+#[cfg(feature = "async-iterator")]
+pub use self::from_async_iter::{from_async_iter, FromAsyncIter};
+
+#[cfg(feature = "channel")]
+mod iter_blocking;
+#[cfg(feature = "channel")]
+#[cfg_attr(docsrs, doc(cfg(feature = "channel")))]
+pub use self::iter_blocking::{iter_blocking, IterBlocking};
+
 mod repeat;
 pub use self::repeat::{repeat, Repeat};
 
 mod repeat_with;
 pub use self::repeat_with::{repeat_with, RepeatWith};
 
+mod repeat_with_async;
+pub use self::repeat_with_async::{repeat_with_async, RepeatWithAsync};
+
 mod empty;
 pub use self::empty::{empty, Empty};
 
@@ -105,6 +133,9 @@ pub use self::select_with_strategy::{select_with_strategy, PollNext, SelectWithS
 mod unfold;
 pub use self::unfold::{unfold, Unfold};
 
+mod unfold_final;
+pub use self::unfold_final::{unfold_final, UnfoldFinal, UnfoldStep};
+
 #[cfg(not(futures_no_atomic_cas))]
 #[cfg(feature = "alloc")]
 mod futures_ordered;
@@ -120,6 +151,20 @@ pub mod futures_unordered;
 #[doc(inline)]
 pub use self::futures_unordered::FuturesUnordered;
 
+#[cfg(not(futures_no_atomic_cas))]
+#[cfg(feature = "alloc")]
+mod bounded_futures_unordered;
+#[cfg(not(futures_no_atomic_cas))]
+#[cfg(feature = "alloc")]
+pub use self::bounded_futures_unordered::BoundedFuturesUnordered;
+
+#[cfg(not(futures_no_atomic_cas))]
+#[cfg(feature = "alloc")]
+mod keyed_futures_unordered;
+#[cfg(not(futures_no_atomic_cas))]
+#[cfg(feature = "alloc")]
+pub use self::keyed_futures_unordered::{FutureToken, KeyedFuturesUnordered};
+
 #[cfg(not(futures_no_atomic_cas))]
 #[cfg(feature = "alloc")]
 pub mod select_all;
@@ -128,15 +173,27 @@ pub mod select_all;
 #[doc(inline)]
 pub use self::select_all::{select_all, SelectAll};
 
+#[cfg(not(futures_no_atomic_cas))]
+#[cfg(feature = "alloc")]
+mod stream_group;
+#[cfg(not(futures_no_atomic_cas))]
+#[cfg(feature = "alloc")]
+pub use self::stream_group::{StreamGroup, StreamGroupEvent};
+
+#[cfg(feature = "alloc")]
+mod priority_select_all;
+#[cfg(feature = "alloc")]
+pub use self::priority_select_all::{PrioritySelectAll, PriorityStrategy};
+
 #[cfg(not(futures_no_atomic_cas))]
 #[cfg(feature = "alloc")]
 mod abortable;
 #[cfg(not(futures_no_atomic_cas))]
 #[cfg(feature = "alloc")]
-pub use crate::abortable::{AbortHandle, AbortRegistration, Abortable, Aborted};
+pub use crate::abortable::{AbortHandle, AbortRegistration, Abortable, Aborted, DrainAbortable};
 #[cfg(not(futures_no_atomic_cas))]
 #[cfg(feature = "alloc")]
-pub use abortable::abortable;
+pub use abortable::{abortable, drain_abortable};
 
 // Just a helper function to ensure the streams we're returning all have the
 // right implementations.
@@ -0,0 +1,227 @@
+use alloc::vec::Vec;
+use core::fmt::{self, Debug};
+use core::pin::Pin;
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Context, Poll};
+
+use crate::stream::StreamExt;
+
+/// How a [`PrioritySelectAll`] chooses between streams that have an item
+/// ready.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PriorityStrategy {
+    /// Always yield from the ready stream with the highest priority.
+    ///
+    /// Lower priority streams are only served while every higher priority
+    /// stream is pending, so they can be starved. Streams with equal
+    /// priority take turns.
+    Strict,
+    /// Share the output between the ready streams in proportion to their
+    /// priorities, which act as weights.
+    ///
+    /// A stream with priority 3 is served three times as often as a ready
+    /// stream with priority 1, but no ready stream is ever starved.
+    WeightedFair,
+}
+
+impl Default for PriorityStrategy {
+    fn default() -> Self {
+        PriorityStrategy::Strict
+    }
+}
+
+struct Entry<St: Stream> {
+    stream: St,
+    item: Option<St::Item>,
+    done: bool,
+    priority: u32,
+    current: i64,
+    last_yield: u64,
+}
+
+/// A set of streams merged according to per-stream priorities.
+///
+/// This is like [`SelectAll`](super::SelectAll), but each stream is pushed
+/// with a priority, and when several streams have an item ready the
+/// [`PriorityStrategy`] decides which one is yielded. This lets, for
+/// example, control messages preempt bulk data in a multiplexed consumer.
+///
+/// To compare streams, the set pulls at most one item ahead from each of
+/// them and holds it until it is its turn. Every stream is polled on every
+/// call to `poll_next`, so this is meant for a modest number of streams.
+///
+/// The set terminates once all of its streams have terminated.
+///
+/// # Examples
+///
+/// ```
+/// # futures::executor::block_on(async {
+/// use futures::stream::{self, PrioritySelectAll, PriorityStrategy, StreamExt};
+///
+/// let mut set = PrioritySelectAll::new(PriorityStrategy::Strict);
+/// set.push(stream::iter(vec!["bulk 1", "bulk 2"]), 1);
+/// set.push(stream::iter(vec!["control 1", "control 2"]), 10);
+///
+/// let items = set.collect::<Vec<_>>().await;
+/// assert_eq!(items, vec!["control 1", "control 2", "bulk 1", "bulk 2"]);
+/// # });
+/// ```
+#[must_use = "streams do nothing unless polled"]
+pub struct PrioritySelectAll<St: Stream> {
+    entries: Vec<Entry<St>>,
+    strategy: PriorityStrategy,
+    yields: u64,
+    is_terminated: bool,
+}
+
+// Neither the streams nor their buffered items are ever pinned.
+impl<St: Stream> Unpin for PrioritySelectAll<St> {}
+
+impl<St: Stream + Unpin> PrioritySelectAll<St> {
+    /// Constructs a new, empty `PrioritySelectAll` using the given strategy.
+    pub fn new(strategy: PriorityStrategy) -> Self {
+        Self { entries: Vec::new(), strategy, yields: 0, is_terminated: false }
+    }
+
+    /// Returns the strategy used to choose between ready streams.
+    pub fn strategy(&self) -> PriorityStrategy {
+        self.strategy
+    }
+
+    /// Returns the number of streams contained in the set.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if the set contains no streams.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Push a stream into the set with the given priority.
+    ///
+    /// Higher values mean higher priority. With
+    /// [`PriorityStrategy::WeightedFair`], a priority of zero is treated as
+    /// one.
+    ///
+    /// This function will not call `poll` on the submitted stream. The caller
+    /// must ensure that `PrioritySelectAll::poll_next` is called in order to
+    /// receive task notifications.
+    pub fn push(&mut self, stream: St, priority: u32) {
+        self.entries.push(Entry {
+            stream,
+            item: None,
+            done: false,
+            priority,
+            current: 0,
+            last_yield: 0,
+        });
+        self.is_terminated = false;
+    }
+
+    /// Clears the set, removing all streams and any items pulled ahead from
+    /// them.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    fn weight(&self, entry: &Entry<St>) -> i64 {
+        i64::from(entry.priority.max(1))
+    }
+
+    /// Picks the ready entry to yield from next.
+    fn select(&self) -> Option<usize> {
+        let mut best: Option<(usize, i64)> = None;
+        for (index, entry) in self.entries.iter().enumerate() {
+            if entry.item.is_none() {
+                continue;
+            }
+            let score = match self.strategy {
+                PriorityStrategy::Strict => i64::from(entry.priority),
+                PriorityStrategy::WeightedFair => entry.current + self.weight(entry),
+            };
+            // Ties go to the entry that yielded least recently.
+            let better = match best {
+                None => true,
+                Some((best_index, best_score)) => {
+                    score > best_score
+                        || (score == best_score
+                            && entry.last_yield < self.entries[best_index].last_yield)
+                }
+            };
+            if better {
+                best = Some((index, score));
+            }
+        }
+        best.map(|(index, _)| index)
+    }
+}
+
+impl<St: Stream + Unpin> Stream for PrioritySelectAll<St> {
+    type Item = St::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+
+        for entry in &mut this.entries {
+            if entry.item.is_none() && !entry.done {
+                match entry.stream.poll_next_unpin(cx) {
+                    Poll::Ready(Some(item)) => entry.item = Some(item),
+                    Poll::Ready(None) => entry.done = true,
+                    Poll::Pending => {}
+                }
+            }
+        }
+        this.entries.retain(|entry| !entry.done || entry.item.is_some());
+
+        let index = match this.select() {
+            Some(index) => index,
+            None if this.entries.is_empty() => {
+                this.is_terminated = true;
+                return Poll::Ready(None);
+            }
+            None => return Poll::Pending,
+        };
+
+        if this.strategy == PriorityStrategy::WeightedFair {
+            // Smooth weighted round-robin over the streams that are ready.
+            let mut total = 0;
+            for i in 0..this.entries.len() {
+                if this.entries[i].item.is_some() {
+                    let weight = this.weight(&this.entries[i]);
+                    this.entries[i].current += weight;
+                    total += weight;
+                }
+            }
+            this.entries[index].current -= total;
+        }
+
+        this.yields += 1;
+        let entry = &mut this.entries[index];
+        entry.last_yield = this.yields;
+        Poll::Ready(entry.item.take())
+    }
+}
+
+impl<St: Stream + Unpin> FusedStream for PrioritySelectAll<St> {
+    fn is_terminated(&self) -> bool {
+        self.is_terminated
+    }
+}
+
+impl<St: Stream + Unpin> Extend<(St, u32)> for PrioritySelectAll<St> {
+    fn extend<T: IntoIterator<Item = (St, u32)>>(&mut self, iter: T) {
+        for (stream, priority) in iter {
+            self.push(stream, priority);
+        }
+    }
+}
+
+impl<St: Stream> Debug for PrioritySelectAll<St> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PrioritySelectAll")
+            .field("len", &self.entries.len())
+            .field("strategy", &self.strategy)
+            .finish()
+    }
+}
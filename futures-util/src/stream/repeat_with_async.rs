@@ -0,0 +1,100 @@
+use super::assert_stream;
+use core::fmt;
+use core::pin::Pin;
+use futures_core::future::Future;
+use futures_core::ready;
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Context, Poll};
+use pin_project_lite::pin_project;
+
+pin_project! {
+    /// An stream that repeats elements of type `A` endlessly by
+    /// awaiting the futures returned by the provided closure
+    /// `F: FnMut() -> Fut`.
+    ///
+    /// This `struct` is created by the [`repeat_with_async()`] function.
+    /// See its documentation for more.
+    #[must_use = "streams do nothing unless polled"]
+    pub struct RepeatWithAsync<F, Fut> {
+        repeater: F,
+        #[pin]
+        future: Option<Fut>,
+    }
+}
+
+impl<F, Fut> fmt::Debug for RepeatWithAsync<F, Fut>
+where
+    Fut: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RepeatWithAsync").field("future", &self.future).finish()
+    }
+}
+
+impl<F, Fut> Stream for RepeatWithAsync<F, Fut>
+where
+    F: FnMut() -> Fut,
+    Fut: Future,
+{
+    type Item = Fut::Output;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        if this.future.is_none() {
+            this.future.set(Some((this.repeater)()));
+        }
+
+        let item = ready!(this.future.as_mut().as_pin_mut().unwrap().poll(cx));
+        this.future.set(None);
+        Poll::Ready(Some(item))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::max_value(), None)
+    }
+}
+
+impl<F, Fut> FusedStream for RepeatWithAsync<F, Fut>
+where
+    F: FnMut() -> Fut,
+    Fut: Future,
+{
+    fn is_terminated(&self) -> bool {
+        false
+    }
+}
+
+/// Creates a new stream that repeats elements endlessly by calling the
+/// provided closure and awaiting the future it returns.
+///
+/// This is the asynchronous counterpart of [`repeat_with()`](super::repeat_with()):
+/// each item is produced by a fresh call to the repeater, and the next call is
+/// only made once the previous future has completed.
+///
+/// Infinite streams like `repeat_with_async()` are often used with adapters
+/// like [`take`](super::StreamExt::take), in order to make them finite.
+///
+/// # Examples
+///
+/// ```
+/// # futures::executor::block_on(async {
+/// use futures::stream::{self, StreamExt};
+///
+/// let mut count = 0;
+/// let stream = stream::repeat_with_async(|| {
+///     count += 1;
+///     let value = count;
+///     async move { value * 10 }
+/// });
+///
+/// assert_eq!(stream.take(3).collect::<Vec<_>>().await, vec![10, 20, 30]);
+/// # });
+/// ```
+pub fn repeat_with_async<F, Fut>(repeater: F) -> RepeatWithAsync<F, Fut>
+where
+    F: FnMut() -> Fut,
+    Fut: Future,
+{
+    assert_stream::<Fut::Output, _>(RepeatWithAsync { repeater, future: None })
+}
@@ -0,0 +1,119 @@
+use crate::stream::Fuse;
+use alloc::vec::Vec;
+use core::fmt;
+use core::mem;
+use core::pin::Pin;
+use futures_core::ready;
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Context, Poll};
+#[cfg(feature = "sink")]
+use futures_sink::Sink;
+use pin_project_lite::pin_project;
+
+pin_project! {
+    /// Stream for the [`buffer_while`](super::StreamExt::buffer_while) method.
+    #[must_use = "streams do nothing unless polled"]
+    pub struct BufferWhile<St: Stream, F> {
+        #[pin]
+        stream: Fuse<St>,
+        items: Vec<St::Item>,
+        f: F,
+    }
+}
+
+impl<St, F> fmt::Debug for BufferWhile<St, F>
+where
+    St: Stream + fmt::Debug,
+    St::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BufferWhile")
+            .field("stream", &self.stream)
+            .field("items", &self.items)
+            .finish()
+    }
+}
+
+impl<St, F> BufferWhile<St, F>
+where
+    St: Stream,
+    F: FnMut(&[St::Item], &St::Item) -> bool,
+{
+    pub(super) fn new(stream: St, f: F) -> Self {
+        Self { stream: super::Fuse::new(stream), items: Vec::new(), f }
+    }
+
+    delegate_access_inner!(stream, St, (.));
+}
+
+impl<St, F> Stream for BufferWhile<St, F>
+where
+    St: Stream,
+    F: FnMut(&[St::Item], &St::Item) -> bool,
+{
+    type Item = Vec<St::Item>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+        loop {
+            match ready!(this.stream.as_mut().poll_next(cx)) {
+                // The first item of a batch is always accepted. Later items
+                // are added while the predicate holds; the item that breaks
+                // it starts the next batch.
+                Some(item) => {
+                    if this.items.is_empty() || (this.f)(this.items, &item) {
+                        this.items.push(item);
+                    } else {
+                        let batch = mem::replace(this.items, Vec::new());
+                        this.items.push(item);
+                        return Poll::Ready(Some(batch));
+                    }
+                }
+
+                // Since the underlying stream ran out of values, return what we
+                // have buffered, if we have anything.
+                None => {
+                    let last = if this.items.is_empty() {
+                        None
+                    } else {
+                        Some(mem::replace(this.items, Vec::new()))
+                    };
+
+                    return Poll::Ready(last);
+                }
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let batch_len = if self.items.is_empty() { 0 } else { 1 };
+        let (lower, upper) = self.stream.size_hint();
+        let lower = if lower > 0 || batch_len > 0 { 1 } else { 0 };
+        let upper = match upper {
+            Some(x) => x.checked_add(batch_len),
+            None => None,
+        };
+        (lower, upper)
+    }
+}
+
+impl<St, F> FusedStream for BufferWhile<St, F>
+where
+    St: FusedStream,
+    F: FnMut(&[St::Item], &St::Item) -> bool,
+{
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated() && self.items.is_empty()
+    }
+}
+
+// Forwarding impl of Sink from the underlying stream
+#[cfg(feature = "sink")]
+impl<S, F, Item> Sink<Item> for BufferWhile<S, F>
+where
+    S: Stream + Sink<Item>,
+{
+    type Error = S::Error;
+
+    delegate_sink!(stream, Item);
+}
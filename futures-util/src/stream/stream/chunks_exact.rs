@@ -0,0 +1,116 @@
+use crate::stream::Fuse;
+use alloc::vec::Vec;
+use core::mem;
+use core::pin::Pin;
+use futures_core::ready;
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Context, Poll};
+#[cfg(feature = "sink")]
+use futures_sink::Sink;
+use pin_project_lite::pin_project;
+
+pin_project! {
+    /// Stream for the [`chunks_exact`](super::StreamExt::chunks_exact) method.
+    #[derive(Debug)]
+    #[must_use = "streams do nothing unless polled"]
+    pub struct ChunksExact<St: Stream> {
+        #[pin]
+        stream: Fuse<St>,
+        items: Vec<St::Item>,
+        cap: usize,
+    }
+}
+
+impl<St: Stream> ChunksExact<St>
+where
+    St: Stream,
+{
+    pub(super) fn new(stream: St, capacity: usize) -> Self {
+        assert!(capacity > 0);
+
+        Self {
+            stream: super::Fuse::new(stream),
+            items: Vec::with_capacity(capacity),
+            cap: capacity,
+        }
+    }
+
+    /// Returns the items which have been buffered but do not make up a full
+    /// chunk.
+    ///
+    /// Once the underlying stream has terminated, these are the left over
+    /// items which were not yielded from this stream.
+    pub fn remainder(&self) -> &[St::Item] {
+        &self.items
+    }
+
+    /// Takes the items which have been buffered but do not make up a full
+    /// chunk, leaving the buffer empty.
+    pub fn take_remainder(self: Pin<&mut Self>) -> Vec<St::Item> {
+        mem::replace(self.project().items, Vec::new())
+    }
+
+    /// Consumes this combinator, returning the items which have been buffered
+    /// but do not make up a full chunk.
+    pub fn into_remainder(self) -> Vec<St::Item> {
+        self.items
+    }
+
+    delegate_access_inner!(stream, St, (.));
+}
+
+impl<St: Stream> Stream for ChunksExact<St> {
+    type Item = Vec<St::Item>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+        loop {
+            match ready!(this.stream.as_mut().poll_next(cx)) {
+                // Push the item into the buffer and check whether it is full.
+                // If so, replace our buffer with a new and empty one and return
+                // the full one.
+                Some(item) => {
+                    this.items.push(item);
+                    if this.items.len() >= *this.cap {
+                        return Poll::Ready(Some(mem::replace(
+                            this.items,
+                            Vec::with_capacity(*this.cap),
+                        )));
+                    }
+                }
+
+                // Since the underlying stream ran out of values, keep the
+                // partial chunk, if any, as the remainder.
+                None => return Poll::Ready(None),
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.stream.size_hint();
+        let buffered = self.items.len();
+        let lower = lower.saturating_add(buffered) / self.cap;
+        let upper = match upper {
+            Some(x) => x.checked_add(buffered).map(|x| x / self.cap),
+            None => None,
+        };
+        (lower, upper)
+    }
+}
+
+impl<St: FusedStream> FusedStream for ChunksExact<St> {
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated()
+    }
+}
+
+// Forwarding impl of Sink from the underlying stream
+#[cfg(feature = "sink")]
+impl<S, Item> Sink<Item> for ChunksExact<S>
+where
+    S: Stream + Sink<Item>,
+{
+    type Error = S::Error;
+
+    delegate_sink!(stream, Item);
+}
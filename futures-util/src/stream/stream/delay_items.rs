@@ -0,0 +1,112 @@
+use core::fmt;
+use core::pin::Pin;
+use futures_core::future::Future;
+use futures_core::ready;
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Context, Poll};
+#[cfg(feature = "sink")]
+use futures_sink::Sink;
+use pin_project_lite::pin_project;
+
+pin_project! {
+    /// Stream for the [`delay_items`](super::StreamExt::delay_items) method.
+    #[must_use = "streams do nothing unless polled"]
+    pub struct DelayItems<St: Stream, F, Fut> {
+        #[pin]
+        stream: St,
+        f: F,
+        item: Option<St::Item>,
+        #[pin]
+        delay: Option<Fut>,
+    }
+}
+
+impl<St, F, Fut> fmt::Debug for DelayItems<St, F, Fut>
+where
+    St: Stream + fmt::Debug,
+    St::Item: fmt::Debug,
+    Fut: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DelayItems")
+            .field("stream", &self.stream)
+            .field("item", &self.item)
+            .field("delay", &self.delay)
+            .finish()
+    }
+}
+
+impl<St, F, Fut> DelayItems<St, F, Fut>
+where
+    St: Stream,
+    F: FnMut(&St::Item) -> Fut,
+    Fut: Future,
+{
+    pub(super) fn new(stream: St, f: F) -> Self {
+        Self { stream, f, item: None, delay: None }
+    }
+
+    delegate_access_inner!(stream, St, ());
+}
+
+impl<St, F, Fut> FusedStream for DelayItems<St, F, Fut>
+where
+    St: FusedStream,
+    F: FnMut(&St::Item) -> Fut,
+    Fut: Future,
+{
+    fn is_terminated(&self) -> bool {
+        self.item.is_none() && self.stream.is_terminated()
+    }
+}
+
+impl<St, F, Fut> Stream for DelayItems<St, F, Fut>
+where
+    St: Stream,
+    F: FnMut(&St::Item) -> Fut,
+    Fut: Future,
+{
+    type Item = St::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        if this.item.is_none() {
+            match ready!(this.stream.as_mut().poll_next(cx)) {
+                Some(item) => {
+                    this.delay.set(Some((this.f)(&item)));
+                    *this.item = Some(item);
+                }
+                None => return Poll::Ready(None),
+            }
+        }
+
+        if let Some(delay) = this.delay.as_mut().as_pin_mut() {
+            ready!(delay.poll(cx));
+            this.delay.set(None);
+        }
+        Poll::Ready(this.item.take())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let pending_len = if self.item.is_some() { 1 } else { 0 };
+        let (lower, upper) = self.stream.size_hint();
+        let lower = lower.saturating_add(pending_len);
+        let upper = match upper {
+            Some(x) => x.checked_add(pending_len),
+            None => None,
+        };
+        (lower, upper)
+    }
+}
+
+// Forwarding impl of Sink from the underlying stream
+#[cfg(feature = "sink")]
+impl<S, F, Fut, Item> Sink<Item> for DelayItems<S, F, Fut>
+where
+    S: Stream + Sink<Item>,
+{
+    type Error = S::Error;
+
+    delegate_sink!(stream, Item);
+}
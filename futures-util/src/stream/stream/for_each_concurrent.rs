@@ -3,6 +3,7 @@ use core::fmt;
 use core::num::NonZeroUsize;
 use core::pin::Pin;
 use futures_core::future::{FusedFuture, Future};
+use futures_core::ready;
 use futures_core::stream::Stream;
 use futures_core::task::{Context, Poll};
 use pin_project_lite::pin_project;
@@ -73,6 +74,7 @@ where
     fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
         let mut this = self.project();
         loop {
+            ready!(crate::task::coop::poll_proceed(cx));
             let mut made_progress_this_iter = false;
 
             // Check if we've already created a number of futures greater than `limit`
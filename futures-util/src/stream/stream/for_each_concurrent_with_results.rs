@@ -0,0 +1,161 @@
+use crate::stream::{FuturesUnordered, StreamExt};
+use alloc::vec::Vec;
+use core::fmt;
+use core::mem;
+use core::num::NonZeroUsize;
+use core::pin::Pin;
+use futures_core::future::{FusedFuture, Future};
+use futures_core::ready;
+use futures_core::stream::Stream;
+use futures_core::task::{Context, Poll};
+use pin_project_lite::pin_project;
+
+pin_project! {
+    /// A future tagged with the position of the stream item that produced it.
+    #[derive(Debug)]
+    pub(super) struct Indexed<Fut> {
+        #[pin]
+        future: Fut,
+        index: usize,
+    }
+}
+
+impl<Fut> Indexed<Fut> {
+    pub(super) fn new(future: Fut, index: usize) -> Self {
+        Self { future, index }
+    }
+}
+
+impl<Fut: Future> Future for Indexed<Fut> {
+    type Output = (usize, Fut::Output);
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        let output = ready!(this.future.poll(cx));
+        Poll::Ready((*this.index, output))
+    }
+}
+
+pin_project! {
+    /// Future for the
+    /// [`for_each_concurrent_with_results`](super::StreamExt::for_each_concurrent_with_results)
+    /// method.
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct ForEachConcurrentWithResults<St, Fut, F>
+        where Fut: Future
+    {
+        #[pin]
+        stream: Option<St>,
+        f: F,
+        futures: FuturesUnordered<Indexed<Fut>>,
+        limit: Option<NonZeroUsize>,
+        next_index: usize,
+        results: Vec<(usize, Fut::Output)>,
+    }
+}
+
+impl<St, Fut, F> fmt::Debug for ForEachConcurrentWithResults<St, Fut, F>
+where
+    St: fmt::Debug,
+    Fut: Future + fmt::Debug,
+    Fut::Output: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ForEachConcurrentWithResults")
+            .field("stream", &self.stream)
+            .field("futures", &self.futures)
+            .field("limit", &self.limit)
+            .field("results", &self.results)
+            .finish()
+    }
+}
+
+impl<St, Fut, F> ForEachConcurrentWithResults<St, Fut, F>
+where
+    St: Stream,
+    F: FnMut(St::Item) -> Fut,
+    Fut: Future,
+{
+    pub(super) fn new(stream: St, limit: Option<usize>, f: F) -> Self {
+        Self {
+            stream: Some(stream),
+            // Note: `limit` = 0 gets ignored.
+            limit: limit.and_then(NonZeroUsize::new),
+            f,
+            futures: FuturesUnordered::new(),
+            next_index: 0,
+            results: Vec::new(),
+        }
+    }
+}
+
+impl<St, Fut, F> FusedFuture for ForEachConcurrentWithResults<St, Fut, F>
+where
+    St: Stream,
+    F: FnMut(St::Item) -> Fut,
+    Fut: Future,
+{
+    fn is_terminated(&self) -> bool {
+        self.stream.is_none() && self.futures.is_empty()
+    }
+}
+
+impl<St, Fut, F> Future for ForEachConcurrentWithResults<St, Fut, F>
+where
+    St: Stream,
+    F: FnMut(St::Item) -> Fut,
+    Fut: Future,
+{
+    type Output = Vec<(usize, Fut::Output)>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut this = self.project();
+        loop {
+            let mut made_progress_this_iter = false;
+
+            // Check if we've already created a number of futures greater than `limit`
+            if this.limit.map(|limit| limit.get() > this.futures.len()).unwrap_or(true) {
+                let mut stream_completed = false;
+                let elem = if let Some(stream) = this.stream.as_mut().as_pin_mut() {
+                    match stream.poll_next(cx) {
+                        Poll::Ready(Some(elem)) => {
+                            made_progress_this_iter = true;
+                            Some(elem)
+                        }
+                        Poll::Ready(None) => {
+                            stream_completed = true;
+                            None
+                        }
+                        Poll::Pending => None,
+                    }
+                } else {
+                    None
+                };
+                if stream_completed {
+                    this.stream.set(None);
+                }
+                if let Some(elem) = elem {
+                    this.futures.push(Indexed::new((this.f)(elem), *this.next_index));
+                    *this.next_index += 1;
+                }
+            }
+
+            match this.futures.poll_next_unpin(cx) {
+                Poll::Ready(Some(result)) => {
+                    this.results.push(result);
+                    made_progress_this_iter = true;
+                }
+                Poll::Ready(None) => {
+                    if this.stream.is_none() {
+                        return Poll::Ready(mem::replace(this.results, Vec::new()));
+                    }
+                }
+                Poll::Pending => {}
+            }
+
+            if !made_progress_this_iter {
+                return Poll::Pending;
+            }
+        }
+    }
+}
@@ -56,6 +56,11 @@ where
                 si.as_mut().start_send(buffered_item.take().unwrap())?;
             }
 
+            if crate::task::coop::poll_proceed(cx).is_pending() {
+                ready!(si.poll_flush(cx))?;
+                return Poll::Pending;
+            }
+
             match stream.as_mut().poll_next(cx) {
                 Poll::Ready(Some(item)) => {
                     *buffered_item = Some(item);
@@ -0,0 +1,127 @@
+use crate::stream::Fuse;
+use core::fmt;
+use core::pin::Pin;
+use futures_core::future::{FusedFuture, Future};
+use futures_core::ready;
+use futures_core::stream::Stream;
+use futures_core::task::{Context, Poll};
+use futures_sink::Sink;
+use pin_project_lite::pin_project;
+
+pin_project! {
+    /// Future for the [`forward_flush_every`](super::StreamExt::forward_flush_every) method.
+    #[project = ForwardFlushEveryProj]
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct ForwardFlushEvery<St, Si>
+    where
+        St: Stream,
+    {
+        #[pin]
+        sink: Option<Si>,
+        #[pin]
+        stream: Fuse<St>,
+        buffered_item: Option<St::Item>,
+        unflushed: usize,
+        every: usize,
+        flushing: bool,
+    }
+}
+
+impl<St, Si> fmt::Debug for ForwardFlushEvery<St, Si>
+where
+    St: Stream + fmt::Debug,
+    St::Item: fmt::Debug,
+    Si: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ForwardFlushEvery")
+            .field("sink", &self.sink)
+            .field("stream", &self.stream)
+            .field("buffered_item", &self.buffered_item)
+            .field("unflushed", &self.unflushed)
+            .field("every", &self.every)
+            .field("flushing", &self.flushing)
+            .finish()
+    }
+}
+
+impl<St: Stream, Si> ForwardFlushEvery<St, Si> {
+    pub(crate) fn new(stream: St, sink: Si, every: usize) -> Self {
+        assert!(every > 0);
+
+        Self {
+            sink: Some(sink),
+            stream: Fuse::new(stream),
+            buffered_item: None,
+            unflushed: 0,
+            every,
+            flushing: false,
+        }
+    }
+}
+
+impl<St, Si> FusedFuture for ForwardFlushEvery<St, Si>
+where
+    Si: Sink<St::Item>,
+    St: Stream,
+{
+    fn is_terminated(&self) -> bool {
+        self.sink.is_none()
+    }
+}
+
+impl<St, Si> Future for ForwardFlushEvery<St, Si>
+where
+    Si: Sink<St::Item>,
+    St: Stream,
+{
+    type Output = Result<(), Si::Error>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let ForwardFlushEveryProj {
+            mut sink,
+            mut stream,
+            buffered_item,
+            unflushed,
+            every,
+            flushing,
+        } = self.project();
+        let mut si =
+            sink.as_mut().as_pin_mut().expect("polled `ForwardFlushEvery` after completion");
+
+        loop {
+            // If we've got an item buffered already, we need to write it to the
+            // sink before we can do anything else
+            if buffered_item.is_some() {
+                ready!(si.as_mut().poll_ready(cx))?;
+                si.as_mut().start_send(buffered_item.take().unwrap())?;
+                *unflushed += 1;
+                if *unflushed >= *every {
+                    *flushing = true;
+                }
+            }
+
+            if *flushing {
+                ready!(si.as_mut().poll_flush(cx))?;
+                *flushing = false;
+                *unflushed = 0;
+            }
+
+            match stream.as_mut().poll_next(cx) {
+                Poll::Ready(Some(item)) => {
+                    *buffered_item = Some(item);
+                }
+                Poll::Ready(None) => {
+                    ready!(si.poll_close(cx))?;
+                    sink.set(None);
+                    return Poll::Ready(Ok(()));
+                }
+                Poll::Pending => {
+                    ready!(si.poll_flush(cx))?;
+                    *unflushed = 0;
+                    return Poll::Pending;
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,137 @@
+use crate::stream::Fuse;
+use core::fmt;
+use core::pin::Pin;
+use futures_core::future::{FusedFuture, Future};
+use futures_core::ready;
+use futures_core::stream::Stream;
+use futures_core::task::{Context, Poll};
+use futures_sink::Sink;
+use pin_project_lite::pin_project;
+
+pin_project! {
+    /// Future for the [`forward_flush_interval`](super::StreamExt::forward_flush_interval) method.
+    #[project = ForwardFlushIntervalProj]
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct ForwardFlushInterval<St, Si, F, Fut>
+    where
+        St: Stream,
+    {
+        #[pin]
+        sink: Option<Si>,
+        #[pin]
+        stream: Fuse<St>,
+        buffered_item: Option<St::Item>,
+        delay: F,
+        #[pin]
+        deadline: Option<Fut>,
+        flushing: bool,
+    }
+}
+
+impl<St, Si, F, Fut> fmt::Debug for ForwardFlushInterval<St, Si, F, Fut>
+where
+    St: Stream + fmt::Debug,
+    St::Item: fmt::Debug,
+    Si: fmt::Debug,
+    Fut: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ForwardFlushInterval")
+            .field("sink", &self.sink)
+            .field("stream", &self.stream)
+            .field("buffered_item", &self.buffered_item)
+            .field("deadline", &self.deadline)
+            .field("flushing", &self.flushing)
+            .finish()
+    }
+}
+
+impl<St: Stream, Si, F, Fut> ForwardFlushInterval<St, Si, F, Fut> {
+    pub(crate) fn new(stream: St, sink: Si, delay: F) -> Self {
+        Self {
+            sink: Some(sink),
+            stream: Fuse::new(stream),
+            buffered_item: None,
+            delay,
+            deadline: None,
+            flushing: false,
+        }
+    }
+}
+
+impl<St, Si, F, Fut> FusedFuture for ForwardFlushInterval<St, Si, F, Fut>
+where
+    Si: Sink<St::Item>,
+    St: Stream,
+    F: FnMut() -> Fut,
+    Fut: Future,
+{
+    fn is_terminated(&self) -> bool {
+        self.sink.is_none()
+    }
+}
+
+impl<St, Si, F, Fut> Future for ForwardFlushInterval<St, Si, F, Fut>
+where
+    Si: Sink<St::Item>,
+    St: Stream,
+    F: FnMut() -> Fut,
+    Fut: Future,
+{
+    type Output = Result<(), Si::Error>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let ForwardFlushIntervalProj {
+            mut sink,
+            mut stream,
+            buffered_item,
+            delay,
+            mut deadline,
+            flushing,
+        } = self.project();
+        let mut si =
+            sink.as_mut().as_pin_mut().expect("polled `ForwardFlushInterval` after completion");
+
+        loop {
+            // If we've got an item buffered already, we need to write it to the
+            // sink before we can do anything else
+            if buffered_item.is_some() {
+                ready!(si.as_mut().poll_ready(cx))?;
+                si.as_mut().start_send(buffered_item.take().unwrap())?;
+                // The first unflushed item starts the flush deadline
+                if deadline.is_none() {
+                    deadline.set(Some(delay()));
+                }
+            }
+
+            if let Some(d) = deadline.as_mut().as_pin_mut() {
+                if d.poll(cx).is_ready() {
+                    deadline.set(None);
+                    *flushing = true;
+                }
+            }
+
+            if *flushing {
+                ready!(si.as_mut().poll_flush(cx))?;
+                *flushing = false;
+            }
+
+            match stream.as_mut().poll_next(cx) {
+                Poll::Ready(Some(item)) => {
+                    *buffered_item = Some(item);
+                }
+                Poll::Ready(None) => {
+                    deadline.set(None);
+                    ready!(si.poll_close(cx))?;
+                    sink.set(None);
+                    return Poll::Ready(Ok(()));
+                }
+                Poll::Pending => {
+                    ready!(si.poll_flush(cx))?;
+                    deadline.set(None);
+                    return Poll::Pending;
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,128 @@
+use core::pin::Pin;
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Context, Poll};
+#[cfg(feature = "sink")]
+use futures_sink::Sink;
+use pin_project_lite::pin_project;
+use std::time::{Duration, Instant};
+
+/// Statistics recorded by the [`InstrumentPolls`] stream.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PollStats {
+    polls: u64,
+    pending: u64,
+    items: u64,
+    total_poll_duration: Duration,
+    max_poll_duration: Duration,
+}
+
+impl PollStats {
+    /// The number of times `poll_next` was called.
+    pub fn polls(&self) -> u64 {
+        self.polls
+    }
+
+    /// The number of times `poll_next` returned `Poll::Pending`.
+    pub fn pending_polls(&self) -> u64 {
+        self.pending
+    }
+
+    /// The number of times `poll_next` returned `Poll::Ready`, including the
+    /// final `Poll::Ready(None)`.
+    pub fn ready_polls(&self) -> u64 {
+        self.polls - self.pending
+    }
+
+    /// The number of items yielded by the stream.
+    pub fn items(&self) -> u64 {
+        self.items
+    }
+
+    /// The total time spent inside `poll_next`.
+    pub fn total_poll_duration(&self) -> Duration {
+        self.total_poll_duration
+    }
+
+    /// The longest time a single call to `poll_next` took.
+    pub fn max_poll_duration(&self) -> Duration {
+        self.max_poll_duration
+    }
+
+    fn record<T>(&mut self, poll: &Poll<Option<T>>, elapsed: Duration) {
+        self.polls += 1;
+        match poll {
+            Poll::Pending => self.pending += 1,
+            Poll::Ready(Some(_)) => self.items += 1,
+            Poll::Ready(None) => {}
+        }
+        self.total_poll_duration += elapsed;
+        if elapsed > self.max_poll_duration {
+            self.max_poll_duration = elapsed;
+        }
+    }
+}
+
+pin_project! {
+    /// Stream for the [`instrument_polls`](super::StreamExt::instrument_polls) method.
+    #[derive(Debug)]
+    #[must_use = "streams do nothing unless polled"]
+    pub struct InstrumentPolls<St> {
+        #[pin]
+        stream: St,
+        stats: PollStats,
+    }
+}
+
+impl<St: Stream> InstrumentPolls<St> {
+    pub(super) fn new(stream: St) -> Self {
+        Self { stream, stats: PollStats::default() }
+    }
+
+    /// Returns the statistics recorded so far.
+    pub fn stats(&self) -> PollStats {
+        self.stats
+    }
+
+    /// Returns the statistics recorded so far, and resets them.
+    ///
+    /// This can be used to observe the stream over consecutive intervals.
+    pub fn take_stats(self: Pin<&mut Self>) -> PollStats {
+        core::mem::replace(self.project().stats, PollStats::default())
+    }
+
+    delegate_access_inner!(stream, St, ());
+}
+
+impl<St: FusedStream> FusedStream for InstrumentPolls<St> {
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated()
+    }
+}
+
+impl<St: Stream> Stream for InstrumentPolls<St> {
+    type Item = St::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+
+        let start = Instant::now();
+        let poll = this.stream.poll_next(cx);
+        this.stats.record(&poll, start.elapsed());
+        poll
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
+
+// Forwarding impl of Sink from the underlying stream
+#[cfg(feature = "sink")]
+impl<S, Item> Sink<Item> for InstrumentPolls<S>
+where
+    S: Stream + Sink<Item>,
+{
+    type Error = S::Error;
+
+    delegate_sink!(stream, Item);
+}
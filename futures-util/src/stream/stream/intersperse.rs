@@ -0,0 +1,169 @@
+use core::fmt;
+use core::pin::Pin;
+use futures_core::ready;
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Context, Poll};
+use pin_project_lite::pin_project;
+
+pin_project! {
+    /// Stream for the [`intersperse`](super::StreamExt::intersperse) method.
+    #[derive(Debug)]
+    #[must_use = "streams do nothing unless polled"]
+    pub struct Intersperse<St: Stream> {
+        #[pin]
+        stream: St,
+        separator: St::Item,
+        peeked: Option<St::Item>,
+        started: bool,
+    }
+}
+
+impl<St: Stream> Intersperse<St>
+where
+    St::Item: Clone,
+{
+    pub(super) fn new(stream: St, separator: St::Item) -> Self {
+        Self { stream, separator, peeked: None, started: false }
+    }
+
+    delegate_access_inner!(stream, St, ());
+}
+
+impl<St: FusedStream> FusedStream for Intersperse<St>
+where
+    St::Item: Clone,
+{
+    fn is_terminated(&self) -> bool {
+        self.peeked.is_none() && self.stream.is_terminated()
+    }
+}
+
+impl<St: Stream> Stream for Intersperse<St>
+where
+    St::Item: Clone,
+{
+    type Item = St::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+
+        if let Some(item) = this.peeked.take() {
+            return Poll::Ready(Some(item));
+        }
+        match ready!(this.stream.poll_next(cx)) {
+            Some(item) if *this.started => {
+                *this.peeked = Some(item);
+                Poll::Ready(Some(this.separator.clone()))
+            }
+            Some(item) => {
+                *this.started = true;
+                Poll::Ready(Some(item))
+            }
+            None => Poll::Ready(None),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        size_hint(self.stream.size_hint(), self.peeked.is_some(), self.started)
+    }
+}
+
+pin_project! {
+    /// Stream for the [`intersperse_with`](super::StreamExt::intersperse_with) method.
+    #[must_use = "streams do nothing unless polled"]
+    pub struct IntersperseWith<St: Stream, F> {
+        #[pin]
+        stream: St,
+        f: F,
+        peeked: Option<St::Item>,
+        started: bool,
+    }
+}
+
+impl<St, F> fmt::Debug for IntersperseWith<St, F>
+where
+    St: Stream + fmt::Debug,
+    St::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("IntersperseWith")
+            .field("stream", &self.stream)
+            .field("peeked", &self.peeked)
+            .field("started", &self.started)
+            .finish()
+    }
+}
+
+impl<St, F> IntersperseWith<St, F>
+where
+    St: Stream,
+    F: FnMut() -> St::Item,
+{
+    pub(super) fn new(stream: St, f: F) -> Self {
+        Self { stream, f, peeked: None, started: false }
+    }
+
+    delegate_access_inner!(stream, St, ());
+}
+
+impl<St, F> FusedStream for IntersperseWith<St, F>
+where
+    St: FusedStream,
+    F: FnMut() -> St::Item,
+{
+    fn is_terminated(&self) -> bool {
+        self.peeked.is_none() && self.stream.is_terminated()
+    }
+}
+
+impl<St, F> Stream for IntersperseWith<St, F>
+where
+    St: Stream,
+    F: FnMut() -> St::Item,
+{
+    type Item = St::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+
+        if let Some(item) = this.peeked.take() {
+            return Poll::Ready(Some(item));
+        }
+        match ready!(this.stream.poll_next(cx)) {
+            Some(item) if *this.started => {
+                *this.peeked = Some(item);
+                Poll::Ready(Some((this.f)()))
+            }
+            Some(item) => {
+                *this.started = true;
+                Poll::Ready(Some(item))
+            }
+            None => Poll::Ready(None),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        size_hint(self.stream.size_hint(), self.peeked.is_some(), self.started)
+    }
+}
+
+fn size_hint(
+    (lower, upper): (usize, Option<usize>),
+    peeked: bool,
+    started: bool,
+) -> (usize, Option<usize>) {
+    // Every item still to come is preceded by a separator, except for the very
+    // first item of the stream.
+    let with_separators = |n: usize| {
+        let n = n.checked_mul(2)?;
+        if started {
+            Some(n)
+        } else {
+            Some(n.saturating_sub(1))
+        }
+    };
+    let peeked_len = if peeked { 1 } else { 0 };
+    let lower = with_separators(lower).unwrap_or(usize::max_value()).saturating_add(peeked_len);
+    let upper = upper.and_then(with_separators).and_then(|n| n.checked_add(peeked_len));
+    (lower, upper)
+}
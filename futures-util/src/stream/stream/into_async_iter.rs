@@ -0,0 +1,54 @@
+use core::async_iter::AsyncIterator;
+use core::pin::Pin;
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Context, Poll};
+use pin_project_lite::pin_project;
+
+pin_project! {
+    /// Async iterator for the [`into_async_iter`](super::StreamExt::into_async_iter) method.
+    #[derive(Debug)]
+    #[must_use = "async iterators do nothing unless polled"]
+    pub struct IntoAsyncIter<St> {
+        #[pin]
+        stream: St,
+    }
+}
+
+impl<St: Stream> IntoAsyncIter<St> {
+    pub(super) fn new(stream: St) -> Self {
+        Self { stream }
+    }
+
+    delegate_access_inner!(stream, St, ());
+}
+
+impl<St: Stream> AsyncIterator for IntoAsyncIter<St> {
+    type Item = St::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.project().stream.poll_next(cx)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
+
+// The adapter is still a stream, so it can be handed back to combinators.
+impl<St: Stream> Stream for IntoAsyncIter<St> {
+    type Item = St::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.project().stream.poll_next(cx)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
+
+impl<St: FusedStream> FusedStream for IntoAsyncIter<St> {
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated()
+    }
+}
@@ -0,0 +1,69 @@
+use super::ForEachConcurrentWithResults;
+use alloc::vec::Vec;
+use core::fmt;
+use core::pin::Pin;
+use futures_core::future::{FusedFuture, Future};
+use futures_core::ready;
+use futures_core::stream::Stream;
+use futures_core::task::{Context, Poll};
+use pin_project_lite::pin_project;
+
+pin_project! {
+    /// Future for the [`map_concurrent`](super::StreamExt::map_concurrent)
+    /// method.
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct MapConcurrent<St, Fut, F>
+        where Fut: Future
+    {
+        #[pin]
+        inner: ForEachConcurrentWithResults<St, Fut, F>,
+    }
+}
+
+impl<St, Fut, F> fmt::Debug for MapConcurrent<St, Fut, F>
+where
+    St: fmt::Debug,
+    Fut: Future + fmt::Debug,
+    Fut::Output: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MapConcurrent").field("inner", &self.inner).finish()
+    }
+}
+
+impl<St, Fut, F> MapConcurrent<St, Fut, F>
+where
+    St: Stream,
+    F: FnMut(St::Item) -> Fut,
+    Fut: Future,
+{
+    pub(super) fn new(stream: St, limit: Option<usize>, f: F) -> Self {
+        Self { inner: ForEachConcurrentWithResults::new(stream, limit, f) }
+    }
+}
+
+impl<St, Fut, F> FusedFuture for MapConcurrent<St, Fut, F>
+where
+    St: Stream,
+    F: FnMut(St::Item) -> Fut,
+    Fut: Future,
+{
+    fn is_terminated(&self) -> bool {
+        self.inner.is_terminated()
+    }
+}
+
+impl<St, Fut, F> Future for MapConcurrent<St, Fut, F>
+where
+    St: Stream,
+    F: FnMut(St::Item) -> Fut,
+    Fut: Future,
+{
+    type Output = Vec<Fut::Output>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut results = ready!(self.project().inner.poll(cx));
+        results.sort_unstable_by_key(|(index, _)| *index);
+        Poll::Ready(results.into_iter().map(|(_, output)| output).collect())
+    }
+}
@@ -0,0 +1,73 @@
+use core::cmp::Ordering;
+use core::fmt;
+use core::pin::Pin;
+use futures_core::future::{FusedFuture, Future};
+use futures_core::ready;
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Context, Poll};
+use pin_project_lite::pin_project;
+
+pin_project! {
+    /// Future for the [`max_by`](super::StreamExt::max_by) method.
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct MaxBy<St, F>
+    where
+        St: Stream,
+    {
+        #[pin]
+        stream: St,
+        f: F,
+        best: Option<St::Item>,
+    }
+}
+
+impl<St, F> fmt::Debug for MaxBy<St, F>
+where
+    St: Stream + fmt::Debug,
+    St::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MaxBy").field("stream", &self.stream).field("best", &self.best).finish()
+    }
+}
+
+impl<St, F> MaxBy<St, F>
+where
+    St: Stream,
+    F: FnMut(&St::Item, &St::Item) -> Ordering,
+{
+    pub(super) fn new(stream: St, f: F) -> Self {
+        Self { stream, f, best: None }
+    }
+}
+
+impl<St, F> FusedFuture for MaxBy<St, F>
+where
+    St: FusedStream,
+    F: FnMut(&St::Item, &St::Item) -> Ordering,
+{
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated()
+    }
+}
+
+impl<St, F> Future for MaxBy<St, F>
+where
+    St: Stream,
+    F: FnMut(&St::Item, &St::Item) -> Ordering,
+{
+    type Output = Option<St::Item>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut this = self.project();
+        loop {
+            match ready!(this.stream.as_mut().poll_next(cx)) {
+                Some(item) => match this.best {
+                    Some(best) if (this.f)(best, &item) == Ordering::Greater => {}
+                    _ => *this.best = Some(item),
+                },
+                None => return Poll::Ready(this.best.take()),
+            }
+        }
+    }
+}
@@ -0,0 +1,79 @@
+use core::fmt;
+use core::pin::Pin;
+use futures_core::future::{FusedFuture, Future};
+use futures_core::ready;
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Context, Poll};
+use pin_project_lite::pin_project;
+
+pin_project! {
+    /// Future for the [`max_by_key`](super::StreamExt::max_by_key) method.
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct MaxByKey<St, B, F>
+    where
+        St: Stream,
+    {
+        #[pin]
+        stream: St,
+        f: F,
+        best: Option<(B, St::Item)>,
+    }
+}
+
+impl<St, B, F> fmt::Debug for MaxByKey<St, B, F>
+where
+    St: Stream + fmt::Debug,
+    St::Item: fmt::Debug,
+    B: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MaxByKey").field("stream", &self.stream).field("best", &self.best).finish()
+    }
+}
+
+impl<St, B, F> MaxByKey<St, B, F>
+where
+    St: Stream,
+    B: Ord,
+    F: FnMut(&St::Item) -> B,
+{
+    pub(super) fn new(stream: St, f: F) -> Self {
+        Self { stream, f, best: None }
+    }
+}
+
+impl<St, B, F> FusedFuture for MaxByKey<St, B, F>
+where
+    St: FusedStream,
+    B: Ord,
+    F: FnMut(&St::Item) -> B,
+{
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated()
+    }
+}
+
+impl<St, B, F> Future for MaxByKey<St, B, F>
+where
+    St: Stream,
+    B: Ord,
+    F: FnMut(&St::Item) -> B,
+{
+    type Output = Option<St::Item>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut this = self.project();
+        loop {
+            match ready!(this.stream.as_mut().poll_next(cx)) {
+                Some(item) => {
+                    let key = (this.f)(&item);
+                    match this.best {
+                        Some((best_key, _)) if key < *best_key => {}
+                        _ => *this.best = Some((key, item)),
+                    }
+                }
+                None => return Poll::Ready(this.best.take().map(|(_, item)| item)),
+            }
+        }
+    }
+}
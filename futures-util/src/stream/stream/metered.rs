@@ -0,0 +1,189 @@
+use crate::task::{waker_ref, ArcWake, AtomicWaker};
+use core::pin::Pin;
+use core::sync::atomic::{AtomicUsize, Ordering};
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Context, Poll};
+#[cfg(feature = "sink")]
+use futures_sink::Sink;
+use pin_project_lite::pin_project;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// A snapshot of the backpressure observed by a [`Metered`] adapter in one
+/// direction, either through `poll_next` or through `poll_ready`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Metrics {
+    items: u64,
+    pending: u64,
+    wakeups: u64,
+    blocked: Duration,
+}
+
+impl Metrics {
+    /// The number of items that went through the adapter.
+    ///
+    /// For a stream this counts the items yielded by `poll_next`, for a sink
+    /// the items passed to `start_send`.
+    pub fn items(&self) -> u64 {
+        self.items
+    }
+
+    /// The number of times the inner stream or sink returned `Poll::Pending`.
+    pub fn pending(&self) -> u64 {
+        self.pending
+    }
+
+    /// The number of times the inner stream or sink woke the task up.
+    pub fn wakeups(&self) -> u64 {
+        self.wakeups
+    }
+
+    /// The total time spent between a `Poll::Pending` and the following
+    /// `Poll::Ready`.
+    ///
+    /// Time spent in a wait that has not finished yet is not included.
+    pub fn blocked(&self) -> Duration {
+        self.blocked
+    }
+}
+
+#[derive(Debug, Default)]
+struct WakeCounter {
+    wakeups: AtomicUsize,
+    waker: AtomicWaker,
+}
+
+impl ArcWake for WakeCounter {
+    fn wake_by_ref(arc_self: &Arc<Self>) {
+        arc_self.wakeups.fetch_add(1, Ordering::Relaxed);
+        arc_self.waker.wake();
+    }
+}
+
+#[derive(Debug, Default)]
+struct Meter {
+    counter: Arc<WakeCounter>,
+    items: u64,
+    pending: u64,
+    blocked: Duration,
+    blocked_since: Option<Instant>,
+}
+
+impl Meter {
+    fn poll<T>(
+        &mut self,
+        cx: &mut Context<'_>,
+        f: impl FnOnce(&mut Context<'_>) -> Poll<T>,
+    ) -> Poll<T> {
+        self.counter.waker.register(cx.waker());
+        let waker = waker_ref(&self.counter);
+        let poll = f(&mut crate::task::child_context(cx, &waker));
+
+        if poll.is_pending() {
+            self.pending += 1;
+            if self.blocked_since.is_none() {
+                self.blocked_since = Some(Instant::now());
+            }
+        } else if let Some(since) = self.blocked_since.take() {
+            self.blocked += since.elapsed();
+        }
+        poll
+    }
+
+    fn metrics(&self) -> Metrics {
+        Metrics {
+            items: self.items,
+            pending: self.pending,
+            wakeups: self.counter.wakeups.load(Ordering::Relaxed) as u64,
+            blocked: self.blocked,
+        }
+    }
+}
+
+pin_project! {
+    /// Stream and sink for the [`metered`](super::StreamExt::metered) method.
+    #[derive(Debug)]
+    #[must_use = "streams do nothing unless polled"]
+    pub struct Metered<T> {
+        #[pin]
+        inner: T,
+        next: Meter,
+        ready: Meter,
+    }
+}
+
+impl<T> Metered<T> {
+    /// Wraps a stream, a sink, or a value that is both, recording the
+    /// backpressure it exerts.
+    ///
+    /// This is how sinks are metered; streams can also use
+    /// [`StreamExt::metered`](super::StreamExt::metered).
+    pub fn new(inner: T) -> Self {
+        Self { inner, next: Meter::default(), ready: Meter::default() }
+    }
+
+    /// Returns the metrics recorded so far for `poll_next`.
+    pub fn stream_metrics(&self) -> Metrics {
+        self.next.metrics()
+    }
+
+    /// Returns the metrics recorded so far for `poll_ready` and `start_send`.
+    pub fn sink_metrics(&self) -> Metrics {
+        self.ready.metrics()
+    }
+
+    delegate_access_inner!(inner, T, ());
+}
+
+impl<St: FusedStream> FusedStream for Metered<St> {
+    fn is_terminated(&self) -> bool {
+        self.inner.is_terminated()
+    }
+}
+
+impl<St: Stream> Stream for Metered<St> {
+    type Item = St::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        let mut inner = this.inner;
+
+        let poll = this.next.poll(cx, |cx| inner.as_mut().poll_next(cx));
+        if let Poll::Ready(Some(_)) = poll {
+            this.next.items += 1;
+        }
+        poll
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+#[cfg(feature = "sink")]
+impl<Si: Sink<Item>, Item> Sink<Item> for Metered<Si> {
+    type Error = Si::Error;
+
+    fn poll_ready(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        let this = self.project();
+        let mut inner = this.inner;
+
+        this.ready.poll(cx, |cx| inner.as_mut().poll_ready(cx))
+    }
+
+    fn start_send(self: Pin<&mut Self>, item: Item) -> Result<(), Self::Error> {
+        let this = self.project();
+
+        this.inner.start_send(item)?;
+        this.ready.items += 1;
+        Ok(())
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.project().inner.poll_flush(cx)
+    }
+
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.project().inner.poll_close(cx)
+    }
+}
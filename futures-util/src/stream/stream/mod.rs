@@ -9,6 +9,7 @@ use crate::stream::assert_stream;
 use alloc::boxed::Box;
 #[cfg(feature = "alloc")]
 use alloc::vec::Vec;
+use core::cmp::Ordering;
 use core::pin::Pin;
 #[cfg(feature = "alloc")]
 use futures_core::stream::{BoxStream, LocalBoxStream};
@@ -21,15 +22,29 @@ use futures_core::{
 use futures_sink::Sink;
 
 use crate::fns::{inspect_fn, InspectFn};
+#[cfg(feature = "instrument")]
+use crate::instrument::Instrument;
 
 mod chain;
 #[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
 pub use self::chain::Chain;
 
+mod start_with;
+#[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
+pub use self::start_with::StartWith;
+
+mod intersperse;
+#[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
+pub use self::intersperse::{Intersperse, IntersperseWith};
+
 mod collect;
 #[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
 pub use self::collect::Collect;
 
+mod collect_into;
+#[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
+pub use self::collect_into::CollectInto;
+
 mod unzip;
 #[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
 pub use self::unzip::Unzip;
@@ -72,10 +87,34 @@ mod fold;
 #[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
 pub use self::fold::Fold;
 
+mod reduce;
+#[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
+pub use self::reduce::Reduce;
+
+mod min_by_key;
+#[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
+pub use self::min_by_key::MinByKey;
+
+mod max_by_key;
+#[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
+pub use self::max_by_key::MaxByKey;
+
+mod min_by;
+#[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
+pub use self::min_by::MinBy;
+
+mod max_by;
+#[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
+pub use self::max_by::MaxBy;
+
 mod any;
 #[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
 pub use self::any::Any;
 
+mod position;
+#[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
+pub use self::position::Position;
+
 mod all;
 #[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
 pub use self::all::All;
@@ -83,6 +122,18 @@ pub use self::all::All;
 #[cfg(feature = "sink")]
 mod forward;
 
+#[cfg(feature = "sink")]
+mod forward_flush_every;
+#[cfg(feature = "sink")]
+#[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
+pub use self::forward_flush_every::ForwardFlushEvery;
+
+#[cfg(feature = "sink")]
+mod forward_flush_interval;
+#[cfg(feature = "sink")]
+#[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
+pub use self::forward_flush_interval::ForwardFlushInterval;
+
 #[cfg(feature = "sink")]
 delegate_all!(
     /// Future for the [`forward`](super::StreamExt::forward) method.
@@ -171,18 +222,58 @@ mod zip;
 #[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
 pub use self::zip::Zip;
 
+mod zip_with;
+#[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
+pub use self::zip_with::ZipWith;
+
+mod with_latest_from;
+#[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
+pub use self::with_latest_from::WithLatestFrom;
+
+#[cfg(feature = "alloc")]
+mod buffer_while;
+#[cfg(feature = "alloc")]
+#[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
+pub use self::buffer_while::BufferWhile;
+
 #[cfg(feature = "alloc")]
 mod chunks;
 #[cfg(feature = "alloc")]
 #[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
 pub use self::chunks::Chunks;
 
+#[cfg(feature = "alloc")]
+mod chunks_exact;
+#[cfg(feature = "alloc")]
+#[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
+pub use self::chunks_exact::ChunksExact;
+
+#[cfg(feature = "alloc")]
+mod split_at;
+#[cfg(feature = "alloc")]
+#[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
+pub use self::split_at::SplitAt;
+
 #[cfg(feature = "alloc")]
 mod ready_chunks;
 #[cfg(feature = "alloc")]
 #[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
 pub use self::ready_chunks::ReadyChunks;
 
+#[cfg(feature = "alloc")]
+mod ready_chunks_timeout;
+#[cfg(feature = "alloc")]
+#[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
+pub use self::ready_chunks_timeout::ReadyChunksTimeout;
+
+mod delay_items;
+#[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
+pub use self::delay_items::DelayItems;
+
+mod sample;
+#[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
+pub use self::sample::Sample;
+
 mod scan;
 #[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
 pub use self::scan::Scan;
@@ -231,6 +322,58 @@ mod try_for_each_concurrent;
 #[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
 pub use self::try_for_each_concurrent::TryForEachConcurrent;
 
+#[cfg(not(futures_no_atomic_cas))]
+#[cfg(feature = "alloc")]
+mod for_each_concurrent_with_results;
+#[cfg(not(futures_no_atomic_cas))]
+#[cfg(feature = "alloc")]
+#[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
+pub use self::for_each_concurrent_with_results::ForEachConcurrentWithResults;
+
+#[cfg(not(futures_no_atomic_cas))]
+#[cfg(feature = "alloc")]
+mod map_concurrent;
+#[cfg(not(futures_no_atomic_cas))]
+#[cfg(feature = "alloc")]
+#[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
+pub use self::map_concurrent::MapConcurrent;
+
+#[cfg(not(futures_no_atomic_cas))]
+#[cfg(feature = "alloc")]
+mod try_map_concurrent;
+#[cfg(not(futures_no_atomic_cas))]
+#[cfg(feature = "alloc")]
+#[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
+pub use self::try_map_concurrent::{ErrorPolicy, TryMapConcurrent};
+
+#[cfg(not(futures_no_atomic_cas))]
+#[cfg(feature = "alloc")]
+mod try_for_each_concurrent_with_policy;
+#[cfg(not(futures_no_atomic_cas))]
+#[cfg(feature = "alloc")]
+#[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
+pub use self::try_for_each_concurrent_with_policy::TryForEachConcurrentWithPolicy;
+
+#[cfg(feature = "std")]
+mod instrument_polls;
+#[cfg(feature = "std")]
+#[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
+pub use self::instrument_polls::{InstrumentPolls, PollStats};
+
+#[cfg(not(futures_no_atomic_cas))]
+#[cfg(feature = "std")]
+mod metered;
+#[cfg(not(futures_no_atomic_cas))]
+#[cfg(feature = "std")]
+#[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
+pub use self::metered::{Metered, Metrics};
+
+#[cfg(feature = "async-iterator")]
+mod into_async_iter;
+#[cfg(feature = "async-iterator")]
+#[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
+pub use self::into_async_iter::IntoAsyncIter;
+
 #[cfg(feature = "std")]
 mod catch_unwind;
 #[cfg(feature = "std")]
@@ -307,6 +450,37 @@ pub trait StreamExt: Stream {
         assert_future::<(Option<Self::Item>, Self), _>(StreamFuture::new(self))
     }
 
+    /// Converts this stream into a future which resolves to the first `n`
+    /// items of the stream together with the rest of the stream.
+    ///
+    /// The returned future resolves once `n` items have been received, or
+    /// once the stream terminates, whichever comes first. The remaining
+    /// stream is handed back untouched, so it can be consumed separately (for
+    /// example, after parsing a fixed-size header).
+    ///
+    /// Note that because the stream is returned by value, it must be [`Unpin`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let stream = stream::iter(1..=5);
+    ///
+    /// let (header, rest) = stream.split_at(2).await;
+    /// assert_eq!(header, vec![1, 2]);
+    /// assert_eq!(rest.collect::<Vec<_>>().await, vec![3, 4, 5]);
+    /// # });
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn split_at(self, n: usize) -> SplitAt<Self>
+    where
+        Self: Sized + Unpin,
+    {
+        assert_future::<(Vec<Self::Item>, Self), _>(SplitAt::new(self, n))
+    }
+
     /// Maps this stream's items to a different type, returning a new stream of
     /// the resulting type.
     ///
@@ -515,6 +689,40 @@ pub trait StreamExt: Stream {
         assert_future::<C, _>(Collect::new(self))
     }
 
+    /// Transforms a stream into a collection, extending the provided
+    /// collection rather than creating a new one, and returning a future
+    /// which resolves to a mutable reference to that collection.
+    ///
+    /// This is useful for reusing an existing allocation (for example, a
+    /// `Vec` which is cleared between batches) instead of allocating a fresh
+    /// collection every time.
+    ///
+    /// The returned future will be resolved when the stream terminates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let mut buf = vec![1, 2];
+    ///
+    /// stream::iter(3..=5).collect_into(&mut buf).await;
+    /// assert_eq!(buf, vec![1, 2, 3, 4, 5]);
+    ///
+    /// buf.clear();
+    /// let out = stream::iter(6..=7).collect_into(&mut buf).await;
+    /// out.push(8);
+    /// assert_eq!(buf, vec![6, 7, 8]);
+    /// # });
+    /// ```
+    fn collect_into<C: Extend<Self::Item>>(self, collection: &mut C) -> CollectInto<'_, Self, C>
+    where
+        Self: Sized,
+    {
+        assert_future::<&mut C, _>(CollectInto::new(self, collection))
+    }
+
     /// Converts a stream of pairs into a future, which
     /// resolves to pair of containers.
     ///
@@ -685,6 +893,136 @@ pub trait StreamExt: Stream {
         assert_future::<T, _>(Fold::new(self, f, init))
     }
 
+    /// Execute an accumulating asynchronous computation over a stream, using
+    /// the first item of the stream as the initial accumulator.
+    ///
+    /// This is similar to [`fold`](StreamExt::fold), except that no initial
+    /// state has to be provided. The closure is called with the result of the
+    /// previous call (or the first item) and each subsequent item. Once the
+    /// entire stream has been exhausted the returned future will resolve to
+    /// the final value, or `None` if the stream was empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let number_stream = stream::iter(1..=5);
+    /// let product = number_stream.reduce(|acc, x| async move { acc * x });
+    /// assert_eq!(product.await, Some(120));
+    ///
+    /// let empty = stream::empty::<i32>();
+    /// assert_eq!(empty.reduce(|acc, x| async move { acc + x }).await, None);
+    /// # });
+    /// ```
+    fn reduce<Fut, F>(self, f: F) -> Reduce<Self, Fut, F>
+    where
+        F: FnMut(Self::Item, Self::Item) -> Fut,
+        Fut: Future<Output = Self::Item>,
+        Self: Sized,
+    {
+        assert_future::<Option<Self::Item>, _>(Reduce::new(self, f))
+    }
+
+    /// Returns a future which resolves to the item of this stream that gives
+    /// the minimum value from the specified function.
+    ///
+    /// If several items are equally minimum, the first item is returned. If
+    /// the stream is empty, `None` is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let stream = stream::iter(vec![-3_i32, 0, 1, 5, -10, 10]);
+    /// assert_eq!(stream.min_by_key(|x| x.abs()).await, Some(0));
+    /// # });
+    /// ```
+    fn min_by_key<B, F>(self, f: F) -> MinByKey<Self, B, F>
+    where
+        B: Ord,
+        F: FnMut(&Self::Item) -> B,
+        Self: Sized,
+    {
+        assert_future::<Option<Self::Item>, _>(MinByKey::new(self, f))
+    }
+
+    /// Returns a future which resolves to the item of this stream that gives
+    /// the maximum value from the specified function.
+    ///
+    /// If several items are equally maximum, the last item is returned. If
+    /// the stream is empty, `None` is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let stream = stream::iter(vec![-3_i32, 0, 1, 5, -10, 10]);
+    /// assert_eq!(stream.max_by_key(|x| x.abs()).await, Some(10));
+    /// # });
+    /// ```
+    fn max_by_key<B, F>(self, f: F) -> MaxByKey<Self, B, F>
+    where
+        B: Ord,
+        F: FnMut(&Self::Item) -> B,
+        Self: Sized,
+    {
+        assert_future::<Option<Self::Item>, _>(MaxByKey::new(self, f))
+    }
+
+    /// Returns a future which resolves to the item of this stream that gives
+    /// the minimum value with respect to the specified comparison function.
+    ///
+    /// If several items are equally minimum, the first item is returned. If
+    /// the stream is empty, `None` is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let stream = stream::iter(vec![2.4_f64, -1.5, 3.0, -1.5]);
+    /// assert_eq!(stream.min_by(|a, b| a.partial_cmp(b).unwrap()).await, Some(-1.5));
+    /// # });
+    /// ```
+    fn min_by<F>(self, f: F) -> MinBy<Self, F>
+    where
+        F: FnMut(&Self::Item, &Self::Item) -> Ordering,
+        Self: Sized,
+    {
+        assert_future::<Option<Self::Item>, _>(MinBy::new(self, f))
+    }
+
+    /// Returns a future which resolves to the item of this stream that gives
+    /// the maximum value with respect to the specified comparison function.
+    ///
+    /// If several items are equally maximum, the last item is returned. If
+    /// the stream is empty, `None` is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let stream = stream::iter(vec![(1, 'a'), (3, 'b'), (3, 'c'), (2, 'd')]);
+    /// assert_eq!(stream.max_by(|a, b| a.0.cmp(&b.0)).await, Some((3, 'c')));
+    /// # });
+    /// ```
+    fn max_by<F>(self, f: F) -> MaxBy<Self, F>
+    where
+        F: FnMut(&Self::Item, &Self::Item) -> Ordering,
+        Self: Sized,
+    {
+        assert_future::<Option<Self::Item>, _>(MaxBy::new(self, f))
+    }
+
     /// Execute predicate over asynchronous stream, and return `true` if any element in stream satisfied a predicate.
     ///
     /// # Examples
@@ -707,6 +1045,33 @@ pub trait StreamExt: Stream {
         assert_future::<bool, _>(Any::new(self, f))
     }
 
+    /// Returns a future which resolves to the index of the first item of this
+    /// stream that satisfies the predicate, or `None` if there is no such item.
+    ///
+    /// The stream is not polled any further once a matching item is found.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let stream = stream::iter(vec![1, 4, 9, 16]);
+    /// assert_eq!(stream.position(|i| async move { i > 5 }).await, Some(2));
+    ///
+    /// let stream = stream::iter(vec![1, 4, 9, 16]);
+    /// assert_eq!(stream.position(|i| async move { i > 20 }).await, None);
+    /// # });
+    /// ```
+    fn position<Fut, F>(self, f: F) -> Position<Self, Fut, F>
+    where
+        F: FnMut(Self::Item) -> Fut,
+        Fut: Future<Output = bool>,
+        Self: Sized,
+    {
+        assert_future::<Option<usize>, _>(Position::new(self, f))
+    }
+
     /// Execute predicate over asynchronous stream, and return `true` if all element in stream satisfied a predicate.
     ///
     /// # Examples
@@ -1049,48 +1414,142 @@ pub trait StreamExt: Stream {
         assert_future::<(), _>(ForEachConcurrent::new(self, limit.into(), f))
     }
 
-    /// Attempt to execute an accumulating asynchronous computation over a
-    /// stream, collecting all the values into one final result.
+    /// Runs this stream to completion like
+    /// [`for_each_concurrent`](StreamExt::for_each_concurrent), but collects
+    /// the output of every future the closure produces.
     ///
-    /// This combinator will accumulate all values returned by this stream
-    /// according to the closure provided. The initial state is also provided to
-    /// this method and then is returned again by each execution of the closure.
-    /// Once the entire stream has been exhausted the returned future will
-    /// resolve to this value.
+    /// Each output is paired with the position of the stream item that
+    /// produced it, and the returned vector is in the order in which the
+    /// futures completed. Use [`map_concurrent`](StreamExt::map_concurrent)
+    /// to get the outputs back in stream order instead.
     ///
-    /// This method is similar to [`fold`](crate::stream::StreamExt::fold), but
-    /// will exit early if an error is encountered in the provided closure.
+    /// The `limit` argument behaves as it does for `for_each_concurrent`.
+    ///
+    /// This method is only available when the `std` or `alloc` feature of this
+    /// library is activated, and it is activated by default.
     ///
     /// # Examples
     ///
     /// ```
     /// # futures::executor::block_on(async {
+    /// use futures::channel::oneshot;
     /// use futures::stream::{self, StreamExt};
     ///
-    /// let number_stream = stream::iter(vec![1, 2]);
-    /// let sum = number_stream.try_fold(0, |acc, x| async move { Ok::<i32, i32>(acc + x) });
-    /// assert_eq!(sum.await, Ok(3));
+    /// let (tx1, rx1) = oneshot::channel();
+    /// let (tx2, rx2) = oneshot::channel();
     ///
-    /// let number_stream_with_err = stream::iter(vec![Ok::<i32, i32>(1), Err(2), Ok(1)]);
-    /// let sum = number_stream_with_err.try_fold(0, |acc, x| async move { Ok(acc + x?) });
-    /// assert_eq!(sum.await, Err(2));
+    /// let fut = stream::iter(vec![rx1, rx2]).for_each_concurrent_with_results(
+    ///     None,
+    ///     |rx| async move { rx.await.unwrap() },
+    /// );
+    /// tx2.send("second").unwrap();
+    /// tx1.send("first").unwrap();
+    ///
+    /// let mut results = fut.await;
+    /// results.sort();
+    /// assert_eq!(results, vec![(0, "first"), (1, "second")]);
     /// # })
     /// ```
-    fn try_fold<T, Fut, F>(self, init: T, f: F) -> TryFold<Self, Fut, T, F>
+    #[cfg(not(futures_no_atomic_cas))]
+    #[cfg(feature = "alloc")]
+    fn for_each_concurrent_with_results<Fut, F>(
+        self,
+        limit: impl Into<Option<usize>>,
+        f: F,
+    ) -> ForEachConcurrentWithResults<Self, Fut, F>
     where
-        F: FnMut(T, Self::Item) -> Fut,
-        Fut: TryFuture<Ok = T>,
+        F: FnMut(Self::Item) -> Fut,
+        Fut: Future,
         Self: Sized,
     {
-        assert_future::<Result<T, Fut::Error>, _>(TryFold::new(self, f, init))
+        assert_future::<Vec<(usize, Fut::Output)>, _>(ForEachConcurrentWithResults::new(
+            self,
+            limit.into(),
+            f,
+        ))
     }
 
-    /// Attempts to run this stream to completion, executing the provided
-    /// asynchronous closure for each element on the stream.
+    /// Maps every item of this stream to a future, runs those futures
+    /// concurrently and collects their outputs in stream order.
     ///
-    /// The provided closure will be called for each item this stream produces,
-    /// yielding a future. That future will then be executed to completion
-    /// before moving on to the next item.
+    /// Unlike [`buffered`](StreamExt::buffered), which yields the outputs as a
+    /// stream, this resolves once the stream and every future have completed.
+    /// A future that completes early does not hold back the ones started after
+    /// it; only the final vector is ordered.
+    ///
+    /// The `limit` argument behaves as it does for
+    /// [`for_each_concurrent`](StreamExt::for_each_concurrent).
+    ///
+    /// This method is only available when the `std` or `alloc` feature of this
+    /// library is activated, and it is activated by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let fut = stream::iter(1..=4).map_concurrent(2, |x| async move { x * 10 });
+    /// assert_eq!(fut.await, vec![10, 20, 30, 40]);
+    /// # })
+    /// ```
+    #[cfg(not(futures_no_atomic_cas))]
+    #[cfg(feature = "alloc")]
+    fn map_concurrent<Fut, F>(
+        self,
+        limit: impl Into<Option<usize>>,
+        f: F,
+    ) -> MapConcurrent<Self, Fut, F>
+    where
+        F: FnMut(Self::Item) -> Fut,
+        Fut: Future,
+        Self: Sized,
+    {
+        assert_future::<Vec<Fut::Output>, _>(MapConcurrent::new(self, limit.into(), f))
+    }
+
+    /// Attempt to execute an accumulating asynchronous computation over a
+    /// stream, collecting all the values into one final result.
+    ///
+    /// This combinator will accumulate all values returned by this stream
+    /// according to the closure provided. The initial state is also provided to
+    /// this method and then is returned again by each execution of the closure.
+    /// Once the entire stream has been exhausted the returned future will
+    /// resolve to this value.
+    ///
+    /// This method is similar to [`fold`](crate::stream::StreamExt::fold), but
+    /// will exit early if an error is encountered in the provided closure.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let number_stream = stream::iter(vec![1, 2]);
+    /// let sum = number_stream.try_fold(0, |acc, x| async move { Ok::<i32, i32>(acc + x) });
+    /// assert_eq!(sum.await, Ok(3));
+    ///
+    /// let number_stream_with_err = stream::iter(vec![Ok::<i32, i32>(1), Err(2), Ok(1)]);
+    /// let sum = number_stream_with_err.try_fold(0, |acc, x| async move { Ok(acc + x?) });
+    /// assert_eq!(sum.await, Err(2));
+    /// # })
+    /// ```
+    fn try_fold<T, Fut, F>(self, init: T, f: F) -> TryFold<Self, Fut, T, F>
+    where
+        F: FnMut(T, Self::Item) -> Fut,
+        Fut: TryFuture<Ok = T>,
+        Self: Sized,
+    {
+        assert_future::<Result<T, Fut::Error>, _>(TryFold::new(self, f, init))
+    }
+
+    /// Attempts to run this stream to completion, executing the provided
+    /// asynchronous closure for each element on the stream.
+    ///
+    /// The provided closure will be called for each item this stream produces,
+    /// yielding a future. That future will then be executed to completion
+    /// before moving on to the next item.
     ///
     /// The returned value is a [`Future`](futures_core::future::Future) where
     /// the [`Output`](futures_core::future::Future::Output) type is
@@ -1133,7 +1592,9 @@ pub trait StreamExt: Stream {
     /// This is similar to
     /// [`StreamExt::for_each_concurrent`](crate::stream::StreamExt::for_each_concurrent),
     /// but will resolve to an error immediately if the provided closure returns
-    /// an error.
+    /// an error. Any futures still in flight at that point are dropped; see
+    /// [`try_for_each_concurrent_with_policy`](StreamExt::try_for_each_concurrent_with_policy)
+    /// to wait for them instead.
     ///
     /// This method is only available when the `std` or `alloc` feature of this
     /// library is activated, and it is activated by default.
@@ -1182,6 +1643,123 @@ pub trait StreamExt: Stream {
         assert_future::<Result<(), E>, _>(TryForEachConcurrent::new(self, limit.into(), f))
     }
 
+    /// Like [`try_for_each_concurrent`](StreamExt::try_for_each_concurrent),
+    /// but lets the caller decide what happens to the futures that are still
+    /// running when one of them fails.
+    ///
+    /// `try_for_each_concurrent` drops every in-flight future as soon as an
+    /// error arrives, which can leave external state half-written. With
+    /// [`ErrorPolicy::DrainInFlight`] no new futures are started after the
+    /// first error, but the ones that were already started are awaited before
+    /// that error is returned. [`ErrorPolicy::Continue`] processes the whole
+    /// stream before returning the first error, and
+    /// [`ErrorPolicy::FailFast`] behaves exactly like
+    /// `try_for_each_concurrent`. Errors after the first one are discarded.
+    ///
+    /// This method is only available when the `std` or `alloc` feature of this
+    /// library is activated, and it is activated by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::channel::oneshot;
+    /// use futures::stream::{self, ErrorPolicy, StreamExt};
+    ///
+    /// let (tx1, rx1) = oneshot::channel();
+    /// let (tx2, rx2) = oneshot::channel();
+    ///
+    /// let fut = stream::iter(vec![rx1, rx2]).try_for_each_concurrent_with_policy(
+    ///     None,
+    ///     ErrorPolicy::DrainInFlight,
+    ///     |rx| rx,
+    /// );
+    ///
+    /// // The first future fails, but the second one still gets to finish.
+    /// drop(tx1);
+    /// tx2.send(()).unwrap();
+    /// assert_eq!(fut.await, Err(oneshot::Canceled));
+    /// # })
+    /// ```
+    #[cfg(not(futures_no_atomic_cas))]
+    #[cfg(feature = "alloc")]
+    fn try_for_each_concurrent_with_policy<Fut, F>(
+        self,
+        limit: impl Into<Option<usize>>,
+        policy: ErrorPolicy,
+        f: F,
+    ) -> TryForEachConcurrentWithPolicy<Self, Fut, F>
+    where
+        F: FnMut(Self::Item) -> Fut,
+        Fut: TryFuture<Ok = ()>,
+        Self: Sized,
+    {
+        assert_future::<Result<(), Fut::Error>, _>(TryForEachConcurrentWithPolicy::new(
+            self,
+            limit.into(),
+            policy,
+            f,
+        ))
+    }
+
+    /// Maps every item of this stream to a fallible future, runs those futures
+    /// concurrently and collects their successful outputs in stream order.
+    ///
+    /// This is the fallible counterpart of
+    /// [`map_concurrent`](StreamExt::map_concurrent). The `policy` argument
+    /// controls what happens once one of the futures resolves to an error:
+    ///
+    /// * [`ErrorPolicy::FailFast`] drops the in-flight futures and resolves to
+    ///   the error immediately.
+    /// * [`ErrorPolicy::DrainInFlight`] stops pulling items from the stream,
+    ///   waits for the futures that were already started, and then resolves
+    ///   to the first error.
+    /// * [`ErrorPolicy::Continue`] processes the rest of the stream and then
+    ///   resolves to the first error.
+    ///
+    /// This method is only available when the `std` or `alloc` feature of this
+    /// library is activated, and it is activated by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, ErrorPolicy, StreamExt};
+    ///
+    /// let fut = stream::iter(1..=3)
+    ///     .try_map_concurrent(None, ErrorPolicy::FailFast, |x| async move { Ok::<_, ()>(x + 1) });
+    /// assert_eq!(fut.await, Ok(vec![2, 3, 4]));
+    ///
+    /// let mut seen = Vec::new();
+    /// let fut = stream::iter(1..=5).try_map_concurrent(1, ErrorPolicy::Continue, |x| {
+    ///     seen.push(x);
+    ///     async move { if x % 2 == 0 { Err(x) } else { Ok(x) } }
+    /// });
+    /// assert_eq!(fut.await, Err(2));
+    /// assert_eq!(seen, vec![1, 2, 3, 4, 5]);
+    /// # })
+    /// ```
+    #[cfg(not(futures_no_atomic_cas))]
+    #[cfg(feature = "alloc")]
+    fn try_map_concurrent<Fut, F>(
+        self,
+        limit: impl Into<Option<usize>>,
+        policy: ErrorPolicy,
+        f: F,
+    ) -> TryMapConcurrent<Self, Fut, F>
+    where
+        F: FnMut(Self::Item) -> Fut,
+        Fut: TryFuture,
+        Self: Sized,
+    {
+        assert_future::<Result<Vec<Fut::Ok>, Fut::Error>, _>(TryMapConcurrent::new(
+            self,
+            limit.into(),
+            policy,
+            f,
+        ))
+    }
+
     /// Creates a new stream of at most `n` items of the underlying stream.
     ///
     /// Once `n` items have been yielded from this stream then it will always
@@ -1304,6 +1882,37 @@ pub trait StreamExt: Stream {
         self
     }
 
+    /// Creates a stream of at most `n` items that borrows this stream rather
+    /// than consuming it.
+    ///
+    /// This is a shorthand for `self.by_ref().take(n)`: once the returned
+    /// stream is dropped, the remaining items can still be read from the
+    /// original stream. Streams that are not `Unpin` can be pinned first,
+    /// for example with [`pin_mut!`](crate::pin_mut) or
+    /// [`boxed`](StreamExt::boxed).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let mut stream = stream::iter(1..=5);
+    ///
+    /// let header = stream.take_ref(2).collect::<Vec<_>>().await;
+    /// assert_eq!(header, vec![1, 2]);
+    ///
+    /// let body = stream.collect::<Vec<_>>().await;
+    /// assert_eq!(body, vec![3, 4, 5]);
+    /// # });
+    /// ```
+    fn take_ref(&mut self, n: usize) -> Take<&mut Self>
+    where
+        Self: Unpin,
+    {
+        assert_stream::<Self::Item, _>(Take::new(self, n))
+    }
+
     /// Catches unwinding panics while polling the stream.
     ///
     /// Caught panic (if any) will be the last element of the resulting stream.
@@ -1352,6 +1961,152 @@ pub trait StreamExt: Stream {
         assert_stream(CatchUnwind::new(self))
     }
 
+    /// Wraps the stream so that every call to `poll_next` is measured.
+    ///
+    /// The returned stream records how many times it was polled, how many of
+    /// those polls returned `Poll::Pending`, how many items were yielded and
+    /// how long the calls to the underlying `poll_next` took. The statistics
+    /// can be read at any time through [`InstrumentPolls::stats`], which makes
+    /// it possible to observe where a pipeline spends its time, or where it is
+    /// waiting, without pulling in a tracing framework.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let mut stream = stream::iter(vec![1, 2, 3]).instrument_polls();
+    ///
+    /// while let Some(_) = stream.next().await {}
+    ///
+    /// let stats = stream.stats();
+    /// assert_eq!(stats.items(), 3);
+    /// assert_eq!(stats.polls(), 4);
+    /// assert_eq!(stats.pending_polls(), 0);
+    /// # });
+    /// ```
+    #[cfg(feature = "std")]
+    fn instrument_polls(self) -> InstrumentPolls<Self>
+    where
+        Self: Sized,
+    {
+        assert_stream::<Self::Item, _>(InstrumentPolls::new(self))
+    }
+
+    /// Names this stream for the [`PollHook`](crate::instrument::PollHook)s
+    /// registered with [`add_hook`](crate::instrument::add_hook), which are
+    /// called around each call to its `poll_next`.
+    ///
+    /// Unlike [`instrument_polls`](StreamExt::instrument_polls), which keeps
+    /// the statistics of a single stream, this reports to hooks that are
+    /// shared by all instrumented futures and streams.
+    ///
+    /// This method is only available when the `instrument` feature of this
+    /// library is activated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures_util::stream::{self, StreamExt};
+    ///
+    /// let stream = stream::iter(vec![1, 2, 3]).instrument("numbers");
+    /// assert_eq!(stream.collect::<Vec<_>>().await, vec![1, 2, 3]);
+    /// # });
+    /// ```
+    #[cfg(feature = "instrument")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "instrument")))]
+    fn instrument(self, name: &'static str) -> Instrument<Self>
+    where
+        Self: Sized,
+    {
+        assert_stream::<Self::Item, _>(Instrument::new(self, name))
+    }
+
+    /// Converts this stream into an [`AsyncIterator`](core::async_iter::AsyncIterator)
+    /// from the standard library.
+    ///
+    /// The returned adapter implements both traits, so it can be handed to
+    /// APIs written against `AsyncIterator` while still being usable with
+    /// the combinators of this crate. The reverse conversion is
+    /// [`stream::from_async_iter`](crate::stream::from_async_iter).
+    ///
+    /// This method is only available when the `async-iterator` feature of this
+    /// library is activated, which requires a nightly compiler.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_iterator)]
+    /// # futures::executor::block_on(async {
+    /// use core::async_iter::AsyncIterator;
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// fn takes_async_iter(iter: impl AsyncIterator<Item = i32>) -> (usize, Option<usize>) {
+    ///     iter.size_hint()
+    /// }
+    ///
+    /// let iter = stream::iter(vec![1, 2, 3]).into_async_iter();
+    /// assert_eq!(takes_async_iter(iter), (3, Some(3)));
+    /// # });
+    /// ```
+    #[cfg(feature = "async-iterator")]
+    fn into_async_iter(self) -> IntoAsyncIter<Self>
+    where
+        Self: Sized,
+    {
+        IntoAsyncIter::new(self)
+    }
+
+    /// Wraps the stream so that the backpressure it exerts can be observed.
+    ///
+    /// The returned stream counts the items it yields, the number of times
+    /// the underlying stream returned `Poll::Pending`, the number of times it
+    /// woke the task up and the time spent waiting between a `Poll::Pending`
+    /// and the next `Poll::Ready`. A snapshot can be read at any time through
+    /// [`Metered::stream_metrics`].
+    ///
+    /// If the stream is also a sink, the same counters are kept for
+    /// `poll_ready` and are available through [`Metered::sink_metrics`]. A
+    /// sink that is not a stream can be wrapped with [`Metered::new`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::channel::mpsc;
+    /// use futures::join;
+    /// use futures::sink::SinkExt;
+    /// use futures::stream::StreamExt;
+    ///
+    /// let (mut tx, rx) = mpsc::channel(0);
+    /// let mut rx = rx.metered();
+    ///
+    /// let producer = async move {
+    ///     for i in 0..3 {
+    ///         tx.send(i).await.unwrap();
+    ///     }
+    /// };
+    /// let consumer = async {
+    ///     while let Some(_) = rx.next().await {}
+    /// };
+    /// join!(producer, consumer);
+    ///
+    /// let metrics = rx.stream_metrics();
+    /// assert_eq!(metrics.items(), 3);
+    /// assert!(metrics.pending() >= 1);
+    /// # });
+    /// ```
+    #[cfg(not(futures_no_atomic_cas))]
+    #[cfg(feature = "std")]
+    fn metered(self) -> Metered<Self>
+    where
+        Self: Sized,
+    {
+        assert_stream::<Self::Item, _>(Metered::new(self))
+    }
+
     /// Wrap the stream in a Box, pinning it.
     ///
     /// This method is only available when the `std` or `alloc` feature of this
@@ -1483,6 +2238,86 @@ pub trait StreamExt: Stream {
         assert_stream::<(Self::Item, St::Item), _>(Zip::new(self, other))
     }
 
+    /// An adapter for zipping two streams together and combining each pair
+    /// of items with a closure.
+    ///
+    /// This behaves like [`zip`](StreamExt::zip) followed by
+    /// [`map`](StreamExt::map), without the intermediate tuple. To combine
+    /// the items asynchronously, the closure can return a future and the
+    /// result be passed to [`then`](StreamExt::then) or one of the
+    /// [`buffered`](StreamExt::buffered) adapters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let stream1 = stream::iter(1..=3);
+    /// let stream2 = stream::iter(5..=10);
+    ///
+    /// let vec = stream1.zip_with(stream2, |a, b| a * b).collect::<Vec<_>>().await;
+    /// assert_eq!(vec![5, 12, 21], vec);
+    /// # });
+    /// ```
+    fn zip_with<St, F, T>(self, other: St, f: F) -> ZipWith<Self, St, F>
+    where
+        St: Stream,
+        F: FnMut(Self::Item, St::Item) -> T,
+        Self: Sized,
+    {
+        assert_stream::<T, _>(ZipWith::new(self, other, f))
+    }
+
+    /// Pairs each item of this stream with the most recent item of `other`.
+    ///
+    /// Whenever this stream yields an item, it is paired with a clone of the
+    /// last item seen on `other`. Items of this stream that arrive before
+    /// `other` has produced anything are dropped. The returned stream ends
+    /// together with this stream; once `other` ends, its last item keeps
+    /// being used.
+    ///
+    /// `other` is polled until it is pending every time this stream is
+    /// polled, so it must not be a stream that is always ready, such as
+    /// [`stream::repeat`](crate::stream::repeat).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::channel::mpsc;
+    /// use futures::future::FutureExt;
+    /// use futures::stream::StreamExt;
+    ///
+    /// let (config_tx, config_rx) = mpsc::unbounded();
+    /// let (event_tx, event_rx) = mpsc::unbounded();
+    /// let mut stream = event_rx.with_latest_from(config_rx);
+    ///
+    /// // there is no config yet, so the event is dropped
+    /// event_tx.unbounded_send(1).unwrap();
+    /// assert_eq!(stream.next().now_or_never(), None);
+    ///
+    /// config_tx.unbounded_send("a").unwrap();
+    /// event_tx.unbounded_send(2).unwrap();
+    /// assert_eq!(stream.next().await, Some((2, "a")));
+    ///
+    /// config_tx.unbounded_send("b").unwrap();
+    /// config_tx.unbounded_send("c").unwrap();
+    /// event_tx.unbounded_send(3).unwrap();
+    /// event_tx.unbounded_send(4).unwrap();
+    /// drop(event_tx);
+    /// assert_eq!(stream.collect::<Vec<_>>().await, vec![(3, "c"), (4, "c")]);
+    /// # });
+    /// ```
+    fn with_latest_from<St>(self, other: St) -> WithLatestFrom<Self, St>
+    where
+        St: Stream,
+        St::Item: Clone,
+        Self: Sized,
+    {
+        assert_stream::<(Self::Item, St::Item), _>(WithLatestFrom::new(self, other))
+    }
+
     /// Adapter for chaining two streams.
     ///
     /// The resulting stream emits elements from the first stream, and when
@@ -1514,6 +2349,112 @@ pub trait StreamExt: Stream {
         assert_stream::<Self::Item, _>(Chain::new(self, other))
     }
 
+    /// Creates a stream which yields the items of `items` before the items of
+    /// this stream.
+    ///
+    /// This avoids having to name the type of
+    /// `stream::iter(items).chain(self)`. To prepend another stream instead,
+    /// use [`start_with_stream`](StreamExt::start_with_stream).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let stream = stream::iter(vec![3, 4]).start_with(vec![1, 2]);
+    ///
+    /// assert_eq!(vec![1, 2, 3, 4], stream.collect::<Vec<_>>().await);
+    /// # });
+    /// ```
+    fn start_with<I>(self, items: I) -> StartWith<Self, I::IntoIter>
+    where
+        I: IntoIterator<Item = Self::Item>,
+        Self: Sized,
+    {
+        assert_stream::<Self::Item, _>(StartWith::new(self, items.into_iter()))
+    }
+
+    /// Creates a stream which yields the items of `other` until it ends, and
+    /// then the items of this stream.
+    ///
+    /// This is equivalent to `other.chain(self)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let header = stream::once(async { 0 });
+    /// let stream = stream::iter(vec![1, 2]).start_with_stream(header);
+    ///
+    /// assert_eq!(vec![0, 1, 2], stream.collect::<Vec<_>>().await);
+    /// # });
+    /// ```
+    fn start_with_stream<St>(self, other: St) -> Chain<St, Self>
+    where
+        St: Stream<Item = Self::Item>,
+        Self: Sized,
+    {
+        assert_stream::<Self::Item, _>(Chain::new(other, self))
+    }
+
+    /// Creates a stream which places a clone of `separator` between adjacent
+    /// items of this stream.
+    ///
+    /// A separator is only yielded once the item following it is available,
+    /// so the stream never ends with a separator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let stream = stream::iter(vec!["a", "b", "c"]).intersperse(",");
+    ///
+    /// assert_eq!(vec!["a", ",", "b", ",", "c"], stream.collect::<Vec<_>>().await);
+    /// # });
+    /// ```
+    fn intersperse(self, separator: Self::Item) -> Intersperse<Self>
+    where
+        Self::Item: Clone,
+        Self: Sized,
+    {
+        assert_stream::<Self::Item, _>(Intersperse::new(self, separator))
+    }
+
+    /// Creates a stream which places an item generated by `f` between
+    /// adjacent items of this stream.
+    ///
+    /// This is like [`intersperse`](StreamExt::intersperse), for separators
+    /// that are not `Clone` or that differ from one another. `f` is only
+    /// called once the item following the separator is available.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let mut frame = 0;
+    /// let stream = stream::iter(vec!["a".to_string(), "b".to_string()]).intersperse_with(|| {
+    ///     frame += 1;
+    ///     format!("--{}", frame)
+    /// });
+    ///
+    /// assert_eq!(vec!["a", "--1", "b"], stream.collect::<Vec<_>>().await);
+    /// # });
+    /// ```
+    fn intersperse_with<F>(self, f: F) -> IntersperseWith<Self, F>
+    where
+        F: FnMut() -> Self::Item,
+        Self: Sized,
+    {
+        assert_stream::<Self::Item, _>(IntersperseWith::new(self, f))
+    }
+
     /// Creates a new stream which exposes a `peek` method.
     ///
     /// Calling `peek` returns a reference to the next item in the stream.
@@ -1550,6 +2491,80 @@ pub trait StreamExt: Stream {
         assert_stream::<Vec<Self::Item>, _>(Chunks::new(self, capacity))
     }
 
+    /// An adaptor for chunking up items of the stream inside vectors of
+    /// exactly `capacity` elements.
+    ///
+    /// This combinator works like [`chunks`](StreamExt::chunks), except that
+    /// every yielded vector has exactly `capacity` elements. If the underlying
+    /// stream ends with a partial chunk, it is not yielded; instead it can be
+    /// retrieved through [`ChunksExact::remainder`] (or
+    /// [`ChunksExact::into_remainder`]) once the stream has ended.
+    ///
+    /// This method is only available when the `std` or `alloc` feature of this
+    /// library is activated, and it is activated by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let mut stream = stream::iter(1..=5).chunks_exact(2);
+    ///
+    /// assert_eq!(stream.next().await, Some(vec![1, 2]));
+    /// assert_eq!(stream.next().await, Some(vec![3, 4]));
+    /// assert_eq!(stream.next().await, None);
+    /// assert_eq!(stream.remainder(), &[5]);
+    /// # });
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `capacity` is zero.
+    #[cfg(feature = "alloc")]
+    fn chunks_exact(self, capacity: usize) -> ChunksExact<Self>
+    where
+        Self: Sized,
+    {
+        assert_stream::<Vec<Self::Item>, _>(ChunksExact::new(self, capacity))
+    }
+
+    /// An adaptor for grouping consecutive items of the stream into batches
+    /// with data-dependent boundaries.
+    ///
+    /// Each received item is offered to `f` together with the batch built so
+    /// far. While `f` returns `true`, the item is added to the batch. When it
+    /// returns `false`, the batch is yielded and a new one is started with
+    /// that item. The first item of a batch is always accepted without
+    /// calling `f`. This can express boundaries such as "same trace id" or
+    /// "total size below 64 KiB", which [`chunks`](StreamExt::chunks) cannot.
+    ///
+    /// If the underlying stream ends with a partial batch, it is returned.
+    ///
+    /// This method is only available when the `std` or `alloc` feature of this
+    /// library is activated, and it is activated by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// // Batches whose sum stays at or below 5.
+    /// let stream = stream::iter(vec![1, 2, 3, 4, 1, 5])
+    ///     .buffer_while(|batch, item| batch.iter().sum::<i32>() + item <= 5);
+    /// assert_eq!(stream.collect::<Vec<_>>().await, vec![vec![1, 2], vec![3], vec![4, 1], vec![5]]);
+    /// # });
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn buffer_while<F>(self, f: F) -> BufferWhile<Self, F>
+    where
+        F: FnMut(&[Self::Item], &Self::Item) -> bool,
+        Self: Sized,
+    {
+        assert_stream::<Vec<Self::Item>, _>(BufferWhile::new(self, f))
+    }
+
     /// An adaptor for chunking up ready items of the stream inside a vector.
     ///
     /// This combinator will attempt to pull ready items from this stream and
@@ -1576,6 +2591,128 @@ pub trait StreamExt: Stream {
         assert_stream::<Vec<Self::Item>, _>(ReadyChunks::new(self, capacity))
     }
 
+    /// An adaptor for chunking up ready items of the stream inside a vector,
+    /// waiting a bounded amount of time for each chunk to fill up.
+    ///
+    /// This combinator works like [`ready_chunks`](StreamExt::ready_chunks),
+    /// except that when the underlying stream returns `Poll::Pending` with a
+    /// non-empty chunk, the chunk is not returned immediately. Instead, the
+    /// chunk is returned once it holds `capacity` items, or once the deadline
+    /// of the chunk has elapsed, whichever comes first.
+    ///
+    /// The deadline of a chunk is a future created by calling `delay` when the
+    /// first item of the chunk is received, which typically comes from a timer
+    /// such as `async_std::task::sleep` or `tokio::time::sleep`. This keeps the
+    /// combinator independent of any particular runtime, while producing
+    /// bigger batches for downstream bulk operations under a slow producer.
+    ///
+    /// If the underlying stream ended and only a partial vector was created,
+    /// it'll be returned.
+    ///
+    /// This method is only available when the `std` or `alloc` feature of this
+    /// library is activated, and it is activated by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::future;
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// // A deadline which elapses immediately degenerates to `ready_chunks`.
+    /// let stream = stream::iter(1..=5).ready_chunks_timeout(2, || future::ready(()));
+    /// assert_eq!(stream.collect::<Vec<_>>().await, vec![vec![1, 2], vec![3, 4], vec![5]]);
+    /// # });
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `capacity` is zero.
+    #[cfg(feature = "alloc")]
+    fn ready_chunks_timeout<F, Fut>(
+        self,
+        capacity: usize,
+        delay: F,
+    ) -> ReadyChunksTimeout<Self, F, Fut>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future,
+        Self: Sized,
+    {
+        assert_stream::<Vec<Self::Item>, _>(ReadyChunksTimeout::new(self, capacity, delay))
+    }
+
+    /// Delays each item of this stream by a duration chosen per item.
+    ///
+    /// When an item is received, `f` is called with a reference to it to
+    /// create a delay future, typically a timer such as
+    /// `async_std::task::sleep` or `tokio::time::sleep`. The item is yielded
+    /// once that future completes. The next item is only pulled from the
+    /// underlying stream after the previous one has been yielded, so order is
+    /// preserved and the delays add up, which makes this suitable for replaying
+    /// a recorded trace with its original gaps between events. For a fixed
+    /// delay, ignore the argument of `f`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::future;
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let mut delays = Vec::new();
+    /// let stream = stream::iter(vec![(10, 'a'), (30, 'b')]).delay_items(|&(gap, _)| {
+    ///     delays.push(gap);
+    ///     future::ready(())
+    /// });
+    /// assert_eq!(stream.collect::<Vec<_>>().await, vec![(10, 'a'), (30, 'b')]);
+    /// assert_eq!(delays, vec![10, 30]);
+    /// # });
+    /// ```
+    fn delay_items<F, Fut>(self, f: F) -> DelayItems<Self, F, Fut>
+    where
+        F: FnMut(&Self::Item) -> Fut,
+        Fut: Future,
+        Self: Sized,
+    {
+        assert_stream::<Self::Item, _>(DelayItems::new(self, f))
+    }
+
+    /// Samples this stream, yielding only the most recent item seen during
+    /// each tick and dropping the rest.
+    ///
+    /// Ticks are delimited by delay futures created by calling `delay`,
+    /// typically a timer such as `async_std::task::sleep` or
+    /// `tokio::time::sleep`; a new tick starts as soon as the previous one
+    /// elapses. When a tick elapses, the latest item received during it is
+    /// yielded, and nothing is yielded for a tick in which no item arrived.
+    /// This suits telemetry streams where only the freshest value matters.
+    ///
+    /// When the underlying stream ends, the last item it produced that has
+    /// not been yielded yet is returned without waiting for the tick.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::future;
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// // All items are ready before the first tick, so only the last one
+    /// // survives.
+    /// let stream = stream::iter(1..=5).sample(future::pending::<()>);
+    /// assert_eq!(stream.collect::<Vec<_>>().await, vec![5]);
+    /// # });
+    /// ```
+    fn sample<F, Fut>(self, delay: F) -> Sample<Self, F, Fut>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future,
+        Self: Sized,
+    {
+        assert_stream::<Self::Item, _>(Sample::new(self, delay))
+    }
+
     /// A future that completes after the given stream has been fully processed
     /// into the sink and the sink has been flushed and closed.
     ///
@@ -1596,6 +2733,81 @@ pub trait StreamExt: Stream {
         assert_future::<Result<(), S::Error>, _>(Forward::new(self, sink))
     }
 
+    /// A future that completes after the given stream has been fully processed
+    /// into the sink, flushing the sink after every `n` items.
+    ///
+    /// This works like [`forward`](StreamExt::forward), which only flushes the
+    /// sink when the stream is not ready to produce more items or has ended.
+    /// With a fast producer and a buffering sink, that could hold back items
+    /// indefinitely, so this variant additionally flushes the sink once `n`
+    /// items have been sent to it since the last flush.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::sink::SinkExt;
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let mut sink = Vec::new().buffer(16);
+    /// stream::iter(1..=5).forward_flush_every(&mut sink, 2).await?;
+    /// assert_eq!(sink.get_ref(), &[1, 2, 3, 4, 5]);
+    /// # Ok::<(), core::convert::Infallible>(()) }).unwrap();
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `n` is zero.
+    #[cfg(feature = "sink")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "sink")))]
+    fn forward_flush_every<S>(self, sink: S, n: usize) -> ForwardFlushEvery<Self, S>
+    where
+        S: Sink<Self::Item>,
+        Self: Sized,
+    {
+        assert_future::<Result<(), S::Error>, _>(ForwardFlushEvery::new(self, sink, n))
+    }
+
+    /// A future that completes after the given stream has been fully processed
+    /// into the sink, flushing the sink whenever items have been left
+    /// unflushed for a while.
+    ///
+    /// This works like [`forward`](StreamExt::forward), except that once an
+    /// item has been sent to the sink, a deadline is started by calling
+    /// `delay`, and the sink is flushed when it elapses even if the stream
+    /// keeps producing items. The deadline is usually a timer future, such as
+    /// `async_std::task::sleep` or `tokio::time::sleep`, which bounds the
+    /// latency added by a buffering sink.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::future;
+    /// use futures::sink::SinkExt;
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let mut sink = Vec::new().buffer(16);
+    /// stream::iter(1..=5).forward_flush_interval(&mut sink, || future::ready(())).await?;
+    /// assert_eq!(sink.get_ref(), &[1, 2, 3, 4, 5]);
+    /// # Ok::<(), core::convert::Infallible>(()) }).unwrap();
+    /// ```
+    #[cfg(feature = "sink")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "sink")))]
+    fn forward_flush_interval<S, F, Fut>(
+        self,
+        sink: S,
+        delay: F,
+    ) -> ForwardFlushInterval<Self, S, F, Fut>
+    where
+        S: Sink<Self::Item>,
+        F: FnMut() -> Fut,
+        Fut: Future,
+        Self: Sized,
+    {
+        assert_future::<Result<(), S::Error>, _>(ForwardFlushInterval::new(self, sink, delay))
+    }
+
     /// Splits this `Stream + Sink` object into separate `Sink` and `Stream`
     /// objects.
     ///
@@ -0,0 +1,94 @@
+use core::fmt;
+use core::pin::Pin;
+use futures_core::future::{FusedFuture, Future};
+use futures_core::ready;
+use futures_core::stream::Stream;
+use futures_core::task::{Context, Poll};
+use pin_project_lite::pin_project;
+
+pin_project! {
+    /// Future for the [`position`](super::StreamExt::position) method.
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct Position<St, Fut, F> {
+        #[pin]
+        stream: St,
+        f: F,
+        index: Option<usize>,
+        #[pin]
+        future: Option<Fut>,
+    }
+}
+
+impl<St, Fut, F> fmt::Debug for Position<St, Fut, F>
+where
+    St: fmt::Debug,
+    Fut: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Position")
+            .field("stream", &self.stream)
+            .field("index", &self.index)
+            .field("future", &self.future)
+            .finish()
+    }
+}
+
+impl<St, Fut, F> Position<St, Fut, F>
+where
+    St: Stream,
+    F: FnMut(St::Item) -> Fut,
+    Fut: Future<Output = bool>,
+{
+    pub(super) fn new(stream: St, f: F) -> Self {
+        Self { stream, f, index: Some(0), future: None }
+    }
+}
+
+impl<St, Fut, F> FusedFuture for Position<St, Fut, F>
+where
+    St: Stream,
+    F: FnMut(St::Item) -> Fut,
+    Fut: Future<Output = bool>,
+{
+    fn is_terminated(&self) -> bool {
+        self.index.is_none() && self.future.is_none()
+    }
+}
+
+impl<St, Fut, F> Future for Position<St, Fut, F>
+where
+    St: Stream,
+    F: FnMut(St::Item) -> Fut,
+    Fut: Future<Output = bool>,
+{
+    type Output = Option<usize>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<usize>> {
+        let mut this = self.project();
+        Poll::Ready(loop {
+            if let Some(fut) = this.future.as_mut().as_pin_mut() {
+                // we're currently checking the item at `index`
+                let found = ready!(fut.poll(cx));
+                this.future.set(None);
+                let index = this.index.take().unwrap();
+                if found {
+                    break Some(index);
+                }
+                *this.index = Some(index + 1);
+            } else if this.index.is_some() {
+                // we're waiting on a new item from the stream
+                match ready!(this.stream.as_mut().poll_next(cx)) {
+                    Some(item) => {
+                        this.future.set(Some((this.f)(item)));
+                    }
+                    None => {
+                        *this.index = None;
+                        break None;
+                    }
+                }
+            } else {
+                panic!("Position polled after completion")
+            }
+        })
+    }
+}
@@ -0,0 +1,162 @@
+use crate::stream::Fuse;
+use alloc::vec::Vec;
+use core::fmt;
+use core::mem;
+use core::pin::Pin;
+use futures_core::future::Future;
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Context, Poll};
+#[cfg(feature = "sink")]
+use futures_sink::Sink;
+use pin_project_lite::pin_project;
+
+pin_project! {
+    /// Stream for the [`ready_chunks_timeout`](super::StreamExt::ready_chunks_timeout) method.
+    #[must_use = "streams do nothing unless polled"]
+    pub struct ReadyChunksTimeout<St: Stream, F, Fut> {
+        #[pin]
+        stream: Fuse<St>,
+        items: Vec<St::Item>,
+        cap: usize,
+        delay: F,
+        #[pin]
+        deadline: Option<Fut>,
+    }
+}
+
+impl<St, F, Fut> fmt::Debug for ReadyChunksTimeout<St, F, Fut>
+where
+    St: Stream + fmt::Debug,
+    St::Item: fmt::Debug,
+    Fut: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ReadyChunksTimeout")
+            .field("stream", &self.stream)
+            .field("items", &self.items)
+            .field("cap", &self.cap)
+            .field("deadline", &self.deadline)
+            .finish()
+    }
+}
+
+impl<St, F, Fut> ReadyChunksTimeout<St, F, Fut>
+where
+    St: Stream,
+    F: FnMut() -> Fut,
+    Fut: Future,
+{
+    pub(super) fn new(stream: St, capacity: usize, delay: F) -> Self {
+        assert!(capacity > 0);
+
+        Self {
+            stream: super::Fuse::new(stream),
+            items: Vec::with_capacity(capacity),
+            cap: capacity,
+            delay,
+            deadline: None,
+        }
+    }
+
+    delegate_access_inner!(stream, St, (.));
+}
+
+impl<St, F, Fut> Stream for ReadyChunksTimeout<St, F, Fut>
+where
+    St: Stream,
+    F: FnMut() -> Fut,
+    Fut: Future,
+{
+    type Item = Vec<St::Item>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        loop {
+            match this.stream.as_mut().poll_next(cx) {
+                // The underlying stream has no more ready values, so wait for
+                // the deadline of the current chunk, if there is one.
+                Poll::Pending => {
+                    let deadline = match this.deadline.as_mut().as_pin_mut() {
+                        Some(deadline) => deadline,
+                        None => return Poll::Pending,
+                    };
+                    return match deadline.poll(cx) {
+                        Poll::Ready(_) => {
+                            this.deadline.set(None);
+                            Poll::Ready(Some(mem::replace(
+                                this.items,
+                                Vec::with_capacity(*this.cap),
+                            )))
+                        }
+                        Poll::Pending => Poll::Pending,
+                    };
+                }
+
+                // Push the ready item into the buffer, starting the deadline
+                // if this is the first item of the chunk, and check whether
+                // the buffer is full.
+                Poll::Ready(Some(item)) => {
+                    if this.items.is_empty() {
+                        this.deadline.set(Some((this.delay)()));
+                    }
+                    this.items.push(item);
+                    if this.items.len() >= *this.cap {
+                        this.deadline.set(None);
+                        return Poll::Ready(Some(mem::replace(
+                            this.items,
+                            Vec::with_capacity(*this.cap),
+                        )));
+                    }
+                }
+
+                // Since the underlying stream ran out of values, return what we
+                // have buffered, if we have anything.
+                Poll::Ready(None) => {
+                    this.deadline.set(None);
+                    let last = if this.items.is_empty() {
+                        None
+                    } else {
+                        let full_buf = mem::replace(this.items, Vec::new());
+                        Some(full_buf)
+                    };
+
+                    return Poll::Ready(last);
+                }
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let chunk_len = if self.items.is_empty() { 0 } else { 1 };
+        let (lower, upper) = self.stream.size_hint();
+        let lower = (lower / self.cap).saturating_add(chunk_len);
+        let upper = match upper {
+            Some(x) => x.checked_add(chunk_len),
+            None => None,
+        };
+        (lower, upper)
+    }
+}
+
+impl<St, F, Fut> FusedStream for ReadyChunksTimeout<St, F, Fut>
+where
+    St: FusedStream,
+    F: FnMut() -> Fut,
+    Fut: Future,
+{
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated() && self.items.is_empty()
+    }
+}
+
+// Forwarding impl of Sink from the underlying stream
+#[cfg(feature = "sink")]
+impl<S, F, Fut, Item> Sink<Item> for ReadyChunksTimeout<S, F, Fut>
+where
+    S: Stream + Sink<Item>,
+{
+    type Error = S::Error;
+
+    delegate_sink!(stream, Item);
+}
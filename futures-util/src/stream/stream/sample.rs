@@ -0,0 +1,126 @@
+use crate::stream::{Fuse, StreamExt};
+use core::fmt;
+use core::pin::Pin;
+use futures_core::future::Future;
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Context, Poll};
+#[cfg(feature = "sink")]
+use futures_sink::Sink;
+use pin_project_lite::pin_project;
+
+pin_project! {
+    /// Stream for the [`sample`](super::StreamExt::sample) method.
+    #[must_use = "streams do nothing unless polled"]
+    pub struct Sample<St: Stream, F, Fut> {
+        #[pin]
+        stream: Fuse<St>,
+        latest: Option<St::Item>,
+        delay: F,
+        #[pin]
+        tick: Option<Fut>,
+    }
+}
+
+impl<St, F, Fut> fmt::Debug for Sample<St, F, Fut>
+where
+    St: Stream + fmt::Debug,
+    St::Item: fmt::Debug,
+    Fut: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Sample")
+            .field("stream", &self.stream)
+            .field("latest", &self.latest)
+            .field("tick", &self.tick)
+            .finish()
+    }
+}
+
+impl<St, F, Fut> Sample<St, F, Fut>
+where
+    St: Stream,
+    F: FnMut() -> Fut,
+    Fut: Future,
+{
+    pub(super) fn new(stream: St, delay: F) -> Self {
+        Self { stream: stream.fuse(), latest: None, delay, tick: None }
+    }
+
+    delegate_access_inner!(stream, St, (.));
+}
+
+impl<St, F, Fut> FusedStream for Sample<St, F, Fut>
+where
+    St: Stream,
+    F: FnMut() -> Fut,
+    Fut: Future,
+{
+    fn is_terminated(&self) -> bool {
+        self.latest.is_none() && self.stream.is_terminated()
+    }
+}
+
+impl<St, F, Fut> Stream for Sample<St, F, Fut>
+where
+    St: Stream,
+    F: FnMut() -> Fut,
+    Fut: Future,
+{
+    type Item = St::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        // Keep only the most recent of the items that are ready.
+        while let Poll::Ready(Some(item)) = this.stream.as_mut().poll_next(cx) {
+            *this.latest = Some(item);
+        }
+
+        // Once the stream has ended, the last item seen is yielded right away.
+        if this.stream.is_terminated() {
+            this.tick.set(None);
+            return Poll::Ready(this.latest.take());
+        }
+
+        if this.tick.is_none() {
+            this.tick.set(Some((this.delay)()));
+        }
+        if this.tick.as_mut().as_pin_mut().unwrap().poll(cx).is_pending() {
+            return Poll::Pending;
+        }
+        this.tick.set(Some((this.delay)()));
+        if let Some(item) = this.latest.take() {
+            return Poll::Ready(Some(item));
+        }
+
+        // Nothing was received during this tick, so wait for the next one.
+        if this.tick.as_mut().as_pin_mut().unwrap().poll(cx).is_ready() {
+            // The new tick elapsed immediately as well; yield to the executor
+            // rather than spinning.
+            this.tick.set(None);
+            cx.waker().wake_by_ref();
+        }
+        Poll::Pending
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let latest_len = if self.latest.is_some() { 1 } else { 0 };
+        let (_, upper) = self.stream.size_hint();
+        let upper = match upper {
+            Some(x) => x.checked_add(latest_len),
+            None => None,
+        };
+        (latest_len, upper)
+    }
+}
+
+// Forwarding impl of Sink from the underlying stream
+#[cfg(feature = "sink")]
+impl<S, F, Fut, Item> Sink<Item> for Sample<S, F, Fut>
+where
+    S: Stream + Sink<Item>,
+{
+    type Error = S::Error;
+
+    delegate_sink!(stream, Item);
+}
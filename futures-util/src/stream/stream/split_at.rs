@@ -0,0 +1,95 @@
+use crate::stream::StreamExt;
+use alloc::vec::Vec;
+use core::fmt;
+use core::mem;
+use core::pin::Pin;
+use futures_core::future::{FusedFuture, Future};
+use futures_core::ready;
+use futures_core::stream::Stream;
+use futures_core::task::{Context, Poll};
+
+/// Future for the [`split_at`](super::StreamExt::split_at) method.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct SplitAt<St: Stream> {
+    stream: Option<St>,
+    items: Vec<St::Item>,
+    n: usize,
+}
+
+impl<St> fmt::Debug for SplitAt<St>
+where
+    St: Stream + fmt::Debug,
+    St::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SplitAt")
+            .field("stream", &self.stream)
+            .field("items", &self.items)
+            .field("n", &self.n)
+            .finish()
+    }
+}
+
+impl<St: Stream + Unpin> Unpin for SplitAt<St> {}
+
+impl<St: Stream + Unpin> SplitAt<St> {
+    pub(super) fn new(stream: St, n: usize) -> Self {
+        Self { stream: Some(stream), items: Vec::with_capacity(n), n }
+    }
+
+    /// Acquires a reference to the underlying stream that this combinator is
+    /// pulling from.
+    ///
+    /// This method returns an `Option` to account for the fact that `SplitAt`'s
+    /// implementation of `Future::poll` consumes the underlying stream during polling
+    /// in order to return it to the caller of `Future::poll`.
+    pub fn get_ref(&self) -> Option<&St> {
+        self.stream.as_ref()
+    }
+
+    /// Acquires a mutable reference to the underlying stream that this
+    /// combinator is pulling from.
+    ///
+    /// Note that care must be taken to avoid tampering with the state of the
+    /// stream which may otherwise confuse this combinator.
+    ///
+    /// This method returns an `Option` to account for the fact that `SplitAt`'s
+    /// implementation of `Future::poll` consumes the underlying stream during polling
+    /// in order to return it to the caller of `Future::poll`.
+    pub fn get_mut(&mut self) -> Option<&mut St> {
+        self.stream.as_mut()
+    }
+
+    /// Consumes this combinator, returning the items collected so far and the
+    /// underlying stream.
+    ///
+    /// This method returns an `Option` to account for the fact that `SplitAt`'s
+    /// implementation of `Future::poll` consumes the underlying stream during polling
+    /// in order to return it to the caller of `Future::poll`.
+    pub fn into_inner(self) -> (Vec<St::Item>, Option<St>) {
+        (self.items, self.stream)
+    }
+}
+
+impl<St: Stream + Unpin> FusedFuture for SplitAt<St> {
+    fn is_terminated(&self) -> bool {
+        self.stream.is_none()
+    }
+}
+
+impl<St: Stream + Unpin> Future for SplitAt<St> {
+    type Output = (Vec<St::Item>, St);
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = &mut *self;
+        let stream = this.stream.as_mut().expect("SplitAt polled after completion");
+        while this.items.len() < this.n {
+            match ready!(stream.poll_next_unpin(cx)) {
+                Some(item) => this.items.push(item),
+                None => break,
+            }
+        }
+        let items = mem::replace(&mut this.items, Vec::new());
+        Poll::Ready((items, this.stream.take().unwrap()))
+    }
+}
@@ -0,0 +1,150 @@
+use super::ErrorPolicy;
+use crate::future::{IntoFuture, TryFutureExt};
+use crate::stream::{FuturesUnordered, StreamExt};
+use core::fmt;
+use core::mem;
+use core::num::NonZeroUsize;
+use core::pin::Pin;
+use futures_core::future::{FusedFuture, Future, TryFuture};
+use futures_core::stream::Stream;
+use futures_core::task::{Context, Poll};
+use pin_project_lite::pin_project;
+
+pin_project! {
+    /// Future for the
+    /// [`try_for_each_concurrent_with_policy`](super::StreamExt::try_for_each_concurrent_with_policy)
+    /// method.
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct TryForEachConcurrentWithPolicy<St, Fut, F>
+        where Fut: TryFuture
+    {
+        #[pin]
+        stream: Option<St>,
+        f: F,
+        futures: FuturesUnordered<IntoFuture<Fut>>,
+        limit: Option<NonZeroUsize>,
+        policy: ErrorPolicy,
+        error: Option<Fut::Error>,
+    }
+}
+
+impl<St, Fut, F> fmt::Debug for TryForEachConcurrentWithPolicy<St, Fut, F>
+where
+    St: fmt::Debug,
+    Fut: TryFuture + fmt::Debug,
+    Fut::Error: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TryForEachConcurrentWithPolicy")
+            .field("stream", &self.stream)
+            .field("futures", &self.futures)
+            .field("limit", &self.limit)
+            .field("policy", &self.policy)
+            .field("error", &self.error)
+            .finish()
+    }
+}
+
+impl<St, Fut, F> TryForEachConcurrentWithPolicy<St, Fut, F>
+where
+    St: Stream,
+    F: FnMut(St::Item) -> Fut,
+    Fut: TryFuture<Ok = ()>,
+{
+    pub(super) fn new(stream: St, limit: Option<usize>, policy: ErrorPolicy, f: F) -> Self {
+        Self {
+            stream: Some(stream),
+            // Note: `limit` = 0 gets ignored.
+            limit: limit.and_then(NonZeroUsize::new),
+            f,
+            futures: FuturesUnordered::new(),
+            policy,
+            error: None,
+        }
+    }
+}
+
+impl<St, Fut, F> FusedFuture for TryForEachConcurrentWithPolicy<St, Fut, F>
+where
+    St: Stream,
+    F: FnMut(St::Item) -> Fut,
+    Fut: TryFuture<Ok = ()>,
+{
+    fn is_terminated(&self) -> bool {
+        self.stream.is_none() && self.futures.is_empty() && self.error.is_none()
+    }
+}
+
+impl<St, Fut, F> Future for TryForEachConcurrentWithPolicy<St, Fut, F>
+where
+    St: Stream,
+    F: FnMut(St::Item) -> Fut,
+    Fut: TryFuture<Ok = ()>,
+{
+    type Output = Result<(), Fut::Error>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut this = self.project();
+        loop {
+            let mut made_progress_this_iter = false;
+
+            // Check if we've already created a number of futures greater than `limit`
+            if this.limit.map(|limit| limit.get() > this.futures.len()).unwrap_or(true) {
+                let poll_res = match this.stream.as_mut().as_pin_mut() {
+                    Some(stream) => stream.poll_next(cx),
+                    None => Poll::Ready(None),
+                };
+
+                let elem = match poll_res {
+                    Poll::Ready(Some(elem)) => {
+                        made_progress_this_iter = true;
+                        Some(elem)
+                    }
+                    Poll::Ready(None) => {
+                        this.stream.set(None);
+                        None
+                    }
+                    Poll::Pending => None,
+                };
+
+                if let Some(elem) = elem {
+                    this.futures.push((this.f)(elem).into_future());
+                }
+            }
+
+            match this.futures.poll_next_unpin(cx) {
+                Poll::Ready(Some(Ok(()))) => made_progress_this_iter = true,
+                Poll::Ready(Some(Err(e))) => {
+                    made_progress_this_iter = true;
+                    match this.policy {
+                        ErrorPolicy::FailFast => {
+                            // Empty the stream and futures so that we know
+                            // the future has completed.
+                            this.stream.set(None);
+                            drop(mem::replace(this.futures, FuturesUnordered::new()));
+                            return Poll::Ready(Err(e));
+                        }
+                        ErrorPolicy::DrainInFlight => this.stream.set(None),
+                        ErrorPolicy::Continue => {}
+                    }
+                    if this.error.is_none() {
+                        *this.error = Some(e);
+                    }
+                }
+                Poll::Ready(None) => {
+                    if this.stream.is_none() {
+                        return Poll::Ready(match this.error.take() {
+                            Some(e) => Err(e),
+                            None => Ok(()),
+                        });
+                    }
+                }
+                Poll::Pending => {}
+            }
+
+            if !made_progress_this_iter {
+                return Poll::Pending;
+            }
+        }
+    }
+}
@@ -0,0 +1,192 @@
+use super::for_each_concurrent_with_results::Indexed;
+use crate::future::{IntoFuture, TryFutureExt};
+use crate::stream::{FuturesUnordered, StreamExt};
+use alloc::vec::Vec;
+use core::fmt;
+use core::mem;
+use core::num::NonZeroUsize;
+use core::pin::Pin;
+use futures_core::future::{FusedFuture, Future, TryFuture};
+use futures_core::stream::Stream;
+use futures_core::task::{Context, Poll};
+use pin_project_lite::pin_project;
+
+/// Determines what a concurrent combinator does once one of its futures
+/// resolves to an error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ErrorPolicy {
+    /// Drop every in-flight future and resolve to the error immediately.
+    ///
+    /// This is the default.
+    FailFast,
+    /// Stop pulling items from the stream, but run the futures that have
+    /// already been started to completion before resolving to the first
+    /// error.
+    DrainInFlight,
+    /// Keep processing the remainder of the stream and resolve to the first
+    /// error once everything has completed.
+    Continue,
+}
+
+impl Default for ErrorPolicy {
+    fn default() -> Self {
+        Self::FailFast
+    }
+}
+
+pin_project! {
+    /// Future for the [`try_map_concurrent`](super::StreamExt::try_map_concurrent)
+    /// method.
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct TryMapConcurrent<St, Fut, F>
+        where Fut: TryFuture
+    {
+        #[pin]
+        stream: Option<St>,
+        f: F,
+        futures: FuturesUnordered<Indexed<IntoFuture<Fut>>>,
+        limit: Option<NonZeroUsize>,
+        policy: ErrorPolicy,
+        next_index: usize,
+        results: Vec<(usize, Fut::Ok)>,
+        error: Option<Fut::Error>,
+    }
+}
+
+impl<St, Fut, F> fmt::Debug for TryMapConcurrent<St, Fut, F>
+where
+    St: fmt::Debug,
+    Fut: TryFuture + fmt::Debug,
+    Fut::Ok: fmt::Debug,
+    Fut::Error: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TryMapConcurrent")
+            .field("stream", &self.stream)
+            .field("futures", &self.futures)
+            .field("limit", &self.limit)
+            .field("policy", &self.policy)
+            .field("results", &self.results)
+            .field("error", &self.error)
+            .finish()
+    }
+}
+
+impl<St, Fut, F> TryMapConcurrent<St, Fut, F>
+where
+    St: Stream,
+    F: FnMut(St::Item) -> Fut,
+    Fut: TryFuture,
+{
+    pub(super) fn new(stream: St, limit: Option<usize>, policy: ErrorPolicy, f: F) -> Self {
+        Self {
+            stream: Some(stream),
+            // Note: `limit` = 0 gets ignored.
+            limit: limit.and_then(NonZeroUsize::new),
+            f,
+            futures: FuturesUnordered::new(),
+            policy,
+            next_index: 0,
+            results: Vec::new(),
+            error: None,
+        }
+    }
+}
+
+impl<St, Fut, F> FusedFuture for TryMapConcurrent<St, Fut, F>
+where
+    St: Stream,
+    F: FnMut(St::Item) -> Fut,
+    Fut: TryFuture,
+{
+    fn is_terminated(&self) -> bool {
+        self.stream.is_none() && self.futures.is_empty() && self.error.is_none()
+    }
+}
+
+impl<St, Fut, F> Future for TryMapConcurrent<St, Fut, F>
+where
+    St: Stream,
+    F: FnMut(St::Item) -> Fut,
+    Fut: TryFuture,
+{
+    type Output = Result<Vec<Fut::Ok>, Fut::Error>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut this = self.project();
+        loop {
+            let mut made_progress_this_iter = false;
+
+            // Check if we've already created a number of futures greater than `limit`
+            if this.limit.map(|limit| limit.get() > this.futures.len()).unwrap_or(true) {
+                let poll_res = match this.stream.as_mut().as_pin_mut() {
+                    Some(stream) => stream.poll_next(cx),
+                    None => Poll::Ready(None),
+                };
+
+                let elem = match poll_res {
+                    Poll::Ready(Some(elem)) => {
+                        made_progress_this_iter = true;
+                        Some(elem)
+                    }
+                    Poll::Ready(None) => {
+                        this.stream.set(None);
+                        None
+                    }
+                    Poll::Pending => None,
+                };
+
+                if let Some(elem) = elem {
+                    let future = (this.f)(elem).into_future();
+                    this.futures.push(Indexed::new(future, *this.next_index));
+                    *this.next_index += 1;
+                }
+            }
+
+            match this.futures.poll_next_unpin(cx) {
+                Poll::Ready(Some((index, Ok(output)))) => {
+                    // Once an error has been seen the results are never
+                    // returned, so there is no point in keeping them.
+                    if this.error.is_none() {
+                        this.results.push((index, output));
+                    }
+                    made_progress_this_iter = true;
+                }
+                Poll::Ready(Some((_, Err(e)))) => {
+                    made_progress_this_iter = true;
+                    match this.policy {
+                        ErrorPolicy::FailFast => {
+                            // Empty the stream and futures so that we know
+                            // the future has completed.
+                            this.stream.set(None);
+                            drop(mem::replace(this.futures, FuturesUnordered::new()));
+                            this.results.clear();
+                            return Poll::Ready(Err(e));
+                        }
+                        ErrorPolicy::DrainInFlight => this.stream.set(None),
+                        ErrorPolicy::Continue => {}
+                    }
+                    if this.error.is_none() {
+                        *this.error = Some(e);
+                        this.results.clear();
+                    }
+                }
+                Poll::Ready(None) => {
+                    if this.stream.is_none() {
+                        if let Some(e) = this.error.take() {
+                            return Poll::Ready(Err(e));
+                        }
+                        let mut results = mem::replace(this.results, Vec::new());
+                        results.sort_unstable_by_key(|(index, _)| *index);
+                        return Poll::Ready(Ok(results.into_iter().map(|(_, ok)| ok).collect()));
+                    }
+                }
+                Poll::Pending => {}
+            }
+
+            if !made_progress_this_iter {
+                return Poll::Pending;
+            }
+        }
+    }
+}
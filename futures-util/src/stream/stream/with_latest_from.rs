@@ -0,0 +1,113 @@
+use crate::stream::{Fuse, StreamExt};
+use core::pin::Pin;
+use futures_core::ready;
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Context, Poll};
+use pin_project_lite::pin_project;
+
+pin_project! {
+    /// Stream for the [`with_latest_from`](super::StreamExt::with_latest_from) method.
+    #[derive(Debug)]
+    #[must_use = "streams do nothing unless polled"]
+    pub struct WithLatestFrom<St: Stream, Other: Stream> {
+        #[pin]
+        stream: St,
+        #[pin]
+        other: Fuse<Other>,
+        latest: Option<Other::Item>,
+    }
+}
+
+impl<St: Stream, Other: Stream> WithLatestFrom<St, Other> {
+    pub(super) fn new(stream: St, other: Other) -> Self {
+        Self { stream, other: other.fuse(), latest: None }
+    }
+
+    /// Returns the most recent item seen on the secondary stream, if any.
+    pub fn latest(&self) -> Option<&Other::Item> {
+        self.latest.as_ref()
+    }
+
+    /// Acquires a reference to the underlying streams that this combinator is
+    /// pulling from.
+    pub fn get_ref(&self) -> (&St, &Other) {
+        (&self.stream, self.other.get_ref())
+    }
+
+    /// Acquires a mutable reference to the underlying streams that this
+    /// combinator is pulling from.
+    ///
+    /// Note that care must be taken to avoid tampering with the state of the
+    /// stream which may otherwise confuse this combinator.
+    pub fn get_mut(&mut self) -> (&mut St, &mut Other) {
+        (&mut self.stream, self.other.get_mut())
+    }
+
+    /// Acquires a pinned mutable reference to the underlying streams that this
+    /// combinator is pulling from.
+    ///
+    /// Note that care must be taken to avoid tampering with the state of the
+    /// stream which may otherwise confuse this combinator.
+    pub fn get_pin_mut(self: Pin<&mut Self>) -> (Pin<&mut St>, Pin<&mut Other>) {
+        let this = self.project();
+        (this.stream, this.other.get_pin_mut())
+    }
+
+    /// Consumes this combinator, returning the underlying streams.
+    ///
+    /// Note that this may discard intermediate state of this combinator, so
+    /// care should be taken to avoid losing resources when this is called.
+    pub fn into_inner(self) -> (St, Other) {
+        (self.stream, self.other.into_inner())
+    }
+}
+
+impl<St, Other> FusedStream for WithLatestFrom<St, Other>
+where
+    St: FusedStream,
+    Other: Stream,
+    Other::Item: Clone,
+{
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated()
+    }
+}
+
+impl<St, Other> Stream for WithLatestFrom<St, Other>
+where
+    St: Stream,
+    Other: Stream,
+    Other::Item: Clone,
+{
+    type Item = (St::Item, Other::Item);
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        loop {
+            // Catch up with the secondary stream first, so that the item paired
+            // with the primary one is the most recent that is available.
+            while let Poll::Ready(Some(item)) = this.other.as_mut().poll_next(cx) {
+                *this.latest = Some(item);
+            }
+
+            match ready!(this.stream.as_mut().poll_next(cx)) {
+                Some(item) => {
+                    if let Some(latest) = this.latest {
+                        return Poll::Ready(Some((item, latest.clone())));
+                    }
+                }
+                None => return Poll::Ready(None),
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.stream.size_hint();
+        if self.latest.is_some() {
+            (lower, upper)
+        } else {
+            (0, upper)
+        }
+    }
+}
@@ -0,0 +1,98 @@
+use super::Zip;
+use core::fmt;
+use core::pin::Pin;
+use futures_core::ready;
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Context, Poll};
+use pin_project_lite::pin_project;
+
+pin_project! {
+    /// Stream for the [`zip_with`](super::StreamExt::zip_with) method.
+    #[must_use = "streams do nothing unless polled"]
+    pub struct ZipWith<St1: Stream, St2: Stream, F> {
+        #[pin]
+        zip: Zip<St1, St2>,
+        f: F,
+    }
+}
+
+impl<St1, St2, F> fmt::Debug for ZipWith<St1, St2, F>
+where
+    St1: Stream + fmt::Debug,
+    St1::Item: fmt::Debug,
+    St2: Stream + fmt::Debug,
+    St2::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ZipWith").field("zip", &self.zip).finish()
+    }
+}
+
+impl<St1: Stream, St2: Stream, F> ZipWith<St1, St2, F> {
+    pub(super) fn new(stream1: St1, stream2: St2, f: F) -> Self {
+        Self { zip: Zip::new(stream1, stream2), f }
+    }
+
+    /// Acquires a reference to the underlying streams that this combinator is
+    /// pulling from.
+    pub fn get_ref(&self) -> (&St1, &St2) {
+        self.zip.get_ref()
+    }
+
+    /// Acquires a mutable reference to the underlying streams that this
+    /// combinator is pulling from.
+    ///
+    /// Note that care must be taken to avoid tampering with the state of the
+    /// stream which may otherwise confuse this combinator.
+    pub fn get_mut(&mut self) -> (&mut St1, &mut St2) {
+        self.zip.get_mut()
+    }
+
+    /// Acquires a pinned mutable reference to the underlying streams that this
+    /// combinator is pulling from.
+    ///
+    /// Note that care must be taken to avoid tampering with the state of the
+    /// stream which may otherwise confuse this combinator.
+    pub fn get_pin_mut(self: Pin<&mut Self>) -> (Pin<&mut St1>, Pin<&mut St2>) {
+        self.project().zip.get_pin_mut()
+    }
+
+    /// Consumes this combinator, returning the underlying streams.
+    ///
+    /// Note that this may discard intermediate state of this combinator, so
+    /// care should be taken to avoid losing resources when this is called.
+    pub fn into_inner(self) -> (St1, St2) {
+        self.zip.into_inner()
+    }
+}
+
+impl<St1, St2, F, T> FusedStream for ZipWith<St1, St2, F>
+where
+    St1: Stream,
+    St2: Stream,
+    F: FnMut(St1::Item, St2::Item) -> T,
+{
+    fn is_terminated(&self) -> bool {
+        self.zip.is_terminated()
+    }
+}
+
+impl<St1, St2, F, T> Stream for ZipWith<St1, St2, F>
+where
+    St1: Stream,
+    St2: Stream,
+    F: FnMut(St1::Item, St2::Item) -> T,
+{
+    type Item = T;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<T>> {
+        let this = self.project();
+        let f = this.f;
+        let res = ready!(this.zip.poll_next(cx));
+        Poll::Ready(res.map(|(item1, item2)| f(item1, item2)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.zip.size_hint()
+    }
+}
@@ -0,0 +1,231 @@
+//! A keyed set of streams
+
+use core::fmt::{self, Debug};
+use core::iter::FromIterator;
+use core::pin::Pin;
+
+use futures_core::future::Future;
+use futures_core::ready;
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Context, Poll, Waker};
+
+use super::assert_stream;
+use crate::stream::{FuturesUnordered, StreamExt};
+
+/// An event produced by a [`StreamGroup`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StreamGroupEvent<K, T> {
+    /// The stream registered under the key produced an item.
+    Item(K, T),
+    /// The stream registered under the key has terminated and has been
+    /// removed from the group.
+    Terminated(K),
+}
+
+/// A dynamic set of streams, each registered under a key.
+///
+/// This is like [`SelectAll`](super::SelectAll), but every stream is
+/// inserted under a key, and the group yields the key together with each
+/// item so the caller can tell which stream produced it. When a stream
+/// terminates, the group yields a [`StreamGroupEvent::Terminated`] event
+/// for its key. Streams can also be removed by key at any time.
+///
+/// Looking up a key is a linear scan over the streams in the group.
+///
+/// The group itself terminates once it contains no more streams.
+///
+/// # Examples
+///
+/// ```
+/// # futures::executor::block_on(async {
+/// use futures::stream::{self, StreamExt, StreamGroup, StreamGroupEvent};
+///
+/// let mut group = StreamGroup::new();
+/// group.insert("a", stream::iter(vec![1]));
+/// group.insert("b", stream::iter(vec![2]));
+/// assert!(group.contains_key(&"b"));
+///
+/// let mut events = group.collect::<Vec<_>>().await;
+/// events.sort_by_key(|event| match event {
+///     StreamGroupEvent::Item(key, _) => (*key, 0),
+///     StreamGroupEvent::Terminated(key) => (*key, 1),
+/// });
+/// assert_eq!(
+///     events,
+///     vec![
+///         StreamGroupEvent::Item("a", 1),
+///         StreamGroupEvent::Terminated("a"),
+///         StreamGroupEvent::Item("b", 2),
+///         StreamGroupEvent::Terminated("b"),
+///     ]
+/// );
+/// # });
+/// ```
+#[must_use = "streams do nothing unless polled"]
+pub struct StreamGroup<K, S> {
+    inner: FuturesUnordered<Keyed<K, S>>,
+    len: usize,
+}
+
+/// A stream in the group, waiting for its next item.
+///
+/// Removing a stream from the group leaves its key and stream empty; the
+/// stored waker is used to get the entry polled one final time so that
+/// `FuturesUnordered` releases it.
+struct Keyed<K, S> {
+    key: Option<K>,
+    stream: Option<S>,
+    waker: Option<Waker>,
+}
+
+// The key and stream are never pinned.
+impl<K, S> Unpin for Keyed<K, S> {}
+
+impl<K, S: Stream + Unpin> Future for Keyed<K, S> {
+    type Output = Option<(K, S, Option<S::Item>)>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+        let stream = match this.stream.as_mut() {
+            Some(stream) => stream,
+            None => return Poll::Ready(None),
+        };
+        match stream.poll_next_unpin(cx) {
+            Poll::Ready(item) => {
+                let key = this.key.take().unwrap();
+                let stream = this.stream.take().unwrap();
+                Poll::Ready(Some((key, stream, item)))
+            }
+            Poll::Pending => {
+                if !this.waker.as_ref().map_or(false, |w| w.will_wake(cx.waker())) {
+                    this.waker = Some(cx.waker().clone());
+                }
+                Poll::Pending
+            }
+        }
+    }
+}
+
+impl<K: Debug, S> Debug for StreamGroup<K, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StreamGroup").field("len", &self.len).finish()
+    }
+}
+
+impl<K: Clone + Eq, S: Stream + Unpin> StreamGroup<K, S> {
+    /// Constructs a new, empty `StreamGroup`.
+    ///
+    /// The returned `StreamGroup` does not contain any streams and, in this
+    /// state, `StreamGroup::poll_next` will return `Poll::Ready(None)`.
+    pub fn new() -> Self {
+        Self { inner: FuturesUnordered::new(), len: 0 }
+    }
+
+    /// Returns the number of streams contained in the group.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the group contains no streams.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns `true` if a stream is registered under `key`.
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.inner.iter().any(|keyed| keyed.key.as_ref() == Some(key))
+    }
+
+    /// Inserts a stream into the group under `key`.
+    ///
+    /// If a stream was already registered under `key`, it is removed and
+    /// returned without producing a [`StreamGroupEvent::Terminated`] event.
+    ///
+    /// This function will not call `poll` on the inserted stream. The caller
+    /// must ensure that `StreamGroup::poll_next` is called in order to
+    /// receive task notifications.
+    pub fn insert(&mut self, key: K, stream: S) -> Option<S> {
+        let previous = self.remove(&key);
+        self.push(key, stream);
+        self.len += 1;
+        previous
+    }
+
+    /// Removes the stream registered under `key` from the group and returns
+    /// it, if there was one.
+    ///
+    /// No [`StreamGroupEvent::Terminated`] event is produced for a stream
+    /// that is removed this way.
+    pub fn remove(&mut self, key: &K) -> Option<S> {
+        let keyed = self.inner.iter_mut().find(|keyed| keyed.key.as_ref() == Some(key))?;
+        keyed.key = None;
+        let stream = keyed.stream.take();
+        if let Some(waker) = keyed.waker.take() {
+            waker.wake();
+        }
+        self.len -= 1;
+        stream
+    }
+
+    /// Clears the group, removing all streams.
+    pub fn clear(&mut self) {
+        self.inner.clear();
+        self.len = 0;
+    }
+
+    fn push(&self, key: K, stream: S) {
+        self.inner.push(Keyed { key: Some(key), stream: Some(stream), waker: None });
+    }
+}
+
+impl<K: Clone + Eq, S: Stream + Unpin> Default for StreamGroup<K, S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Clone + Eq, S: Stream + Unpin> Stream for StreamGroup<K, S> {
+    type Item = StreamGroupEvent<K, S::Item>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            match ready!(self.inner.poll_next_unpin(cx)) {
+                Some(Some((key, stream, Some(item)))) => {
+                    self.push(key.clone(), stream);
+                    return Poll::Ready(Some(StreamGroupEvent::Item(key, item)));
+                }
+                Some(Some((key, _, None))) => {
+                    self.len -= 1;
+                    return Poll::Ready(Some(StreamGroupEvent::Terminated(key)));
+                }
+                Some(None) => {
+                    // A stream that was removed from the group; poll
+                    // `FuturesUnordered` again in the next loop iteration.
+                }
+                None => return Poll::Ready(None),
+            }
+        }
+    }
+}
+
+impl<K: Clone + Eq, S: Stream + Unpin> FusedStream for StreamGroup<K, S> {
+    fn is_terminated(&self) -> bool {
+        self.inner.is_terminated()
+    }
+}
+
+impl<K: Clone + Eq, S: Stream + Unpin> FromIterator<(K, S)> for StreamGroup<K, S> {
+    fn from_iter<T: IntoIterator<Item = (K, S)>>(iter: T) -> Self {
+        let mut group = Self::new();
+        group.extend(iter);
+        assert_stream::<StreamGroupEvent<K, S::Item>, _>(group)
+    }
+}
+
+impl<K: Clone + Eq, S: Stream + Unpin> Extend<(K, S)> for StreamGroup<K, S> {
+    fn extend<T: IntoIterator<Item = (K, S)>>(&mut self, iter: T) {
+        for (key, stream) in iter {
+            self.insert(key, stream);
+        }
+    }
+}
@@ -69,6 +69,14 @@ mod or_else;
 #[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
 pub use self::or_else::OrElse;
 
+mod or_else_stream;
+#[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
+pub use self::or_else_stream::OrElseStream;
+
+mod try_start_with;
+#[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
+pub use self::try_start_with::TryStartWith;
+
 mod try_next;
 #[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
 pub use self::try_next::TryNext;
@@ -102,6 +110,10 @@ mod try_collect;
 #[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
 pub use self::try_collect::TryCollect;
 
+mod try_collect_into;
+#[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
+pub use self::try_collect_into::TryCollectInto;
+
 mod try_concat;
 #[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
 pub use self::try_concat::TryConcat;
@@ -301,6 +313,87 @@ pub trait TryStreamExt: TryStream {
         assert_stream::<Result<Self::Ok, Fut::Error>, _>(OrElse::new(self, f))
     }
 
+    /// Switches to a fallback stream whenever this stream yields an error.
+    ///
+    /// When an error is received, it is passed to `f`, which returns a future
+    /// resolving to the stream to continue with, for example by reconnecting
+    /// and resubscribing. The error itself is not yielded. Since the fallback
+    /// has the same type as this stream, it can fail and be replaced in turn.
+    ///
+    /// `max_switches` bounds how many times the stream may be replaced. It is
+    /// of type `Into<Option<usize>>`, so it can be given as `None` for no
+    /// bound, or as a number. Once the bound is reached, further errors are
+    /// yielded as they are and the current stream keeps being polled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::future;
+    /// use futures::stream::{self, BoxStream, StreamExt, TryStreamExt};
+    ///
+    /// fn connect(attempt: u32) -> BoxStream<'static, Result<u32, u32>> {
+    ///     stream::iter(vec![Ok(attempt), Err(attempt)]).boxed()
+    /// }
+    ///
+    /// let stream = connect(0).or_else_stream(2, |e| future::ready(connect(e + 1)));
+    /// assert_eq!(
+    ///     stream.collect::<Vec<_>>().await,
+    ///     vec![Ok(0), Ok(1), Ok(2), Err(2)],
+    /// );
+    /// # })
+    /// ```
+    fn or_else_stream<Fut, F>(
+        self,
+        max_switches: impl Into<Option<usize>>,
+        f: F,
+    ) -> OrElseStream<Self, Fut, F>
+    where
+        F: FnMut(Self::Error) -> Fut,
+        Fut: Future<Output = Self>,
+        Self: Sized,
+    {
+        assert_stream::<Result<Self::Ok, Self::Error>, _>(OrElseStream::new(
+            self,
+            max_switches.into(),
+            f,
+        ))
+    }
+
+    /// Creates a stream which yields the items of `items` as successful
+    /// values before the items of this stream.
+    ///
+    /// This is the fallible counterpart of
+    /// [`StreamExt::start_with`](crate::stream::StreamExt::start_with): the
+    /// prepended items are wrapped in `Ok`, so the error type of this stream
+    /// does not need to be spelled out.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, TryStreamExt};
+    ///
+    /// let stream = stream::iter(vec![Ok(3), Err("oops")]);
+    /// let mut stream = stream.try_start_with(vec![1, 2]);
+    ///
+    /// assert_eq!(stream.try_next().await, Ok(Some(1)));
+    /// assert_eq!(stream.try_next().await, Ok(Some(2)));
+    /// assert_eq!(stream.try_next().await, Ok(Some(3)));
+    /// assert_eq!(stream.try_next().await, Err("oops"));
+    /// # });
+    /// ```
+    fn try_start_with<I>(self, items: I) -> TryStartWith<Self, I::IntoIter>
+    where
+        I: IntoIterator<Item = Self::Ok>,
+        Self: Sized,
+    {
+        assert_stream::<Result<Self::Ok, Self::Error>, _>(TryStartWith::new(
+            self,
+            items.into_iter(),
+        ))
+    }
+
     /// A future that completes after the given stream has been fully processed
     /// into the sink and the sink has been flushed and closed.
     ///
@@ -501,6 +594,40 @@ pub trait TryStreamExt: TryStream {
         assert_future::<Result<C, Self::Error>, _>(TryCollect::new(self))
     }
 
+    /// Attempt to transform a stream into a collection, extending the
+    /// provided collection rather than creating a new one.
+    ///
+    /// The returned future resolves to a mutable reference to the collection
+    /// once the stream terminates. If an error happens then the error will be
+    /// returned, and any successful values which were received before it are
+    /// left in the collection.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, TryStreamExt};
+    ///
+    /// let mut buf = Vec::new();
+    ///
+    /// let stream = stream::iter(vec![Ok::<i32, i32>(1), Ok(2)]);
+    /// assert_eq!(stream.try_collect_into(&mut buf).await, Ok(&mut vec![1, 2]));
+    ///
+    /// let stream = stream::iter(vec![Ok(3), Err(4), Ok(5)]);
+    /// assert_eq!(stream.try_collect_into(&mut buf).await, Err(4));
+    /// assert_eq!(buf, vec![1, 2, 3]);
+    /// # })
+    /// ```
+    fn try_collect_into<C: Extend<Self::Ok>>(
+        self,
+        collection: &mut C,
+    ) -> TryCollectInto<'_, Self, C>
+    where
+        Self: Sized,
+    {
+        assert_future::<Result<&mut C, Self::Error>, _>(TryCollectInto::new(self, collection))
+    }
+
     /// An adaptor for chunking up successful items of the stream inside a vector.
     ///
     /// This combinator will attempt to pull successful items from this stream and buffer
@@ -0,0 +1,120 @@
+use core::fmt;
+use core::pin::Pin;
+use futures_core::future::Future;
+use futures_core::ready;
+use futures_core::stream::{FusedStream, Stream, TryStream};
+use futures_core::task::{Context, Poll};
+#[cfg(feature = "sink")]
+use futures_sink::Sink;
+use pin_project_lite::pin_project;
+
+pin_project! {
+    /// Stream for the [`or_else_stream`](super::TryStreamExt::or_else_stream) method.
+    #[must_use = "streams do nothing unless polled"]
+    pub struct OrElseStream<St, Fut, F> {
+        #[pin]
+        stream: St,
+        #[pin]
+        future: Option<Fut>,
+        f: F,
+        remaining: Option<usize>,
+    }
+}
+
+impl<St, Fut, F> fmt::Debug for OrElseStream<St, Fut, F>
+where
+    St: fmt::Debug,
+    Fut: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OrElseStream")
+            .field("stream", &self.stream)
+            .field("future", &self.future)
+            .field("remaining", &self.remaining)
+            .finish()
+    }
+}
+
+impl<St, Fut, F> OrElseStream<St, Fut, F>
+where
+    St: TryStream,
+    F: FnMut(St::Error) -> Fut,
+    Fut: Future<Output = St>,
+{
+    pub(super) fn new(stream: St, max_switches: Option<usize>, f: F) -> Self {
+        Self { stream, future: None, f, remaining: max_switches }
+    }
+
+    /// Returns how many more times the stream may be switched, or `None` if
+    /// the number of switches is unbounded.
+    pub fn remaining_switches(&self) -> Option<usize> {
+        self.remaining
+    }
+
+    delegate_access_inner!(stream, St, ());
+}
+
+impl<St, Fut, F> Stream for OrElseStream<St, Fut, F>
+where
+    St: TryStream,
+    F: FnMut(St::Error) -> Fut,
+    Fut: Future<Output = St>,
+{
+    type Item = Result<St::Ok, St::Error>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        Poll::Ready(loop {
+            if let Some(fut) = this.future.as_mut().as_pin_mut() {
+                let stream = ready!(fut.poll(cx));
+                this.future.set(None);
+                this.stream.set(stream);
+            }
+
+            match ready!(this.stream.as_mut().try_poll_next(cx)) {
+                Some(Ok(item)) => break Some(Ok(item)),
+                Some(Err(e)) => {
+                    match this.remaining {
+                        Some(0) => break Some(Err(e)),
+                        Some(remaining) => *remaining -= 1,
+                        None => {}
+                    }
+                    this.future.set(Some((this.f)(e)));
+                }
+                None => break None,
+            }
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.future.is_some() {
+            (0, None)
+        } else {
+            let (lower, _) = self.stream.size_hint();
+            (lower, None)
+        }
+    }
+}
+
+impl<St, Fut, F> FusedStream for OrElseStream<St, Fut, F>
+where
+    St: TryStream + FusedStream,
+    F: FnMut(St::Error) -> Fut,
+    Fut: Future<Output = St>,
+{
+    fn is_terminated(&self) -> bool {
+        self.future.is_none() && self.stream.is_terminated()
+    }
+}
+
+// Forwarding impl of Sink from the underlying stream
+#[cfg(feature = "sink")]
+impl<S, Fut, F, Item> Sink<Item> for OrElseStream<S, Fut, F>
+where
+    S: Sink<Item>,
+{
+    type Error = S::Error;
+
+    delegate_sink!(stream, Item);
+}
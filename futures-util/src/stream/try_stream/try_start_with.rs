@@ -0,0 +1,85 @@
+use core::pin::Pin;
+use futures_core::stream::{FusedStream, Stream, TryStream};
+use futures_core::task::{Context, Poll};
+#[cfg(feature = "sink")]
+use futures_sink::Sink;
+use pin_project_lite::pin_project;
+
+pin_project! {
+    /// Stream for the [`try_start_with`](super::TryStreamExt::try_start_with) method.
+    #[derive(Debug)]
+    #[must_use = "streams do nothing unless polled"]
+    pub struct TryStartWith<St, I> {
+        iter: Option<I>,
+        #[pin]
+        stream: St,
+    }
+}
+
+impl<St, I> TryStartWith<St, I>
+where
+    St: TryStream,
+    I: Iterator<Item = St::Ok>,
+{
+    pub(super) fn new(stream: St, iter: I) -> Self {
+        Self { iter: Some(iter), stream }
+    }
+
+    delegate_access_inner!(stream, St, ());
+}
+
+impl<St, I> FusedStream for TryStartWith<St, I>
+where
+    St: TryStream + FusedStream,
+    I: Iterator<Item = St::Ok>,
+{
+    fn is_terminated(&self) -> bool {
+        self.iter.is_none() && self.stream.is_terminated()
+    }
+}
+
+impl<St, I> Stream for TryStartWith<St, I>
+where
+    St: TryStream,
+    I: Iterator<Item = St::Ok>,
+{
+    type Item = Result<St::Ok, St::Error>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        if let Some(iter) = this.iter {
+            if let Some(item) = iter.next() {
+                return Poll::Ready(Some(Ok(item)));
+            }
+            *this.iter = None;
+        }
+        this.stream.try_poll_next(cx)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (stream_lower, stream_upper) = self.stream.size_hint();
+        match &self.iter {
+            Some(iter) => {
+                let (iter_lower, iter_upper) = iter.size_hint();
+                let lower = stream_lower.saturating_add(iter_lower);
+                let upper = match (stream_upper, iter_upper) {
+                    (Some(x), Some(y)) => x.checked_add(y),
+                    _ => None,
+                };
+                (lower, upper)
+            }
+            None => (stream_lower, stream_upper),
+        }
+    }
+}
+
+// Forwarding impl of Sink from the underlying stream
+#[cfg(feature = "sink")]
+impl<S, I, Item> Sink<Item> for TryStartWith<S, I>
+where
+    S: Sink<Item>,
+{
+    type Error = S::Error;
+
+    delegate_sink!(stream, Item);
+}
@@ -0,0 +1,150 @@
+use super::assert_stream;
+use crate::unfold_state::UnfoldState;
+use core::fmt;
+use core::pin::Pin;
+use futures_core::future::Future;
+use futures_core::ready;
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Context, Poll};
+use pin_project_lite::pin_project;
+
+/// The result of a single step of an [`unfold_final`] stream.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnfoldStep<Item, T, R> {
+    /// Yield `Item` from the stream, and use `T` as the next internal state.
+    Next(Item, T),
+    /// End the stream, storing `R` as its final value.
+    Done(R),
+}
+
+/// Creates a `Stream` from a seed and a closure returning a `Future`, where
+/// the closure can end the stream with a final value.
+///
+/// This function works like [`unfold`](super::unfold()), except that the
+/// future returned by the closure resolves to an [`UnfoldStep`]. When it
+/// resolves to `UnfoldStep::Next(a, b)`, the stream yields `a` and uses `b`
+/// as the next internal state. When it resolves to `UnfoldStep::Done(r)`, the
+/// stream terminates and `r` is kept, so it can be retrieved afterwards with
+/// [`UnfoldFinal::final_value`] or [`UnfoldFinal::take_final_value`].
+///
+/// # Example
+///
+/// ```
+/// # futures::executor::block_on(async {
+/// use futures::pin_mut;
+/// use futures::stream::{self, StreamExt, UnfoldStep};
+///
+/// let stream = stream::unfold_final((0, 0), |(n, sum)| async move {
+///     if n < 3 {
+///         UnfoldStep::Next(n * 2, (n + 1, sum + n * 2))
+///     } else {
+///         UnfoldStep::Done(sum)
+///     }
+/// });
+/// pin_mut!(stream);
+///
+/// let result = stream.as_mut().collect::<Vec<i32>>().await;
+/// assert_eq!(result, vec![0, 2, 4]);
+/// assert_eq!(stream.final_value(), Some(&6));
+/// assert_eq!(stream.take_final_value(), Some(6));
+/// # });
+/// ```
+pub fn unfold_final<T, F, Fut, Item, R>(init: T, f: F) -> UnfoldFinal<T, F, Fut, R>
+where
+    F: FnMut(T) -> Fut,
+    Fut: Future<Output = UnfoldStep<Item, T, R>>,
+{
+    assert_stream::<Item, _>(UnfoldFinal {
+        f,
+        state: UnfoldState::Value { value: init },
+        final_value: None,
+    })
+}
+
+pin_project! {
+    /// Stream for the [`unfold_final`] function.
+    #[must_use = "streams do nothing unless polled"]
+    pub struct UnfoldFinal<T, F, Fut, R> {
+        f: F,
+        #[pin]
+        state: UnfoldState<T, Fut>,
+        final_value: Option<R>,
+    }
+}
+
+impl<T, F, Fut, R> UnfoldFinal<T, F, Fut, R> {
+    /// Returns a reference to the final value of the stream, if it has
+    /// terminated and the value has not been taken yet.
+    pub fn final_value(&self) -> Option<&R> {
+        self.final_value.as_ref()
+    }
+
+    /// Takes the final value out of the stream, if it has terminated and the
+    /// value has not been taken yet.
+    pub fn take_final_value(self: Pin<&mut Self>) -> Option<R> {
+        self.project().final_value.take()
+    }
+
+    /// Consumes this stream, returning its final value if it has terminated
+    /// and the value has not been taken yet.
+    pub fn into_final_value(self) -> Option<R> {
+        self.final_value
+    }
+}
+
+impl<T, F, Fut, R> fmt::Debug for UnfoldFinal<T, F, Fut, R>
+where
+    T: fmt::Debug,
+    Fut: fmt::Debug,
+    R: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UnfoldFinal")
+            .field("state", &self.state)
+            .field("final_value", &self.final_value)
+            .finish()
+    }
+}
+
+impl<T, F, Fut, Item, R> FusedStream for UnfoldFinal<T, F, Fut, R>
+where
+    F: FnMut(T) -> Fut,
+    Fut: Future<Output = UnfoldStep<Item, T, R>>,
+{
+    fn is_terminated(&self) -> bool {
+        self.state.is_empty()
+    }
+}
+
+impl<T, F, Fut, Item, R> Stream for UnfoldFinal<T, F, Fut, R>
+where
+    F: FnMut(T) -> Fut,
+    Fut: Future<Output = UnfoldStep<Item, T, R>>,
+{
+    type Item = Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        if let Some(state) = this.state.as_mut().take_value() {
+            this.state.set(UnfoldState::Future { future: (this.f)(state) });
+        }
+
+        let step = match this.state.as_mut().project_future() {
+            Some(fut) => ready!(fut.poll(cx)),
+            None => panic!("UnfoldFinal must not be polled after it returned `Poll::Ready(None)`"),
+        };
+
+        match step {
+            UnfoldStep::Next(item, next_state) => {
+                this.state.set(UnfoldState::Value { value: next_state });
+                Poll::Ready(Some(item))
+            }
+            UnfoldStep::Done(final_value) => {
+                this.state.set(UnfoldState::Empty);
+                *this.final_value = Some(final_value);
+                Poll::Ready(None)
+            }
+        }
+    }
+}
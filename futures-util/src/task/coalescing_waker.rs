@@ -0,0 +1,99 @@
+use super::{waker_ref, ArcWake, AtomicWaker, WakerRef};
+use alloc::sync::Arc;
+use core::fmt;
+use core::sync::atomic::{AtomicBool, Ordering};
+use futures_core::task::Waker;
+
+/// A waker for registering one task with many sources, which wakes the task
+/// only once between two of its polls.
+///
+/// A combinator waiting on many sources, such as many futures, typically
+/// hands them all the waker of its task. If many of them become ready
+/// together, the task is then woken once for each of them, although a single
+/// poll handles them all. Handing them the [`waker`](CoalescingWaker::waker)
+/// of a `CoalescingWaker` instead only wakes the task for the first of them,
+/// until the task calls [`take_woken`](CoalescingWaker::take_woken) when it is
+/// polled again.
+///
+/// # Examples
+///
+/// ```
+/// use futures::task::{noop_waker_ref, CoalescingWaker};
+///
+/// let coalescing = CoalescingWaker::new();
+///
+/// // At the start of each poll:
+/// coalescing.register(noop_waker_ref());
+/// assert!(!coalescing.take_woken());
+///
+/// // The sources all wake the task, which is only woken once.
+/// let waker = coalescing.waker();
+/// waker.wake_by_ref();
+/// waker.wake_by_ref();
+/// assert!(coalescing.take_woken());
+/// ```
+pub struct CoalescingWaker {
+    inner: Arc<Inner>,
+}
+
+struct Inner {
+    // Set when the task was woken since the last call to `take_woken`.
+    woken: AtomicBool,
+    task: AtomicWaker,
+}
+
+impl ArcWake for Inner {
+    fn wake_by_ref(arc_self: &Arc<Self>) {
+        if !arc_self.woken.swap(true, Ordering::AcqRel) {
+            arc_self.task.wake();
+        }
+    }
+}
+
+impl CoalescingWaker {
+    /// Creates a new `CoalescingWaker`, with no task registered.
+    pub fn new() -> Self {
+        Self { inner: Arc::new(Inner { woken: AtomicBool::new(false), task: AtomicWaker::new() }) }
+    }
+
+    /// Registers the waker of the task to wake.
+    ///
+    /// As with [`AtomicWaker::register`], this must be called each time the
+    /// task is polled, before [`take_woken`](CoalescingWaker::take_woken).
+    pub fn register(&self, waker: &Waker) {
+        self.inner.task.register(waker);
+    }
+
+    /// Returns whether the task was woken since the last call to this method,
+    /// allowing the next wake to reach the task again.
+    pub fn take_woken(&self) -> bool {
+        self.inner.woken.swap(false, Ordering::AcqRel)
+    }
+
+    /// Returns a waker to hand to the sources, which wakes the registered task
+    /// if it was not woken since the last call to
+    /// [`take_woken`](CoalescingWaker::take_woken).
+    pub fn waker(&self) -> Waker {
+        super::waker(self.inner.clone())
+    }
+
+    /// Returns a reference to the waker returned by
+    /// [`waker`](CoalescingWaker::waker), without cloning it.
+    pub fn waker_ref(&self) -> WakerRef<'_> {
+        waker_ref(&self.inner)
+    }
+}
+
+impl Default for CoalescingWaker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for CoalescingWaker {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CoalescingWaker")
+            .field("woken", &self.inner.woken.load(Ordering::Relaxed))
+            .finish()
+    }
+}
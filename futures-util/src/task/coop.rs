@@ -0,0 +1,153 @@
+//! Cooperative scheduling budget.
+//!
+//! A task polling many futures or streams that are all ready, such as a loop
+//! over a channel that is never empty, can keep the thread of its executor
+//! busy for a long time, starving the other tasks. Wrapping the future of the
+//! task with [`budget`] gives it a budget of operations for each time it is
+//! polled. [`consume_budget`] and the combinators that may loop over many
+//! ready futures, such as `FuturesUnordered`, `for_each_concurrent` and
+//! `forward`, spend it, and yield to the executor once it is exhausted.
+//!
+//! Outside of a future wrapped with `budget`, the budget is unlimited, so that
+//! this has no effect unless opted into.
+//!
+//! This module is only available when the `std` feature of this
+//! library is activated, and it is activated by default.
+
+use core::pin::Pin;
+use futures_core::future::{FusedFuture, Future};
+use futures_core::task::{Context, Poll};
+use pin_project_lite::pin_project;
+use std::cell::Cell;
+
+// The number of operations a task may do each time it is polled.
+const INITIAL_BUDGET: u32 = 128;
+
+thread_local! {
+    // The budget left to the task being polled, or `None` if it is
+    // unlimited.
+    static BUDGET: Cell<Option<u32>> = Cell::new(None);
+}
+
+// Takes a unit of the budget of the current task, or wakes it up and
+// returns `Pending` if its budget is exhausted.
+pub(crate) fn poll_proceed(cx: &mut Context<'_>) -> Poll<()> {
+    BUDGET.with(|budget| match budget.get() {
+        None => Poll::Ready(()),
+        Some(0) => {
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }
+        Some(n) => {
+            budget.set(Some(n - 1));
+            Poll::Ready(())
+        }
+    })
+}
+
+pin_project! {
+    /// Future for the [`budget`] function.
+    #[derive(Debug)]
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct Budget<Fut> {
+        #[pin]
+        future: Fut,
+    }
+}
+
+/// Gives `future` a fresh budget each time it is polled.
+///
+/// This is meant to wrap the future of a whole task. Within another future
+/// wrapped with `budget`, it spends the budget of that future instead.
+///
+/// # Examples
+///
+/// ```
+/// # futures::executor::block_on(async {
+/// use futures::stream::{self, StreamExt};
+/// use futures::task::coop;
+///
+/// // Yields to the executor every so often, although the stream is
+/// // always ready.
+/// let task = stream::repeat(1).take(1000).for_each_concurrent(None, |_| async {});
+/// coop::budget(task).await;
+/// # });
+/// ```
+pub fn budget<Fut: Future>(future: Fut) -> Budget<Fut> {
+    Budget { future }
+}
+
+impl<Fut: Future> Future for Budget<Fut> {
+    type Output = Fut::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        // Takes the budget away again, even if the future panics.
+        struct Reset;
+
+        impl Drop for Reset {
+            fn drop(&mut self) {
+                BUDGET.with(|budget| budget.set(None));
+            }
+        }
+
+        let _reset = BUDGET.with(|budget| {
+            if budget.get().is_none() {
+                budget.set(Some(INITIAL_BUDGET));
+                Some(Reset)
+            } else {
+                None
+            }
+        });
+        self.project().future.poll(cx)
+    }
+}
+
+impl<Fut: FusedFuture> FusedFuture for Budget<Fut> {
+    fn is_terminated(&self) -> bool {
+        self.future.is_terminated()
+    }
+}
+
+/// Future for the [`consume_budget`] function.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct ConsumeBudget {
+    _priv: (),
+}
+
+/// Spends a unit of the budget of the current task, first yielding to the
+/// executor if it is exhausted.
+///
+/// Adding this to a loop that may run for a long time without waiting lets
+/// the other tasks of the executor run in between. Outside of a
+/// future wrapped with [`budget`], this completes right away.
+///
+/// # Examples
+///
+/// ```
+/// # futures::executor::block_on(async {
+/// use futures::task::coop;
+///
+/// let sum = coop::budget(async {
+///     let mut sum = 0u64;
+///     for i in 0..1000 {
+///         coop::consume_budget().await;
+///         sum += i;
+///     }
+///     sum
+/// })
+/// .await;
+/// assert_eq!(sum, 499500);
+/// # });
+/// ```
+pub fn consume_budget() -> ConsumeBudget {
+    ConsumeBudget { _priv: () }
+}
+
+impl Future for ConsumeBudget {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        poll_proceed(cx)
+    }
+}
@@ -6,6 +6,10 @@
 //! - [`Context`], a context of an asynchronous task,
 //!   including a handle for waking up the task.
 //! - [`Waker`], a handle for waking up a task.
+//! - [`WakerSet`], a set of wakers for synchronization primitives with many
+//!   waiting tasks.
+//! - [`LocalKey`], a key for values local to a task, declared with
+//!   [`task_local!`](crate::task_local).
 //!
 //! The remaining types and traits in the module are used for implementing
 //! executors or dealing with synchronization issues around task wakeup.
@@ -13,25 +17,92 @@
 #[doc(no_inline)]
 pub use core::task::{Context, Poll, RawWaker, RawWakerVTable, Waker};
 
-pub use futures_task::{FutureObj, LocalFutureObj, LocalSpawn, Spawn, SpawnError, UnsafeFutureObj};
+pub use futures_task::{
+    FutureObj, LocalFutureObj, LocalSpawn, LocalTrySpawn, Spawn, SpawnError, TrySpawn,
+    TrySpawnError, UnsafeFutureObj,
+};
 
 pub use futures_task::noop_waker;
 pub use futures_task::noop_waker_ref;
 
 #[cfg(not(futures_no_atomic_cas))]
+#[cfg(not(futures_portable_atomic))]
 #[cfg(feature = "alloc")]
 pub use futures_task::ArcWake;
 
 #[cfg(not(futures_no_atomic_cas))]
+#[cfg(not(futures_portable_atomic))]
 #[cfg(feature = "alloc")]
 pub use futures_task::waker;
 
 #[cfg(not(futures_no_atomic_cas))]
+#[cfg(not(futures_portable_atomic))]
+#[cfg(feature = "alloc")]
+pub use futures_task::waker_fn;
+
+#[cfg(not(futures_no_atomic_cas))]
+#[cfg(not(futures_portable_atomic))]
 #[cfg(feature = "alloc")]
 pub use futures_task::{waker_ref, WakerRef};
 
+#[cfg(not(futures_no_atomic_cas))]
+#[cfg(not(futures_portable_atomic))]
+#[cfg(feature = "alloc")]
+pub use futures_task::{rc_waker, rc_waker_ref, RcWake};
+
+// The `Arc` of `portable-atomic-util` can't be used with the `ArcWake` of
+// `futures-task`, so `FuturesUnordered` uses its own waker on these targets.
+#[cfg(futures_portable_atomic)]
+#[cfg(feature = "alloc")]
+mod portable_arc_wake;
+#[cfg(futures_portable_atomic)]
+#[cfg(feature = "alloc")]
+pub(crate) use self::portable_arc_wake::{waker_ref, ArcWake, WakerRef};
+
 #[cfg(not(futures_no_atomic_cas))]
 pub use futures_core::task::__internal::AtomicWaker;
 
+#[cfg(not(futures_no_atomic_cas))]
+#[cfg(not(futures_portable_atomic))]
+#[cfg(feature = "alloc")]
+mod coalescing_waker;
+#[cfg(not(futures_no_atomic_cas))]
+#[cfg(not(futures_portable_atomic))]
+#[cfg(feature = "alloc")]
+pub use self::coalescing_waker::CoalescingWaker;
+
 mod spawn;
 pub use self::spawn::{LocalSpawnExt, SpawnExt};
+
+#[cfg(feature = "std")]
+pub mod coop;
+#[cfg(not(feature = "std"))]
+pub(crate) mod coop {
+    use futures_core::task::{Context, Poll};
+
+    // Without `std`, there is no budget to honor.
+    pub(crate) fn poll_proceed(_cx: &mut Context<'_>) -> Poll<()> {
+        Poll::Ready(())
+    }
+}
+
+#[cfg(feature = "std")]
+mod waker_set;
+#[cfg(feature = "std")]
+pub use self::waker_set::{WakerKey, WakerSet};
+
+#[cfg(feature = "std")]
+mod task_local;
+#[cfg(feature = "std")]
+pub use self::task_local::{AccessError, LocalKey};
+
+// Builds the context for polling a child future that is woken through
+// `waker`, keeping the extension data of `cx`.
+#[cfg(feature = "context-ext")]
+pub(crate) fn child_context<'a>(cx: &'a mut Context<'_>, waker: &'a Waker) -> Context<'a> {
+    core::task::ContextBuilder::from(cx).waker(waker).build()
+}
+#[cfg(not(feature = "context-ext"))]
+pub(crate) fn child_context<'a>(_cx: &'a mut Context<'_>, waker: &'a Waker) -> Context<'a> {
+    Context::from_waker(waker)
+}
@@ -34,14 +34,15 @@ pub trait SpawnExt: Spawn {
     /// today. Feel free to use this method in the meantime.
     ///
     /// ```
-    /// # if cfg!(miri) { return; } // https://github.com/rust-lang/miri/issues/1038
-    /// use futures::executor::ThreadPool;
+    /// use futures::executor::LocalPool;
     /// use futures::task::SpawnExt;
     ///
-    /// let executor = ThreadPool::new().unwrap();
+    /// let mut executor = LocalPool::new();
+    /// let spawner = executor.spawner();
     ///
     /// let future = async { /* ... */ };
-    /// executor.spawn(future).unwrap();
+    /// spawner.spawn(future).unwrap();
+    /// executor.run();
     /// ```
     #[cfg(feature = "alloc")]
     fn spawn<Fut>(&self, future: Fut) -> Result<(), SpawnError>
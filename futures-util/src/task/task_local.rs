@@ -0,0 +1,148 @@
+use std::cell::RefCell;
+use std::error::Error;
+use std::fmt;
+use std::mem;
+
+/// Declares task-local values, which are attached to a future with
+/// [`FutureExt::with_value`](crate::future::FutureExt::with_value) and can
+/// be read from anywhere beneath it while it is polled.
+///
+/// Each declaration creates a `static` of type [`LocalKey`].
+///
+/// Task-local values don't depend on the executor running the future: the
+/// future returned by `with_value` sets the value on the current thread while
+/// it polls the future it wraps, and takes it back afterwards.
+///
+/// # Examples
+///
+/// ```
+/// use futures::executor::block_on;
+/// use futures::future::FutureExt;
+/// use futures::task_local;
+///
+/// task_local! {
+///     static REQUEST_ID: u32;
+/// }
+///
+/// async fn handle() -> u32 {
+///     REQUEST_ID.with(|id| *id)
+/// }
+///
+/// assert_eq!(block_on(handle().with_value(&REQUEST_ID, 7)), 7);
+/// assert!(REQUEST_ID.try_with(|_| ()).is_err());
+/// ```
+#[macro_export]
+macro_rules! task_local {
+    () => {};
+    ($(#[$attr:meta])* $vis:vis static $name:ident: $t:ty; $($rest:tt)*) => {
+        $crate::__task_local_inner!($(#[$attr])* $vis $name, $t);
+        $crate::task_local!($($rest)*);
+    };
+    ($(#[$attr:meta])* $vis:vis static $name:ident: $t:ty) => {
+        $crate::__task_local_inner!($(#[$attr])* $vis $name, $t);
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __task_local_inner {
+    ($(#[$attr:meta])* $vis:vis $name:ident, $t:ty) => {
+        $(#[$attr])*
+        $vis static $name: $crate::task::LocalKey<$t> = {
+            std::thread_local! {
+                static __KEY: std::cell::RefCell<std::option::Option<$t>> =
+                    std::cell::RefCell::new(std::option::Option::None);
+            }
+            $crate::task::LocalKey { __inner: __KEY }
+        };
+    };
+}
+
+/// A key for a task-local value, declared with [`task_local!`](crate::task_local).
+pub struct LocalKey<T: 'static> {
+    #[doc(hidden)]
+    pub __inner: std::thread::LocalKey<RefCell<Option<T>>>,
+}
+
+/// The error returned by [`LocalKey::try_with`] when the value is not set.
+pub struct AccessError {
+    _priv: (),
+}
+
+impl<T: 'static> LocalKey<T> {
+    /// Calls `f` with a reference to the value of this key, as set by the
+    /// closest enclosing [`with_value`](crate::future::FutureExt::with_value).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is not set, because this is not called from a
+    /// future run with `with_value` for this key.
+    pub fn with<F, R>(&'static self, f: F) -> R
+    where
+        F: FnOnce(&T) -> R,
+    {
+        match self.try_with(f) {
+            Ok(r) => r,
+            Err(_) => panic!("cannot access a task-local value that is not set"),
+        }
+    }
+
+    /// Calls `f` with a reference to the value of this key, or returns an
+    /// error if it is not set.
+    pub fn try_with<F, R>(&'static self, f: F) -> Result<R, AccessError>
+    where
+        F: FnOnce(&T) -> R,
+    {
+        self.__inner.with(|cell| match &*cell.borrow() {
+            Some(value) => Ok(f(value)),
+            None => Err(AccessError { _priv: () }),
+        })
+    }
+
+    // Sets the value of this key to `slot` while `f` runs, leaving the
+    // previous value in `slot` meanwhile.
+    pub(crate) fn scope<F, R>(&'static self, slot: &mut Option<T>, f: F) -> R
+    where
+        F: FnOnce() -> R,
+    {
+        // Swaps the values back, even if `f` panics.
+        struct Reset<'a, T: 'static> {
+            key: &'static LocalKey<T>,
+            slot: &'a mut Option<T>,
+        }
+
+        impl<T: 'static> Drop for Reset<'_, T> {
+            fn drop(&mut self) {
+                self.key.swap(self.slot);
+            }
+        }
+
+        self.swap(slot);
+        let _reset = Reset { key: self, slot };
+        f()
+    }
+
+    fn swap(&'static self, slot: &mut Option<T>) {
+        self.__inner.with(|cell| mem::swap(slot, &mut *cell.borrow_mut()));
+    }
+}
+
+impl<T: 'static> fmt::Debug for LocalKey<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LocalKey").finish()
+    }
+}
+
+impl fmt::Debug for AccessError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AccessError").finish()
+    }
+}
+
+impl fmt::Display for AccessError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("task-local value not set")
+    }
+}
+
+impl Error for AccessError {}
@@ -0,0 +1,192 @@
+use futures_core::task::Waker;
+use slab::Slab;
+use std::fmt;
+use std::mem;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+
+/// A set of wakers of the tasks waiting for something, for building
+/// synchronization primitives with many waiters.
+///
+/// This is the multi-task counterpart of
+/// [`AtomicWaker`](crate::task::AtomicWaker): each waiting future registers
+/// its waker with [`register`](WakerSet::register), keeping the returned
+/// [`WakerKey`], and removes it with [`remove`](WakerSet::remove) once it is
+/// done waiting, or dropped.
+///
+/// A waker woken with [`wake_one`](WakerSet::wake_one) or
+/// [`wake_all`](WakerSet::wake_all) stays in the set, marked as woken, until
+/// it is registered again or removed.
+///
+/// As with `AtomicWaker`, a future must check the condition it waits for
+/// after registering its waker, so that it doesn't miss a wakeup for a
+/// change that happened in between.
+///
+/// # Examples
+///
+/// ```
+/// use futures::task::{noop_waker_ref, WakerKey, WakerSet};
+///
+/// let set = WakerSet::new();
+/// let mut key: Option<WakerKey> = None;
+///
+/// set.register(&mut key, noop_waker_ref());
+/// assert_eq!(set.waiting(), 1);
+///
+/// assert!(set.wake_one());
+/// assert_eq!(set.waiting(), 0);
+///
+/// // The future was woken, and didn't register again.
+/// assert!(set.remove(key.take().unwrap()));
+/// ```
+pub struct WakerSet {
+    entries: Mutex<Slab<Entry>>,
+    // The number of entries that are `Waiting`, to skip locking `entries`
+    // when there is nothing to wake.
+    waiting: AtomicUsize,
+}
+
+/// The key of a waker registered in a [`WakerSet`].
+#[derive(Debug)]
+pub struct WakerKey {
+    key: usize,
+}
+
+enum Entry {
+    Waiting(Waker),
+    Woken,
+}
+
+impl Entry {
+    // Marks the entry as woken, returning the waker to wake if it was
+    // waiting.
+    fn wake(&mut self) -> Option<Waker> {
+        match mem::replace(self, Self::Woken) {
+            Self::Waiting(waker) => Some(waker),
+            Self::Woken => None,
+        }
+    }
+}
+
+impl WakerSet {
+    /// Creates a new, empty set.
+    pub fn new() -> Self {
+        Self { entries: Mutex::new(Slab::new()), waiting: AtomicUsize::new(0) }
+    }
+
+    /// Registers `waker` to be woken, storing its key in `key`, or replaces
+    /// the waker registered under `key` if it is `Some`.
+    ///
+    /// Registering a waker again marks it as waiting, if it was woken.
+    ///
+    /// # Panics
+    ///
+    /// May panic if `key` is the key of a waker of another set.
+    pub fn register(&self, key: &mut Option<WakerKey>, waker: &Waker) {
+        let mut entries = self.entries.lock().unwrap();
+        match key {
+            Some(key) => {
+                let entry = &mut entries[key.key];
+                match entry {
+                    Entry::Waiting(w) => {
+                        if !w.will_wake(waker) {
+                            *w = waker.clone();
+                        }
+                    }
+                    Entry::Woken => {
+                        *entry = Entry::Waiting(waker.clone());
+                        self.waiting.fetch_add(1, Ordering::SeqCst);
+                    }
+                }
+            }
+            None => {
+                *key = Some(WakerKey { key: entries.insert(Entry::Waiting(waker.clone())) });
+                self.waiting.fetch_add(1, Ordering::SeqCst);
+            }
+        }
+    }
+
+    /// Removes the waker registered under `key`.
+    ///
+    /// Returns `true` if it was woken since it was last registered. A future
+    /// that is dropped before it could act on a call to
+    /// [`wake_one`](WakerSet::wake_one) can then call it again, so that the
+    /// wakeup is not lost.
+    ///
+    /// # Panics
+    ///
+    /// May panic if `key` is the key of a waker of another set.
+    pub fn remove(&self, key: WakerKey) -> bool {
+        let mut entries = self.entries.lock().unwrap();
+        match entries.remove(key.key) {
+            Entry::Waiting(_) => {
+                self.waiting.fetch_sub(1, Ordering::SeqCst);
+                false
+            }
+            Entry::Woken => true,
+        }
+    }
+
+    /// Wakes one of the wakers that are waiting, if any.
+    ///
+    /// Returns `true` if a waker was woken.
+    pub fn wake_one(&self) -> bool {
+        if self.waiting.load(Ordering::SeqCst) == 0 {
+            return false;
+        }
+        let waker = {
+            let mut entries = self.entries.lock().unwrap();
+            let waker = entries.iter_mut().find_map(|(_, entry)| entry.wake());
+            if waker.is_some() {
+                self.waiting.fetch_sub(1, Ordering::SeqCst);
+            }
+            waker
+        };
+        // Wake outside of the lock, in case the waker registers again.
+        match waker {
+            Some(waker) => {
+                waker.wake();
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Wakes all the wakers that are waiting.
+    ///
+    /// Returns the number of wakers woken.
+    pub fn wake_all(&self) -> usize {
+        if self.waiting.load(Ordering::SeqCst) == 0 {
+            return 0;
+        }
+        let wakers: Vec<_> = {
+            let mut entries = self.entries.lock().unwrap();
+            let wakers = entries.iter_mut().filter_map(|(_, entry)| entry.wake()).collect();
+            self.waiting.store(0, Ordering::SeqCst);
+            wakers
+        };
+        let woken = wakers.len();
+        for waker in wakers {
+            waker.wake();
+        }
+        woken
+    }
+
+    /// Returns the number of wakers that are waiting, that is which were
+    /// registered and not woken since.
+    pub fn waiting(&self) -> usize {
+        self.waiting.load(Ordering::SeqCst)
+    }
+}
+
+impl Default for WakerSet {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for WakerSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WakerSet").field("waiting", &self.waiting()).finish()
+    }
+}
@@ -0,0 +1,70 @@
+//! Timers.
+//!
+//! This module contains the [`Timer`] trait, which abstracts over the timers
+//! of the different runtimes, so that time-based code can be written once and
+//! tested with a mock clock.
+
+use core::time::Duration;
+use futures_core::future::Future;
+
+/// A source of delay futures, such as the timer of a runtime.
+///
+/// The time-based combinators of this library, e.g.
+/// [`ready_chunks_timeout`](crate::stream::StreamExt::ready_chunks_timeout),
+/// take a closure creating a delay future, which can be built from any timer
+/// as `|| timer.delay(duration)`. Taking a `Timer` in time-based code rather
+/// than calling a runtime directly lets tests drive it with a mock clock, such
+/// as `futures_test::time::MockClock`, instead of waiting for real time to
+/// pass.
+///
+/// # Examples
+///
+/// ```
+/// use futures::executor::block_on;
+/// use futures::future::{self, Either};
+/// use futures::time::Timer;
+/// use futures_test::time::MockClock;
+/// use std::time::Duration;
+///
+/// async fn with_timeout<F: future::Future + Unpin>(
+///     timer: &impl Timer,
+///     future: F,
+/// ) -> Option<F::Output> {
+///     let delay = Box::pin(timer.delay(Duration::from_secs(30)));
+///     match future::select(future, delay).await {
+///         Either::Left((output, _)) => Some(output),
+///         Either::Right(((), _)) => None,
+///     }
+/// }
+///
+/// let clock = MockClock::new();
+/// let timeout = with_timeout(&clock, future::pending::<()>());
+/// let advance = async { clock.advance(Duration::from_secs(30)) };
+/// assert_eq!(block_on(future::join(timeout, advance)), (None, ()));
+/// ```
+pub trait Timer {
+    /// The future returned by [`delay`](Timer::delay).
+    type Delay: Future<Output = ()>;
+
+    /// Returns a future which completes once `duration` has elapsed.
+    fn delay(&self, duration: Duration) -> Self::Delay;
+}
+
+impl<T: Timer + ?Sized> Timer for &T {
+    type Delay = T::Delay;
+
+    fn delay(&self, duration: Duration) -> Self::Delay {
+        (**self).delay(duration)
+    }
+}
+
+#[cfg(not(futures_no_atomic_cas))]
+#[cfg(not(futures_portable_atomic))]
+#[cfg(feature = "alloc")]
+impl<T: Timer + ?Sized> Timer for alloc::sync::Arc<T> {
+    type Delay = T::Delay;
+
+    fn delay(&self, duration: Duration) -> Self::Delay {
+        (**self).delay(duration)
+    }
+}
@@ -123,10 +123,15 @@ pub use futures_util::{AsyncBufReadExt, AsyncReadExt, AsyncSeekExt, AsyncWriteEx
 
 // Macro reexports
 pub use futures_core::ready; // Readiness propagation
+#[cfg(feature = "alloc")]
+#[cfg(feature = "async-await")]
+pub use futures_util::join_try_all;
 pub use futures_util::pin_mut;
 #[cfg(feature = "std")]
 #[cfg(feature = "async-await")]
 pub use futures_util::select;
+#[cfg(feature = "std")]
+pub use futures_util::task_local; // Task-local storage
 #[cfg(feature = "async-await")]
 pub use futures_util::{join, pending, poll, select_biased, try_join}; // Async-await
 
@@ -149,6 +154,9 @@ pub use futures_util::lock;
 #[doc(inline)]
 pub use futures_util::io;
 
+#[doc(inline)]
+pub use futures_util::time;
+
 #[cfg(feature = "executor")]
 #[cfg_attr(docsrs, doc(cfg(feature = "executor")))]
 #[doc(inline)]
@@ -159,6 +167,11 @@ pub use futures_executor as executor;
 #[doc(inline)]
 pub use futures_util::compat;
 
+#[cfg(feature = "instrument")]
+#[cfg_attr(docsrs, doc(cfg(feature = "instrument")))]
+#[doc(inline)]
+pub use futures_util::instrument;
+
 pub mod prelude {
     //! A "prelude" for crates using the `futures` crate.
     //!
@@ -6,8 +6,10 @@
     feature = "io-compat",
     feature = "executor",
     feature = "thread-pool",
+    feature = "instrument",
+    feature = "tokio-compat",
 )))]
 compile_error!(
     "`futures` tests must have all stable features activated: \
-    use `--all-features` or `--features default,thread-pool,io-compat`"
+    use `--all-features` or `--features default,thread-pool,io-compat,instrument,tokio-compat`"
 );
@@ -7,7 +7,8 @@ use futures::sink::SinkExt;
 use futures::stream::StreamExt;
 use futures::task::{Context, Poll};
 use futures::{
-    join, pending, pin_mut, poll, select, select_biased, stream, stream_select, try_join,
+    join, join_try_all, pending, pin_mut, poll, select, select_biased, stream, stream_select,
+    try_join,
 };
 use std::mem;
 
@@ -275,6 +276,76 @@ fn select_with_complete_can_be_used_as_expression() {
     });
 }
 
+#[test]
+fn select_prefers_higher_priority() {
+    block_on(async {
+        for _ in 0..10 {
+            let res = select! {
+                x = future::ready(1) => x,
+                #[priority(1)]
+                y = future::ready(2) => y,
+                z = future::ready(3) => z,
+            };
+            assert_eq!(res, 2);
+        }
+    });
+}
+
+#[test]
+fn select_biased_orders_equal_priorities_by_declaration() {
+    block_on(async {
+        let res = select_biased! {
+            x = future::ready(1) => x,
+            #[priority(2)]
+            y = future::pending::<i32>() => y,
+            #[priority(1)]
+            z = future::ready(3) => z,
+            #[priority(1)]
+            w = future::ready(4) => w,
+        };
+        assert_eq!(res, 3);
+    });
+}
+
+#[test]
+fn select_skips_disabled_branches() {
+    block_on(async {
+        let mut a = future::ready(1);
+        let enabled = false;
+        let res = select_biased! {
+            x = a, if enabled => x,
+            y = future::ready(2), if enabled => y,
+            z = future::ready(3), if !enabled => z,
+        };
+        assert_eq!(res, 3);
+
+        let res = select! {
+            x = a, if enabled => x,
+            complete => 0,
+        };
+        assert_eq!(res, 0);
+    });
+}
+
+#[test]
+fn select_on_optional_future() {
+    block_on(async {
+        let mut fut = None;
+        let res = select! {
+            x = fut.as_mut().unwrap(), if fut.is_some() => x,
+            complete => 0,
+        };
+        assert_eq!(res, 0);
+
+        fut = Some(future::ready(5));
+        let res = select! {
+            x = fut.as_mut().unwrap(), if fut.is_some() => x,
+            complete => 0,
+        };
+        assert_eq!(res, 5);
+    });
+}
+
 #[test]
 #[allow(unused_assignments)]
 fn select_on_mutable_borrowing_future_with_same_borrow_in_block() {
@@ -348,6 +419,45 @@ fn stream_select() {
     });
 }
 
+#[test]
+fn stream_select_with_dynamic_streams() {
+    block_on(async {
+        let endless_ints = |i| stream::iter(vec![i].into_iter().cycle());
+
+        let mut dynamic_only = stream_select!(stream::pending(), ..stream::SelectAll::new());
+        dynamic_only.dynamic_mut().push(endless_ints(1i32));
+        assert_eq!(dynamic_only.next().await, Some(1));
+
+        let mut finite_list = stream_select!(
+            stream::iter(vec![1].into_iter()),
+            ..stream::select_all(vec![stream::iter(vec![2].into_iter())])
+        );
+        let mut items = finite_list.by_ref().collect::<Vec<_>>().await;
+        items.sort_unstable();
+        assert_eq!(items, vec![1, 2]);
+        assert_eq!(finite_list.next().await, None);
+
+        let endless_mixed = stream_select!(
+            endless_ints(1i32),
+            ..stream::select_all(vec![endless_ints(2), endless_ints(3)])
+        );
+        // Take 1000, and assert a somewhat even distribution of values, the
+        // dynamic streams being as likely to be selected as the static ones.
+        let mut count = 0;
+        let results = endless_mixed
+            .take_while(move |_| {
+                count += 1;
+                let ret = count < 1000;
+                async move { ret }
+            })
+            .collect::<Vec<_>>()
+            .await;
+        assert!(results.iter().filter(|x| **x == 1).count() >= 250);
+        assert!(results.iter().filter(|x| **x == 2).count() >= 250);
+        assert!(results.iter().filter(|x| **x == 3).count() >= 250);
+    });
+}
+
 #[test]
 fn join_size() {
     let fut = async {
@@ -380,6 +490,31 @@ fn try_join_size() {
     assert_eq!(mem::size_of_val(&fut), 28);
 }
 
+#[test]
+fn join_try_all_collects_every_error() {
+    let (tx, rx) = oneshot::channel::<i32>();
+    block_on(async {
+        let res = join_try_all!(
+            async { Err::<i32, i32>(1) },
+            async { Ok::<(), i32>(()) },
+            async { Err::<(), i32>(rx.await.unwrap()) },
+            async {
+                tx.send(3).unwrap();
+                Ok::<u8, i32>(4)
+            },
+        );
+        assert_eq!(res, Err(vec![1, 3]));
+    });
+}
+
+#[test]
+fn join_try_all_succeeds() {
+    block_on(async {
+        let res = join_try_all!(async { Ok::<i32, ()>(1) }, async { Ok::<&str, ()>("2") });
+        assert_eq!(res, Ok((1, "2")));
+    });
+}
+
 #[test]
 fn join_doesnt_require_unpin() {
     let _ = async { join!(async {}, async {}) };
@@ -389,3 +524,8 @@ fn join_doesnt_require_unpin() {
 fn try_join_doesnt_require_unpin() {
     let _ = async { try_join!(async { Ok::<(), ()>(()) }, async { Ok::<(), ()>(()) },) };
 }
+
+#[test]
+fn join_try_all_doesnt_require_unpin() {
+    let _ = async { join_try_all!(async { Ok::<(), ()>(()) }, async { Ok::<(), ()>(()) },) };
+}
@@ -72,16 +72,125 @@ pub mod channel {
     use super::*;
     use futures::channel::*;
 
+    assert_impl!(broadcast::LagPolicy: Send);
+    assert_impl!(broadcast::LagPolicy: Sync);
+    assert_impl!(broadcast::LagPolicy: Unpin);
+
+    assert_impl!(broadcast::Lagged: Send);
+    assert_impl!(broadcast::Lagged: Sync);
+    assert_impl!(broadcast::Lagged: Unpin);
+
+    assert_impl!(broadcast::Receiver<()>: Send);
+    assert_not_impl!(broadcast::Receiver<*const ()>: Send);
+    assert_impl!(broadcast::Receiver<()>: Sync);
+    assert_not_impl!(broadcast::Receiver<*const ()>: Sync);
+    assert_impl!(broadcast::Receiver<PhantomPinned>: Unpin);
+
+    assert_impl!(broadcast::SendError<()>: Send);
+    assert_not_impl!(broadcast::SendError<*const ()>: Send);
+    assert_impl!(broadcast::SendError<()>: Sync);
+    assert_not_impl!(broadcast::SendError<*const ()>: Sync);
+    assert_impl!(broadcast::SendError<()>: Unpin);
+    assert_not_impl!(broadcast::SendError<PhantomPinned>: Unpin);
+
+    assert_impl!(broadcast::Sender<()>: Send);
+    assert_not_impl!(broadcast::Sender<*const ()>: Send);
+    assert_impl!(broadcast::Sender<()>: Sync);
+    assert_not_impl!(broadcast::Sender<*const ()>: Sync);
+    assert_impl!(broadcast::Sender<PhantomPinned>: Unpin);
+
+    assert_impl!(broadcast::TryRecvError: Send);
+    assert_impl!(broadcast::TryRecvError: Sync);
+    assert_impl!(broadcast::TryRecvError: Unpin);
+
+    assert_impl!(mpmc::Receiver<()>: Send);
+    assert_not_impl!(mpmc::Receiver<*const ()>: Send);
+    assert_impl!(mpmc::Receiver<()>: Sync);
+    assert_not_impl!(mpmc::Receiver<*const ()>: Sync);
+    assert_impl!(mpmc::Receiver<PhantomPinned>: Unpin);
+
+    assert_impl!(mpmc::SendError: Send);
+    assert_impl!(mpmc::SendError: Sync);
+    assert_impl!(mpmc::SendError: Unpin);
+
+    assert_impl!(mpmc::Sender<()>: Send);
+    assert_not_impl!(mpmc::Sender<*const ()>: Send);
+    assert_impl!(mpmc::Sender<()>: Sync);
+    assert_not_impl!(mpmc::Sender<*const ()>: Sync);
+    assert_impl!(mpmc::Sender<PhantomPinned>: Unpin);
+
+    assert_impl!(mpmc::TryRecvError: Send);
+    assert_impl!(mpmc::TryRecvError: Sync);
+    assert_impl!(mpmc::TryRecvError: Unpin);
+
+    assert_impl!(mpmc::TrySendError<()>: Send);
+    assert_not_impl!(mpmc::TrySendError<*const ()>: Send);
+    assert_impl!(mpmc::TrySendError<()>: Sync);
+    assert_not_impl!(mpmc::TrySendError<*const ()>: Sync);
+    assert_impl!(mpmc::TrySendError<()>: Unpin);
+    assert_not_impl!(mpmc::TrySendError<PhantomPinned>: Unpin);
+
+    assert_impl!(mpsc::Closed<'_, ()>: Send);
+    assert_not_impl!(mpsc::Closed<'_, *const ()>: Send);
+    assert_impl!(mpsc::Closed<'_, ()>: Sync);
+    assert_not_impl!(mpsc::Closed<'_, *const ()>: Sync);
+    assert_impl!(mpsc::Closed<'_, PhantomPinned>: Unpin);
+
+    assert_impl!(mpsc::Drain<'_, ()>: Send);
+    assert_not_impl!(mpsc::Drain<'_, *const ()>: Send);
+    assert_impl!(mpsc::Drain<'_, ()>: Sync);
+    assert_not_impl!(mpsc::Drain<'_, *const ()>: Sync);
+    assert_impl!(mpsc::Drain<'_, PhantomPinned>: Unpin);
+
+    assert_impl!(mpsc::Peek<'_, ()>: Send);
+    assert_not_impl!(mpsc::Peek<'_, *const ()>: Send);
+    assert_impl!(mpsc::Peek<'_, ()>: Sync);
+    assert_not_impl!(mpsc::Peek<'_, *const ()>: Sync);
+    assert_impl!(mpsc::Peek<'_, PhantomPinned>: Unpin);
+
+    assert_impl!(mpsc::Permit<'_, ()>: Send);
+    assert_not_impl!(mpsc::Permit<'_, *const ()>: Send);
+    assert_impl!(mpsc::Permit<'_, ()>: Sync);
+    assert_not_impl!(mpsc::Permit<'_, *const ()>: Sync);
+    assert_impl!(mpsc::Permit<'_, PhantomPinned>: Unpin);
+
     assert_impl!(mpsc::Receiver<()>: Send);
     assert_not_impl!(mpsc::Receiver<*const ()>: Send);
     assert_impl!(mpsc::Receiver<()>: Sync);
     assert_not_impl!(mpsc::Receiver<*const ()>: Sync);
     assert_impl!(mpsc::Receiver<PhantomPinned>: Unpin);
 
+    assert_impl!(mpsc::RecvMany<'_, ()>: Send);
+    assert_not_impl!(mpsc::RecvMany<'_, *const ()>: Send);
+    assert_impl!(mpsc::RecvMany<'_, ()>: Sync);
+    assert_not_impl!(mpsc::RecvMany<'_, *const ()>: Sync);
+    assert_impl!(mpsc::RecvMany<'_, PhantomPinned>: Unpin);
+
+    assert_impl!(mpsc::Reserve<'_, ()>: Send);
+    assert_not_impl!(mpsc::Reserve<'_, *const ()>: Send);
+    assert_impl!(mpsc::Reserve<'_, ()>: Sync);
+    assert_not_impl!(mpsc::Reserve<'_, *const ()>: Sync);
+    assert_impl!(mpsc::Reserve<'_, PhantomPinned>: Unpin);
+
     assert_impl!(mpsc::SendError: Send);
     assert_impl!(mpsc::SendError: Sync);
     assert_impl!(mpsc::SendError: Unpin);
 
+    assert_impl!(mpsc::SendTimeout<'_, (), ()>: Send);
+    assert_not_impl!(mpsc::SendTimeout<'_, *const (), ()>: Send);
+    assert_not_impl!(mpsc::SendTimeout<'_, (), *const ()>: Send);
+    assert_impl!(mpsc::SendTimeout<'_, (), ()>: Sync);
+    assert_not_impl!(mpsc::SendTimeout<'_, *const (), ()>: Sync);
+    assert_not_impl!(mpsc::SendTimeout<'_, (), *const ()>: Sync);
+    assert_impl!(mpsc::SendTimeout<'_, PhantomPinned, ()>: Unpin);
+    assert_not_impl!(mpsc::SendTimeout<'_, (), PhantomPinned>: Unpin);
+
+    assert_impl!(mpsc::SendSink<'_, ()>: Send);
+    assert_not_impl!(mpsc::SendSink<'_, *const ()>: Send);
+    assert_impl!(mpsc::SendSink<'_, ()>: Sync);
+    assert_not_impl!(mpsc::SendSink<'_, *const ()>: Sync);
+    assert_impl!(mpsc::SendSink<'_, PhantomPinned>: Unpin);
+
     assert_impl!(mpsc::Sender<()>: Send);
     assert_not_impl!(mpsc::Sender<*const ()>: Send);
     assert_impl!(mpsc::Sender<()>: Sync);
@@ -99,6 +208,24 @@ pub mod channel {
     assert_impl!(mpsc::TrySendError<()>: Unpin);
     assert_not_impl!(mpsc::TrySendError<PhantomPinned>: Unpin);
 
+    assert_impl!(mpsc::UnboundedClosed<'_, ()>: Send);
+    assert_not_impl!(mpsc::UnboundedClosed<'_, *const ()>: Send);
+    assert_impl!(mpsc::UnboundedClosed<'_, ()>: Sync);
+    assert_not_impl!(mpsc::UnboundedClosed<'_, *const ()>: Sync);
+    assert_impl!(mpsc::UnboundedClosed<'_, PhantomPinned>: Unpin);
+
+    assert_impl!(mpsc::UnboundedDrain<'_, ()>: Send);
+    assert_not_impl!(mpsc::UnboundedDrain<'_, *const ()>: Send);
+    assert_impl!(mpsc::UnboundedDrain<'_, ()>: Sync);
+    assert_not_impl!(mpsc::UnboundedDrain<'_, *const ()>: Sync);
+    assert_impl!(mpsc::UnboundedDrain<'_, PhantomPinned>: Unpin);
+
+    assert_impl!(mpsc::UnboundedPeek<'_, ()>: Send);
+    assert_not_impl!(mpsc::UnboundedPeek<'_, *const ()>: Send);
+    assert_impl!(mpsc::UnboundedPeek<'_, ()>: Sync);
+    assert_not_impl!(mpsc::UnboundedPeek<'_, *const ()>: Sync);
+    assert_impl!(mpsc::UnboundedPeek<'_, PhantomPinned>: Unpin);
+
     assert_impl!(mpsc::UnboundedReceiver<()>: Send);
     assert_not_impl!(mpsc::UnboundedReceiver<*const ()>: Send);
     assert_impl!(mpsc::UnboundedReceiver<()>: Sync);
@@ -111,6 +238,59 @@ pub mod channel {
     assert_not_impl!(mpsc::UnboundedReceiver<*const ()>: Sync);
     assert_impl!(mpsc::UnboundedReceiver<PhantomPinned>: Unpin);
 
+    assert_impl!(mpsc::UnboundedRecvMany<'_, ()>: Send);
+    assert_not_impl!(mpsc::UnboundedRecvMany<'_, *const ()>: Send);
+    assert_impl!(mpsc::UnboundedRecvMany<'_, ()>: Sync);
+    assert_not_impl!(mpsc::UnboundedRecvMany<'_, *const ()>: Sync);
+    assert_impl!(mpsc::UnboundedRecvMany<'_, PhantomPinned>: Unpin);
+
+    assert_impl!(mpsc::WeakSender<()>: Send);
+    assert_not_impl!(mpsc::WeakSender<*const ()>: Send);
+    assert_impl!(mpsc::WeakSender<()>: Sync);
+    assert_not_impl!(mpsc::WeakSender<*const ()>: Sync);
+    assert_impl!(mpsc::WeakSender<PhantomPinned>: Unpin);
+
+    assert_impl!(mpsc::WeakUnboundedSender<()>: Send);
+    assert_not_impl!(mpsc::WeakUnboundedSender<*const ()>: Send);
+    assert_impl!(mpsc::WeakUnboundedSender<()>: Sync);
+    assert_not_impl!(mpsc::WeakUnboundedSender<*const ()>: Sync);
+    assert_impl!(mpsc::WeakUnboundedSender<PhantomPinned>: Unpin);
+
+    assert_impl!(mux::Receiver<(), ()>: Send);
+    assert_not_impl!(mux::Receiver<*const (), ()>: Send);
+    assert_not_impl!(mux::Receiver<(), *const ()>: Send);
+    assert_impl!(mux::Receiver<(), ()>: Sync);
+    assert_not_impl!(mux::Receiver<*const (), ()>: Sync);
+    assert_not_impl!(mux::Receiver<(), *const ()>: Sync);
+    assert_impl!(mux::Receiver<(), PhantomPinned>: Unpin);
+
+    assert_impl!(mux::Router<(), ()>: Send);
+    assert_not_impl!(mux::Router<*const (), ()>: Send);
+    assert_not_impl!(mux::Router<(), *const ()>: Send);
+    assert_impl!(mux::Router<(), ()>: Sync);
+    assert_not_impl!(mux::Router<*const (), ()>: Sync);
+    assert_not_impl!(mux::Router<(), *const ()>: Sync);
+    assert_impl!(mux::Router<PhantomPinned, PhantomPinned>: Unpin);
+
+    assert_impl!(mux::SendError<()>: Send);
+    assert_not_impl!(mux::SendError<*const ()>: Send);
+    assert_impl!(mux::SendError<()>: Sync);
+    assert_not_impl!(mux::SendError<*const ()>: Sync);
+    assert_impl!(mux::SendError<()>: Unpin);
+    assert_not_impl!(mux::SendError<PhantomPinned>: Unpin);
+
+    assert_impl!(mux::Sender<(), ()>: Send);
+    assert_not_impl!(mux::Sender<*const (), ()>: Send);
+    assert_not_impl!(mux::Sender<(), *const ()>: Send);
+    assert_impl!(mux::Sender<(), ()>: Sync);
+    assert_not_impl!(mux::Sender<*const (), ()>: Sync);
+    assert_not_impl!(mux::Sender<(), *const ()>: Sync);
+    assert_impl!(mux::Sender<PhantomPinned, PhantomPinned>: Unpin);
+
+    assert_impl!(mux::TryRecvError: Send);
+    assert_impl!(mux::TryRecvError: Sync);
+    assert_impl!(mux::TryRecvError: Unpin);
+
     assert_impl!(oneshot::Canceled: Send);
     assert_impl!(oneshot::Canceled: Sync);
     assert_impl!(oneshot::Canceled: Unpin);
@@ -127,11 +307,105 @@ pub mod channel {
     assert_not_impl!(oneshot::Receiver<*const ()>: Sync);
     assert_impl!(oneshot::Receiver<PhantomPinned>: Unpin);
 
+    assert_impl!(oneshot::SendAndWait<()>: Send);
+    assert_not_impl!(oneshot::SendAndWait<*const ()>: Send);
+    assert_impl!(oneshot::SendAndWait<()>: Sync);
+    assert_not_impl!(oneshot::SendAndWait<*const ()>: Sync);
+    assert_impl!(oneshot::SendAndWait<PhantomPinned>: Unpin);
+
     assert_impl!(oneshot::Sender<()>: Send);
     assert_not_impl!(oneshot::Sender<*const ()>: Send);
     assert_impl!(oneshot::Sender<()>: Sync);
     assert_not_impl!(oneshot::Sender<*const ()>: Sync);
     assert_impl!(oneshot::Sender<PhantomPinned>: Unpin);
+
+    assert_impl!(priority::Receiver<(), ()>: Send);
+    assert_not_impl!(priority::Receiver<*const (), ()>: Send);
+    assert_not_impl!(priority::Receiver<(), *const ()>: Send);
+    assert_impl!(priority::Receiver<(), ()>: Sync);
+    assert_not_impl!(priority::Receiver<*const (), ()>: Sync);
+    assert_not_impl!(priority::Receiver<(), *const ()>: Sync);
+    assert_impl!(priority::Receiver<PhantomPinned, PhantomPinned>: Unpin);
+
+    assert_impl!(priority::SendError: Send);
+    assert_impl!(priority::SendError: Sync);
+    assert_impl!(priority::SendError: Unpin);
+
+    assert_impl!(priority::Sender<(), ()>: Send);
+    assert_not_impl!(priority::Sender<*const (), ()>: Send);
+    assert_not_impl!(priority::Sender<(), *const ()>: Send);
+    assert_impl!(priority::Sender<(), ()>: Sync);
+    assert_not_impl!(priority::Sender<*const (), ()>: Sync);
+    assert_not_impl!(priority::Sender<(), *const ()>: Sync);
+    assert_impl!(priority::Sender<PhantomPinned, PhantomPinned>: Unpin);
+
+    assert_impl!(priority::TryRecvError: Send);
+    assert_impl!(priority::TryRecvError: Sync);
+    assert_impl!(priority::TryRecvError: Unpin);
+
+    assert_impl!(priority::TrySendError<()>: Send);
+    assert_not_impl!(priority::TrySendError<*const ()>: Send);
+    assert_impl!(priority::TrySendError<()>: Sync);
+    assert_not_impl!(priority::TrySendError<*const ()>: Sync);
+    assert_impl!(priority::TrySendError<()>: Unpin);
+    assert_not_impl!(priority::TrySendError<PhantomPinned>: Unpin);
+
+    assert_impl!(ring::Receiver<()>: Send);
+    assert_not_impl!(ring::Receiver<*const ()>: Send);
+    assert_impl!(ring::Receiver<()>: Sync);
+    assert_not_impl!(ring::Receiver<*const ()>: Sync);
+    assert_impl!(ring::Receiver<PhantomPinned>: Unpin);
+
+    assert_impl!(ring::SendError<()>: Send);
+    assert_not_impl!(ring::SendError<*const ()>: Send);
+    assert_impl!(ring::SendError<()>: Sync);
+    assert_not_impl!(ring::SendError<*const ()>: Sync);
+    assert_impl!(ring::SendError<()>: Unpin);
+    assert_not_impl!(ring::SendError<PhantomPinned>: Unpin);
+
+    assert_impl!(ring::Sender<()>: Send);
+    assert_not_impl!(ring::Sender<*const ()>: Send);
+    assert_impl!(ring::Sender<()>: Sync);
+    assert_not_impl!(ring::Sender<*const ()>: Sync);
+    assert_impl!(ring::Sender<PhantomPinned>: Unpin);
+
+    assert_impl!(ring::TryRecvError: Send);
+    assert_impl!(ring::TryRecvError: Sync);
+    assert_impl!(ring::TryRecvError: Unpin);
+
+    assert_impl!(watch::Changed<'_, ()>: Send);
+    assert_not_impl!(watch::Changed<'_, *const ()>: Send);
+    assert_impl!(watch::Changed<'_, ()>: Sync);
+    assert_not_impl!(watch::Changed<'_, *const ()>: Sync);
+    assert_impl!(watch::Changed<'_, PhantomPinned>: Unpin);
+
+    assert_impl!(watch::RecvError: Send);
+    assert_impl!(watch::RecvError: Sync);
+    assert_impl!(watch::RecvError: Unpin);
+
+    assert_not_impl!(watch::Ref<'_, ()>: Send);
+    assert_impl!(watch::Ref<'_, ()>: Sync);
+    assert_not_impl!(watch::Ref<'_, *const ()>: Sync);
+    assert_impl!(watch::Ref<'_, PhantomPinned>: Unpin);
+
+    assert_impl!(watch::Receiver<()>: Send);
+    assert_not_impl!(watch::Receiver<*const ()>: Send);
+    assert_impl!(watch::Receiver<()>: Sync);
+    assert_not_impl!(watch::Receiver<*const ()>: Sync);
+    assert_impl!(watch::Receiver<PhantomPinned>: Unpin);
+
+    assert_impl!(watch::SendError<()>: Send);
+    assert_not_impl!(watch::SendError<*const ()>: Send);
+    assert_impl!(watch::SendError<()>: Sync);
+    assert_not_impl!(watch::SendError<*const ()>: Sync);
+    assert_impl!(watch::SendError<()>: Unpin);
+    assert_not_impl!(watch::SendError<PhantomPinned>: Unpin);
+
+    assert_impl!(watch::Sender<()>: Send);
+    assert_not_impl!(watch::Sender<*const ()>: Send);
+    assert_impl!(watch::Sender<()>: Sync);
+    assert_not_impl!(watch::Sender<*const ()>: Sync);
+    assert_impl!(watch::Sender<PhantomPinned>: Unpin);
 }
 
 /// Assert Send/Sync/Unpin for all public types in `futures::compat`.
@@ -197,6 +471,16 @@ pub mod executor {
     assert_impl!(EnterError: Sync);
     assert_impl!(EnterError: Unpin);
 
+    assert_impl!(JoinError: Send);
+    assert_impl!(JoinError: Sync);
+    assert_impl!(JoinError: Unpin);
+
+    assert_impl!(JoinHandle<()>: Send);
+    assert_not_impl!(JoinHandle<*const ()>: Send);
+    assert_impl!(JoinHandle<()>: Sync);
+    assert_not_impl!(JoinHandle<*const ()>: Sync);
+    assert_impl!(JoinHandle<PhantomPinned>: Unpin);
+
     assert_not_impl!(LocalPool: Send);
     assert_not_impl!(LocalPool: Sync);
     assert_impl!(LocalPool: Unpin);
@@ -205,6 +489,18 @@ pub mod executor {
     assert_not_impl!(LocalSpawner: Sync);
     assert_impl!(LocalSpawner: Unpin);
 
+    assert_impl!(Metrics: Send);
+    assert_impl!(Metrics: Sync);
+    assert_impl!(Metrics: Unpin);
+
+    assert_impl!(Priority: Send);
+    assert_impl!(Priority: Sync);
+    assert_impl!(Priority: Unpin);
+
+    assert_impl!(Scope<'_, '_>: Send);
+    assert_impl!(Scope<'_, '_>: Sync);
+    assert_impl!(Scope<'_, '_>: Unpin);
+
     assert_impl!(ThreadPool: Send);
     assert_impl!(ThreadPool: Sync);
     assert_impl!(ThreadPool: Unpin);
@@ -616,6 +912,32 @@ pub mod future {
     assert_not_impl!(WeakShared<SyncFuture<()>>: Sync);
     assert_impl!(WeakShared<PinnedFuture>: Unpin);
 
+    assert_impl!(WithValue<SendFuture, ()>: Send);
+    assert_not_impl!(WithValue<SendFuture, *const ()>: Send);
+    assert_not_impl!(WithValue<LocalFuture, ()>: Send);
+    assert_impl!(WithValue<SyncFuture, ()>: Sync);
+    assert_not_impl!(WithValue<SyncFuture, *const ()>: Sync);
+    assert_not_impl!(WithValue<LocalFuture, ()>: Sync);
+    assert_impl!(WithValue<UnpinFuture, PhantomPinned>: Unpin);
+    assert_not_impl!(WithValue<PinnedFuture, ()>: Unpin);
+
+    #[cfg(feature = "context-ext")]
+    assert_impl!(WithExt<SendFuture, ()>: Send);
+    #[cfg(feature = "context-ext")]
+    assert_not_impl!(WithExt<SendFuture, *const ()>: Send);
+    #[cfg(feature = "context-ext")]
+    assert_not_impl!(WithExt<LocalFuture, ()>: Send);
+    #[cfg(feature = "context-ext")]
+    assert_impl!(WithExt<SyncFuture, ()>: Sync);
+    #[cfg(feature = "context-ext")]
+    assert_not_impl!(WithExt<SyncFuture, *const ()>: Sync);
+    #[cfg(feature = "context-ext")]
+    assert_not_impl!(WithExt<LocalFuture, ()>: Sync);
+    #[cfg(feature = "context-ext")]
+    assert_impl!(WithExt<UnpinFuture, ()>: Unpin);
+    #[cfg(feature = "context-ext")]
+    assert_not_impl!(WithExt<PinnedFuture, ()>: Unpin);
+
     assert_impl!(Either<SendFuture, SendFuture>: Send);
     assert_not_impl!(Either<SendFuture, LocalFuture>: Send);
     assert_not_impl!(Either<LocalFuture, SendFuture>: Send);
@@ -656,6 +978,22 @@ pub mod io {
     assert_not_impl!(AllowStdIo<*const ()>: Sync);
     assert_impl!(AllowStdIo<PhantomPinned>: Unpin);
 
+    assert_impl!(AutoFlushWriter<(), (), ()>: Send);
+    assert_not_impl!(AutoFlushWriter<*const (), (), ()>: Send);
+    assert_not_impl!(AutoFlushWriter<(), *const (), ()>: Send);
+    assert_not_impl!(AutoFlushWriter<(), (), *const ()>: Send);
+    assert_impl!(AutoFlushWriter<(), (), ()>: Sync);
+    assert_not_impl!(AutoFlushWriter<*const (), (), ()>: Sync);
+    assert_not_impl!(AutoFlushWriter<(), *const (), ()>: Sync);
+    assert_not_impl!(AutoFlushWriter<(), (), *const ()>: Sync);
+    assert_impl!(AutoFlushWriter<(), PhantomPinned, ()>: Unpin);
+    assert_not_impl!(AutoFlushWriter<PhantomPinned, (), ()>: Unpin);
+    assert_not_impl!(AutoFlushWriter<(), (), PhantomPinned>: Unpin);
+
+    assert_impl!(BufferPool: Send);
+    assert_impl!(BufferPool: Sync);
+    assert_impl!(BufferPool: Unpin);
+
     assert_impl!(BufReader<()>: Send);
     assert_not_impl!(BufReader<*const ()>: Send);
     assert_impl!(BufReader<()>: Sync);
@@ -680,6 +1018,13 @@ pub mod io {
     assert_not_impl!(Chain<(), PhantomPinned>: Unpin);
     assert_not_impl!(Chain<PhantomPinned, ()>: Unpin);
 
+    assert_impl!(ChainAll<std::vec::IntoIter<()>>: Send);
+    assert_not_impl!(ChainAll<std::vec::IntoIter<*const ()>>: Send);
+    assert_impl!(ChainAll<std::vec::IntoIter<()>>: Sync);
+    assert_not_impl!(ChainAll<std::vec::IntoIter<*const ()>>: Sync);
+    assert_impl!(ChainAll<std::vec::IntoIter<()>>: Unpin);
+    assert_not_impl!(ChainAll<std::vec::IntoIter<PhantomPinned>>: Unpin);
+
     assert_impl!(Close<'_, ()>: Send);
     assert_not_impl!(Close<'_, *const ()>: Send);
     assert_impl!(Close<'_, ()>: Sync);
@@ -687,6 +1032,35 @@ pub mod io {
     assert_impl!(Close<'_, ()>: Unpin);
     assert_not_impl!(Close<'_, PhantomPinned>: Unpin);
 
+    assert_impl!(CoalescingWriter<()>: Send);
+    assert_not_impl!(CoalescingWriter<*const ()>: Send);
+    assert_impl!(CoalescingWriter<()>: Sync);
+    assert_not_impl!(CoalescingWriter<*const ()>: Sync);
+    assert_impl!(CoalescingWriter<()>: Unpin);
+    assert_not_impl!(CoalescingWriter<PhantomPinned>: Unpin);
+
+    assert_impl!(codec::FramedRead<(), ()>: Send);
+    assert_not_impl!(codec::FramedRead<*const (), ()>: Send);
+    assert_not_impl!(codec::FramedRead<(), *const ()>: Send);
+    assert_impl!(codec::FramedRead<(), ()>: Sync);
+    assert_not_impl!(codec::FramedRead<*const (), ()>: Sync);
+    assert_not_impl!(codec::FramedRead<(), *const ()>: Sync);
+    assert_impl!(codec::FramedRead<(), PhantomPinned>: Unpin);
+    assert_not_impl!(codec::FramedRead<PhantomPinned, ()>: Unpin);
+
+    assert_impl!(codec::FramedWrite<(), ()>: Send);
+    assert_not_impl!(codec::FramedWrite<*const (), ()>: Send);
+    assert_not_impl!(codec::FramedWrite<(), *const ()>: Send);
+    assert_impl!(codec::FramedWrite<(), ()>: Sync);
+    assert_not_impl!(codec::FramedWrite<*const (), ()>: Sync);
+    assert_not_impl!(codec::FramedWrite<(), *const ()>: Sync);
+    assert_impl!(codec::FramedWrite<(), PhantomPinned>: Unpin);
+    assert_not_impl!(codec::FramedWrite<PhantomPinned, ()>: Unpin);
+
+    assert_impl!(codec::LengthDelimitedCodec: Send);
+    assert_impl!(codec::LengthDelimitedCodec: Sync);
+    assert_impl!(codec::LengthDelimitedCodec: Unpin);
+
     assert_impl!(Copy<(), ()>: Send);
     assert_not_impl!(Copy<(), *const ()>: Send);
     assert_not_impl!(Copy<*const (), ()>: Send);
@@ -705,6 +1079,43 @@ pub mod io {
     assert_impl!(CopyBuf<(), PhantomPinned>: Unpin);
     assert_not_impl!(CopyBuf<PhantomPinned, ()>: Unpin);
 
+    assert_impl!(CopyBufWithProgress<(), (), ()>: Send);
+    assert_not_impl!(CopyBufWithProgress<(), *const (), ()>: Send);
+    assert_not_impl!(CopyBufWithProgress<*const (), (), ()>: Send);
+    assert_not_impl!(CopyBufWithProgress<(), (), *const ()>: Send);
+    assert_impl!(CopyBufWithProgress<(), (), ()>: Sync);
+    assert_not_impl!(CopyBufWithProgress<(), *const (), ()>: Sync);
+    assert_not_impl!(CopyBufWithProgress<*const (), (), ()>: Sync);
+    assert_not_impl!(CopyBufWithProgress<(), (), *const ()>: Sync);
+    assert_impl!(CopyBufWithProgress<(), PhantomPinned, ()>: Unpin);
+    assert_not_impl!(CopyBufWithProgress<PhantomPinned, (), ()>: Unpin);
+    assert_impl!(CopyBufWithProgress<(), (), PhantomPinned>: Unpin);
+
+    assert_impl!(CopyConfig: Send);
+    assert_impl!(CopyConfig: Sync);
+    assert_impl!(CopyConfig: Unpin);
+
+    assert_impl!(CopyWith<(), ()>: Send);
+    assert_not_impl!(CopyWith<(), *const ()>: Send);
+    assert_not_impl!(CopyWith<*const (), ()>: Send);
+    assert_impl!(CopyWith<(), ()>: Sync);
+    assert_not_impl!(CopyWith<(), *const ()>: Sync);
+    assert_not_impl!(CopyWith<*const (), ()>: Sync);
+    assert_impl!(CopyWith<(), PhantomPinned>: Unpin);
+    assert_not_impl!(CopyWith<PhantomPinned, ()>: Unpin);
+
+    assert_impl!(CopyWithProgress<(), (), ()>: Send);
+    assert_not_impl!(CopyWithProgress<(), *const (), ()>: Send);
+    assert_not_impl!(CopyWithProgress<*const (), (), ()>: Send);
+    assert_not_impl!(CopyWithProgress<(), (), *const ()>: Send);
+    assert_impl!(CopyWithProgress<(), (), ()>: Sync);
+    assert_not_impl!(CopyWithProgress<(), *const (), ()>: Sync);
+    assert_not_impl!(CopyWithProgress<*const (), (), ()>: Sync);
+    assert_not_impl!(CopyWithProgress<(), (), *const ()>: Sync);
+    assert_impl!(CopyWithProgress<(), PhantomPinned, ()>: Unpin);
+    assert_not_impl!(CopyWithProgress<PhantomPinned, (), ()>: Unpin);
+    assert_impl!(CopyWithProgress<(), (), PhantomPinned>: Unpin);
+
     assert_impl!(Cursor<()>: Send);
     assert_not_impl!(Cursor<*const ()>: Send);
     assert_impl!(Cursor<()>: Sync);
@@ -712,6 +1123,28 @@ pub mod io {
     assert_impl!(Cursor<()>: Unpin);
     assert_not_impl!(Cursor<PhantomPinned>: Unpin);
 
+    assert_impl!(DatagramSink<(), (), ()>: Send);
+    assert_not_impl!(DatagramSink<*const (), (), ()>: Send);
+    assert_not_impl!(DatagramSink<(), *const (), ()>: Send);
+    assert_not_impl!(DatagramSink<(), (), *const ()>: Send);
+    assert_impl!(DatagramSink<(), (), ()>: Sync);
+    assert_not_impl!(DatagramSink<*const (), (), ()>: Sync);
+    assert_not_impl!(DatagramSink<(), *const (), ()>: Sync);
+    assert_not_impl!(DatagramSink<(), (), *const ()>: Sync);
+    assert_impl!(DatagramSink<(), PhantomPinned, PhantomPinned>: Unpin);
+    assert_not_impl!(DatagramSink<PhantomPinned, (), ()>: Unpin);
+
+    assert_impl!(Datagrams<(), *const ()>: Send);
+    assert_not_impl!(Datagrams<*const (), ()>: Send);
+    assert_impl!(Datagrams<(), *const ()>: Sync);
+    assert_not_impl!(Datagrams<*const (), ()>: Sync);
+    assert_impl!(Datagrams<(), PhantomPinned>: Unpin);
+    assert_not_impl!(Datagrams<PhantomPinned, ()>: Unpin);
+
+    assert_impl!(DuplexStream: Send);
+    assert_impl!(DuplexStream: Sync);
+    assert_impl!(DuplexStream: Unpin);
+
     assert_impl!(Empty: Send);
     assert_impl!(Empty: Sync);
     assert_impl!(Empty: Unpin);
@@ -729,6 +1162,24 @@ pub mod io {
     assert_impl!(Flush<'_, ()>: Unpin);
     assert_not_impl!(Flush<'_, PhantomPinned>: Unpin);
 
+    assert_impl!(InspectRead<(), ()>: Send);
+    assert_not_impl!(InspectRead<*const (), ()>: Send);
+    assert_not_impl!(InspectRead<(), *const ()>: Send);
+    assert_impl!(InspectRead<(), ()>: Sync);
+    assert_not_impl!(InspectRead<*const (), ()>: Sync);
+    assert_not_impl!(InspectRead<(), *const ()>: Sync);
+    assert_impl!(InspectRead<(), PhantomPinned>: Unpin);
+    assert_not_impl!(InspectRead<PhantomPinned, ()>: Unpin);
+
+    assert_impl!(InspectWrite<(), ()>: Send);
+    assert_not_impl!(InspectWrite<*const (), ()>: Send);
+    assert_not_impl!(InspectWrite<(), *const ()>: Send);
+    assert_impl!(InspectWrite<(), ()>: Sync);
+    assert_not_impl!(InspectWrite<*const (), ()>: Sync);
+    assert_not_impl!(InspectWrite<(), *const ()>: Sync);
+    assert_impl!(InspectWrite<(), PhantomPinned>: Unpin);
+    assert_not_impl!(InspectWrite<PhantomPinned, ()>: Unpin);
+
     assert_impl!(IntoSink<(), ()>: Send);
     assert_not_impl!(IntoSink<(), *const ()>: Send);
     assert_not_impl!(IntoSink<*const (), ()>: Send);
@@ -738,6 +1189,13 @@ pub mod io {
     assert_impl!(IntoSink<(), PhantomPinned>: Unpin);
     assert_not_impl!(IntoSink<PhantomPinned, ()>: Unpin);
 
+    assert_impl!(LimitedWriter<()>: Send);
+    assert_not_impl!(LimitedWriter<*const ()>: Send);
+    assert_impl!(LimitedWriter<()>: Sync);
+    assert_not_impl!(LimitedWriter<*const ()>: Sync);
+    assert_impl!(LimitedWriter<()>: Unpin);
+    assert_not_impl!(LimitedWriter<PhantomPinned>: Unpin);
+
     assert_impl!(Lines<()>: Send);
     assert_not_impl!(Lines<*const ()>: Send);
     assert_impl!(Lines<()>: Sync);
@@ -745,6 +1203,34 @@ pub mod io {
     assert_impl!(Lines<()>: Unpin);
     assert_not_impl!(Lines<PhantomPinned>: Unpin);
 
+    assert_impl!(Overflow: Send);
+    assert_impl!(Overflow: Sync);
+    assert_impl!(Overflow: Unpin);
+
+    assert_impl!(ratelimit::RateLimitedReader<(), (), ()>: Send);
+    assert_not_impl!(ratelimit::RateLimitedReader<*const (), (), ()>: Send);
+    assert_not_impl!(ratelimit::RateLimitedReader<(), *const (), ()>: Send);
+    assert_not_impl!(ratelimit::RateLimitedReader<(), (), *const ()>: Send);
+    assert_impl!(ratelimit::RateLimitedReader<(), (), ()>: Sync);
+    assert_not_impl!(ratelimit::RateLimitedReader<*const (), (), ()>: Sync);
+    assert_not_impl!(ratelimit::RateLimitedReader<(), *const (), ()>: Sync);
+    assert_not_impl!(ratelimit::RateLimitedReader<(), (), *const ()>: Sync);
+    assert_impl!(ratelimit::RateLimitedReader<(), PhantomPinned, ()>: Unpin);
+    assert_not_impl!(ratelimit::RateLimitedReader<PhantomPinned, (), ()>: Unpin);
+    assert_not_impl!(ratelimit::RateLimitedReader<(), (), PhantomPinned>: Unpin);
+
+    assert_impl!(ratelimit::RateLimitedWriter<(), (), ()>: Send);
+    assert_not_impl!(ratelimit::RateLimitedWriter<*const (), (), ()>: Send);
+    assert_not_impl!(ratelimit::RateLimitedWriter<(), *const (), ()>: Send);
+    assert_not_impl!(ratelimit::RateLimitedWriter<(), (), *const ()>: Send);
+    assert_impl!(ratelimit::RateLimitedWriter<(), (), ()>: Sync);
+    assert_not_impl!(ratelimit::RateLimitedWriter<*const (), (), ()>: Sync);
+    assert_not_impl!(ratelimit::RateLimitedWriter<(), *const (), ()>: Sync);
+    assert_not_impl!(ratelimit::RateLimitedWriter<(), (), *const ()>: Sync);
+    assert_impl!(ratelimit::RateLimitedWriter<(), PhantomPinned, ()>: Unpin);
+    assert_not_impl!(ratelimit::RateLimitedWriter<PhantomPinned, (), ()>: Unpin);
+    assert_not_impl!(ratelimit::RateLimitedWriter<(), (), PhantomPinned>: Unpin);
+
     assert_impl!(Read<'_, ()>: Send);
     assert_not_impl!(Read<'_, *const ()>: Send);
     assert_impl!(Read<'_, ()>: Sync);
@@ -752,6 +1238,10 @@ pub mod io {
     assert_impl!(Read<'_, ()>: Unpin);
     assert_not_impl!(Read<'_, PhantomPinned>: Unpin);
 
+    assert_impl!(ReadBuf<'_>: Send);
+    assert_impl!(ReadBuf<'_>: Sync);
+    assert_impl!(ReadBuf<'_>: Unpin);
+
     assert_impl!(ReadExact<'_, ()>: Send);
     assert_not_impl!(ReadExact<'_, *const ()>: Send);
     assert_impl!(ReadExact<'_, ()>: Sync);
@@ -759,12 +1249,26 @@ pub mod io {
     assert_impl!(ReadExact<'_, ()>: Unpin);
     assert_not_impl!(ReadExact<'_, PhantomPinned>: Unpin);
 
+    assert_impl!(ReadExactOrEof<'_, ()>: Send);
+    assert_not_impl!(ReadExactOrEof<'_, *const ()>: Send);
+    assert_impl!(ReadExactOrEof<'_, ()>: Sync);
+    assert_not_impl!(ReadExactOrEof<'_, *const ()>: Sync);
+    assert_impl!(ReadExactOrEof<'_, ()>: Unpin);
+    assert_not_impl!(ReadExactOrEof<'_, PhantomPinned>: Unpin);
+
     assert_impl!(ReadHalf<()>: Send);
     assert_not_impl!(ReadHalf<*const ()>: Send);
     assert_impl!(ReadHalf<()>: Sync);
     assert_not_impl!(ReadHalf<*const ()>: Sync);
     assert_impl!(ReadHalf<PhantomPinned>: Unpin);
 
+    assert_impl!(ReadIntoBuf<'_, '_, ()>: Send);
+    assert_not_impl!(ReadIntoBuf<'_, '_, *const ()>: Send);
+    assert_impl!(ReadIntoBuf<'_, '_, ()>: Sync);
+    assert_not_impl!(ReadIntoBuf<'_, '_, *const ()>: Sync);
+    assert_impl!(ReadIntoBuf<'_, '_, ()>: Unpin);
+    assert_not_impl!(ReadIntoBuf<'_, '_, PhantomPinned>: Unpin);
+
     assert_impl!(ReadLine<'_, ()>: Send);
     assert_not_impl!(ReadLine<'_, *const ()>: Send);
     assert_impl!(ReadLine<'_, ()>: Sync);
@@ -772,6 +1276,13 @@ pub mod io {
     assert_impl!(ReadLine<'_, ()>: Unpin);
     assert_not_impl!(ReadLine<'_, PhantomPinned>: Unpin);
 
+    assert_impl!(ReadLineLimited<'_, ()>: Send);
+    assert_not_impl!(ReadLineLimited<'_, *const ()>: Send);
+    assert_impl!(ReadLineLimited<'_, ()>: Sync);
+    assert_not_impl!(ReadLineLimited<'_, *const ()>: Sync);
+    assert_impl!(ReadLineLimited<'_, ()>: Unpin);
+    assert_not_impl!(ReadLineLimited<'_, PhantomPinned>: Unpin);
+
     assert_impl!(ReadToEnd<'_, ()>: Send);
     assert_not_impl!(ReadToEnd<'_, *const ()>: Send);
     assert_impl!(ReadToEnd<'_, ()>: Sync);
@@ -793,6 +1304,13 @@ pub mod io {
     assert_impl!(ReadUntil<'_, ()>: Unpin);
     assert_not_impl!(ReadUntil<'_, PhantomPinned>: Unpin);
 
+    assert_impl!(ReadUntilLimited<'_, ()>: Send);
+    assert_not_impl!(ReadUntilLimited<'_, *const ()>: Send);
+    assert_impl!(ReadUntilLimited<'_, ()>: Sync);
+    assert_not_impl!(ReadUntilLimited<'_, *const ()>: Sync);
+    assert_impl!(ReadUntilLimited<'_, ()>: Unpin);
+    assert_not_impl!(ReadUntilLimited<'_, PhantomPinned>: Unpin);
+
     assert_impl!(ReadVectored<'_, '_, ()>: Send);
     assert_not_impl!(ReadVectored<'_, '_, *const ()>: Send);
     assert_impl!(ReadVectored<'_, '_, ()>: Sync);
@@ -800,6 +1318,20 @@ pub mod io {
     assert_impl!(ReadVectored<'_, '_, ()>: Unpin);
     assert_not_impl!(ReadVectored<'_, '_, PhantomPinned>: Unpin);
 
+    assert_impl!(ReaderStream<()>: Send);
+    assert_not_impl!(ReaderStream<*const ()>: Send);
+    assert_impl!(ReaderStream<()>: Sync);
+    assert_not_impl!(ReaderStream<*const ()>: Sync);
+    assert_impl!(ReaderStream<()>: Unpin);
+    assert_not_impl!(ReaderStream<PhantomPinned>: Unpin);
+
+    assert_impl!(RecvFrom<'_, (), *const ()>: Send);
+    assert_not_impl!(RecvFrom<'_, *const (), ()>: Send);
+    assert_impl!(RecvFrom<'_, (), *const ()>: Sync);
+    assert_not_impl!(RecvFrom<'_, *const (), ()>: Sync);
+    assert_impl!(RecvFrom<'_, (), PhantomPinned>: Unpin);
+    assert_not_impl!(RecvFrom<'_, PhantomPinned, ()>: Unpin);
+
     assert_impl!(Repeat: Send);
     assert_impl!(Repeat: Sync);
     assert_impl!(Repeat: Unpin);
@@ -823,10 +1355,53 @@ pub mod io {
     assert_not_impl!(SeeKRelative<'_, *const ()>: Sync);
     assert_impl!(SeeKRelative<'_, PhantomPinned>: Unpin);
 
+    assert_impl!(SeekRelative<'_, ()>: Send);
+    assert_not_impl!(SeekRelative<'_, *const ()>: Send);
+    assert_impl!(SeekRelative<'_, ()>: Sync);
+    assert_not_impl!(SeekRelative<'_, *const ()>: Sync);
+    assert_impl!(SeekRelative<'_, PhantomPinned>: Unpin);
+
+    assert_impl!(SegmentedCursor<()>: Send);
+    assert_not_impl!(SegmentedCursor<*const ()>: Send);
+    assert_impl!(SegmentedCursor<()>: Sync);
+    assert_not_impl!(SegmentedCursor<*const ()>: Sync);
+    assert_impl!(SegmentedCursor<()>: Unpin);
+    assert_not_impl!(SegmentedCursor<PhantomPinned>: Unpin);
+
+    assert_impl!(SendTo<'_, (), ()>: Send);
+    assert_not_impl!(SendTo<'_, *const (), ()>: Send);
+    assert_not_impl!(SendTo<'_, (), *const ()>: Send);
+    assert_impl!(SendTo<'_, (), ()>: Sync);
+    assert_not_impl!(SendTo<'_, *const (), ()>: Sync);
+    assert_not_impl!(SendTo<'_, (), *const ()>: Sync);
+    assert_impl!(SendTo<'_, (), PhantomPinned>: Unpin);
+    assert_not_impl!(SendTo<'_, PhantomPinned, ()>: Unpin);
+
     assert_impl!(Sink: Send);
     assert_impl!(Sink: Sync);
     assert_impl!(Sink: Unpin);
 
+    assert_impl!(SplitBy<()>: Send);
+    assert_not_impl!(SplitBy<*const ()>: Send);
+    assert_impl!(SplitBy<()>: Sync);
+    assert_not_impl!(SplitBy<*const ()>: Sync);
+    assert_impl!(SplitBy<()>: Unpin);
+    assert_not_impl!(SplitBy<PhantomPinned>: Unpin);
+
+    assert_impl!(SyncReader<()>: Send);
+    assert_not_impl!(SyncReader<*const ()>: Send);
+    assert_impl!(SyncReader<()>: Sync);
+    assert_not_impl!(SyncReader<*const ()>: Sync);
+    assert_impl!(SyncReader<()>: Unpin);
+    assert_not_impl!(SyncReader<PhantomPinned>: Unpin);
+
+    assert_impl!(SyncWriter<()>: Send);
+    assert_not_impl!(SyncWriter<*const ()>: Send);
+    assert_impl!(SyncWriter<()>: Sync);
+    assert_not_impl!(SyncWriter<*const ()>: Sync);
+    assert_impl!(SyncWriter<()>: Unpin);
+    assert_not_impl!(SyncWriter<PhantomPinned>: Unpin);
+
     assert_impl!(Take<()>: Send);
     assert_not_impl!(Take<*const ()>: Send);
     assert_impl!(Take<()>: Sync);
@@ -834,6 +1409,16 @@ pub mod io {
     assert_impl!(Take<()>: Unpin);
     assert_not_impl!(Take<PhantomPinned>: Unpin);
 
+    assert_impl!(Tee<(), ()>: Send);
+    assert_not_impl!(Tee<*const (), ()>: Send);
+    assert_not_impl!(Tee<(), *const ()>: Send);
+    assert_impl!(Tee<(), ()>: Sync);
+    assert_not_impl!(Tee<*const (), ()>: Sync);
+    assert_not_impl!(Tee<(), *const ()>: Sync);
+    assert_impl!(Tee<(), ()>: Unpin);
+    assert_not_impl!(Tee<PhantomPinned, ()>: Unpin);
+    assert_not_impl!(Tee<(), PhantomPinned>: Unpin);
+
     assert_impl!(Window<()>: Send);
     assert_not_impl!(Window<*const ()>: Send);
     assert_impl!(Window<()>: Sync);
@@ -883,6 +1468,23 @@ pub mod io {
     assert_not_impl!(WriteVectored<'_, '_, PhantomPinned>: Unpin);
 }
 
+/// Assert Send/Sync/Unpin for all public types in `futures::instrument`.
+pub mod instrument {
+    use super::*;
+    use futures::instrument::*;
+
+    assert_impl!(HookId: Send);
+    assert_impl!(HookId: Sync);
+    assert_impl!(HookId: Unpin);
+
+    assert_impl!(Instrument<()>: Send);
+    assert_not_impl!(Instrument<*const ()>: Send);
+    assert_impl!(Instrument<()>: Sync);
+    assert_not_impl!(Instrument<*const ()>: Sync);
+    assert_impl!(Instrument<()>: Unpin);
+    assert_not_impl!(Instrument<PhantomPinned>: Unpin);
+}
+
 /// Assert Send/Sync/Unpin for all public types in `futures::lock`.
 pub mod lock {
     use super::*;
@@ -921,6 +1523,28 @@ pub mod lock {
     #[cfg(feature = "bilock")]
     assert_impl!(BiLockGuard<'_, PhantomPinned>: Unpin);
 
+    assert_impl!(CancellationToken: Send);
+    assert_impl!(CancellationToken: Sync);
+    assert_impl!(CancellationToken: Unpin);
+
+    assert_impl!(Condvar: Send);
+    assert_impl!(Condvar: Sync);
+    assert_impl!(Condvar: Unpin);
+
+    assert_impl!(CondvarWaitFuture<'_, ()>: Send);
+    assert_not_impl!(CondvarWaitFuture<'_, *const ()>: Send);
+    assert_impl!(CondvarWaitFuture<'_, ()>: Sync);
+    assert_not_impl!(CondvarWaitFuture<'_, *const ()>: Sync);
+    assert_not_impl!(CondvarWaitFuture<'_, ()>: Unpin);
+
+    assert_impl!(Event: Send);
+    assert_impl!(Event: Sync);
+    assert_impl!(Event: Unpin);
+
+    assert_impl!(EventWaitFuture<'_>: Send);
+    assert_impl!(EventWaitFuture<'_>: Sync);
+    assert_not_impl!(EventWaitFuture<'_>: Unpin);
+
     assert_impl!(MappedMutexGuard<'_, (), ()>: Send);
     assert_not_impl!(MappedMutexGuard<'_, (), *const ()>: Send);
     assert_not_impl!(MappedMutexGuard<'_, *const (), ()>: Send);
@@ -942,10 +1566,55 @@ pub mod lock {
     assert_not_impl!(MutexGuard<'_, *const ()>: Sync);
     assert_impl!(MutexGuard<'_, PhantomPinned>: Unpin);
 
+    assert_impl!(MutexLockCheckedFuture<'_, ()>: Send);
+    assert_not_impl!(MutexLockCheckedFuture<'_, *const ()>: Send);
+    assert_impl!(MutexLockCheckedFuture<'_, *const ()>: Sync);
+    assert_not_impl!(MutexLockCheckedFuture<'_, ()>: Unpin);
+
     assert_impl!(MutexLockFuture<'_, ()>: Send);
     assert_not_impl!(MutexLockFuture<'_, *const ()>: Send);
     assert_impl!(MutexLockFuture<'_, *const ()>: Sync);
-    assert_impl!(MutexLockFuture<'_, PhantomPinned>: Unpin);
+    assert_not_impl!(MutexLockFuture<'_, ()>: Unpin);
+
+    assert_impl!(Notified<'_>: Send);
+    assert_impl!(Notified<'_>: Sync);
+    assert_not_impl!(Notified<'_>: Unpin);
+
+    assert_impl!(Notify: Send);
+    assert_impl!(Notify: Sync);
+    assert_impl!(Notify: Unpin);
+
+    assert_impl!(OwnedRwLockReadFuture<()>: Send);
+    assert_not_impl!(OwnedRwLockReadFuture<*const ()>: Send);
+    assert_impl!(OwnedRwLockReadFuture<()>: Sync);
+    assert_not_impl!(OwnedRwLockReadFuture<*const ()>: Sync);
+    assert_not_impl!(OwnedRwLockReadFuture<()>: Unpin);
+
+    assert_impl!(OwnedRwLockReadGuard<()>: Send);
+    assert_not_impl!(OwnedRwLockReadGuard<*const ()>: Send);
+    assert_impl!(OwnedRwLockReadGuard<()>: Sync);
+    assert_not_impl!(OwnedRwLockReadGuard<*const ()>: Sync);
+    assert_impl!(OwnedRwLockReadGuard<PhantomPinned>: Unpin);
+
+    assert_impl!(OwnedRwLockWriteFuture<()>: Send);
+    assert_not_impl!(OwnedRwLockWriteFuture<*const ()>: Send);
+    assert_impl!(OwnedRwLockWriteFuture<()>: Sync);
+    assert_not_impl!(OwnedRwLockWriteFuture<*const ()>: Sync);
+    assert_not_impl!(OwnedRwLockWriteFuture<()>: Unpin);
+
+    assert_impl!(OwnedRwLockWriteGuard<()>: Send);
+    assert_not_impl!(OwnedRwLockWriteGuard<*const ()>: Send);
+    assert_impl!(OwnedRwLockWriteGuard<()>: Sync);
+    assert_not_impl!(OwnedRwLockWriteGuard<*const ()>: Sync);
+    assert_impl!(OwnedRwLockWriteGuard<PhantomPinned>: Unpin);
+
+    assert_impl!(OwnedSemaphoreAcquireFuture: Send);
+    assert_impl!(OwnedSemaphoreAcquireFuture: Sync);
+    assert_not_impl!(OwnedSemaphoreAcquireFuture: Unpin);
+
+    assert_impl!(OwnedSemaphorePermit: Send);
+    assert_impl!(OwnedSemaphorePermit: Sync);
+    assert_impl!(OwnedSemaphorePermit: Unpin);
 
     #[cfg(feature = "bilock")]
     assert_impl!(ReuniteError<()>: Send);
@@ -957,6 +1626,81 @@ pub mod lock {
     assert_not_impl!(ReuniteError<*const ()>: Sync);
     #[cfg(feature = "bilock")]
     assert_impl!(ReuniteError<PhantomPinned>: Unpin);
+
+    assert_impl!(RunUntilCancelled<'_, ()>: Send);
+    assert_not_impl!(RunUntilCancelled<'_, *const ()>: Send);
+    assert_impl!(RunUntilCancelled<'_, ()>: Sync);
+    assert_not_impl!(RunUntilCancelled<'_, *const ()>: Sync);
+    assert_not_impl!(RunUntilCancelled<'_, ()>: Unpin);
+
+    assert_impl!(RwLock<()>: Send);
+    assert_not_impl!(RwLock<*const ()>: Send);
+    assert_impl!(RwLock<()>: Sync);
+    assert_not_impl!(RwLock<*const ()>: Sync);
+    assert_impl!(RwLock<()>: Unpin);
+    assert_not_impl!(RwLock<PhantomPinned>: Unpin);
+
+    assert_impl!(RwLockReadFuture<'_, ()>: Send);
+    assert_not_impl!(RwLockReadFuture<'_, *const ()>: Send);
+    assert_impl!(RwLockReadFuture<'_, *const ()>: Sync);
+    assert_not_impl!(RwLockReadFuture<'_, ()>: Unpin);
+
+    assert_impl!(RwLockReadGuard<'_, ()>: Send);
+    assert_not_impl!(RwLockReadGuard<'_, *const ()>: Send);
+    assert_impl!(RwLockReadGuard<'_, ()>: Sync);
+    assert_not_impl!(RwLockReadGuard<'_, *const ()>: Sync);
+    assert_impl!(RwLockReadGuard<'_, PhantomPinned>: Unpin);
+
+    assert_impl!(RwLockUpgradableReadFuture<'_, ()>: Send);
+    assert_not_impl!(RwLockUpgradableReadFuture<'_, *const ()>: Send);
+    assert_impl!(RwLockUpgradableReadFuture<'_, *const ()>: Sync);
+    assert_not_impl!(RwLockUpgradableReadFuture<'_, ()>: Unpin);
+
+    assert_impl!(RwLockUpgradableReadGuard<'_, ()>: Send);
+    assert_not_impl!(RwLockUpgradableReadGuard<'_, *const ()>: Send);
+    assert_impl!(RwLockUpgradableReadGuard<'_, ()>: Sync);
+    assert_not_impl!(RwLockUpgradableReadGuard<'_, *const ()>: Sync);
+    assert_impl!(RwLockUpgradableReadGuard<'_, PhantomPinned>: Unpin);
+
+    assert_impl!(RwLockUpgradeFuture<'_, ()>: Send);
+    assert_not_impl!(RwLockUpgradeFuture<'_, *const ()>: Send);
+    assert_impl!(RwLockUpgradeFuture<'_, *const ()>: Sync);
+    assert_not_impl!(RwLockUpgradeFuture<'_, ()>: Unpin);
+
+    assert_impl!(RwLockWriteFuture<'_, ()>: Send);
+    assert_not_impl!(RwLockWriteFuture<'_, *const ()>: Send);
+    assert_impl!(RwLockWriteFuture<'_, *const ()>: Sync);
+    assert_not_impl!(RwLockWriteFuture<'_, ()>: Unpin);
+
+    assert_impl!(RwLockWriteGuard<'_, ()>: Send);
+    assert_not_impl!(RwLockWriteGuard<'_, *const ()>: Send);
+    assert_impl!(RwLockWriteGuard<'_, ()>: Sync);
+    assert_not_impl!(RwLockWriteGuard<'_, *const ()>: Sync);
+    assert_impl!(RwLockWriteGuard<'_, PhantomPinned>: Unpin);
+
+    assert_impl!(Semaphore: Send);
+    assert_impl!(Semaphore: Sync);
+    assert_impl!(Semaphore: Unpin);
+
+    assert_impl!(SemaphoreAcquireFuture<'_>: Send);
+    assert_impl!(SemaphoreAcquireFuture<'_>: Sync);
+    assert_not_impl!(SemaphoreAcquireFuture<'_>: Unpin);
+
+    assert_impl!(SemaphorePermit<'_>: Send);
+    assert_impl!(SemaphorePermit<'_>: Sync);
+    assert_impl!(SemaphorePermit<'_>: Unpin);
+
+    assert_impl!(WaitForCancellationFuture<'_>: Send);
+    assert_impl!(WaitForCancellationFuture<'_>: Sync);
+    assert_not_impl!(WaitForCancellationFuture<'_>: Unpin);
+
+    assert_impl!(WaitGroup: Send);
+    assert_impl!(WaitGroup: Sync);
+    assert_impl!(WaitGroup: Unpin);
+
+    assert_impl!(WaitGroupFuture: Send);
+    assert_impl!(WaitGroupFuture: Sync);
+    assert_not_impl!(WaitGroupFuture: Unpin);
 }
 
 /// Assert Send/Sync/Unpin for all public types in `futures::sink`.
@@ -968,12 +1712,32 @@ pub mod sink {
     assert_impl!(Buffer<(), ()>: Send);
     assert_not_impl!(Buffer<(), *const ()>: Send);
     assert_not_impl!(Buffer<*const (), ()>: Send);
+    assert_not_impl!(Buffer<(), (), *const ()>: Send);
     assert_impl!(Buffer<(), ()>: Sync);
     assert_not_impl!(Buffer<(), *const ()>: Sync);
     assert_not_impl!(Buffer<*const (), ()>: Sync);
-    assert_impl!(Buffer<(), PhantomPinned>: Unpin);
+    assert_not_impl!(Buffer<(), (), *const ()>: Sync);
+    assert_impl!(Buffer<(), PhantomPinned, PhantomPinned>: Unpin);
     assert_not_impl!(Buffer<PhantomPinned, ()>: Unpin);
 
+    assert_impl!(BufferLevel: Send);
+    assert_impl!(BufferLevel: Sync);
+    assert_impl!(BufferLevel: Unpin);
+
+    assert_impl!(BufferLinger<(), (), (), ()>: Send);
+    assert_not_impl!(BufferLinger<*const (), (), (), ()>: Send);
+    assert_not_impl!(BufferLinger<(), *const (), (), ()>: Send);
+    assert_not_impl!(BufferLinger<(), (), *const (), ()>: Send);
+    assert_not_impl!(BufferLinger<(), (), (), *const ()>: Send);
+    assert_impl!(BufferLinger<(), (), (), ()>: Sync);
+    assert_not_impl!(BufferLinger<*const (), (), (), ()>: Sync);
+    assert_not_impl!(BufferLinger<(), *const (), (), ()>: Sync);
+    assert_not_impl!(BufferLinger<(), (), *const (), ()>: Sync);
+    assert_not_impl!(BufferLinger<(), (), (), *const ()>: Sync);
+    assert_impl!(BufferLinger<(), PhantomPinned, PhantomPinned, ()>: Unpin);
+    assert_not_impl!(BufferLinger<PhantomPinned, (), (), ()>: Unpin);
+    assert_not_impl!(BufferLinger<(), (), (), PhantomPinned>: Unpin);
+
     assert_impl!(Close<'_, (), *const ()>: Send);
     assert_not_impl!(Close<'_, *const (), ()>: Send);
     assert_impl!(Close<'_, (), *const ()>: Sync);
@@ -981,6 +1745,18 @@ pub mod sink {
     assert_impl!(Close<'_, (), PhantomPinned>: Unpin);
     assert_not_impl!(Close<'_, PhantomPinned, ()>: Unpin);
 
+    assert_impl!(Demux<(), (), (), ()>: Send);
+    assert_not_impl!(Demux<*const (), (), (), ()>: Send);
+    assert_not_impl!(Demux<(), *const (), (), ()>: Send);
+    assert_not_impl!(Demux<(), (), *const (), ()>: Send);
+    assert_not_impl!(Demux<(), (), (), *const ()>: Send);
+    assert_impl!(Demux<(), (), (), ()>: Sync);
+    assert_not_impl!(Demux<*const (), (), (), ()>: Sync);
+    assert_not_impl!(Demux<(), *const (), (), ()>: Sync);
+    assert_not_impl!(Demux<(), (), *const (), ()>: Sync);
+    assert_not_impl!(Demux<(), (), (), *const ()>: Sync);
+    assert_impl!(Demux<PhantomPinned, PhantomPinned, PhantomPinned, PhantomPinned>: Unpin);
+
     assert_impl!(Drain<()>: Send);
     assert_not_impl!(Drain<*const ()>: Send);
     assert_impl!(Drain<()>: Sync);
@@ -997,6 +1773,13 @@ pub mod sink {
     assert_not_impl!(Fanout<(), PhantomPinned>: Unpin);
     assert_not_impl!(Fanout<PhantomPinned, ()>: Unpin);
 
+    assert_impl!(FanoutSet<()>: Send);
+    assert_not_impl!(FanoutSet<*const ()>: Send);
+    assert_impl!(FanoutSet<()>: Sync);
+    assert_not_impl!(FanoutSet<*const ()>: Sync);
+    assert_impl!(FanoutSet<()>: Unpin);
+    assert_not_impl!(FanoutSet<PhantomPinned>: Unpin);
+
     assert_impl!(Feed<'_, (), ()>: Send);
     assert_not_impl!(Feed<'_, (), *const ()>: Send);
     assert_not_impl!(Feed<'_, *const (), ()>: Send);
@@ -1006,6 +1789,39 @@ pub mod sink {
     assert_impl!(Feed<'_, (), PhantomPinned>: Unpin);
     assert_not_impl!(Feed<'_, PhantomPinned, ()>: Unpin);
 
+    assert_impl!(FeedAll<'_, (), SendStream<()>>: Send);
+    assert_not_impl!(FeedAll<'_, (), SendStream>: Send);
+    assert_not_impl!(FeedAll<'_, (), LocalStream>: Send);
+    assert_not_impl!(FeedAll<'_, *const (), SendStream<()>>: Send);
+    assert_impl!(FeedAll<'_, (), SyncStream<()>>: Sync);
+    assert_not_impl!(FeedAll<'_, (), SyncStream>: Sync);
+    assert_not_impl!(FeedAll<'_, (), LocalStream>: Sync);
+    assert_not_impl!(FeedAll<'_, *const (), SyncStream<()>>: Sync);
+    assert_impl!(FeedAll<'_, (), UnpinStream>: Unpin);
+    assert_impl!(FeedAll<'_, PhantomPinned, UnpinStream>: Unpin);
+    assert_not_impl!(FeedAll<'_, (), PinnedStream>: Unpin);
+
+    assert_impl!(FeedTimeout<'_, (), (), ()>: Send);
+    assert_not_impl!(FeedTimeout<'_, *const (), (), ()>: Send);
+    assert_not_impl!(FeedTimeout<'_, (), *const (), ()>: Send);
+    assert_not_impl!(FeedTimeout<'_, (), (), *const ()>: Send);
+    assert_impl!(FeedTimeout<'_, (), (), ()>: Sync);
+    assert_not_impl!(FeedTimeout<'_, *const (), (), ()>: Sync);
+    assert_not_impl!(FeedTimeout<'_, (), *const (), ()>: Sync);
+    assert_not_impl!(FeedTimeout<'_, (), (), *const ()>: Sync);
+    assert_impl!(FeedTimeout<'_, PhantomPinned, PhantomPinned, ()>: Unpin);
+    assert_not_impl!(FeedTimeout<'_, (), (), PhantomPinned>: Unpin);
+    assert_impl!(SendTimeout<'_, (), (), ()>: Send);
+    assert_not_impl!(SendTimeout<'_, *const (), (), ()>: Send);
+    assert_not_impl!(SendTimeout<'_, (), *const (), ()>: Send);
+    assert_not_impl!(SendTimeout<'_, (), (), *const ()>: Send);
+    assert_impl!(SendTimeout<'_, (), (), ()>: Sync);
+    assert_not_impl!(SendTimeout<'_, *const (), (), ()>: Sync);
+    assert_not_impl!(SendTimeout<'_, (), *const (), ()>: Sync);
+    assert_not_impl!(SendTimeout<'_, (), (), *const ()>: Sync);
+    assert_impl!(SendTimeout<'_, PhantomPinned, PhantomPinned, ()>: Unpin);
+    assert_not_impl!(SendTimeout<'_, (), (), PhantomPinned>: Unpin);
+
     assert_impl!(Flush<'_, (), *const ()>: Send);
     assert_not_impl!(Flush<'_, *const (), ()>: Send);
     assert_impl!(Flush<'_, (), *const ()>: Sync);
@@ -1013,6 +1829,18 @@ pub mod sink {
     assert_impl!(Flush<'_, (), PhantomPinned>: Unpin);
     assert_not_impl!(Flush<'_, PhantomPinned, ()>: Unpin);
 
+    assert_impl!(FromFn<(), (), (), ()>: Send);
+    assert_not_impl!(FromFn<*const (), (), (), ()>: Send);
+    assert_not_impl!(FromFn<(), *const (), (), ()>: Send);
+    assert_not_impl!(FromFn<(), (), *const (), ()>: Send);
+    assert_not_impl!(FromFn<(), (), (), *const ()>: Send);
+    assert_impl!(FromFn<(), (), (), ()>: Sync);
+    assert_not_impl!(FromFn<*const (), (), (), ()>: Sync);
+    assert_not_impl!(FromFn<(), *const (), (), ()>: Sync);
+    assert_not_impl!(FromFn<(), (), *const (), ()>: Sync);
+    assert_not_impl!(FromFn<(), (), (), *const ()>: Sync);
+    assert_impl!(FromFn<PhantomPinned, PhantomPinned, PhantomPinned, PhantomPinned>: Unpin);
+
     assert_impl!(sink::Send<'_, (), ()>: Send);
     assert_not_impl!(sink::Send<'_, (), *const ()>: Send);
     assert_not_impl!(sink::Send<'_, *const (), ()>: Send);
@@ -1034,6 +1862,17 @@ pub mod sink {
     assert_impl!(SendAll<'_, PhantomPinned, UnpinTryStream>: Unpin);
     assert_not_impl!(SendAll<'_, (), PinnedTryStream>: Unpin);
 
+    assert_impl!(SendAllConcurrent<'_, (), SendStream<SendTryFuture<(), ()>>>: Send);
+    assert_not_impl!(SendAllConcurrent<'_, *const (), SendStream<SendTryFuture<(), ()>>>: Send);
+    assert_not_impl!(SendAllConcurrent<'_, (), LocalStream<SendTryFuture<(), ()>>>: Send);
+    assert_not_impl!(SendAllConcurrent<'_, (), SendStream<LocalTryFuture<(), ()>>>: Send);
+    assert_not_impl!(SendAllConcurrent<'_, (), SendStream<SendTryFuture<*const (), ()>>>: Send);
+    assert_impl!(SendAllConcurrent<'_, (), SyncStream<SyncTryFuture<(), ()>>>: Sync);
+    assert_not_impl!(SendAllConcurrent<'_, *const (), SyncStream<SyncTryFuture<(), ()>>>: Sync);
+    assert_not_impl!(SendAllConcurrent<'_, (), LocalStream<SyncTryFuture<(), ()>>>: Sync);
+    assert_impl!(SendAllConcurrent<'_, (), UnpinStream<PinnedTryFuture<(), ()>>>: Unpin);
+    assert_not_impl!(SendAllConcurrent<'_, (), PinnedStream<UnpinTryFuture<(), ()>>>: Unpin);
+
     assert_impl!(SinkErrInto<SendSink, *const (), *const ()>: Send);
     assert_not_impl!(SinkErrInto<LocalSink<()>, (), ()>: Send);
     assert_impl!(SinkErrInto<SyncSink, *const (), *const ()>: Sync);
@@ -1041,6 +1880,15 @@ pub mod sink {
     assert_impl!(SinkErrInto<UnpinSink, PhantomPinned, PhantomPinned>: Unpin);
     assert_not_impl!(SinkErrInto<PinnedSink<()>, (), ()>: Unpin);
 
+    assert_impl!(SinkInspect<SendSink, ()>: Send);
+    assert_not_impl!(SinkInspect<SendSink, *const ()>: Send);
+    assert_not_impl!(SinkInspect<LocalSink<()>, ()>: Send);
+    assert_impl!(SinkInspect<SyncSink, ()>: Sync);
+    assert_not_impl!(SinkInspect<SyncSink, *const ()>: Sync);
+    assert_not_impl!(SinkInspect<LocalSink<()>, ()>: Sync);
+    assert_impl!(SinkInspect<UnpinSink, PhantomPinned>: Unpin);
+    assert_not_impl!(SinkInspect<PinnedSink<()>, ()>: Unpin);
+
     assert_impl!(SinkMapErr<SendSink, ()>: Send);
     assert_not_impl!(SinkMapErr<SendSink, *const ()>: Send);
     assert_not_impl!(SinkMapErr<LocalSink<()>, ()>: Send);
@@ -1050,6 +1898,31 @@ pub mod sink {
     assert_impl!(SinkMapErr<UnpinSink, PhantomPinned>: Unpin);
     assert_not_impl!(SinkMapErr<PinnedSink<()>, ()>: Unpin);
 
+    assert_impl!(Throttle<(), (), ()>: Send);
+    assert_not_impl!(Throttle<*const (), (), ()>: Send);
+    assert_not_impl!(Throttle<(), *const (), ()>: Send);
+    assert_not_impl!(Throttle<(), (), *const ()>: Send);
+    assert_impl!(Throttle<(), (), ()>: Sync);
+    assert_not_impl!(Throttle<*const (), (), ()>: Sync);
+    assert_not_impl!(Throttle<(), *const (), ()>: Sync);
+    assert_not_impl!(Throttle<(), (), *const ()>: Sync);
+    assert_impl!(Throttle<(), PhantomPinned, PhantomPinned>: Unpin);
+    assert_not_impl!(Throttle<PhantomPinned, (), ()>: Unpin);
+
+    assert_impl!(TryWithFlatMap<(), (), *const (), (), ()>: Send);
+    assert_not_impl!(TryWithFlatMap<*const (), (), (), (), ()>: Send);
+    assert_not_impl!(TryWithFlatMap<(), *const (), (), (), ()>: Send);
+    assert_not_impl!(TryWithFlatMap<(), (), (), *const (), ()>: Send);
+    assert_not_impl!(TryWithFlatMap<(), (), (), (), *const ()>: Send);
+    assert_impl!(TryWithFlatMap<(), (), *const (), (), ()>: Sync);
+    assert_not_impl!(TryWithFlatMap<*const (), (), (), (), ()>: Sync);
+    assert_not_impl!(TryWithFlatMap<(), *const (), (), (), ()>: Sync);
+    assert_not_impl!(TryWithFlatMap<(), (), (), *const (), ()>: Sync);
+    assert_not_impl!(TryWithFlatMap<(), (), (), (), *const ()>: Sync);
+    assert_impl!(TryWithFlatMap<(), PhantomPinned, PhantomPinned, (), PhantomPinned>: Unpin);
+    assert_not_impl!(TryWithFlatMap<PhantomPinned, (), (), (), ()>: Unpin);
+    assert_not_impl!(TryWithFlatMap<(), (), (), PhantomPinned, ()>: Unpin);
+
     assert_impl!(Unfold<(), (), ()>: Send);
     assert_not_impl!(Unfold<*const (), (), ()>: Send);
     assert_not_impl!(Unfold<(), *const (), ()>: Send);
@@ -1061,6 +1934,27 @@ pub mod sink {
     assert_impl!(Unfold<PhantomPinned, PhantomPinned, ()>: Unpin);
     assert_not_impl!(Unfold<PinnedSink<()>, (), PhantomPinned>: Unpin);
 
+    assert_impl!(UnfoldFull<(), (), (), (), (), (), ()>: Send);
+    assert_not_impl!(UnfoldFull<*const (), (), (), (), (), (), ()>: Send);
+    assert_not_impl!(UnfoldFull<(), *const (), (), (), (), (), ()>: Send);
+    assert_not_impl!(UnfoldFull<(), (), *const (), (), (), (), ()>: Send);
+    assert_not_impl!(UnfoldFull<(), (), (), *const (), (), (), ()>: Send);
+    assert_not_impl!(UnfoldFull<(), (), (), (), *const (), (), ()>: Send);
+    assert_not_impl!(UnfoldFull<(), (), (), (), (), *const (), ()>: Send);
+    assert_not_impl!(UnfoldFull<(), (), (), (), (), (), *const ()>: Send);
+    assert_impl!(UnfoldFull<(), (), (), (), (), (), ()>: Sync);
+    assert_not_impl!(UnfoldFull<*const (), (), (), (), (), (), ()>: Sync);
+    assert_not_impl!(UnfoldFull<(), *const (), (), (), (), (), ()>: Sync);
+    assert_not_impl!(UnfoldFull<(), (), *const (), (), (), (), ()>: Sync);
+    assert_not_impl!(UnfoldFull<(), (), (), *const (), (), (), ()>: Sync);
+    assert_not_impl!(UnfoldFull<(), (), (), (), *const (), (), ()>: Sync);
+    assert_not_impl!(UnfoldFull<(), (), (), (), (), *const (), ()>: Sync);
+    assert_not_impl!(UnfoldFull<(), (), (), (), (), (), *const ()>: Sync);
+    assert_impl!(UnfoldFull<PhantomPinned, PhantomPinned, (), PhantomPinned, (), PhantomPinned, ()>: Unpin);
+    assert_not_impl!(UnfoldFull<(), (), PhantomPinned, (), (), (), ()>: Unpin);
+    assert_not_impl!(UnfoldFull<(), (), (), (), PhantomPinned, (), ()>: Unpin);
+    assert_not_impl!(UnfoldFull<(), (), (), (), (), (), PhantomPinned>: Unpin);
+
     assert_impl!(With<(), *const (), *const (), (), ()>: Send);
     assert_not_impl!(With<*const (), (), (), (), ()>: Send);
     assert_not_impl!(With<(), (), (), *const (), ()>: Send);
@@ -1086,6 +1980,10 @@ pub mod sink {
     assert_impl!(WithFlatMap<(), PhantomPinned, PhantomPinned, (), PhantomPinned>: Unpin);
     assert_not_impl!(WithFlatMap<PhantomPinned, (), (), (), ()>: Unpin);
     assert_not_impl!(WithFlatMap<(), (), (), PhantomPinned, ()>: Unpin);
+
+    assert_impl!(WriteBlocking: Send);
+    assert_impl!(WriteBlocking: Sync);
+    assert_impl!(WriteBlocking: Unpin);
 }
 
 /// Assert Send/Sync/Unpin for all public types in `futures::stream`.
@@ -1105,6 +2003,12 @@ pub mod stream {
     assert_not_impl!(AndThen<PhantomPinned, (), ()>: Unpin);
     assert_not_impl!(AndThen<(), PhantomPinned, ()>: Unpin);
 
+    assert_impl!(BoundedFuturesUnordered<()>: Send);
+    assert_not_impl!(BoundedFuturesUnordered<*const ()>: Send);
+    assert_impl!(BoundedFuturesUnordered<()>: Sync);
+    assert_not_impl!(BoundedFuturesUnordered<*const ()>: Sync);
+    assert_impl!(BoundedFuturesUnordered<PhantomPinned>: Unpin);
+
     assert_impl!(BufferUnordered<SendStream<()>>: Send);
     assert_not_impl!(BufferUnordered<SendStream>: Send);
     assert_not_impl!(BufferUnordered<LocalStream>: Send);
@@ -1114,6 +2018,17 @@ pub mod stream {
     assert_impl!(BufferUnordered<UnpinStream>: Unpin);
     assert_not_impl!(BufferUnordered<PinnedStream>: Unpin);
 
+    assert_impl!(BufferWhile<SendStream<()>, ()>: Send);
+    assert_not_impl!(BufferWhile<SendStream, ()>: Send);
+    assert_not_impl!(BufferWhile<LocalStream<()>, ()>: Send);
+    assert_not_impl!(BufferWhile<SendStream<()>, *const ()>: Send);
+    assert_impl!(BufferWhile<SyncStream<()>, ()>: Sync);
+    assert_not_impl!(BufferWhile<SyncStream, ()>: Sync);
+    assert_not_impl!(BufferWhile<LocalStream<()>, ()>: Sync);
+    assert_not_impl!(BufferWhile<SyncStream<()>, *const ()>: Sync);
+    assert_impl!(BufferWhile<UnpinStream<()>, PhantomPinned>: Unpin);
+    assert_not_impl!(BufferWhile<PinnedStream, ()>: Unpin);
+
     assert_impl!(Buffered<SendStream<SendFuture<()>>>: Send);
     assert_not_impl!(Buffered<SendStream<SendFuture>>: Send);
     assert_not_impl!(Buffered<SendStream<LocalFuture>>: Send);
@@ -1151,6 +2066,15 @@ pub mod stream {
     assert_impl!(Chunks<UnpinStream>: Unpin);
     assert_not_impl!(Chunks<PinnedStream>: Unpin);
 
+    assert_impl!(ChunksExact<SendStream<()>>: Send);
+    assert_not_impl!(ChunksExact<SendStream>: Send);
+    assert_not_impl!(ChunksExact<LocalStream>: Send);
+    assert_impl!(ChunksExact<SyncStream<()>>: Sync);
+    assert_not_impl!(ChunksExact<SyncStream>: Sync);
+    assert_not_impl!(ChunksExact<LocalStream>: Sync);
+    assert_impl!(ChunksExact<UnpinStream>: Unpin);
+    assert_not_impl!(ChunksExact<PinnedStream>: Unpin);
+
     assert_impl!(Collect<(), ()>: Send);
     assert_not_impl!(Collect<*const (), ()>: Send);
     assert_not_impl!(Collect<(), *const ()>: Send);
@@ -1160,6 +2084,15 @@ pub mod stream {
     assert_impl!(Collect<(), PhantomPinned>: Unpin);
     assert_not_impl!(Collect<PhantomPinned, ()>: Unpin);
 
+    assert_impl!(CollectInto<'_, (), ()>: Send);
+    assert_not_impl!(CollectInto<'_, *const (), ()>: Send);
+    assert_not_impl!(CollectInto<'_, (), *const ()>: Send);
+    assert_impl!(CollectInto<'_, (), ()>: Sync);
+    assert_not_impl!(CollectInto<'_, *const (), ()>: Sync);
+    assert_not_impl!(CollectInto<'_, (), *const ()>: Sync);
+    assert_impl!(CollectInto<'_, (), PhantomPinned>: Unpin);
+    assert_not_impl!(CollectInto<'_, PhantomPinned, ()>: Unpin);
+
     assert_impl!(Concat<SendStream<()>>: Send);
     assert_not_impl!(Concat<SendStream>: Send);
     assert_not_impl!(Concat<LocalStream>: Send);
@@ -1176,6 +2109,27 @@ pub mod stream {
     assert_impl!(Cycle<()>: Unpin);
     assert_not_impl!(Cycle<PhantomPinned>: Unpin);
 
+    assert_impl!(DelayItems<SendStream<()>, (), ()>: Send);
+    assert_not_impl!(DelayItems<SendStream, (), ()>: Send);
+    assert_not_impl!(DelayItems<LocalStream<()>, (), ()>: Send);
+    assert_not_impl!(DelayItems<SendStream<()>, *const (), ()>: Send);
+    assert_not_impl!(DelayItems<SendStream<()>, (), *const ()>: Send);
+    assert_impl!(DelayItems<SyncStream<()>, (), ()>: Sync);
+    assert_not_impl!(DelayItems<SyncStream, (), ()>: Sync);
+    assert_not_impl!(DelayItems<LocalStream<()>, (), ()>: Sync);
+    assert_not_impl!(DelayItems<SyncStream<()>, *const (), ()>: Sync);
+    assert_not_impl!(DelayItems<SyncStream<()>, (), *const ()>: Sync);
+    assert_impl!(DelayItems<UnpinStream<()>, PhantomPinned, ()>: Unpin);
+    assert_not_impl!(DelayItems<PinnedStream, (), ()>: Unpin);
+    assert_not_impl!(DelayItems<UnpinStream<()>, (), PhantomPinned>: Unpin);
+
+    assert_impl!(DrainAbortable<SendStream>: Send);
+    assert_not_impl!(DrainAbortable<LocalStream>: Send);
+    assert_impl!(DrainAbortable<SyncStream>: Sync);
+    assert_not_impl!(DrainAbortable<LocalStream>: Sync);
+    assert_impl!(DrainAbortable<UnpinStream>: Unpin);
+    assert_not_impl!(DrainAbortable<PinnedStream>: Unpin);
+
     assert_impl!(Empty<()>: Send);
     assert_not_impl!(Empty<*const ()>: Send);
     assert_impl!(Empty<()>: Sync);
@@ -1196,6 +2150,10 @@ pub mod stream {
     assert_impl!(ErrInto<(), PhantomPinned>: Unpin);
     assert_not_impl!(ErrInto<PhantomPinned, ()>: Unpin);
 
+    assert_impl!(ErrorPolicy: Send);
+    assert_impl!(ErrorPolicy: Sync);
+    assert_impl!(ErrorPolicy: Unpin);
+
     assert_impl!(Filter<SendStream<()>, (), ()>: Send);
     assert_not_impl!(Filter<LocalStream<()>, (), ()>: Send);
     assert_not_impl!(Filter<SendStream, (), ()>: Send);
@@ -1281,6 +2239,19 @@ pub mod stream {
     assert_impl!(ForEachConcurrent<(), PhantomPinned, PhantomPinned>: Unpin);
     assert_not_impl!(ForEachConcurrent<PhantomPinned, (), ()>: Unpin);
 
+    assert_impl!(ForEachConcurrentWithResults<(), SendFuture<()>, ()>: Send);
+    assert_not_impl!(ForEachConcurrentWithResults<*const (), SendFuture<()>, ()>: Send);
+    assert_not_impl!(ForEachConcurrentWithResults<(), SendFuture, ()>: Send);
+    assert_not_impl!(ForEachConcurrentWithResults<(), LocalFuture<()>, ()>: Send);
+    assert_not_impl!(ForEachConcurrentWithResults<(), SendFuture<()>, *const ()>: Send);
+    assert_impl!(ForEachConcurrentWithResults<(), SyncFuture<()>, ()>: Sync);
+    assert_not_impl!(ForEachConcurrentWithResults<*const (), SyncFuture<()>, ()>: Sync);
+    assert_not_impl!(ForEachConcurrentWithResults<(), SyncFuture, ()>: Sync);
+    assert_not_impl!(ForEachConcurrentWithResults<(), LocalFuture<()>, ()>: Sync);
+    assert_not_impl!(ForEachConcurrentWithResults<(), SyncFuture<()>, *const ()>: Sync);
+    assert_impl!(ForEachConcurrentWithResults<(), PinnedFuture<()>, PhantomPinned>: Unpin);
+    assert_not_impl!(ForEachConcurrentWithResults<PhantomPinned, UnpinFuture<()>, ()>: Unpin);
+
     assert_impl!(Forward<SendStream<()>, ()>: Send);
     assert_not_impl!(Forward<SendStream, ()>: Send);
     assert_not_impl!(Forward<SendStream<()>, *const ()>: Send);
@@ -1293,6 +2264,48 @@ pub mod stream {
     assert_not_impl!(Forward<UnpinStream, PhantomPinned>: Unpin);
     assert_not_impl!(Forward<PinnedStream, ()>: Unpin);
 
+    assert_impl!(ForwardFlushEvery<SendStream<()>, ()>: Send);
+    assert_not_impl!(ForwardFlushEvery<SendStream, ()>: Send);
+    assert_not_impl!(ForwardFlushEvery<SendStream<()>, *const ()>: Send);
+    assert_not_impl!(ForwardFlushEvery<LocalStream, ()>: Send);
+    assert_impl!(ForwardFlushEvery<SyncStream<()>, ()>: Sync);
+    assert_not_impl!(ForwardFlushEvery<SyncStream, ()>: Sync);
+    assert_not_impl!(ForwardFlushEvery<SyncStream<()>, *const ()>: Sync);
+    assert_not_impl!(ForwardFlushEvery<LocalStream, ()>: Sync);
+    assert_impl!(ForwardFlushEvery<UnpinStream, ()>: Unpin);
+    assert_not_impl!(ForwardFlushEvery<UnpinStream, PhantomPinned>: Unpin);
+    assert_not_impl!(ForwardFlushEvery<PinnedStream, ()>: Unpin);
+
+    assert_impl!(ForwardFlushInterval<SendStream<()>, (), (), ()>: Send);
+    assert_not_impl!(ForwardFlushInterval<SendStream, (), (), ()>: Send);
+    assert_not_impl!(ForwardFlushInterval<SendStream<()>, *const (), (), ()>: Send);
+    assert_not_impl!(ForwardFlushInterval<SendStream<()>, (), *const (), ()>: Send);
+    assert_not_impl!(ForwardFlushInterval<SendStream<()>, (), (), *const ()>: Send);
+    assert_not_impl!(ForwardFlushInterval<LocalStream, (), (), ()>: Send);
+    assert_impl!(ForwardFlushInterval<SyncStream<()>, (), (), ()>: Sync);
+    assert_not_impl!(ForwardFlushInterval<SyncStream, (), (), ()>: Sync);
+    assert_not_impl!(ForwardFlushInterval<SyncStream<()>, *const (), (), ()>: Sync);
+    assert_not_impl!(ForwardFlushInterval<SyncStream<()>, (), *const (), ()>: Sync);
+    assert_not_impl!(ForwardFlushInterval<SyncStream<()>, (), (), *const ()>: Sync);
+    assert_not_impl!(ForwardFlushInterval<LocalStream, (), (), ()>: Sync);
+    assert_impl!(ForwardFlushInterval<UnpinStream, (), PhantomPinned, ()>: Unpin);
+    assert_not_impl!(ForwardFlushInterval<UnpinStream, PhantomPinned, (), ()>: Unpin);
+    assert_not_impl!(ForwardFlushInterval<UnpinStream, (), (), PhantomPinned>: Unpin);
+    assert_not_impl!(ForwardFlushInterval<PinnedStream, (), (), ()>: Unpin);
+
+    #[cfg(feature = "async-iterator")]
+    assert_impl!(FromAsyncIter<()>: Send);
+    #[cfg(feature = "async-iterator")]
+    assert_not_impl!(FromAsyncIter<*const ()>: Send);
+    #[cfg(feature = "async-iterator")]
+    assert_impl!(FromAsyncIter<()>: Sync);
+    #[cfg(feature = "async-iterator")]
+    assert_not_impl!(FromAsyncIter<*const ()>: Sync);
+    #[cfg(feature = "async-iterator")]
+    assert_impl!(FromAsyncIter<()>: Unpin);
+    #[cfg(feature = "async-iterator")]
+    assert_not_impl!(FromAsyncIter<PhantomPinned>: Unpin);
+
     assert_impl!(TryForward<SendTryStream<()>, ()>: Send);
     assert_not_impl!(TryForward<SendTryStream, ()>: Send);
     assert_not_impl!(TryForward<SendTryStream<()>, *const ()>: Send);
@@ -1312,6 +2325,10 @@ pub mod stream {
     assert_impl!(Fuse<()>: Unpin);
     assert_not_impl!(Fuse<PhantomPinned>: Unpin);
 
+    assert_impl!(FutureToken: Send);
+    assert_impl!(FutureToken: Sync);
+    assert_impl!(FutureToken: Unpin);
+
     assert_impl!(FuturesOrdered<SendFuture<()>>: Send);
     assert_not_impl!(FuturesOrdered<SendFuture>: Send);
     assert_not_impl!(FuturesOrdered<SendFuture>: Send);
@@ -1353,6 +2370,46 @@ pub mod stream {
     assert_impl!(InspectOk<(), PhantomPinned>: Unpin);
     assert_not_impl!(InspectOk<PhantomPinned, ()>: Unpin);
 
+    assert_impl!(InstrumentPolls<()>: Send);
+    assert_not_impl!(InstrumentPolls<*const ()>: Send);
+    assert_impl!(InstrumentPolls<()>: Sync);
+    assert_not_impl!(InstrumentPolls<*const ()>: Sync);
+    assert_impl!(InstrumentPolls<()>: Unpin);
+    assert_not_impl!(InstrumentPolls<PhantomPinned>: Unpin);
+
+    assert_impl!(Intersperse<SendStream<()>>: Send);
+    assert_not_impl!(Intersperse<SendStream>: Send);
+    assert_not_impl!(Intersperse<LocalStream<()>>: Send);
+    assert_impl!(Intersperse<SyncStream<()>>: Sync);
+    assert_not_impl!(Intersperse<SyncStream>: Sync);
+    assert_not_impl!(Intersperse<LocalStream<()>>: Sync);
+    assert_impl!(Intersperse<UnpinStream>: Unpin);
+    assert_not_impl!(Intersperse<PinnedStream>: Unpin);
+
+    assert_impl!(IntersperseWith<SendStream<()>, ()>: Send);
+    assert_not_impl!(IntersperseWith<SendStream, ()>: Send);
+    assert_not_impl!(IntersperseWith<LocalStream<()>, ()>: Send);
+    assert_not_impl!(IntersperseWith<SendStream<()>, *const ()>: Send);
+    assert_impl!(IntersperseWith<SyncStream<()>, ()>: Sync);
+    assert_not_impl!(IntersperseWith<SyncStream, ()>: Sync);
+    assert_not_impl!(IntersperseWith<LocalStream<()>, ()>: Sync);
+    assert_not_impl!(IntersperseWith<SyncStream<()>, *const ()>: Sync);
+    assert_impl!(IntersperseWith<UnpinStream, PhantomPinned>: Unpin);
+    assert_not_impl!(IntersperseWith<PinnedStream, ()>: Unpin);
+
+    #[cfg(feature = "async-iterator")]
+    assert_impl!(IntoAsyncIter<()>: Send);
+    #[cfg(feature = "async-iterator")]
+    assert_not_impl!(IntoAsyncIter<*const ()>: Send);
+    #[cfg(feature = "async-iterator")]
+    assert_impl!(IntoAsyncIter<()>: Sync);
+    #[cfg(feature = "async-iterator")]
+    assert_not_impl!(IntoAsyncIter<*const ()>: Sync);
+    #[cfg(feature = "async-iterator")]
+    assert_impl!(IntoAsyncIter<()>: Unpin);
+    #[cfg(feature = "async-iterator")]
+    assert_not_impl!(IntoAsyncIter<PhantomPinned>: Unpin);
+
     assert_impl!(IntoAsyncRead<SendTryStream<Vec<u8>, io::Error>>: Send);
     assert_not_impl!(IntoAsyncRead<LocalTryStream<Vec<u8>, io::Error>>: Send);
     assert_impl!(IntoAsyncRead<SyncTryStream<Vec<u8>, io::Error>>: Sync);
@@ -1374,6 +2431,18 @@ pub mod stream {
     assert_not_impl!(Iter<*const ()>: Sync);
     assert_impl!(Iter<PhantomPinned>: Unpin);
 
+    assert_impl!(IterBlocking<()>: Send);
+    assert_not_impl!(IterBlocking<*const ()>: Send);
+    assert_impl!(IterBlocking<()>: Sync);
+    assert_not_impl!(IterBlocking<*const ()>: Sync);
+    assert_impl!(IterBlocking<PhantomPinned>: Unpin);
+
+    assert_impl!(KeyedFuturesUnordered<()>: Send);
+    assert_not_impl!(KeyedFuturesUnordered<*const ()>: Send);
+    assert_impl!(KeyedFuturesUnordered<()>: Sync);
+    assert_not_impl!(KeyedFuturesUnordered<*const ()>: Sync);
+    assert_impl!(KeyedFuturesUnordered<PhantomPinned>: Unpin);
+
     assert_impl!(Map<(), ()>: Send);
     assert_not_impl!(Map<*const (), ()>: Send);
     assert_not_impl!(Map<(), *const ()>: Send);
@@ -1383,6 +2452,19 @@ pub mod stream {
     assert_impl!(Map<(), PhantomPinned>: Unpin);
     assert_not_impl!(Map<PhantomPinned, ()>: Unpin);
 
+    assert_impl!(MapConcurrent<(), SendFuture<()>, ()>: Send);
+    assert_not_impl!(MapConcurrent<*const (), SendFuture<()>, ()>: Send);
+    assert_not_impl!(MapConcurrent<(), SendFuture, ()>: Send);
+    assert_not_impl!(MapConcurrent<(), LocalFuture<()>, ()>: Send);
+    assert_not_impl!(MapConcurrent<(), SendFuture<()>, *const ()>: Send);
+    assert_impl!(MapConcurrent<(), SyncFuture<()>, ()>: Sync);
+    assert_not_impl!(MapConcurrent<*const (), SyncFuture<()>, ()>: Sync);
+    assert_not_impl!(MapConcurrent<(), SyncFuture, ()>: Sync);
+    assert_not_impl!(MapConcurrent<(), LocalFuture<()>, ()>: Sync);
+    assert_not_impl!(MapConcurrent<(), SyncFuture<()>, *const ()>: Sync);
+    assert_impl!(MapConcurrent<(), PinnedFuture<()>, PhantomPinned>: Unpin);
+    assert_not_impl!(MapConcurrent<PhantomPinned, UnpinFuture<()>, ()>: Unpin);
+
     assert_impl!(MapErr<(), ()>: Send);
     assert_not_impl!(MapErr<*const (), ()>: Send);
     assert_not_impl!(MapErr<(), *const ()>: Send);
@@ -1401,6 +2483,65 @@ pub mod stream {
     assert_impl!(MapOk<(), PhantomPinned>: Unpin);
     assert_not_impl!(MapOk<PhantomPinned, ()>: Unpin);
 
+    assert_impl!(MaxBy<SendStream<()>, ()>: Send);
+    assert_not_impl!(MaxBy<SendStream, ()>: Send);
+    assert_not_impl!(MaxBy<LocalStream<()>, ()>: Send);
+    assert_not_impl!(MaxBy<SendStream<()>, *const ()>: Send);
+    assert_impl!(MaxBy<SyncStream<()>, ()>: Sync);
+    assert_not_impl!(MaxBy<SyncStream, ()>: Sync);
+    assert_not_impl!(MaxBy<LocalStream<()>, ()>: Sync);
+    assert_not_impl!(MaxBy<SyncStream<()>, *const ()>: Sync);
+    assert_impl!(MaxBy<UnpinStream, PhantomPinned>: Unpin);
+    assert_not_impl!(MaxBy<PinnedStream, ()>: Unpin);
+
+    assert_impl!(MaxByKey<SendStream<()>, (), ()>: Send);
+    assert_not_impl!(MaxByKey<SendStream, (), ()>: Send);
+    assert_not_impl!(MaxByKey<SendStream<()>, *const (), ()>: Send);
+    assert_not_impl!(MaxByKey<SendStream<()>, (), *const ()>: Send);
+    assert_not_impl!(MaxByKey<LocalStream<()>, (), ()>: Send);
+    assert_impl!(MaxByKey<SyncStream<()>, (), ()>: Sync);
+    assert_not_impl!(MaxByKey<SyncStream, (), ()>: Sync);
+    assert_not_impl!(MaxByKey<SyncStream<()>, *const (), ()>: Sync);
+    assert_not_impl!(MaxByKey<SyncStream<()>, (), *const ()>: Sync);
+    assert_not_impl!(MaxByKey<LocalStream<()>, (), ()>: Sync);
+    assert_impl!(MaxByKey<UnpinStream, PhantomPinned, PhantomPinned>: Unpin);
+    assert_not_impl!(MaxByKey<PinnedStream, (), ()>: Unpin);
+
+    assert_impl!(Metered<()>: Send);
+    assert_not_impl!(Metered<*const ()>: Send);
+    assert_impl!(Metered<()>: Sync);
+    assert_not_impl!(Metered<*const ()>: Sync);
+    assert_impl!(Metered<()>: Unpin);
+    assert_not_impl!(Metered<PhantomPinned>: Unpin);
+
+    assert_impl!(Metrics: Send);
+    assert_impl!(Metrics: Sync);
+    assert_impl!(Metrics: Unpin);
+
+    assert_impl!(MinBy<SendStream<()>, ()>: Send);
+    assert_not_impl!(MinBy<SendStream, ()>: Send);
+    assert_not_impl!(MinBy<LocalStream<()>, ()>: Send);
+    assert_not_impl!(MinBy<SendStream<()>, *const ()>: Send);
+    assert_impl!(MinBy<SyncStream<()>, ()>: Sync);
+    assert_not_impl!(MinBy<SyncStream, ()>: Sync);
+    assert_not_impl!(MinBy<LocalStream<()>, ()>: Sync);
+    assert_not_impl!(MinBy<SyncStream<()>, *const ()>: Sync);
+    assert_impl!(MinBy<UnpinStream, PhantomPinned>: Unpin);
+    assert_not_impl!(MinBy<PinnedStream, ()>: Unpin);
+
+    assert_impl!(MinByKey<SendStream<()>, (), ()>: Send);
+    assert_not_impl!(MinByKey<SendStream, (), ()>: Send);
+    assert_not_impl!(MinByKey<SendStream<()>, *const (), ()>: Send);
+    assert_not_impl!(MinByKey<SendStream<()>, (), *const ()>: Send);
+    assert_not_impl!(MinByKey<LocalStream<()>, (), ()>: Send);
+    assert_impl!(MinByKey<SyncStream<()>, (), ()>: Sync);
+    assert_not_impl!(MinByKey<SyncStream, (), ()>: Sync);
+    assert_not_impl!(MinByKey<SyncStream<()>, *const (), ()>: Sync);
+    assert_not_impl!(MinByKey<SyncStream<()>, (), *const ()>: Sync);
+    assert_not_impl!(MinByKey<LocalStream<()>, (), ()>: Sync);
+    assert_impl!(MinByKey<UnpinStream, PhantomPinned, PhantomPinned>: Unpin);
+    assert_not_impl!(MinByKey<PinnedStream, (), ()>: Unpin);
+
     assert_impl!(Next<'_, ()>: Send);
     assert_not_impl!(Next<'_, *const ()>: Send);
     assert_impl!(Next<'_, ()>: Sync);
@@ -1447,6 +2588,18 @@ pub mod stream {
     assert_not_impl!(OrElse<PhantomPinned, (), ()>: Unpin);
     assert_not_impl!(OrElse<(), PhantomPinned, ()>: Unpin);
 
+    assert_impl!(OrElseStream<(), (), ()>: Send);
+    assert_not_impl!(OrElseStream<*const (), (), ()>: Send);
+    assert_not_impl!(OrElseStream<(), *const (), ()>: Send);
+    assert_not_impl!(OrElseStream<(), (), *const ()>: Send);
+    assert_impl!(OrElseStream<(), (), ()>: Sync);
+    assert_not_impl!(OrElseStream<*const (), (), ()>: Sync);
+    assert_not_impl!(OrElseStream<(), *const (), ()>: Sync);
+    assert_not_impl!(OrElseStream<(), (), *const ()>: Sync);
+    assert_impl!(OrElseStream<(), (), PhantomPinned>: Unpin);
+    assert_not_impl!(OrElseStream<PhantomPinned, (), ()>: Unpin);
+    assert_not_impl!(OrElseStream<(), PhantomPinned, ()>: Unpin);
+
     assert_impl!(Peek<'_, SendStream<()>>: Send);
     assert_not_impl!(Peek<'_, SendStream>: Send);
     assert_not_impl!(Peek<'_, LocalStream<()>>: Send);
@@ -1491,6 +2644,34 @@ pub mod stream {
     assert_impl!(PollImmediate<UnpinStream>: Unpin);
     assert_not_impl!(PollImmediate<PinnedStream>: Unpin);
 
+    assert_impl!(PollStats: Send);
+    assert_impl!(PollStats: Sync);
+    assert_impl!(PollStats: Unpin);
+
+    assert_impl!(Position<(), (), ()>: Send);
+    assert_not_impl!(Position<*const (), (), ()>: Send);
+    assert_not_impl!(Position<(), *const (), ()>: Send);
+    assert_not_impl!(Position<(), (), *const ()>: Send);
+    assert_impl!(Position<(), (), ()>: Sync);
+    assert_not_impl!(Position<*const (), (), ()>: Sync);
+    assert_not_impl!(Position<(), *const (), ()>: Sync);
+    assert_not_impl!(Position<(), (), *const ()>: Sync);
+    assert_impl!(Position<(), (), PhantomPinned>: Unpin);
+    assert_not_impl!(Position<PhantomPinned, (), ()>: Unpin);
+    assert_not_impl!(Position<(), PhantomPinned, ()>: Unpin);
+
+    assert_impl!(PrioritySelectAll<SendStream<()>>: Send);
+    assert_not_impl!(PrioritySelectAll<SendStream>: Send);
+    assert_not_impl!(PrioritySelectAll<LocalStream<()>>: Send);
+    assert_impl!(PrioritySelectAll<SyncStream<()>>: Sync);
+    assert_not_impl!(PrioritySelectAll<SyncStream>: Sync);
+    assert_not_impl!(PrioritySelectAll<LocalStream<()>>: Sync);
+    assert_impl!(PrioritySelectAll<PinnedStream>: Unpin);
+
+    assert_impl!(PriorityStrategy: Send);
+    assert_impl!(PriorityStrategy: Sync);
+    assert_impl!(PriorityStrategy: Unpin);
+
     assert_impl!(ReadyChunks<SendStream<()>>: Send);
     assert_not_impl!(ReadyChunks<SendStream>: Send);
     assert_not_impl!(ReadyChunks<LocalStream>: Send);
@@ -1500,6 +2681,34 @@ pub mod stream {
     assert_impl!(ReadyChunks<UnpinStream>: Unpin);
     assert_not_impl!(ReadyChunks<PinnedStream>: Unpin);
 
+    assert_impl!(ReadyChunksTimeout<SendStream<()>, (), ()>: Send);
+    assert_not_impl!(ReadyChunksTimeout<SendStream, (), ()>: Send);
+    assert_not_impl!(ReadyChunksTimeout<SendStream<()>, *const (), ()>: Send);
+    assert_not_impl!(ReadyChunksTimeout<SendStream<()>, (), *const ()>: Send);
+    assert_not_impl!(ReadyChunksTimeout<LocalStream, (), ()>: Send);
+    assert_impl!(ReadyChunksTimeout<SyncStream<()>, (), ()>: Sync);
+    assert_not_impl!(ReadyChunksTimeout<SyncStream, (), ()>: Sync);
+    assert_not_impl!(ReadyChunksTimeout<SyncStream<()>, *const (), ()>: Sync);
+    assert_not_impl!(ReadyChunksTimeout<SyncStream<()>, (), *const ()>: Sync);
+    assert_not_impl!(ReadyChunksTimeout<LocalStream, (), ()>: Sync);
+    assert_impl!(ReadyChunksTimeout<UnpinStream, PhantomPinned, ()>: Unpin);
+    assert_not_impl!(ReadyChunksTimeout<PinnedStream, (), ()>: Unpin);
+    assert_not_impl!(ReadyChunksTimeout<UnpinStream, (), PhantomPinned>: Unpin);
+
+    assert_impl!(Reduce<SendStream<()>, (), ()>: Send);
+    assert_not_impl!(Reduce<SendStream, (), ()>: Send);
+    assert_not_impl!(Reduce<SendStream<()>, *const (), ()>: Send);
+    assert_not_impl!(Reduce<SendStream<()>, (), *const ()>: Send);
+    assert_not_impl!(Reduce<LocalStream<()>, (), ()>: Send);
+    assert_impl!(Reduce<SyncStream<()>, (), ()>: Sync);
+    assert_not_impl!(Reduce<SyncStream, (), ()>: Sync);
+    assert_not_impl!(Reduce<SyncStream<()>, *const (), ()>: Sync);
+    assert_not_impl!(Reduce<SyncStream<()>, (), *const ()>: Sync);
+    assert_not_impl!(Reduce<LocalStream<()>, (), ()>: Sync);
+    assert_impl!(Reduce<UnpinStream, (), PhantomPinned>: Unpin);
+    assert_not_impl!(Reduce<PinnedStream, (), ()>: Unpin);
+    assert_not_impl!(Reduce<UnpinStream, PhantomPinned, ()>: Unpin);
+
     assert_impl!(Repeat<()>: Send);
     assert_not_impl!(Repeat<*const ()>: Send);
     assert_impl!(Repeat<()>: Sync);
@@ -1514,6 +2723,15 @@ pub mod stream {
     assert_impl!(RepeatWith<fn() -> ()>: Unpin);
     // assert_impl!(RepeatWith<PhantomPinned>: Unpin);
 
+    assert_impl!(RepeatWithAsync<(), ()>: Send);
+    assert_not_impl!(RepeatWithAsync<*const (), ()>: Send);
+    assert_not_impl!(RepeatWithAsync<(), *const ()>: Send);
+    assert_impl!(RepeatWithAsync<(), ()>: Sync);
+    assert_not_impl!(RepeatWithAsync<*const (), ()>: Sync);
+    assert_not_impl!(RepeatWithAsync<(), *const ()>: Sync);
+    assert_impl!(RepeatWithAsync<PhantomPinned, ()>: Unpin);
+    assert_not_impl!(RepeatWithAsync<(), PhantomPinned>: Unpin);
+
     assert_impl!(ReuniteError<(), ()>: Send);
     assert_not_impl!(ReuniteError<*const (), ()>: Send);
     assert_not_impl!(ReuniteError<(), *const ()>: Send);
@@ -1522,6 +2740,20 @@ pub mod stream {
     assert_not_impl!(ReuniteError<(), *const ()>: Sync);
     assert_impl!(ReuniteError<PhantomPinned, PhantomPinned>: Unpin);
 
+    assert_impl!(Sample<SendStream<()>, (), ()>: Send);
+    assert_not_impl!(Sample<SendStream, (), ()>: Send);
+    assert_not_impl!(Sample<LocalStream<()>, (), ()>: Send);
+    assert_not_impl!(Sample<SendStream<()>, *const (), ()>: Send);
+    assert_not_impl!(Sample<SendStream<()>, (), *const ()>: Send);
+    assert_impl!(Sample<SyncStream<()>, (), ()>: Sync);
+    assert_not_impl!(Sample<SyncStream, (), ()>: Sync);
+    assert_not_impl!(Sample<LocalStream<()>, (), ()>: Sync);
+    assert_not_impl!(Sample<SyncStream<()>, *const (), ()>: Sync);
+    assert_not_impl!(Sample<SyncStream<()>, (), *const ()>: Sync);
+    assert_impl!(Sample<UnpinStream<()>, PhantomPinned, ()>: Unpin);
+    assert_not_impl!(Sample<PinnedStream, (), ()>: Unpin);
+    assert_not_impl!(Sample<UnpinStream<()>, (), PhantomPinned>: Unpin);
+
     assert_impl!(Scan<SendStream, (), (), ()>: Send);
     assert_not_impl!(Scan<LocalStream<()>, (), (), ()>: Send);
     assert_not_impl!(Scan<SendStream<()>, *const (), (), ()>: Send);
@@ -1579,6 +2811,15 @@ pub mod stream {
     assert_not_impl!(SkipWhile<PinnedStream, (), ()>: Unpin);
     assert_not_impl!(SkipWhile<UnpinStream, PhantomPinned, ()>: Unpin);
 
+    assert_impl!(SplitAt<SendStream<()>>: Send);
+    assert_not_impl!(SplitAt<SendStream>: Send);
+    assert_not_impl!(SplitAt<LocalStream<()>>: Send);
+    assert_impl!(SplitAt<SyncStream<()>>: Sync);
+    assert_not_impl!(SplitAt<SyncStream>: Sync);
+    assert_not_impl!(SplitAt<LocalStream<()>>: Sync);
+    assert_impl!(SplitAt<UnpinStream<()>>: Unpin);
+    assert_not_impl!(SplitAt<PinnedStream<()>>: Unpin);
+
     assert_impl!(SplitSink<(), ()>: Send);
     assert_not_impl!(SplitSink<*const (), ()>: Send);
     assert_not_impl!(SplitSink<(), *const ()>: Send);
@@ -1593,6 +2834,15 @@ pub mod stream {
     assert_not_impl!(SplitStream<*const ()>: Sync);
     assert_impl!(SplitStream<PhantomPinned>: Unpin);
 
+    assert_impl!(StartWith<(), ()>: Send);
+    assert_not_impl!(StartWith<*const (), ()>: Send);
+    assert_not_impl!(StartWith<(), *const ()>: Send);
+    assert_impl!(StartWith<(), ()>: Sync);
+    assert_not_impl!(StartWith<*const (), ()>: Sync);
+    assert_not_impl!(StartWith<(), *const ()>: Sync);
+    assert_impl!(StartWith<(), PhantomPinned>: Unpin);
+    assert_not_impl!(StartWith<PhantomPinned, ()>: Unpin);
+
     assert_impl!(StreamFuture<()>: Send);
     assert_not_impl!(StreamFuture<*const ()>: Send);
     assert_impl!(StreamFuture<()>: Sync);
@@ -1600,6 +2850,24 @@ pub mod stream {
     assert_impl!(StreamFuture<()>: Unpin);
     assert_not_impl!(StreamFuture<PhantomPinned>: Unpin);
 
+    assert_impl!(StreamGroup<(), ()>: Send);
+    assert_not_impl!(StreamGroup<*const (), ()>: Send);
+    assert_not_impl!(StreamGroup<(), *const ()>: Send);
+    assert_impl!(StreamGroup<(), ()>: Sync);
+    assert_not_impl!(StreamGroup<*const (), ()>: Sync);
+    assert_not_impl!(StreamGroup<(), *const ()>: Sync);
+    assert_impl!(StreamGroup<PhantomPinned, PhantomPinned>: Unpin);
+
+    assert_impl!(StreamGroupEvent<(), ()>: Send);
+    assert_not_impl!(StreamGroupEvent<*const (), ()>: Send);
+    assert_not_impl!(StreamGroupEvent<(), *const ()>: Send);
+    assert_impl!(StreamGroupEvent<(), ()>: Sync);
+    assert_not_impl!(StreamGroupEvent<*const (), ()>: Sync);
+    assert_not_impl!(StreamGroupEvent<(), *const ()>: Sync);
+    assert_impl!(StreamGroupEvent<(), ()>: Unpin);
+    assert_not_impl!(StreamGroupEvent<PhantomPinned, ()>: Unpin);
+    assert_not_impl!(StreamGroupEvent<(), PhantomPinned>: Unpin);
+
     assert_impl!(Take<()>: Send);
     assert_not_impl!(Take<*const ()>: Send);
     assert_impl!(Take<()>: Sync);
@@ -1676,6 +2944,15 @@ pub mod stream {
     assert_impl!(TryCollect<(), PhantomPinned>: Unpin);
     assert_not_impl!(TryCollect<PhantomPinned, ()>: Unpin);
 
+    assert_impl!(TryCollectInto<'_, (), ()>: Send);
+    assert_not_impl!(TryCollectInto<'_, *const (), ()>: Send);
+    assert_not_impl!(TryCollectInto<'_, (), *const ()>: Send);
+    assert_impl!(TryCollectInto<'_, (), ()>: Sync);
+    assert_not_impl!(TryCollectInto<'_, *const (), ()>: Sync);
+    assert_not_impl!(TryCollectInto<'_, (), *const ()>: Sync);
+    assert_impl!(TryCollectInto<'_, (), PhantomPinned>: Unpin);
+    assert_not_impl!(TryCollectInto<'_, PhantomPinned, ()>: Unpin);
+
     assert_impl!(TryConcat<SendTryStream<()>>: Send);
     assert_not_impl!(TryConcat<SendTryStream>: Send);
     assert_not_impl!(TryConcat<LocalTryStream>: Send);
@@ -1758,6 +3035,34 @@ pub mod stream {
     assert_impl!(TryForEachConcurrent<(), PhantomPinned, PhantomPinned>: Unpin);
     assert_not_impl!(TryForEachConcurrent<PhantomPinned, (), ()>: Unpin);
 
+    assert_impl!(TryForEachConcurrentWithPolicy<(), SendTryFuture<(), ()>, ()>: Send);
+    assert_not_impl!(TryForEachConcurrentWithPolicy<*const (), SendTryFuture<(), ()>, ()>: Send);
+    assert_not_impl!(TryForEachConcurrentWithPolicy<(), SendTryFuture<(), *const ()>, ()>: Send);
+    assert_not_impl!(TryForEachConcurrentWithPolicy<(), LocalTryFuture<(), ()>, ()>: Send);
+    assert_not_impl!(TryForEachConcurrentWithPolicy<(), SendTryFuture<(), ()>, *const ()>: Send);
+    assert_impl!(TryForEachConcurrentWithPolicy<(), SyncTryFuture<(), ()>, ()>: Sync);
+    assert_not_impl!(TryForEachConcurrentWithPolicy<*const (), SyncTryFuture<(), ()>, ()>: Sync);
+    assert_not_impl!(TryForEachConcurrentWithPolicy<(), SyncTryFuture<(), *const ()>, ()>: Sync);
+    assert_not_impl!(TryForEachConcurrentWithPolicy<(), LocalTryFuture<(), ()>, ()>: Sync);
+    assert_not_impl!(TryForEachConcurrentWithPolicy<(), SyncTryFuture<(), ()>, *const ()>: Sync);
+    assert_impl!(TryForEachConcurrentWithPolicy<(), PinnedTryFuture<(), ()>, PhantomPinned>: Unpin);
+    assert_not_impl!(TryForEachConcurrentWithPolicy<PhantomPinned, UnpinTryFuture<(), ()>, ()>: Unpin);
+
+    assert_impl!(TryMapConcurrent<(), SendTryFuture<(), ()>, ()>: Send);
+    assert_not_impl!(TryMapConcurrent<*const (), SendTryFuture<(), ()>, ()>: Send);
+    assert_not_impl!(TryMapConcurrent<(), SendTryFuture<*const (), ()>, ()>: Send);
+    assert_not_impl!(TryMapConcurrent<(), SendTryFuture<(), *const ()>, ()>: Send);
+    assert_not_impl!(TryMapConcurrent<(), LocalTryFuture<(), ()>, ()>: Send);
+    assert_not_impl!(TryMapConcurrent<(), SendTryFuture<(), ()>, *const ()>: Send);
+    assert_impl!(TryMapConcurrent<(), SyncTryFuture<(), ()>, ()>: Sync);
+    assert_not_impl!(TryMapConcurrent<*const (), SyncTryFuture<(), ()>, ()>: Sync);
+    assert_not_impl!(TryMapConcurrent<(), SyncTryFuture<*const (), ()>, ()>: Sync);
+    assert_not_impl!(TryMapConcurrent<(), SyncTryFuture<(), *const ()>, ()>: Sync);
+    assert_not_impl!(TryMapConcurrent<(), LocalTryFuture<(), ()>, ()>: Sync);
+    assert_not_impl!(TryMapConcurrent<(), SyncTryFuture<(), ()>, *const ()>: Sync);
+    assert_impl!(TryMapConcurrent<(), PinnedTryFuture<(), ()>, PhantomPinned>: Unpin);
+    assert_not_impl!(TryMapConcurrent<PhantomPinned, UnpinTryFuture<(), ()>, ()>: Unpin);
+
     assert_impl!(TryNext<'_, ()>: Send);
     assert_not_impl!(TryNext<'_, *const ()>: Send);
     assert_impl!(TryNext<'_, ()>: Sync);
@@ -1779,6 +3084,15 @@ pub mod stream {
     assert_not_impl!(TrySkipWhile<PinnedTryStream, (), ()>: Unpin);
     assert_not_impl!(TrySkipWhile<UnpinTryStream, PhantomPinned, ()>: Unpin);
 
+    assert_impl!(TryStartWith<(), ()>: Send);
+    assert_not_impl!(TryStartWith<*const (), ()>: Send);
+    assert_not_impl!(TryStartWith<(), *const ()>: Send);
+    assert_impl!(TryStartWith<(), ()>: Sync);
+    assert_not_impl!(TryStartWith<*const (), ()>: Sync);
+    assert_not_impl!(TryStartWith<(), *const ()>: Sync);
+    assert_impl!(TryStartWith<(), PhantomPinned>: Unpin);
+    assert_not_impl!(TryStartWith<PhantomPinned, ()>: Unpin);
+
     assert_impl!(TryTakeWhile<SendTryStream<()>, (), ()>: Send);
     assert_not_impl!(TryTakeWhile<LocalTryStream<()>, (), ()>: Send);
     assert_not_impl!(TryTakeWhile<SendTryStream, (), ()>: Send);
@@ -1815,6 +3129,32 @@ pub mod stream {
     assert_impl!(Unfold<PhantomPinned, PhantomPinned, ()>: Unpin);
     assert_not_impl!(Unfold<(), (), PhantomPinned>: Unpin);
 
+    assert_impl!(UnfoldFinal<(), (), (), ()>: Send);
+    assert_not_impl!(UnfoldFinal<*const (), (), (), ()>: Send);
+    assert_not_impl!(UnfoldFinal<(), *const (), (), ()>: Send);
+    assert_not_impl!(UnfoldFinal<(), (), *const (), ()>: Send);
+    assert_not_impl!(UnfoldFinal<(), (), (), *const ()>: Send);
+    assert_impl!(UnfoldFinal<(), (), (), ()>: Sync);
+    assert_not_impl!(UnfoldFinal<*const (), (), (), ()>: Sync);
+    assert_not_impl!(UnfoldFinal<(), *const (), (), ()>: Sync);
+    assert_not_impl!(UnfoldFinal<(), (), *const (), ()>: Sync);
+    assert_not_impl!(UnfoldFinal<(), (), (), *const ()>: Sync);
+    assert_impl!(UnfoldFinal<PhantomPinned, PhantomPinned, (), PhantomPinned>: Unpin);
+    assert_not_impl!(UnfoldFinal<(), (), PhantomPinned, ()>: Unpin);
+
+    assert_impl!(UnfoldStep<(), (), ()>: Send);
+    assert_not_impl!(UnfoldStep<*const (), (), ()>: Send);
+    assert_not_impl!(UnfoldStep<(), *const (), ()>: Send);
+    assert_not_impl!(UnfoldStep<(), (), *const ()>: Send);
+    assert_impl!(UnfoldStep<(), (), ()>: Sync);
+    assert_not_impl!(UnfoldStep<*const (), (), ()>: Sync);
+    assert_not_impl!(UnfoldStep<(), *const (), ()>: Sync);
+    assert_not_impl!(UnfoldStep<(), (), *const ()>: Sync);
+    assert_impl!(UnfoldStep<(), (), ()>: Unpin);
+    assert_not_impl!(UnfoldStep<PhantomPinned, (), ()>: Unpin);
+    assert_not_impl!(UnfoldStep<(), PhantomPinned, ()>: Unpin);
+    assert_not_impl!(UnfoldStep<(), (), PhantomPinned>: Unpin);
+
     assert_impl!(Unzip<(), (), ()>: Send);
     assert_not_impl!(Unzip<*const (), (), ()>: Send);
     assert_not_impl!(Unzip<(), *const (), ()>: Send);
@@ -1826,6 +3166,18 @@ pub mod stream {
     assert_impl!(Unzip<(), PhantomPinned, PhantomPinned>: Unpin);
     assert_not_impl!(Unzip<PhantomPinned, (), ()>: Unpin);
 
+    assert_impl!(WithLatestFrom<SendStream<()>, SendStream<()>>: Send);
+    assert_not_impl!(WithLatestFrom<SendStream<()>, SendStream>: Send);
+    assert_not_impl!(WithLatestFrom<LocalStream<()>, SendStream<()>>: Send);
+    assert_not_impl!(WithLatestFrom<SendStream<()>, LocalStream<()>>: Send);
+    assert_impl!(WithLatestFrom<SyncStream<()>, SyncStream<()>>: Sync);
+    assert_not_impl!(WithLatestFrom<SyncStream<()>, SyncStream>: Sync);
+    assert_not_impl!(WithLatestFrom<LocalStream<()>, SyncStream<()>>: Sync);
+    assert_not_impl!(WithLatestFrom<SyncStream<()>, LocalStream<()>>: Sync);
+    assert_impl!(WithLatestFrom<UnpinStream, UnpinStream>: Unpin);
+    assert_not_impl!(WithLatestFrom<UnpinStream, PinnedStream>: Unpin);
+    assert_not_impl!(WithLatestFrom<PinnedStream, UnpinStream>: Unpin);
+
     assert_impl!(Zip<SendStream<()>, SendStream<()>>: Send);
     assert_not_impl!(Zip<SendStream, SendStream<()>>: Send);
     assert_not_impl!(Zip<SendStream<()>, SendStream>: Send);
@@ -1840,6 +3192,22 @@ pub mod stream {
     assert_not_impl!(Zip<UnpinStream, PinnedStream>: Unpin);
     assert_not_impl!(Zip<PinnedStream, UnpinStream>: Unpin);
 
+    assert_impl!(ZipWith<SendStream<()>, SendStream<()>, ()>: Send);
+    assert_not_impl!(ZipWith<SendStream, SendStream<()>, ()>: Send);
+    assert_not_impl!(ZipWith<SendStream<()>, SendStream, ()>: Send);
+    assert_not_impl!(ZipWith<LocalStream, SendStream<()>, ()>: Send);
+    assert_not_impl!(ZipWith<SendStream<()>, LocalStream, ()>: Send);
+    assert_not_impl!(ZipWith<SendStream<()>, SendStream<()>, *const ()>: Send);
+    assert_impl!(ZipWith<SyncStream<()>, SyncStream<()>, ()>: Sync);
+    assert_not_impl!(ZipWith<SyncStream, SyncStream<()>, ()>: Sync);
+    assert_not_impl!(ZipWith<SyncStream<()>, SyncStream, ()>: Sync);
+    assert_not_impl!(ZipWith<LocalStream, SyncStream<()>, ()>: Sync);
+    assert_not_impl!(ZipWith<SyncStream<()>, LocalStream, ()>: Sync);
+    assert_not_impl!(ZipWith<SyncStream<()>, SyncStream<()>, *const ()>: Sync);
+    assert_impl!(ZipWith<UnpinStream, UnpinStream, PhantomPinned>: Unpin);
+    assert_not_impl!(ZipWith<UnpinStream, PinnedStream, ()>: Unpin);
+    assert_not_impl!(ZipWith<PinnedStream, UnpinStream, ()>: Unpin);
+
     assert_impl!(futures_unordered::Iter<()>: Send);
     assert_not_impl!(futures_unordered::Iter<*const ()>: Send);
     assert_impl!(futures_unordered::Iter<()>: Sync);
@@ -1868,6 +3236,14 @@ pub mod stream {
     assert_not_impl!(futures_unordered::IterPinRef<*const ()>: Sync);
     assert_impl!(futures_unordered::IterPinRef<PhantomPinned>: Unpin);
 
+    assert_impl!(futures_unordered::Drain<'_, ()>: Send);
+    assert_not_impl!(futures_unordered::Drain<'_, *const ()>: Send);
+    assert_impl!(futures_unordered::Drain<'_, ()>: Sync);
+    assert_not_impl!(futures_unordered::Drain<'_, *const ()>: Sync);
+    assert_impl!(futures_unordered::Drain<'_, ()>: Unpin);
+    // The definition of futures_unordered::Drain has `Fut: Unpin` bounds.
+    // assert_not_impl!(futures_unordered::Drain<PhantomPinned>: Unpin);
+
     assert_impl!(futures_unordered::IntoIter<()>: Send);
     assert_not_impl!(futures_unordered::IntoIter<*const ()>: Send);
     assert_impl!(futures_unordered::IntoIter<()>: Sync);
@@ -1881,10 +3257,29 @@ pub mod task {
     use super::*;
     use futures::task::*;
 
+    assert_impl!(AccessError: Send);
+    assert_impl!(AccessError: Sync);
+    assert_impl!(AccessError: Unpin);
+
     assert_impl!(AtomicWaker: Send);
     assert_impl!(AtomicWaker: Sync);
     assert_impl!(AtomicWaker: Unpin);
 
+    assert_impl!(coop::Budget<SendFuture>: Send);
+    assert_not_impl!(coop::Budget<LocalFuture>: Send);
+    assert_impl!(coop::Budget<SyncFuture>: Sync);
+    assert_not_impl!(coop::Budget<LocalFuture>: Sync);
+    assert_impl!(coop::Budget<UnpinFuture>: Unpin);
+    assert_not_impl!(coop::Budget<PinnedFuture>: Unpin);
+
+    assert_impl!(coop::ConsumeBudget: Send);
+    assert_impl!(coop::ConsumeBudget: Sync);
+    assert_impl!(coop::ConsumeBudget: Unpin);
+
+    assert_impl!(CoalescingWaker: Send);
+    assert_impl!(CoalescingWaker: Sync);
+    assert_impl!(CoalescingWaker: Unpin);
+
     assert_impl!(FutureObj<*const ()>: Send);
     assert_not_impl!(FutureObj<()>: Sync);
     assert_impl!(FutureObj<PhantomPinned>: Unpin);
@@ -1893,11 +3288,30 @@ pub mod task {
     assert_not_impl!(LocalFutureObj<()>: Sync);
     assert_impl!(LocalFutureObj<PhantomPinned>: Unpin);
 
+    assert_impl!(LocalKey<*const ()>: Send);
+    assert_impl!(LocalKey<*const ()>: Sync);
+    assert_impl!(LocalKey<PhantomPinned>: Unpin);
+
     assert_impl!(SpawnError: Send);
     assert_impl!(SpawnError: Sync);
     assert_impl!(SpawnError: Unpin);
 
+    assert_impl!(TrySpawnError<()>: Send);
+    assert_not_impl!(TrySpawnError<*const ()>: Send);
+    assert_impl!(TrySpawnError<()>: Sync);
+    assert_not_impl!(TrySpawnError<*const ()>: Sync);
+    assert_impl!(TrySpawnError<()>: Unpin);
+    assert_not_impl!(TrySpawnError<PhantomPinned>: Unpin);
+
+    assert_impl!(WakerKey: Send);
+    assert_impl!(WakerKey: Sync);
+    assert_impl!(WakerKey: Unpin);
+
     assert_impl!(WakerRef<'_>: Send);
     assert_impl!(WakerRef<'_>: Sync);
     assert_impl!(WakerRef<'_>: Unpin);
+
+    assert_impl!(WakerSet: Send);
+    assert_impl!(WakerSet: Sync);
+    assert_impl!(WakerSet: Unpin);
 }
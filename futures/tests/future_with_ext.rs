@@ -0,0 +1,54 @@
+#![cfg(feature = "context-ext")]
+#![feature(context_ext)]
+
+use futures::executor::block_on;
+use futures::future::{self, FutureExt};
+use futures::stream::{FuturesUnordered, StreamExt};
+use futures::task::{Context, Poll};
+
+#[derive(Debug, PartialEq)]
+struct Id(u32);
+
+fn id(cx: &mut Context<'_>) -> Option<u32> {
+    cx.ext().downcast_ref::<Id>().map(|id| id.0)
+}
+
+fn read_id() -> impl futures::Future<Output = Option<u32>> {
+    future::poll_fn(|cx| Poll::Ready(id(cx)))
+}
+
+#[test]
+fn child_sees_data() {
+    assert_eq!(block_on(read_id()), None);
+    assert_eq!(block_on(future::with_ext(read_id(), Id(1))), Some(1));
+}
+
+#[test]
+fn inner_data_replaces_outer_data() {
+    let future = async { (read_id().await, future::with_ext(read_id(), Id(2)).await) };
+    assert_eq!(block_on(future::with_ext(future, Id(1))), (Some(1), Some(2)));
+}
+
+#[test]
+fn child_can_mutate_data() {
+    let child = future::poll_fn(|cx| {
+        cx.ext().downcast_mut::<Id>().unwrap().0 += 1;
+        Poll::Ready(())
+    });
+    let mut future = future::with_ext(child, Id(1));
+    block_on(&mut future);
+    assert_eq!(future.into_inner().1, Id(2));
+}
+
+#[test]
+fn futures_unordered_keeps_data() {
+    let futures: FuturesUnordered<_> = (0..3).map(|_| read_id()).collect();
+    let ids = block_on(future::with_ext(futures.collect::<Vec<_>>(), Id(3)));
+    assert_eq!(ids, [Some(3), Some(3), Some(3)]);
+}
+
+#[test]
+fn shared_keeps_data() {
+    let shared = read_id().shared();
+    assert_eq!(block_on(future::with_ext(shared, Id(4))), Some(4));
+}
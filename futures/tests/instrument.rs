@@ -0,0 +1,113 @@
+use futures::channel::oneshot;
+use futures::executor::block_on;
+use futures::future::{self, FutureExt};
+use futures::instrument::{self, PollHook};
+use futures::stream::{self, StreamExt};
+use futures::task::Poll;
+use futures_test::task::noop_context;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+// The hooks are global, and the tests run in parallel, so each test only
+// records the events of the names it uses.
+struct Record {
+    names: &'static [&'static str],
+    events: Arc<Mutex<Vec<String>>>,
+}
+
+impl Record {
+    fn add(names: &'static [&'static str]) -> (instrument::HookId, Arc<Mutex<Vec<String>>>) {
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let id = instrument::add_hook(Record { names, events: events.clone() });
+        (id, events)
+    }
+}
+
+impl PollHook for Record {
+    fn poll_start(&self, name: &'static str) {
+        if self.names.contains(&name) {
+            self.events.lock().unwrap().push(format!("start {}", name));
+        }
+    }
+
+    fn poll_end(&self, name: &'static str, _elapsed: Duration, ready: bool) {
+        if self.names.contains(&name) {
+            self.events.lock().unwrap().push(format!("end {} {}", name, ready));
+        }
+    }
+}
+
+#[test]
+fn hooks_see_each_poll() {
+    let (id, events) = Record::add(&["oneshot"]);
+
+    let (tx, rx) = oneshot::channel::<i32>();
+    let mut future = rx.instrument("oneshot");
+    let mut cx = noop_context();
+    assert_eq!(future.poll_unpin(&mut cx), Poll::Pending);
+    tx.send(1).unwrap();
+    assert_eq!(future.poll_unpin(&mut cx), Poll::Ready(Ok(1)));
+
+    assert!(instrument::remove_hook(id));
+    assert_eq!(
+        *events.lock().unwrap(),
+        ["start oneshot", "end oneshot false", "start oneshot", "end oneshot true"]
+    );
+}
+
+#[test]
+fn nested_instrumentation() {
+    let (id, events) = Record::add(&["outer", "inner"]);
+
+    let future = async { future::ready(()).instrument("inner").await }.instrument("outer");
+    block_on(future);
+
+    assert!(instrument::remove_hook(id));
+    assert_eq!(
+        *events.lock().unwrap(),
+        ["start outer", "start inner", "end inner true", "end outer true"]
+    );
+}
+
+#[test]
+fn streams_are_instrumented() {
+    let (id, events) = Record::add(&["numbers"]);
+
+    let items = block_on(stream::iter(vec![1, 2]).instrument("numbers").collect::<Vec<_>>());
+    assert_eq!(items, [1, 2]);
+
+    assert!(instrument::remove_hook(id));
+    assert_eq!(events.lock().unwrap().len(), 6);
+    assert_eq!(events.lock().unwrap()[5], "end numbers true");
+}
+
+#[test]
+fn removed_hooks_are_not_called() {
+    let (id, events) = Record::add(&["removed"]);
+    assert!(instrument::remove_hook(id));
+    assert!(!instrument::remove_hook(id));
+
+    block_on(future::ready(()).instrument("removed"));
+    assert!(events.lock().unwrap().is_empty());
+}
+
+#[test]
+fn elapsed_covers_the_poll() {
+    struct Elapsed(Arc<Mutex<Option<Duration>>>);
+
+    impl PollHook for Elapsed {
+        fn poll_end(&self, name: &'static str, elapsed: Duration, _ready: bool) {
+            if name == "sleepy" {
+                *self.0.lock().unwrap() = Some(elapsed);
+            }
+        }
+    }
+
+    let elapsed = Arc::new(Mutex::new(None));
+    let id = instrument::add_hook(Elapsed(elapsed.clone()));
+    block_on(future::lazy(|_| thread::sleep(Duration::from_millis(20))).instrument("sleepy"));
+    assert!(instrument::remove_hook(id));
+
+    assert!(elapsed.lock().unwrap().unwrap() >= Duration::from_millis(20));
+}
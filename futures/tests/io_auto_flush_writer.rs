@@ -0,0 +1,63 @@
+use futures::channel::oneshot;
+use futures::executor::block_on;
+use futures::future;
+use futures::io::{AsyncWriteExt, AutoFlushWriter};
+use futures_test::io::AsyncWriteTestExt;
+use std::cell::RefCell;
+
+#[test]
+fn flushes_once_the_threshold_is_reached() {
+    let mut writer = AutoFlushWriter::new(Vec::new(), 4, future::pending::<()>);
+
+    block_on(writer.write_all(b"abc")).unwrap();
+    block_on(writer.write_all(b"d")).unwrap();
+    assert_eq!(writer.get_ref(), b"");
+    assert_eq!(writer.buffer(), b"abcd");
+
+    block_on(writer.write_all(b"e")).unwrap();
+    assert_eq!(writer.get_ref(), b"abcd");
+
+    // Large writes bypass the buffer once it is empty.
+    block_on(writer.write_all(b"fghijk")).unwrap();
+    assert_eq!(writer.get_ref(), b"abcdefghijk");
+}
+
+#[test]
+fn flushes_once_the_deadline_expires() {
+    let timers = RefCell::new(Vec::new());
+    let delay = || {
+        let (tx, rx) = oneshot::channel::<()>();
+        timers.borrow_mut().push(tx);
+        rx
+    };
+    let mut writer = AutoFlushWriter::new(Vec::new(), 64, delay);
+
+    block_on(writer.write_all(b"ab")).unwrap();
+    block_on(writer.write_all(b"cd")).unwrap();
+    assert_eq!(timers.borrow().len(), 1);
+    assert_eq!(writer.get_ref(), b"");
+
+    timers.borrow_mut().remove(0).send(()).unwrap();
+    block_on(writer.write_all(b"ef")).unwrap();
+    assert_eq!(writer.get_ref(), b"abcd");
+    assert_eq!(writer.buffer(), b"ef");
+
+    // The next deadline starts with the next buffered write.
+    assert_eq!(timers.borrow().len(), 1);
+    drop(writer);
+}
+
+#[test]
+fn flush_and_close_write_everything_out() {
+    let inner = Vec::new().limited_write(1).interleave_pending_write().track_closed();
+    let mut writer = AutoFlushWriter::new(inner, 16, future::pending::<()>);
+
+    block_on(writer.write_all(b"hello")).unwrap();
+    block_on(writer.flush()).unwrap();
+    assert_eq!(writer.get_ref().get_ref().get_ref().get_ref(), b"hello");
+
+    block_on(writer.write_all(b" world")).unwrap();
+    block_on(writer.close()).unwrap();
+    assert!(writer.get_ref().is_closed());
+    assert_eq!(writer.get_ref().get_ref().get_ref().get_ref(), b"hello world");
+}
@@ -190,6 +190,87 @@ fn test_buffered_reader_seek_relative() {
     });
 }
 
+#[test]
+fn test_buffered_reader_seek_relative_ext() {
+    block_on(async {
+        let inner: &[u8] = &[5, 6, 7, 0, 1, 2, 3, 4];
+        let mut reader = BufReader::with_capacity(4, futures::io::Cursor::new(inner));
+
+        assert_eq!(reader.fill_buf().await.unwrap(), &[5, 6, 7, 0][..]);
+        AsyncSeekExt::seek_relative(&mut reader, 1).await.unwrap();
+        assert_eq!(reader.buffer(), &[6, 7, 0][..]);
+        AsyncSeekExt::seek_relative(&mut reader, -1).await.unwrap();
+        assert_eq!(reader.buffer(), &[5, 6, 7, 0][..]);
+        AsyncSeekExt::seek_relative(&mut reader, 5).await.unwrap();
+        assert_eq!(reader.buffer(), &[][..]);
+        assert_eq!(reader.fill_buf().await.unwrap(), &[2, 3, 4][..]);
+
+        reader.rewind().await.unwrap();
+        assert_eq!(reader.fill_buf().await.unwrap(), &[5, 6, 7, 0][..]);
+    });
+}
+
+#[test]
+fn test_seek_relative_and_rewind() {
+    use futures_test::io::AsyncReadTestExt;
+
+    block_on(async {
+        let inner: &[u8] = &[5, 6, 7, 0, 1, 2, 3, 4];
+        let mut reader = futures::io::Cursor::new(inner).interleave_pending();
+        let mut buf = [0; 2];
+
+        reader.seek_relative(3).await.unwrap();
+        reader.read_exact(&mut buf).await.unwrap();
+        assert_eq!(buf, [0, 1]);
+        reader.seek_relative(-4).await.unwrap();
+        reader.read_exact(&mut buf).await.unwrap();
+        assert_eq!(buf, [6, 7]);
+        assert!(reader.seek_relative(-4).await.is_err());
+
+        reader.rewind().await.unwrap();
+        reader.read_exact(&mut buf).await.unwrap();
+        assert_eq!(buf, [5, 6]);
+    });
+}
+
+#[test]
+fn test_buffered_reader_set_capacity() {
+    block_on(async {
+        let inner: &[u8] = &[5, 6, 7, 0, 1, 2, 3, 4];
+        let mut reader = BufReader::with_capacity(4, inner);
+        assert_eq!(reader.capacity(), 4);
+
+        assert_eq!(reader.fill_buf().await.unwrap(), &[5, 6, 7, 0][..]);
+        reader.consume_unpin(1);
+
+        // Shrinking keeps what is buffered.
+        reader.set_capacity(1);
+        assert_eq!(reader.capacity(), 3);
+        assert_eq!(reader.buffer(), [6, 7, 0]);
+        reader.consume_unpin(3);
+        reader.set_capacity(1);
+        assert_eq!(reader.capacity(), 1);
+
+        reader.set_capacity(8);
+        assert_eq!(reader.capacity(), 8);
+        assert_eq!(reader.fill_buf().await.unwrap(), &[1, 2, 3, 4][..]);
+    });
+}
+
+#[test]
+fn test_buffered_reader_into_parts() {
+    block_on(async {
+        let inner: &[u8] = &[5, 6, 7, 0, 1, 2, 3, 4];
+        let mut reader = BufReader::with_capacity(4, inner);
+
+        let mut buf = [0];
+        reader.read_exact(&mut buf).await.unwrap();
+        let (inner, buffered) = reader.into_parts();
+        assert_eq!(buffered, [6, 7, 0]);
+        assert_eq!(inner, [1, 2, 3, 4]);
+    });
+}
+
 #[test]
 fn test_buffered_reader_invalidated_after_read() {
     block_on(async {
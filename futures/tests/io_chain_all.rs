@@ -0,0 +1,63 @@
+use futures::executor::block_on;
+use futures::io::{self, AsyncBufReadExt, AsyncReadExt, Cursor};
+use futures_test::io::AsyncReadTestExt;
+use std::cell::Cell;
+
+#[test]
+fn chain_all_reads_each_reader_in_order() {
+    let parts = vec![&b"hello "[..], b"", b"wor", b"ld"];
+    let readers = parts.into_iter().map(|part| Cursor::new(part).interleave_pending());
+    let mut reader = io::chain_all(readers);
+
+    let mut buf = Vec::new();
+    assert_eq!(block_on(reader.read_to_end(&mut buf)).unwrap(), 11);
+    assert_eq!(buf, b"hello world");
+    assert!(reader.current().is_none());
+}
+
+#[test]
+fn chain_all_without_readers_is_empty() {
+    let mut reader = io::chain_all(Vec::<Cursor<Vec<u8>>>::new());
+
+    let mut buf = [0; 4];
+    assert_eq!(block_on(reader.read(&mut buf)).unwrap(), 0);
+    assert_eq!(block_on(reader.fill_buf()).unwrap(), b"");
+}
+
+#[test]
+fn chain_all_takes_readers_lazily() {
+    let opened = Cell::new(0);
+    let readers = (0..3).map(|i| {
+        opened.set(opened.get() + 1);
+        Cursor::new(vec![i; 2])
+    });
+    let mut reader = io::chain_all(readers);
+    assert_eq!(opened.get(), 0);
+
+    let mut buf = [0; 2];
+    block_on(reader.read_exact(&mut buf)).unwrap();
+    assert_eq!(buf, [0, 0]);
+    assert_eq!(opened.get(), 1);
+
+    block_on(reader.read_exact(&mut buf)).unwrap();
+    assert_eq!(buf, [1, 1]);
+    assert_eq!(opened.get(), 2);
+}
+
+#[test]
+fn chain_all_fill_buf_passes_through() {
+    let parts = vec![&b"ab"[..], b"", b"c\nd"];
+    let readers = parts.into_iter().map(Cursor::new);
+    let mut reader = io::chain_all(readers);
+
+    assert_eq!(block_on(reader.fill_buf()).unwrap(), b"ab");
+
+    let mut line = String::new();
+    assert_eq!(block_on(reader.read_line(&mut line)).unwrap(), 4);
+    assert_eq!(line, "abc\n");
+
+    line.clear();
+    assert_eq!(block_on(reader.read_line(&mut line)).unwrap(), 1);
+    assert_eq!(line, "d");
+    assert_eq!(block_on(reader.fill_buf()).unwrap(), b"");
+}
@@ -0,0 +1,103 @@
+use futures::executor::block_on;
+use futures::io::{AsyncWrite, AsyncWriteExt, CoalescingWriter, IoSlice};
+use futures::task::{Context, Poll};
+use futures_test::io::AsyncWriteTestExt;
+use std::io;
+use std::pin::Pin;
+
+// Records the slices of each vectored write, writing at most `limit` bytes.
+#[derive(Default)]
+struct Recorder {
+    data: Vec<u8>,
+    batches: Vec<Vec<usize>>,
+    limit: Option<usize>,
+}
+
+impl AsyncWrite for Recorder {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        self.poll_write_vectored(cx, &[IoSlice::new(buf)])
+    }
+
+    fn poll_write_vectored(
+        mut self: Pin<&mut Self>,
+        _: &mut Context<'_>,
+        bufs: &[IoSlice<'_>],
+    ) -> Poll<io::Result<usize>> {
+        let this = &mut *self;
+        let mut limit = this.limit.unwrap_or(usize::max_value());
+        this.batches.push(bufs.iter().map(|buf| buf.len()).collect());
+        let before = this.data.len();
+        for buf in bufs {
+            let n = buf.len().min(limit);
+            this.data.extend_from_slice(&buf[..n]);
+            limit -= n;
+        }
+        Poll::Ready(Ok(this.data.len() - before))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_close(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+}
+
+#[test]
+fn coalescing_writer_batches_by_count() {
+    let mut writer = CoalescingWriter::new(Recorder::default(), 2, 100);
+    for part in &[&b"a"[..], b"bc", b"def", b"g"] {
+        block_on(writer.write_all(part)).unwrap();
+    }
+    assert_eq!(writer.buffered(), 4);
+    block_on(writer.flush()).unwrap();
+    assert_eq!(writer.buffered(), 0);
+
+    let inner = writer.into_inner();
+    assert_eq!(inner.data, b"abcdefg");
+    assert_eq!(inner.batches, [vec![1, 2], vec![3, 1]]);
+}
+
+#[test]
+fn coalescing_writer_batches_by_bytes() {
+    let mut writer = CoalescingWriter::new(Recorder::default(), 10, 4);
+    for part in &[&b"ab"[..], b"c", b"de", b"fghij", b"k"] {
+        block_on(writer.write_all(part)).unwrap();
+    }
+    block_on(writer.close()).unwrap();
+
+    let inner = writer.into_inner();
+    assert_eq!(inner.data, b"abcdefghijk");
+    // The large write is passed through on its own.
+    assert_eq!(inner.batches, [vec![2, 1], vec![2], vec![5], vec![1]]);
+}
+
+#[test]
+fn coalescing_writer_handles_partial_writes() {
+    let recorder = Recorder { limit: Some(2), ..Recorder::default() };
+    let mut writer = CoalescingWriter::new(recorder, 4, 100);
+    for part in &[&b"abc"[..], b"d", b"ef"] {
+        block_on(writer.write_all(part)).unwrap();
+    }
+    block_on(writer.flush()).unwrap();
+
+    let inner = writer.into_inner();
+    assert_eq!(inner.data, b"abcdef");
+    assert_eq!(inner.batches, [vec![3, 1, 2], vec![1, 1, 2], vec![2]]);
+}
+
+#[test]
+fn coalescing_writer_with_pending_writer() {
+    let inner = Vec::new().limited_write(3).interleave_pending_write();
+    let mut writer = CoalescingWriter::new(inner, 3, 8);
+    for part in &[&b"hello"[..], b" ", b"async", b" ", b"world"] {
+        block_on(writer.write_all(part)).unwrap();
+    }
+    block_on(writer.flush()).unwrap();
+    assert_eq!(writer.get_ref().get_ref().get_ref(), b"hello async world");
+}
@@ -0,0 +1,90 @@
+use futures::executor::block_on;
+use futures::io::codec::{Decoder, Encoder, FramedRead, FramedWrite, LengthDelimitedCodec};
+use futures::io::{Cursor, ErrorKind};
+use futures::sink::SinkExt;
+use futures::stream::{StreamExt, TryStreamExt};
+use futures_test::io::{AsyncReadTestExt, AsyncWriteTestExt};
+
+fn encode(codec: &mut LengthDelimitedCodec, frames: &[&[u8]]) -> Vec<u8> {
+    let mut buffer = Vec::new();
+    for frame in frames {
+        codec.encode(frame, &mut buffer).unwrap();
+    }
+    buffer
+}
+
+#[test]
+fn length_delimited_round_trip_through_small_reads() {
+    let mut codec = LengthDelimitedCodec::new();
+    let bytes = encode(&mut codec, &[b"hello", b"", b"world!"]);
+
+    let reader = Cursor::new(bytes).limited(3).interleave_pending();
+    let frames: Vec<Vec<u8>> = block_on(FramedRead::new(reader, codec).try_collect()).unwrap();
+    assert_eq!(frames, [b"hello".to_vec(), Vec::new(), b"world!".to_vec()]);
+}
+
+#[test]
+fn length_field_settings() {
+    let mut codec = LengthDelimitedCodec::new().length_field_length(3);
+    assert_eq!(encode(&mut codec, &[b"ab"]), b"\x00\x00\x02ab");
+
+    let mut codec = codec.little_endian();
+    assert_eq!(encode(&mut codec, &[b"ab"]), b"\x02\x00\x00ab");
+
+    let mut codec = LengthDelimitedCodec::new().length_field_length(1);
+    let err = codec.encode(&[0u8; 256][..], &mut Vec::new()).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidInput);
+}
+
+#[test]
+fn frame_longer_than_the_maximum_is_rejected() {
+    let mut codec = LengthDelimitedCodec::new().max_frame_length(4);
+    let err = codec.encode(b"hello", &mut Vec::new()).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidInput);
+
+    // The length field alone is enough to reject the frame.
+    let mut buffer = b"\x00\x00\x00\x05hel".to_vec();
+    assert_eq!(codec.decode(&mut buffer).unwrap_err().kind(), ErrorKind::InvalidData);
+
+    let mut reader = FramedRead::new(Cursor::new(b"\x00\x00\x01\x00".to_vec()), codec);
+    let err = block_on(reader.next()).unwrap().unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+    assert!(block_on(reader.next()).is_none());
+}
+
+#[test]
+fn partial_frame_at_eof_is_an_error() {
+    let bytes = b"\x00\x00\x00\x02a".to_vec();
+    let mut reader = FramedRead::new(Cursor::new(bytes), LengthDelimitedCodec::new());
+    assert_eq!(block_on(reader.next()).unwrap().unwrap_err().kind(), ErrorKind::UnexpectedEof);
+
+    let mut reader = FramedRead::new(
+        Cursor::new(b"\x00\x00\x00\x01a\x00\x00".to_vec()),
+        LengthDelimitedCodec::new(),
+    );
+    assert_eq!(block_on(reader.next()).unwrap().unwrap(), b"a");
+    assert_eq!(block_on(reader.next()).unwrap().unwrap_err().kind(), ErrorKind::UnexpectedEof);
+}
+
+#[test]
+fn framed_write_through_a_slow_writer() {
+    let writer = Cursor::new(Vec::new()).limited_write(5).interleave_pending_write();
+    let mut sink = FramedWrite::new(writer, LengthDelimitedCodec::new());
+
+    block_on(async {
+        for i in 0..100u8 {
+            sink.feed(vec![i; i as usize]).await.unwrap();
+        }
+        SinkExt::<Vec<u8>>::close(&mut sink).await.unwrap();
+    });
+    assert!(sink.write_buffer().is_empty());
+
+    let bytes = sink.into_inner().into_inner().into_inner().into_inner();
+    let frames: Vec<Vec<u8>> =
+        block_on(FramedRead::new(Cursor::new(bytes), LengthDelimitedCodec::new()).try_collect())
+            .unwrap();
+    assert_eq!(frames.len(), 100);
+    for (i, frame) in frames.iter().enumerate() {
+        assert_eq!(frame, &vec![i as u8; i]);
+    }
+}
@@ -0,0 +1,45 @@
+use futures::executor::block_on;
+use futures::future::FutureExt;
+use futures::io::{self, BufferPool, CopyConfig, Cursor};
+use futures_test::io::{AsyncReadTestExt, AsyncWriteTestExt};
+use futures_test::task::noop_context;
+
+#[test]
+fn copy_with_uses_the_buffer_size() {
+    let data: Vec<u8> = (0..100).collect();
+    let reader = Cursor::new(data.clone()).interleave_pending();
+    let mut writer = Vec::new().limited_write(7).interleave_pending_write();
+
+    let config = CopyConfig { buf_size: 16, ..CopyConfig::default() };
+    assert_eq!(block_on(io::copy_with(reader, &mut writer, config)).unwrap(), 100);
+    assert_eq!(writer.get_ref().get_ref(), &data);
+}
+
+#[test]
+fn copy_with_reuses_pooled_buffers() {
+    let pool = BufferPool::new(1);
+    let config = CopyConfig { buf_size: 4, pool: Some(pool.clone()) };
+
+    let mut first = Vec::new();
+    let mut second = Vec::new();
+    let copy1 = io::copy_with(Cursor::new(b"first"), &mut first, config.clone());
+    let copy2 = io::copy_with(Cursor::new(b"second"), &mut second, config.clone());
+    assert_eq!(pool.idle(), 0);
+
+    // Only one buffer is kept once both copies are done.
+    block_on(copy1).unwrap();
+    assert_eq!(pool.idle(), 1);
+    block_on(copy2).unwrap();
+    assert_eq!(pool.idle(), 1);
+    assert_eq!(first, b"first");
+    assert_eq!(second, b"second");
+
+    // A buffer taken from the pool is given back when the copy is dropped.
+    let mut writer = Vec::new();
+    let reader = Cursor::new(b"dropped").interleave_pending();
+    let mut copy = io::copy_with(reader, &mut writer, config);
+    assert_eq!(pool.idle(), 0);
+    assert!(copy.poll_unpin(&mut noop_context()).is_pending());
+    drop(copy);
+    assert_eq!(pool.idle(), 1);
+}
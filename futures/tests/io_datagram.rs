@@ -0,0 +1,109 @@
+use futures::executor::block_on;
+use futures::io::{
+    AsyncDatagramRead, AsyncDatagramReadExt, AsyncDatagramWrite, AsyncDatagramWriteExt,
+};
+use futures::sink::SinkExt;
+use futures::stream::{self, StreamExt};
+use futures::task::{Context, Poll};
+use std::collections::VecDeque;
+use std::io;
+use std::pin::Pin;
+
+// A fake socket with address type `u32`, that is pending every other poll.
+#[derive(Default)]
+struct Socket {
+    incoming: VecDeque<io::Result<(Vec<u8>, u32)>>,
+    sent: Vec<(Vec<u8>, u32)>,
+    pended: bool,
+}
+
+impl Socket {
+    fn pend(&mut self, cx: &mut Context<'_>) -> bool {
+        self.pended = !self.pended;
+        if self.pended {
+            cx.waker().wake_by_ref();
+        }
+        self.pended
+    }
+}
+
+impl AsyncDatagramRead<u32> for Socket {
+    fn poll_recv_from(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<(usize, u32)>> {
+        if self.pend(cx) {
+            return Poll::Pending;
+        }
+        match self.incoming.pop_front() {
+            Some(Ok((datagram, from))) => {
+                let n = datagram.len().min(buf.len());
+                buf[..n].copy_from_slice(&datagram[..n]);
+                Poll::Ready(Ok((n, from)))
+            }
+            Some(Err(e)) => Poll::Ready(Err(e)),
+            None => Poll::Pending,
+        }
+    }
+}
+
+impl AsyncDatagramWrite<u32> for Socket {
+    fn poll_send_to(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+        target: &u32,
+    ) -> Poll<io::Result<usize>> {
+        if self.pend(cx) {
+            return Poll::Pending;
+        }
+        self.sent.push((buf.to_vec(), *target));
+        Poll::Ready(Ok(buf.len()))
+    }
+}
+
+#[test]
+fn recv_from_and_send_to() {
+    let mut socket = Socket::default();
+    socket.incoming.push_back(Ok((b"hello".to_vec(), 7)));
+
+    let mut buf = [0; 3];
+    let (n, from) = block_on(socket.recv_from(&mut buf)).unwrap();
+    assert_eq!((&buf[..n], from), (&b"hel"[..], 7));
+
+    assert_eq!(block_on(socket.send_to(b"reply", &from)).unwrap(), 5);
+    assert_eq!(socket.sent, [(b"reply".to_vec(), 7)]);
+
+    // The traits are implemented through mutable references and boxes too.
+    let mut boxed: Box<dyn AsyncDatagramWrite<u32> + Unpin> = Box::new(&mut socket);
+    block_on(boxed.send_to(b"boxed", &8)).unwrap();
+    drop(boxed);
+    assert_eq!(socket.sent[1], (b"boxed".to_vec(), 8));
+}
+
+#[test]
+fn datagrams_stream() {
+    let mut socket = Socket::default();
+    socket.incoming.push_back(Ok((b"one".to_vec(), 1)));
+    socket.incoming.push_back(Err(io::ErrorKind::ConnectionRefused.into()));
+    socket.incoming.push_back(Ok((b"three".to_vec(), 3)));
+
+    let mut datagrams = socket.datagrams(4);
+    assert_eq!(block_on(datagrams.next()).unwrap().unwrap(), (b"one".to_vec(), 1));
+    let err = block_on(datagrams.next()).unwrap().unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::ConnectionRefused);
+    assert_eq!(block_on(datagrams.next()).unwrap().unwrap(), (b"thre".to_vec(), 3));
+    assert!(datagrams.get_ref().incoming.is_empty());
+}
+
+#[test]
+fn datagram_sink() {
+    let mut sink = Socket::default().into_datagram_sink();
+    let items = vec![(&b"a"[..], 1), (b"bc", 2), (b"", 3)];
+    block_on(sink.send_all(&mut stream::iter(items).map(Ok))).unwrap();
+    block_on(sink.close()).unwrap();
+
+    let sent = &sink.get_ref().sent;
+    assert_eq!(sent, &[(b"a".to_vec(), 1), (b"bc".to_vec(), 2), (b"".to_vec(), 3)]);
+}
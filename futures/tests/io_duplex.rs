@@ -0,0 +1,101 @@
+use futures::executor::block_on;
+use futures::future::{join, FutureExt};
+use futures::io::{self, AsyncReadExt, AsyncWriteExt, ErrorKind};
+use futures::task::Poll;
+use futures_test::task::new_count_waker;
+use std::thread;
+
+#[test]
+fn duplex_is_bidirectional() {
+    let (mut a, mut b) = io::duplex(16);
+    block_on(a.write_all(b"to b")).unwrap();
+    block_on(b.write_all(b"to a")).unwrap();
+
+    let mut buf = [0; 4];
+    block_on(b.read_exact(&mut buf)).unwrap();
+    assert_eq!(&buf, b"to b");
+    block_on(a.read_exact(&mut buf)).unwrap();
+    assert_eq!(&buf, b"to a");
+}
+
+#[test]
+fn duplex_applies_backpressure() {
+    let (mut a, mut b) = io::duplex(4);
+    assert_eq!(block_on(a.write(b"abcdef")).unwrap(), 4);
+
+    let (waker, count) = new_count_waker();
+    let mut cx = std::task::Context::from_waker(&waker);
+    let mut write = a.write(b"ef");
+    assert!(write.poll_unpin(&mut cx).is_pending());
+    assert_eq!(count, 0);
+
+    let mut buf = [0; 3];
+    assert_eq!(block_on(b.read(&mut buf)).unwrap(), 3);
+    assert_eq!(count, 1);
+    assert_eq!(write.poll_unpin(&mut cx).map(Result::unwrap), Poll::Ready(2));
+}
+
+#[test]
+fn duplex_reader_waits_for_data() {
+    let (mut a, mut b) = io::duplex(4);
+
+    let (waker, count) = new_count_waker();
+    let mut cx = std::task::Context::from_waker(&waker);
+    let mut buf = [0; 4];
+    let mut read = b.read(&mut buf);
+    assert!(read.poll_unpin(&mut cx).is_pending());
+
+    block_on(a.write_all(b"x")).unwrap();
+    assert_eq!(count, 1);
+    assert_eq!(read.poll_unpin(&mut cx).map(Result::unwrap), Poll::Ready(1));
+}
+
+#[test]
+fn duplex_close_and_drop() {
+    let (mut a, mut b) = io::duplex(8);
+    block_on(a.write_all(b"bye")).unwrap();
+    block_on(a.close()).unwrap();
+    assert_eq!(block_on(a.write(b"more")).unwrap_err().kind(), ErrorKind::BrokenPipe);
+
+    let mut buf = Vec::new();
+    block_on(b.read_to_end(&mut buf)).unwrap();
+    assert_eq!(buf, b"bye");
+
+    // `b` can still write to `a` until `a` is dropped.
+    block_on(b.write_all(b"ok")).unwrap();
+    drop(a);
+    assert_eq!(block_on(b.write(b"!")).unwrap_err().kind(), ErrorKind::BrokenPipe);
+    assert_eq!(block_on(b.read(&mut [0; 1])).unwrap(), 0);
+}
+
+#[test]
+fn duplex_across_threads() {
+    let (mut a, mut b) = io::duplex(3);
+    let data: Vec<u8> = (0..=255).cycle().take(10_000).collect();
+
+    let expected = data.clone();
+    let reader = thread::spawn(move || {
+        let mut buf = Vec::new();
+        block_on(b.read_to_end(&mut buf)).unwrap();
+        assert_eq!(buf, expected);
+    });
+    block_on(async {
+        a.write_all(&data).await.unwrap();
+        a.close().await.unwrap();
+    });
+    reader.join().unwrap();
+
+    let (mut a, mut b) = io::duplex(5);
+    let ((), read) = block_on(join(
+        async {
+            a.write_all(b"hello world").await.unwrap();
+            drop(a);
+        },
+        async {
+            let mut buf = String::new();
+            b.read_to_string(&mut buf).await.unwrap();
+            buf
+        },
+    ));
+    assert_eq!(read, "hello world");
+}
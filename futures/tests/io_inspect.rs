@@ -0,0 +1,56 @@
+use futures::executor::block_on;
+use futures::io::{self, AsyncReadExt, AsyncWriteExt, Cursor, IoSlice, IoSliceMut};
+use futures_test::io::{AsyncReadTestExt, AsyncWriteTestExt};
+
+#[test]
+fn inspect_read_sees_every_chunk() {
+    let mut chunks = Vec::new();
+    let reader = Cursor::new(b"hello world".to_vec()).limited(4).interleave_pending();
+    let mut reader = io::inspect_read(reader, |chunk| chunks.push(chunk.to_vec()));
+
+    let mut out = String::new();
+    block_on(reader.read_to_string(&mut out)).unwrap();
+    drop(reader);
+
+    assert_eq!(out, "hello world");
+    assert_eq!(chunks, [&b"hell"[..], b"o wo", b"rld"]);
+}
+
+#[test]
+fn inspect_read_vectored() {
+    let mut seen = Vec::new();
+    let mut reader = io::inspect_read(&b"abcde"[..], |chunk| seen.push(chunk.to_vec()));
+
+    let (mut a, mut b) = ([0; 2], [0; 8]);
+    let mut bufs = [IoSliceMut::new(&mut a), IoSliceMut::new(&mut []), IoSliceMut::new(&mut b)];
+    assert_eq!(block_on(reader.read_vectored(&mut bufs)).unwrap(), 5);
+    drop(reader);
+
+    assert_eq!(seen, [&b"ab"[..], b"cde"]);
+}
+
+#[test]
+fn inspect_write_sees_accepted_bytes() {
+    let mut total = 0;
+    let writer = Cursor::new(Vec::new()).limited_write(3);
+    let mut writer = io::inspect_write(writer, |chunk| total += chunk.len());
+
+    assert_eq!(block_on(writer.write(b"abcdef")).unwrap(), 3);
+    block_on(writer.write_all(b"ghij")).unwrap();
+    let inner = writer.into_inner().into_inner().into_inner();
+
+    assert_eq!(inner, b"abcghij");
+    assert_eq!(total, 7);
+}
+
+#[test]
+fn inspect_write_vectored() {
+    let mut seen = Vec::new();
+    let mut writer = io::inspect_write(Vec::new(), |chunk| seen.push(chunk.to_vec()));
+
+    let bufs = [IoSlice::new(b"ab"), IoSlice::new(b""), IoSlice::new(b"cd")];
+    assert_eq!(block_on(writer.write_vectored(&bufs)).unwrap(), 4);
+    drop(writer);
+
+    assert_eq!(seen, [&b"ab"[..], b"cd"]);
+}
@@ -0,0 +1,54 @@
+use futures::executor::block_on;
+use futures::io::{AsyncWriteExt, Cursor, ErrorKind, IoSlice, LimitedWriter};
+use futures_test::io::AsyncWriteTestExt;
+
+#[test]
+fn limited_writer_cuts_writes_short() {
+    let mut writer = LimitedWriter::new(Vec::new(), 5);
+    assert_eq!(block_on(writer.write(b"abc")).unwrap(), 3);
+    assert_eq!(block_on(writer.write(b"defg")).unwrap(), 2);
+    assert_eq!(writer.limit(), 0);
+    assert_eq!(writer.get_ref(), b"abcde");
+}
+
+#[test]
+fn limited_writer_errors_past_the_limit() {
+    let mut writer = LimitedWriter::new(Vec::new(), 3);
+    let err = block_on(writer.write_all(b"abcdef")).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::WriteZero);
+    assert_eq!(writer.get_ref(), b"abc");
+
+    // Empty writes and flushes are still fine.
+    assert_eq!(block_on(writer.write(b"")).unwrap(), 0);
+    block_on(writer.flush()).unwrap();
+}
+
+#[test]
+fn limited_writer_set_limit() {
+    let mut writer = LimitedWriter::new(Vec::new(), 0);
+    assert_eq!(block_on(writer.write(b"a")).unwrap_err().kind(), ErrorKind::WriteZero);
+
+    writer.set_limit(2);
+    block_on(writer.write_all(b"ab")).unwrap();
+    assert_eq!(writer.into_inner(), b"ab");
+}
+
+#[test]
+fn limited_writer_counts_partial_writes() {
+    let inner = Cursor::new(Vec::new()).limited_write(2).interleave_pending_write();
+    let mut writer = LimitedWriter::new(inner, 5);
+    assert_eq!(block_on(writer.write(b"abcd")).unwrap(), 2);
+    assert_eq!(writer.limit(), 3);
+}
+
+#[test]
+fn limited_writer_vectored() {
+    let mut writer = LimitedWriter::new(Vec::new(), 4);
+    let bufs = [IoSlice::new(b"ab"), IoSlice::new(b"c")];
+    assert_eq!(block_on(writer.write_vectored(&bufs)).unwrap(), 3);
+
+    // These do not fit, so only part of the first slice is written.
+    let bufs = [IoSlice::new(b""), IoSlice::new(b"de"), IoSlice::new(b"f")];
+    assert_eq!(block_on(writer.write_vectored(&bufs)).unwrap(), 1);
+    assert_eq!(writer.into_inner(), b"abcd");
+}
@@ -0,0 +1,71 @@
+use futures::channel::oneshot;
+use futures::executor::block_on;
+use futures::future::{self, FutureExt};
+use futures::io::ratelimit::{RateLimitedReader, RateLimitedWriter};
+use futures::io::{AsyncReadExt, AsyncWriteExt, Cursor};
+use futures_test::task::noop_context;
+use std::cell::RefCell;
+
+#[test]
+fn reader_refills_after_delay() {
+    // Each delay completes once the matching sender is used.
+    let timers = RefCell::new(Vec::new());
+    let delay = || {
+        let (tx, rx) = oneshot::channel::<()>();
+        timers.borrow_mut().push(tx);
+        rx
+    };
+    let mut reader = RateLimitedReader::new(Cursor::new([7u8; 16]), 3, 5, delay);
+    let mut buffer = [0; 16];
+    let mut cx = noop_context();
+
+    assert_eq!(block_on(reader.read(&mut buffer)).unwrap(), 5);
+    assert_eq!(reader.tokens(), 0);
+    assert!(reader.read(&mut buffer).poll_unpin(&mut cx).is_pending());
+    assert_eq!(timers.borrow().len(), 1);
+
+    timers.borrow_mut().remove(0).send(()).unwrap();
+    assert_eq!(block_on(reader.read(&mut buffer)).unwrap(), 3);
+    assert_eq!(timers.borrow().len(), 1);
+
+    // Refills add up, but never go over the burst.
+    timers.borrow_mut().remove(0).send(()).unwrap();
+    assert_eq!(block_on(reader.read(&mut buffer[..1])).unwrap(), 1);
+    assert_eq!(reader.tokens(), 2);
+    timers.borrow_mut().remove(0).send(()).unwrap();
+    assert_eq!(block_on(reader.read(&mut buffer)).unwrap(), 5);
+}
+
+#[test]
+fn reader_with_ready_delay_reads_everything() {
+    let mut reader =
+        RateLimitedReader::new(Cursor::new(vec![1u8; 100]), 8, 8, || future::ready(()));
+    let mut out = Vec::new();
+    block_on(reader.read_to_end(&mut out)).unwrap();
+    assert_eq!(out, vec![1u8; 100]);
+}
+
+#[test]
+fn writer_limits_writes() {
+    let mut writer = RateLimitedWriter::new(Cursor::new(Vec::new()), 4, 4, future::pending::<()>);
+    let mut cx = noop_context();
+
+    assert_eq!(block_on(writer.write(&[1, 2, 3, 4, 5, 6])).unwrap(), 4);
+    assert_eq!(writer.tokens(), 0);
+    assert!(writer.write(&[7]).poll_unpin(&mut cx).is_pending());
+    block_on(writer.close()).unwrap();
+    assert_eq!(writer.into_inner().into_inner(), [1, 2, 3, 4]);
+}
+
+#[test]
+fn writer_with_ready_delay_writes_everything() {
+    let mut writer = RateLimitedWriter::new(Cursor::new(Vec::new()), 3, 5, || future::ready(()));
+    block_on(writer.write_all(&[9u8; 50])).unwrap();
+    assert_eq!(writer.get_ref().get_ref(), &vec![9u8; 50]);
+}
+
+#[test]
+#[should_panic(expected = "burst must be greater than zero")]
+fn zero_burst_panics() {
+    let _ = RateLimitedReader::new(Cursor::new([0u8]), 1, 0, future::pending::<()>);
+}
@@ -0,0 +1,172 @@
+use futures::executor::block_on;
+use futures::io::{AsyncRead, AsyncReadExt, BufReader, Cursor, ReadBuf};
+use std::io;
+use std::mem::MaybeUninit;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+/// A reader that only implements `poll_read`, counting the bytes it is handed.
+struct CountingReader {
+    data: Vec<u8>,
+    seen: usize,
+}
+
+impl AsyncRead for CountingReader {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        _cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        self.seen += buf.len();
+        let n = buf.len().min(self.data.len());
+        buf[..n].copy_from_slice(&self.data[..n]);
+        self.data.drain(..n);
+        Poll::Ready(Ok(n))
+    }
+}
+
+#[test]
+fn read_buf_tracks_filled_and_initialized() {
+    let mut storage = [MaybeUninit::<u8>::uninit(); 8];
+    let mut buf = ReadBuf::uninit(&mut storage);
+    assert_eq!(buf.capacity(), 8);
+    assert_eq!(buf.remaining(), 8);
+
+    buf.put_slice(&[1, 2]);
+    assert_eq!(buf.filled(), [1, 2]);
+    assert_eq!(buf.initialized().len(), 2);
+
+    assert_eq!(buf.initialize_unfilled_to(3), [0, 0, 0]);
+    assert_eq!(buf.initialized().len(), 5);
+    buf.advance(1);
+    assert_eq!(buf.filled(), [1, 2, 0]);
+
+    buf.clear();
+    assert_eq!(buf.filled(), []);
+    assert_eq!(buf.initialized().len(), 5);
+}
+
+#[test]
+#[should_panic]
+fn read_buf_advance_past_initialized_panics() {
+    let mut storage = [MaybeUninit::<u8>::uninit(); 8];
+    let mut buf = ReadBuf::uninit(&mut storage);
+    buf.advance(1);
+}
+
+#[test]
+fn default_poll_read_buf_initializes_once() {
+    let mut reader = CountingReader { data: vec![1, 2, 3], seen: 0 };
+    let mut storage = [MaybeUninit::<u8>::uninit(); 4];
+    let mut buf = ReadBuf::uninit(&mut storage);
+
+    block_on(reader.read_buf(&mut buf)).unwrap();
+    assert_eq!(buf.filled(), [1, 2, 3]);
+    assert_eq!(buf.initialized().len(), 4);
+
+    buf.clear();
+    block_on(reader.read_buf(&mut buf)).unwrap();
+    assert_eq!(buf.filled(), []);
+    assert_eq!(reader.seen, 8);
+}
+
+#[test]
+fn slice_and_cursor_do_not_initialize_beyond_the_data() {
+    let mut storage = [MaybeUninit::<u8>::uninit(); 8];
+
+    let mut buf = ReadBuf::uninit(&mut storage);
+    let mut slice: &[u8] = &[1, 2, 3];
+    block_on(slice.read_buf(&mut buf)).unwrap();
+    assert_eq!(buf.filled(), [1, 2, 3]);
+    assert_eq!(buf.initialized().len(), 3);
+    assert!(slice.is_empty());
+
+    let mut buf = ReadBuf::uninit(&mut storage);
+    let mut cursor = Cursor::new(vec![4, 5]);
+    block_on(cursor.read_buf(&mut buf)).unwrap();
+    assert_eq!(buf.filled(), [4, 5]);
+    assert_eq!(buf.initialized().len(), 2);
+    assert_eq!(cursor.position(), 2);
+}
+
+#[test]
+fn take_limits_read_buf() {
+    let mut storage = [MaybeUninit::<u8>::uninit(); 8];
+    let mut buf = ReadBuf::uninit(&mut storage);
+    let mut reader = (&[1u8, 2, 3, 4, 5][..]).take(3);
+
+    block_on(reader.read_buf(&mut buf)).unwrap();
+    assert_eq!(buf.filled(), [1, 2, 3]);
+    assert_eq!(buf.initialized().len(), 3);
+    assert_eq!(reader.limit(), 0);
+
+    block_on(reader.read_buf(&mut buf)).unwrap();
+    assert_eq!(buf.filled(), [1, 2, 3]);
+}
+
+/// A reader that replaces the buffer it is handed with a filled one.
+struct SwappingReader;
+
+impl AsyncRead for SwappingReader {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        _cx: &mut Context<'_>,
+        _buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        unreachable!()
+    }
+
+    fn poll_read_buf(
+        self: Pin<&mut Self>,
+        _cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        let mut swapped = ReadBuf::new(Box::leak(vec![0; 8].into_boxed_slice()));
+        swapped.advance(8);
+        *buf = swapped;
+        Poll::Ready(Ok(()))
+    }
+}
+
+#[test]
+#[should_panic(expected = "ReadBuf swapped")]
+fn take_rejects_swapped_read_buf() {
+    let mut storage = [MaybeUninit::<u8>::uninit(); 8];
+    let mut buf = ReadBuf::uninit(&mut storage);
+    let mut reader = SwappingReader.take(8);
+    let _ = block_on(reader.read_buf(&mut buf));
+}
+
+#[test]
+fn chain_moves_on_at_end_of_first() {
+    let mut storage = [MaybeUninit::<u8>::uninit(); 8];
+    let mut buf = ReadBuf::uninit(&mut storage);
+    let mut reader = (&[1u8, 2][..]).chain(&[3u8, 4][..]);
+
+    block_on(reader.read_buf(&mut buf)).unwrap();
+    assert_eq!(buf.filled(), [1, 2]);
+    block_on(reader.read_buf(&mut buf)).unwrap();
+    assert_eq!(buf.filled(), [1, 2, 3, 4]);
+    block_on(reader.read_buf(&mut buf)).unwrap();
+    assert_eq!(buf.filled(), [1, 2, 3, 4]);
+}
+
+#[test]
+fn buf_reader_read_buf() {
+    // Small reads go through the internal buffer
+    let mut reader = BufReader::with_capacity(4, &[1u8, 2, 3, 4, 5, 6][..]);
+    let mut storage = [MaybeUninit::<u8>::uninit(); 2];
+    let mut buf = ReadBuf::uninit(&mut storage);
+    block_on(reader.read_buf(&mut buf)).unwrap();
+    assert_eq!(buf.filled(), [1, 2]);
+    assert_eq!(reader.buffer(), [3, 4]);
+
+    // Large reads bypass it once it is empty
+    let mut storage = [MaybeUninit::<u8>::uninit(); 8];
+    let mut buf = ReadBuf::uninit(&mut storage);
+    block_on(reader.read_buf(&mut buf)).unwrap();
+    assert_eq!(buf.filled(), [3, 4]);
+    block_on(reader.read_buf(&mut buf)).unwrap();
+    assert_eq!(buf.filled(), [3, 4, 5, 6]);
+    assert_eq!(buf.initialized().len(), 4);
+}
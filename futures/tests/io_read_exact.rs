@@ -1,5 +1,6 @@
 use futures::executor::block_on;
 use futures::io::AsyncReadExt;
+use futures_test::io::AsyncReadTestExt;
 
 #[test]
 fn read_exact() {
@@ -15,3 +16,19 @@ fn read_exact() {
     assert!(res.is_err());
     assert_eq!(reader.len(), 0);
 }
+
+#[test]
+fn read_exact_or_eof() {
+    let mut reader = (&[1u8, 2, 3, 4, 5][..]).limited(2).interleave_pending();
+    let mut out = [0u8; 3];
+
+    assert_eq!(block_on(reader.read_exact_or_eof(&mut out)).unwrap(), 3);
+    assert_eq!(out, [1, 2, 3]);
+
+    // Only 2 bytes left, which is not an error.
+    assert_eq!(block_on(reader.read_exact_or_eof(&mut out)).unwrap(), 2);
+    assert_eq!(out[..2], [4, 5]);
+
+    assert_eq!(block_on(reader.read_exact_or_eof(&mut out)).unwrap(), 0);
+    assert_eq!(block_on(reader.read_exact_or_eof(&mut [])).unwrap(), 0);
+}
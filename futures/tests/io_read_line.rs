@@ -1,6 +1,6 @@
 use futures::executor::block_on;
 use futures::future::{Future, FutureExt};
-use futures::io::{AsyncBufReadExt, Cursor};
+use futures::io::{AsyncBufReadExt, Cursor, ErrorKind, Overflow};
 use futures::stream::{self, StreamExt, TryStreamExt};
 use futures::task::Poll;
 use futures_test::io::AsyncReadTestExt;
@@ -56,3 +56,27 @@ fn maybe_pending() {
     assert_eq!(run(buf.read_line(&mut v)).unwrap(), 0);
     assert_eq!(v, "");
 }
+
+#[test]
+fn read_line_limited() {
+    let mut buf = Cursor::new("ab\ncdef\ng".as_bytes());
+    let mut v = String::new();
+    assert_eq!(block_on(buf.read_line_limited(&mut v, 3, Overflow::Error)).unwrap(), 3);
+    assert_eq!(v, "ab\n");
+    v.clear();
+    let err = block_on(buf.read_line_limited(&mut v, 3, Overflow::Error)).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+    assert_eq!(v, "cde");
+}
+
+#[test]
+fn read_line_limited_truncates_at_char_boundary() {
+    // "é" takes two bytes, and the limit falls between them.
+    let mut buf = Cursor::new("abé\nxyz".as_bytes()).interleave_pending();
+    let mut v = String::new();
+    assert_eq!(run(buf.read_line_limited(&mut v, 3, Overflow::Truncate)).unwrap(), 5);
+    assert_eq!(v, "ab");
+    v.clear();
+    assert_eq!(run(buf.read_line_limited(&mut v, 3, Overflow::Truncate)).unwrap(), 3);
+    assert_eq!(v, "xyz");
+}
@@ -1,6 +1,6 @@
 use futures::executor::block_on;
 use futures::future::{Future, FutureExt};
-use futures::io::{AsyncBufReadExt, Cursor};
+use futures::io::{AsyncBufReadExt, Cursor, ErrorKind, Overflow};
 use futures::stream::{self, StreamExt, TryStreamExt};
 use futures::task::Poll;
 use futures_test::io::AsyncReadTestExt;
@@ -58,3 +58,35 @@ fn maybe_pending() {
     assert_eq!(run(buf.read_until(b'3', &mut v)).unwrap(), 0);
     assert_eq!(v, []);
 }
+
+#[test]
+fn read_until_limited() {
+    let mut buf = Cursor::new(b"123|45678|9");
+    let mut v = Vec::new();
+    assert_eq!(block_on(buf.read_until_limited(b'|', &mut v, 4, Overflow::Error)).unwrap(), 4);
+    assert_eq!(v, b"123|");
+    v.clear();
+    let err = block_on(buf.read_until_limited(b'|', &mut v, 4, Overflow::Error)).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+    assert_eq!(v, b"4567");
+    v.clear();
+    assert_eq!(block_on(buf.read_until_limited(b'|', &mut v, 4, Overflow::Error)).unwrap(), 2);
+    assert_eq!(v, b"8|");
+}
+
+#[test]
+fn read_until_limited_truncate_maybe_pending() {
+    let mut buf = stream::iter(vec![&b"12"[..], &b"34"[..], &b"5|6"[..], &b"|"[..]])
+        .map(Ok)
+        .into_async_read()
+        .interleave_pending();
+    let mut v = Vec::new();
+    assert_eq!(run(buf.read_until_limited(b'|', &mut v, 3, Overflow::Truncate)).unwrap(), 6);
+    assert_eq!(v, b"123");
+    v.clear();
+    assert_eq!(run(buf.read_until_limited(b'|', &mut v, 3, Overflow::Truncate)).unwrap(), 2);
+    assert_eq!(v, b"6|");
+    v.clear();
+    assert_eq!(run(buf.read_until_limited(b'|', &mut v, 3, Overflow::Truncate)).unwrap(), 0);
+    assert_eq!(v, []);
+}
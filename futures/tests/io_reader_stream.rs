@@ -0,0 +1,34 @@
+use futures::io::{Cursor, ReaderStream};
+use futures::stream::{FusedStream, StreamExt};
+use futures::task::Poll;
+use futures_test::io::AsyncReadTestExt;
+use futures_test::task::noop_context;
+
+#[test]
+fn reader_stream_pending() {
+    let reader = Cursor::new(vec![1, 2, 3, 4, 5]).interleave_pending();
+    let mut stream = ReaderStream::new(reader, 3);
+    let mut cx = noop_context();
+
+    assert!(stream.poll_next_unpin(&mut cx).is_pending());
+    assert_eq!(
+        stream.poll_next_unpin(&mut cx).map(|c| c.map(Result::unwrap)),
+        Poll::Ready(Some(vec![1, 2, 3]))
+    );
+    assert!(stream.poll_next_unpin(&mut cx).is_pending());
+    assert_eq!(
+        stream.poll_next_unpin(&mut cx).map(|c| c.map(Result::unwrap)),
+        Poll::Ready(Some(vec![4, 5]))
+    );
+    assert!(!stream.is_terminated());
+    assert!(stream.poll_next_unpin(&mut cx).is_pending());
+    assert!(matches!(stream.poll_next_unpin(&mut cx), Poll::Ready(None)));
+    assert!(stream.is_terminated());
+    assert!(matches!(stream.poll_next_unpin(&mut cx), Poll::Ready(None)));
+}
+
+#[test]
+#[should_panic]
+fn reader_stream_panic_on_chunk_size_zero() {
+    let _ = ReaderStream::new(Cursor::new(Vec::<u8>::new()), 0);
+}
@@ -0,0 +1,87 @@
+use futures::executor::block_on;
+use futures::io::{
+    AsyncBufReadExt, AsyncReadExt, AsyncSeekExt, AsyncWriteExt, ErrorKind, SeekFrom,
+    SegmentedCursor,
+};
+use futures::stream::TryStreamExt;
+use std::collections::VecDeque;
+
+#[test]
+fn segmented_cursor_reads_across_segments() {
+    let segments: Vec<Vec<u8>> = vec![b"ab".to_vec(), vec![], b"cde".to_vec(), b"f".to_vec()];
+    let mut cursor = SegmentedCursor::new(segments);
+
+    let mut buf = [0; 4];
+    assert_eq!(block_on(cursor.read(&mut buf)).unwrap(), 4);
+    assert_eq!(&buf, b"abcd");
+    assert_eq!(block_on(cursor.read(&mut buf)).unwrap(), 2);
+    assert_eq!(&buf[..2], b"ef");
+    assert_eq!(block_on(cursor.read(&mut buf)).unwrap(), 0);
+    assert_eq!(cursor.position(), 6);
+}
+
+#[test]
+fn segmented_cursor_fill_buf_returns_segments() {
+    let segments = [&b"one\ntw"[..], b"", b"o\nthree"];
+    let mut cursor = SegmentedCursor::new(&segments[..]);
+
+    assert_eq!(block_on(cursor.fill_buf()).unwrap(), b"one\ntw");
+    let lines: Vec<String> = block_on(cursor.lines().try_collect()).unwrap();
+    assert_eq!(lines, ["one", "two", "three"]);
+}
+
+#[test]
+fn segmented_cursor_seeks() {
+    let mut cursor = SegmentedCursor::new(vec![&b"abc"[..], b"def"]);
+
+    assert_eq!(block_on(cursor.seek(SeekFrom::End(-2))).unwrap(), 4);
+    let mut buf = String::new();
+    block_on(cursor.read_to_string(&mut buf)).unwrap();
+    assert_eq!(buf, "ef");
+
+    assert_eq!(block_on(cursor.seek(SeekFrom::Current(-4))).unwrap(), 2);
+    let mut buf = [0; 2];
+    block_on(cursor.read_exact(&mut buf)).unwrap();
+    assert_eq!(&buf, b"cd");
+
+    let err = block_on(cursor.seek(SeekFrom::Current(-5))).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidInput);
+    assert_eq!(cursor.position(), 4);
+
+    // Seeking past the end is allowed, and reads nothing.
+    block_on(cursor.seek(SeekFrom::Start(10))).unwrap();
+    assert_eq!(block_on(cursor.read(&mut buf)).unwrap(), 0);
+}
+
+#[test]
+fn segmented_cursor_over_vec_deque() {
+    // Reuse the front of the deque so that its data can wrap around.
+    let mut deque = VecDeque::with_capacity(4);
+    deque.extend(b"xxab");
+    deque.drain(..2);
+    deque.extend(b"cd");
+    let mut cursor = SegmentedCursor::new(deque);
+
+    let mut buf = Vec::new();
+    block_on(cursor.read_to_end(&mut buf)).unwrap();
+    assert_eq!(buf, b"abcd");
+
+    // New data appended to the buffer can be read from the same position.
+    cursor.get_mut().extend(b"ef");
+    buf.clear();
+    block_on(cursor.read_to_end(&mut buf)).unwrap();
+    assert_eq!(buf, b"ef");
+}
+
+#[test]
+fn segmented_cursor_writes_to_vec_deque() {
+    let mut cursor = SegmentedCursor::new(VecDeque::new());
+    block_on(cursor.write_all(b"hello")).unwrap();
+    cursor.set_position(1);
+    block_on(cursor.write_all(b"ipp")).unwrap();
+    cursor.set_position(7);
+    block_on(cursor.write_all(b"!")).unwrap();
+
+    assert_eq!(cursor.position(), 8);
+    assert_eq!(cursor.into_inner(), b"hippo\0\0!".to_vec());
+}
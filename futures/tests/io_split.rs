@@ -0,0 +1,84 @@
+use futures::executor::block_on;
+use futures::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt, Cursor};
+use futures::task::{Context, Poll};
+use std::io;
+use std::pin::Pin;
+
+#[derive(Debug)]
+struct Duplex {
+    read: Cursor<Vec<u8>>,
+    written: Vec<u8>,
+    closed: bool,
+}
+
+impl AsyncRead for Duplex {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        Pin::new(&mut self.read).poll_read(cx, buf)
+    }
+}
+
+impl AsyncWrite for Duplex {
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        _cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        assert!(!self.closed);
+        self.written.extend_from_slice(buf);
+        Poll::Ready(Ok(buf.len()))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_close(mut self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        self.closed = true;
+        Poll::Ready(Ok(()))
+    }
+}
+
+fn duplex(data: &[u8]) -> Duplex {
+    Duplex { read: Cursor::new(data.to_vec()), written: Vec::new(), closed: false }
+}
+
+#[test]
+fn closing_write_half_closes_the_stream() {
+    let (mut reader, mut writer) = duplex(b"hello").split();
+
+    block_on(writer.write_all(b"bye")).unwrap();
+    block_on(writer.close()).unwrap();
+
+    // Reading still works after the write side is shut down.
+    let mut buf = Vec::new();
+    block_on(reader.read_to_end(&mut buf)).unwrap();
+    assert_eq!(buf, b"hello");
+
+    let inner = reader.reunite(writer).unwrap();
+    assert!(inner.closed);
+    assert_eq!(inner.written, b"bye");
+}
+
+#[test]
+fn is_pair_of_and_reunite_error() {
+    let (reader1, writer1) = duplex(b"").split();
+    let (reader2, writer2) = duplex(b"").split();
+
+    assert!(reader1.is_pair_of(&writer1));
+    assert!(writer1.is_pair_of(&reader1));
+    assert!(!reader1.is_pair_of(&writer2));
+    assert!(!writer2.is_pair_of(&reader1));
+
+    let err = reader1.reunite(writer2).unwrap_err();
+    let message = err.to_string();
+    assert!(message.starts_with("tried to reunite a ReadHalf (of split 0x"), "{}", message);
+    assert_ne!(format!("{:?}", err).matches("0x").count(), 0);
+
+    let (reader1, writer2) = (err.0, err.1);
+    assert!(reader1.reunite(writer1).is_ok());
+    assert!(reader2.reunite(writer2).is_ok());
+}
@@ -0,0 +1,39 @@
+use futures::executor::block_on;
+use futures::io::{AsyncBufReadExt, BufReader, Cursor, ErrorKind};
+use futures::stream::{StreamExt, TryStreamExt};
+use futures_test::io::AsyncReadTestExt;
+
+fn segments(data: &[u8], delimiter: &str, max_len: usize) -> Vec<Vec<u8>> {
+    // A tiny buffer makes delimiters span several reads.
+    let reader = BufReader::with_capacity(2, Cursor::new(data.to_vec()).interleave_pending());
+    block_on(reader.split_by(delimiter, max_len).try_collect()).unwrap()
+}
+
+#[test]
+fn split_by_multi_byte_delimiter() {
+    assert_eq!(segments(b"a||b||||c", "||", 10), [&b"a"[..], b"b", b"", b"c"]);
+    assert_eq!(segments(b"a||b||", "||", 10), [&b"a"[..], b"b"]);
+    assert_eq!(segments(b"a|b|||c", "||", 10), [&b"a|b"[..], b"|c"]);
+    assert_eq!(segments(b"", "||", 10), Vec::<Vec<u8>>::new());
+    assert_eq!(segments(b"abcabcabd", "abd", 10), [&b"abcabc"[..]]);
+}
+
+#[test]
+fn split_by_max_len() {
+    assert_eq!(segments(b"abc\r\n\r\nd", "\r\n\r\n", 3), [&b"abc"[..], b"d"]);
+
+    let cursor = Cursor::new(b"abcd\r\n\r\n".to_vec());
+    let mut stream = cursor.split_by("\r\n\r\n", 3);
+    assert_eq!(block_on(stream.next()).unwrap().unwrap_err().kind(), ErrorKind::InvalidData);
+    assert!(block_on(stream.next()).is_none());
+
+    // The error is reported without reading the whole segment.
+    let reader = BufReader::with_capacity(4, Cursor::new(vec![b'x'; 1024]));
+    let mut stream = reader.split_by("\r\n", 8);
+    assert_eq!(block_on(stream.next()).unwrap().unwrap_err().kind(), ErrorKind::InvalidData);
+    assert!(stream.get_ref().get_ref().position() < 16);
+
+    let cursor = Cursor::new(b"abcd".to_vec());
+    let mut stream = cursor.split_by("\r\n", 3);
+    assert_eq!(block_on(stream.next()).unwrap().unwrap_err().kind(), ErrorKind::InvalidData);
+}
@@ -0,0 +1,72 @@
+use futures::executor::block_on;
+use futures::io::{self, AsyncReadExt, AsyncWriteExt, Cursor};
+use futures_test::io::{AsyncReadTestExt, AsyncWriteTestExt};
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::thread;
+
+#[test]
+fn sync_read_waits_for_pending_reader() {
+    let reader = Cursor::new(b"hello world").limited(3).interleave_pending();
+    let mut reader = reader.into_sync_read();
+
+    let mut buf = [0; 5];
+    reader.read_exact(&mut buf).unwrap();
+    assert_eq!(&buf, b"hello");
+
+    let mut rest = String::new();
+    reader.read_to_string(&mut rest).unwrap();
+    assert_eq!(rest, " world");
+}
+
+#[test]
+fn sync_write_waits_for_pending_writer() {
+    let writer = Cursor::new(Vec::new()).limited_write(2).interleave_pending_write();
+    let mut writer = writer.into_sync_write();
+
+    writer.write_all(b"hello world").unwrap();
+    writer.flush().unwrap();
+    writer.close().unwrap();
+    assert_eq!(writer.get_ref().get_ref().get_ref().get_ref(), b"hello world");
+}
+
+#[test]
+fn sync_seek() {
+    let mut reader = Cursor::new(b"abcdef").into_sync_read();
+    assert_eq!(reader.seek(SeekFrom::End(-2)).unwrap(), 4);
+    let mut buf = Vec::new();
+    reader.read_to_end(&mut buf).unwrap();
+    assert_eq!(buf, b"ef");
+
+    let mut writer = Cursor::new(vec![0; 4]).into_sync_write();
+    writer.seek(SeekFrom::Start(2)).unwrap();
+    writer.write_all(b"xy").unwrap();
+    assert_eq!(writer.into_inner().into_inner(), b"\0\0xy");
+}
+
+#[test]
+fn sync_read_is_woken_from_another_thread() {
+    let (mut client, server) = io::duplex(4);
+    let writer = thread::spawn(move || {
+        block_on(async {
+            client.write_all(b"from another thread").await.unwrap();
+            client.close().await.unwrap();
+        })
+    });
+
+    let mut received = String::new();
+    server.into_sync_read().read_to_string(&mut received).unwrap();
+    assert_eq!(received, "from another thread");
+    writer.join().unwrap();
+
+    // The other direction, with a blocking writer.
+    let (client, mut server) = io::duplex(4);
+    let writer = thread::spawn(move || {
+        let mut client = client.into_sync_write();
+        client.write_all(b"blocking writes").unwrap();
+    });
+
+    let mut received = Vec::new();
+    block_on(server.read_to_end(&mut received)).unwrap();
+    assert_eq!(received, b"blocking writes");
+    writer.join().unwrap();
+}
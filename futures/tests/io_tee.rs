@@ -0,0 +1,48 @@
+use futures::executor::block_on;
+use futures::io::{AsyncWriteExt, Cursor};
+use futures_test::io::AsyncWriteTestExt;
+
+#[test]
+fn tee_writes_to_both() {
+    let mut writer = Cursor::new(Vec::new()).tee(Vec::new());
+    block_on(writer.write_all(b"hello ")).unwrap();
+    block_on(writer.write_all(b"world")).unwrap();
+    block_on(writer.flush()).unwrap();
+
+    let (first, second) = writer.into_inner();
+    assert_eq!(first.into_inner(), b"hello world");
+    assert_eq!(second, b"hello world");
+}
+
+#[test]
+fn tee_reports_what_the_first_writer_takes() {
+    let mut writer = Cursor::new(Vec::new()).limited_write(3).tee(Vec::new());
+    assert_eq!(block_on(writer.write(b"abcdef")).unwrap(), 3);
+
+    let (first, second) = writer.get_ref();
+    assert_eq!(first.get_ref().get_ref(), b"abc");
+    assert_eq!(second, b"abc");
+}
+
+#[test]
+fn tee_catches_up_a_slow_second_writer() {
+    let second = Cursor::new(Vec::new()).limited_write(2).interleave_pending_write();
+    let mut writer = Vec::new().tee(second);
+
+    block_on(writer.write_all(b"abcdefgh")).unwrap();
+    block_on(writer.close()).unwrap();
+
+    let (first, second) = writer.into_inner();
+    assert_eq!(first, b"abcdefgh");
+    assert_eq!(second.into_inner().into_inner().into_inner(), b"abcdefgh");
+}
+
+#[test]
+fn tee_closes_both() {
+    let mut writer = Vec::new().track_closed().tee(Vec::new().track_closed());
+    block_on(writer.close()).unwrap();
+
+    let (first, second) = writer.get_ref();
+    assert!(first.is_closed());
+    assert!(second.is_closed());
+}
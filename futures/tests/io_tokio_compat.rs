@@ -0,0 +1,51 @@
+#![cfg(feature = "tokio-compat")]
+
+use futures::executor::block_on;
+use futures::io::{AsyncReadExt, AsyncWriteExt, Cursor, TokioCompatExt};
+use tokio_1::io::{
+    AsyncBufReadExt as TokioAsyncBufReadExt, AsyncReadExt as TokioAsyncReadExt,
+    AsyncWriteExt as TokioAsyncWriteExt,
+};
+
+#[test]
+fn futures_reader_as_tokio_reader() {
+    block_on(async {
+        let mut reader = Cursor::new(b"hello\nworld".to_vec()).tokio_compat();
+        let mut line = String::new();
+        TokioAsyncBufReadExt::read_line(&mut reader, &mut line).await.unwrap();
+        assert_eq!(line, "hello\n");
+        let mut rest = Vec::new();
+        TokioAsyncReadExt::read_to_end(&mut reader, &mut rest).await.unwrap();
+        assert_eq!(rest, b"world");
+    });
+}
+
+#[test]
+fn futures_writer_as_tokio_writer() {
+    block_on(async {
+        let mut writer = Cursor::new(Vec::new()).tokio_compat();
+        TokioAsyncWriteExt::write_all(&mut writer, b"hello").await.unwrap();
+        TokioAsyncWriteExt::shutdown(&mut writer).await.unwrap();
+        assert_eq!(writer.into_inner().into_inner(), b"hello");
+    });
+}
+
+#[test]
+fn tokio_duplex_as_futures_io() {
+    block_on(async {
+        let (client, server) = tokio_1::io::duplex(64);
+        let mut client = client.tokio_compat();
+        let mut server = server.tokio_compat();
+
+        client.write_all(b"ping\n").await.unwrap();
+        let mut request = [0; 5];
+        server.read_exact(&mut request).await.unwrap();
+        assert_eq!(&request, b"ping\n");
+
+        server.write_all(b"pong").await.unwrap();
+        server.close().await.unwrap();
+        let mut reply = Vec::new();
+        AsyncReadExt::read_to_end(&mut client, &mut reply).await.unwrap();
+        assert_eq!(reply, b"pong");
+    });
+}
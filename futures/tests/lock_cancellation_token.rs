@@ -0,0 +1,80 @@
+use futures::executor::block_on;
+use futures::future::{self, FutureExt};
+use futures::lock::CancellationToken;
+use futures::task::{Context, Poll};
+use futures_test::task::{new_count_waker, panic_context};
+use std::thread;
+
+#[test]
+fn cancel_wakes_waiters() {
+    let token = CancellationToken::new();
+    let (waker, counter) = new_count_waker();
+    let mut cx = Context::from_waker(&waker);
+
+    let mut first = Box::pin(token.cancelled());
+    let mut second = Box::pin(token.cancelled());
+    assert!(first.poll_unpin(&mut cx).is_pending());
+    assert!(second.poll_unpin(&mut cx).is_pending());
+    assert!(!token.is_cancelled());
+
+    token.clone().cancel();
+    assert_eq!(counter, 2);
+    assert!(token.is_cancelled());
+    assert!(first.poll_unpin(&mut panic_context()).is_ready());
+    assert!(second.poll_unpin(&mut panic_context()).is_ready());
+}
+
+#[test]
+fn cancel_propagates_to_children() {
+    let parent = CancellationToken::new();
+    let child = parent.child_token();
+    let grandchild = child.child_token();
+    let sibling = parent.child_token();
+
+    child.cancel();
+    assert!(!parent.is_cancelled());
+    assert!(!sibling.is_cancelled());
+    assert!(grandchild.is_cancelled());
+
+    parent.cancel();
+    assert!(sibling.is_cancelled());
+    assert!(parent.child_token().is_cancelled());
+}
+
+#[test]
+fn dropped_children_are_released() {
+    let parent = CancellationToken::new();
+    for _ in 0..100 {
+        drop(parent.child_token());
+    }
+    let child = parent.child_token();
+    parent.cancel();
+    assert!(child.is_cancelled());
+}
+
+#[test]
+fn run_until_cancelled() {
+    let token = CancellationToken::new();
+    let (waker, counter) = new_count_waker();
+    let mut cx = Context::from_waker(&waker);
+
+    let mut run = Box::pin(token.run_until_cancelled(future::pending::<()>()));
+    assert!(run.poll_unpin(&mut cx).is_pending());
+
+    token.cancel();
+    assert_eq!(counter, 1);
+    assert_eq!(run.poll_unpin(&mut panic_context()), Poll::Ready(None));
+
+    // The future isn't polled once the token is cancelled.
+    assert_eq!(block_on(token.run_until_cancelled(future::ready(1))), None);
+}
+
+#[cfg_attr(miri, ignore)] // Miri is too slow
+#[test]
+fn cancel_from_other_thread() {
+    let token = CancellationToken::new();
+    let child = token.child_token();
+    let handle = thread::spawn(move || token.cancel());
+    block_on(child.cancelled());
+    handle.join().unwrap();
+}
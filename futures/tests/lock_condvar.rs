@@ -0,0 +1,147 @@
+use futures::executor::{block_on, ThreadPool};
+use futures::future::FutureExt;
+use futures::lock::{Condvar, Mutex};
+use futures::task::Context;
+use futures_test::task::{new_count_waker, panic_context};
+use std::sync::Arc;
+
+#[test]
+fn wait_releases_lock() {
+    let mutex = Mutex::new(0);
+    let condvar = Condvar::new();
+    let (waker, counter) = new_count_waker();
+    let mut cx = Context::from_waker(&waker);
+
+    let guard = mutex.try_lock().unwrap();
+    let mut wait = Box::pin(condvar.wait(guard));
+    assert!(wait.poll_unpin(&mut cx).is_pending());
+
+    // The lock was released while waiting.
+    let mut guard = mutex.try_lock().unwrap();
+    *guard += 1;
+    condvar.notify_one();
+    assert_eq!(counter, 1);
+
+    // The lock is acquired again once released.
+    assert!(wait.poll_unpin(&mut cx).is_pending());
+    drop(guard);
+    let guard = wait.now_or_never().unwrap();
+    assert_eq!(*guard, 1);
+}
+
+#[test]
+fn wait_holds_lock_until_polled() {
+    let mutex = Mutex::new(());
+    let condvar = Condvar::new();
+    let (waker, counter) = new_count_waker();
+    let mut cx = Context::from_waker(&waker);
+
+    let mut wait = Box::pin(condvar.wait(mutex.try_lock().unwrap()));
+    assert!(mutex.try_lock().is_none());
+    assert!(wait.poll_unpin(&mut cx).is_pending());
+
+    // A notification sent once the lock was released is not missed.
+    let guard = mutex.try_lock().unwrap();
+    condvar.notify_one();
+    assert_eq!(counter, 1);
+    drop(guard);
+    assert!(wait.poll_unpin(&mut panic_context()).is_ready());
+}
+
+#[test]
+fn notify_one_wakes_waiters_in_order() {
+    let mutex = Mutex::new(());
+    let condvar = Condvar::new();
+    let (waker, counter) = new_count_waker();
+    let mut cx = Context::from_waker(&waker);
+
+    let mut first = Box::pin(condvar.wait(mutex.try_lock().unwrap()));
+    assert!(first.poll_unpin(&mut cx).is_pending());
+    let mut second = Box::pin(condvar.wait(mutex.try_lock().unwrap()));
+    assert!(second.poll_unpin(&mut cx).is_pending());
+
+    condvar.notify_one();
+    assert_eq!(counter, 1);
+    let guard = first.poll_unpin(&mut panic_context());
+    assert!(guard.is_ready());
+    assert!(second.poll_unpin(&mut cx).is_pending());
+    drop(guard);
+
+    // A notification sent while no task waits is lost.
+    drop(second);
+    condvar.notify_one();
+    let mut third = Box::pin(condvar.wait(mutex.try_lock().unwrap()));
+    assert!(third.poll_unpin(&mut cx).is_pending());
+}
+
+#[test]
+fn notify_all_wakes_all_waiters() {
+    let mutex = Mutex::new(());
+    let condvar = Condvar::new();
+    let (waker, counter) = new_count_waker();
+    let mut cx = Context::from_waker(&waker);
+
+    let mut first = Box::pin(condvar.wait(mutex.try_lock().unwrap()));
+    assert!(first.poll_unpin(&mut cx).is_pending());
+    let mut second = Box::pin(condvar.wait(mutex.try_lock().unwrap()));
+    assert!(second.poll_unpin(&mut cx).is_pending());
+
+    condvar.notify_all();
+    assert_eq!(counter, 2);
+    let guard = first.poll_unpin(&mut panic_context());
+    assert!(guard.is_ready());
+    assert!(second.poll_unpin(&mut cx).is_pending());
+    drop(guard);
+    assert!(second.poll_unpin(&mut panic_context()).is_ready());
+}
+
+#[test]
+fn dropped_waiter_passes_on_notification() {
+    let mutex = Mutex::new(());
+    let condvar = Condvar::new();
+
+    let (waker, counter) = new_count_waker();
+    let mut cx = Context::from_waker(&waker);
+
+    let mut first = Box::pin(condvar.wait(mutex.try_lock().unwrap()));
+    assert!(first.poll_unpin(&mut cx).is_pending());
+    let mut second = Box::pin(condvar.wait(mutex.try_lock().unwrap()));
+    assert!(second.poll_unpin(&mut cx).is_pending());
+
+    condvar.notify_one();
+    drop(first);
+    assert_eq!(counter, 2);
+    assert!(second.poll_unpin(&mut panic_context()).is_ready());
+}
+
+#[cfg_attr(miri, ignore)] // https://github.com/rust-lang/miri/issues/1038
+#[test]
+fn producer_consumer() {
+    let pool = ThreadPool::builder().pool_size(4).create().unwrap();
+    let state = Arc::new((Mutex::new(Vec::new()), Condvar::new()));
+
+    let num_items = 100;
+    {
+        let state = state.clone();
+        pool.spawn_ok(async move {
+            let (items, condvar) = &*state;
+            for i in 0..num_items {
+                items.lock().await.push(i);
+                condvar.notify_one();
+            }
+        });
+    }
+
+    block_on(async {
+        let (items, condvar) = &*state;
+        let mut received = Vec::new();
+        let mut guard = items.lock().await;
+        while received.len() < num_items {
+            while guard.is_empty() {
+                guard = condvar.wait(guard).await;
+            }
+            received.append(&mut guard);
+        }
+        assert_eq!(received, (0..num_items).collect::<Vec<_>>());
+    });
+}
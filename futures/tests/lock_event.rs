@@ -0,0 +1,108 @@
+use futures::channel::mpsc;
+use futures::executor::{block_on, ThreadPool};
+use futures::future::FutureExt;
+use futures::lock::Event;
+use futures::stream::StreamExt;
+use futures::task::Context;
+use futures_test::task::{new_count_waker, panic_context};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+#[test]
+fn wait_completes_once_set() {
+    let event = Event::new();
+    assert!(!event.is_set());
+    let (waker, counter) = new_count_waker();
+    let mut cx = Context::from_waker(&waker);
+
+    let mut first = Box::pin(event.wait());
+    let mut second = Box::pin(event.wait());
+    assert!(first.poll_unpin(&mut cx).is_pending());
+    assert!(second.poll_unpin(&mut cx).is_pending());
+
+    event.set();
+    assert!(event.is_set());
+    assert_eq!(counter, 2);
+    assert!(first.poll_unpin(&mut panic_context()).is_ready());
+    assert!(second.poll_unpin(&mut panic_context()).is_ready());
+
+    // The event stays set for later waiters.
+    assert!(Box::pin(event.wait()).poll_unpin(&mut panic_context()).is_ready());
+}
+
+#[test]
+fn reset_makes_waiters_wait_again() {
+    let event = Event::new();
+    event.set();
+    event.reset();
+    assert!(!event.is_set());
+
+    let (waker, counter) = new_count_waker();
+    let mut cx = Context::from_waker(&waker);
+    let mut wait = Box::pin(event.wait());
+    assert!(wait.poll_unpin(&mut cx).is_pending());
+
+    event.set();
+    assert_eq!(counter, 1);
+    assert!(wait.poll_unpin(&mut panic_context()).is_ready());
+}
+
+#[test]
+fn woken_waiter_completes_after_reset() {
+    let event = Event::new();
+    let (waker, counter) = new_count_waker();
+    let mut cx = Context::from_waker(&waker);
+    let mut woken = Box::pin(event.wait());
+    assert!(woken.poll_unpin(&mut cx).is_pending());
+
+    event.set();
+    event.reset();
+    assert_eq!(counter, 1);
+    assert!(woken.poll_unpin(&mut panic_context()).is_ready());
+
+    let mut late = Box::pin(event.wait());
+    assert!(late.poll_unpin(&mut cx).is_pending());
+}
+
+#[test]
+fn dropped_waiter_is_removed() {
+    let event = Event::new();
+    let (waker, counter) = new_count_waker();
+    let mut cx = Context::from_waker(&waker);
+
+    let mut first = Box::pin(event.wait());
+    let mut second = Box::pin(event.wait());
+    assert!(first.poll_unpin(&mut cx).is_pending());
+    assert!(second.poll_unpin(&mut cx).is_pending());
+    drop(first);
+
+    event.set();
+    assert_eq!(counter, 1);
+    assert!(second.poll_unpin(&mut panic_context()).is_ready());
+}
+
+#[cfg_attr(miri, ignore)] // https://github.com/rust-lang/miri/issues/1038
+#[test]
+fn gate_spawned_tasks() {
+    let pool = ThreadPool::builder().pool_size(4).create().unwrap();
+    let event = Arc::new(Event::new());
+    let passed = Arc::new(AtomicUsize::new(0));
+
+    let num_tasks = 100;
+    let (tx, rx) = mpsc::unbounded();
+    for _ in 0..num_tasks {
+        let event = event.clone();
+        let passed = passed.clone();
+        let tx = tx.clone();
+        pool.spawn_ok(async move {
+            event.wait().await;
+            passed.fetch_add(1, Ordering::SeqCst);
+            tx.unbounded_send(()).unwrap();
+        });
+    }
+    drop(tx);
+
+    event.set();
+    block_on(rx.collect::<Vec<_>>());
+    assert_eq!(passed.load(Ordering::SeqCst), num_tasks);
+}
@@ -3,16 +3,19 @@ use futures::executor::{block_on, ThreadPool};
 use futures::future::{ready, FutureExt};
 use futures::lock::Mutex;
 use futures::stream::StreamExt;
-use futures::task::{Context, SpawnExt};
+use futures::task::Context;
 use futures_test::future::FutureTestExt;
 use futures_test::task::{new_count_waker, panic_context};
+use std::panic::{self, AssertUnwindSafe};
 use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
 
 #[test]
 fn mutex_acquire_uncontested() {
     let mutex = Mutex::new(());
     for _ in 0..10 {
-        assert!(mutex.lock().poll_unpin(&mut panic_context()).is_ready());
+        assert!(Box::pin(mutex.lock()).poll_unpin(&mut panic_context()).is_ready());
     }
 }
 
@@ -20,11 +23,11 @@ fn mutex_acquire_uncontested() {
 fn mutex_wakes_waiters() {
     let mutex = Mutex::new(());
     let (waker, counter) = new_count_waker();
-    let lock = mutex.lock().poll_unpin(&mut panic_context());
+    let lock = Box::pin(mutex.lock()).poll_unpin(&mut panic_context());
     assert!(lock.is_ready());
 
     let mut cx = Context::from_waker(&waker);
-    let mut waiter = mutex.lock();
+    let mut waiter = Box::pin(mutex.lock());
     assert!(waiter.poll_unpin(&mut cx).is_pending());
     assert_eq!(counter, 0);
 
@@ -34,6 +37,25 @@ fn mutex_wakes_waiters() {
     assert!(waiter.poll_unpin(&mut panic_context()).is_ready());
 }
 
+#[test]
+fn mutex_dropped_woken_waiter_wakes_another() {
+    let mutex = Mutex::new(());
+    let (waker, counter) = new_count_waker();
+    let mut cx = Context::from_waker(&waker);
+    let lock = mutex.try_lock().unwrap();
+
+    let mut first = Box::pin(mutex.lock());
+    let mut second = Box::pin(mutex.lock());
+    assert!(first.poll_unpin(&mut cx).is_pending());
+    assert!(second.poll_unpin(&mut cx).is_pending());
+
+    drop(lock);
+    assert_eq!(counter, 1);
+    drop(first);
+    assert_eq!(counter, 2);
+    assert!(second.poll_unpin(&mut panic_context()).is_ready());
+}
+
 #[cfg_attr(miri, ignore)] // https://github.com/rust-lang/miri/issues/1038
 #[test]
 fn mutex_contested() {
@@ -47,14 +69,13 @@ fn mutex_contested() {
     for _ in 0..num_tasks {
         let tx = tx.clone();
         let mutex = mutex.clone();
-        pool.spawn(async move {
+        pool.spawn_ok(async move {
             let mut lock = mutex.lock().await;
             ready(()).pending_once().await;
             *lock += 1;
             tx.unbounded_send(()).unwrap();
             drop(lock);
-        })
-        .unwrap();
+        });
     }
 
     block_on(async {
@@ -65,3 +86,162 @@ fn mutex_contested() {
         assert_eq!(num_tasks, *lock);
     })
 }
+
+#[test]
+fn fair_mutex_hands_over_in_order() {
+    let mutex = Mutex::new_fair(());
+    let (waker, counter) = new_count_waker();
+    let mut cx = Context::from_waker(&waker);
+    let lock = mutex.try_lock().unwrap();
+
+    let mut first = Box::pin(mutex.lock());
+    let mut second = Box::pin(mutex.lock());
+    assert!(first.poll_unpin(&mut cx).is_pending());
+    assert!(second.poll_unpin(&mut cx).is_pending());
+
+    drop(lock);
+    assert_eq!(counter, 1);
+    // The lock was handed over to the first waiter.
+    assert!(mutex.try_lock().is_none());
+    assert!(second.poll_unpin(&mut cx).is_pending());
+    let lock = first.poll_unpin(&mut panic_context());
+    assert!(lock.is_ready());
+
+    drop(lock);
+    assert_eq!(counter, 2);
+    assert!(second.poll_unpin(&mut panic_context()).is_ready());
+}
+
+#[test]
+fn fair_mutex_handed_over_lock_is_exclusive() {
+    let mutex = Mutex::new_fair(());
+    let (waker, counter) = new_count_waker();
+    let mut cx = Context::from_waker(&waker);
+    let lock = mutex.try_lock().unwrap();
+
+    let mut first = Box::pin(mutex.lock());
+    let mut second = Box::pin(mutex.lock());
+    assert!(first.poll_unpin(&mut cx).is_pending());
+    assert!(second.poll_unpin(&mut cx).is_pending());
+
+    drop(lock);
+    let lock = first.poll_unpin(&mut panic_context());
+    assert!(lock.is_ready());
+
+    // Taking the handed over lock doesn't hand it over again.
+    assert!(mutex.try_lock().is_none());
+    assert_eq!(counter, 1);
+    assert!(second.poll_unpin(&mut cx).is_pending());
+
+    drop(lock);
+    assert_eq!(counter, 2);
+    assert!(second.poll_unpin(&mut panic_context()).is_ready());
+}
+
+#[test]
+fn fair_mutex_dropped_waiter_hands_over() {
+    let mutex = Mutex::new_fair(());
+    let (waker, counter) = new_count_waker();
+    let mut cx = Context::from_waker(&waker);
+    let lock = mutex.try_lock().unwrap();
+
+    let mut first = Box::pin(mutex.lock());
+    let mut second = Box::pin(mutex.lock());
+    assert!(first.poll_unpin(&mut cx).is_pending());
+    assert!(second.poll_unpin(&mut cx).is_pending());
+
+    drop(lock);
+    drop(first);
+    assert_eq!(counter, 2);
+    assert!(second.poll_unpin(&mut panic_context()).is_ready());
+    assert!(mutex.try_lock().is_some());
+}
+
+#[cfg_attr(miri, ignore)] // https://github.com/rust-lang/miri/issues/1038
+#[test]
+fn fair_mutex_contested() {
+    let (tx, mut rx) = mpsc::unbounded();
+    let pool = ThreadPool::builder().pool_size(16).create().unwrap();
+
+    let tx = Arc::new(tx);
+    let mutex = Arc::new(Mutex::new_fair(0));
+
+    let num_tasks = 1000;
+    for _ in 0..num_tasks {
+        let tx = tx.clone();
+        let mutex = mutex.clone();
+        pool.spawn_ok(async move {
+            let mut lock = mutex.lock().await;
+            ready(()).pending_once().await;
+            *lock += 1;
+            tx.unbounded_send(()).unwrap();
+            drop(lock);
+        });
+    }
+
+    block_on(async {
+        for _ in 0..num_tasks {
+            rx.next().await.unwrap();
+        }
+        let lock = mutex.lock().await;
+        assert_eq!(num_tasks, *lock);
+    })
+}
+
+#[cfg_attr(miri, ignore)] // Miri is too slow
+#[test]
+fn mutex_blocking_lock() {
+    let mutex = Arc::new(Mutex::new(0));
+    let lock = mutex.try_lock().unwrap();
+
+    let handle = {
+        let mutex = mutex.clone();
+        thread::spawn(move || *mutex.blocking_lock() += 1)
+    };
+    thread::sleep(Duration::from_millis(50));
+    drop(lock);
+
+    handle.join().unwrap();
+    assert_eq!(*mutex.blocking_lock(), 1);
+}
+
+#[test]
+fn mutex_poisoned_by_panic() {
+    let mutex = Mutex::new(0);
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        let mut guard = mutex.try_lock().unwrap();
+        *guard += 1;
+        panic!("panic while holding the lock");
+    }));
+    assert!(result.is_err());
+    assert!(mutex.is_poisoned());
+
+    // `lock` ignores the poison, `lock_checked` reports it.
+    drop(block_on(mutex.lock()));
+    let guard = block_on(mutex.lock_checked()).unwrap_err().into_inner();
+    assert_eq!(*guard, 1);
+    drop(guard);
+
+    mutex.clear_poison();
+    assert!(!mutex.is_poisoned());
+    assert!(block_on(mutex.lock_checked()).is_ok());
+}
+
+#[test]
+fn mutex_not_poisoned_by_lock_taken_while_panicking() {
+    struct LockOnDrop<'a>(&'a Mutex<()>);
+
+    impl Drop for LockOnDrop<'_> {
+        fn drop(&mut self) {
+            drop(self.0.try_lock().unwrap());
+        }
+    }
+
+    let mutex = Mutex::new(());
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        let _lock_on_drop = LockOnDrop(&mutex);
+        panic!("panic without holding the lock");
+    }));
+    assert!(result.is_err());
+    assert!(!mutex.is_poisoned());
+}
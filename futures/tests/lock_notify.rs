@@ -0,0 +1,87 @@
+use futures::executor::block_on;
+use futures::future::FutureExt;
+use futures::lock::Notify;
+use futures::task::Context;
+use futures_test::task::{new_count_waker, panic_context};
+use std::sync::Arc;
+use std::thread;
+
+#[test]
+fn notify_one_stores_permit() {
+    let notify = Notify::new();
+    notify.notify_one();
+    notify.notify_one();
+
+    assert!(Box::pin(notify.notified()).poll_unpin(&mut panic_context()).is_ready());
+    // Only a single permit was stored.
+    assert!(Box::pin(notify.notified()).poll_unpin(&mut panic_context()).is_pending());
+}
+
+#[test]
+fn notify_one_wakes_waiters_in_order() {
+    let notify = Notify::new();
+    let (waker, counter) = new_count_waker();
+    let mut cx = Context::from_waker(&waker);
+
+    let mut first = Box::pin(notify.notified());
+    let mut second = Box::pin(notify.notified());
+    assert!(first.poll_unpin(&mut cx).is_pending());
+    assert!(second.poll_unpin(&mut cx).is_pending());
+
+    notify.notify_one();
+    assert_eq!(counter, 1);
+    assert!(first.poll_unpin(&mut panic_context()).is_ready());
+    assert!(second.poll_unpin(&mut cx).is_pending());
+
+    notify.notify_one();
+    assert_eq!(counter, 2);
+    assert!(second.poll_unpin(&mut panic_context()).is_ready());
+}
+
+#[test]
+fn dropped_waiter_passes_on_notification() {
+    let notify = Notify::new();
+    let (waker, counter) = new_count_waker();
+    let mut cx = Context::from_waker(&waker);
+
+    let mut first = Box::pin(notify.notified());
+    let mut second = Box::pin(notify.notified());
+    assert!(first.poll_unpin(&mut cx).is_pending());
+    assert!(second.poll_unpin(&mut cx).is_pending());
+
+    notify.notify_one();
+    drop(first);
+    assert_eq!(counter, 2);
+    assert!(second.poll_unpin(&mut panic_context()).is_ready());
+}
+
+#[test]
+fn notify_waiters_wakes_created_futures() {
+    let notify = Notify::new();
+    let (waker, counter) = new_count_waker();
+    let mut cx = Context::from_waker(&waker);
+
+    let mut polled = Box::pin(notify.notified());
+    assert!(polled.poll_unpin(&mut cx).is_pending());
+    let mut unpolled = Box::pin(notify.notified());
+
+    notify.notify_waiters();
+    assert_eq!(counter, 1);
+    assert!(polled.poll_unpin(&mut panic_context()).is_ready());
+    assert!(unpolled.poll_unpin(&mut panic_context()).is_ready());
+
+    // No permit was stored.
+    assert!(Box::pin(notify.notified()).poll_unpin(&mut panic_context()).is_pending());
+}
+
+#[cfg_attr(miri, ignore)] // Miri is too slow
+#[test]
+fn notify_from_other_thread() {
+    let notify = Arc::new(Notify::new());
+    let handle = {
+        let notify = notify.clone();
+        thread::spawn(move || notify.notify_one())
+    };
+    block_on(notify.notified());
+    handle.join().unwrap();
+}
@@ -0,0 +1,208 @@
+use futures::channel::mpsc;
+use futures::executor::{block_on, ThreadPool};
+use futures::future::{ready, FutureExt};
+use futures::lock::{RwLock, RwLockUpgradableReadGuard};
+use futures::stream::StreamExt;
+use futures::task::Context;
+use futures_test::future::FutureTestExt;
+use futures_test::task::{new_count_waker, panic_context};
+use std::sync::Arc;
+
+#[test]
+fn rwlock_readers_share() {
+    let lock = RwLock::new(1);
+    let first = lock.read().now_or_never().unwrap();
+    let second = lock.try_read().unwrap();
+    assert_eq!(*first + *second, 2);
+    assert!(lock.try_write().is_none());
+
+    drop((first, second));
+    assert!(lock.try_write().is_some());
+}
+
+#[test]
+fn rwlock_writer_excludes() {
+    let lock = RwLock::new(0);
+    let mut guard = lock.write().now_or_never().unwrap();
+    *guard += 1;
+    assert!(lock.try_read().is_none());
+    assert!(lock.try_write().is_none());
+    assert!(Box::pin(lock.read()).poll_unpin(&mut panic_context()).is_pending());
+
+    drop(guard);
+    assert_eq!(*lock.try_read().unwrap(), 1);
+}
+
+#[test]
+fn rwlock_writer_wakes_readers() {
+    let lock = RwLock::new(());
+    let (waker, counter) = new_count_waker();
+    let mut cx = Context::from_waker(&waker);
+    let guard = lock.try_write().unwrap();
+
+    let mut first = Box::pin(lock.read());
+    let mut second = Box::pin(lock.read());
+    assert!(first.poll_unpin(&mut cx).is_pending());
+    assert!(second.poll_unpin(&mut cx).is_pending());
+    assert_eq!(counter, 0);
+
+    drop(guard);
+    assert_eq!(counter, 2);
+    assert!(first.poll_unpin(&mut panic_context()).is_ready());
+    assert!(second.poll_unpin(&mut panic_context()).is_ready());
+}
+
+#[test]
+fn rwlock_prefers_writers() {
+    let lock = RwLock::new(());
+    let (waker, counter) = new_count_waker();
+    let mut cx = Context::from_waker(&waker);
+    let reader = lock.try_read().unwrap();
+
+    let mut writer = Box::pin(lock.write());
+    assert!(writer.poll_unpin(&mut cx).is_pending());
+
+    // New readers wait for the writer.
+    assert!(lock.try_read().is_none());
+    let mut late_reader = Box::pin(lock.read());
+    assert!(late_reader.poll_unpin(&mut cx).is_pending());
+
+    drop(reader);
+    assert_eq!(counter, 1);
+    let guard = writer.poll_unpin(&mut panic_context());
+    assert!(guard.is_ready());
+    assert!(late_reader.poll_unpin(&mut cx).is_pending());
+
+    drop(guard);
+    assert_eq!(counter, 2);
+    assert!(late_reader.poll_unpin(&mut panic_context()).is_ready());
+}
+
+#[test]
+fn rwlock_dropped_writer_releases_readers() {
+    let lock = RwLock::new(());
+    let (waker, counter) = new_count_waker();
+    let mut cx = Context::from_waker(&waker);
+    let reader = lock.try_read().unwrap();
+
+    let mut writer = Box::pin(lock.write());
+    assert!(writer.poll_unpin(&mut cx).is_pending());
+    let mut late_reader = Box::pin(lock.read());
+    assert!(late_reader.poll_unpin(&mut cx).is_pending());
+
+    drop(writer);
+    assert_eq!(counter, 1);
+    assert!(late_reader.poll_unpin(&mut panic_context()).is_ready());
+    drop(reader);
+}
+
+#[test]
+fn rwlock_owned_guards() {
+    let lock = Arc::new(RwLock::new(0));
+    let mut guard = block_on(lock.clone().write_owned());
+    *guard += 1;
+    assert!(lock.try_read().is_none());
+    drop(guard);
+
+    let first = block_on(lock.clone().read_owned());
+    let second = block_on(lock.clone().read_owned());
+    drop(lock);
+    assert_eq!(*first + *second, 2);
+}
+
+#[test]
+fn rwlock_upgradable_read_shares_with_readers() {
+    let lock = RwLock::new(0);
+    let guard = lock.upgradable_read().now_or_never().unwrap();
+    assert!(lock.try_read().is_some());
+    assert!(lock.try_upgradable_read().is_none());
+    assert!(lock.try_write().is_none());
+
+    let mut guard = RwLockUpgradableReadGuard::try_upgrade(guard).unwrap();
+    *guard += 1;
+    assert!(lock.try_read().is_none());
+    drop(guard);
+    assert_eq!(*lock.try_upgradable_read().unwrap(), 1);
+}
+
+#[test]
+fn rwlock_upgrade_waits_for_readers() {
+    let lock = RwLock::new(());
+    let (waker, counter) = new_count_waker();
+    let mut cx = Context::from_waker(&waker);
+    let reader = lock.try_read().unwrap();
+    let guard = lock.try_upgradable_read().unwrap();
+    let guard = RwLockUpgradableReadGuard::try_upgrade(guard).unwrap_err();
+
+    let mut writer = Box::pin(lock.write());
+    assert!(writer.poll_unpin(&mut cx).is_pending());
+    let mut upgrade = Box::pin(RwLockUpgradableReadGuard::upgrade(guard));
+    assert!(upgrade.poll_unpin(&mut cx).is_pending());
+    // New readers wait for the upgrade.
+    assert!(lock.try_read().is_none());
+
+    // The upgrade acquires the lock before the waiting writer.
+    drop(reader);
+    assert_eq!(counter, 1);
+    let guard = upgrade.poll_unpin(&mut panic_context());
+    assert!(guard.is_ready());
+    assert!(writer.poll_unpin(&mut cx).is_pending());
+
+    drop(guard);
+    assert_eq!(counter, 2);
+    assert!(writer.poll_unpin(&mut panic_context()).is_ready());
+}
+
+#[test]
+fn rwlock_dropped_upgrade_releases_lock() {
+    let lock = RwLock::new(());
+    let reader = lock.try_read().unwrap();
+    let guard = lock.try_upgradable_read().unwrap();
+
+    let mut upgrade = Box::pin(RwLockUpgradableReadGuard::upgrade(guard));
+    assert!(upgrade.poll_unpin(&mut panic_context()).is_pending());
+    drop(upgrade);
+    drop(reader);
+    assert!(lock.try_write().is_some());
+}
+
+#[cfg_attr(miri, ignore)] // https://github.com/rust-lang/miri/issues/1038
+#[test]
+fn rwlock_contested() {
+    let (tx, mut rx) = mpsc::unbounded();
+    let pool = ThreadPool::builder().pool_size(16).create().unwrap();
+
+    let tx = Arc::new(tx);
+    let lock = Arc::new(RwLock::new(0));
+
+    let num_tasks = 1000;
+    for i in 0..num_tasks {
+        let tx = tx.clone();
+        let lock = lock.clone();
+        pool.spawn_ok(async move {
+            if i % 4 == 0 {
+                let mut guard = lock.write().await;
+                ready(()).pending_once().await;
+                *guard += 1;
+            } else if i % 4 == 1 {
+                let guard = lock.upgradable_read().await;
+                ready(()).pending_once().await;
+                let mut guard = RwLockUpgradableReadGuard::upgrade(guard).await;
+                *guard += 1;
+            } else {
+                let guard = lock.read().await;
+                ready(()).pending_once().await;
+                assert!(*guard <= num_tasks / 2);
+            }
+            tx.unbounded_send(()).unwrap();
+        });
+    }
+
+    block_on(async {
+        for _ in 0..num_tasks {
+            rx.next().await.unwrap();
+        }
+        let guard = lock.read().await;
+        assert_eq!(num_tasks / 2, *guard);
+    })
+}
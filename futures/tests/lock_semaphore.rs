@@ -0,0 +1,136 @@
+use futures::channel::mpsc;
+use futures::executor::{block_on, ThreadPool};
+use futures::future::{ready, FutureExt};
+use futures::lock::Semaphore;
+use futures::stream::StreamExt;
+use futures::task::Context;
+use futures_test::future::FutureTestExt;
+use futures_test::task::{new_count_waker, panic_context};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+#[test]
+fn semaphore_acquire_uncontested() {
+    let semaphore = Semaphore::new(3);
+    let permit = semaphore.acquire(2).now_or_never().unwrap();
+    assert_eq!(permit.permits(), 2);
+    assert_eq!(semaphore.available_permits(), 1);
+    assert!(semaphore.try_acquire(2).is_none());
+
+    drop(permit);
+    assert_eq!(semaphore.available_permits(), 3);
+}
+
+#[test]
+fn semaphore_wakes_waiters() {
+    let semaphore = Semaphore::new(1);
+    let (waker, counter) = new_count_waker();
+    let mut cx = Context::from_waker(&waker);
+    let permit = semaphore.try_acquire(1).unwrap();
+
+    let mut waiter = Box::pin(semaphore.acquire(1));
+    assert!(waiter.poll_unpin(&mut cx).is_pending());
+    assert_eq!(counter, 0);
+
+    drop(permit);
+    assert_eq!(counter, 1);
+    // The permit was handed to the waiter.
+    assert!(semaphore.try_acquire(1).is_none());
+    assert!(waiter.poll_unpin(&mut panic_context()).is_ready());
+}
+
+#[test]
+fn semaphore_serves_waiters_in_order() {
+    let semaphore = Semaphore::new(1);
+    let (waker, counter) = new_count_waker();
+    let mut cx = Context::from_waker(&waker);
+    let permit = semaphore.try_acquire(1).unwrap();
+
+    let mut large = Box::pin(semaphore.acquire(2));
+    assert!(large.poll_unpin(&mut cx).is_pending());
+    let mut small = Box::pin(semaphore.acquire(1));
+    assert!(small.poll_unpin(&mut cx).is_pending());
+
+    // Adding a permit isn't enough for the first waiter, which keeps the
+    // second one waiting.
+    semaphore.add_permits(1);
+    assert_eq!(counter, 0);
+
+    drop(permit);
+    assert_eq!(counter, 1);
+    let large = large.poll_unpin(&mut panic_context());
+    assert!(large.is_ready());
+    assert!(small.poll_unpin(&mut cx).is_pending());
+
+    drop(large);
+    assert_eq!(counter, 2);
+    assert!(small.poll_unpin(&mut panic_context()).is_ready());
+}
+
+#[test]
+fn semaphore_dropped_waiter_returns_permits() {
+    let semaphore = Semaphore::new(1);
+    let (waker, counter) = new_count_waker();
+    let mut cx = Context::from_waker(&waker);
+    let permit = semaphore.try_acquire(1).unwrap();
+
+    let mut first = Box::pin(semaphore.acquire(1));
+    assert!(first.poll_unpin(&mut cx).is_pending());
+    let mut second = Box::pin(semaphore.acquire(1));
+    assert!(second.poll_unpin(&mut cx).is_pending());
+
+    drop(permit);
+    assert_eq!(counter, 1);
+    drop(first);
+    assert_eq!(counter, 2);
+    assert!(second.poll_unpin(&mut panic_context()).is_ready());
+}
+
+#[test]
+fn semaphore_forget_permit() {
+    let semaphore = Semaphore::new(2);
+    semaphore.try_acquire(1).unwrap().forget();
+    assert_eq!(semaphore.available_permits(), 1);
+}
+
+#[test]
+fn semaphore_owned_permits() {
+    let semaphore = Arc::new(Semaphore::new(1));
+    let permit = block_on(semaphore.clone().acquire_owned(1));
+    assert!(semaphore.clone().try_acquire_owned(1).is_none());
+    drop(permit);
+    assert!(semaphore.try_acquire_owned(1).is_some());
+}
+
+#[cfg_attr(miri, ignore)] // https://github.com/rust-lang/miri/issues/1038
+#[test]
+fn semaphore_contested() {
+    let (tx, mut rx) = mpsc::unbounded();
+    let pool = ThreadPool::builder().pool_size(16).create().unwrap();
+
+    let tx = Arc::new(tx);
+    let semaphore = Arc::new(Semaphore::new(4));
+    let active = Arc::new(AtomicUsize::new(0));
+
+    let num_tasks = 1000;
+    for _ in 0..num_tasks {
+        let tx = tx.clone();
+        let semaphore = semaphore.clone();
+        let active = active.clone();
+        pool.spawn_ok(async move {
+            let permit = semaphore.acquire_owned(1).await;
+            assert!(active.fetch_add(1, Ordering::SeqCst) < 4);
+            ready(()).pending_once().await;
+            active.fetch_sub(1, Ordering::SeqCst);
+            drop(permit);
+            tx.unbounded_send(()).unwrap();
+        });
+    }
+
+    block_on(async {
+        for _ in 0..num_tasks {
+            rx.next().await.unwrap();
+        }
+        assert_eq!(semaphore.available_permits(), 4);
+    })
+}
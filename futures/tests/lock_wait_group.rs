@@ -0,0 +1,78 @@
+use futures::channel::oneshot;
+use futures::executor::{block_on, ThreadPool};
+use futures::future::FutureExt;
+use futures::lock::WaitGroup;
+use futures::task::Context;
+use futures_test::task::{new_count_waker, panic_context};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+#[test]
+fn wait_without_clones() {
+    assert!(Box::pin(WaitGroup::new().wait()).poll_unpin(&mut panic_context()).is_ready());
+}
+
+#[test]
+fn wait_for_clones() {
+    let wait_group = WaitGroup::new();
+    let first = wait_group.clone();
+    let second = first.clone();
+    assert_eq!(wait_group.count(), 3);
+
+    let (waker, counter) = new_count_waker();
+    let mut cx = Context::from_waker(&waker);
+    let mut wait = Box::pin(wait_group.wait());
+    assert!(wait.poll_unpin(&mut cx).is_pending());
+    assert_eq!(second.count(), 2);
+
+    drop(first);
+    assert_eq!(counter, 0);
+    assert!(wait.poll_unpin(&mut cx).is_pending());
+
+    drop(second);
+    assert_eq!(counter, 1);
+    assert!(wait.poll_unpin(&mut panic_context()).is_ready());
+}
+
+#[test]
+fn many_waiters() {
+    let wait_group = WaitGroup::new();
+    let (waker, counter) = new_count_waker();
+    let mut cx = Context::from_waker(&waker);
+
+    let mut first = Box::pin(wait_group.clone().wait());
+    let mut second = Box::pin(wait_group.clone().wait());
+    assert!(first.poll_unpin(&mut cx).is_pending());
+    assert!(second.poll_unpin(&mut cx).is_pending());
+
+    drop(wait_group);
+    assert_eq!(counter, 2);
+    assert!(first.poll_unpin(&mut panic_context()).is_ready());
+    assert!(second.poll_unpin(&mut panic_context()).is_ready());
+}
+
+#[cfg_attr(miri, ignore)] // https://github.com/rust-lang/miri/issues/1038
+#[test]
+fn wait_for_spawned_tasks() {
+    let pool = ThreadPool::builder().pool_size(4).create().unwrap();
+    let wait_group = WaitGroup::new();
+    let completed = Arc::new(AtomicUsize::new(0));
+
+    let num_tasks = 100;
+    let (tx, rx) = oneshot::channel::<()>();
+    let rx = rx.shared();
+    for _ in 0..num_tasks {
+        let wait_group = wait_group.clone();
+        let completed = completed.clone();
+        let rx = rx.clone();
+        pool.spawn_ok(async move {
+            rx.await.unwrap();
+            completed.fetch_add(1, Ordering::SeqCst);
+            drop(wait_group);
+        });
+    }
+
+    tx.send(()).unwrap();
+    block_on(wait_group.wait());
+    assert_eq!(completed.load(Ordering::SeqCst), num_tasks);
+}
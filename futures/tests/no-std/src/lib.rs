@@ -29,3 +29,21 @@ pub use futures_util::task::AtomicWaker as _;
 #[cfg(feature = "futures-util-alloc")]
 #[cfg(target_has_atomic = "ptr")]
 pub use futures_util::stream::FuturesOrdered as _;
+
+#[cfg(feature = "futures-core-portable-atomic")]
+pub use futures_core::task::__internal::AtomicWaker as _;
+
+#[cfg(feature = "futures-channel-portable-atomic")]
+pub use futures_channel::oneshot as _;
+
+#[cfg(feature = "futures-portable-atomic")]
+pub use futures::stream::FuturesUnordered as _;
+
+#[cfg(feature = "futures-util-portable-atomic")]
+pub use futures_util::future::{AbortHandle as _, TryJoinAll as _};
+
+#[cfg(feature = "futures-util-portable-atomic")]
+pub use futures_util::stream::{FuturesUnordered as _, SelectAll as _};
+
+#[cfg(feature = "futures-util-portable-atomic")]
+pub use futures_util::task::AtomicWaker as _;
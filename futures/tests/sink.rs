@@ -5,7 +5,7 @@ use futures::ready;
 use futures::sink::{self, Sink, SinkErrInto, SinkExt};
 use futures::stream::{self, Stream, StreamExt};
 use futures::task::{self, ArcWake, Context, Poll, Waker};
-use futures_test::task::panic_context;
+use futures_test::task::{noop_context, panic_context};
 use std::cell::{Cell, RefCell};
 use std::collections::VecDeque;
 use std::convert::Infallible;
@@ -235,6 +235,24 @@ fn vecdeque_sink() {
     assert_eq!(deque.pop_front(), None);
 }
 
+#[test]
+fn drain_counts() {
+    let mut sink = sink::drain();
+    block_on(sink.send_all(&mut stream::iter(vec![Ok(1), Ok(2), Ok(3)]))).unwrap();
+    assert_eq!(sink.count(), 3);
+}
+
+#[test]
+fn sink_inspect() {
+    let mut seen = Vec::new();
+    let mut sink = Vec::new().sink_inspect(|item: &i32| seen.push(*item * 10));
+    block_on(sink.send(1)).unwrap();
+    block_on(sink.send(2)).unwrap();
+    assert_eq!(sink.get_ref(), &[1, 2]);
+    drop(sink);
+    assert_eq!(seen, vec![10, 20]);
+}
+
 #[test]
 fn send() {
     let mut v = Vec::new();
@@ -265,6 +283,59 @@ fn send_all() {
 
 // Test that `start_send` on an `mpsc` channel does indeed block when the
 // channel is full
+#[test]
+fn send_timeout() {
+    use futures::sink::SendTimeoutError;
+
+    let (mut tx, mut rx) = mpsc::channel::<i32>(0);
+
+    // the sender's own slot takes the item, but it stays unflushed until
+    // the receiver reads it
+    assert_eq!(
+        block_on(tx.send_timeout(1, future::ready(()))),
+        Err(SendTimeoutError::FlushTimeout)
+    );
+    assert_eq!(block_on(tx.send_timeout(2, future::ready(()))), Err(SendTimeoutError::Timeout(2)));
+
+    assert_eq!(rx.try_next().unwrap(), Some(1));
+    assert_eq!(block_on(tx.feed_timeout(3, future::pending::<()>())), Ok(()));
+    assert_eq!(rx.try_next().unwrap(), Some(3));
+
+    drop(rx);
+    let err = block_on(tx.feed_timeout(4, future::pending::<()>())).unwrap_err();
+    assert!(!err.is_timeout());
+    assert_eq!(err.into_item(), None);
+}
+
+// test that feed_all sends every item without flushing, even while the
+// stream is pending
+#[test]
+fn feed_all() {
+    let mut sink = sink::from_fn(
+        (Vec::new(), 0),
+        |_, _| Poll::Ready(Ok::<_, ()>(())),
+        |(data, _): &mut (Vec<i32>, usize), item| {
+            data.push(item);
+            Ok(())
+        },
+        |(_, flushes), _| {
+            *flushes += 1;
+            Poll::Ready(Ok(()))
+        },
+    );
+
+    let (tx, rx) = mpsc::unbounded();
+    tx.unbounded_send(1).unwrap();
+    {
+        let mut feed = sink.feed_all(rx);
+        assert!(feed.poll_unpin(&mut noop_context()).is_pending());
+        tx.unbounded_send(2).unwrap();
+        tx.close_channel();
+        assert_eq!(feed.poll_unpin(&mut noop_context()), Poll::Ready(Ok(())));
+    }
+    assert_eq!(sink.get_ref(), &(vec![1, 2], 0));
+}
+
 #[test]
 fn mpsc_blocking_start_send() {
     let (mut tx, mut rx) = mpsc::channel::<i32>(0);
@@ -335,6 +406,28 @@ fn with_flat_map() {
     assert_eq!(sink.get_ref(), &[1, 2, 2, 3, 3, 3]);
 }
 
+#[test]
+fn try_with_flat_map() {
+    #[derive(Debug, PartialEq)]
+    enum Error {
+        Odd(usize),
+    }
+
+    impl From<Infallible> for Error {
+        fn from(e: Infallible) -> Self {
+            match e {}
+        }
+    }
+
+    let mut sink = Vec::new().try_with_flat_map(|item| {
+        stream::iter(vec![item; item]).map(|i| if i % 2 == 0 { Ok(i) } else { Err(Error::Odd(i)) })
+    });
+    block_on(sink.send(0)).unwrap();
+    block_on(sink.send(2)).unwrap();
+    assert_eq!(block_on(sink.send(3)), Err(Error::Odd(3)));
+    assert_eq!(sink.get_ref(), &[2, 2]);
+}
+
 // Check that `with` propagates `poll_ready` to the inner sink.
 // Regression test for the issue #1834.
 #[test]
@@ -442,6 +535,141 @@ fn buffer() {
     })
 }
 
+// test that a buffer reports its occupancy and calls its callback when the
+// thresholds are crossed
+#[test]
+fn buffer_occupancy() {
+    use futures::sink::BufferLevel;
+
+    let levels = Rc::new(RefCell::new(Vec::new()));
+    let (sink, allow) = manual_allow::<i32>();
+    let mut sink = sink.buffer(4).on_threshold(1, 3, {
+        let levels = levels.clone();
+        move |level| levels.borrow_mut().push(level)
+    });
+    assert_eq!(sink.capacity(), 4);
+
+    flag_cx(|_, cx| {
+        for i in 0..3 {
+            unwrap(sink.poll_ready_unpin(cx));
+            sink.start_send_unpin(i).unwrap();
+        }
+        assert_eq!(sink.len(), 3);
+        assert_eq!(*levels.borrow(), vec![BufferLevel::High]);
+
+        allow.start();
+        unwrap(sink.poll_flush_unpin(cx));
+        assert!(sink.is_empty());
+        assert_eq!(sink.high_water_mark(), 3);
+        assert_eq!(*levels.borrow(), vec![BufferLevel::High, BufferLevel::Low]);
+        assert_eq!(sink.get_ref().data, vec![0, 1, 2]);
+
+        sink.reset_high_water_mark();
+        assert_eq!(sink.high_water_mark(), 0);
+    })
+}
+
+// test that a lingering buffer flushes the underlying sink once its deadline
+// has fired, and restarts the deadline with the next item
+#[test]
+fn buffer_linger() {
+    struct CountFlushes {
+        data: Vec<i32>,
+        flushes: usize,
+    }
+
+    impl Sink<i32> for CountFlushes {
+        type Error = ();
+
+        fn poll_ready(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Result<(), ()>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn start_send(mut self: Pin<&mut Self>, item: i32) -> Result<(), ()> {
+            self.data.push(item);
+            Ok(())
+        }
+
+        fn poll_flush(mut self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Result<(), ()>> {
+            self.flushes += 1;
+            Poll::Ready(Ok(()))
+        }
+
+        fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), ()>> {
+            self.poll_flush(cx)
+        }
+    }
+
+    let timers = Rc::new(RefCell::new(Vec::new()));
+    let mut sink = CountFlushes { data: Vec::new(), flushes: 0 }.buffer_linger(4, {
+        let timers = timers.clone();
+        move || {
+            let (tx, rx) = oneshot::channel::<()>();
+            timers.borrow_mut().push(tx);
+            rx
+        }
+    });
+
+    flag_cx(|flag, cx| {
+        unwrap(sink.poll_ready_unpin(cx));
+        sink.start_send_unpin(0).unwrap();
+        unwrap(sink.poll_ready_unpin(cx));
+        sink.start_send_unpin(1).unwrap();
+        assert_eq!(timers.borrow().len(), 1);
+        assert_eq!(sink.get_ref().flushes, 0);
+
+        unwrap(sink.poll_ready_unpin(cx));
+        assert!(!flag.take());
+        timers.borrow_mut().remove(0).send(()).unwrap();
+        assert!(flag.take());
+
+        unwrap(sink.poll_ready_unpin(cx));
+        assert_eq!(sink.get_ref().data, vec![0, 1]);
+        assert_eq!(sink.get_ref().flushes, 1);
+
+        sink.start_send_unpin(2).unwrap();
+        assert_eq!(timers.borrow().len(), 1);
+        unwrap(sink.poll_ready_unpin(cx));
+        assert_eq!(sink.get_ref().flushes, 1);
+    })
+}
+
+// test that a throttled sink waits for a token to be given back once its
+// burst is used up
+#[test]
+fn throttle() {
+    let timers = Rc::new(RefCell::new(VecDeque::new()));
+    let mut sink = Vec::new().throttle(2, {
+        let timers = timers.clone();
+        move || {
+            let (tx, rx) = oneshot::channel::<()>();
+            timers.borrow_mut().push_back(tx);
+            rx
+        }
+    });
+
+    flag_cx(|flag, cx| {
+        for i in 0..2 {
+            unwrap(sink.poll_ready_unpin(cx));
+            sink.start_send_unpin(i).unwrap();
+        }
+        assert_eq!(timers.borrow().len(), 2);
+        assert!(sink.poll_ready_unpin(cx).is_pending());
+
+        timers.borrow_mut().pop_front().unwrap().send(()).unwrap();
+        assert!(flag.take());
+        unwrap(sink.poll_ready_unpin(cx));
+        sink.start_send_unpin(2).unwrap();
+        assert_eq!(sink.tokens(), 0);
+        assert!(sink.poll_ready_unpin(cx).is_pending());
+
+        timers.borrow_mut().clear();
+        unwrap(sink.poll_ready_unpin(cx));
+        assert_eq!(sink.tokens(), 2);
+        assert_eq!(sink.get_ref(), &[0, 1, 2]);
+    })
+}
+
 #[test]
 fn fanout_smoke() {
     let sink1 = Vec::new();
@@ -485,6 +713,81 @@ fn fanout_backpressure() {
     })
 }
 
+// test that a fanout set handles slow and failed members according to its
+// policy
+#[test]
+fn fanout_set_policies() {
+    use futures::sink::{FanoutPolicy, FanoutSet};
+
+    let (tx1, mut rx1) = mpsc::channel(0);
+    let (tx2, rx2) = mpsc::channel(0);
+    let (tx3, rx3) = mpsc::channel(4);
+
+    let mut set = FanoutSet::with_policy(FanoutPolicy::Skip);
+    set.insert(tx1);
+    set.insert(tx2);
+    let key3 = set.insert(tx3);
+
+    let cx = &mut noop_context();
+    // each bounded sender has room for exactly one item
+    assert!(set.poll_ready_unpin(cx).is_ready());
+    set.start_send_unpin(1).unwrap();
+    assert!(set.poll_ready_unpin(cx).is_ready());
+    set.start_send_unpin(2).unwrap();
+    assert_eq!(set.len(), 3);
+
+    // the first member is closed, the second one is still full
+    rx1.close();
+    set.set_policy(FanoutPolicy::Remove);
+    assert!(set.poll_ready_unpin(cx).is_ready());
+    set.start_send_unpin(3).unwrap();
+    assert_eq!(set.len(), 1);
+    assert!(set.contains(key3));
+    drop(set);
+
+    assert_eq!(block_on(rx2.collect::<Vec<_>>()), vec![1]);
+    assert_eq!(block_on(rx3.collect::<Vec<_>>()), vec![1, 2, 3]);
+}
+
+// test that a demux creates sinks for new keys and that a full sink only
+// blocks the items sent to its own key
+#[test]
+fn demux_backpressure() {
+    use futures::sink::Demux;
+
+    let receivers = Rc::new(RefCell::new(Vec::new()));
+    let mut demux = Demux::new({
+        let receivers = receivers.clone();
+        move |key: &u8| {
+            let (tx, rx) = mpsc::channel::<i32>(0);
+            receivers.borrow_mut().push((*key, rx));
+            tx
+        }
+    });
+
+    let cx = &mut noop_context();
+    assert!(demux.poll_ready_unpin(cx).is_ready());
+    demux.start_send_unpin((0, 1)).unwrap();
+    assert!(demux.poll_ready_unpin(cx).is_ready());
+    demux.start_send_unpin((1, 2)).unwrap();
+    assert!(demux.poll_ready_unpin(cx).is_ready());
+    assert_eq!(demux.len(), 2);
+
+    // the sender for key 0 has used up its slot
+    demux.start_send_unpin((0, 3)).unwrap();
+    assert!(demux.poll_ready_unpin(cx).is_pending());
+
+    let (key, mut rx0) = receivers.borrow_mut().remove(0);
+    assert_eq!(key, 0);
+    assert_eq!(rx0.try_next().unwrap(), Some(1));
+    assert!(demux.poll_ready_unpin(cx).is_ready());
+    assert_eq!(rx0.try_next().unwrap(), Some(3));
+
+    let (key, mut rx1) = receivers.borrow_mut().remove(0);
+    assert_eq!(key, 1);
+    assert_eq!(rx1.try_next().unwrap(), Some(2));
+}
+
 #[test]
 fn sink_map_err() {
     {
@@ -531,6 +834,111 @@ fn sink_unfold() {
     }))
 }
 
+#[test]
+fn sink_unfold_full() {
+    let log = Rc::new(RefCell::new(Vec::new()));
+    let unfold = sink::unfold_full(
+        0,
+        {
+            let log = log.clone();
+            move |pending, i: i32| {
+                log.borrow_mut().push(format!("send {}", i));
+                future::ok::<_, String>(pending + 1)
+            }
+        },
+        {
+            let log = log.clone();
+            move |pending| {
+                log.borrow_mut().push(format!("flush {}", pending));
+                future::ok(0)
+            }
+        },
+        {
+            let log = log.clone();
+            move |pending| {
+                log.borrow_mut().push(format!("close {}", pending));
+                future::ok(())
+            }
+        },
+    );
+    futures::pin_mut!(unfold);
+
+    block_on(unfold.as_mut().feed(1)).unwrap();
+    block_on(unfold.as_mut().send(2)).unwrap();
+    block_on(unfold.as_mut().feed(3)).unwrap();
+    block_on(unfold.as_mut().close()).unwrap();
+    block_on(unfold.as_mut().close()).unwrap();
+
+    assert_eq!(*log.borrow(), vec!["send 1", "send 2", "flush 2", "send 3", "close 1"]);
+}
+
+#[test]
+fn sink_from_fn() {
+    // refuses to take more than two unflushed items
+    let mut sink = sink::from_fn(
+        (Vec::new(), 0),
+        |(_, flushed): &mut (Vec<i32>, usize), _| {
+            Poll::Ready(if *flushed < 2 { Ok(()) } else { Err(()) })
+        },
+        |(data, flushed), item| {
+            data.push(item);
+            *flushed += 1;
+            Ok(())
+        },
+        |(_, flushed), _| {
+            *flushed = 0;
+            Poll::Ready(Ok(()))
+        },
+    );
+
+    block_on(sink.feed(1)).unwrap();
+    block_on(sink.feed(2)).unwrap();
+    assert_eq!(block_on(sink.feed(3)), Err(()));
+    block_on(sink.flush()).unwrap();
+    block_on(sink.send(3)).unwrap();
+    assert_eq!(sink.into_inner(), (vec![1, 2, 3], 0));
+}
+
+#[test]
+fn write_blocking() {
+    use futures::executor::ThreadPool;
+    use std::io::{self, Write};
+    use std::sync::Mutex;
+
+    struct Shared(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for Shared {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            if buf.starts_with(b"!") {
+                return Err(io::Error::new(io::ErrorKind::Other, "refused"));
+            }
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    let pool = ThreadPool::new().unwrap();
+    let data = Arc::new(Mutex::new(Vec::new()));
+    let mut sink = sink::write_blocking(Shared(data.clone()), &pool, 2).unwrap();
+
+    block_on(sink.feed(b"hello ".to_vec())).unwrap();
+    block_on(sink.send(b"world".to_vec())).unwrap();
+    assert_eq!(&*data.lock().unwrap(), b"hello world");
+
+    // the failed write is reported by the next flush, and the writer can
+    // still be used afterwards
+    block_on(sink.feed(b"!".to_vec())).unwrap();
+    assert_eq!(block_on(sink.flush()).unwrap_err().kind(), io::ErrorKind::Other);
+    block_on(sink.send(b"!".to_vec())).unwrap_err();
+    block_on(sink.send(b".".to_vec())).unwrap();
+    block_on(sink.close()).unwrap();
+    assert_eq!(&*data.lock().unwrap(), b"hello world.");
+}
+
 #[test]
 fn err_into() {
     #[derive(Copy, Clone, Debug, PartialEq, Eq)]
@@ -553,3 +961,56 @@ fn err_into() {
     let tx = mpsc::channel(0).0;
     assert_eq!(Pin::new(&mut tx.sink_err_into()).start_send(()), Err(ErrIntoTest));
 }
+
+#[test]
+fn send_all_concurrent() {
+    let (mut tx, mut rx) = mpsc::unbounded::<i32>();
+    let (senders, receivers): (Vec<_>, Vec<_>) = (0..3).map(|_| oneshot::channel()).unzip();
+    let started = Cell::new(0);
+
+    let futures = stream::iter(receivers).map(|rx| {
+        started.set(started.get() + 1);
+        rx.map(|res| Ok(res.unwrap()))
+    });
+    let mut fut = tx.send_all_concurrent(futures, 2);
+    let mut cx = futures_test::task::noop_context();
+
+    assert!(fut.poll_unpin(&mut cx).is_pending());
+    assert_eq!(started.get(), 2);
+
+    // items are sent in completion order, and a finished future makes room
+    // for the next one
+    let mut senders = senders.into_iter();
+    let (first, second, third) =
+        (senders.next().unwrap(), senders.next().unwrap(), senders.next().unwrap());
+    second.send(2).unwrap();
+    assert!(fut.poll_unpin(&mut cx).is_pending());
+    assert_eq!(started.get(), 3);
+    assert_eq!(rx.try_next().unwrap(), Some(2));
+
+    third.send(3).unwrap();
+    first.send(1).unwrap();
+    assert_eq!(fut.poll_unpin(&mut cx), Poll::Ready(Ok(())));
+    drop(fut);
+    drop(tx);
+    assert_eq!(block_on(rx.collect::<Vec<_>>()), vec![3, 1]);
+}
+
+#[test]
+fn send_all_concurrent_backpressure() {
+    let (mut sink, allow) = manual_allow::<i32>();
+    let futures = stream::iter(vec![future::ok(0), future::ok(1)]);
+
+    {
+        let mut fut = sink.send_all_concurrent(futures, None);
+        let mut cx = futures_test::task::noop_context();
+        assert!(fut.poll_unpin(&mut cx).is_pending());
+        allow.start();
+        assert_eq!(fut.poll_unpin(&mut cx), Poll::Ready(Ok(())));
+    }
+    assert_eq!(sink.data.len(), 2);
+
+    let failing = stream::iter(vec![future::ok(2), future::err(())]);
+    assert_eq!(block_on(sink.send_all_concurrent(failing, 1)), Err(()));
+    assert_eq!(sink.data, vec![0, 1, 2]);
+}
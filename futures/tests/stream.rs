@@ -1,11 +1,14 @@
-use futures::channel::mpsc;
+use futures::channel::{mpsc, oneshot};
 use futures::executor::block_on;
 use futures::future::{self, Future};
 use futures::sink::SinkExt;
-use futures::stream::{self, StreamExt};
-use futures::task::Poll;
+use futures::stream::{self, ErrorPolicy, FusedStream, Stream, StreamExt};
+use futures::task::{Context, Poll};
 use futures::FutureExt;
+use futures_test::stream::StreamTestExt;
 use futures_test::task::noop_context;
+use std::cell::Cell;
+use std::pin::Pin;
 
 #[test]
 fn select() {
@@ -166,3 +169,562 @@ fn ready_chunks() {
         assert_eq!(s.next().await.unwrap(), vec![4]);
     });
 }
+
+#[test]
+fn cycle() {
+    block_on(async {
+        let s = stream::iter(vec![1, 2, 3]).cycle();
+        assert_eq!(s.size_hint(), (usize::max_value(), None));
+        assert_eq!(s.take(7).collect::<Vec<_>>().await, vec![1, 2, 3, 1, 2, 3, 1]);
+
+        let mut s = stream::iter(Vec::<i32>::new()).cycle();
+        assert_eq!(s.next().await, None);
+    });
+}
+
+#[test]
+fn split_at() {
+    block_on(async {
+        let (head, rest) = stream::iter(1..=3).split_at(0).await;
+        assert!(head.is_empty());
+        assert_eq!(rest.collect::<Vec<_>>().await, vec![1, 2, 3]);
+
+        // fewer items than requested
+        let (head, mut rest) = stream::iter(1..=3).split_at(5).await;
+        assert_eq!(head, vec![1, 2, 3]);
+        assert_eq!(rest.next().await, None);
+    });
+}
+
+#[test]
+fn instrument_polls() {
+    let mut s = stream::iter(vec![1, 2]).interleave_pending().instrument_polls();
+    let mut cx = noop_context();
+
+    assert_eq!(s.poll_next_unpin(&mut cx), Poll::Pending);
+    assert_eq!(s.poll_next_unpin(&mut cx), Poll::Ready(Some(1)));
+    assert_eq!(s.stats().polls(), 2);
+    assert_eq!(s.stats().pending_polls(), 1);
+    assert_eq!(s.stats().items(), 1);
+
+    // taking the stats resets them
+    let taken = Pin::new(&mut s).take_stats();
+    assert_eq!(taken.ready_polls(), 1);
+    assert_eq!(s.stats().polls(), 0);
+
+    assert_eq!(s.poll_next_unpin(&mut cx), Poll::Pending);
+    assert_eq!(s.poll_next_unpin(&mut cx), Poll::Ready(Some(2)));
+    assert_eq!(s.poll_next_unpin(&mut cx), Poll::Pending);
+    assert_eq!(s.poll_next_unpin(&mut cx), Poll::Ready(None));
+    let stats = s.stats();
+    assert_eq!((stats.polls(), stats.pending_polls(), stats.items()), (4, 2, 1));
+    assert!(stats.max_poll_duration() <= stats.total_poll_duration());
+}
+
+#[test]
+fn metered() {
+    let mut cx = noop_context();
+    let (tx, rx) = mpsc::channel::<i32>(0);
+    let mut tx = stream::Metered::new(tx);
+    let mut rx = rx.metered();
+
+    assert_eq!(tx.poll_ready_unpin(&mut cx), Poll::Ready(Ok(())));
+    tx.start_send_unpin(1).unwrap();
+    assert_eq!(tx.poll_ready_unpin(&mut cx), Poll::Pending);
+    assert_eq!(tx.sink_metrics().pending(), 1);
+    assert_eq!(tx.sink_metrics().wakeups(), 0);
+
+    // receiving frees the slot and wakes the blocked sender
+    assert_eq!(rx.poll_next_unpin(&mut cx), Poll::Ready(Some(1)));
+    assert_eq!(tx.sink_metrics().wakeups(), 1);
+    assert_eq!(tx.poll_ready_unpin(&mut cx), Poll::Ready(Ok(())));
+    tx.start_send_unpin(2).unwrap();
+    assert_eq!(tx.sink_metrics().items(), 2);
+    assert_eq!(tx.sink_metrics().pending(), 1);
+
+    assert_eq!(rx.poll_next_unpin(&mut cx), Poll::Ready(Some(2)));
+    assert_eq!(rx.poll_next_unpin(&mut cx), Poll::Pending);
+    drop(tx);
+    assert_eq!(rx.poll_next_unpin(&mut cx), Poll::Ready(None));
+    let metrics = rx.stream_metrics();
+    assert_eq!((metrics.items(), metrics.pending(), metrics.wakeups()), (2, 1, 1));
+    assert_eq!(rx.sink_metrics(), stream::Metrics::default());
+}
+
+#[test]
+fn ready_chunks_timeout() {
+    use futures::channel::oneshot;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    let (mut tx, rx1) = mpsc::channel::<i32>(16);
+    let deadlines = Rc::new(RefCell::new(Vec::new()));
+    let d = deadlines.clone();
+    let mut s = rx1.ready_chunks_timeout(3, move || {
+        let (tx, rx) = oneshot::channel::<()>();
+        d.borrow_mut().push(tx);
+        rx
+    });
+
+    let mut cx = noop_context();
+    assert!(s.next().poll_unpin(&mut cx).is_pending());
+    assert!(deadlines.borrow().is_empty());
+
+    block_on(async {
+        // a partial chunk is held back until its deadline elapses
+        tx.send(1).await.unwrap();
+        tx.send(2).await.unwrap();
+        assert!(s.next().poll_unpin(&mut cx).is_pending());
+        assert_eq!(deadlines.borrow().len(), 1);
+        deadlines.borrow_mut().remove(0).send(()).unwrap();
+        assert_eq!(s.next().await.unwrap(), vec![1, 2]);
+
+        // a full chunk is returned without waiting for the deadline
+        tx.send(3).await.unwrap();
+        tx.send(4).await.unwrap();
+        tx.send(5).await.unwrap();
+        tx.send(6).await.unwrap();
+        assert_eq!(s.next().await.unwrap(), vec![3, 4, 5]);
+        assert_eq!(deadlines.borrow().len(), 1);
+
+        // the rest is returned when the stream ends
+        drop(tx);
+        assert_eq!(s.next().await.unwrap(), vec![6]);
+        assert_eq!(s.next().await, None);
+    });
+}
+
+#[test]
+#[should_panic]
+fn chunks_exact_panic_on_cap_zero() {
+    let (_, rx1) = mpsc::channel::<()>(1);
+
+    let _ = rx1.chunks_exact(0);
+}
+
+#[test]
+fn chunks_exact() {
+    block_on(async {
+        let mut s = stream::iter(1..=7).chunks_exact(3);
+        assert_eq!(s.size_hint(), (2, Some(2)));
+        assert_eq!(s.next().await, Some(vec![1, 2, 3]));
+        assert_eq!(s.next().await, Some(vec![4, 5, 6]));
+        assert_eq!(s.next().await, None);
+        assert_eq!(s.remainder(), &[7]);
+        assert_eq!(s.into_remainder(), vec![7]);
+
+        // no remainder when the stream divides evenly
+        let mut s = stream::iter(1..=4).chunks_exact(2);
+        assert_eq!(s.by_ref().collect::<Vec<_>>().await, vec![vec![1, 2], vec![3, 4]]);
+        assert!(s.remainder().is_empty());
+    });
+}
+
+#[test]
+fn forward_flush_every() {
+    use futures::sink::Sink;
+    use std::convert::Infallible;
+
+    #[derive(Default)]
+    struct FlushLog {
+        unflushed: Vec<i32>,
+        flushes: Vec<Vec<i32>>,
+    }
+
+    impl Sink<i32> for FlushLog {
+        type Error = Infallible;
+
+        fn poll_ready(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Result<(), Infallible>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn start_send(mut self: Pin<&mut Self>, item: i32) -> Result<(), Infallible> {
+            self.unflushed.push(item);
+            Ok(())
+        }
+
+        fn poll_flush(
+            mut self: Pin<&mut Self>,
+            _: &mut Context<'_>,
+        ) -> Poll<Result<(), Infallible>> {
+            if !self.unflushed.is_empty() {
+                let items = std::mem::replace(&mut self.unflushed, Vec::new());
+                self.flushes.push(items);
+            }
+            Poll::Ready(Ok(()))
+        }
+
+        fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Infallible>> {
+            self.poll_flush(cx)
+        }
+    }
+
+    let mut sink = FlushLog::default();
+    block_on(stream::iter(1..=5).forward_flush_every(&mut sink, 2)).unwrap();
+    assert_eq!(sink.flushes, vec![vec![1, 2], vec![3, 4], vec![5]]);
+
+    // a deadline which has already elapsed flushes after every item
+    let mut sink = FlushLog::default();
+    block_on(stream::iter(1..=3).forward_flush_interval(&mut sink, || future::ready(()))).unwrap();
+    assert_eq!(sink.flushes, vec![vec![1], vec![2], vec![3]]);
+
+    // a deadline which never elapses only flushes when the stream ends
+    let mut sink = FlushLog::default();
+    block_on(stream::iter(1..=3).forward_flush_interval(&mut sink, future::pending::<()>)).unwrap();
+    assert_eq!(sink.flushes, vec![vec![1, 2, 3]]);
+}
+
+#[test]
+fn for_each_concurrent_with_results() {
+    let (tx1, rx1) = oneshot::channel();
+    let (tx2, rx2) = oneshot::channel();
+    let fut = stream::iter(vec![rx1, rx2])
+        .for_each_concurrent_with_results(None, |rx| async move { rx.await.unwrap() });
+    futures::pin_mut!(fut);
+    let mut cx = noop_context();
+
+    assert!(fut.as_mut().poll(&mut cx).is_pending());
+    tx2.send('b').unwrap();
+    assert!(fut.as_mut().poll(&mut cx).is_pending());
+    tx1.send('a').unwrap();
+    assert_eq!(fut.poll(&mut cx), Poll::Ready(vec![(1, 'b'), (0, 'a')]));
+}
+
+#[test]
+fn try_map_concurrent_policies() {
+    let mut cx = noop_context();
+
+    for &policy in &[ErrorPolicy::FailFast, ErrorPolicy::DrainInFlight, ErrorPolicy::Continue] {
+        let started = Cell::new(0);
+        let (tx1, rx1) = oneshot::channel::<i32>();
+        let (tx2, rx2) = oneshot::channel();
+        let (tx3, rx3) = oneshot::channel();
+        let fut = stream::iter(vec![rx1, rx2, rx3]).try_map_concurrent(2, policy, |rx| {
+            started.set(started.get() + 1);
+            rx
+        });
+        futures::pin_mut!(fut);
+
+        assert!(fut.as_mut().poll(&mut cx).is_pending());
+        assert_eq!(started.get(), 2);
+        drop(tx1);
+
+        match policy {
+            ErrorPolicy::FailFast => {
+                assert_eq!(fut.as_mut().poll(&mut cx), Poll::Ready(Err(oneshot::Canceled)));
+                assert_eq!(started.get(), 2);
+            }
+            ErrorPolicy::DrainInFlight => {
+                assert!(fut.as_mut().poll(&mut cx).is_pending());
+                assert_eq!(started.get(), 2);
+                tx2.send(2).unwrap();
+                assert_eq!(fut.as_mut().poll(&mut cx), Poll::Ready(Err(oneshot::Canceled)));
+                assert_eq!(started.get(), 2);
+            }
+            ErrorPolicy::Continue => {
+                assert!(fut.as_mut().poll(&mut cx).is_pending());
+                assert_eq!(started.get(), 3);
+                tx2.send(2).unwrap();
+                tx3.send(3).unwrap();
+                assert_eq!(fut.as_mut().poll(&mut cx), Poll::Ready(Err(oneshot::Canceled)));
+            }
+        }
+    }
+}
+
+#[test]
+fn try_for_each_concurrent_drain_in_flight() {
+    let mut cx = noop_context();
+    let finished = Cell::new(0);
+    let (tx1, rx1) = oneshot::channel::<()>();
+    let (tx2, rx2) = oneshot::channel::<()>();
+    let (_tx3, rx3) = oneshot::channel::<()>();
+    let fut = stream::iter(vec![rx1, rx2, rx3]).try_for_each_concurrent_with_policy(
+        2,
+        ErrorPolicy::DrainInFlight,
+        |rx| {
+            let finished = &finished;
+            async move {
+                rx.await?;
+                finished.set(finished.get() + 1);
+                Ok(())
+            }
+        },
+    );
+    futures::pin_mut!(fut);
+
+    assert!(fut.as_mut().poll(&mut cx).is_pending());
+    drop(tx1);
+    assert!(fut.as_mut().poll(&mut cx).is_pending());
+    tx2.send(()).unwrap();
+    assert_eq!(fut.as_mut().poll(&mut cx), Poll::Ready(Err(oneshot::Canceled)));
+    assert_eq!(finished.get(), 1);
+}
+
+#[test]
+fn delay_items() {
+    use std::cell::RefCell;
+
+    let delays = RefCell::new(Vec::new());
+    let pulled = Cell::new(0);
+    let mut s =
+        stream::iter(vec![1, 2]).inspect(|_| pulled.set(pulled.get() + 1)).delay_items(|_| {
+            let (tx, rx) = oneshot::channel::<()>();
+            delays.borrow_mut().push(tx);
+            rx
+        });
+    let mut cx = noop_context();
+
+    // an item is held back until its delay elapses
+    assert!(s.poll_next_unpin(&mut cx).is_pending());
+    assert_eq!(delays.borrow().len(), 1);
+    assert_eq!(pulled.get(), 1);
+    delays.borrow_mut().remove(0).send(()).unwrap();
+    assert_eq!(s.poll_next_unpin(&mut cx), Poll::Ready(Some(1)));
+
+    // the next item is only pulled after the previous one was yielded
+    assert!(s.poll_next_unpin(&mut cx).is_pending());
+    assert_eq!(pulled.get(), 2);
+    delays.borrow_mut().remove(0).send(()).unwrap();
+    assert_eq!(s.poll_next_unpin(&mut cx), Poll::Ready(Some(2)));
+    assert_eq!(s.poll_next_unpin(&mut cx), Poll::Ready(None));
+}
+
+#[test]
+fn sample() {
+    use std::cell::RefCell;
+
+    let (tx, rx) = mpsc::unbounded::<i32>();
+    let ticks = RefCell::new(Vec::new());
+    let mut s = rx.sample(|| {
+        let (tx, rx) = oneshot::channel::<()>();
+        ticks.borrow_mut().push(tx);
+        rx
+    });
+    let mut cx = noop_context();
+
+    // only the latest item of a tick is yielded
+    tx.unbounded_send(1).unwrap();
+    tx.unbounded_send(2).unwrap();
+    assert!(s.poll_next_unpin(&mut cx).is_pending());
+    assert_eq!(ticks.borrow().len(), 1);
+    tx.unbounded_send(3).unwrap();
+    ticks.borrow_mut().remove(0).send(()).unwrap();
+    assert_eq!(s.poll_next_unpin(&mut cx), Poll::Ready(Some(3)));
+
+    // nothing is yielded for a tick without items
+    assert_eq!(ticks.borrow().len(), 1);
+    ticks.borrow_mut().remove(0).send(()).unwrap();
+    assert!(s.poll_next_unpin(&mut cx).is_pending());
+    assert_eq!(ticks.borrow().len(), 1);
+
+    // the last item is yielded when the stream ends
+    tx.unbounded_send(4).unwrap();
+    drop(tx);
+    assert_eq!(s.poll_next_unpin(&mut cx), Poll::Ready(Some(4)));
+    assert_eq!(s.poll_next_unpin(&mut cx), Poll::Ready(None));
+    assert!(s.is_terminated());
+}
+
+#[test]
+fn buffer_while() {
+    // batches of items sharing the same key
+    let items = vec![(1, 'a'), (1, 'b'), (2, 'c'), (3, 'd'), (3, 'e')];
+    let batches = block_on(
+        stream::iter(items).buffer_while(|batch, item| batch[0].0 == item.0).collect::<Vec<_>>(),
+    );
+    assert_eq!(batches, vec![vec![(1, 'a'), (1, 'b')], vec![(2, 'c')], vec![(3, 'd'), (3, 'e')]]);
+
+    // a pending stream holds back the current batch
+    let (tx, rx) = mpsc::unbounded::<i32>();
+    let mut s = rx.buffer_while(|batch, item| batch.len() < 2 && *item > 0);
+    let mut cx = noop_context();
+    tx.unbounded_send(1).unwrap();
+    tx.unbounded_send(2).unwrap();
+    assert!(s.poll_next_unpin(&mut cx).is_pending());
+    tx.unbounded_send(3).unwrap();
+    assert_eq!(s.poll_next_unpin(&mut cx), Poll::Ready(Some(vec![1, 2])));
+    drop(tx);
+    assert_eq!(s.poll_next_unpin(&mut cx), Poll::Ready(Some(vec![3])));
+    assert_eq!(s.poll_next_unpin(&mut cx), Poll::Ready(None));
+}
+
+#[test]
+fn iter_blocking_prefetch() {
+    use futures::executor::ThreadPool;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    let pool = ThreadPool::builder().pool_size(1).create().unwrap();
+    let pulled = Arc::new(AtomicUsize::new(0));
+    let iter = {
+        let pulled = pulled.clone();
+        (0..10).inspect(move |_| {
+            pulled.fetch_add(1, Ordering::SeqCst);
+        })
+    };
+
+    let mut s = stream::iter_blocking(iter, &pool, 3).unwrap();
+    assert_eq!(block_on(s.next()), Some(0));
+
+    // the task stops pulling once the buffer is full
+    std::thread::sleep(Duration::from_millis(50));
+    assert_eq!(pulled.load(Ordering::SeqCst), 4);
+
+    assert_eq!(block_on(s.collect::<Vec<_>>()), (1..10).collect::<Vec<_>>());
+}
+
+#[test]
+fn take_ref() {
+    block_on(async {
+        // a stream that is not `Unpin`, pinned on the stack
+        let stream = stream::iter(1..=6).then(future::ready);
+        futures::pin_mut!(stream);
+
+        assert_eq!(stream.take_ref(2).collect::<Vec<_>>().await, vec![1, 2]);
+        assert_eq!(stream.take_ref(0).next().await, None);
+
+        let mut chunk = stream.take_ref(3);
+        assert_eq!(chunk.size_hint(), (3, Some(3)));
+        assert_eq!(chunk.next().await, Some(3));
+        drop(chunk);
+
+        assert_eq!(stream.collect::<Vec<_>>().await, vec![4, 5, 6]);
+    });
+}
+
+#[test]
+fn with_latest_from() {
+    let mut cx = noop_context();
+    let (tx, rx) = mpsc::unbounded();
+    let mut s = stream::iter(1..=4).interleave_pending().with_latest_from(rx);
+
+    // the primary stream is skipped until the secondary one has produced
+    assert_eq!(s.poll_next_unpin(&mut cx), Poll::Pending);
+    assert_eq!(s.poll_next_unpin(&mut cx), Poll::Pending);
+    assert_eq!(s.latest(), None);
+    assert_eq!(s.size_hint(), (0, Some(3)));
+
+    tx.unbounded_send('a').unwrap();
+    assert_eq!(s.poll_next_unpin(&mut cx), Poll::Ready(Some((2, 'a'))));
+
+    // the secondary stream ending keeps its last item
+    tx.unbounded_send('b').unwrap();
+    drop(tx);
+    assert_eq!(s.poll_next_unpin(&mut cx), Poll::Pending);
+    assert_eq!(s.poll_next_unpin(&mut cx), Poll::Ready(Some((3, 'b'))));
+    assert_eq!(block_on(s.collect::<Vec<_>>()), vec![(4, 'b')]);
+}
+
+#[test]
+fn zip_with() {
+    let mut cx = noop_context();
+    let (tx, rx) = mpsc::unbounded();
+    let mut s = stream::iter(vec!["a", "b"]).zip_with(rx, |s, n| s.repeat(n));
+
+    assert_eq!(s.poll_next_unpin(&mut cx), Poll::Pending);
+    tx.unbounded_send(2).unwrap();
+    tx.unbounded_send(3).unwrap();
+    tx.unbounded_send(4).unwrap();
+    assert_eq!(s.poll_next_unpin(&mut cx), Poll::Ready(Some("aa".to_string())));
+    assert_eq!(s.poll_next_unpin(&mut cx), Poll::Ready(Some("bbb".to_string())));
+    assert_eq!(s.poll_next_unpin(&mut cx), Poll::Ready(None));
+}
+
+#[test]
+fn start_with() {
+    let mut cx = noop_context();
+    let (tx, rx) = mpsc::unbounded();
+    let mut s = rx.start_with(vec![1, 2]);
+
+    assert_eq!(s.size_hint(), (2, None));
+    assert_eq!(s.poll_next_unpin(&mut cx), Poll::Ready(Some(1)));
+    assert_eq!(s.poll_next_unpin(&mut cx), Poll::Ready(Some(2)));
+    assert_eq!(s.poll_next_unpin(&mut cx), Poll::Pending);
+
+    tx.unbounded_send(3).unwrap();
+    drop(tx);
+    assert_eq!(s.poll_next_unpin(&mut cx), Poll::Ready(Some(3)));
+    assert!(!s.is_terminated());
+    assert_eq!(s.poll_next_unpin(&mut cx), Poll::Ready(None));
+    assert!(s.is_terminated());
+}
+
+#[test]
+fn intersperse() {
+    let mut cx = noop_context();
+    let (tx, rx) = mpsc::unbounded();
+    let mut s = rx.intersperse(0);
+
+    tx.unbounded_send(1).unwrap();
+    assert_eq!(s.poll_next_unpin(&mut cx), Poll::Ready(Some(1)));
+    // no separator until the next item arrives
+    assert_eq!(s.poll_next_unpin(&mut cx), Poll::Pending);
+
+    tx.unbounded_send(2).unwrap();
+    drop(tx);
+    assert_eq!(s.poll_next_unpin(&mut cx), Poll::Ready(Some(0)));
+    assert_eq!(s.size_hint(), (1, None));
+    assert!(!s.is_terminated());
+    assert_eq!(s.poll_next_unpin(&mut cx), Poll::Ready(Some(2)));
+    assert_eq!(s.poll_next_unpin(&mut cx), Poll::Ready(None));
+    assert!(s.is_terminated());
+
+    let s = stream::iter(1..=3).intersperse_with(|| 0);
+    assert_eq!(s.size_hint(), (5, Some(5)));
+    assert_eq!(block_on(s.collect::<Vec<_>>()), vec![1, 0, 2, 0, 3]);
+    assert_eq!(block_on(stream::empty::<i32>().intersperse(0).collect::<Vec<_>>()), vec![]);
+}
+
+#[test]
+fn min_by_max_by() {
+    block_on(async {
+        let items = vec![(2, 'a'), (1, 'b'), (2, 'c'), (1, 'd')];
+
+        let min = stream::iter(items.clone()).min_by(|a, b| a.0.cmp(&b.0));
+        assert_eq!(min.await, Some((1, 'b')));
+        let max = stream::iter(items).max_by(|a, b| a.0.cmp(&b.0));
+        assert_eq!(max.await, Some((2, 'c')));
+
+        assert_eq!(stream::empty::<i32>().min_by(Ord::cmp).await, None);
+        assert_eq!(stream::empty::<i32>().max_by(Ord::cmp).await, None);
+    });
+}
+
+#[test]
+fn position() {
+    let mut cx = noop_context();
+    let (tx, rx) = mpsc::unbounded();
+    let (check_tx, check_rx) = oneshot::channel::<bool>();
+    let mut check_rx = Some(check_rx);
+    let fut = rx.position(|i: i32| {
+        let check = if i == 2 { check_rx.take() } else { None };
+        async move {
+            match check {
+                Some(rx) => rx.await.unwrap(),
+                None => false,
+            }
+        }
+    });
+    futures::pin_mut!(fut);
+
+    tx.unbounded_send(1).unwrap();
+    tx.unbounded_send(2).unwrap();
+    assert_eq!(fut.poll_unpin(&mut cx), Poll::Pending);
+    check_tx.send(true).unwrap();
+    assert_eq!(fut.poll_unpin(&mut cx), Poll::Ready(Some(1)));
+
+    let fut = stream::iter(vec![1, 2]).position(|_| future::ready(false));
+    assert_eq!(block_on(fut), None);
+}
+
+#[test]
+fn unzip_into_extend_targets() {
+    use std::collections::BTreeSet;
+
+    let pairs = stream::iter(vec![('a', 3), ('b', 1), ('c', 3)]).interleave_pending();
+    let (letters, counts): (String, BTreeSet<_>) = block_on(pairs.unzip());
+    assert_eq!(letters, "abc");
+    assert_eq!(counts.into_iter().collect::<Vec<_>>(), vec![1, 3]);
+}
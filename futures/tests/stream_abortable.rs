@@ -1,6 +1,6 @@
 use futures::channel::mpsc;
 use futures::executor::block_on;
-use futures::stream::{abortable, Stream, StreamExt};
+use futures::stream::{abortable, drain_abortable, FusedStream, Stream, StreamExt};
 use futures::task::{Context, Poll};
 use futures::SinkExt;
 use futures_test::task::new_count_waker;
@@ -44,3 +44,43 @@ fn abortable_resolves() {
     assert!(!abortable_rx.is_aborted());
     assert_eq!(Some(()), block_on(abortable_rx.next()));
 }
+
+#[test]
+fn drain_abortable_yields_ready_items() {
+    let (mut tx, a_rx) = mpsc::channel::<u32>(4);
+    let (mut abortable_rx, abort_handle) = drain_abortable(a_rx, None);
+
+    block_on(tx.send(1)).unwrap();
+    block_on(tx.send(2)).unwrap();
+    abort_handle.abort();
+    assert!(abortable_rx.is_aborted());
+
+    // the buffered items are still delivered, then the stream ends even
+    // though the channel is still open
+    assert_eq!(Some(1), block_on(abortable_rx.next()));
+    assert_eq!(Some(2), block_on(abortable_rx.next()));
+    assert_eq!(None, block_on(abortable_rx.next()));
+    assert!(abortable_rx.is_terminated());
+
+    block_on(tx.send(3)).unwrap();
+    assert_eq!(None, block_on(abortable_rx.next()));
+}
+
+#[test]
+fn drain_abortable_limit_and_wakeup() {
+    let (mut tx, a_rx) = mpsc::channel::<u32>(4);
+    let (mut abortable_rx, abort_handle) = drain_abortable(a_rx, 1);
+
+    let (waker, counter) = new_count_waker();
+    let mut cx = Context::from_waker(&waker);
+
+    assert_eq!(Poll::Pending, Pin::new(&mut abortable_rx).poll_next(&mut cx));
+    block_on(tx.send(1)).unwrap();
+    block_on(tx.send(2)).unwrap();
+    assert_eq!(counter, 1);
+
+    abort_handle.abort();
+    assert_eq!(counter, 2);
+    assert_eq!(Poll::Ready(Some(1)), Pin::new(&mut abortable_rx).poll_next(&mut cx));
+    assert_eq!(Poll::Ready(None), Pin::new(&mut abortable_rx).poll_next(&mut cx));
+}
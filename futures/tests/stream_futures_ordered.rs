@@ -2,7 +2,8 @@ use futures::channel::oneshot;
 use futures::executor::{block_on, block_on_stream};
 use futures::future::{self, join, Future, FutureExt, TryFutureExt};
 use futures::stream::{FuturesOrdered, StreamExt};
-use futures_test::task::noop_context;
+use futures::task::{Context, Poll};
+use futures_test::task::{new_count_waker, noop_context};
 use std::any::Any;
 
 #[test]
@@ -84,3 +85,80 @@ fn queue_never_unblocked() {
     assert!(stream.poll_next_unpin(cx).is_pending());
     assert!(stream.poll_next_unpin(cx).is_pending());
 }
+
+#[test]
+fn buffered_len_and_cancel() {
+    let (a_tx, a_rx) = oneshot::channel::<i32>();
+    let (b_tx, b_rx) = oneshot::channel::<i32>();
+    let (c_tx, c_rx) = oneshot::channel::<i32>();
+
+    let mut stream = FuturesOrdered::new();
+    stream.push(a_rx);
+    let b_index = stream.next_index();
+    stream.push(b_rx);
+    stream.push(c_rx);
+
+    let mut cx = noop_context();
+    c_tx.send(3).unwrap();
+    assert!(stream.poll_next_unpin(&mut cx).is_pending());
+    assert_eq!(stream.in_progress_len(), 2);
+    assert_eq!(stream.buffered_len(), 1);
+
+    assert!(stream.cancel(b_index, Err(oneshot::Canceled)));
+    assert!(b_tx.is_canceled());
+    assert!(!stream.cancel(b_index, Err(oneshot::Canceled)));
+    assert_eq!(stream.buffered_len(), 2);
+
+    a_tx.send(1).unwrap();
+    let mut iter = block_on_stream(stream);
+    assert_eq!(iter.next(), Some(Ok(1)));
+    assert_eq!(iter.next(), Some(Err(oneshot::Canceled)));
+    assert_eq!(iter.next(), Some(Ok(3)));
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn cancel_wakes_the_task() {
+    let (_a_tx, a_rx) = oneshot::channel::<i32>();
+    let (b_tx, b_rx) = oneshot::channel::<i32>();
+
+    let mut stream = FuturesOrdered::new();
+    let a_index = stream.next_index();
+    stream.push(a_rx);
+    stream.push(b_rx);
+
+    let (waker, count) = new_count_waker();
+    let mut cx = Context::from_waker(&waker);
+    assert!(stream.poll_next_unpin(&mut cx).is_pending());
+    let wakeups = count.get();
+
+    assert!(stream.cancel(a_index, Err(oneshot::Canceled)));
+    assert_eq!(count, wakeups + 1);
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(Some(Err(oneshot::Canceled))));
+
+    drop(b_tx);
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(Some(Err(oneshot::Canceled))));
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(None));
+}
+
+#[test]
+fn clear() {
+    let (a_tx, a_rx) = oneshot::channel::<i32>();
+    let (b_tx, b_rx) = oneshot::channel::<i32>();
+
+    let mut stream = FuturesOrdered::new();
+    stream.push(a_rx);
+    stream.push(b_rx);
+    b_tx.send(2).unwrap();
+    assert!(stream.poll_next_unpin(&mut noop_context()).is_pending());
+
+    stream.clear();
+    assert!(stream.is_empty());
+    assert!(a_tx.is_canceled());
+
+    // Futures pushed after clearing are yielded normally.
+    let (c_tx, c_rx) = oneshot::channel::<i32>();
+    stream.push(c_rx);
+    c_tx.send(3).unwrap();
+    assert_eq!(block_on(stream.next()), Some(Ok(3)));
+}
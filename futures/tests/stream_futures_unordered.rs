@@ -1,11 +1,14 @@
 use futures::channel::oneshot;
 use futures::executor::{block_on, block_on_stream};
 use futures::future::{self, join, Future, FutureExt};
-use futures::stream::{FusedStream, FuturesUnordered, StreamExt};
+use futures::stream::{
+    BoundedFuturesUnordered, FusedStream, FuturesUnordered, KeyedFuturesUnordered, StreamExt,
+};
 use futures::task::{Context, Poll};
 use futures_test::future::FutureTestExt;
 use futures_test::task::noop_context;
 use futures_test::{assert_stream_done, assert_stream_next, assert_stream_pending};
+use std::cell::Cell;
 use std::iter::FromIterator;
 use std::pin::Pin;
 use std::sync::atomic::{AtomicBool, Ordering};
@@ -369,3 +372,150 @@ fn clear() {
     tasks.clear();
     assert!(!tasks.is_terminated());
 }
+
+#[test]
+fn retain() {
+    let (a_tx, a_rx) = oneshot::channel::<i32>();
+    let (b_tx, b_rx) = oneshot::channel::<i32>();
+    let (c_tx, c_rx) = oneshot::channel::<i32>();
+    let mut cx = noop_context();
+
+    let mut stream = vec![a_rx, b_rx, c_rx].into_iter().collect::<FuturesUnordered<_>>();
+    assert!(stream.poll_next_unpin(&mut cx).is_pending());
+
+    // Evicting futures closes their receivers without polling them again.
+    let mut visited = 0;
+    stream.retain(|_| {
+        visited += 1;
+        visited == 2
+    });
+    assert_eq!(visited, 3);
+    assert_eq!(stream.len(), 1);
+    assert_eq!([&a_tx, &b_tx, &c_tx].iter().filter(|tx| tx.is_canceled()).count(), 2);
+
+    for tx in vec![a_tx, b_tx, c_tx] {
+        let _ = tx.send(7);
+    }
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(Some(Ok(7))));
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(None));
+}
+
+#[test]
+fn drain() {
+    let (a_tx, a_rx) = oneshot::channel::<i32>();
+    let (b_tx, b_rx) = oneshot::channel::<i32>();
+    let mut cx = noop_context();
+
+    let mut stream = vec![a_rx, b_rx].into_iter().collect::<FuturesUnordered<_>>();
+    assert!(stream.poll_next_unpin(&mut cx).is_pending());
+
+    let drained = stream.drain();
+    assert_eq!(drained.len(), 2);
+    let drained = drained.collect::<Vec<_>>();
+    assert!(stream.is_empty());
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(None));
+
+    // The drained futures are handed back intact.
+    a_tx.send(1).unwrap();
+    b_tx.send(2).unwrap();
+    let mut results = block_on(future::try_join_all(drained)).unwrap();
+    results.sort();
+    assert_eq!(results, vec![1, 2]);
+
+    // Futures that are not taken out by the iterator are dropped with it.
+    let (c_tx, c_rx) = oneshot::channel::<i32>();
+    stream.push(c_rx);
+    drop(stream.drain());
+    assert!(stream.is_empty());
+    assert!(c_tx.is_canceled());
+}
+
+#[test]
+fn bounded_admits_lazily() {
+    let mut cx = noop_context();
+    let started = Cell::new(0);
+    let (a_tx, a_rx) = oneshot::channel::<i32>();
+    let (b_tx, b_rx) = oneshot::channel::<i32>();
+    let (c_tx, c_rx) = oneshot::channel::<i32>();
+
+    let mut set = BoundedFuturesUnordered::new(2);
+    for rx in vec![a_rx, b_rx, c_rx] {
+        let started = &started;
+        set.push(async move {
+            started.set(started.get() + 1);
+            rx.await.unwrap()
+        });
+    }
+    assert_eq!(set.len(), 3);
+    assert_eq!(set.in_progress_len(), 2);
+    assert_eq!(set.queued_len(), 1);
+
+    assert!(set.poll_next_unpin(&mut cx).is_pending());
+    assert_eq!(started.get(), 2);
+
+    // A queued future only starts once a running one completes.
+    c_tx.send(3).unwrap();
+    assert!(set.poll_next_unpin(&mut cx).is_pending());
+    b_tx.send(2).unwrap();
+    assert_eq!(set.poll_next_unpin(&mut cx), Poll::Ready(Some(2)));
+    assert_eq!(set.queued_len(), 1);
+    assert_eq!(set.poll_next_unpin(&mut cx), Poll::Ready(Some(3)));
+    assert_eq!(started.get(), 3);
+
+    a_tx.send(1).unwrap();
+    assert_eq!(set.poll_next_unpin(&mut cx), Poll::Ready(Some(1)));
+    assert_eq!(set.poll_next_unpin(&mut cx), Poll::Ready(None));
+    assert!(set.is_terminated());
+}
+
+#[test]
+fn keyed_cancel() {
+    let mut cx = noop_context();
+    let (a_tx, a_rx) = oneshot::channel::<i32>();
+    let (b_tx, b_rx) = oneshot::channel::<i32>();
+
+    let mut set = KeyedFuturesUnordered::new();
+    let a = set.push(a_rx);
+    let b = set.push(b_rx);
+    assert_ne!(a, b);
+    assert!(set.poll_next_unpin(&mut cx).is_pending());
+
+    assert!(set.cancel(a));
+    assert!(a_tx.is_canceled());
+    assert!(!set.contains(a));
+    assert!(set.contains(b));
+    assert_eq!(set.len(), 1);
+
+    b_tx.send(2).unwrap();
+    assert_eq!(set.poll_next_unpin(&mut cx), Poll::Ready(Some((b, Ok(2)))));
+    assert!(!set.cancel(b));
+    assert_eq!(set.poll_next_unpin(&mut cx), Poll::Ready(None));
+    assert!(set.is_terminated());
+}
+
+#[test]
+fn keyed_tokens_stay_unique() {
+    let mut cx = noop_context();
+    let (_a_tx, a_rx) = oneshot::channel::<i32>();
+    let (b_tx, b_rx) = oneshot::channel::<i32>();
+    let (_c_tx, c_rx) = oneshot::channel::<i32>();
+
+    let mut set = KeyedFuturesUnordered::new();
+    let a = set.push(a_rx);
+    let b = set.push(b_rx);
+    b_tx.send(2).unwrap();
+    assert_eq!(set.poll_next_unpin(&mut cx), Poll::Ready(Some((b, Ok(2)))));
+
+    // The future pushed after `b` completed must not be reachable through `b`.
+    let c = set.push(c_rx);
+    assert_ne!(b, c);
+    assert!(!set.contains(b));
+    assert!(!set.cancel(b));
+    assert!(set.contains(c));
+    assert_eq!(set.len(), 2);
+
+    assert!(set.cancel(c));
+    assert!(set.cancel(a));
+    assert!(set.is_empty());
+    assert_eq!(set.poll_next_unpin(&mut cx), Poll::Ready(None));
+}
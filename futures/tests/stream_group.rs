@@ -0,0 +1,69 @@
+use futures::channel::mpsc;
+use futures::stream::{FusedStream, StreamExt, StreamGroup, StreamGroupEvent};
+use futures::task::Poll;
+use futures_test::task::noop_context;
+
+#[test]
+fn yields_keyed_items_and_terminations() {
+    let mut cx = noop_context();
+    let (a_tx, a_rx) = mpsc::unbounded::<u32>();
+    let (b_tx, b_rx) = mpsc::unbounded::<u32>();
+
+    let mut group = StreamGroup::new();
+    assert!(group.insert(1, a_rx).is_none());
+    assert!(group.insert(2, b_rx).is_none());
+    assert_eq!(group.len(), 2);
+    assert_eq!(group.poll_next_unpin(&mut cx), Poll::Pending);
+
+    b_tx.unbounded_send(20).unwrap();
+    assert_eq!(group.poll_next_unpin(&mut cx), Poll::Ready(Some(StreamGroupEvent::Item(2, 20))));
+    a_tx.unbounded_send(10).unwrap();
+    assert_eq!(group.poll_next_unpin(&mut cx), Poll::Ready(Some(StreamGroupEvent::Item(1, 10))));
+
+    drop(a_tx);
+    assert_eq!(group.poll_next_unpin(&mut cx), Poll::Ready(Some(StreamGroupEvent::Terminated(1))));
+    assert_eq!(group.len(), 1);
+    assert!(!group.contains_key(&1));
+    assert!(group.contains_key(&2));
+
+    drop(b_tx);
+    assert_eq!(group.poll_next_unpin(&mut cx), Poll::Ready(Some(StreamGroupEvent::Terminated(2))));
+    assert_eq!(group.poll_next_unpin(&mut cx), Poll::Ready(None));
+    assert!(group.is_terminated());
+}
+
+#[test]
+fn remove_and_replace() {
+    let mut cx = noop_context();
+    let (a_tx, a_rx) = mpsc::unbounded::<u32>();
+    let (b_tx, b_rx) = mpsc::unbounded::<u32>();
+    let (c_tx, c_rx) = mpsc::unbounded::<u32>();
+
+    let mut group = StreamGroup::new();
+    group.insert("a", a_rx);
+    group.insert("b", b_rx);
+    assert_eq!(group.poll_next_unpin(&mut cx), Poll::Pending);
+
+    // Removing a pending stream hands it back without a termination event.
+    let mut a_rx = group.remove(&"a").unwrap();
+    assert!(group.remove(&"a").is_none());
+    assert_eq!(group.len(), 1);
+    a_tx.unbounded_send(1).unwrap();
+    assert_eq!(a_rx.poll_next_unpin(&mut cx), Poll::Ready(Some(1)));
+
+    // Inserting under an existing key replaces the old stream.
+    let b_rx = group.insert("b", c_rx).unwrap();
+    drop(b_rx);
+    assert_eq!(group.len(), 1);
+    assert_eq!(group.poll_next_unpin(&mut cx), Poll::Pending);
+    assert!(b_tx.unbounded_send(2).is_err());
+
+    c_tx.unbounded_send(3).unwrap();
+    drop(c_tx);
+    assert_eq!(group.poll_next_unpin(&mut cx), Poll::Ready(Some(StreamGroupEvent::Item("b", 3))));
+    assert_eq!(
+        group.poll_next_unpin(&mut cx),
+        Poll::Ready(Some(StreamGroupEvent::Terminated("b")))
+    );
+    assert_eq!(group.poll_next_unpin(&mut cx), Poll::Ready(None));
+}
@@ -56,3 +56,24 @@ fn peekable_next_if_eq() {
         assert_eq!(s.as_mut().next_if_eq("").await, None);
     });
 }
+
+#[test]
+fn peekable_next_if() {
+    block_on(async {
+        let s = stream::iter(1..10).peekable();
+        pin_mut!(s);
+        // consume while the predicate holds
+        while s.as_mut().next_if(|&x| x < 4).await.is_some() {}
+        // the rejected item is stored and can be peeked
+        assert_eq!(s.as_mut().peek().await, Some(&4));
+        assert_eq!(s.as_mut().next_if(|&x| x % 2 == 0).await, Some(4));
+        assert_eq!(s.as_mut().next_if(|&x| x % 2 == 0).await, None);
+        assert_eq!(s.as_mut().peek_mut().await, Some(&mut 5));
+        assert_eq!(s.collect::<Vec<_>>().await, vec![5, 6, 7, 8, 9]);
+
+        // the predicate is not called once the stream has terminated
+        let s = stream::empty::<u8>().peekable();
+        pin_mut!(s);
+        assert_eq!(s.as_mut().next_if(|_| unreachable!()).await, None);
+    });
+}
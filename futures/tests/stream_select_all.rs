@@ -1,7 +1,9 @@
 use futures::channel::mpsc;
 use futures::executor::{block_on, block_on_stream};
 use futures::future::{self, FutureExt};
-use futures::stream::{self, select_all, FusedStream, SelectAll, StreamExt};
+use futures::stream::{
+    self, select_all, FusedStream, PrioritySelectAll, PriorityStrategy, SelectAll, StreamExt,
+};
 use futures::task::Poll;
 use futures_test::task::noop_context;
 
@@ -195,3 +197,49 @@ fn into_iter() {
     assert_eq!(iter.len(), 0);
     assert!(iter.next().is_none());
 }
+
+#[test]
+fn priority_strict() {
+    let (low_tx, low_rx) = mpsc::unbounded::<&str>();
+    let (high_tx, high_rx) = mpsc::unbounded::<&str>();
+    let mut cx = noop_context();
+
+    let mut set = PrioritySelectAll::new(PriorityStrategy::Strict);
+    set.push(low_rx, 1);
+    set.push(high_rx, 2);
+    assert_eq!(set.poll_next_unpin(&mut cx), Poll::Pending);
+
+    low_tx.unbounded_send("low").unwrap();
+    high_tx.unbounded_send("high 1").unwrap();
+    high_tx.unbounded_send("high 2").unwrap();
+    assert_eq!(set.poll_next_unpin(&mut cx), Poll::Ready(Some("high 1")));
+    assert_eq!(set.poll_next_unpin(&mut cx), Poll::Ready(Some("high 2")));
+    assert_eq!(set.poll_next_unpin(&mut cx), Poll::Ready(Some("low")));
+
+    drop((low_tx, high_tx));
+    assert_eq!(set.poll_next_unpin(&mut cx), Poll::Ready(None));
+    assert!(set.is_terminated());
+}
+
+#[test]
+fn priority_strict_ties_take_turns() {
+    let mut set = PrioritySelectAll::new(PriorityStrategy::Strict);
+    set.push(stream::repeat(1), 5);
+    set.push(stream::repeat(2), 5);
+    set.push(stream::repeat(3), 1);
+
+    let items = block_on(set.take(4).collect::<Vec<_>>());
+    assert_eq!(items, vec![1, 2, 1, 2]);
+}
+
+#[test]
+fn priority_weighted_fair() {
+    let mut set = PrioritySelectAll::new(PriorityStrategy::WeightedFair);
+    set.push(stream::repeat('a'), 3);
+    set.push(stream::repeat('b'), 1);
+
+    let items = block_on(set.take(8).collect::<Vec<_>>());
+    assert_eq!(items.iter().filter(|&&c| c == 'a').count(), 6);
+    assert_eq!(items.iter().filter(|&&c| c == 'b').count(), 2);
+    assert_eq!(items[..4].iter().filter(|&&c| c == 'b').count(), 1);
+}
@@ -1,7 +1,7 @@
 #![cfg(not(miri))] // https://github.com/rust-lang/miri/issues/1038
 
 use futures::{
-    stream::{self, StreamExt, TryStreamExt},
+    stream::{self, Stream, StreamExt, TryStreamExt},
     task::Poll,
 };
 use futures_test::task::noop_context;
@@ -38,3 +38,46 @@ fn try_take_while_after_err() {
         .boxed();
     assert_eq!(Poll::Ready(None), s.poll_next_unpin(cx));
 }
+
+#[test]
+fn or_else_stream_waits_for_fallback() {
+    use futures::channel::{mpsc, oneshot};
+    use futures::future::FutureExt;
+
+    let cx = &mut noop_context();
+    let (tx, rx) = mpsc::unbounded::<Result<i32, i32>>();
+    let (reconnect_tx, reconnect_rx) = oneshot::channel();
+    let mut reconnect_rx = Some(reconnect_rx);
+    let mut s = rx.or_else_stream(None, move |e| {
+        assert_eq!(e, -1);
+        reconnect_rx.take().unwrap().map(Result::unwrap)
+    });
+
+    tx.unbounded_send(Ok(1)).unwrap();
+    tx.unbounded_send(Err(-1)).unwrap();
+    tx.unbounded_send(Ok(2)).unwrap();
+    assert_eq!(Poll::Ready(Some(Ok(1))), s.poll_next_unpin(cx));
+    assert_eq!(Poll::Pending, s.poll_next_unpin(cx));
+    assert_eq!(s.remaining_switches(), None);
+
+    // the items still queued on the failed stream are dropped with it
+    let (tx2, rx2) = mpsc::unbounded();
+    reconnect_tx.send(rx2).unwrap();
+    tx2.unbounded_send(Ok(3)).unwrap();
+    drop(tx2);
+    assert_eq!(Poll::Ready(Some(Ok(3))), s.poll_next_unpin(cx));
+    assert_eq!(Poll::Ready(None), s.poll_next_unpin(cx));
+    assert!(tx.is_closed());
+}
+
+#[test]
+fn try_start_with_keeps_error_type() {
+    let cx = &mut noop_context();
+    let mut s = stream::iter(vec![Err::<i32, &str>("boom"), Ok(2)]).try_start_with(Some(1));
+
+    assert_eq!(s.size_hint(), (3, Some(3)));
+    assert_eq!(Poll::Ready(Some(Ok(1))), s.try_poll_next_unpin(cx));
+    assert_eq!(Poll::Ready(Some(Err("boom"))), s.try_poll_next_unpin(cx));
+    assert_eq!(Poll::Ready(Some(Ok(2))), s.try_poll_next_unpin(cx));
+    assert_eq!(Poll::Ready(None), s.try_poll_next_unpin(cx));
+}
@@ -1,5 +1,5 @@
 use futures::future;
-use futures::stream;
+use futures::stream::{self, UnfoldStep};
 use futures_test::future::FutureTestExt;
 use futures_test::{assert_stream_done, assert_stream_next, assert_stream_pending};
 
@@ -30,3 +30,27 @@ fn unfold1() {
     assert_stream_pending!(stream);
     assert_stream_done!(stream);
 }
+
+#[test]
+fn unfold_final() {
+    let mut stream = stream::unfold_final(0, |state| {
+        if state <= 1 {
+            future::ready(UnfoldStep::Next(state * 2, state + 1)).pending_once()
+        } else {
+            future::ready(UnfoldStep::Done(state)).pending_once()
+        }
+    });
+
+    assert_stream_pending!(stream);
+    assert_stream_next!(stream, 0);
+
+    assert_stream_pending!(stream);
+    assert_stream_next!(stream, 2);
+    assert_eq!(stream.final_value(), None);
+
+    // The final value is only available once the stream has terminated
+    assert_stream_pending!(stream);
+    assert_stream_done!(stream);
+    assert_eq!(stream.final_value(), Some(&2));
+    assert_eq!(stream.into_final_value(), Some(2));
+}
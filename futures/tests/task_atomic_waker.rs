@@ -1,8 +1,10 @@
 use futures::executor::block_on;
 use futures::future::poll_fn;
-use futures::task::{AtomicWaker, Poll};
-use std::sync::atomic::AtomicUsize;
+use futures::task::{
+    noop_waker_ref, waker, ArcWake, AtomicWaker, Poll, RawWaker, RawWakerVTable, Waker,
+};
 use std::sync::atomic::Ordering;
+use std::sync::atomic::{AtomicBool, AtomicUsize};
 use std::sync::Arc;
 use std::thread;
 
@@ -47,3 +49,85 @@ fn basic() {
 
     t.join().unwrap();
 }
+
+#[test]
+fn register_keeps_waker_of_same_task() {
+    static CLONES: AtomicUsize = AtomicUsize::new(0);
+
+    unsafe fn clone(data: *const ()) -> RawWaker {
+        CLONES.fetch_add(1, Ordering::SeqCst);
+        RawWaker::new(data, &VTABLE)
+    }
+    unsafe fn noop(_data: *const ()) {}
+    static VTABLE: RawWakerVTable = RawWakerVTable::new(clone, noop, noop, noop);
+
+    let waker =
+        unsafe { Waker::from_raw(RawWaker::new(&VTABLE as *const _ as *const (), &VTABLE)) };
+    let atomic_waker = AtomicWaker::new();
+
+    atomic_waker.register(&waker);
+    atomic_waker.register(&waker);
+    atomic_waker.register(&waker);
+    assert_eq!(CLONES.load(Ordering::SeqCst), 1);
+
+    // Another task replaces the waker.
+    atomic_waker.register(noop_waker_ref());
+    assert!(noop_waker_ref().will_wake(&atomic_waker.take().unwrap()));
+}
+
+#[test]
+fn is_registered() {
+    let atomic_waker = AtomicWaker::new();
+    assert!(!atomic_waker.is_registered());
+
+    atomic_waker.register(noop_waker_ref());
+    assert!(atomic_waker.is_registered());
+    assert!(atomic_waker.is_registered());
+
+    atomic_waker.wake();
+    assert!(!atomic_waker.is_registered());
+
+    atomic_waker.register(noop_waker_ref());
+    assert!(atomic_waker.take().is_some());
+    assert!(!atomic_waker.is_registered());
+    assert!(atomic_waker.take().is_none());
+}
+
+#[cfg_attr(miri, ignore)] // Miri is too slow
+#[test]
+fn register_racing_is_registered_is_woken() {
+    struct Counter(AtomicUsize);
+
+    impl ArcWake for Counter {
+        fn wake_by_ref(arc_self: &Arc<Self>) {
+            arc_self.0.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    let atomic_waker = Arc::new(AtomicWaker::new());
+    let queries = Arc::new(AtomicUsize::new(0));
+    let stop = Arc::new(AtomicBool::new(false));
+    let querying = {
+        let atomic_waker = atomic_waker.clone();
+        let queries = queries.clone();
+        let stop = stop.clone();
+        thread::spawn(move || {
+            while !stop.load(Ordering::Relaxed) {
+                atomic_waker.is_registered();
+                queries.fetch_add(1, Ordering::Relaxed);
+            }
+        })
+    };
+    while queries.load(Ordering::Relaxed) == 0 {}
+
+    let counter = Arc::new(Counter(AtomicUsize::new(0)));
+    let waker = waker(counter.clone());
+    for i in 1..=100_000 {
+        atomic_waker.register(&waker);
+        atomic_waker.wake();
+        assert_eq!(counter.0.load(Ordering::SeqCst), i);
+    }
+
+    stop.store(true, Ordering::Relaxed);
+    querying.join().unwrap();
+}
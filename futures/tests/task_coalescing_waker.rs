@@ -0,0 +1,69 @@
+use futures::executor::block_on;
+use futures::future::poll_fn;
+use futures::task::{CoalescingWaker, Poll};
+use futures_test::task::new_count_waker;
+use std::sync::Arc;
+use std::thread;
+
+#[test]
+fn wakes_task_once_between_polls() {
+    let (task, count) = new_count_waker();
+    let coalescing = CoalescingWaker::new();
+
+    coalescing.register(&task);
+    assert!(!coalescing.take_woken());
+
+    let wakers: Vec<_> = (0..5).map(|_| coalescing.waker()).collect();
+    for waker in &wakers {
+        waker.wake_by_ref();
+    }
+    assert_eq!(count, 1);
+
+    // The task is polled again.
+    coalescing.register(&task);
+    assert!(coalescing.take_woken());
+    assert!(!coalescing.take_woken());
+
+    coalescing.waker_ref().wake_by_ref();
+    wakers[0].wake_by_ref();
+    assert_eq!(count, 2);
+}
+
+#[test]
+fn wake_before_register_is_seen_by_take_woken() {
+    let (task, count) = new_count_waker();
+    let coalescing = CoalescingWaker::new();
+
+    coalescing.waker().wake();
+    coalescing.register(&task);
+    assert_eq!(count, 0);
+    assert!(coalescing.take_woken());
+}
+
+#[cfg_attr(miri, ignore)] // Miri is too slow
+#[test]
+fn wakes_from_other_threads() {
+    let coalescing = Arc::new(CoalescingWaker::new());
+    let mut handles = Vec::new();
+    let mut polls = 0;
+
+    block_on(poll_fn(|cx| {
+        coalescing.register(cx.waker());
+        coalescing.take_woken();
+        polls += 1;
+        if handles.is_empty() {
+            for _ in 0..4 {
+                let waker = coalescing.waker();
+                handles.push(thread::spawn(move || waker.wake()));
+            }
+            Poll::Pending
+        } else {
+            Poll::Ready(())
+        }
+    }));
+
+    for handle in handles {
+        handle.join().unwrap();
+    }
+    assert_eq!(polls, 2);
+}
@@ -0,0 +1,85 @@
+use futures::future::{self, Future, FutureExt};
+use futures::sink;
+use futures::stream::{self, FuturesUnordered, StreamExt};
+use futures::task::{coop, Context, Poll};
+use futures_test::task::{new_count_waker, noop_context};
+use std::convert::Infallible;
+
+// Polls `future` to completion, returning the number of times it was polled.
+fn polls_to_complete<Fut: Future + Unpin>(mut future: Fut) -> usize {
+    let (waker, count) = new_count_waker();
+    let mut cx = Context::from_waker(&waker);
+    let mut polls = 1;
+    while future.poll_unpin(&mut cx).is_pending() {
+        // the future must have woken itself up to be polled again
+        assert_eq!(count.get(), polls);
+        polls += 1;
+    }
+    polls
+}
+
+#[test]
+fn consume_budget_yields_when_exhausted() {
+    let future = coop::budget(async {
+        for _ in 0..300 {
+            coop::consume_budget().await;
+        }
+    });
+    assert_eq!(polls_to_complete(Box::pin(future)), 3);
+}
+
+#[test]
+fn consume_budget_is_unlimited_outside_of_budget() {
+    let future = async {
+        for _ in 0..300 {
+            coop::consume_budget().await;
+        }
+    };
+    assert_eq!(polls_to_complete(Box::pin(future)), 1);
+}
+
+#[test]
+fn nested_budget_spends_outer_budget() {
+    let future = coop::budget(async {
+        for _ in 0..200 {
+            coop::budget(coop::consume_budget()).await;
+        }
+    });
+    assert_eq!(polls_to_complete(Box::pin(future)), 2);
+}
+
+#[test]
+fn budget_is_reset_after_poll() {
+    let mut cx = noop_context();
+    let mut future = coop::budget(future::ready(()));
+    assert_eq!(future.poll_unpin(&mut cx), Poll::Ready(()));
+    assert_eq!(polls_to_complete(Box::pin(coop::consume_budget())), 1);
+}
+
+#[test]
+fn futures_unordered_honors_budget() {
+    let task = || async {
+        // leave a single unit of the budget
+        for _ in 0..127 {
+            coop::consume_budget().await;
+        }
+        let futures: FuturesUnordered<_> = (0..10).map(future::ready).collect();
+        futures.collect::<Vec<_>>().await.len()
+    };
+    assert_eq!(polls_to_complete(Box::pin(task())), 1);
+    assert_eq!(polls_to_complete(Box::pin(coop::budget(task()))), 2);
+}
+
+#[test]
+fn for_each_concurrent_honors_budget() {
+    let task = || stream::repeat(()).take(300).for_each_concurrent(None, |()| async {});
+    assert_eq!(polls_to_complete(task()), 1);
+    assert!(polls_to_complete(coop::budget(task())) > 1);
+}
+
+#[test]
+fn forward_honors_budget() {
+    let task = || stream::iter(0..300).map(Ok::<_, Infallible>).forward(sink::drain());
+    assert_eq!(polls_to_complete(task()), 1);
+    assert!(polls_to_complete(coop::budget(task())) > 1);
+}
@@ -0,0 +1,74 @@
+use futures::channel::oneshot;
+use futures::executor::{block_on, LocalPool};
+use futures::future::{self, FutureExt};
+use futures::task::LocalSpawnExt;
+use futures::task_local;
+use std::cell::RefCell;
+use std::panic::{self, AssertUnwindSafe};
+use std::rc::Rc;
+
+task_local! {
+    static NUMBER: u32;
+    /// A value that is not `Copy`.
+    pub(crate) static NAME: String;
+}
+
+#[test]
+fn value_is_set_while_polled() {
+    assert!(NUMBER.try_with(|_| ()).is_err());
+    let output = block_on(async { NUMBER.with(|n| *n) + 1 }.with_value(&NUMBER, 1));
+    assert_eq!(output, 2);
+    assert!(NUMBER.try_with(|_| ()).is_err());
+}
+
+#[test]
+#[should_panic(expected = "not set")]
+fn with_panics_if_not_set() {
+    NUMBER.with(|_| ());
+}
+
+#[test]
+fn values_of_interleaved_tasks_are_separate() {
+    let mut pool = LocalPool::new();
+    let spawner = pool.spawner();
+    let seen = Rc::new(RefCell::new(Vec::new()));
+    let (tx, rx) = oneshot::channel();
+
+    let first = {
+        let seen = seen.clone();
+        async move {
+            seen.borrow_mut().push(NAME.with(String::clone));
+            rx.await.unwrap();
+            seen.borrow_mut().push(NAME.with(String::clone));
+        }
+    };
+    let second = {
+        let seen = seen.clone();
+        async move {
+            seen.borrow_mut().push(NAME.with(String::clone));
+            tx.send(()).unwrap();
+        }
+    };
+    spawner.spawn_local(first.with_value(&NAME, "first".to_string())).unwrap();
+    spawner.spawn_local(second.with_value(&NAME, "second".to_string())).unwrap();
+
+    pool.run();
+    assert_eq!(*seen.borrow(), ["first", "second", "first"]);
+}
+
+#[test]
+fn inner_value_shadows_outer_value() {
+    let future = async {
+        let inner = future::lazy(|_| NUMBER.with(|n| *n)).with_value(&NUMBER, 2).await;
+        (NUMBER.with(|n| *n), inner)
+    };
+    assert_eq!(block_on(future.with_value(&NUMBER, 1)), (1, 2));
+}
+
+#[test]
+fn value_is_unset_after_panic() {
+    let future = future::lazy(|_| panic!("boom")).with_value(&NUMBER, 1);
+    let res = panic::catch_unwind(AssertUnwindSafe(|| block_on(future)));
+    assert!(res.is_err());
+    assert!(NUMBER.try_with(|_| ()).is_err());
+}
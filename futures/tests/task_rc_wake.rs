@@ -0,0 +1,86 @@
+use futures::channel::oneshot;
+use futures::future::FutureExt;
+use futures::task::{self, Context, Poll, RcWake, Waker};
+use std::cell::Cell;
+use std::rc::Rc;
+
+struct CountingWaker {
+    nr_wake: Cell<i32>,
+}
+
+impl CountingWaker {
+    fn new() -> Self {
+        Self { nr_wake: Cell::new(0) }
+    }
+
+    fn wakes(&self) -> i32 {
+        self.nr_wake.get()
+    }
+}
+
+impl RcWake for CountingWaker {
+    fn wake_by_ref(rc_self: &Rc<Self>) {
+        rc_self.nr_wake.set(rc_self.nr_wake.get() + 1);
+    }
+}
+
+#[test]
+fn create_from_rc() {
+    let some_w = Rc::new(CountingWaker::new());
+
+    let w1: Waker = unsafe { task::rc_waker(some_w.clone()) };
+    assert_eq!(2, Rc::strong_count(&some_w));
+    w1.wake_by_ref();
+    assert_eq!(1, some_w.wakes());
+
+    let w2 = w1.clone();
+    assert_eq!(3, Rc::strong_count(&some_w));
+
+    w2.wake();
+    assert_eq!(2, some_w.wakes());
+    assert_eq!(2, Rc::strong_count(&some_w));
+
+    drop(w1);
+    assert_eq!(1, Rc::strong_count(&some_w));
+}
+
+#[test]
+fn ref_wake_same() {
+    let some_w = Rc::new(CountingWaker::new());
+
+    let w1: Waker = unsafe { task::rc_waker(some_w.clone()) };
+    let w3 = {
+        let w2 = unsafe { task::rc_waker_ref(&some_w) };
+        let w3 = w2.clone();
+
+        assert!(w1.will_wake(&w2));
+        assert!(w2.will_wake(&w3));
+        assert_eq!(3, Rc::strong_count(&some_w));
+
+        w2.wake_by_ref();
+        assert_eq!(1, some_w.wakes());
+        w3
+    };
+    // Dropping the reference didn't touch the refcount.
+    assert_eq!(3, Rc::strong_count(&some_w));
+    drop(w3);
+    assert_eq!(2, Rc::strong_count(&some_w));
+}
+
+#[test]
+fn wakes_polled_future() {
+    let some_w = Rc::new(CountingWaker::new());
+    let waker = unsafe { task::rc_waker_ref(&some_w) };
+    let mut cx = Context::from_waker(&waker);
+
+    let (tx, mut rx) = oneshot::channel::<i32>();
+    assert_eq!(rx.poll_unpin(&mut cx), Poll::Pending);
+    assert_eq!(0, some_w.wakes());
+
+    tx.send(1).unwrap();
+    assert_eq!(1, some_w.wakes());
+    assert_eq!(rx.poll_unpin(&mut cx), Poll::Ready(Ok(1)));
+
+    // The channel dropped its clone of the waker.
+    assert_eq!(1, Rc::strong_count(&some_w));
+}
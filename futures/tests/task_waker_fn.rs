@@ -0,0 +1,49 @@
+use futures::task::{waker_fn, Waker};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+fn counting_waker() -> (Waker, Arc<AtomicUsize>) {
+    let wakes = Arc::new(AtomicUsize::new(0));
+    let waker = {
+        let wakes = wakes.clone();
+        waker_fn(move || {
+            wakes.fetch_add(1, Ordering::SeqCst);
+        })
+    };
+    (waker, wakes)
+}
+
+#[test]
+fn wake_calls_closure() {
+    let (waker, wakes) = counting_waker();
+
+    waker.wake_by_ref();
+    assert_eq!(wakes.load(Ordering::SeqCst), 1);
+
+    let clone = waker.clone();
+    assert!(clone.will_wake(&waker));
+    clone.wake();
+    assert_eq!(wakes.load(Ordering::SeqCst), 2);
+
+    waker.wake();
+    assert_eq!(wakes.load(Ordering::SeqCst), 3);
+}
+
+#[test]
+fn closure_is_dropped_with_last_waker() {
+    let (waker, wakes) = counting_waker();
+    let clone = waker.clone();
+    assert_eq!(Arc::strong_count(&wakes), 2);
+
+    drop(waker);
+    assert_eq!(Arc::strong_count(&wakes), 2);
+    drop(clone);
+    assert_eq!(Arc::strong_count(&wakes), 1);
+}
+
+#[test]
+fn wakes_from_other_thread() {
+    let (waker, wakes) = counting_waker();
+    std::thread::spawn(move || waker.wake()).join().unwrap();
+    assert_eq!(wakes.load(Ordering::SeqCst), 1);
+}
@@ -0,0 +1,102 @@
+use futures::task::{waker_fn, Waker, WakerKey, WakerSet};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+fn counting_waker() -> (Waker, Arc<AtomicUsize>) {
+    let wakes = Arc::new(AtomicUsize::new(0));
+    let waker = {
+        let wakes = wakes.clone();
+        waker_fn(move || {
+            wakes.fetch_add(1, Ordering::SeqCst);
+        })
+    };
+    (waker, wakes)
+}
+
+#[test]
+fn wake_one_wakes_each_waker_once() {
+    let set = WakerSet::new();
+    let (waker, wakes) = counting_waker();
+    let mut keys: Vec<Option<WakerKey>> = (0..3).map(|_| None).collect();
+    for key in &mut keys {
+        set.register(key, &waker);
+    }
+    assert_eq!(set.waiting(), 3);
+
+    assert!(set.wake_one());
+    assert!(set.wake_one());
+    assert!(set.wake_one());
+    assert!(!set.wake_one());
+    assert_eq!(wakes.load(Ordering::SeqCst), 3);
+    assert_eq!(set.waiting(), 0);
+}
+
+#[test]
+fn wake_all() {
+    let set = WakerSet::new();
+    let (waker, wakes) = counting_waker();
+    let mut key1 = None;
+    let mut key2 = None;
+    set.register(&mut key1, &waker);
+    set.register(&mut key2, &waker);
+
+    assert_eq!(set.wake_all(), 2);
+    assert_eq!(set.wake_all(), 0);
+    assert_eq!(wakes.load(Ordering::SeqCst), 2);
+
+    // registering again makes a woken waker wait again
+    set.register(&mut key1, &waker);
+    assert_eq!(set.waiting(), 1);
+    assert_eq!(set.wake_all(), 1);
+    assert_eq!(wakes.load(Ordering::SeqCst), 3);
+}
+
+#[test]
+fn register_replaces_waker() {
+    let set = WakerSet::new();
+    let (waker1, wakes1) = counting_waker();
+    let (waker2, wakes2) = counting_waker();
+    let mut key = None;
+    set.register(&mut key, &waker1);
+    set.register(&mut key, &waker2);
+    assert_eq!(set.waiting(), 1);
+
+    assert!(set.wake_one());
+    assert_eq!(wakes1.load(Ordering::SeqCst), 0);
+    assert_eq!(wakes2.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn remove_reports_wakeups() {
+    let set = WakerSet::new();
+    let (waker, wakes) = counting_waker();
+    let mut key1 = None;
+    let mut key2 = None;
+    set.register(&mut key1, &waker);
+    set.register(&mut key2, &waker);
+
+    assert!(!set.remove(key1.take().unwrap()));
+    assert_eq!(set.waiting(), 1);
+
+    assert!(set.wake_one());
+    assert!(set.remove(key2.take().unwrap()));
+    assert_eq!(set.waiting(), 0);
+    assert!(!set.wake_one());
+    assert_eq!(wakes.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn waker_may_register_again_when_woken() {
+    let set = Arc::new(WakerSet::new());
+    let waker = {
+        let set = set.clone();
+        waker_fn(move || {
+            let mut key = None;
+            set.register(&mut key, futures::task::noop_waker_ref());
+        })
+    };
+    let mut key = None;
+    set.register(&mut key, &waker);
+    assert!(set.wake_one());
+    assert_eq!(set.waiting(), 1);
+}
@@ -0,0 +1,75 @@
+use futures::channel::mpsc;
+use futures::sink;
+use futures::stream::{self, StreamExt};
+use futures_test::sink::SinkTestExt;
+use futures_test::stream::StreamTestExt;
+use futures_test::{
+    assert_sink_flushed, assert_sink_pending, assert_sink_ready, assert_sink_send,
+    assert_stream_done, assert_stream_items, assert_stream_next, assert_stream_pending,
+};
+
+#[test]
+fn stream_items() {
+    let mut stream = stream::iter(1..=3).interleave_pending();
+    assert_stream_pending!(stream);
+    assert_stream_next!(stream, 1);
+    assert_stream_pending!(stream);
+    assert_stream_items!(stream, Some(2));
+
+    let mut stream = stream::iter(1..=3);
+    assert_stream_items!(stream, 1..=3);
+    assert_stream_done!(stream);
+}
+
+#[test]
+#[should_panic(expected = "unexpected item at index 1")]
+fn stream_items_mismatch() {
+    let mut stream = stream::iter(vec![1, 2]);
+    assert_stream_items!(stream, vec![1, 3]);
+}
+
+#[test]
+#[should_panic(expected = "item at index 2 but stream is at its end")]
+fn stream_items_too_short() {
+    let mut stream = stream::iter(vec![1, 2]);
+    assert_stream_items!(stream, vec![1, 2, 3]);
+}
+
+#[test]
+fn sink_readiness() {
+    let (tx, mut rx) = mpsc::channel::<i32>(0);
+    let mut tx = tx.count_polls_sink();
+    assert_sink_send!(tx, 1);
+    assert_sink_pending!(tx);
+    assert_stream_next!(rx, 1);
+    assert_sink_ready!(tx);
+    assert_sink_flushed!(tx);
+    assert_eq!(tx.polls(), 4);
+}
+
+#[test]
+#[should_panic(expected = "expected sink to be ready but it was pending")]
+fn sink_not_ready() {
+    let mut sink = sink::drain::<i32>().interleave_pending_sink();
+    assert_sink_ready!(sink);
+}
+
+#[test]
+#[should_panic(expected = "expected sink to be ready but it failed")]
+fn sink_failed() {
+    let (mut tx, rx) = mpsc::channel::<i32>(0);
+    drop(rx);
+    assert_sink_send!(tx, 1);
+}
+
+#[test]
+fn fuse_stops_polling_after_end() {
+    let stream = stream::iter(vec![1]).count_polls();
+    let counter = stream.counter();
+    let mut stream = stream.fuse();
+    assert_stream_items!(stream, vec![1]);
+    for _ in 0..3 {
+        assert_stream_done!(stream);
+    }
+    assert_eq!(counter, 2);
+}
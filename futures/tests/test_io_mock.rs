@@ -0,0 +1,85 @@
+use futures::executor::block_on;
+use futures::future;
+use futures::io::{self, AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+use futures::task::Poll;
+use futures_test::io::Mock;
+use futures_test::task::noop_context;
+use std::pin::Pin;
+
+#[test]
+fn reads_and_writes_in_chunks() {
+    let mut mock = Mock::builder().read(b"hello").write(b"world").read(b"!").build();
+    block_on(async {
+        let mut buf = [0; 3];
+        assert_eq!(mock.read(&mut buf).await.unwrap(), 3);
+        assert_eq!(&buf, b"hel");
+        assert_eq!(mock.read(&mut buf).await.unwrap(), 2);
+        assert_eq!(&buf[..2], b"lo");
+        mock.write_all(b"wor").await.unwrap();
+        mock.write_all(b"ld").await.unwrap();
+        let mut rest = Vec::new();
+        mock.read_to_end(&mut rest).await.unwrap();
+        assert_eq!(rest, b"!");
+    });
+}
+
+#[test]
+fn pending_and_errors() {
+    let mut mock = Mock::builder()
+        .pending()
+        .read(b"a")
+        .read_error(io::ErrorKind::ConnectionReset.into())
+        .write_error(io::ErrorKind::BrokenPipe.into())
+        .build();
+    let mut cx = noop_context();
+    let mut buf = [0; 4];
+    let mut mock = Pin::new(&mut mock);
+
+    assert!(mock.as_mut().poll_read(&mut cx, &mut buf).is_pending());
+    assert!(matches!(mock.as_mut().poll_read(&mut cx, &mut buf), Poll::Ready(Ok(1))));
+    match mock.as_mut().poll_read(&mut cx, &mut buf) {
+        Poll::Ready(Err(e)) => assert_eq!(e.kind(), io::ErrorKind::ConnectionReset),
+        _ => panic!("expected a read error"),
+    }
+    match mock.as_mut().poll_write(&mut cx, b"b") {
+        Poll::Ready(Err(e)) => assert_eq!(e.kind(), io::ErrorKind::BrokenPipe),
+        _ => panic!("expected a write error"),
+    }
+    assert!(matches!(mock.as_mut().poll_read(&mut cx, &mut buf), Poll::Ready(Ok(0))));
+}
+
+#[test]
+fn read_waits_for_scripted_write() {
+    let mock = Mock::builder().write(b"request").read(b"response").build();
+    let (mut reader, mut writer) = mock.split();
+    block_on(async {
+        let read = async {
+            let mut buf = Vec::new();
+            reader.read_to_end(&mut buf).await.unwrap();
+            buf
+        };
+        let write = async { writer.write_all(b"request").await.unwrap() };
+        let (buf, ()) = future::join(read, write).await;
+        assert_eq!(buf, b"response");
+    });
+}
+
+#[test]
+#[should_panic(expected = "the written bytes don't match the scripted write")]
+fn unexpected_write_contents() {
+    let mut mock = Mock::builder().write(b"hello").build();
+    block_on(mock.write_all(b"help")).unwrap();
+}
+
+#[test]
+#[should_panic(expected = "the script is exhausted")]
+fn write_after_script() {
+    let mut mock = Mock::builder().build();
+    block_on(mock.write_all(b"hello")).unwrap();
+}
+
+#[test]
+#[should_panic(expected = "the mock was dropped before the end of the script")]
+fn dropped_before_end_of_script() {
+    let _mock = Mock::builder().read(b"hello").build();
+}
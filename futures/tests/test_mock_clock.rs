@@ -0,0 +1,73 @@
+use futures::channel::mpsc;
+use futures::executor::block_on;
+use futures::future::FutureExt;
+use futures::sink::SinkExt;
+use futures::stream::StreamExt;
+use futures::task::Poll;
+use futures::time::Timer;
+use futures_test::task::{new_count_waker, noop_context};
+use futures_test::time::MockClock;
+use std::task::Context;
+use std::time::Duration;
+
+#[test]
+fn delay_completes_once_advanced_past_deadline() {
+    let clock = MockClock::new();
+    let (waker, count) = new_count_waker();
+    let mut cx = Context::from_waker(&waker);
+    let mut delay = clock.delay(Duration::from_millis(100));
+    assert_eq!(delay.deadline(), Duration::from_millis(100));
+
+    assert_eq!(delay.poll_unpin(&mut cx), Poll::Pending);
+    clock.advance(Duration::from_millis(99));
+    assert_eq!(count, 0);
+    assert_eq!(delay.poll_unpin(&mut cx), Poll::Pending);
+    clock.advance(Duration::from_millis(1));
+    assert_eq!(count, 1);
+    assert_eq!(delay.poll_unpin(&mut cx), Poll::Ready(()));
+    assert_eq!(clock.elapsed(), Duration::from_millis(100));
+}
+
+#[test]
+fn clones_share_time() {
+    let clock = MockClock::new();
+    let delay = clock.clone().delay(Duration::from_secs(1));
+    clock.advance(Duration::from_secs(1));
+    assert_eq!(delay.now_or_never(), Some(()));
+
+    // Delays are relative to the time at which they are created.
+    let delay = clock.delay(Duration::from_secs(1));
+    assert_eq!(delay.deadline(), Duration::from_secs(2));
+}
+
+#[test]
+fn next_deadline() {
+    let clock = MockClock::new();
+    let mut cx = noop_context();
+    let mut later = clock.delay(Duration::from_secs(2));
+    let mut sooner = clock.delay(Duration::from_secs(1));
+    assert_eq!(clock.next_deadline(), None);
+
+    assert!(later.poll_unpin(&mut cx).is_pending());
+    assert!(sooner.poll_unpin(&mut cx).is_pending());
+    assert_eq!(clock.next_deadline(), Some(Duration::from_secs(1)));
+    drop(sooner);
+    assert_eq!(clock.next_deadline(), Some(Duration::from_secs(2)));
+    clock.advance(Duration::from_secs(2));
+    assert_eq!(clock.next_deadline(), None);
+    assert!(later.poll_unpin(&mut cx).is_ready());
+}
+
+#[test]
+fn drives_time_based_combinators() {
+    let clock = MockClock::new();
+    let (mut tx, rx) = mpsc::unbounded();
+    let mut chunks = rx.ready_chunks_timeout(10, || clock.delay(Duration::from_secs(1)));
+    let mut cx = noop_context();
+
+    block_on(tx.send(1)).unwrap();
+    block_on(tx.send(2)).unwrap();
+    assert_eq!(chunks.poll_next_unpin(&mut cx), Poll::Pending);
+    clock.advance(clock.next_deadline().unwrap());
+    assert_eq!(chunks.poll_next_unpin(&mut cx), Poll::Ready(Some(vec![1, 2])));
+}
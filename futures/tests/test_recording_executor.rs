@@ -0,0 +1,107 @@
+use futures::channel::oneshot;
+use futures::future::poll_fn;
+use futures::lock::Mutex;
+use futures::task::{LocalSpawnExt, Poll, Waker};
+use futures_test::task::{Event, RecordingExecutor};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+fn poll_order(executor: &RecordingExecutor) -> Vec<usize> {
+    let ids = executor
+        .events()
+        .into_iter()
+        .filter_map(|event| match event {
+            Event::Spawned(id) => Some(id),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    executor
+        .events()
+        .into_iter()
+        .filter_map(|event| match event {
+            Event::Polled(id, _) => Some(ids.iter().position(|i| *i == id).unwrap()),
+            _ => None,
+        })
+        .collect()
+}
+
+fn run_with_seed(seed: Option<u64>) -> Vec<usize> {
+    let executor = seed.map_or_else(RecordingExecutor::new, RecordingExecutor::with_seed);
+    for _ in 0..8 {
+        executor.spawn(async {});
+    }
+    executor.run_until_stalled();
+    assert_eq!(executor.pending_tasks(), 0);
+    poll_order(&executor)
+}
+
+#[test]
+fn polls_in_wake_order_by_default() {
+    assert_eq!(run_with_seed(None), (0..8).collect::<Vec<_>>());
+}
+
+#[test]
+fn seeded_order_is_reproducible() {
+    assert_eq!(run_with_seed(Some(1)), run_with_seed(Some(1)));
+    let orders = (0..8).map(|seed| run_with_seed(Some(seed))).collect::<Vec<_>>();
+    assert!(orders.iter().any(|order| *order != orders[0]));
+    for mut order in orders {
+        order.sort_unstable();
+        assert_eq!(order, (0..8).collect::<Vec<_>>());
+    }
+}
+
+#[test]
+fn tasks_spawn_tasks() {
+    let executor = RecordingExecutor::new();
+    let spawner = executor.spawner();
+    let (tx, rx) = oneshot::channel();
+    executor.spawn(async move {
+        spawner.spawn_local(async move { tx.send(()).unwrap() }).unwrap();
+        rx.await.unwrap();
+    });
+    executor.run_until_stalled();
+    assert_eq!(executor.pending_tasks(), 0);
+    assert_eq!(poll_order(&executor), vec![0, 1, 0]);
+    executor.assert_no_spurious_wakeups();
+}
+
+#[test]
+fn mutex_waiters_are_not_woken_spuriously() {
+    let executor = RecordingExecutor::new();
+    let mutex = Rc::new(Mutex::new(()));
+    let (tx, rx) = oneshot::channel();
+    let holder = mutex.clone();
+    executor.spawn(async move {
+        let _guard = holder.lock().await;
+        rx.await.unwrap();
+    });
+    for _ in 0..2 {
+        let mutex = mutex.clone();
+        executor.spawn(async move {
+            let _guard = mutex.lock().await;
+        });
+    }
+    executor.run_until_stalled();
+    assert_eq!(executor.pending_tasks(), 3);
+    tx.send(()).unwrap();
+    executor.run_until_stalled();
+    assert_eq!(executor.pending_tasks(), 0);
+    executor.assert_no_spurious_wakeups();
+}
+
+#[test]
+#[should_panic(expected = "tasks were woken without making progress")]
+fn detects_spurious_wakeups() {
+    let executor = RecordingExecutor::new();
+    let waker = Rc::new(RefCell::new(None::<Waker>));
+    let stored = waker.clone();
+    executor.spawn(poll_fn(move |cx| {
+        *stored.borrow_mut() = Some(cx.waker().clone());
+        Poll::Pending
+    }));
+    executor.run_until_stalled();
+    waker.borrow().as_ref().unwrap().wake_by_ref();
+    executor.run_until_stalled();
+    executor.assert_no_spurious_wakeups();
+}
@@ -1,6 +1,6 @@
 #![deny(unreachable_code)]
 
-use futures::{executor::block_on, try_join};
+use futures::{executor::block_on, join_try_all, try_join};
 
 // TODO: This abuses https://github.com/rust-lang/rust/issues/58733 in order to
 // test behavior of the `try_join!` macro with the never type before it is
@@ -33,3 +33,23 @@ fn try_join_never_ok() {
     })
     .unwrap_err();
 }
+
+#[test]
+fn join_try_all_never_error() {
+    block_on(async {
+        let future1 = async { Ok::<(), Never>(()) };
+        let future2 = async { Ok::<(), Never>(()) };
+        join_try_all!(future1, future2)
+    })
+    .unwrap();
+}
+
+#[test]
+fn join_try_all_never_ok() {
+    block_on(async {
+        let future1 = async { Err::<Never, ()>(()) };
+        let future2 = async { Err::<Never, ()>(()) };
+        join_try_all!(future1, future2)
+    })
+    .unwrap_err();
+}